@@ -0,0 +1,988 @@
+//! CalDAV backend implementation.
+//!
+//! Calendars that can hold VTODO components map to projects, and their VTODO resources
+//! map to tasks. Tasks are identified by the href of their `.ics` resource; subtasks use
+//! `RELATED-TO` with the parent's UID and labels come from `CATEGORIES`. CalDAV has no
+//! sections, no inbox and no separate label list, so labels are the categories used by
+//! open tasks and new tasks need a project (see `default_project` in the configuration).
+//!
+//! Works with servers such as Nextcloud Tasks and Fastmail, using the calendar home URL,
+//! the username and an app password.
+
+use super::{
+    Backend, BackendError, BackendLabel, BackendProject, BackendSection, BackendTask, CreateLabelArgs,
+    CreateProjectArgs, CreateTaskArgs, UpdateLabelArgs, UpdateProjectArgs, UpdateTaskArgs,
+};
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use reqwest::{Method, StatusCode, Url};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{PoisonError, RwLock};
+use uuid::Uuid;
+
+/// Color given to calendars and categories, which carry no Todoist color name
+const DEFAULT_COLOR: &str = "charcoal";
+
+const CALENDAR_LIST_BODY: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop>
+    <d:displayname/>
+    <d:resourcetype/>
+    <c:supported-calendar-component-set/>
+  </d:prop>
+</d:propfind>"#;
+
+const TODO_QUERY_BODY: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop>
+    <d:getetag/>
+    <c:calendar-data/>
+  </d:prop>
+  <c:filter>
+    <c:comp-filter name="VCALENDAR">
+      <c:comp-filter name="VTODO"/>
+    </c:comp-filter>
+  </c:filter>
+</c:calendar-query>"#;
+
+#[derive(Clone)]
+struct Credentials {
+    base_url: Url,
+    username: String,
+    password: String,
+}
+
+/// CalDAV backend implementation.
+pub struct CaldavBackend {
+    client: reqwest::Client,
+    // Behind a lock so the password can be replaced after a 401 without recreating the backend
+    credentials: RwLock<Credentials>,
+}
+
+/// Map a failed request to a backend error, detecting rejected credentials.
+fn status_error(method: &Method, url: &Url, status: StatusCode) -> BackendError {
+    let message = format!("{} {} returned {}", method, url.path(), status);
+    match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => BackendError::Auth(message),
+        StatusCode::NOT_FOUND => BackendError::NotFound(message),
+        _ => BackendError::Other(message),
+    }
+}
+
+fn dav_method(name: &'static str) -> Method {
+    Method::from_bytes(name.as_bytes()).expect("WebDAV method names are valid HTTP methods")
+}
+
+/// Parse a base URL, making sure it ends with `/` so relative paths join below it
+fn parse_base_url(url: &str) -> Result<Url, BackendError> {
+    let url = if url.ends_with('/') {
+        url.to_string()
+    } else {
+        format!("{}/", url)
+    };
+    Url::parse(&url).map_err(|e| BackendError::InvalidData(format!("Invalid CalDAV URL '{}': {}", url, e)))
+}
+
+impl CaldavBackend {
+    /// Create a new CalDAV backend for the calendar home at `base_url`.
+    ///
+    /// # Errors
+    /// Returns an error if `base_url` is not a valid URL
+    pub fn new(base_url: &str, username: String, password: String) -> Result<Self, BackendError> {
+        Ok(Self {
+            client: reqwest::Client::new(),
+            credentials: RwLock::new(Credentials {
+                base_url: parse_base_url(base_url)?,
+                username,
+                password,
+            }),
+        })
+    }
+
+    fn credentials(&self) -> Credentials {
+        self.credentials.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Absolute URL of a server path (hrefs in responses are usually absolute paths)
+    fn resolve(&self, href: &str) -> Result<Url, BackendError> {
+        self.credentials()
+            .base_url
+            .join(href)
+            .map_err(|e| BackendError::InvalidData(format!("Invalid href '{}': {}", href, e)))
+    }
+
+    /// Send a request and return the response body, mapping HTTP failures to backend errors
+    async fn send(
+        &self,
+        method: Method,
+        url: &Url,
+        headers: &[(&str, &str)],
+        body: Option<String>,
+    ) -> Result<String, BackendError> {
+        let credentials = self.credentials();
+        let mut request = self
+            .client
+            .request(method.clone(), url.clone())
+            .basic_auth(&credentials.username, Some(&credentials.password));
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        if let Some(body) = body {
+            request = request.body(body);
+        }
+
+        let response = request.send().await.map_err(|e| BackendError::Network(e.to_string()))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(status_error(&method, url, status));
+        }
+        response.text().await.map_err(|e| BackendError::Network(e.to_string()))
+    }
+
+    /// Fetch a task resource and parse its VTODO
+    async fn get_todo(&self, href: &str) -> Result<TodoResource, BackendError> {
+        let url = self.resolve(href)?;
+        let ics = self.send(Method::GET, &url, &[], None).await?;
+        TodoResource::parse(&ics).ok_or_else(|| BackendError::InvalidData(format!("No VTODO in {}", href)))
+    }
+
+    /// Store a task resource; `create` refuses to overwrite an existing one
+    async fn put_todo(&self, href: &str, todo: &TodoResource, create: bool) -> Result<(), BackendError> {
+        let url = self.resolve(href)?;
+        let mut headers = vec![("Content-Type", "text/calendar; charset=utf-8")];
+        if create {
+            headers.push(("If-None-Match", "*"));
+        }
+        self.send(Method::PUT, &url, &headers, Some(todo.to_ics())).await?;
+        Ok(())
+    }
+
+    /// UID of the task stored at `href`, for `RELATED-TO` links
+    async fn uid_of(&self, href: &str) -> Result<String, BackendError> {
+        self.get_todo(href)
+            .await?
+            .uid()
+            .ok_or_else(|| BackendError::InvalidData(format!("Task {} has no UID", href)))
+    }
+
+    /// Apply `change` to the task at `href` and store it again
+    async fn modify_todo<F>(&self, href: &str, change: F) -> Result<BackendTask, BackendError>
+    where
+        F: FnOnce(&mut TodoResource),
+    {
+        let mut todo = self.get_todo(href).await?;
+        change(&mut todo);
+        todo.touch();
+        self.put_todo(href, &todo, false).await?;
+        Ok(todo.to_backend(href, &calendar_of(href), None))
+    }
+
+    async fn fetch_calendars(&self) -> Result<Vec<BackendProject>, BackendError> {
+        let base_url = self.credentials().base_url;
+        let xml = self
+            .send(
+                dav_method("PROPFIND"),
+                &base_url,
+                &[("Depth", "1"), ("Content-Type", "application/xml; charset=utf-8")],
+                Some(CALENDAR_LIST_BODY.to_string()),
+            )
+            .await?;
+        Ok(parse_calendar_list(&xml))
+    }
+}
+
+/// Href of the calendar holding the task resource at `href`
+fn calendar_of(href: &str) -> String {
+    match href.trim_end_matches('/').rfind('/') {
+        Some(index) => href[..=index].to_string(),
+        None => String::new(),
+    }
+}
+
+#[async_trait]
+impl Backend for CaldavBackend {
+    fn backend_type(&self) -> &str {
+        "caldav"
+    }
+
+    fn set_credentials(&self, credentials: &str) -> Result<(), BackendError> {
+        let creds: serde_json::Value = serde_json::from_str(credentials)
+            .map_err(|e| BackendError::InvalidData(format!("Failed to parse credentials JSON: {}", e)))?;
+        // A token update only carries the secret, which is the app password here
+        let password = creds["password"]
+            .as_str()
+            .or_else(|| creds["api_token"].as_str())
+            .ok_or_else(|| BackendError::InvalidData("Missing 'password' in CalDAV credentials".to_string()))?;
+
+        let mut current = self.credentials.write().unwrap_or_else(PoisonError::into_inner);
+        if let Some(url) = creds["url"].as_str() {
+            current.base_url = parse_base_url(url)?;
+        }
+        if let Some(username) = creds["username"].as_str() {
+            current.username = username.to_string();
+        }
+        current.password = password.to_string();
+        Ok(())
+    }
+
+    fn has_inbox(&self) -> bool {
+        false
+    }
+
+    async fn fetch_projects(&self) -> Result<Vec<BackendProject>, BackendError> {
+        self.fetch_calendars().await
+    }
+
+    async fn fetch_tasks(&self) -> Result<Vec<BackendTask>, BackendError> {
+        let mut resources = Vec::new();
+        for calendar in self.fetch_calendars().await? {
+            let url = self.resolve(&calendar.remote_id)?;
+            let xml = self
+                .send(
+                    dav_method("REPORT"),
+                    &url,
+                    &[("Depth", "1"), ("Content-Type", "application/xml; charset=utf-8")],
+                    Some(TODO_QUERY_BODY.to_string()),
+                )
+                .await?;
+            resources.extend(
+                parse_todo_list(&xml)
+                    .into_iter()
+                    .map(|(href, todo)| (href, calendar.remote_id.clone(), todo)),
+            );
+        }
+
+        // Subtasks point at their parent's UID, tasks are identified by href
+        let href_by_uid: HashMap<String, String> = resources
+            .iter()
+            .filter_map(|(href, _, todo)| todo.uid().map(|uid| (uid, href.clone())))
+            .collect();
+
+        // Like the Todoist backend, only open tasks are returned
+        Ok(resources
+            .iter()
+            .filter(|(_, _, todo)| !todo.is_closed())
+            .map(|(href, calendar, todo)| {
+                let parent_href = todo.parent_uid().and_then(|uid| href_by_uid.get(&uid).cloned());
+                todo.to_backend(href, calendar, parent_href)
+            })
+            .collect())
+    }
+
+    async fn fetch_labels(&self) -> Result<Vec<BackendLabel>, BackendError> {
+        let categories: BTreeSet<String> = self.fetch_tasks().await?.into_iter().flat_map(|task| task.labels).collect();
+        Ok(categories
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| category_label(name, index as i32))
+            .collect())
+    }
+
+    async fn fetch_sections(&self) -> Result<Vec<BackendSection>, BackendError> {
+        Ok(Vec::new())
+    }
+
+    async fn create_project(&self, args: CreateProjectArgs) -> Result<BackendProject, BackendError> {
+        // Calendars are not nested, so a parent project is ignored; colors are not kept either
+        let url = self.resolve(&format!("{}/", Uuid::new_v4()))?;
+        let body = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<c:mkcalendar xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:set>
+    <d:prop>
+      <d:displayname>{}</d:displayname>
+      <c:supported-calendar-component-set>
+        <c:comp name="VTODO"/>
+      </c:supported-calendar-component-set>
+    </d:prop>
+  </d:set>
+</c:mkcalendar>"#,
+            xml_escape(&args.name)
+        );
+        self.send(
+            dav_method("MKCALENDAR"),
+            &url,
+            &[("Content-Type", "application/xml; charset=utf-8")],
+            Some(body),
+        )
+        .await?;
+
+        Ok(BackendProject {
+            remote_id: url.path().to_string(),
+            name: args.name,
+            color: DEFAULT_COLOR.to_string(),
+            is_favorite: false,
+            is_inbox: false,
+            order_index: 0,
+            parent_remote_id: None,
+        })
+    }
+
+    async fn update_project(&self, remote_id: &str, args: UpdateProjectArgs) -> Result<BackendProject, BackendError> {
+        let url = self.resolve(remote_id)?;
+        if let Some(name) = &args.name {
+            let body = format!(
+                r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propertyupdate xmlns:d="DAV:">
+  <d:set>
+    <d:prop>
+      <d:displayname>{}</d:displayname>
+    </d:prop>
+  </d:set>
+</d:propertyupdate>"#,
+                xml_escape(name)
+            );
+            self.send(
+                dav_method("PROPPATCH"),
+                &url,
+                &[("Content-Type", "application/xml; charset=utf-8")],
+                Some(body),
+            )
+            .await?;
+        }
+
+        self.fetch_calendars()
+            .await?
+            .into_iter()
+            .find(|calendar| calendar.remote_id == remote_id)
+            .ok_or_else(|| BackendError::NotFound(format!("Calendar {}", remote_id)))
+    }
+
+    async fn delete_project(&self, remote_id: &str) -> Result<(), BackendError> {
+        let url = self.resolve(remote_id)?;
+        self.send(Method::DELETE, &url, &[], None).await?;
+        Ok(())
+    }
+
+    async fn create_task(&self, args: CreateTaskArgs) -> Result<BackendTask, BackendError> {
+        if args.project_remote_id.is_empty() {
+            return Err(BackendError::InvalidData(
+                "CalDAV tasks need a calendar; set default_project for this backend".to_string(),
+            ));
+        }
+
+        let uid = Uuid::new_v4().to_string();
+        let calendar = if args.project_remote_id.ends_with('/') {
+            args.project_remote_id.clone()
+        } else {
+            format!("{}/", args.project_remote_id)
+        };
+        let href = format!("{}{}.ics", calendar, uid);
+
+        let mut todo = TodoResource::new(&uid, &args.content);
+        if let Some(description) = args.description.as_deref().filter(|d| !d.is_empty()) {
+            todo.set_text("DESCRIPTION", description);
+        }
+        if let Some(priority) = args.priority {
+            todo.set_priority(priority);
+        }
+        todo.set_due(args.due_date.as_deref(), args.due_datetime.as_deref());
+        todo.set_categories(&args.labels);
+        if let Some(parent) = &args.parent_remote_id {
+            todo.set("RELATED-TO", &self.uid_of(parent).await?);
+        }
+
+        self.put_todo(&href, &todo, true).await?;
+        Ok(todo.to_backend(&href, &calendar, args.parent_remote_id))
+    }
+
+    async fn update_task(&self, remote_id: &str, args: UpdateTaskArgs) -> Result<BackendTask, BackendError> {
+        if args
+            .project_remote_id
+            .as_deref()
+            .is_some_and(|project| project.trim_end_matches('/') != calendar_of(remote_id).trim_end_matches('/'))
+        {
+            return Err(BackendError::Other(
+                "Moving tasks between CalDAV calendars is not supported".to_string(),
+            ));
+        }
+        let parent_uid = match &args.parent_remote_id {
+            Some(parent) => Some(self.uid_of(parent).await?),
+            None => None,
+        };
+
+        // Deadlines have no VTODO property and stay local
+        let mut task = self
+            .modify_todo(remote_id, |todo| {
+                if let Some(content) = &args.content {
+                    todo.set_text("SUMMARY", content);
+                }
+                if let Some(description) = &args.description {
+                    if description.is_empty() {
+                        todo.remove("DESCRIPTION");
+                    } else {
+                        todo.set_text("DESCRIPTION", description);
+                    }
+                }
+                if let Some(uid) = &parent_uid {
+                    todo.set("RELATED-TO", uid);
+                }
+                if let Some(priority) = args.priority {
+                    todo.set_priority(priority);
+                }
+                if args.due_date.is_some() || args.due_datetime.is_some() {
+                    todo.set_due(args.due_date.as_deref(), args.due_datetime.as_deref());
+                }
+                if let Some(labels) = &args.labels {
+                    todo.set_categories(labels);
+                }
+            })
+            .await?;
+        task.parent_remote_id = args.parent_remote_id;
+        Ok(task)
+    }
+
+    async fn delete_task(&self, remote_id: &str) -> Result<(), BackendError> {
+        let url = self.resolve(remote_id)?;
+        self.send(Method::DELETE, &url, &[], None).await?;
+        Ok(())
+    }
+
+    /// Recurring tasks are completed outright; the server doesn't advance them like Todoist
+    async fn complete_task(&self, remote_id: &str) -> Result<(), BackendError> {
+        self.modify_todo(remote_id, |todo| {
+            todo.set("STATUS", "COMPLETED");
+            todo.set("COMPLETED", &Utc::now().format("%Y%m%dT%H%M%SZ").to_string());
+            todo.set("PERCENT-COMPLETE", "100");
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn reopen_task(&self, remote_id: &str) -> Result<(), BackendError> {
+        self.modify_todo(remote_id, |todo| {
+            todo.set("STATUS", "NEEDS-ACTION");
+            todo.remove("COMPLETED");
+            todo.remove("PERCENT-COMPLETE");
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Categories exist once a task uses them, so nothing is sent to the server
+    async fn create_label(&self, args: CreateLabelArgs) -> Result<BackendLabel, BackendError> {
+        Ok(category_label(args.name, 0))
+    }
+
+    async fn update_label(&self, _remote_id: &str, _args: UpdateLabelArgs) -> Result<BackendLabel, BackendError> {
+        Err(BackendError::Other(
+            "CalDAV has no label list; change the task's categories instead".to_string(),
+        ))
+    }
+
+    async fn delete_label(&self, _remote_id: &str) -> Result<(), BackendError> {
+        Err(BackendError::Other(
+            "CalDAV has no label list; change the task's categories instead".to_string(),
+        ))
+    }
+}
+
+fn category_label(name: String, order_index: i32) -> BackendLabel {
+    BackendLabel {
+        remote_id: name.clone(),
+        name,
+        color: DEFAULT_COLOR.to_string(),
+        order_index,
+        is_favorite: false,
+    }
+}
+
+/// Calendars able to hold tasks in a `PROPFIND` multistatus response.
+///
+/// Collections that are not calendars (such as the calendar home itself) and calendars
+/// limited to other components (e.g. events only) are skipped.
+pub fn parse_calendar_list(xml: &str) -> Vec<BackendProject> {
+    xml_elements(xml, "response")
+        .into_iter()
+        .filter(|response| {
+            let is_calendar = xml_elements(response, "resourcetype")
+                .first()
+                .is_some_and(|types| !xml_elements(types, "calendar").is_empty());
+            let holds_todos = xml_elements(response, "supported-calendar-component-set")
+                .first()
+                .map_or(true, |components| components.contains("VTODO"));
+            is_calendar && holds_todos
+        })
+        .filter_map(|response| {
+            let href = xml_text(response, "href")?;
+            let name = xml_text(response, "displayname")
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| href.trim_end_matches('/').rsplit('/').next().unwrap_or_default().to_string());
+            Some(BackendProject {
+                remote_id: href,
+                name,
+                color: DEFAULT_COLOR.to_string(),
+                is_favorite: false,
+                is_inbox: false,
+                order_index: 0,
+                parent_remote_id: None,
+            })
+        })
+        .enumerate()
+        .map(|(index, mut project)| {
+            project.order_index = index as i32;
+            project
+        })
+        .collect()
+}
+
+/// Task resources (href and VTODO) in a `calendar-query` multistatus response
+pub fn parse_todo_list(xml: &str) -> Vec<(String, TodoResource)> {
+    xml_elements(xml, "response")
+        .into_iter()
+        .filter_map(|response| {
+            let href = xml_text(response, "href")?;
+            let ics = xml_text(response, "calendar-data")?;
+            Some((href, TodoResource::parse(&ics)?))
+        })
+        .collect()
+}
+
+/// A calendar object holding one VTODO.
+///
+/// The content lines are kept as they are so properties this backend doesn't know
+/// (alarms, time zones, client extensions) survive an update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TodoResource {
+    /// Unfolded lines before the VTODO properties, up to and including `BEGIN:VTODO`
+    head: Vec<String>,
+    /// Unfolded lines of the VTODO, including nested components such as VALARM
+    body: Vec<String>,
+    /// Unfolded lines from `END:VTODO` on
+    tail: Vec<String>,
+}
+
+impl TodoResource {
+    fn new(uid: &str, summary: &str) -> Self {
+        let now = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let mut todo = Self {
+            head: vec![
+                "BEGIN:VCALENDAR".to_string(),
+                "VERSION:2.0".to_string(),
+                "PRODID:-//terminalist//EN".to_string(),
+                "BEGIN:VTODO".to_string(),
+            ],
+            body: Vec::new(),
+            tail: vec!["END:VTODO".to_string(), "END:VCALENDAR".to_string()],
+        };
+        todo.set("UID", uid);
+        todo.set("DTSTAMP", &now);
+        todo.set("CREATED", &now);
+        todo.set_text("SUMMARY", summary);
+        todo.set("STATUS", "NEEDS-ACTION");
+        todo
+    }
+
+    /// Parse an iCalendar object, returning `None` if it has no VTODO
+    pub fn parse(ics: &str) -> Option<Self> {
+        let lines = unfold(ics);
+        let begin = lines.iter().position(|line| line.eq_ignore_ascii_case("BEGIN:VTODO"))?;
+        let end = begin + lines[begin..].iter().position(|line| line.eq_ignore_ascii_case("END:VTODO"))?;
+        Some(Self {
+            head: lines[..=begin].to_vec(),
+            body: lines[begin + 1..end].to_vec(),
+            tail: lines[end..].to_vec(),
+        })
+    }
+
+    /// Serialize with CRLF line endings and lines folded at 75 octets
+    pub fn to_ics(&self) -> String {
+        self.head
+            .iter()
+            .chain(&self.body)
+            .chain(&self.tail)
+            .map(|line| fold(line))
+            .collect()
+    }
+
+    /// Indices of the VTODO's own properties, skipping nested components
+    fn property_indices(&self) -> Vec<usize> {
+        let mut depth = 0;
+        let mut indices = Vec::new();
+        for (index, line) in self.body.iter().enumerate() {
+            let name = property_name(line);
+            if name == "BEGIN" {
+                depth += 1;
+            } else if name == "END" {
+                depth -= 1;
+            } else if depth == 0 {
+                indices.push(index);
+            }
+        }
+        indices
+    }
+
+    /// Raw values of every occurrence of a property
+    fn values(&self, name: &str) -> Vec<(&str, &str)> {
+        self.property_indices()
+            .into_iter()
+            .map(|index| self.body[index].as_str())
+            .filter(|line| property_name(line) == name)
+            .map(split_property)
+            .collect()
+    }
+
+    /// Raw value of the first occurrence of a property, with its parameters
+    fn get(&self, name: &str) -> Option<(&str, &str)> {
+        self.values(name).into_iter().next()
+    }
+
+    fn get_text(&self, name: &str) -> Option<String> {
+        self.get(name).map(|(_, value)| unescape_text(value))
+    }
+
+    fn remove(&mut self, name: &str) {
+        let indices = self.property_indices();
+        let mut index = 0;
+        self.body.retain(|line| {
+            let keep = !(indices.contains(&index) && property_name(line) == name);
+            index += 1;
+            keep
+        });
+    }
+
+    /// Replace every occurrence of a property with one raw line (`NAME:value` or `NAME;PARAMS:value`)
+    fn set_line(&mut self, line: String) {
+        self.remove(property_name(&line).as_str());
+        // Properties go before nested components such as alarms
+        let position = self
+            .body
+            .iter()
+            .position(|line| property_name(line) == "BEGIN")
+            .unwrap_or(self.body.len());
+        self.body.insert(position, line);
+    }
+
+    fn set(&mut self, name: &str, value: &str) {
+        self.set_line(format!("{}:{}", name, value));
+    }
+
+    fn set_text(&mut self, name: &str, value: &str) {
+        self.set(name, &escape_text(value));
+    }
+
+    fn set_priority(&mut self, priority: i32) {
+        // Backend priority 4 (P1) is the most urgent; iCalendar uses 1 (high) to 9 (low)
+        match priority {
+            4 => self.set("PRIORITY", "1"),
+            3 => self.set("PRIORITY", "5"),
+            2 => self.set("PRIORITY", "9"),
+            _ => self.remove("PRIORITY"),
+        }
+    }
+
+    /// Set the due date or datetime; an empty date removes it
+    fn set_due(&mut self, due_date: Option<&str>, due_datetime: Option<&str>) {
+        if let Some(datetime) = due_datetime.and_then(crate::utils::datetime::parse_datetime) {
+            let utc = datetime.with_timezone(&Utc);
+            self.set("DUE", &utc.format("%Y%m%dT%H%M%SZ").to_string());
+        } else if let Some(date) = due_date.and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok()) {
+            self.set_line(format!("DUE;VALUE=DATE:{}", date.format("%Y%m%d")));
+        } else if due_date == Some("") {
+            self.remove("DUE");
+        }
+    }
+
+    fn set_categories(&mut self, labels: &[String]) {
+        if labels.is_empty() {
+            self.remove("CATEGORIES");
+        } else {
+            let escaped: Vec<String> = labels.iter().map(|label| escape_text(label)).collect();
+            self.set("CATEGORIES", &escaped.join(","));
+        }
+    }
+
+    /// Update the modification timestamps after a change
+    fn touch(&mut self) {
+        let now = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        self.set("DTSTAMP", &now);
+        self.set("LAST-MODIFIED", &now);
+    }
+
+    pub fn uid(&self) -> Option<String> {
+        self.get_text("UID")
+    }
+
+    /// UID of the parent task (`RELATED-TO` without a RELTYPE, or with `RELTYPE=PARENT`)
+    pub fn parent_uid(&self) -> Option<String> {
+        self.values("RELATED-TO")
+            .into_iter()
+            .find(|(params, _)| {
+                !params.to_ascii_uppercase().contains("RELTYPE=")
+                    || params.to_ascii_uppercase().contains("RELTYPE=PARENT")
+            })
+            .map(|(_, value)| unescape_text(value))
+    }
+
+    /// Completed or cancelled
+    pub fn is_closed(&self) -> bool {
+        self.get("STATUS").is_some_and(|(_, status)| {
+            status.eq_ignore_ascii_case("COMPLETED") || status.eq_ignore_ascii_case("CANCELLED")
+        }) || self.get("COMPLETED").is_some()
+    }
+
+    /// Convert to a backend task stored at `href` in the calendar `calendar_href`
+    pub fn to_backend(&self, href: &str, calendar_href: &str, parent_href: Option<String>) -> BackendTask {
+        let (due_date, due_datetime) = match self.get("DUE") {
+            Some((params, value)) => parse_due(params, value),
+            None => (None, None),
+        };
+        // iCalendar 1-4 is high, 5 medium and 6-9 low; 0 or missing means undefined
+        let priority = match self.get("PRIORITY").and_then(|(_, value)| value.trim().parse::<u8>().ok()) {
+            Some(1..=4) => 4,
+            Some(5) => 3,
+            Some(6..=9) => 2,
+            _ => 1,
+        };
+        let labels = self
+            .values("CATEGORIES")
+            .into_iter()
+            .flat_map(|(_, value)| split_list(value))
+            .filter(|label| !label.is_empty())
+            .collect();
+
+        BackendTask {
+            remote_id: href.to_string(),
+            content: self.get_text("SUMMARY").unwrap_or_default(),
+            description: self.get_text("DESCRIPTION"),
+            project_remote_id: calendar_href.to_string(),
+            section_remote_id: None,
+            parent_remote_id: parent_href,
+            priority,
+            order_index: 0,
+            due_date,
+            due_datetime,
+            due_string: self.get("RRULE").and_then(|(_, rule)| describe_rrule(rule)),
+            is_recurring: self.get("RRULE").is_some(),
+            deadline: None,
+            duration: None,
+            is_completed: self.is_closed(),
+            labels,
+        }
+    }
+}
+
+/// Due date and datetime in the formats stored on tasks ("YYYY-MM-DD", RFC 3339 or local)
+fn parse_due(params: &str, value: &str) -> (Option<String>, Option<String>) {
+    let value = value.trim();
+    let is_date = params.to_ascii_uppercase().split(';').any(|param| param == "VALUE=DATE");
+    if is_date || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok();
+        return (date.map(|d| d.format("%Y-%m-%d").to_string()), None);
+    }
+
+    let (local, is_utc) = match value.strip_suffix('Z') {
+        Some(local) => (local, true),
+        None => (value, false),
+    };
+    let Ok(datetime) = NaiveDateTime::parse_from_str(local, "%Y%m%dT%H%M%S") else {
+        return (None, None);
+    };
+    // Datetimes with a TZID are taken as local time
+    let formatted = if is_utc {
+        datetime.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+    } else {
+        datetime.format("%Y-%m-%dT%H:%M:%S").to_string()
+    };
+    (Some(datetime.format("%Y-%m-%d").to_string()), Some(formatted))
+}
+
+/// Plain-English text for the common forms of an RRULE ("every 2 weeks", "every monday")
+///
+/// Rules this doesn't know how to say give `None`; the task still counts as recurring.
+fn describe_rrule(rule: &str) -> Option<String> {
+    let parts: Vec<(String, &str)> = rule
+        .split(';')
+        .filter_map(|part| part.split_once('='))
+        .map(|(name, value)| (name.trim().to_ascii_uppercase(), value.trim()))
+        .collect();
+    let part = |name: &str| parts.iter().find(|(key, _)| key == name).map(|(_, value)| *value);
+
+    let unit = match part("FREQ")?.to_ascii_uppercase().as_str() {
+        "DAILY" => "day",
+        "WEEKLY" => "week",
+        "MONTHLY" => "month",
+        "YEARLY" => "year",
+        _ => return None,
+    };
+    let interval = part("INTERVAL").and_then(|i| i.parse::<u32>().ok()).unwrap_or(1);
+
+    // A weekly rule on a single day reads best as that day
+    if unit == "week" && interval == 1 {
+        let day = match part("BYDAY").map(str::to_ascii_uppercase).as_deref() {
+            Some("MO") => Some("monday"),
+            Some("TU") => Some("tuesday"),
+            Some("WE") => Some("wednesday"),
+            Some("TH") => Some("thursday"),
+            Some("FR") => Some("friday"),
+            Some("SA") => Some("saturday"),
+            Some("SU") => Some("sunday"),
+            _ => None,
+        };
+        if let Some(day) = day {
+            return Some(format!("every {}", day));
+        }
+    }
+
+    Some(match interval {
+        1 => format!("every {}", unit),
+        n => format!("every {} {}s", n, unit),
+    })
+}
+
+/// Join continuation lines (starting with a space or tab) to the line before
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in ics.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        if let (Some(continuation), Some(previous)) = (
+            raw.strip_prefix(' ').or_else(|| raw.strip_prefix('\t')),
+            lines.last_mut(),
+        ) {
+            previous.push_str(continuation);
+        } else if !raw.is_empty() {
+            lines.push(raw.to_string());
+        }
+    }
+    lines
+}
+
+/// Fold a content line at 75 octets and terminate it with CRLF
+fn fold(line: &str) -> String {
+    let mut folded = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+/// Upper-case property name of a content line
+fn property_name(line: &str) -> String {
+    line.split([';', ':']).next().unwrap_or_default().to_ascii_uppercase()
+}
+
+/// Split a content line into its parameters and its value, honoring quoted parameters
+fn split_property(line: &str) -> (&str, &str) {
+    let mut in_quotes = false;
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ':' if !in_quotes => {
+                let name_end = line[..index].find(';').unwrap_or(index);
+                return (&line[name_end..index], &line[index + 1..]);
+            }
+            _ => {}
+        }
+    }
+    ("", "")
+}
+
+/// Split a comma-separated TEXT list, respecting escaped commas
+fn split_list(value: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                current.push(c);
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            ',' => items.push(unescape_text(&std::mem::take(&mut current)).trim().to_string()),
+            _ => current.push(c),
+        }
+    }
+    items.push(unescape_text(&current).trim().to_string());
+    items
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn unescape_text(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => unescaped.push('\n'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Contents of every element with the given local name, whatever its namespace prefix.
+///
+/// Self-closing elements yield an empty string. Elements of the same name are not
+/// expected to nest, which holds for the WebDAV responses parsed here.
+fn xml_elements<'a>(xml: &'a str, local_name: &str) -> Vec<&'a str> {
+    let mut elements = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        let Some(tag_end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[..tag_end];
+        let qualified_name = tag.split(|c: char| c.is_whitespace() || c == '/').next().unwrap_or_default();
+        let name = qualified_name.rsplit(':').next().unwrap_or_default();
+        if tag.starts_with(['/', '?', '!']) || name != local_name {
+            continue;
+        }
+
+        rest = &rest[tag_end + 1..];
+        if tag.ends_with('/') {
+            elements.push("");
+            continue;
+        }
+        let closing = format!("</{}>", qualified_name);
+        match rest.find(&closing) {
+            Some(end) => {
+                elements.push(&rest[..end]);
+                rest = &rest[end + closing.len()..];
+            }
+            None => break,
+        }
+    }
+    elements
+}
+
+/// Unescaped text of the first element with the given local name
+fn xml_text(xml: &str, local_name: &str) -> Option<String> {
+    xml_elements(xml, local_name).first().map(|text| xml_unescape(text.trim()))
+}
+
+fn xml_unescape(text: &str) -> String {
+    if let Some(cdata) = text.strip_prefix("<![CDATA[").and_then(|t| t.strip_suffix("]]>")) {
+        return cdata.to_string();
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&#13;", "\r")
+        .replace("&#xD;", "\r")
+        .replace("&amp;", "&")
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
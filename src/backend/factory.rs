@@ -3,13 +3,15 @@
 use anyhow::{anyhow, Result};
 use serde_json::Value;
 
-use super::{todoist::TodoistBackend, Backend};
+use super::{caldav::CaldavBackend, local::LocalBackend, todoist::TodoistBackend, Backend};
 
 /// Create a backend instance from backend type and credentials.
 ///
 /// # Arguments
-/// * `backend_type` - The type of backend (e.g., "todoist")
-/// * `credentials` - JSON-encoded credentials string
+/// * `backend_type` - The type of backend ("todoist", "caldav" or "local")
+/// * `credentials` - JSON-encoded credentials string: `api_token` for Todoist,
+///   `url`, `username` and `password` for CalDAV, and an optional database `path`
+///   for the local backend
 ///
 /// # Returns
 /// A boxed Backend trait object
@@ -30,6 +32,29 @@ pub fn create_backend(backend_type: &str, credentials: &str) -> Result<Box<dyn B
                 .ok_or_else(|| anyhow!("Missing 'api_token' in Todoist credentials"))?;
             Ok(Box::new(TodoistBackend::new(api_token.to_string())))
         }
+        "caldav" => {
+            let url = creds["url"]
+                .as_str()
+                .ok_or_else(|| anyhow!("Missing 'url' in CalDAV credentials"))?;
+            let username = creds["username"]
+                .as_str()
+                .ok_or_else(|| anyhow!("Missing 'username' in CalDAV credentials"))?;
+            let password = creds["password"]
+                .as_str()
+                .ok_or_else(|| anyhow!("Missing 'password' in CalDAV credentials"))?;
+            Ok(Box::new(CaldavBackend::new(
+                url,
+                username.to_string(),
+                password.to_string(),
+            )?))
+        }
+        "local" => {
+            let path = match creds["path"].as_str() {
+                Some(path) => path.into(),
+                None => LocalBackend::default_path()?,
+            };
+            Ok(Box::new(LocalBackend::open(path)))
+        }
         // Future backends can be added here:
         // "ticktick" => {
         //     let api_token = creds["api_token"].as_str().ok_or(...)?;
@@ -0,0 +1,561 @@
+//! Local-only backend implementation.
+//!
+//! Keeps projects, sections, labels and tasks in a SQLite database of its own, so
+//! terminalist can be used as a standalone task manager without an account. The
+//! session database is rebuilt from this store on every sync like with any other
+//! backend; nothing leaves the machine.
+//!
+//! Records are identified by the string form of their UUID in the store, which stays
+//! the same across runs.
+
+use super::{
+    Backend, BackendError, BackendLabel, BackendProject, BackendSection, BackendTask, CreateLabelArgs,
+    CreateProjectArgs, CreateTaskArgs, UpdateLabelArgs, UpdateProjectArgs, UpdateTaskArgs,
+};
+use crate::entities::{backend, label, project, section, task};
+use crate::repositories::{BackendRepository, LabelRepository, ProjectRepository, TaskRepository};
+use crate::storage::LocalStorage;
+use crate::utils::datetime;
+use anyhow::Context;
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, IntoActiveModel, PaginatorTrait,
+    QueryFilter, QueryOrder, TransactionTrait,
+};
+use std::path::{Path, PathBuf};
+use tokio::sync::OnceCell;
+use uuid::Uuid;
+
+/// Color given to projects and labels created without one
+const DEFAULT_COLOR: &str = "charcoal";
+
+/// Opened store: the database and the backend row its records belong to
+struct Store {
+    storage: LocalStorage,
+    backend_uuid: Uuid,
+}
+
+/// Local-only backend implementation.
+pub struct LocalBackend {
+    /// Database file, or `None` for a throwaway in-memory store
+    path: Option<PathBuf>,
+    // Opened on first use, since backends are created synchronously by the factory
+    store: OnceCell<Store>,
+}
+
+fn store_error(error: impl std::fmt::Display) -> BackendError {
+    BackendError::Other(format!("Local store: {:#}", error))
+}
+
+/// Parse a remote ID (the record's UUID in the store)
+fn parse_id(remote_id: &str) -> Result<Uuid, BackendError> {
+    Uuid::parse_str(remote_id).map_err(|_| BackendError::NotFound(remote_id.to_string()))
+}
+
+impl LocalBackend {
+    /// Create a backend storing its data in the database file at `path`.
+    pub fn open(path: PathBuf) -> Self {
+        Self {
+            path: Some(path),
+            store: OnceCell::new(),
+        }
+    }
+
+    /// Create a backend whose data only lives as long as the process (used by tests).
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            store: OnceCell::new(),
+        }
+    }
+
+    /// Default store location (`<XDG data dir>/terminalist/local.db`)
+    pub fn default_path() -> anyhow::Result<PathBuf> {
+        let data_dir = dirs::data_dir().context("Failed to get XDG data directory")?;
+        Ok(data_dir.join("terminalist").join("local.db"))
+    }
+
+    async fn store(&self) -> Result<&Store, BackendError> {
+        self.store
+            .get_or_try_init(|| async { Self::init_store(self.path.as_deref()).await.map_err(store_error) })
+            .await
+    }
+
+    async fn conn(&self) -> Result<&DatabaseConnection, BackendError> {
+        Ok(&self.store().await?.storage.conn)
+    }
+
+    /// Open the database, creating the backend row and an inbox on first use
+    async fn init_store(path: Option<&Path>) -> anyhow::Result<Store> {
+        let storage = match path {
+            Some(path) => LocalStorage::open(path).await?,
+            None => LocalStorage::new_in_memory().await?,
+        };
+
+        let backend_uuid = match BackendRepository::get_first(&storage.conn).await? {
+            Some(existing) => existing.uuid,
+            None => {
+                let model = backend::ActiveModel {
+                    uuid: ActiveValue::Set(Uuid::new_v4()),
+                    backend_type: ActiveValue::Set("local".to_string()),
+                    name: ActiveValue::Set("Local".to_string()),
+                    is_enabled: ActiveValue::Set(true),
+                    credentials: ActiveValue::Set("{}".to_string()),
+                    settings: ActiveValue::Set("{}".to_string()),
+                    sync_token: ActiveValue::Set(None),
+                    last_sync_at: ActiveValue::Set(None),
+                };
+                BackendRepository::create(&storage.conn, model).await?.uuid
+            }
+        };
+
+        let has_inbox = project::Entity::find()
+            .filter(project::Column::IsInboxProject.eq(true))
+            .count(&storage.conn)
+            .await?
+            > 0;
+        if !has_inbox {
+            let uuid = Uuid::new_v4();
+            project::ActiveModel {
+                uuid: ActiveValue::Set(uuid),
+                backend_uuid: ActiveValue::Set(backend_uuid),
+                remote_id: ActiveValue::Set(uuid.to_string()),
+                name: ActiveValue::Set("Inbox".to_string()),
+                color: ActiveValue::Set(DEFAULT_COLOR.to_string()),
+                is_favorite: ActiveValue::Set(false),
+                is_inbox_project: ActiveValue::Set(true),
+                order_index: ActiveValue::Set(0),
+                parent_uuid: ActiveValue::Set(None),
+            }
+            .insert(&storage.conn)
+            .await?;
+        }
+
+        Ok(Store { storage, backend_uuid })
+    }
+
+    async fn get_project(&self, remote_id: &str) -> Result<project::Model, BackendError> {
+        ProjectRepository::get_by_id(self.conn().await?, &parse_id(remote_id)?)
+            .await
+            .map_err(store_error)?
+            .ok_or_else(|| BackendError::NotFound(format!("Project {}", remote_id)))
+    }
+
+    async fn get_task(&self, remote_id: &str) -> Result<task::Model, BackendError> {
+        TaskRepository::get_by_id(self.conn().await?, &parse_id(remote_id)?)
+            .await
+            .map_err(store_error)?
+            .ok_or_else(|| BackendError::NotFound(format!("Task {}", remote_id)))
+    }
+
+    async fn get_label(&self, remote_id: &str) -> Result<label::Model, BackendError> {
+        LabelRepository::get_by_id(self.conn().await?, &parse_id(remote_id)?)
+            .await
+            .map_err(store_error)?
+            .ok_or_else(|| BackendError::NotFound(format!("Label {}", remote_id)))
+    }
+
+    /// Inbox project, used for tasks created without a project
+    async fn inbox(&self) -> Result<project::Model, BackendError> {
+        project::Entity::find()
+            .filter(project::Column::IsInboxProject.eq(true))
+            .one(self.conn().await?)
+            .await
+            .map_err(store_error)?
+            .ok_or_else(|| BackendError::NotFound("Inbox project".to_string()))
+    }
+
+    /// Create the labels in `names` that don't exist yet, like Todoist does for new label names
+    async fn ensure_labels(&self, names: &[String]) -> Result<(), BackendError> {
+        for name in names {
+            let conn = self.conn().await?;
+            if LabelRepository::get_by_name(conn, name).await.map_err(store_error)?.is_none() {
+                self.create_label(CreateLabelArgs {
+                    name: name.clone(),
+                    is_favorite: None,
+                })
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Store the labels named in `names` on a task
+    async fn set_task_labels(&self, task_uuid: &Uuid, names: &[String]) -> Result<(), BackendError> {
+        self.ensure_labels(names).await?;
+        LabelRepository::set_for_task(self.conn().await?, task_uuid, names)
+            .await
+            .map_err(store_error)
+    }
+
+    async fn task_to_backend(&self, model: task::Model) -> Result<BackendTask, BackendError> {
+        let labels = LabelRepository::get_for_task(self.conn().await?, &model.uuid)
+            .await
+            .map_err(store_error)?
+            .into_iter()
+            .map(|label| label.name)
+            .collect();
+
+        Ok(BackendTask {
+            remote_id: model.remote_id,
+            content: model.content,
+            description: model.description,
+            project_remote_id: model.project_uuid.to_string(),
+            section_remote_id: model.section_uuid.map(|uuid| uuid.to_string()),
+            parent_remote_id: model.parent_uuid.map(|uuid| uuid.to_string()),
+            priority: model.priority,
+            order_index: model.order_index,
+            due_date: model.due_date,
+            due_datetime: model.due_datetime,
+            due_string: model.due_string,
+            is_recurring: model.is_recurring,
+            deadline: model.deadline,
+            duration: model.duration,
+            is_completed: model.is_completed,
+            labels,
+        })
+    }
+
+    /// Projects nested under a project, at any depth
+    async fn descendant_projects(&self, project_uuid: Uuid) -> Result<Vec<project::Model>, BackendError> {
+        let all = ProjectRepository::get_all(self.conn().await?).await.map_err(store_error)?;
+        let mut descendants = Vec::new();
+        let mut parents = vec![project_uuid];
+        while let Some(parent) = parents.pop() {
+            for child in all.iter().filter(|p| p.parent_uuid == Some(parent)) {
+                parents.push(child.uuid);
+                descendants.push(child.clone());
+            }
+        }
+        Ok(descendants)
+    }
+}
+
+fn project_to_backend(model: project::Model) -> BackendProject {
+    BackendProject {
+        remote_id: model.remote_id,
+        name: model.name,
+        color: model.color,
+        is_favorite: model.is_favorite,
+        is_inbox: model.is_inbox_project,
+        order_index: model.order_index,
+        parent_remote_id: model.parent_uuid.map(|uuid| uuid.to_string()),
+    }
+}
+
+fn label_to_backend(model: label::Model) -> BackendLabel {
+    BackendLabel {
+        remote_id: model.remote_id,
+        name: model.name,
+        color: model.color,
+        order_index: model.order_index,
+        is_favorite: model.is_favorite,
+    }
+}
+
+#[async_trait]
+impl Backend for LocalBackend {
+    fn backend_type(&self) -> &str {
+        "local"
+    }
+
+    /// There are no credentials to replace
+    fn set_credentials(&self, _credentials: &str) -> Result<(), BackendError> {
+        Ok(())
+    }
+
+    async fn fetch_projects(&self) -> Result<Vec<BackendProject>, BackendError> {
+        let projects = ProjectRepository::get_all(self.conn().await?).await.map_err(store_error)?;
+        Ok(projects.into_iter().map(project_to_backend).collect())
+    }
+
+    /// Only open tasks are returned, like the Todoist backend
+    async fn fetch_tasks(&self) -> Result<Vec<BackendTask>, BackendError> {
+        let models = task::Entity::find()
+            .filter(task::Column::IsCompleted.eq(false))
+            .filter(task::Column::IsDeleted.eq(false))
+            .order_by_asc(task::Column::OrderIndex)
+            .all(self.conn().await?)
+            .await
+            .map_err(store_error)?;
+
+        let mut tasks = Vec::with_capacity(models.len());
+        for model in models {
+            tasks.push(self.task_to_backend(model).await?);
+        }
+        Ok(tasks)
+    }
+
+    async fn fetch_task(&self, remote_id: &str) -> Result<Option<BackendTask>, BackendError> {
+        let model = TaskRepository::get_by_id(self.conn().await?, &parse_id(remote_id)?)
+            .await
+            .map_err(store_error)?;
+        match model.filter(|model| !model.is_completed && !model.is_deleted) {
+            Some(model) => Ok(Some(self.task_to_backend(model).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn fetch_labels(&self) -> Result<Vec<BackendLabel>, BackendError> {
+        let labels = LabelRepository::get_all(self.conn().await?).await.map_err(store_error)?;
+        Ok(labels.into_iter().map(label_to_backend).collect())
+    }
+
+    async fn fetch_sections(&self) -> Result<Vec<BackendSection>, BackendError> {
+        let sections = section::Entity::find()
+            .order_by_asc(section::Column::OrderIndex)
+            .all(self.conn().await?)
+            .await
+            .map_err(store_error)?;
+        Ok(sections
+            .into_iter()
+            .map(|model| BackendSection {
+                remote_id: model.remote_id,
+                name: model.name,
+                project_remote_id: model.project_uuid.to_string(),
+                order_index: model.order_index,
+            })
+            .collect())
+    }
+
+    async fn create_project(&self, args: CreateProjectArgs) -> Result<BackendProject, BackendError> {
+        let parent_uuid = match &args.parent_remote_id {
+            Some(parent) => Some(self.get_project(parent).await?.uuid),
+            None => None,
+        };
+        let store = self.store().await?;
+        let order_index = project::Entity::find().count(&store.storage.conn).await.map_err(store_error)? as i32;
+
+        let uuid = Uuid::new_v4();
+        let model = project::ActiveModel {
+            uuid: ActiveValue::Set(uuid),
+            backend_uuid: ActiveValue::Set(store.backend_uuid),
+            remote_id: ActiveValue::Set(uuid.to_string()),
+            name: ActiveValue::Set(args.name),
+            color: ActiveValue::Set(args.color.unwrap_or_else(|| DEFAULT_COLOR.to_string())),
+            is_favorite: ActiveValue::Set(args.is_favorite.unwrap_or(false)),
+            is_inbox_project: ActiveValue::Set(false),
+            order_index: ActiveValue::Set(order_index),
+            parent_uuid: ActiveValue::Set(parent_uuid),
+        }
+        .insert(&store.storage.conn)
+        .await
+        .map_err(store_error)?;
+
+        Ok(project_to_backend(model))
+    }
+
+    async fn update_project(&self, remote_id: &str, args: UpdateProjectArgs) -> Result<BackendProject, BackendError> {
+        let mut model = self.get_project(remote_id).await?.into_active_model();
+        if let Some(name) = args.name {
+            model.name = ActiveValue::Set(name);
+        }
+        if let Some(is_favorite) = args.is_favorite {
+            model.is_favorite = ActiveValue::Set(is_favorite);
+        }
+        if let Some(color) = args.color {
+            model.color = ActiveValue::Set(color);
+        }
+
+        let model = ProjectRepository::update(self.conn().await?, model)
+            .await
+            .map_err(store_error)?;
+        Ok(project_to_backend(model))
+    }
+
+    /// Deletes the project's subprojects too; their sections and tasks go with them
+    async fn delete_project(&self, remote_id: &str) -> Result<(), BackendError> {
+        let project = self.get_project(remote_id).await?;
+        if project.is_inbox_project {
+            return Err(BackendError::Other("The inbox project cannot be deleted".to_string()));
+        }
+        let descendants = self.descendant_projects(project.uuid).await?;
+
+        let txn = self.conn().await?.begin().await.map_err(store_error)?;
+        // Deepest first, so no project outlives its parent
+        for model in descendants.into_iter().rev().chain(std::iter::once(project)) {
+            ProjectRepository::delete(&txn, model).await.map_err(store_error)?;
+        }
+        txn.commit().await.map_err(store_error)
+    }
+
+    async fn create_task(&self, args: CreateTaskArgs) -> Result<BackendTask, BackendError> {
+        let project = if args.project_remote_id.is_empty() {
+            self.inbox().await?
+        } else {
+            self.get_project(&args.project_remote_id).await?
+        };
+        let section_uuid = match &args.section_remote_id {
+            Some(section) => Some(parse_id(section)?),
+            None => None,
+        };
+        let parent_uuid = match &args.parent_remote_id {
+            Some(parent) => Some(self.get_task(parent).await?.uuid),
+            None => None,
+        };
+        let store = self.store().await?;
+        let order_index = task::Entity::find()
+            .filter(task::Column::ProjectUuid.eq(project.uuid))
+            .count(&store.storage.conn)
+            .await
+            .map_err(store_error)? as i32;
+
+        // A datetime implies its date, which is what date-based views filter on
+        let due_date = datetime::local_due_date(args.due_date.as_deref(), args.due_datetime.as_deref());
+
+        let uuid = Uuid::new_v4();
+        let model = task::ActiveModel {
+            uuid: ActiveValue::Set(uuid),
+            backend_uuid: ActiveValue::Set(store.backend_uuid),
+            remote_id: ActiveValue::Set(uuid.to_string()),
+            content: ActiveValue::Set(args.content),
+            description: ActiveValue::Set(args.description),
+            project_uuid: ActiveValue::Set(project.uuid),
+            section_uuid: ActiveValue::Set(section_uuid),
+            parent_uuid: ActiveValue::Set(parent_uuid),
+            priority: ActiveValue::Set(args.priority.unwrap_or(1)),
+            order_index: ActiveValue::Set(order_index),
+            due_date: ActiveValue::Set(due_date),
+            due_datetime: ActiveValue::Set(args.due_datetime),
+            due_string: ActiveValue::Set(None),
+            is_recurring: ActiveValue::Set(false),
+            deadline: ActiveValue::Set(None),
+            duration: ActiveValue::Set(args.duration),
+            is_completed: ActiveValue::Set(false),
+            is_deleted: ActiveValue::Set(false),
+            postpone_count: ActiveValue::Set(0),
+        }
+        .insert(&store.storage.conn)
+        .await
+        .map_err(store_error)?;
+
+        self.set_task_labels(&model.uuid, &args.labels).await?;
+        self.task_to_backend(model).await
+    }
+
+    async fn update_task(&self, remote_id: &str, args: UpdateTaskArgs) -> Result<BackendTask, BackendError> {
+        let current = self.get_task(remote_id).await?;
+        let task_uuid = current.uuid;
+        let mut model = current.into_active_model();
+
+        if let Some(content) = args.content {
+            model.content = ActiveValue::Set(content);
+        }
+        if let Some(description) = args.description {
+            model.description = ActiveValue::Set(Some(description).filter(|d| !d.is_empty()));
+        }
+        if let Some(project) = &args.project_remote_id {
+            model.project_uuid = ActiveValue::Set(self.get_project(project).await?.uuid);
+            // Sections belong to a project, so moving drops the section unless one is given
+            model.section_uuid = ActiveValue::Set(None);
+        }
+        if let Some(section) = &args.section_remote_id {
+            model.section_uuid = ActiveValue::Set(Some(parse_id(section)?));
+        }
+        if let Some(parent) = &args.parent_remote_id {
+            model.parent_uuid = ActiveValue::Set(Some(self.get_task(parent).await?.uuid));
+        }
+        if let Some(priority) = args.priority {
+            model.priority = ActiveValue::Set(priority);
+        }
+        if args.due_date.is_some() || args.due_datetime.is_some() {
+            // A new due date replaces the time as well; an empty date clears both
+            let due_date = datetime::local_due_date(args.due_date.as_deref(), args.due_datetime.as_deref())
+                .filter(|d| !d.is_empty());
+            model.due_datetime = ActiveValue::Set(args.due_datetime.filter(|_| due_date.is_some()));
+            model.due_date = ActiveValue::Set(due_date);
+        }
+        if let Some(deadline) = args.deadline {
+            model.deadline = ActiveValue::Set(Some(deadline).filter(|d| !d.is_empty()));
+        }
+        if let Some(duration) = args.duration {
+            model.duration = ActiveValue::Set(Some(duration).filter(|d| !d.is_empty()));
+        }
+
+        let model = TaskRepository::update(self.conn().await?, model).await.map_err(store_error)?;
+        if let Some(labels) = &args.labels {
+            self.set_task_labels(&task_uuid, labels).await?;
+        }
+        self.task_to_backend(model).await
+    }
+
+    /// Subtasks are deleted with their parent
+    async fn delete_task(&self, remote_id: &str) -> Result<(), BackendError> {
+        let model = self.get_task(remote_id).await?;
+        TaskRepository::delete(self.conn().await?, model).await.map_err(store_error)
+    }
+
+    /// Recurring tasks are completed outright; the store keeps no recurrence rule to advance
+    async fn complete_task(&self, remote_id: &str) -> Result<(), BackendError> {
+        let mut model = self.get_task(remote_id).await?.into_active_model();
+        model.is_completed = ActiveValue::Set(true);
+        TaskRepository::update(self.conn().await?, model).await.map_err(store_error)?;
+        Ok(())
+    }
+
+    async fn reopen_task(&self, remote_id: &str) -> Result<(), BackendError> {
+        let mut model = self.get_task(remote_id).await?.into_active_model();
+        model.is_completed = ActiveValue::Set(false);
+        TaskRepository::update(self.conn().await?, model).await.map_err(store_error)?;
+        Ok(())
+    }
+
+    async fn reorder_tasks(&self, orders: &[(String, i32)]) -> Result<(), BackendError> {
+        let txn = self.conn().await?.begin().await.map_err(store_error)?;
+        for (remote_id, order) in orders {
+            let model = TaskRepository::get_by_id(&txn, &parse_id(remote_id)?)
+                .await
+                .map_err(store_error)?
+                .ok_or_else(|| BackendError::NotFound(format!("Task {}", remote_id)))?;
+            let mut model = model.into_active_model();
+            model.order_index = ActiveValue::Set(*order);
+            TaskRepository::update(&txn, model).await.map_err(store_error)?;
+        }
+        txn.commit().await.map_err(store_error)
+    }
+
+    async fn create_label(&self, args: CreateLabelArgs) -> Result<BackendLabel, BackendError> {
+        let store = self.store().await?;
+        let order_index = label::Entity::find().count(&store.storage.conn).await.map_err(store_error)? as i32;
+
+        let uuid = Uuid::new_v4();
+        let model = label::ActiveModel {
+            uuid: ActiveValue::Set(uuid),
+            backend_uuid: ActiveValue::Set(store.backend_uuid),
+            remote_id: ActiveValue::Set(uuid.to_string()),
+            name: ActiveValue::Set(args.name),
+            color: ActiveValue::Set(DEFAULT_COLOR.to_string()),
+            order_index: ActiveValue::Set(order_index),
+            is_favorite: ActiveValue::Set(args.is_favorite.unwrap_or(false)),
+        }
+        .insert(&store.storage.conn)
+        .await
+        .map_err(store_error)?;
+
+        Ok(label_to_backend(model))
+    }
+
+    async fn update_label(&self, remote_id: &str, args: UpdateLabelArgs) -> Result<BackendLabel, BackendError> {
+        let mut model = self.get_label(remote_id).await?.into_active_model();
+        if let Some(name) = args.name {
+            model.name = ActiveValue::Set(name);
+        }
+        if let Some(color) = args.color {
+            model.color = ActiveValue::Set(color);
+        }
+        if let Some(is_favorite) = args.is_favorite {
+            model.is_favorite = ActiveValue::Set(is_favorite);
+        }
+
+        let model = LabelRepository::update(self.conn().await?, model).await.map_err(store_error)?;
+        Ok(label_to_backend(model))
+    }
+
+    async fn delete_label(&self, remote_id: &str) -> Result<(), BackendError> {
+        label::Entity::delete_by_id(parse_id(remote_id)?)
+            .exec(self.conn().await?)
+            .await
+            .map_err(store_error)?;
+        Ok(())
+    }
+}
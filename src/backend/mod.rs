@@ -6,7 +6,11 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
+pub mod caldav;
 pub mod factory;
+pub mod local;
+pub mod rate_limit;
+pub mod retry;
 pub mod todoist;
 
 /// Common error types for backend operations.
@@ -21,6 +25,14 @@ pub enum BackendError {
     #[error("Network error: {0}")]
     Network(String),
 
+    /// The server refused the request because too many were sent, possibly saying when
+    /// to try again (the `Retry-After` header)
+    #[error("Rate limited: {message}")]
+    RateLimited {
+        message: String,
+        retry_after: Option<std::time::Duration>,
+    },
+
     #[error("Invalid data: {0}")]
     InvalidData(String),
 
@@ -28,11 +40,52 @@ pub enum BackendError {
     Other(String),
 }
 
+impl BackendError {
+    /// Whether the backend rejected the request's credentials (e.g. an expired or revoked token).
+    pub fn is_auth(&self) -> bool {
+        matches!(self, BackendError::Auth(_))
+    }
+
+    /// Whether the same request may succeed if sent again (a dropped connection, a
+    /// timeout, an overloaded server or a rate limit); see [`retry::RetryPolicy`].
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, BackendError::Network(_) | BackendError::RateLimited { .. })
+    }
+}
+
+/// Whether an error chain contains a [`BackendError::Auth`].
+///
+/// Sync service methods propagate backend errors with `?`, so callers holding an
+/// `anyhow::Error` can use this to tell authentication failures apart.
+pub fn is_auth_error(error: &anyhow::Error) -> bool {
+    error
+        .chain()
+        .any(|cause| cause.downcast_ref::<BackendError>().is_some_and(BackendError::is_auth))
+}
+
+/// Connection check of a backend, shown in the backend status dialog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackendStatus {
+    /// Name the backend was registered with
+    pub name: String,
+    /// Backend type identifier (e.g. "todoist")
+    pub backend_type: String,
+    /// Whether the server answered; `false` after a network error
+    pub connected: bool,
+    /// Whether the credentials were accepted; `false` only when the server rejected them
+    pub authenticated: bool,
+    /// End of the last successful sync, as recorded in local storage
+    pub last_sync: Option<chrono::DateTime<chrono::Utc>>,
+    /// Why the check failed, if it did
+    pub sync_error: Option<String>,
+}
+
 /// Backend-agnostic project representation.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BackendProject {
     pub remote_id: String,
     pub name: String,
+    pub color: String,
     pub is_favorite: bool,
     pub is_inbox: bool,
     pub order_index: i32,
@@ -52,6 +105,8 @@ pub struct BackendTask {
     pub order_index: i32,
     pub due_date: Option<String>,
     pub due_datetime: Option<String>,
+    /// The due date as the user wrote it; for recurring tasks, the recurrence ("every monday")
+    pub due_string: Option<String>,
     pub is_recurring: bool,
     pub deadline: Option<String>,
     pub duration: Option<String>,
@@ -64,6 +119,7 @@ pub struct BackendTask {
 pub struct BackendLabel {
     pub remote_id: String,
     pub name: String,
+    pub color: String,
     pub order_index: i32,
     pub is_favorite: bool,
 }
@@ -77,12 +133,34 @@ pub struct BackendSection {
     pub order_index: i32,
 }
 
+/// Data changed since a previous sync, returned by [`Backend::fetch_changes`].
+#[derive(Clone, Debug, Default)]
+pub struct BackendChanges {
+    /// Token to pass to the next call to get the changes after this one
+    pub sync_token: String,
+    /// Whether this is a complete snapshot rather than a delta (first sync, or the
+    /// backend did not accept the token); records missing from it no longer exist
+    pub full_sync: bool,
+    /// Added or changed records
+    pub projects: Vec<BackendProject>,
+    pub tasks: Vec<BackendTask>,
+    pub labels: Vec<BackendLabel>,
+    pub sections: Vec<BackendSection>,
+    /// Remote IDs of records deleted (or archived, or completed for tasks) since the token
+    pub removed_project_ids: Vec<String>,
+    pub removed_task_ids: Vec<String>,
+    pub removed_label_ids: Vec<String>,
+    pub removed_section_ids: Vec<String>,
+}
+
 /// Arguments for creating a new project.
 #[derive(Clone, Debug)]
 pub struct CreateProjectArgs {
     pub name: String,
     pub is_favorite: Option<bool>,
     pub parent_remote_id: Option<String>,
+    /// Todoist color name (e.g. "berry_red"); `None` leaves the backend default
+    pub color: Option<String>,
 }
 
 /// Arguments for creating a new task.
@@ -90,6 +168,7 @@ pub struct CreateProjectArgs {
 pub struct CreateTaskArgs {
     pub content: String,
     pub description: Option<String>,
+    /// Empty to file the task in the backend's inbox
     pub project_remote_id: String,
     pub section_remote_id: Option<String>,
     pub parent_remote_id: Option<String>,
@@ -112,10 +191,14 @@ pub struct CreateLabelArgs {
 pub struct UpdateProjectArgs {
     pub name: Option<String>,
     pub is_favorite: Option<bool>,
+    /// Todoist color name (e.g. "berry_red")
+    pub color: Option<String>,
 }
 
 /// Arguments for updating a task.
-#[derive(Clone, Debug)]
+///
+/// Fields left as `None` are not changed.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct UpdateTaskArgs {
     pub content: Option<String>,
     pub description: Option<String>,
@@ -125,14 +208,34 @@ pub struct UpdateTaskArgs {
     pub priority: Option<i32>,
     pub due_date: Option<String>,
     pub due_datetime: Option<String>,
+    /// New deadline (YYYY-MM-DD); `Some("")` removes the deadline
+    pub deadline: Option<String>,
     pub duration: Option<String>,
     pub labels: Option<Vec<String>>,
 }
 
+impl UpdateTaskArgs {
+    /// Whether no field would be changed
+    pub fn is_empty(&self) -> bool {
+        self.content.is_none()
+            && self.description.is_none()
+            && self.project_remote_id.is_none()
+            && self.section_remote_id.is_none()
+            && self.parent_remote_id.is_none()
+            && self.priority.is_none()
+            && self.due_date.is_none()
+            && self.due_datetime.is_none()
+            && self.deadline.is_none()
+            && self.duration.is_none()
+            && self.labels.is_none()
+    }
+}
+
 /// Arguments for updating a label.
 #[derive(Clone, Debug)]
 pub struct UpdateLabelArgs {
     pub name: Option<String>,
+    pub color: Option<String>,
     pub is_favorite: Option<bool>,
 }
 
@@ -145,12 +248,60 @@ pub trait Backend: Send + Sync {
     /// Returns the backend type identifier (e.g., "todoist", "ticktick").
     fn backend_type(&self) -> &str;
 
+    /// Replaces the credentials used for subsequent requests.
+    ///
+    /// Used to recover from [`BackendError::Auth`] (e.g. a rotated token) without
+    /// recreating the backend. `credentials` uses the same JSON format as the factory.
+    fn set_credentials(&self, credentials: &str) -> Result<(), BackendError>;
+
+    /// Sets how network calls are retried after a transient error.
+    ///
+    /// Calls that create something must not be retried: the request may have gone
+    /// through with only the response lost, and a retry would create a duplicate.
+    /// Backends that don't talk to a server ignore it.
+    fn set_retry_policy(&self, _policy: retry::RetryPolicy) {}
+
+    /// Whether tasks created without a project land in a backend-managed inbox.
+    ///
+    /// Backends without one need a configured default project for quick capture.
+    fn has_inbox(&self) -> bool {
+        true
+    }
+
+    /// Checks that the backend can be reached with the current credentials.
+    ///
+    /// The user is waiting for the answer, so backends that retry their calls make this
+    /// one once. The default fetches the projects, the smallest request every backend
+    /// supports.
+    async fn test_connection(&self) -> Result<(), BackendError> {
+        self.fetch_projects().await.map(|_| ())
+    }
+
+    /// Fetches the data changed since `sync_token`, or all of it when the token is `None`.
+    ///
+    /// Backends without incremental sync return `Ok(None)` and are synced with the
+    /// `fetch_*` methods below instead.
+    async fn fetch_changes(&self, _sync_token: Option<&str>) -> Result<Option<BackendChanges>, BackendError> {
+        Ok(None)
+    }
+
     // Sync operations - fetch all data
     async fn fetch_projects(&self) -> Result<Vec<BackendProject>, BackendError>;
     async fn fetch_tasks(&self) -> Result<Vec<BackendTask>, BackendError>;
     async fn fetch_labels(&self) -> Result<Vec<BackendLabel>, BackendError>;
     async fn fetch_sections(&self) -> Result<Vec<BackendSection>, BackendError>;
 
+    /// Fetches a single open task, or `None` if the backend no longer lists it as open.
+    ///
+    /// The default looks it up among all tasks; backends with a cheaper way override it.
+    async fn fetch_task(&self, remote_id: &str) -> Result<Option<BackendTask>, BackendError> {
+        Ok(self
+            .fetch_tasks()
+            .await?
+            .into_iter()
+            .find(|task| task.remote_id == remote_id && !task.is_completed))
+    }
+
     // CRUD operations for projects
     async fn create_project(&self, args: CreateProjectArgs) -> Result<BackendProject, BackendError>;
     async fn update_project(&self, remote_id: &str, args: UpdateProjectArgs) -> Result<BackendProject, BackendError>;
@@ -163,6 +314,14 @@ pub trait Backend: Send + Sync {
     async fn complete_task(&self, remote_id: &str) -> Result<(), BackendError>;
     async fn reopen_task(&self, remote_id: &str) -> Result<(), BackendError>;
 
+    /// Sets the manual order of sibling tasks, as (remote ID, position) pairs.
+    ///
+    /// Backends without a manual order keep this default, which changes nothing there;
+    /// the new order then only lasts until the next sync.
+    async fn reorder_tasks(&self, _orders: &[(String, i32)]) -> Result<(), BackendError> {
+        Ok(())
+    }
+
     // CRUD operations for labels
     async fn create_label(&self, args: CreateLabelArgs) -> Result<BackendLabel, BackendError>;
     async fn update_label(&self, remote_id: &str, args: UpdateLabelArgs) -> Result<BackendLabel, BackendError>;
@@ -0,0 +1,81 @@
+//! Client-side rate limiting for backends with request quotas.
+//!
+//! A token bucket lets short bursts through and then spaces requests out to the
+//! sustained rate, so a large sync or a batch of changes doesn't run into the server's
+//! limit (HTTP 429) in the first place. When the server asks to slow down anyway, the
+//! bucket can be paused until it allows requests again.
+
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Token bucket shared by all requests of a backend
+pub struct RateLimiter {
+    /// Requests that may be sent back to back
+    capacity: f64,
+    /// Tokens added per second
+    refill_per_sec: f64,
+    state: Mutex<BucketState>,
+}
+
+struct BucketState {
+    tokens: f64,
+    refilled_at: Instant,
+    /// No request is let through before this, after the server asked to wait
+    paused_until: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// Allow bursts of `capacity` requests and `requests` per `period` in the long run
+    pub fn new(capacity: u32, requests: u32, period: Duration) -> Self {
+        Self {
+            capacity: f64::from(capacity),
+            refill_per_sec: f64::from(requests) / period.as_secs_f64(),
+            state: Mutex::new(BucketState {
+                tokens: f64::from(capacity),
+                refilled_at: Instant::now(),
+                paused_until: None,
+            }),
+        }
+    }
+
+    /// Wait until a request may be sent, and count it
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().await;
+                let now = Instant::now();
+                match state.paused_until {
+                    Some(until) if until > now => until - now,
+                    _ => {
+                        state.paused_until = None;
+                        let elapsed = now.duration_since(state.refilled_at).as_secs_f64();
+                        state.tokens = (state.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+                        state.refilled_at = now;
+                        if state.tokens >= 1.0 {
+                            state.tokens -= 1.0;
+                            return;
+                        }
+                        Duration::from_secs_f64((1.0 - state.tokens) / self.refill_per_sec)
+                    }
+                }
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Hold back all requests for `duration`, e.g. after a 429 with a `Retry-After`
+    pub async fn pause_for(&self, duration: Duration) {
+        let mut state = self.state.lock().await;
+        let until = Instant::now() + duration;
+        state.paused_until = Some(state.paused_until.map_or(until, |current| current.max(until)));
+        state.tokens = 0.0;
+    }
+}
+
+/// Parse a `Retry-After` header value given in seconds
+///
+/// The HTTP-date form isn't used by the APIs this talks to and gives `None`, like a
+/// missing header, so the caller falls back to its own backoff.
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
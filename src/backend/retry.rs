@@ -0,0 +1,102 @@
+//! Retries for backend calls that fail on transient network errors.
+//!
+//! Only [`BackendError::Network`] and [`BackendError::RateLimited`] are retried:
+//! authentication failures, missing resources and invalid data fail the same way on every
+//! attempt, so they are returned at once. A rate-limited request waits as long as the
+//! server asked to, if it did.
+
+use super::BackendError;
+use std::future::Future;
+use std::time::Duration;
+
+/// Longest wait between two attempts, whatever the base delay and attempt number
+const MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Longest `Retry-After` waited for; a rate limit lasting longer is reported instead
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(120);
+
+/// How often and how patiently a failed backend call is retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts after the first one (0 = no retries)
+    pub max_retries: u32,
+    /// Wait before the first retry, doubled for each following one
+    pub base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay: Duration::ZERO,
+        }
+    }
+
+    /// Wait before retry number `retry` (starting at 0), without jitter:
+    /// `base_delay * 2^retry`, capped at 30 seconds
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.base_delay
+            .checked_mul(2u32.saturating_pow(retry))
+            .map_or(MAX_DELAY, |delay| delay.min(MAX_DELAY))
+    }
+
+    /// [`backoff`](Self::backoff) plus a random part of up to half of it, so clients that
+    /// failed together don't all retry at the same moment
+    fn delay(&self, retry: u32) -> Duration {
+        let backoff = self.backoff(retry);
+        let jitter_ms = backoff.as_millis() as u64 / 2;
+        if jitter_ms == 0 {
+            return backoff;
+        }
+        // Random bits from a v4 UUID, to avoid a dependency for this alone
+        let random = uuid::Uuid::new_v4().as_u128() as u64;
+        backoff + Duration::from_millis(random % (jitter_ms + 1))
+    }
+
+    /// Run `call` until it succeeds, fails with an error that isn't retryable, or runs
+    /// out of retries; the last error is returned
+    ///
+    /// `operation` names the call in the log (e.g. "fetch tasks").
+    pub async fn run<T, F, Fut>(&self, operation: &str, mut call: F) -> Result<T, BackendError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, BackendError>>,
+    {
+        let mut retry = 0;
+        loop {
+            match call().await {
+                Err(error) if error.is_retryable() && retry < self.max_retries => {
+                    let retry_after = match &error {
+                        BackendError::RateLimited { retry_after, .. } => *retry_after,
+                        _ => None,
+                    };
+                    if retry_after.is_some_and(|wait| wait > MAX_RETRY_AFTER) {
+                        return Err(error);
+                    }
+                    let delay = retry_after.unwrap_or_else(|| self.delay(retry));
+                    retry += 1;
+                    log::warn!(
+                        "Failed to {} ({}), retrying in {} ms ({}/{})",
+                        operation,
+                        error,
+                        delay.as_millis(),
+                        retry,
+                        self.max_retries
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
@@ -1,22 +1,401 @@
 //! Todoist backend implementation.
 
+use super::rate_limit::{parse_retry_after, RateLimiter};
+use super::retry::RetryPolicy;
 use super::{
-    Backend, BackendError, BackendLabel, BackendProject, BackendSection, BackendTask, CreateLabelArgs,
+    Backend, BackendChanges, BackendError, BackendLabel, BackendProject, BackendSection, BackendTask, CreateLabelArgs,
     CreateProjectArgs, CreateTaskArgs, UpdateLabelArgs, UpdateProjectArgs, UpdateTaskArgs,
 };
-use crate::todoist::TodoistWrapper;
+use crate::todoist::{TodoistError, TodoistWrapper};
 use async_trait::async_trait;
+use serde::Deserialize;
+use std::future::Future;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+/// Todoist Sync API endpoint, used for incremental syncs
+const SYNC_API_URL: &str = "https://api.todoist.com/api/v1/sync";
+
+/// Resources requested from the Sync API
+const SYNC_RESOURCE_TYPES: &str = r#"["projects","items","labels","sections"]"#;
+
+/// Requests sent back to back before the rate limiter spaces them out
+const RATE_LIMIT_BURST: u32 = 50;
+
+/// Todoist allows about 1000 requests per user every 15 minutes
+const RATE_LIMIT_REQUESTS: u32 = 1000;
+const RATE_LIMIT_PERIOD: Duration = Duration::from_secs(15 * 60);
 
 /// Todoist backend implementation.
 pub struct TodoistBackend {
-    wrapper: TodoistWrapper,
+    // Behind a lock so the token can be replaced after a 401 without recreating the backend
+    wrapper: RwLock<Arc<TodoistWrapper>>,
+    // The Sync API is called directly, so the token is kept as well
+    api_token: RwLock<String>,
+    http: reqwest::Client,
+    retry_policy: RwLock<RetryPolicy>,
+    // Shared by the REST and Sync API calls, which count against the same quota
+    rate_limiter: RateLimiter,
+}
+
+/// Response of the Sync API (only the fields used here)
+#[derive(Debug, Deserialize)]
+pub struct SyncResponse {
+    pub sync_token: String,
+    #[serde(default)]
+    pub full_sync: bool,
+    #[serde(default)]
+    pub projects: Vec<SyncProject>,
+    #[serde(default)]
+    pub items: Vec<SyncItem>,
+    #[serde(default)]
+    pub labels: Vec<SyncLabel>,
+    #[serde(default)]
+    pub sections: Vec<SyncSection>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SyncProject {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub color: String,
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub child_order: i32,
+    #[serde(default)]
+    pub is_favorite: bool,
+    #[serde(default)]
+    pub inbox_project: bool,
+    #[serde(default)]
+    pub is_deleted: bool,
+    #[serde(default)]
+    pub is_archived: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SyncItem {
+    pub id: String,
+    pub content: String,
+    #[serde(default)]
+    pub description: String,
+    pub project_id: String,
+    pub section_id: Option<String>,
+    pub parent_id: Option<String>,
+    #[serde(default = "default_priority")]
+    pub priority: i32,
+    #[serde(default)]
+    pub child_order: i32,
+    pub due: Option<SyncDue>,
+    pub duration: Option<SyncDuration>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub checked: bool,
+    #[serde(default)]
+    pub is_deleted: bool,
+}
+
+fn default_priority() -> i32 {
+    1
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SyncDue {
+    /// "YYYY-MM-DD", or a datetime ("YYYY-MM-DDTHH:MM:SS", with a trailing "Z" when fixed to UTC)
+    pub date: String,
+    /// The due date as the user wrote it ("every monday")
+    #[serde(default)]
+    pub string: Option<String>,
+    #[serde(default)]
+    pub is_recurring: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SyncDuration {
+    pub amount: i32,
+    pub unit: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SyncLabel {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub color: String,
+    #[serde(default)]
+    pub item_order: i32,
+    #[serde(default)]
+    pub is_favorite: bool,
+    #[serde(default)]
+    pub is_deleted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SyncSection {
+    pub id: String,
+    pub name: String,
+    pub project_id: String,
+    #[serde(default)]
+    pub section_order: i32,
+    #[serde(default)]
+    pub is_deleted: bool,
+    #[serde(default)]
+    pub is_archived: bool,
+}
+
+impl SyncResponse {
+    /// Convert to backend changes; deleted, archived and completed records become removals
+    pub fn into_changes(self) -> BackendChanges {
+        let mut changes = BackendChanges {
+            sync_token: self.sync_token,
+            full_sync: self.full_sync,
+            ..BackendChanges::default()
+        };
+
+        for project in self.projects {
+            if project.is_deleted || project.is_archived {
+                changes.removed_project_ids.push(project.id);
+            } else {
+                changes.projects.push(BackendProject {
+                    remote_id: project.id,
+                    name: project.name,
+                    color: project.color,
+                    is_favorite: project.is_favorite,
+                    is_inbox: project.inbox_project,
+                    order_index: project.child_order,
+                    parent_remote_id: project.parent_id,
+                });
+            }
+        }
+
+        for item in self.items {
+            if item.is_deleted || item.checked {
+                changes.removed_task_ids.push(item.id);
+                continue;
+            }
+            // The Sync API has a single date field holding either a date or a datetime
+            let (due_date, due_datetime) = match &item.due {
+                Some(due) if due.date.len() > 10 => (Some(due.date[..10].to_string()), Some(due.date.clone())),
+                Some(due) => (Some(due.date.clone()), None),
+                None => (None, None),
+            };
+            changes.tasks.push(BackendTask {
+                remote_id: item.id,
+                content: item.content,
+                description: Some(item.description),
+                project_remote_id: item.project_id,
+                section_remote_id: item.section_id,
+                parent_remote_id: item.parent_id,
+                priority: item.priority,
+                order_index: item.child_order,
+                due_date,
+                due_datetime,
+                due_string: item.due.as_ref().and_then(|d| d.string.clone()),
+                is_recurring: item.due.as_ref().is_some_and(|d| d.is_recurring),
+                deadline: None, // Not mapped, like the REST fetch
+                duration: item.duration.map(|d| format!("{} {}", d.amount, d.unit)),
+                is_completed: false,
+                labels: item.labels,
+            });
+        }
+
+        for label in self.labels {
+            if label.is_deleted {
+                changes.removed_label_ids.push(label.id);
+            } else {
+                changes.labels.push(BackendLabel {
+                    remote_id: label.id,
+                    name: label.name,
+                    color: label.color,
+                    order_index: label.item_order,
+                    is_favorite: label.is_favorite,
+                });
+            }
+        }
+
+        for section in self.sections {
+            if section.is_deleted || section.is_archived {
+                changes.removed_section_ids.push(section.id);
+            } else {
+                changes.sections.push(BackendSection {
+                    remote_id: section.id,
+                    name: section.name,
+                    project_remote_id: section.project_id,
+                    order_index: section.section_order,
+                });
+            }
+        }
+
+        changes
+    }
+}
+
+/// Map a Todoist API error to a backend error, so that only transient failures are retried.
+fn api_error(error: TodoistError) -> BackendError {
+    let message = error.to_string();
+    if error.is_authentication_error() {
+        return BackendError::Auth(message);
+    }
+    match error {
+        TodoistError::RateLimited { retry_after, .. } => BackendError::RateLimited {
+            message,
+            retry_after: retry_after.map(Duration::from_secs),
+        },
+        TodoistError::NetworkError { .. } | TodoistError::ServerError { .. } => BackendError::Network(message),
+        TodoistError::ValidationError { .. } | TodoistError::ParseError { .. } | TodoistError::EmptyResponse { .. } => {
+            BackendError::InvalidData(message)
+        }
+        TodoistError::NotFound { .. } | TodoistError::AuthorizationError { .. } | TodoistError::Generic { .. } => {
+            BackendError::Other(message)
+        }
+        // Already returned as an authentication failure above
+        TodoistError::AuthenticationError { .. } => BackendError::Auth(message),
+    }
 }
 
 impl TodoistBackend {
     /// Create a new Todoist backend with the provided API token.
     pub fn new(api_token: String) -> Self {
         Self {
-            wrapper: TodoistWrapper::new(api_token),
+            wrapper: RwLock::new(Arc::new(TodoistWrapper::new(api_token.clone()))),
+            api_token: RwLock::new(api_token),
+            http: reqwest::Client::new(),
+            retry_policy: RwLock::new(RetryPolicy::default()),
+            rate_limiter: RateLimiter::new(RATE_LIMIT_BURST, RATE_LIMIT_REQUESTS, RATE_LIMIT_PERIOD),
+        }
+    }
+
+    fn wrapper(&self) -> Arc<TodoistWrapper> {
+        self.wrapper.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        *self.retry_policy.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // Helper: Call the REST API through the client, retrying transient failures.
+    // `operation` names the call in the log.
+    async fn call_api<T, F, Fut>(&self, operation: &str, call: F) -> Result<T, BackendError>
+    where
+        F: Fn(Arc<TodoistWrapper>) -> Fut,
+        Fut: Future<Output = Result<T, TodoistError>>,
+    {
+        self.call_api_with(self.retry_policy(), operation, call).await
+    }
+
+    // Helper: Call the REST API once. Creating something isn't idempotent: retrying a
+    // request whose response was lost would create it twice.
+    async fn call_api_once<T, F, Fut>(&self, operation: &str, call: F) -> Result<T, BackendError>
+    where
+        F: Fn(Arc<TodoistWrapper>) -> Fut,
+        Fut: Future<Output = Result<T, TodoistError>>,
+    {
+        self.call_api_with(RetryPolicy::none(), operation, call).await
+    }
+
+    async fn call_api_with<T, F, Fut>(&self, policy: RetryPolicy, operation: &str, call: F) -> Result<T, BackendError>
+    where
+        F: Fn(Arc<TodoistWrapper>) -> Fut,
+        Fut: Future<Output = Result<T, TodoistError>>,
+    {
+        let call = &call;
+        policy
+            .run(operation, || async move {
+                self.rate_limiter.acquire().await;
+                let error = match call(self.wrapper()).await {
+                    Ok(value) => return Ok(value),
+                    Err(error) => api_error(error),
+                };
+                if let BackendError::RateLimited {
+                    retry_after: Some(retry_after),
+                    ..
+                } = &error
+                {
+                    self.rate_limiter.pause_for(*retry_after).await;
+                }
+                Err(error)
+            })
+            .await
+    }
+
+    // Helper: POST a form to the Sync API and return the response body, retrying
+    // transient failures
+    async fn post_sync(&self, form: &[(&str, &str)]) -> Result<String, BackendError> {
+        self.retry_policy().run("call the Sync API", || self.post_sync_once(form)).await
+    }
+
+    async fn post_sync_once(&self, form: &[(&str, &str)]) -> Result<String, BackendError> {
+        self.rate_limiter.acquire().await;
+        let api_token = self.api_token.read().unwrap_or_else(PoisonError::into_inner).clone();
+        let response = self
+            .http
+            .post(SYNC_API_URL)
+            .bearer_auth(api_token)
+            .form(form)
+            .send()
+            .await
+            .map_err(|e| BackendError::Network(e.to_string()))?;
+        let status = response.status();
+        let seconds_header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_retry_after)
+        };
+        let retry_after = seconds_header("retry-after");
+        // Out of quota even though this request went through: wait for the reset, unless
+        // the header is a timestamp rather than a number of seconds
+        if response.headers().get("x-ratelimit-remaining").and_then(|v| v.to_str().ok()) == Some("0") {
+            if let Some(reset) = seconds_header("x-ratelimit-reset").filter(|reset| *reset <= RATE_LIMIT_PERIOD) {
+                self.rate_limiter.pause_for(reset).await;
+            }
+        }
+        let body = response.text().await.map_err(|e| BackendError::Network(e.to_string()))?;
+        match status.as_u16() {
+            200..=299 => Ok(body),
+            401 | 403 => Err(BackendError::Auth(format!("Sync API returned {}", status))),
+            429 => {
+                if let Some(retry_after) = retry_after {
+                    self.rate_limiter.pause_for(retry_after).await;
+                }
+                Err(BackendError::RateLimited {
+                    message: format!("Sync API returned {}", status),
+                    retry_after,
+                })
+            }
+            // Server errors usually pass
+            500..=599 => Err(BackendError::Network(format!("Sync API returned {}", status))),
+            _ => Err(BackendError::Other(format!("Sync API returned {}: {}", status, body))),
+        }
+    }
+
+    // Helper: Move a task to another project or section with the Sync API `item_move`
+    // command, since the REST task update can't change either. `destination` is
+    // "project_id" or "section_id".
+    async fn move_item(&self, remote_id: &str, destination: &str, destination_id: &str) -> Result<(), BackendError> {
+        let args = serde_json::json!({ "id": remote_id, destination: destination_id });
+        self.run_command("item_move", args, "move task").await
+    }
+
+    // Helper: Run a single Sync API command and check its status; `operation` names it in
+    // the error (e.g. "move task")
+    async fn run_command(&self, kind: &str, args: serde_json::Value, operation: &str) -> Result<(), BackendError> {
+        let command_uuid = uuid::Uuid::new_v4().to_string();
+        let commands = serde_json::json!([{
+            "type": kind,
+            "uuid": command_uuid,
+            "args": args,
+        }])
+        .to_string();
+        let body = self.post_sync(&[("commands", commands.as_str())]).await?;
+
+        let response: serde_json::Value = serde_json::from_str(&body)
+            .map_err(|e| BackendError::InvalidData(format!("Unexpected Sync API response: {}", e)))?;
+        match &response["sync_status"][&command_uuid] {
+            serde_json::Value::String(status) if status == "ok" => Ok(()),
+            error => Err(BackendError::Other(format!("Failed to {}: {}", operation, error))),
         }
     }
 
@@ -25,6 +404,7 @@ impl TodoistBackend {
         BackendProject {
             remote_id: api_project.id.clone(),
             name: api_project.name.clone(),
+            color: api_project.color.clone(),
             is_favorite: api_project.is_favorite,
             is_inbox: api_project.inbox_project,
             order_index: 0, // order field removed from API v1
@@ -45,6 +425,7 @@ impl TodoistBackend {
             order_index: 0, // order field removed from API v1
             due_date: api_task.due.as_ref().map(|d| d.date.clone()),
             due_datetime: api_task.due.as_ref().and_then(|d| d.datetime.clone()),
+            due_string: api_task.due.as_ref().map(|d| d.string.clone()),
             is_recurring: api_task.due.as_ref().map(|d| d.is_recurring).unwrap_or(false),
             deadline: None, // Todoist doesn't have deadline
             duration: api_task.duration.as_ref().map(|d| format!("{} {}", d.amount, d.unit)),
@@ -58,6 +439,7 @@ impl TodoistBackend {
         BackendLabel {
             remote_id: api_label.id.clone(),
             name: api_label.name.clone(),
+            color: api_label.color.clone(),
             order_index: api_label.order.unwrap_or(0),
             is_favorite: api_label.is_favorite,
         }
@@ -80,17 +462,58 @@ impl Backend for TodoistBackend {
         "todoist"
     }
 
+    fn set_credentials(&self, credentials: &str) -> Result<(), BackendError> {
+        let creds: serde_json::Value = serde_json::from_str(credentials)
+            .map_err(|e| BackendError::InvalidData(format!("Failed to parse credentials JSON: {}", e)))?;
+        let api_token = creds["api_token"]
+            .as_str()
+            .ok_or_else(|| BackendError::InvalidData("Missing 'api_token' in Todoist credentials".to_string()))?;
+
+        *self.wrapper.write().unwrap_or_else(PoisonError::into_inner) =
+            Arc::new(TodoistWrapper::new(api_token.to_string()));
+        *self.api_token.write().unwrap_or_else(PoisonError::into_inner) = api_token.to_string();
+        Ok(())
+    }
+
+    fn set_retry_policy(&self, policy: RetryPolicy) {
+        *self.retry_policy.write().unwrap_or_else(PoisonError::into_inner) = policy;
+    }
+
+    async fn test_connection(&self) -> Result<(), BackendError> {
+        // A single project is enough to know the token works
+        self.call_api_once(
+            "test connection",
+            |api| async move { api.get_projects(Some(1), None).await },
+        )
+        .await
+        .map(|_| ())
+    }
+
+    async fn fetch_changes(&self, sync_token: Option<&str>) -> Result<Option<BackendChanges>, BackendError> {
+        // "*" asks for a full sync
+        let form = [
+            ("sync_token", sync_token.unwrap_or("*")),
+            ("resource_types", SYNC_RESOURCE_TYPES),
+        ];
+        let body = self.post_sync(&form).await?;
+
+        let response: SyncResponse = serde_json::from_str(&body)
+            .map_err(|e| BackendError::InvalidData(format!("Unexpected Sync API response: {}", e)))?;
+        Ok(Some(response.into_changes()))
+    }
+
     async fn fetch_projects(&self) -> Result<Vec<BackendProject>, BackendError> {
         let mut all_projects = Vec::new();
         let mut cursor: Option<String> = None;
 
         // Fetch all pages with limit=200
         loop {
+            let page_cursor = &cursor;
             let response = self
-                .wrapper
-                .get_projects(Some(200), cursor.clone())
-                .await
-                .map_err(|e| BackendError::Network(e.to_string()))?;
+                .call_api("fetch projects", |api| async move {
+                    api.get_projects(Some(200), page_cursor.clone()).await
+                })
+                .await?;
 
             all_projects.extend(response.results.iter().map(Self::project_to_backend));
 
@@ -110,11 +533,12 @@ impl Backend for TodoistBackend {
 
         // Fetch all pages with limit=200
         loop {
+            let page_cursor = &cursor;
             let response = self
-                .wrapper
-                .get_tasks(Some(200), cursor.clone())
-                .await
-                .map_err(|e| BackendError::Network(e.to_string()))?;
+                .call_api("fetch tasks", |api| async move {
+                    api.get_tasks(Some(200), page_cursor.clone()).await
+                })
+                .await?;
 
             all_tasks.extend(response.results.iter().map(Self::task_to_backend));
 
@@ -128,17 +552,33 @@ impl Backend for TodoistBackend {
         Ok(all_tasks)
     }
 
+    async fn fetch_task(&self, remote_id: &str) -> Result<Option<BackendTask>, BackendError> {
+        let task = self
+            .call_api("fetch task", |api| async move {
+                match api.get_task(remote_id).await {
+                    Ok(task) => Ok(Some(task)),
+                    Err(e) if e.is_not_found() => Ok(None),
+                    Err(e) => Err(e),
+                }
+            })
+            .await?;
+        Ok(task
+            .filter(|task| !task.checked && !task.is_deleted)
+            .map(|task| Self::task_to_backend(&task)))
+    }
+
     async fn fetch_labels(&self) -> Result<Vec<BackendLabel>, BackendError> {
         let mut all_labels = Vec::new();
         let mut cursor: Option<String> = None;
 
         // Fetch all pages with limit=200
         loop {
+            let page_cursor = &cursor;
             let response = self
-                .wrapper
-                .get_labels(Some(200), cursor.clone())
-                .await
-                .map_err(|e| BackendError::Network(e.to_string()))?;
+                .call_api("fetch labels", |api| async move {
+                    api.get_labels(Some(200), page_cursor.clone()).await
+                })
+                .await?;
 
             all_labels.extend(response.results.iter().map(Self::label_to_backend));
 
@@ -158,11 +598,12 @@ impl Backend for TodoistBackend {
 
         // Fetch all pages with limit=200
         loop {
+            let page_cursor = &cursor;
             let response = self
-                .wrapper
-                .get_sections(Some(200), cursor.clone())
-                .await
-                .map_err(|e| BackendError::Network(e.to_string()))?;
+                .call_api("fetch sections", |api| async move {
+                    api.get_sections(Some(200), page_cursor.clone()).await
+                })
+                .await?;
 
             all_sections.extend(response.results.iter().map(Self::section_to_backend));
 
@@ -179,48 +620,50 @@ impl Backend for TodoistBackend {
     async fn create_project(&self, args: CreateProjectArgs) -> Result<BackendProject, BackendError> {
         let todoist_args = crate::todoist::CreateProjectArgs {
             name: args.name,
-            color: None,
+            color: args.color,
             is_favorite: args.is_favorite,
             parent_id: args.parent_remote_id,
             view_style: None,
         };
 
+        let args = &todoist_args;
         let project = self
-            .wrapper
-            .create_project(&todoist_args)
-            .await
-            .map_err(|e| BackendError::Network(e.to_string()))?;
+            .call_api_once("create project", |api| async move { api.create_project(args).await })
+            .await?;
         Ok(Self::project_to_backend(&project))
     }
 
     async fn update_project(&self, remote_id: &str, args: UpdateProjectArgs) -> Result<BackendProject, BackendError> {
         let todoist_args = crate::todoist::UpdateProjectArgs {
             name: args.name,
-            color: None,
+            color: args.color,
             is_favorite: args.is_favorite,
             view_style: None,
         };
 
+        let args = &todoist_args;
         let project = self
-            .wrapper
-            .update_project(remote_id, &todoist_args)
-            .await
-            .map_err(|e| BackendError::Network(e.to_string()))?;
+            .call_api("update project", |api| async move {
+                api.update_project(remote_id, args).await
+            })
+            .await?;
         Ok(Self::project_to_backend(&project))
     }
 
     async fn delete_project(&self, remote_id: &str) -> Result<(), BackendError> {
-        self.wrapper
-            .delete_project(remote_id)
-            .await
-            .map_err(|e| BackendError::Network(e.to_string()))
+        self.call_api(
+            "delete project",
+            |api| async move { api.delete_project(remote_id).await },
+        )
+        .await
     }
 
     async fn create_task(&self, args: CreateTaskArgs) -> Result<BackendTask, BackendError> {
         let todoist_args = crate::todoist::CreateTaskArgs {
             content: args.content,
             description: args.description,
-            project_id: Some(args.project_remote_id),
+            // Without a project_id Todoist files the task in the inbox
+            project_id: Some(args.project_remote_id).filter(|id| !id.is_empty()),
             section_id: args.section_remote_id,
             parent_id: args.parent_remote_id,
             priority: args.priority,
@@ -239,21 +682,28 @@ impl Backend for TodoistBackend {
             ..Default::default()
         };
 
+        let args = &todoist_args;
         let task = self
-            .wrapper
-            .create_task(&todoist_args)
-            .await
-            .map_err(|e| BackendError::Network(e.to_string()))?;
+            .call_api_once("create task", |api| async move { api.create_task(args).await })
+            .await?;
         Ok(Self::task_to_backend(&task))
     }
 
     async fn update_task(&self, remote_id: &str, args: UpdateTaskArgs) -> Result<BackendTask, BackendError> {
+        // A section implies its project, so it takes precedence
+        if let Some(section_remote_id) = &args.section_remote_id {
+            self.move_item(remote_id, "section_id", section_remote_id).await?;
+        } else if let Some(project_remote_id) = &args.project_remote_id {
+            self.move_item(remote_id, "project_id", project_remote_id).await?;
+        }
+
         let todoist_args = crate::todoist::UpdateTaskArgs {
             content: args.content,
             description: args.description,
             priority: args.priority,
             due_date: args.due_date,
             due_datetime: args.due_datetime,
+            // The Todoist API client doesn't expose deadlines yet, so `args.deadline` is not sent
             labels: args.labels,
             duration: args.duration.as_ref().and_then(|d| {
                 // UpdateTaskArgs.duration is Option<i32> (just the amount)
@@ -267,33 +717,39 @@ impl Backend for TodoistBackend {
             ..Default::default()
         };
 
+        let args = &todoist_args;
         let task = self
-            .wrapper
-            .update_task(remote_id, &todoist_args)
-            .await
-            .map_err(|e| BackendError::Network(e.to_string()))?;
+            .call_api(
+                "update task",
+                |api| async move { api.update_task(remote_id, args).await },
+            )
+            .await?;
         Ok(Self::task_to_backend(&task))
     }
 
     async fn delete_task(&self, remote_id: &str) -> Result<(), BackendError> {
-        self.wrapper
-            .delete_task(remote_id)
+        self.call_api("delete task", |api| async move { api.delete_task(remote_id).await })
             .await
-            .map_err(|e| BackendError::Network(e.to_string()))
     }
 
     async fn complete_task(&self, remote_id: &str) -> Result<(), BackendError> {
-        self.wrapper
-            .complete_task(remote_id)
+        self.call_api("complete task", |api| async move { api.complete_task(remote_id).await })
+            .await
+    }
+
+    async fn reorder_tasks(&self, orders: &[(String, i32)]) -> Result<(), BackendError> {
+        // The REST API has no ordering, so this goes through the Sync API `item_reorder` command
+        let items: Vec<serde_json::Value> = orders
+            .iter()
+            .map(|(remote_id, order)| serde_json::json!({ "id": remote_id, "child_order": order }))
+            .collect();
+        self.run_command("item_reorder", serde_json::json!({ "items": items }), "reorder tasks")
             .await
-            .map_err(|e| BackendError::Network(e.to_string()))
     }
 
     async fn reopen_task(&self, remote_id: &str) -> Result<(), BackendError> {
-        self.wrapper
-            .reopen_task(remote_id)
+        self.call_api("reopen task", |api| async move { api.reopen_task(remote_id).await })
             .await
-            .map_err(|e| BackendError::Network(e.to_string()))
     }
 
     async fn create_label(&self, args: CreateLabelArgs) -> Result<BackendLabel, BackendError> {
@@ -304,34 +760,33 @@ impl Backend for TodoistBackend {
             ..Default::default()
         };
 
+        let args = &todoist_args;
         let label = self
-            .wrapper
-            .create_label(&todoist_args)
-            .await
-            .map_err(|e| BackendError::Network(e.to_string()))?;
+            .call_api_once("create label", |api| async move { api.create_label(args).await })
+            .await?;
         Ok(Self::label_to_backend(&label))
     }
 
     async fn update_label(&self, remote_id: &str, args: UpdateLabelArgs) -> Result<BackendLabel, BackendError> {
         let todoist_args = crate::todoist::UpdateLabelArgs {
             name: args.name,
-            color: None,
+            color: args.color,
             is_favorite: args.is_favorite,
             ..Default::default()
         };
 
+        let args = &todoist_args;
         let label = self
-            .wrapper
-            .update_label(remote_id, &todoist_args)
-            .await
-            .map_err(|e| BackendError::Network(e.to_string()))?;
+            .call_api(
+                "update label",
+                |api| async move { api.update_label(remote_id, args).await },
+            )
+            .await?;
         Ok(Self::label_to_backend(&label))
     }
 
     async fn delete_label(&self, remote_id: &str) -> Result<(), BackendError> {
-        self.wrapper
-            .delete_label(remote_id)
+        self.call_api("delete label", |api| async move { api.delete_label(remote_id).await })
             .await
-            .map_err(|e| BackendError::Network(e.to_string()))
     }
 }
@@ -167,6 +167,8 @@ impl BackendRegistry {
             is_enabled: ActiveValue::Set(true),
             credentials: ActiveValue::Set(credentials),
             settings: ActiveValue::Set(settings),
+            sync_token: ActiveValue::Set(None),
+            last_sync_at: ActiveValue::Set(None),
         };
 
         let storage = self.storage.lock().await;
@@ -180,6 +182,51 @@ impl BackendRegistry {
         Ok(uuid)
     }
 
+    /// Add a backend from an already constructed instance.
+    ///
+    /// Unlike [`add_backend`](Self::add_backend), the instance is not created by the
+    /// factory, which allows registering backends implemented outside this crate.
+    ///
+    /// # Arguments
+    /// * `name` - Human-readable name
+    /// * `credentials` - JSON-encoded credentials, stored alongside the backend
+    /// * `backend_instance` - The backend to register
+    ///
+    /// # Returns
+    /// UUID of the created backend
+    ///
+    /// # Errors
+    /// Returns error if database insert fails
+    pub async fn add_backend_instance(
+        &self,
+        name: String,
+        credentials: String,
+        backend_instance: Box<dyn Backend>,
+    ) -> Result<Uuid> {
+        let uuid = Uuid::new_v4();
+        let backend_type = backend_instance.backend_type().to_string();
+
+        let backend_model = backend::ActiveModel {
+            uuid: ActiveValue::Set(uuid),
+            backend_type: ActiveValue::Set(backend_type.clone()),
+            name: ActiveValue::Set(name.clone()),
+            is_enabled: ActiveValue::Set(true),
+            credentials: ActiveValue::Set(credentials),
+            settings: ActiveValue::Set("{}".to_string()),
+            sync_token: ActiveValue::Set(None),
+            last_sync_at: ActiveValue::Set(None),
+        };
+
+        let storage = self.storage.lock().await;
+        BackendRepository::create(&storage.conn, backend_model).await?;
+
+        let mut backends = self.backends.lock().await;
+        backends.insert(uuid, Arc::new(backend_instance));
+
+        info!("✅ Added backend: {} ({})", name, backend_type);
+        Ok(uuid)
+    }
+
     /// Update an existing backend.
     ///
     /// # Arguments
@@ -210,14 +257,16 @@ impl BackendRegistry {
             active_model.name = ActiveValue::Set(name);
         }
 
-        // If credentials changed, validate and recreate instance
+        // If credentials changed, hand them to the live instance (or create one if none is loaded)
         if let Some(ref new_credentials) = credentials {
-            let backend_instance = factory::create_backend(&backend_type, new_credentials)?;
-            active_model.credentials = ActiveValue::Set(new_credentials.clone());
-
-            // Update in-memory cache
             let mut backends = self.backends.lock().await;
-            backends.insert(*uuid, Arc::new(backend_instance));
+            if let Some(backend_instance) = backends.get(uuid) {
+                backend_instance.set_credentials(new_credentials)?;
+            } else {
+                let backend_instance = factory::create_backend(&backend_type, new_credentials)?;
+                backends.insert(*uuid, Arc::new(backend_instance));
+            }
+            active_model.credentials = ActiveValue::Set(new_credentials.clone());
         }
 
         if let Some(settings) = settings {
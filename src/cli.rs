@@ -0,0 +1,300 @@
+//! Headless commands for scripts, cron jobs and status bars.
+//!
+//! `terminalist add <content>`, `terminalist today`, `terminalist list [--project <name>]`
+//! and `terminalist complete <id>` run against the configured backend and exit without
+//! starting the TUI. Tasks are printed one per line as `<id>\t<due>\t<content>`, where the
+//! ID is the backend's (stable across sessions, unlike local UUIDs), or as JSON with
+//! `--json`.
+//!
+//! JSON output is a [`JsonOutput`] envelope whose fields only change along with
+//! [`JSON_SCHEMA_VERSION`], so bar widgets and editor plugins can depend on it.
+
+use crate::entities::{project, task};
+use crate::sync::{SyncService, SyncStatus};
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+/// Version of the [`JsonOutput`] layout, raised whenever a field is renamed, removed or
+/// changes meaning (adding a field keeps the version)
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Arguments followed by a value, which is never taken for a subcommand or its arguments
+const VALUE_FLAGS: [&str; 3] = ["--backend", "--import", "--project"];
+
+/// Arguments that take no value
+const FLAGS: [&str; 9] = [
+    "-h",
+    "--help",
+    "-V",
+    "--version",
+    "-d",
+    "--debug",
+    "--generate-config",
+    "--add-stdin",
+    "--json",
+];
+
+/// Ends the options: every argument after it is positional, even if it starts with a dash
+const END_OF_OPTIONS: &str = "--";
+
+/// A subcommand given on the command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Create a task, in the project with this name or else the default project or inbox
+    Add { content: String, project: Option<String> },
+    /// List the overdue tasks and those due today
+    Today,
+    /// List the open tasks, of the project with this name or of all projects
+    List { project: Option<String> },
+    /// Complete the task with this backend ID (or local UUID)
+    Complete { id: String },
+}
+
+impl Command {
+    /// Parse the subcommand from the command-line arguments (program name first).
+    ///
+    /// Returns `None` without a subcommand, to start the TUI.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for an unknown subcommand or option, or missing/extra arguments
+    pub fn parse(args: &[String]) -> Result<Option<Self>> {
+        let project = parse_value_arg(args, "--project", "a project name")?;
+        let (words, unknown_option) = scan_args(args);
+        if let Some(option) = unknown_option {
+            anyhow::bail!("Unknown option '{}' (put it after -- to use it as text)", option);
+        }
+        let mut words = words.into_iter();
+        let Some(name) = words.next() else {
+            return Ok(None);
+        };
+        let words: Vec<&str> = words.collect();
+
+        let command = match name {
+            "add" => {
+                let content = words.join(" ");
+                if content.trim().is_empty() {
+                    anyhow::bail!("add requires the task content");
+                }
+                Self::Add { content, project }
+            }
+            "today" => Self::Today,
+            "list" => Self::List { project },
+            "complete" => match words.as_slice() {
+                [id] => Self::Complete { id: id.to_string() },
+                _ => anyhow::bail!("complete requires one task ID"),
+            },
+            other => anyhow::bail!("Unknown command '{}'", other),
+        };
+        if !matches!(command, Self::Add { .. } | Self::Complete { .. }) && !words.is_empty() {
+            anyhow::bail!("{} takes no arguments besides its options", name);
+        }
+        Ok(Some(command))
+    }
+
+    /// The subcommand as typed, e.g. "today"
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Add { .. } => "add",
+            Self::Today => "today",
+            Self::List { .. } => "list",
+            Self::Complete { .. } => "complete",
+        }
+    }
+}
+
+/// What a command prints with `--json`
+#[derive(Debug, Serialize)]
+pub struct JsonOutput {
+    /// Always [`JSON_SCHEMA_VERSION`]
+    pub schema_version: u32,
+    /// The subcommand that ran, see [`Command::name`]; as typed (empty if none) when the
+    /// arguments couldn't be parsed
+    pub command: String,
+    /// The tasks listed, created or completed; empty on error
+    pub tasks: Vec<task::Model>,
+    /// The projects of those tasks, each once
+    pub projects: Vec<project::Model>,
+    /// Why the command failed, in which case the exit status is 1 (2 for invalid arguments)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl JsonOutput {
+    pub fn new(command: &Command, tasks: Vec<task::Model>, projects: Vec<project::Model>) -> Self {
+        Self {
+            schema_version: JSON_SCHEMA_VERSION,
+            command: command.name().to_string(),
+            tasks,
+            projects,
+            error: None,
+        }
+    }
+
+    /// The output of a command that failed with `error`
+    pub fn error(command: &Command, error: &anyhow::Error) -> Self {
+        Self {
+            error: Some(format!("{:#}", error)),
+            ..Self::new(command, Vec::new(), Vec::new())
+        }
+    }
+
+    /// The output when the arguments couldn't be parsed, e.g. an unknown command
+    pub fn usage_error(args: &[String], error: &anyhow::Error) -> Self {
+        Self {
+            schema_version: JSON_SCHEMA_VERSION,
+            command: scan_args(args).0.first().map_or_else(String::new, |name| name.to_string()),
+            tasks: Vec::new(),
+            projects: Vec::new(),
+            error: Some(format!("{:#}", error)),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// The arguments before `--`, where options are looked for (program name first)
+pub fn option_args(args: &[String]) -> &[String] {
+    match args.iter().position(|arg| arg == END_OF_OPTIONS) {
+        Some(end) => &args[..end],
+        None => args,
+    }
+}
+
+/// The arguments that are neither options nor the value of an option, in order, with
+/// the first option that isn't known
+fn scan_args(args: &[String]) -> (Vec<&str>, Option<&str>) {
+    let mut positional = Vec::new();
+    let mut unknown_option = None;
+    let mut skip_value = false;
+    let options = option_args(args);
+    for arg in options.iter().skip(1) {
+        if std::mem::take(&mut skip_value) {
+            continue;
+        }
+        if arg.len() > 1 && arg.starts_with('-') {
+            let name = arg.split_once('=').map_or(arg.as_str(), |(name, _)| name);
+            skip_value = VALUE_FLAGS.contains(&arg.as_str());
+            if !FLAGS.contains(&arg.as_str()) && !VALUE_FLAGS.contains(&name) {
+                unknown_option.get_or_insert(arg.as_str());
+            }
+        } else {
+            positional.push(arg.as_str());
+        }
+    }
+    positional.extend(args.iter().skip(options.len() + 1).map(String::as_str));
+    (positional, unknown_option)
+}
+
+/// Extract the value of `<flag> <value>` (or `<flag>=<value>`) from the arguments before
+/// `--`, such as `--backend <name>`.
+///
+/// # Errors
+///
+/// Returns an error if the flag is given without a value
+pub fn parse_value_arg(args: &[String], flag: &str, value_name: &str) -> Result<Option<String>> {
+    let prefix = format!("{}=", flag);
+    let args = option_args(args);
+    for (index, arg) in args.iter().enumerate() {
+        if let Some(value) = arg.strip_prefix(&prefix) {
+            if value.is_empty() {
+                anyhow::bail!("{} requires {}", flag, value_name);
+            }
+            return Ok(Some(value.to_string()));
+        }
+        if arg == flag {
+            return match args.get(index + 1) {
+                Some(value) if !value.starts_with('-') => Ok(Some(value.clone())),
+                _ => anyhow::bail!("{} requires {}", flag, value_name),
+            };
+        }
+    }
+    Ok(None)
+}
+
+/// Sync, run the command and return what to print: one line per task, or a [`JsonOutput`]
+/// with `json`.
+///
+/// # Errors
+///
+/// Returns an error if the sync fails, a project or task isn't found, or the backend
+/// rejects the change
+pub async fn run(sync_service: &SyncService, command: &Command, json: bool) -> Result<String> {
+    // The local database starts empty, so load everything before looking anything up
+    if let SyncStatus::Error { message } = sync_service.sync().await.context("Initial sync failed")? {
+        anyhow::bail!("Initial sync failed: {}", message);
+    }
+
+    let tasks = match command {
+        Command::Add { content, project } => {
+            let project_uuid = match project {
+                Some(name) => Some(find_project(sync_service, name).await?),
+                None => None,
+            };
+            // Create the task on the backend of its project
+            let service = match &project_uuid {
+                Some(uuid) => sync_service.for_item(uuid).await,
+                None => sync_service.clone(),
+            };
+            let task = service
+                .create_task_with_details(content, project_uuid, None, None, None, None)
+                .await?;
+            vec![task]
+        }
+        Command::Today => open_tasks(sync_service.get_tasks_for_today().await?),
+        Command::List { project: Some(name) } => {
+            let project_uuid = find_project(sync_service, name).await?;
+            open_tasks(sync_service.get_tasks_for_project(&project_uuid).await?)
+        }
+        Command::List { project: None } => open_tasks(sync_service.get_all_tasks().await?),
+        Command::Complete { id } => {
+            let task = sync_service
+                .get_all_tasks()
+                .await?
+                .into_iter()
+                .find(|task| task.remote_id == *id || task.uuid.to_string() == *id)
+                .with_context(|| format!("Task '{}' not found", id))?;
+            sync_service.for_item(&task.uuid).await.complete_task(&task.uuid).await?;
+            let completed = sync_service.get_task_by_id(&task.uuid).await?;
+            vec![completed.unwrap_or(task)]
+        }
+    };
+
+    if json {
+        let projects = sync_service
+            .get_projects()
+            .await?
+            .into_iter()
+            .filter(|project| tasks.iter().any(|task| task.project_uuid == project.uuid))
+            .collect();
+        return JsonOutput::new(command, tasks, projects).to_json();
+    }
+    Ok(tasks.iter().map(format_task_line).collect::<Vec<_>>().join("\n"))
+}
+
+/// A task as printed in plain text: `<id>\t<due>\t<content>`, the due date empty when unset
+pub fn format_task_line(task: &task::Model) -> String {
+    format!(
+        "{}\t{}\t{}",
+        task.remote_id,
+        task.due_date.as_deref().unwrap_or_default(),
+        task.content
+    )
+}
+
+fn open_tasks(tasks: Vec<task::Model>) -> Vec<task::Model> {
+    tasks.into_iter().filter(|t| !t.is_completed && !t.is_deleted).collect()
+}
+
+/// The UUID of the project named `name`, ignoring case
+async fn find_project(sync_service: &SyncService, name: &str) -> Result<uuid::Uuid> {
+    sync_service
+        .get_projects()
+        .await?
+        .into_iter()
+        .find(|project| project.name.eq_ignore_ascii_case(name))
+        .map(|project| project.uuid)
+        .with_context(|| format!("Project '{}' not found", name))
+}
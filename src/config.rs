@@ -2,20 +2,111 @@
 //!
 //! This module handles loading, parsing, and validation of configuration files.
 
-use crate::constants::{CONFIG_GENERATED, SIDEBAR_DEFAULT_WIDTH, SIDEBAR_MAX_WIDTH, SIDEBAR_MIN_WIDTH};
-use crate::utils::datetime;
+use crate::backend::retry::RetryPolicy;
+use crate::constants::{
+    CONFIG_GENERATED, MIN_AUTO_SYNC_INTERVAL_SECS, SIDEBAR_DEFAULT_WIDTH, SIDEBAR_MAX_WIDTH, SIDEBAR_MIN_WIDTH,
+    UPCOMING_DEFAULT_DAYS,
+};
+use crate::icons::IconTheme;
+use crate::ui::core::keymap::{KeyAction, KeyMap};
+use crate::utils::{datetime, export};
 use anyhow::{Context, Result};
+use crossterm::event::{KeyCode, KeyEvent};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Special views that can be listed in `ui.views`
+pub const SPECIAL_VIEW_NAMES: &[&str] = &["inbox", "today", "tomorrow", "upcoming", "someday", "postponed", "all"];
 
 /// Main configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(default)]
 pub struct Config {
+    /// Backend used when `--backend` is not given (defaults to the first configured backend)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_backend: Option<String>,
+    /// Connect every configured backend at once, showing their projects side by side, instead
+    /// of only the selected one
+    pub connect_all_backends: bool,
     pub ui: UiConfig,
     pub sync: SyncConfig,
     pub display: DisplayConfig,
     pub logging: LoggingConfig,
+    pub behavior: BehaviorConfig,
+    pub views: ViewsConfig,
+    /// Configured backends; when empty, a single Todoist backend uses `TODOIST_API_TOKEN`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub backends: Vec<BackendConfig>,
+    /// Saved filters listed in the sidebar (see [`crate::sync::filters`] for the query syntax)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub filters: Vec<FilterConfig>,
+    /// Keys bound to actions, by action name (see [`crate::ui::core::keymap::KeyAction::name`]);
+    /// actions not listed keep their default keys
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub keybindings: BTreeMap<String, String>,
+    pub theme: ThemeConfig,
+}
+
+/// Color theme (see [`crate::ui::core::theme`])
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    /// Built-in theme: "default" or "solarized-dark"
+    pub name: String,
+    /// Colors replacing the theme's own, by role (e.g. `accent = "#268bd2"`)
+    #[serde(flatten)]
+    pub colors: BTreeMap<String, String>,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        Self {
+            name: "default".to_string(),
+            colors: BTreeMap::new(),
+        }
+    }
+}
+
+/// A saved filter shown as a sidebar entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilterConfig {
+    /// Name shown in the sidebar
+    pub name: String,
+    /// Filter query, such as `(today | overdue) & @work`
+    pub query: String,
+}
+
+/// A configured backend account
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackendConfig {
+    /// Name used to select the backend with `--backend <name>`
+    pub name: String,
+    /// Backend type: "todoist", "caldav" or "local" (offline, no account)
+    #[serde(rename = "type", default = "default_backend_type")]
+    pub backend_type: String,
+    /// Environment variable holding the API token (the app password for CalDAV)
+    #[serde(default = "default_token_env")]
+    pub token_env: String,
+    /// CalDAV calendar home URL (e.g. `https://cloud.example.com/remote.php/dav/calendars/me/`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// CalDAV username
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Project (name or ID) receiving tasks created without a project; defaults to the
+    /// backend's inbox, and is required for backends that have none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_project: Option<String>,
+}
+
+fn default_backend_type() -> String {
+    "todoist".to_string()
+}
+
+fn default_token_env() -> String {
+    "TODOIST_API_TOKEN".to_string()
 }
 
 /// UI configuration
@@ -31,6 +122,33 @@ pub struct UiConfig {
     pub sidebar_width: u16,
     /// Show sidebar on startup
     pub sidebar_visible: bool,
+    /// Global key that opens task creation targeting the inbox from any view; `I` by default
+    /// since `a` already creates a task in the current view. Must not be bound to another action.
+    pub quick_add_key: char,
+    /// Where the selection moves when the selected task disappears after a reload
+    /// Options: "nearest" (task now at the same position) or "top" (first task)
+    pub selection_after_removal: SelectionAfterRemoval,
+    /// Special views shown at the top of the sidebar, in display order
+    /// Options: "inbox", "today", "tomorrow", "upcoming", "someday"
+    pub views: Vec<String>,
+    /// Group sidebar rows under collapsible "Views", "Labels" and "Projects" headers
+    pub sidebar_sections: bool,
+    /// Preselect the most recently used project when creating a task outside a project view
+    pub preselect_recent_project: bool,
+    /// Restore the last view and sidebar visibility from the previous session
+    pub persist_state: bool,
+    /// Show a status bar with the view, its task count and the main shortcuts
+    pub status_bar: bool,
+    /// Icon set: "unicode", "emoji" or "ascii"; detected from the terminal when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_mode: Option<IconTheme>,
+}
+
+impl UiConfig {
+    /// Icon set to draw with: `icon_mode`, or the one suited to the terminal
+    pub fn icon_theme(&self) -> IconTheme {
+        self.icon_mode.unwrap_or_else(IconTheme::detect)
+    }
 }
 
 /// Sync configuration
@@ -39,13 +157,63 @@ pub struct UiConfig {
 pub struct SyncConfig {
     /// Auto-sync interval in minutes (0 = disabled, manual sync only)
     pub auto_sync_interval_minutes: u64,
+    /// Auto-sync interval in seconds, overriding `auto_sync_interval_minutes` when set (0 = disabled)
+    pub auto_sync_interval_secs: Option<u64>,
+    /// Only contact the backend for an explicit sync or a change made by the user
+    /// (no startup sync, no auto-sync), e.g. on metered connections
+    pub manual_only: bool,
+    /// How many times a backend call is retried after a network error (0 = never)
+    pub max_retries: u32,
+    /// Wait before the first retry in milliseconds, doubled for each following one
+    pub base_delay_ms: u64,
+    /// Fail the whole sync when sections can't be fetched, instead of syncing the rest
+    /// and showing a warning
+    pub fail_on_section_error: bool,
+    /// SQLite database file, instead of `terminalist.db` in the data directory; a leading
+    /// `~` is the home directory. `TERMINALIST_DB_PATH` overrides it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_path: Option<String>,
+}
+
+impl SyncConfig {
+    /// Whether syncs may start without the user asking (on startup or on a timer)
+    pub fn automatic_sync_enabled(&self) -> bool {
+        !self.manual_only
+    }
+
+    /// Time between background syncs, or `None` if auto-sync is off
+    pub fn auto_sync_interval(&self) -> Option<Duration> {
+        let secs = self.auto_sync_interval_secs.unwrap_or(self.auto_sync_interval_minutes * 60);
+        if !self.automatic_sync_enabled() || secs == 0 {
+            None
+        } else {
+            Some(Duration::from_secs(secs))
+        }
+    }
+
+    /// The configured database file with `~` expanded, or `None` for the default location
+    pub fn database_path(&self) -> Option<PathBuf> {
+        self.database_path
+            .as_deref()
+            .filter(|path| !path.trim().is_empty())
+            .map(export::expand_home)
+    }
+
+    /// How backend calls are retried after a network error
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            max_retries: self.max_retries,
+            base_delay: Duration::from_millis(self.base_delay_ms),
+        }
+    }
 }
 
 /// Display configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct DisplayConfig {
-    /// Date format for task due dates
+    /// Date format for due dates more than a week away; nearer ones are shown as
+    /// "Today", "Tomorrow", a weekday or a number of days
     pub date_format: String,
     /// Time format for datetime fields
     pub time_format: String,
@@ -57,6 +225,78 @@ pub struct DisplayConfig {
     pub show_labels: bool,
     /// Show project colors
     pub show_project_colors: bool,
+    /// How long a completed recurring task stays highlighted after moving to its next
+    /// occurrence, in milliseconds (0 = disabled)
+    pub rescheduled_highlight_ms: u64,
+    /// Wrap long lines in the help, logs, info and error dialogs to the dialog width
+    pub wrap_dialogs: bool,
+    /// Show a postponed badge on tasks whose due date was pushed back at least this
+    /// many times (0 = disabled)
+    pub postponed_badge_threshold: i32,
+    /// Number the tasks in a left gutter and jump to one by typing its number
+    pub show_task_numbers: bool,
+    /// How task labels are shown in the task list
+    /// Options: "full" (@name), "short" (first letters) or "dot" (one ● per label in its color)
+    pub label_style: LabelStyle,
+    /// How task priorities are shown in the task list, in the theme's priority colors
+    /// Options: "flag" (⚑ before the content), "content" (colored content) or "bar" (leading bar)
+    pub priority_style: PriorityStyle,
+    /// Mark each task with a bar in its project's color in views mixing projects
+    /// (Today, Tomorrow, Upcoming, Postponed and labels)
+    pub project_color_coding: bool,
+    /// Show a bar of completed vs total tasks next to each project in the sidebar
+    pub show_project_progress: bool,
+}
+
+/// Behavior configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BehaviorConfig {
+    /// Label marking someday/maybe tasks: they get their own "Someday" view and are
+    /// left out of Today, Tomorrow and Upcoming (empty = disabled)
+    pub someday_label: String,
+    /// Ask before creating labels that don't exist yet from inline `@label` syntax
+    pub confirm_new_label: bool,
+    /// Mark subtasks completed locally when their parent is completed, as the backend does
+    pub complete_subtasks_with_parent: bool,
+    /// Ask before applying the t/T/w/W due date shortcuts, showing the resolved date
+    pub confirm_due_shortcuts: bool,
+    /// Largest number of stored tasks searched with fuzzy matching; larger databases use
+    /// a faster plain substring search (0 = always use substring search)
+    pub fuzzy_search_max_tasks: usize,
+    /// Directory offered for exporting a view with `x` (empty = home directory)
+    pub export_dir: String,
+}
+
+/// Per-view filtering configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ViewsConfig {
+    /// Lowest priority shown in the Today view, as in the UI: 1 = P1 only ... 4 = P1 to P4
+    /// (0 = no filter)
+    pub today_min_priority: u8,
+    /// How many days ahead the Upcoming view looks on startup (changed with +/- at runtime)
+    pub upcoming_days: u32,
+    /// Order of tasks within each group of a view on startup (cycled with `o` at runtime)
+    /// Options: "default", "priority", "due_date" or "content"
+    pub default_sort: TaskSort,
+}
+
+impl Default for ViewsConfig {
+    fn default() -> Self {
+        Self {
+            today_min_priority: 0,
+            upcoming_days: UPCOMING_DEFAULT_DAYS,
+            default_sort: TaskSort::default(),
+        }
+    }
+}
+
+impl ViewsConfig {
+    /// Whether a task with the given backend priority (4 = P1 ... 1 = P4) is shown in Today
+    pub fn today_includes_priority(&self, priority: i32) -> bool {
+        self.today_min_priority == 0 || priority >= 5 - i32::from(self.today_min_priority)
+    }
 }
 
 /// Logging configuration
@@ -65,6 +305,93 @@ pub struct DisplayConfig {
 pub struct LoggingConfig {
     /// Enable logging
     pub enabled: bool,
+    /// Log file output format
+    /// Options: "pretty" (human-readable) or "json" (one JSON object per line)
+    pub format: LogFormat,
+}
+
+/// Selection behavior when the selected task is removed (e.g. by a background sync)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SelectionAfterRemoval {
+    /// Select the task now at the previous position (clamped to the last task)
+    #[default]
+    Nearest,
+    /// Select the first task in the list
+    Top,
+}
+
+/// Order of tasks within each group of a view (section, date or project)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskSort {
+    /// Storage order: open tasks first, then by priority and manual order
+    #[default]
+    Default,
+    /// Highest priority first
+    Priority,
+    /// Earliest due first, tasks without a due date last
+    DueDate,
+    /// Alphabetically by content, ignoring case
+    Content,
+}
+
+impl TaskSort {
+    /// Sort modes in the order `o` cycles through them
+    pub const ALL: [TaskSort; 4] = [TaskSort::Default, TaskSort::Priority, TaskSort::DueDate, TaskSort::Content];
+
+    /// Mode after this one, wrapping around to the default order
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|sort| *sort == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Short name shown in the task list title
+    pub fn label(self) -> &'static str {
+        match self {
+            TaskSort::Default => "default order",
+            TaskSort::Priority => "priority",
+            TaskSort::DueDate => "due date",
+            TaskSort::Content => "name",
+        }
+    }
+}
+
+/// How labels are rendered on task rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelStyle {
+    /// Full label name, e.g. `@waiting`
+    #[default]
+    Full,
+    /// First letters of the label name, e.g. `@wa`
+    Short,
+    /// A colored dot per label, using the label color
+    Dot,
+}
+
+/// How priorities are rendered on task rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PriorityStyle {
+    /// A flag before the content, filled for p1 to p3
+    #[default]
+    Flag,
+    /// The content itself in the priority color (p4 keeps the text color)
+    Content,
+    /// A bar in the priority color at the start of the row (blank for p4)
+    Bar,
+}
+
+/// Output format for the log file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable `[HH:MM:SS.mmm] message` lines
+    #[default]
+    Pretty,
+    /// Structured one-line JSON with timestamp, level, target and message
+    Json,
 }
 
 impl Default for UiConfig {
@@ -74,6 +401,27 @@ impl Default for UiConfig {
             mouse_enabled: true,
             sidebar_width: SIDEBAR_DEFAULT_WIDTH,
             sidebar_visible: true,
+            quick_add_key: 'I',
+            selection_after_removal: SelectionAfterRemoval::default(),
+            views: vec!["today".to_string(), "tomorrow".to_string(), "upcoming".to_string()],
+            sidebar_sections: false,
+            preselect_recent_project: false,
+            persist_state: false,
+            status_bar: true,
+            icon_mode: None,
+        }
+    }
+}
+
+impl Default for BehaviorConfig {
+    fn default() -> Self {
+        Self {
+            someday_label: "someday".to_string(),
+            confirm_new_label: false,
+            complete_subtasks_with_parent: true,
+            confirm_due_shortcuts: false,
+            fuzzy_search_max_tasks: 5000,
+            export_dir: String::new(),
         }
     }
 }
@@ -82,6 +430,12 @@ impl Default for SyncConfig {
     fn default() -> Self {
         Self {
             auto_sync_interval_minutes: 5,
+            auto_sync_interval_secs: None,
+            manual_only: false,
+            max_retries: 3,
+            base_delay_ms: 500,
+            fail_on_section_error: false,
+            database_path: None,
         }
     }
 }
@@ -95,6 +449,14 @@ impl Default for DisplayConfig {
             show_durations: true,
             show_labels: true,
             show_project_colors: false,
+            rescheduled_highlight_ms: 2000,
+            wrap_dialogs: true,
+            postponed_badge_threshold: 3,
+            show_task_numbers: false,
+            label_style: LabelStyle::default(),
+            priority_style: PriorityStyle::default(),
+            project_color_coding: false,
+            show_project_progress: false,
         }
     }
 }
@@ -154,6 +516,93 @@ impl Config {
             );
         }
 
+        if self.ui.quick_add_key.is_whitespace() || self.ui.quick_add_key.is_control() {
+            anyhow::bail!("quick_add_key must be a printable character");
+        }
+
+        // Quick add is checked before the sidebar and task list see a key, so it would
+        // shadow the action bound to the same key
+        let keymap = KeyMap::from_config(&self.ui);
+        let keymap = keymap.clone().with_keybindings(&self.keybindings).unwrap_or(keymap);
+        let quick_add = KeyEvent::from(KeyCode::Char(self.ui.quick_add_key));
+        if keymap.is(KeyAction::QuickAdd, &quick_add) {
+            if let Some(action) = KeyAction::ALL
+                .into_iter()
+                .find(|action| *action != KeyAction::QuickAdd && keymap.is(*action, &quick_add))
+            {
+                anyhow::bail!(
+                    "quick_add_key '{}' is already bound to '{}'",
+                    self.ui.quick_add_key,
+                    action.name()
+                );
+            }
+        }
+
+        for (index, view) in self.ui.views.iter().enumerate() {
+            if !SPECIAL_VIEW_NAMES.contains(&view.as_str()) {
+                anyhow::bail!(
+                    "Unknown view '{}' in views. Valid options: {}",
+                    view,
+                    SPECIAL_VIEW_NAMES.join(", ")
+                );
+            }
+            if self.ui.views[..index].contains(view) {
+                anyhow::bail!("View '{}' is listed more than once in views", view);
+            }
+        }
+
+        if self.views.today_min_priority > 4 {
+            anyhow::bail!(
+                "today_min_priority must be between 1 (P1) and 4 (P4), or 0 to disable, got {}",
+                self.views.today_min_priority
+            );
+        }
+
+        if self.views.upcoming_days == 0 {
+            anyhow::bail!("upcoming_days must be at least 1");
+        }
+
+        // Validate backends
+        for (index, backend) in self.backends.iter().enumerate() {
+            if backend.name.trim().is_empty() {
+                anyhow::bail!("Backend names cannot be empty");
+            }
+            if self.backends[..index].iter().any(|b| b.name == backend.name) {
+                anyhow::bail!("Backend '{}' is configured more than once", backend.name);
+            }
+            match backend.backend_type.as_str() {
+                "todoist" | "local" => {}
+                "caldav" => {
+                    if backend.url.is_none() || backend.username.is_none() {
+                        anyhow::bail!("CalDAV backend '{}' needs both url and username", backend.name);
+                    }
+                }
+                other => anyhow::bail!("Backend '{}' has unknown type '{}'", backend.name, other),
+            }
+        }
+
+        // Validate saved filters; queries are parsed when the filter is opened
+        for (index, filter) in self.filters.iter().enumerate() {
+            if filter.name.trim().is_empty() {
+                anyhow::bail!("Filter names cannot be empty");
+            }
+            if self.filters[..index].iter().any(|f| f.name == filter.name) {
+                anyhow::bail!("Filter '{}' is configured more than once", filter.name);
+            }
+            if filter.query.trim().is_empty() {
+                anyhow::bail!("Filter '{}' has an empty query", filter.name);
+            }
+        }
+
+        if let Some(default_backend) = &self.default_backend {
+            if !self.backends.iter().any(|b| &b.name == default_backend) {
+                anyhow::bail!(
+                    "default_backend '{}' does not match any configured backend",
+                    default_backend
+                );
+            }
+        }
+
         // Validate default project
         let valid_projects = ["inbox", "today", "tomorrow", "upcoming"];
         if !valid_projects.contains(&self.ui.default_project.as_str()) {
@@ -165,19 +614,79 @@ impl Config {
         if self.sync.auto_sync_interval_minutes > 1440 {
             anyhow::bail!("auto_sync_interval_minutes cannot exceed 1440 (24 hours)");
         }
+        if let Some(secs) = self.sync.auto_sync_interval_secs {
+            if secs > 86400 {
+                anyhow::bail!("auto_sync_interval_secs cannot exceed 86400 (24 hours)");
+            }
+            if secs > 0 && secs < MIN_AUTO_SYNC_INTERVAL_SECS {
+                anyhow::bail!(
+                    "auto_sync_interval_secs must be 0 or at least {}",
+                    MIN_AUTO_SYNC_INTERVAL_SECS
+                );
+            }
+        }
+
+        crate::ui::core::theme::Theme::from_config(&self.theme)?;
 
         // Validate date/time formats
-        if let Err(e) = chrono::NaiveDate::parse_from_str("2025-01-01", &self.display.date_format) {
-            anyhow::bail!("Invalid date_format '{}': {}", self.display.date_format, e);
+        if !datetime::is_valid_format(&self.display.date_format) {
+            anyhow::bail!("Invalid date_format '{}'", self.display.date_format);
         }
 
-        if let Err(e) = chrono::NaiveTime::parse_from_str("12:00", &self.display.time_format) {
-            anyhow::bail!("Invalid time_format '{}': {}", self.display.time_format, e);
+        if !datetime::is_valid_format(&self.display.time_format) {
+            anyhow::bail!("Invalid time_format '{}'", self.display.time_format);
         }
 
         Ok(())
     }
 
+    /// Pick the backend to use for this session.
+    ///
+    /// `requested` is the name given with `--backend`; without it, `default_backend` or the
+    /// first configured backend is used. Returns `None` when no backends are configured.
+    ///
+    /// # Errors
+    /// Returns an error if `requested` does not match a configured backend
+    pub fn select_backend(&self, requested: Option<&str>) -> Result<Option<&BackendConfig>> {
+        let Some(name) = requested.or(self.default_backend.as_deref()) else {
+            return Ok(self.backends.first());
+        };
+
+        match self.backends.iter().find(|b| b.name == name) {
+            Some(backend) => Ok(Some(backend)),
+            None if self.backends.is_empty() => anyhow::bail!(
+                "Backend '{}' not found: no backends are configured. Add [[backends]] entries to the config file",
+                name
+            ),
+            None => {
+                let available: Vec<&str> = self.backends.iter().map(|b| b.name.as_str()).collect();
+                anyhow::bail!(
+                    "Backend '{}' not found. Configured backends: {}",
+                    name,
+                    available.join(", ")
+                )
+            }
+        }
+    }
+
+    /// The backends to connect for this session, the selected one first.
+    ///
+    /// With `connect_all_backends`, the other configured backends follow it, unless one was
+    /// requested with `--backend`. Empty when no backends are configured.
+    ///
+    /// # Errors
+    /// Returns an error if `requested` does not match a configured backend
+    pub fn session_backends(&self, requested: Option<&str>) -> Result<Vec<&BackendConfig>> {
+        let Some(selected) = self.select_backend(requested)? else {
+            return Ok(Vec::new());
+        };
+        let mut backends = vec![selected];
+        if self.connect_all_backends && requested.is_none() {
+            backends.extend(self.backends.iter().filter(|b| b.name != selected.name));
+        }
+        Ok(backends)
+    }
+
     /// Generate default configuration file
     pub fn generate_default_config<P: AsRef<Path>>(path: P) -> Result<()> {
         let config = Self::default();
@@ -186,7 +695,7 @@ impl Config {
         // Add header comment
         let header = format!(
             "# Terminalist Configuration File\n# Generated on {}\n\n",
-            chrono::Local::now().format(datetime::TODOIST_DATE_FORMAT)
+            datetime::format_today()
         );
 
         let full_content = header + &toml_content;
@@ -7,6 +7,7 @@
 pub const HEADER_OVERDUE: &str = "⏰ Overdue";
 pub const HEADER_TODAY: &str = "📅 Today";
 pub const HEADER_TOMORROW: &str = "📅 Tomorrow";
+pub const HEADER_NO_LABELS: &str = "No labels";
 
 // Success Messages
 pub const SUCCESS_TASK_COMPLETED: &str = "✅ Task completed";
@@ -18,6 +19,11 @@ pub const SUCCESS_TASK_DUE_TODAY: &str = "✅ Task due date set to today";
 pub const SUCCESS_TASK_DUE_TOMORROW: &str = "✅ Task due date set to tomorrow";
 pub const SUCCESS_TASK_DUE_MONDAY: &str = "✅ Task due date set to next Monday";
 pub const SUCCESS_TASK_DUE_SATURDAY: &str = "✅ Task due date set to next Saturday";
+pub const SUCCESS_TASK_DUE_DATE_SET: &str = "✅ Task due date set";
+pub const SUCCESS_TASK_DUE_DATE_CLEARED: &str = "✅ Task due date cleared";
+pub const SUCCESS_TASK_DEADLINE_SET: &str = "✅ Task deadline set";
+pub const SUCCESS_TASK_DEADLINE_CLEARED: &str = "✅ Task deadline cleared";
+pub const SUCCESS_TASK_SECTION_SET: &str = "✅ Task section updated";
 pub const SUCCESS_PROJECT_CREATED_PARENT: &str = "✅ Project created with parent";
 pub const SUCCESS_PROJECT_CREATED_ROOT: &str = "✅ Root project created";
 pub const SUCCESS_PROJECT_DELETED: &str = "✅ Project deleted";
@@ -27,6 +33,9 @@ pub const SUCCESS_LABEL_DELETED: &str = "✅ Label deleted";
 pub const SUCCESS_LABEL_UPDATED: &str = "✅ Label updated";
 pub const SUCCESS_TASK_PRIORITY_UPDATED: &str = "✅ Task priority updated to P";
 pub const SUCCESS_TASK_RESTORED: &str = "✅ Task restored";
+pub const SUCCESS_TASK_DUPLICATED: &str = "✅ Task duplicated";
+pub const SUCCESS_TASK_LABELS_SET: &str = "✅ Task labels updated";
+pub const SUCCESS_TASK_REORDERED: &str = "✅ Task moved";
 pub const SUCCESS_SYNC_COMPLETED: &str = "Sync completed successfully";
 
 // Error Messages
@@ -35,6 +44,8 @@ pub const ERROR_TASK_DELETE_FAILED: &str = "❌ Failed to delete task";
 pub const ERROR_TASK_UPDATE_FAILED: &str = "❌ Failed to update task";
 pub const ERROR_TASK_CREATE_FAILED: &str = "❌ Failed to create task";
 pub const ERROR_TASK_DUE_DATE_FAILED: &str = "❌ Failed to set task due date";
+pub const ERROR_TASK_DEADLINE_FAILED: &str = "❌ Failed to set task deadline";
+pub const ERROR_TASK_SECTION_FAILED: &str = "❌ Failed to set task section";
 pub const ERROR_TASK_PRIORITY_FAILED: &str = "❌ Failed to update task priority";
 pub const ERROR_PROJECT_CREATE_FAILED: &str = "❌ Failed to create project";
 pub const ERROR_PROJECT_DELETE_FAILED: &str = "❌ Failed to delete project";
@@ -43,6 +54,12 @@ pub const ERROR_LABEL_CREATE_FAILED: &str = "❌ Failed to create label";
 pub const ERROR_LABEL_DELETE_FAILED: &str = "❌ Failed to delete label";
 pub const ERROR_LABEL_UPDATE_FAILED: &str = "❌ Failed to update label";
 pub const ERROR_TASK_RESTORE_FAILED: &str = "❌ Failed to restore task";
+pub const ERROR_TASK_DUPLICATE_FAILED: &str = "❌ Failed to duplicate task";
+pub const ERROR_EXPORT_FAILED: &str = "❌ Failed to export view";
+pub const ERROR_TASK_LABELS_FAILED: &str = "❌ Failed to update task labels";
+pub const ERROR_TASK_REORDER_FAILED: &str = "❌ Failed to move task";
+pub const ERROR_FILTER_FAILED: &str = "❌ Could not apply filter";
+pub const ERROR_CONFIG_RELOAD_FAILED: &str = "❌ Failed to reload configuration, keeping the current one";
 
 // Validation Error Messages
 pub const ERROR_INVALID_PRIORITY_FORMAT: &str = "❌ Invalid priority value format";
@@ -74,20 +91,46 @@ pub const LOG_ERROR_STORE_SECTIONS: &str = "❌ Failed to store sections";
 // UI Messages
 pub const CONFIG_GENERATED: &str = "✅ Generated default configuration file";
 pub const ERROR_NO_API_TOKEN: &str = "❌ Error: TODOIST_API_TOKEN environment variable not set";
+pub const TODOIST_TOKEN_URL: &str = "https://todoist.com/prefs/integrations";
+pub const UI_TOKEN_PROMPT_MESSAGE: &str = "Todoist rejected the API token. It may have expired or been revoked.";
+pub const SUCCESS_API_TOKEN_UPDATED: &str = "API token updated. To keep using it after a restart, \
+     update TODOIST_API_TOKEN in your shell profile or secret store (e.g. export TODOIST_API_TOKEN=<token>).";
+pub const ERROR_API_TOKEN_UPDATE_FAILED: &str = "❌ Failed to update API token";
 pub const DIALOG_TITLE_DEBUG_LOGS: &str = "🔍 Debug Logs - Press 'Esc', 'G' or 'q' to close";
 pub const UI_CANNOT_DELETE_TODAY_VIEW: &str = "Cannot delete the Today view";
+pub const UI_FILTERS_EDITED_IN_CONFIG: &str = "Saved filters are edited in the [[filters]] section of the config file";
 pub const UI_NO_TASK_SELECTED_DUE_DATE: &str = "No task selected to set due date";
+pub const UI_NO_TASK_SELECTED_DEADLINE: &str = "No task selected to set a deadline";
 pub const UI_LOADING_DATA: &str = "Loading data";
 pub const UI_SYNCING_WITH_TODOIST: &str = "Syncing with Todoist";
 pub const UI_LOADING_DATA_FROM_STORAGE: &str = "Loading data from storage";
+pub const UI_NO_TASK_SELECTED_FOCUS: &str = "No task selected to focus on";
+pub const UI_NO_TASK_SELECTED_DUPLICATE: &str = "No task selected to duplicate";
+pub const UI_NO_TASK_SELECTED_LABELS: &str = "No task selected to label";
+pub const UI_NO_TASK_SELECTED_REORDER: &str = "No task selected to move";
+pub const UI_NO_OVERDUE_TASKS: &str = "No overdue tasks in this view";
+pub const UI_NOTHING_TO_UNDO: &str = "Nothing to undo";
+pub const UI_TASK_CHANGED_WHILE_EDITING: &str = "This task changed while editing — overwrite?";
+pub const UI_CONFIG_RELOADED: &str = "Configuration reloaded";
 
 // Date header format for upcoming view
 pub const UPCOMING_DATE_FORMAT: &str = "📊 {} - {}";
 
+// Upcoming view horizon in days: the default and the steps +/- move between
+pub const UPCOMING_DEFAULT_DAYS: u32 = 90;
+pub const UPCOMING_ZOOM_STEPS: [u32; 5] = [7, 14, 30, 90, 365];
+
+// Shortest auto-sync interval allowed, to keep background syncs from hammering the backend
+pub const MIN_AUTO_SYNC_INTERVAL_SECS: u64 = 10;
+
 // UI Layout Constants (width in columns)
 pub const SIDEBAR_MIN_WIDTH: u16 = 15;
 pub const SIDEBAR_MAX_WIDTH: u16 = 50;
 pub const SIDEBAR_DEFAULT_WIDTH: u16 = 30;
 pub const MAIN_AREA_MIN_WIDTH: u16 = 20;
+/// Screens this many rows high or less leave out the status bar
+pub const STATUS_BAR_MIN_SCREEN_HEIGHT: u16 = 10;
+/// How long a toast notification stays on screen, in milliseconds
+pub const TOAST_DURATION_MS: u64 = 4000;
 
 pub const MEMORY_LOGS_LIMIT: usize = 5000;
@@ -13,6 +13,10 @@ pub struct Model {
     pub is_enabled: bool,
     pub credentials: String, // JSON-encoded credentials (to be encrypted in future)
     pub settings: String,    // JSON-encoded backend-specific settings
+    /// Token of the last incremental sync, for backends that support it
+    pub sync_token: Option<String>,
+    /// When the last successful sync completed (RFC 3339, UTC)
+    pub last_sync_at: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -10,6 +10,8 @@ pub struct Model {
     pub backend_uuid: Uuid,
     pub remote_id: String,
     pub name: String,
+    /// Backend color name (e.g. "berry_red")
+    pub color: String,
     pub is_favorite: bool,
     pub is_inbox_project: bool,
     pub order_index: i32,
@@ -3,6 +3,8 @@ use sea_orm::QueryOrder;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::utils::datetime::Due;
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "tasks")]
 pub struct Model {
@@ -19,11 +21,16 @@ pub struct Model {
     pub order_index: i32,
     pub due_date: Option<String>,
     pub due_datetime: Option<String>,
+    /// The due date as the user wrote it; for recurring tasks, the recurrence ("every monday")
+    pub due_string: Option<String>,
     pub is_recurring: bool,
     pub deadline: Option<String>,
     pub duration: Option<String>,
     pub is_completed: bool,
     pub is_deleted: bool,
+    /// Times the due date was moved later; local metadata, never sent to or reset by the backend
+    #[sea_orm(default_value = 0)]
+    pub postpone_count: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -87,6 +94,22 @@ impl Related<super::backend::Entity> for Entity {
 
 impl ActiveModelBehavior for ActiveModel {}
 
+impl Model {
+    /// Parsed due date, or `None` if the task has no (valid) due date
+    pub fn due(&self) -> Option<Due> {
+        Due::parse(self.due_date.as_deref(), self.due_datetime.as_deref())
+    }
+
+    /// Whether this task differs from an earlier copy of it, ignoring local-only metadata
+    pub fn changed_since(&self, snapshot: &Model) -> bool {
+        let comparable = Model {
+            postpone_count: snapshot.postpone_count,
+            ..self.clone()
+        };
+        comparable != *snapshot
+    }
+}
+
 impl Entity {
     /// Scope for overdue tasks (due before today)
     /// Orders by: deleted status, completion status, due date
@@ -3,13 +3,14 @@
 //! This module provides a centralized way to manage icons throughout the application,
 //! supporting different themes like emoji, Unicode, and ASCII fallbacks.
 //!
-//! This is not really used right now as we were doing more important refactoring, but this is
-//! definitely something we will pick up in the future.
+//! The theme comes from `ui.icon_mode`; when it isn't set, [`IconTheme::detect`] falls
+//! back to ASCII on terminals that can't be relied on to draw Unicode symbols.
 
 use serde::{Deserialize, Serialize};
 
 /// Icon theme variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
 pub enum IconTheme {
     /// Emoji icons (colorful, modern look)
     Emoji,
@@ -20,6 +21,34 @@ pub enum IconTheme {
     Ascii,
 }
 
+impl IconTheme {
+    /// Theme for the current terminal, from the `TERM` and locale environment variables
+    #[must_use]
+    pub fn detect() -> Self {
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty());
+        Self::detect_from(std::env::var("TERM").ok().as_deref(), locale.as_deref())
+    }
+
+    /// ASCII for the Linux console and dumb terminals, or when the locale isn't UTF-8;
+    /// Unicode otherwise. Emoji are never picked, as their width varies between terminals
+    #[must_use]
+    pub fn detect_from(term: Option<&str>, locale: Option<&str>) -> Self {
+        let basic_terminal = matches!(term, Some("linux" | "dumb" | "vt100" | "vt220"));
+        let utf8_locale = locale.map_or(true, |locale| {
+            let locale = locale.to_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        });
+        if basic_terminal || !utf8_locale {
+            IconTheme::Ascii
+        } else {
+            IconTheme::Unicode
+        }
+    }
+}
+
 /// Task status icons
 #[derive(Debug, Clone)]
 pub struct TaskStatusIcons {
@@ -54,6 +83,8 @@ pub struct StatusIcons {
     pub recurring: &'static str,
     pub due_date: &'static str,
     pub duration: &'static str,
+    pub postponed: &'static str,
+    pub deadline: &'static str,
     pub sync_in_progress: &'static str,
     pub sync_success: &'static str,
     pub sync_error: &'static str,
@@ -143,6 +174,8 @@ impl IconService {
                 recurring: "🔄",
                 due_date: "📅",
                 duration: "⏱️",
+                postponed: "⏩×",
+                deadline: "🚩",
                 sync_in_progress: "🔄",
                 sync_success: "✅",
                 sync_error: "❌",
@@ -176,6 +209,8 @@ impl IconService {
                 recurring: "↻",
                 due_date: "◷",
                 duration: "⧖",
+                postponed: "»×",
+                deadline: "⇥",
                 sync_in_progress: "⟳",
                 sync_success: "✓",
                 sync_error: "✗",
@@ -209,6 +244,8 @@ impl IconService {
                 recurring: "~",
                 due_date: "@",
                 duration: "T",
+                postponed: ">>x",
+                deadline: "!",
                 sync_in_progress: "...",
                 sync_success: "+",
                 sync_error: "X",
@@ -296,4 +333,55 @@ impl IconService {
             IconTheme::Ascii => ">",
         }
     }
+
+    #[must_use]
+    pub fn postponed(&self) -> &'static str {
+        match self.current_theme {
+            IconTheme::Emoji => "⏩",
+            IconTheme::Unicode => "»",
+            IconTheme::Ascii => ">>",
+        }
+    }
+
+    #[must_use]
+    pub fn all_tasks(&self) -> &'static str {
+        match self.current_theme {
+            IconTheme::Emoji => "🗂️",
+            IconTheme::Unicode => "≡",
+            IconTheme::Ascii => "*",
+        }
+    }
+
+    /// Swatch drawn in a project's color before its name
+    #[must_use]
+    pub fn color_swatch(&self) -> &'static str {
+        match self.current_theme {
+            IconTheme::Emoji | IconTheme::Unicode => "●",
+            IconTheme::Ascii => "o",
+        }
+    }
+
+    /// Bar of `cells` characters, filled in proportion to `percent`
+    #[must_use]
+    pub fn progress_bar(&self, percent: u8, cells: usize) -> String {
+        let (filled, empty) = match self.current_theme {
+            IconTheme::Emoji | IconTheme::Unicode => ('█', '░'),
+            IconTheme::Ascii => ('#', '-'),
+        };
+        let full = usize::from(percent.min(100)) * cells / 100;
+        format!(
+            "{}{}",
+            filled.to_string().repeat(full),
+            empty.to_string().repeat(cells - full)
+        )
+    }
+
+    #[must_use]
+    pub fn filter(&self) -> &'static str {
+        match self.current_theme {
+            IconTheme::Emoji => "🔎",
+            IconTheme::Unicode => "⧩",
+            IconTheme::Ascii => "?",
+        }
+    }
 }
@@ -22,6 +22,9 @@ pub mod backend;
 /// Backend registry for managing multiple backend instances
 pub mod backend_registry;
 
+/// Headless subcommands for scripting (`terminalist today`, `terminalist add ...`)
+pub mod cli;
+
 /// Configuration module for managing application settings
 pub mod config;
 
@@ -52,6 +55,9 @@ pub mod todoist;
 /// Terminal user interface components and rendering
 pub mod ui;
 
+/// UI state persisted between sessions (e.g. collapsed sidebar sections)
+pub mod ui_state;
+
 /// Utility functions for date/time handling and other helpers
 pub mod utils;
 
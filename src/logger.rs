@@ -1,3 +1,4 @@
+use crate::config::LogFormat;
 use crate::constants::MEMORY_LOGS_LIMIT;
 use chrono::Utc;
 use log::Record;
@@ -12,7 +13,10 @@ static MEMORY_LOGS: once_cell::sync::Lazy<Arc<Mutex<VecDeque<String>>>> =
     once_cell::sync::Lazy::new(|| Arc::new(Mutex::new(VecDeque::with_capacity(5000))));
 
 /// Initialize the fern logger with file and memory outputs
-pub fn init_logger(enabled: bool) -> io::Result<()> {
+///
+/// The `format` only applies to the log file; the in-memory logs shown in the
+/// logs dialog always use the human-readable format.
+pub fn init_logger(enabled: bool, format: LogFormat) -> io::Result<()> {
     if !enabled {
         // Set up a logger that only writes to memory
         // Use Trace level so MemoryLogger receives all logs
@@ -34,18 +38,49 @@ pub fn init_logger(enabled: bool) -> io::Result<()> {
     // Open log file in append mode
     let log_file = OpenOptions::new().create(true).append(true).open(&log_file_path)?;
 
+    let file_dispatch = match format {
+        LogFormat::Pretty => pretty_dispatch(),
+        LogFormat::Json => fern::Dispatch::new().format(|out, message, record| {
+            out.finish(format_args!(
+                "{}",
+                format_json_line(
+                    &Utc::now().to_rfc3339(),
+                    record.level(),
+                    record.target(),
+                    &message.to_string()
+                )
+            ))
+        }),
+    };
+
     // Configure fern logger
     fern::Dispatch::new()
-        .format(|out, message, _record| out.finish(format_args!("[{}] {}", Utc::now().format("%H:%M:%S%.3f"), message)))
         .level(log::LevelFilter::Info)
-        .chain(log_file)
-        .chain(Box::new(MemoryLogger) as Box<dyn log::Log>)
+        .chain(file_dispatch.chain(log_file))
+        .chain(pretty_dispatch().chain(Box::new(MemoryLogger) as Box<dyn log::Log>))
         .apply()
         .map_err(io::Error::other)?;
 
     Ok(())
 }
 
+/// Dispatch producing human-readable `[HH:MM:SS.mmm] message` lines
+fn pretty_dispatch() -> fern::Dispatch {
+    fern::Dispatch::new()
+        .format(|out, message, _record| out.finish(format_args!("[{}] {}", Utc::now().format("%H:%M:%S%.3f"), message)))
+}
+
+/// Format a single log entry as a one-line JSON object
+pub fn format_json_line(timestamp: &str, level: log::Level, target: &str, message: &str) -> String {
+    serde_json::json!({
+        "timestamp": timestamp,
+        "level": level.to_string(),
+        "target": target,
+        "message": message,
+    })
+    .to_string()
+}
+
 /// Get the standard log file path
 pub fn get_log_file_path() -> io::Result<PathBuf> {
     let config_dir =
@@ -10,16 +10,32 @@
 //! * `-V, --version` - Show version information
 //! * `-d, --debug` - Use file-backed SQLite database for debugging
 //! * `--generate-config` - Generate a default configuration file
+//! * `--backend <name>` - Use the configured backend with this name
+//! * `--add-stdin` - Create one task per line read from stdin, then exit
+//! * `--import <file>` - Create the tasks of a Markdown checklist or Todoist JSON export, then exit
+//! * `--json` - Print the output of a command as JSON, in an envelope with a schema version
+//!
+//! # Commands
+//!
+//! Without a command the TUI starts; the commands run headless (see [`terminalist::cli`]):
+//!
+//! * `add <content> [--project <name>]` - Create a task (put the content after `--` when
+//!   it has words starting with a dash)
+//! * `today` - List overdue tasks and tasks due today
+//! * `list [--project <name>]` - List open tasks
+//! * `complete <id>` - Complete a task
 //!
 //! # Environment Variables
 //!
-//! * `TODOIST_API_TOKEN` - Your Todoist API token (required)
+//! * `TODOIST_API_TOKEN` - Your Todoist API token (required unless backends configure
+//!   their own `token_env`, such as the app password of a CalDAV backend)
 
 use anyhow::{Context, Result};
 use std::env;
-use std::sync::Arc;
-use terminalist::{backend_registry, config, logger, storage, sync, ui};
-use tokio::sync::Mutex;
+use std::io::Read;
+use terminalist::cli::{self, parse_value_arg};
+use terminalist::utils::import;
+use terminalist::{config, constants, logger, sync, ui};
 
 /// Main entry point for the Terminalist application.
 ///
@@ -41,10 +57,22 @@ use tokio::sync::Mutex;
 async fn main() -> Result<()> {
     // Parse command line arguments
     let args: Vec<String> = env::args().collect();
-    let show_help = args.iter().any(|arg| arg == "--help" || arg == "-h");
-    let show_version = args.iter().any(|arg| arg == "--version" || arg == "-V");
-    let debug_mode = args.iter().any(|arg| arg == "--debug" || arg == "-d");
-    let generate_config = args.iter().any(|arg| arg == "--generate-config");
+    // Arguments after `--` are task content, never options
+    let options = cli::option_args(&args);
+    let show_help = options.iter().any(|arg| arg == "--help" || arg == "-h");
+    let show_version = options.iter().any(|arg| arg == "--version" || arg == "-V");
+    let debug_mode = options.iter().any(|arg| arg == "--debug" || arg == "-d");
+    let generate_config = options.iter().any(|arg| arg == "--generate-config");
+    let add_stdin = options.iter().any(|arg| arg == "--add-stdin");
+    let json = options.iter().any(|arg| arg == "--json");
+    let (backend_name, import_path, command) = match (
+        parse_value_arg(&args, "--backend", "a backend name"),
+        parse_value_arg(&args, "--import", "a file path"),
+        cli::Command::parse(&args),
+    ) {
+        (Ok(name), Ok(path), Ok(command)) => (name, path, command),
+        (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => usage_error(&args, &e, json),
+    };
 
     if show_version {
         println!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
@@ -55,16 +83,30 @@ async fn main() -> Result<()> {
         println!("Terminalist - A TUI for Todoist");
         println!();
         println!("USAGE:");
-        println!("    terminalist [OPTIONS]");
+        println!("    terminalist [OPTIONS] [COMMAND]");
+        println!();
+        println!("COMMANDS (run without the TUI):");
+        println!("    add <content>        Create a task (in --project <name>, else the inbox)");
+        println!("    today                List overdue tasks and tasks due today");
+        println!("    list                 List open tasks (of --project <name>)");
+        println!("    complete <id>        Complete the task with this ID, as listed");
         println!();
         println!("OPTIONS:");
         println!("    -h, --help           Show this help message");
         println!("    -V, --version        Show version information");
         println!("    -d, --debug          Debug mode: keep database file and skip initial sync");
         println!("    --generate-config    Generate a default configuration file");
+        println!("    --backend <name>     Use the configured backend with this name");
+        println!("    --add-stdin          Create one inbox task per line of stdin, then exit");
+        println!("    --import <file>      Create the tasks of a Markdown checklist or Todoist");
+        println!("                         JSON export (.json), then exit");
+        println!("    --json               Print the output of a command as JSON");
+        println!("    --                   End of options: the rest is task content, even");
+        println!("                         words starting with a dash");
         println!();
         println!("ENVIRONMENT VARIABLES:");
         println!("    TODOIST_API_TOKEN    Your Todoist API token (required)");
+        println!("    TERMINALIST_DB_PATH  SQLite database file (overrides sync.database_path)");
         println!();
         return Ok(());
     }
@@ -88,47 +130,58 @@ async fn main() -> Result<()> {
     let config = config::Config::load()?;
 
     // Initialize logger
-    logger::init_logger(config.logging.enabled)?;
-
-    // Check if API token is set
-    if std::env::var("TODOIST_API_TOKEN").is_err() {
-        eprintln!("❌ Error: TODOIST_API_TOKEN environment variable not set");
-        eprintln!("\n💡 To use this app:");
-        eprintln!("1. Get your API token from https://todoist.com/prefs/integrations");
-        eprintln!("2. Set it as environment variable: export TODOIST_API_TOKEN=your_token_here");
-        eprintln!("3. Run the app again to see your actual data!");
-        eprintln!("\n💡 Use --help for more options");
+    logger::init_logger(config.logging.enabled, config.logging.format)?;
+
+    // Pick the backends for this session: the selected one, then the others with connect_all_backends
+    let session_backends = config.session_backends(backend_name.as_deref())?;
+    let Some(primary) = backend_connection(session_backends.first().copied()) else {
         return Ok(());
+    };
+    let mut others = Vec::new();
+    for backend in session_backends.iter().skip(1) {
+        let Some(connection) = backend_connection(Some(backend)) else {
+            return Ok(());
+        };
+        others.push(connection);
     }
 
-    // Initialize storage
-    let local_storage = Arc::new(Mutex::new(storage::LocalStorage::new(debug_mode).await?));
-
-    // Initialize backend registry
-    let backend_registry = Arc::new(backend_registry::BackendRegistry::new(local_storage.clone()));
-
-    // Create initial Todoist backend (DB is always fresh at startup)
-    let api_token = std::env::var("TODOIST_API_TOKEN")?;
-    let credentials = serde_json::json!({ "api_token": api_token }).to_string();
-
-    let backend_uuid = backend_registry
-        .add_backend(
-            "todoist".to_string(),
-            "My Todoist".to_string(),
-            credentials,
-            "{}".to_string(),
-        )
-        .await?;
-
-    // Create sync service with timeout
+    // Create sync service for the selected backend with timeout (DB is always fresh at startup)
     let timeout = tokio::time::Duration::from_secs(10);
     match tokio::time::timeout(
         timeout,
-        sync::SyncService::new(backend_registry.clone(), backend_uuid, debug_mode),
+        sync::SyncService::connect_with_credentials(
+            &primary.backend_type,
+            &primary.name,
+            primary.credentials,
+            config.sync.database_path().as_deref(),
+            debug_mode,
+        ),
     )
     .await
     {
-        Ok(Ok(sync_service)) => {
+        Ok(Ok(mut sync_service)) => {
+            sync_service.set_default_project(primary.default_project);
+            for backend in others {
+                sync_service
+                    .add_backend(
+                        &backend.backend_type,
+                        &backend.name,
+                        backend.credentials,
+                        backend.default_project,
+                    )
+                    .await?;
+            }
+            sync_service.set_retry_policy(config.sync.retry_policy());
+            sync_service.set_fail_on_section_error(config.sync.fail_on_section_error);
+            if let Some(command) = command {
+                return run_command(&sync_service, &command, json).await;
+            }
+            if add_stdin {
+                return add_tasks_from_stdin(&sync_service).await;
+            }
+            if let Some(path) = import_path {
+                return import_tasks_from_file(&sync_service, &path).await;
+            }
             ui::run_app(sync_service, config).await?;
         }
         Ok(Err(e)) => {
@@ -141,3 +194,188 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// Report invalid command-line arguments (an unknown command, a missing value) and exit
+/// with status 2, as is usual for usage errors. With `--json` the error is printed as a
+/// JSON envelope, like the failures of a command.
+fn usage_error(args: &[String], error: &anyhow::Error, json: bool) -> ! {
+    match cli::JsonOutput::usage_error(args, error).to_json() {
+        Ok(output) if json => println!("{}", output),
+        _ => {
+            eprintln!("❌ Error: {}", error);
+            eprintln!("\n💡 Use --help for more options");
+        }
+    }
+    std::process::exit(2);
+}
+
+/// What is needed to connect a configured backend
+struct BackendConnection {
+    backend_type: String,
+    name: String,
+    /// JSON credentials, as expected by the backend factory
+    credentials: String,
+    default_project: Option<String>,
+}
+
+/// Gather the credentials of a configured backend, or of the default Todoist account when
+/// `None`, from the config and the environment.
+///
+/// Returns `None` after explaining how to set the token when its environment variable isn't set.
+fn backend_connection(backend: Option<&config::BackendConfig>) -> Option<BackendConnection> {
+    let (backend_type, name, token_env, default_project) = match backend {
+        Some(backend) => (
+            backend.backend_type.clone(),
+            backend.name.clone(),
+            backend.token_env.clone(),
+            backend.default_project.clone(),
+        ),
+        None => (
+            "todoist".to_string(),
+            "My Todoist".to_string(),
+            "TODOIST_API_TOKEN".to_string(),
+            None,
+        ),
+    };
+
+    // Check if API token is set (the local backend has no account)
+    let api_token = if backend_type == "local" {
+        String::new()
+    } else {
+        let Ok(api_token) = std::env::var(&token_env) else {
+            eprintln!("❌ Error: {} environment variable not set", token_env);
+            eprintln!("\n💡 To use this app:");
+            if backend_type == "caldav" {
+                eprintln!("1. Create an app password on your CalDAV server");
+            } else {
+                eprintln!("1. Get your API token from {}", constants::TODOIST_TOKEN_URL);
+            }
+            eprintln!(
+                "2. Set it as environment variable: export {}=your_token_here",
+                token_env
+            );
+            eprintln!("3. Run the app again to see your actual data!");
+            eprintln!("\n💡 Use --help for more options");
+            return None;
+        };
+        api_token
+    };
+
+    // CalDAV needs the server and account besides the secret from the environment
+    let credentials = match backend {
+        _ if backend_type == "local" => serde_json::json!({}),
+        Some(backend) if backend_type == "caldav" => serde_json::json!({
+            "url": backend.url,
+            "username": backend.username,
+            "password": api_token,
+        }),
+        _ => serde_json::json!({ "api_token": api_token }),
+    };
+
+    Some(BackendConnection {
+        backend_type,
+        name,
+        credentials: credentials.to_string(),
+        default_project,
+    })
+}
+
+/// Run a headless command and print its output.
+///
+/// With `--json`, a failure is printed as a JSON envelope too (and exits with status 1),
+/// so scripts reading stdout always get JSON.
+///
+/// # Errors
+///
+/// Returns an error if the command fails without `--json`
+async fn run_command(sync_service: &sync::SyncService, command: &cli::Command, json: bool) -> Result<()> {
+    match cli::run(sync_service, command, json).await {
+        Ok(output) if output.is_empty() => Ok(()),
+        Ok(output) => {
+            println!("{}", output);
+            Ok(())
+        }
+        Err(e) if json => {
+            println!("{}", cli::JsonOutput::error(command, &e).to_json()?);
+            std::process::exit(1);
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Create one task per non-empty line of stdin and report each line's outcome.
+///
+/// Exits with status 1 if any line failed, so scripts can detect partial failures.
+///
+/// # Errors
+///
+/// Returns an error if stdin cannot be read or the initial sync fails
+async fn add_tasks_from_stdin(sync_service: &sync::SyncService) -> Result<()> {
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .context("Failed to read tasks from stdin")?;
+
+    // The local database starts empty, so load projects and labels before creating tasks
+    if let sync::SyncStatus::Error { message } = sync_service.sync().await.context("Initial sync failed")? {
+        anyhow::bail!("Initial sync failed: {}", message);
+    }
+
+    let results = sync_service.create_tasks_from_lines(&input).await;
+    let failed = results.iter().filter(|(_, result)| result.is_err()).count();
+    for (line, result) in &results {
+        match result {
+            Ok(()) => println!("✅ {}", line),
+            Err(e) => eprintln!("❌ {}: {}", line, e),
+        }
+    }
+    println!("Created {}/{} tasks", results.len() - failed, results.len());
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Create the tasks of a Markdown checklist or Todoist JSON export and report the
+/// outcome of each line.
+///
+/// Exits with status 1 if any line couldn't be parsed or created.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or parsed as a whole, or the initial sync fails
+async fn import_tasks_from_file(sync_service: &sync::SyncService, path: &str) -> Result<()> {
+    let content = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path))?;
+    let parsed = import::parse_file(path, &content).with_context(|| format!("Failed to parse {}", path))?;
+
+    // The local database starts empty, so load projects, sections and labels first
+    if let sync::SyncStatus::Error { message } = sync_service.sync().await.context("Initial sync failed")? {
+        anyhow::bail!("Initial sync failed: {}", message);
+    }
+
+    let results = sync_service.import_tasks(&parsed.tasks).await;
+    let mut failed = parsed.failures.len();
+    for (task, result) in parsed.tasks.iter().zip(&results) {
+        match result {
+            Ok(()) => println!("✅ {}", task.content),
+            Err(e) => {
+                failed += 1;
+                eprintln!("❌ line {}: {}: {}", task.line, task.content, e);
+            }
+        }
+    }
+    for failure in &parsed.failures {
+        eprintln!("❌ line {}: {}: {}", failure.line, failure.text, failure.reason);
+    }
+    let created = results.iter().filter(|result| result.is_ok()).count();
+    println!(
+        "Created {} tasks, {} failed, {} completed skipped",
+        created, failed, parsed.skipped
+    );
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
@@ -1,6 +1,7 @@
 //! Backend repository for database operations.
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
 use uuid::Uuid;
 
@@ -64,6 +65,47 @@ impl BackendRepository {
         Ok(backend.update(conn).await?)
     }
 
+    /// Store the token for the next incremental sync; `None` forces a full sync.
+    pub async fn set_sync_token<C>(conn: &C, uuid: &Uuid, sync_token: Option<String>) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        backend::Entity::update_many()
+            .col_expr(backend::Column::SyncToken, sea_orm::sea_query::Expr::value(sync_token))
+            .filter(backend::Column::Uuid.eq(*uuid))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Record when the backend last synced successfully.
+    pub async fn set_last_sync_at<C>(conn: &C, uuid: &Uuid, at: DateTime<Utc>) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        backend::Entity::update_many()
+            .col_expr(
+                backend::Column::LastSyncAt,
+                sea_orm::sea_query::Expr::value(Some(at.to_rfc3339())),
+            )
+            .filter(backend::Column::Uuid.eq(*uuid))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// When the backend last synced successfully, if it ever did.
+    pub async fn get_last_sync_at<C>(conn: &C, uuid: &Uuid) -> Result<Option<DateTime<Utc>>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(Self::get_by_uuid(conn, uuid)
+            .await?
+            .and_then(|backend| backend.last_sync_at)
+            .and_then(|at| DateTime::parse_from_rfc3339(&at).ok())
+            .map(|at| at.with_timezone(&Utc)))
+    }
+
     /// Delete a backend by UUID.
     pub async fn delete<C>(conn: &C, uuid: &Uuid) -> Result<()>
     where
@@ -1,10 +1,13 @@
 //! Label repository for database operations.
 
 use anyhow::Result;
-use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use sea_orm::{
+    ActiveValue, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
-use crate::entities::label;
+use crate::entities::{label, task_label};
 
 /// Repository for label-related database operations.
 pub struct LabelRepository;
@@ -23,12 +26,16 @@ impl LabelRepository {
             .ok_or_else(|| anyhow::anyhow!("Label not found: {}", uuid))
     }
 
-    /// Get all labels ordered by order index.
+    /// Get all labels, favorites first, then ordered by order index.
     pub async fn get_all<C>(conn: &C) -> Result<Vec<label::Model>>
     where
         C: ConnectionTrait,
     {
-        Ok(label::Entity::find().order_by_asc(label::Column::OrderIndex).all(conn).await?)
+        Ok(label::Entity::find()
+            .order_by_desc(label::Column::IsFavorite)
+            .order_by_asc(label::Column::OrderIndex)
+            .all(conn)
+            .await?)
     }
 
     /// Get a single label by UUID.
@@ -39,6 +46,18 @@ impl LabelRepository {
         Ok(label::Entity::find().filter(label::Column::Uuid.eq(*uuid)).one(conn).await?)
     }
 
+    /// Get a single label by remote_id and backend_uuid.
+    pub async fn get_by_remote_id<C>(conn: &C, backend_uuid: &Uuid, remote_id: &str) -> Result<Option<label::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(label::Entity::find()
+            .filter(label::Column::BackendUuid.eq(*backend_uuid))
+            .filter(label::Column::RemoteId.eq(remote_id))
+            .one(conn)
+            .await?)
+    }
+
     /// Get a single label by name.
     pub async fn get_by_name<C>(conn: &C, name: &str) -> Result<Option<label::Model>>
     where
@@ -47,6 +66,77 @@ impl LabelRepository {
         Ok(label::Entity::find().filter(label::Column::Name.eq(name)).one(conn).await?)
     }
 
+    /// Get the labels attached to a task.
+    pub async fn get_for_task<C>(conn: &C, task_uuid: &Uuid) -> Result<Vec<label::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(label::Entity::find()
+            .filter(
+                label::Column::Uuid.in_subquery(
+                    task_label::Entity::find()
+                        .filter(task_label::Column::TaskUuid.eq(*task_uuid))
+                        .select_only()
+                        .column(task_label::Column::LabelUuid)
+                        .into_query(),
+                ),
+            )
+            .order_by_asc(label::Column::OrderIndex)
+            .all(conn)
+            .await?)
+    }
+
+    /// Get the label names of every task that has labels, keyed by task UUID.
+    pub async fn get_names_by_task<C>(conn: &C) -> Result<HashMap<Uuid, Vec<String>>>
+    where
+        C: ConnectionTrait,
+    {
+        let names: HashMap<Uuid, String> = Self::get_all(conn).await?.into_iter().map(|l| (l.uuid, l.name)).collect();
+
+        let mut by_task: HashMap<Uuid, Vec<String>> = HashMap::new();
+        for relation in task_label::Entity::find().all(conn).await? {
+            if let Some(name) = names.get(&relation.label_uuid) {
+                by_task.entry(relation.task_uuid).or_default().push(name.clone());
+            }
+        }
+        Ok(by_task)
+    }
+
+    /// Replace the labels attached to a task with the labels named in `names`.
+    ///
+    /// Names without a matching local label are skipped.
+    pub async fn set_for_task<C>(conn: &C, task_uuid: &Uuid, names: &[String]) -> Result<()>
+    where
+        C: ConnectionTrait,
+    {
+        task_label::Entity::delete_many()
+            .filter(task_label::Column::TaskUuid.eq(*task_uuid))
+            .exec(conn)
+            .await?;
+
+        for name in names {
+            if let Some(label) = Self::get_by_name(conn, name).await? {
+                let relation = task_label::ActiveModel {
+                    task_uuid: ActiveValue::Set(*task_uuid),
+                    label_uuid: ActiveValue::Set(label.uuid),
+                };
+                task_label::Entity::insert(relation)
+                    .on_conflict(
+                        sea_orm::sea_query::OnConflict::columns([
+                            task_label::Column::TaskUuid,
+                            task_label::Column::LabelUuid,
+                        ])
+                        .do_nothing()
+                        .to_owned(),
+                    )
+                    .exec(conn)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Update a label in the database.
     pub async fn update<C>(conn: &C, label: label::ActiveModel) -> Result<label::Model>
     where
@@ -55,4 +145,34 @@ impl LabelRepository {
         use sea_orm::ActiveModelTrait;
         Ok(label.update(conn).await?)
     }
+
+    /// Delete a backend's labels whose remote_id is not in `remote_ids`.
+    ///
+    /// Used after a full fetch to drop labels that no longer exist remotely. They are removed
+    /// from their tasks as well.
+    ///
+    /// Returns the number of deleted labels.
+    pub async fn delete_missing_for_backend<C>(conn: &C, backend_uuid: &Uuid, remote_ids: &HashSet<&str>) -> Result<u64>
+    where
+        C: ConnectionTrait,
+    {
+        let missing: Vec<Uuid> = label::Entity::find()
+            .filter(label::Column::BackendUuid.eq(*backend_uuid))
+            .all(conn)
+            .await?
+            .into_iter()
+            .filter(|m| !m.remote_id.is_empty() && !remote_ids.contains(m.remote_id.as_str()))
+            .map(|m| m.uuid)
+            .collect();
+
+        if missing.is_empty() {
+            return Ok(0);
+        }
+
+        let result = label::Entity::delete_many()
+            .filter(label::Column::Uuid.is_in(missing))
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
 }
@@ -2,6 +2,7 @@
 
 use anyhow::Result;
 use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use std::collections::HashSet;
 use uuid::Uuid;
 
 use crate::entities::project;
@@ -75,4 +76,34 @@ impl ProjectRepository {
         project.delete(conn).await?;
         Ok(())
     }
+
+    /// Delete a backend's projects whose remote_id is not in `remote_ids`.
+    ///
+    /// Used after a full fetch to drop projects that no longer exist remotely. Their tasks and
+    /// sections are deleted with them.
+    ///
+    /// Returns the number of deleted projects.
+    pub async fn delete_missing_for_backend<C>(conn: &C, backend_uuid: &Uuid, remote_ids: &HashSet<&str>) -> Result<u64>
+    where
+        C: ConnectionTrait,
+    {
+        let missing: Vec<Uuid> = project::Entity::find()
+            .filter(project::Column::BackendUuid.eq(*backend_uuid))
+            .all(conn)
+            .await?
+            .into_iter()
+            .filter(|m| !m.remote_id.is_empty() && !remote_ids.contains(m.remote_id.as_str()))
+            .map(|m| m.uuid)
+            .collect();
+
+        if missing.is_empty() {
+            return Ok(0);
+        }
+
+        let result = project::Entity::delete_many()
+            .filter(project::Column::Uuid.is_in(missing))
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
 }
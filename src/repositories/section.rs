@@ -2,6 +2,7 @@
 
 use anyhow::Result;
 use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use std::collections::HashSet;
 use uuid::Uuid;
 
 use crate::entities::section;
@@ -67,4 +68,34 @@ impl SectionRepository {
             .await?
             .map(|s| s.remote_id))
     }
+
+    /// Delete a backend's sections whose remote_id is not in `remote_ids`.
+    ///
+    /// Used after a full fetch to drop sections that no longer exist remotely. Their tasks are
+    /// kept without a section.
+    ///
+    /// Returns the number of deleted sections.
+    pub async fn delete_missing_for_backend<C>(conn: &C, backend_uuid: &Uuid, remote_ids: &HashSet<&str>) -> Result<u64>
+    where
+        C: ConnectionTrait,
+    {
+        let missing: Vec<Uuid> = section::Entity::find()
+            .filter(section::Column::BackendUuid.eq(*backend_uuid))
+            .all(conn)
+            .await?
+            .into_iter()
+            .filter(|m| !m.remote_id.is_empty() && !remote_ids.contains(m.remote_id.as_str()))
+            .map(|m| m.uuid)
+            .collect();
+
+        if missing.is_empty() {
+            return Ok(0);
+        }
+
+        let result = section::Entity::delete_many()
+            .filter(section::Column::Uuid.is_in(missing))
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
 }
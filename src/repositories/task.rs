@@ -1,7 +1,10 @@
 //! Task repository for database operations.
 
 use anyhow::Result;
-use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+};
+use std::collections::HashSet;
 use uuid::Uuid;
 
 use crate::entities::{task, task_label};
@@ -35,6 +38,14 @@ impl TaskRepository {
             .await?)
     }
 
+    /// Count all stored tasks, including completed and deleted ones.
+    pub async fn count<C>(conn: &C) -> Result<u64>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(task::Entity::find().count(conn).await?)
+    }
+
     /// Get a single task by UUID.
     pub async fn get_by_id<C>(conn: &C, uuid: &Uuid) -> Result<Option<task::Model>>
     where
@@ -69,6 +80,18 @@ impl TaskRepository {
             .await?)
     }
 
+    /// Get the direct subtasks of a task.
+    pub async fn get_children<C>(conn: &C, parent_uuid: &Uuid) -> Result<Vec<task::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(task::Entity::find()
+            .filter(task::Column::ParentUuid.eq(*parent_uuid))
+            .order_by_asc(task::Column::OrderIndex)
+            .all(conn)
+            .await?)
+    }
+
     /// Search tasks by content or description.
     pub async fn search<C>(conn: &C, query: &str) -> Result<Vec<task::Model>>
     where
@@ -137,14 +160,14 @@ impl TaskRepository {
             .await?)
     }
 
-    /// Get tasks for the "Upcoming" view (overdue + today + next 3 months).
-    pub async fn get_for_upcoming<C>(conn: &C, today: &str, three_months_later: &str) -> Result<Vec<task::Model>>
+    /// Get tasks for the "Upcoming" view (overdue + today + up to `end_date`).
+    pub async fn get_for_upcoming<C>(conn: &C, today: &str, end_date: &str) -> Result<Vec<task::Model>>
     where
         C: ConnectionTrait,
     {
         let overdue_tasks = task::Entity::overdue(today).all(conn).await?;
         let today_tasks = task::Entity::due_today(today).all(conn).await?;
-        let future_tasks = task::Entity::due_between(today, three_months_later).all(conn).await?;
+        let future_tasks = task::Entity::due_between(today, end_date).all(conn).await?;
 
         let mut result = overdue_tasks;
         result.extend(today_tasks);
@@ -158,6 +181,21 @@ impl TaskRepository {
         Ok(result)
     }
 
+    /// Get open tasks that were postponed at least once, most postponed first.
+    pub async fn get_most_postponed<C>(conn: &C) -> Result<Vec<task::Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(task::Entity::find()
+            .filter(task::Column::PostponeCount.gt(0))
+            .filter(task::Column::IsCompleted.eq(false))
+            .filter(task::Column::IsDeleted.eq(false))
+            .order_by_desc(task::Column::PostponeCount)
+            .order_by_asc(task::Column::OrderIndex)
+            .all(conn)
+            .await?)
+    }
+
     /// Update a task in the database.
     pub async fn update<C>(conn: &C, task: task::ActiveModel) -> Result<task::Model>
     where
@@ -176,4 +214,34 @@ impl TaskRepository {
         task.delete(conn).await?;
         Ok(())
     }
+
+    /// Delete a backend's tasks whose remote_id is not in `remote_ids`.
+    ///
+    /// Used after a full fetch to drop tasks that no longer exist remotely. Tasks
+    /// without a remote_id (not yet created on the backend) are kept.
+    ///
+    /// Returns the number of deleted tasks.
+    pub async fn delete_missing_for_backend<C>(conn: &C, backend_uuid: &Uuid, remote_ids: &HashSet<&str>) -> Result<u64>
+    where
+        C: ConnectionTrait,
+    {
+        let missing: Vec<Uuid> = task::Entity::find()
+            .filter(task::Column::BackendUuid.eq(*backend_uuid))
+            .all(conn)
+            .await?
+            .into_iter()
+            .filter(|t| !t.remote_id.is_empty() && !remote_ids.contains(t.remote_id.as_str()))
+            .map(|t| t.uuid)
+            .collect();
+
+        if missing.is_empty() {
+            return Ok(0);
+        }
+
+        let result = task::Entity::delete_many()
+            .filter(task::Column::Uuid.is_in(missing))
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
 }
@@ -0,0 +1,232 @@
+//! Versioned schema migrations.
+//!
+//! The schema changes through an ordered list of [`MIGRATIONS`]. The ones a database
+//! hasn't seen yet are applied when it is opened, each in its own transaction, and
+//! recorded in the `schema_version` table. Kept databases (debug mode, the local backend)
+//! are upgraded in place instead of having to be deleted.
+//!
+//! Every step is idempotent: tables and indexes are created if missing, and columns are
+//! only added when absent. Databases created before versioning (with no `schema_version`
+//! table) already hold part of the schema, and go through all steps safely.
+//!
+//! To change the schema, update the entity and append a migration with the next
+//! version; never edit or reorder the migrations already released.
+
+use anyhow::{Context, Result};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Schema, Statement, TransactionTrait};
+
+use crate::entities::{backend, label, project, section, task, task_label};
+
+/// One schema change, applied once in version order
+pub struct Migration {
+    pub version: i64,
+    pub description: &'static str,
+    step: Step,
+}
+
+enum Step {
+    /// Create the tables of all entities, with their current columns
+    CreateTables,
+    /// Add a column to a table that was created without it
+    AddColumn {
+        table: &'static str,
+        column: &'static str,
+        column_type: &'static str,
+    },
+    /// Statements that are idempotent themselves (`IF NOT EXISTS`)
+    Sql(&'static [&'static str]),
+}
+
+/// All migrations, oldest first
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Create tables",
+        step: Step::CreateTables,
+    },
+    Migration {
+        version: 2,
+        description: "Add backends.last_sync_at",
+        step: Step::AddColumn {
+            table: "backends",
+            column: "last_sync_at",
+            column_type: "TEXT",
+        },
+    },
+    Migration {
+        version: 3,
+        description: "Add tasks.due_string",
+        step: Step::AddColumn {
+            table: "tasks",
+            column: "due_string",
+            column_type: "TEXT",
+        },
+    },
+    Migration {
+        version: 4,
+        description: "Index items by backend and remote id",
+        step: Step::Sql(&[
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_projects_backend_remote ON projects(backend_uuid, remote_id)",
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_sections_backend_remote ON sections(backend_uuid, remote_id)",
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_labels_backend_remote ON labels(backend_uuid, remote_id)",
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_tasks_backend_remote ON tasks(backend_uuid, remote_id)",
+        ]),
+    },
+    Migration {
+        version: 5,
+        description: "Add labels.color",
+        step: Step::AddColumn {
+            table: "labels",
+            column: "color",
+            column_type: "TEXT NOT NULL DEFAULT 'charcoal'",
+        },
+    },
+    Migration {
+        version: 6,
+        description: "Add tasks.postpone_count",
+        step: Step::AddColumn {
+            table: "tasks",
+            column: "postpone_count",
+            column_type: "INTEGER NOT NULL DEFAULT 0",
+        },
+    },
+    Migration {
+        version: 7,
+        description: "Add projects.color",
+        step: Step::AddColumn {
+            table: "projects",
+            column: "color",
+            column_type: "TEXT NOT NULL DEFAULT 'charcoal'",
+        },
+    },
+    Migration {
+        version: 8,
+        description: "Add backends.sync_token",
+        step: Step::AddColumn {
+            table: "backends",
+            column: "sync_token",
+            column_type: "TEXT",
+        },
+    },
+];
+
+/// Version of the newest migration, which a migrated database is at
+pub fn latest_version() -> i64 {
+    MIGRATIONS.last().map_or(0, |migration| migration.version)
+}
+
+/// Apply the migrations the database hasn't seen yet, in order
+///
+/// # Errors
+/// Returns an error if a migration fails; it is rolled back and the later ones aren't applied
+pub async fn run(conn: &DatabaseConnection) -> Result<()> {
+    conn.execute(Statement::from_string(
+        DbBackend::Sqlite,
+        "CREATE TABLE IF NOT EXISTS schema_version (
+            version INTEGER NOT NULL PRIMARY KEY,
+            description TEXT NOT NULL,
+            applied_at TEXT NOT NULL
+        )"
+        .to_owned(),
+    ))
+    .await?;
+
+    let current = current_version(conn).await?;
+    if current > latest_version() {
+        log::warn!(
+            "Storage: Database schema version {} is newer than this version of Terminalist knows ({})",
+            current,
+            latest_version()
+        );
+        return Ok(());
+    }
+
+    for migration in MIGRATIONS.iter().filter(|migration| migration.version > current) {
+        log::info!(
+            "Storage: Applying migration {} ({})",
+            migration.version,
+            migration.description
+        );
+        let txn = conn.begin().await?;
+        apply(&txn, &migration.step)
+            .await
+            .with_context(|| format!("Migration {} ({}) failed", migration.version, migration.description))?;
+        txn.execute(Statement::from_sql_and_values(
+            DbBackend::Sqlite,
+            "INSERT INTO schema_version (version, description, applied_at) VALUES (?, ?, ?)",
+            [
+                migration.version.into(),
+                migration.description.into(),
+                chrono::Utc::now().to_rfc3339().into(),
+            ],
+        ))
+        .await?;
+        txn.commit().await?;
+    }
+    Ok(())
+}
+
+/// Version of the last migration applied (0 for a new database)
+pub async fn current_version(conn: &impl ConnectionTrait) -> Result<i64> {
+    let row = conn
+        .query_one(Statement::from_string(
+            DbBackend::Sqlite,
+            "SELECT MAX(version) AS version FROM schema_version".to_owned(),
+        ))
+        .await?;
+    Ok(row
+        .and_then(|row| row.try_get::<Option<i64>>("", "version").ok())
+        .flatten()
+        .unwrap_or(0))
+}
+
+async fn apply(conn: &impl ConnectionTrait, step: &Step) -> Result<()> {
+    match step {
+        Step::CreateTables => {
+            let backend = conn.get_database_backend();
+            let schema = Schema::new(backend);
+
+            // Parent tables first, for the foreign keys
+            let mut table_statements = vec![
+                schema.create_table_from_entity(backend::Entity),
+                schema.create_table_from_entity(project::Entity),
+                schema.create_table_from_entity(section::Entity),
+                schema.create_table_from_entity(label::Entity),
+                schema.create_table_from_entity(task::Entity),
+                schema.create_table_from_entity(task_label::Entity),
+            ];
+            for statement in &mut table_statements {
+                statement.if_not_exists();
+                conn.execute(backend.build(&*statement)).await?;
+            }
+        }
+        Step::AddColumn {
+            table,
+            column,
+            column_type,
+        } => {
+            let existing = conn
+                .query_all(Statement::from_string(
+                    DbBackend::Sqlite,
+                    format!("PRAGMA table_info({table})"),
+                ))
+                .await?;
+            let present = existing
+                .iter()
+                .any(|row| row.try_get::<String>("", "name").is_ok_and(|name| name == *column));
+            if !present {
+                conn.execute(Statement::from_string(
+                    DbBackend::Sqlite,
+                    format!("ALTER TABLE {table} ADD COLUMN {column} {column_type}"),
+                ))
+                .await?;
+            }
+        }
+        Step::Sql(statements) => {
+            for sql in *statements {
+                conn.execute(Statement::from_string(DbBackend::Sqlite, sql.to_string())).await?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anyhow::{Context, Result};
+use sea_orm::{ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DbBackend, Statement};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub mod migrations;
+
+/// Environment variable overriding the database file path
+pub const DB_PATH_ENV: &str = "TERMINALIST_DB_PATH";
+
+/// Local storage manager for Todoist data
+pub struct LocalStorage {
+    pub conn: DatabaseConnection,
+}
+
+impl LocalStorage {
+    /// Get the database file path: the `TERMINALIST_DB_PATH` environment variable, then
+    /// the configured path, then the XDG data directory
+    ///
+    /// The parent directory is created if it doesn't exist.
+    pub fn get_db_path(configured: Option<&Path>) -> Result<PathBuf> {
+        let db_path = match std::env::var_os(DB_PATH_ENV).filter(|path| !path.is_empty()) {
+            Some(path) => PathBuf::from(path),
+            None => match configured {
+                Some(path) => path.to_path_buf(),
+                None => dirs::data_dir()
+                    .context("Failed to get XDG data directory")?
+                    .join("terminalist")
+                    .join("terminalist.db"),
+            },
+        };
+
+        if let Some(parent) = db_path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create database directory: {}", parent.display()))?;
+        }
+
+        Ok(db_path)
+    }
+
+    /// Initialize the local storage with SQLite database in the default location
+    pub async fn new(debug_mode: bool) -> Result<Self> {
+        Self::new_at(debug_mode, None).await
+    }
+
+    /// Initialize the local storage with SQLite database at `database_path`, unless
+    /// `TERMINALIST_DB_PATH` overrides it (see [`get_db_path`](Self::get_db_path))
+    pub async fn new_at(debug_mode: bool, database_path: Option<&Path>) -> Result<Self> {
+        let db_path = Self::get_db_path(database_path)?;
+
+        // In normal mode, always delete the database file to start fresh
+        // In debug mode, keep the database file if it exists (for debugging without re-syncing)
+        if !debug_mode && db_path.exists() {
+            std::fs::remove_file(&db_path)?;
+        }
+
+        let database_url = format!("sqlite:{}?mode=rwc", db_path.display());
+
+        let mut opt = ConnectOptions::new(database_url);
+        opt.max_connections(4)
+            .min_connections(1)
+            .connect_timeout(Duration::from_secs(8))
+            .idle_timeout(Duration::from_secs(3600))
+            .sqlx_logging(false);
+
+        Self::connect(opt).await
+    }
+
+    /// Open a SQLite database file that is kept between runs, creating it if needed
+    pub async fn open(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create database directory: {}", parent.display()))?;
+        }
+
+        let database_url = format!("sqlite:{}?mode=rwc", path.display());
+
+        let mut opt = ConnectOptions::new(database_url);
+        opt.max_connections(4)
+            .min_connections(1)
+            .connect_timeout(Duration::from_secs(8))
+            .idle_timeout(Duration::from_secs(3600))
+            .sqlx_logging(false);
+
+        Self::connect(opt).await
+    }
+
+    /// Initialize a throwaway in-memory SQLite database (used by tests)
+    pub async fn new_in_memory() -> Result<Self> {
+        let mut opt = ConnectOptions::new("sqlite::memory:");
+        // Each pooled connection would otherwise get its own empty database
+        opt.max_connections(1).min_connections(1).sqlx_logging(false);
+
+        Self::connect(opt).await
+    }
+
+    /// Connect with the given options and bring the schema up to date
+    async fn connect(opt: ConnectOptions) -> Result<Self> {
+        let conn = Database::connect(opt).await?;
+
+        // Enable foreign keys for SQLite
+        conn.execute(Statement::from_string(
+            DbBackend::Sqlite,
+            "PRAGMA foreign_keys = ON;".to_owned(),
+        ))
+        .await?;
+
+        migrations::run(&conn).await?;
+
+        Ok(LocalStorage { conn })
+    }
+
+    /// Version of the schema, i.e. of the last migration applied
+    pub async fn schema_version(&self) -> Result<i64> {
+        migrations::current_version(&self.conn).await
+    }
+}
@@ -0,0 +1,295 @@
+//! Batch operations with progress reporting and cancellation.
+//!
+//! Bulk actions issue one backend call per task, which can take a while for large
+//! selections. The batch methods in this module report `processed/total` after every
+//! item through a [`ProgressSender`] and stop issuing further backend calls as soon as
+//! the associated [`BatchCancel`] flag is set.
+
+use crate::sync::SyncService;
+use crate::utils::import::ImportedTask;
+use anyhow::Context;
+use log::{info, warn};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+/// Progress update emitted after each item of a batch operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchProgress {
+    /// Number of items processed so far (successfully or not)
+    pub processed: usize,
+    /// Total number of items in the batch
+    pub total: usize,
+}
+
+/// Channel used by batch methods to report their progress
+pub type ProgressSender = mpsc::UnboundedSender<BatchProgress>;
+
+/// Shared cancellation flag for a running batch operation.
+///
+/// Cloning the flag shares the underlying state, so the UI can keep one copy
+/// and cancel the batch while the background task holds the other.
+#[derive(Debug, Clone, Default)]
+pub struct BatchCancel(Arc<AtomicBool>);
+
+impl BatchCancel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation; items already in flight still finish.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Result of a batch operation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchOutcome {
+    pub succeeded: usize,
+    pub failed: usize,
+    pub total: usize,
+    pub cancelled: bool,
+}
+
+impl BatchOutcome {
+    /// Human-readable summary, e.g. "Completed 3/5 tasks (1 failed, cancelled)"
+    pub fn summary(&self, verb: &str) -> String {
+        let mut notes = Vec::new();
+        if self.failed > 0 {
+            notes.push(format!("{} failed", self.failed));
+        }
+        if self.cancelled {
+            notes.push("cancelled".to_string());
+        }
+
+        let base = format!("{} {}/{} tasks", verb, self.succeeded, self.total);
+        if notes.is_empty() {
+            base
+        } else {
+            format!("{} ({})", base, notes.join(", "))
+        }
+    }
+}
+
+/// Run `operation` for each task, reporting progress and honoring cancellation.
+pub(super) async fn run_batch<F, Fut>(
+    task_uuids: &[Uuid],
+    progress: &ProgressSender,
+    cancel: &BatchCancel,
+    mut operation: F,
+) -> BatchOutcome
+where
+    F: FnMut(Uuid) -> Fut,
+    Fut: Future<Output = anyhow::Result<()>>,
+{
+    let mut outcome = BatchOutcome {
+        total: task_uuids.len(),
+        ..Default::default()
+    };
+
+    let _ = progress.send(BatchProgress {
+        processed: 0,
+        total: outcome.total,
+    });
+
+    for (index, task_uuid) in task_uuids.iter().enumerate() {
+        if cancel.is_cancelled() {
+            info!("Batch: Cancelled after {}/{} tasks", index, outcome.total);
+            outcome.cancelled = true;
+            break;
+        }
+
+        match operation(*task_uuid).await {
+            Ok(()) => outcome.succeeded += 1,
+            Err(e) => {
+                warn!("Batch: Operation failed for task {}: {}", task_uuid, e);
+                outcome.failed += 1;
+            }
+        }
+
+        let _ = progress.send(BatchProgress {
+            processed: index + 1,
+            total: outcome.total,
+        });
+    }
+
+    outcome
+}
+
+impl SyncService {
+    /// Completes several tasks, one backend call per task.
+    ///
+    /// Like the other batch methods, each task is changed on the backend it belongs to.
+    ///
+    /// # Arguments
+    /// * `task_uuids` - Local UUIDs of the tasks to complete
+    /// * `progress` - Receives a [`BatchProgress`] update after each task
+    /// * `cancel` - Stops issuing further backend calls once set
+    pub async fn complete_tasks_batch(
+        &self,
+        task_uuids: &[Uuid],
+        progress: &ProgressSender,
+        cancel: &BatchCancel,
+    ) -> BatchOutcome {
+        run_batch(task_uuids, progress, cancel, |task_uuid| async move {
+            self.for_item(&task_uuid).await.complete_task(&task_uuid).await
+        })
+        .await
+    }
+
+    /// Sets the same due date on several tasks, one backend call per task.
+    ///
+    /// # Arguments
+    /// * `task_uuids` - Local UUIDs of the tasks to update
+    /// * `due_date` - New due date in YYYY-MM-DD format, or `None` to clear it
+    /// * `progress` - Receives a [`BatchProgress`] update after each task
+    /// * `cancel` - Stops issuing further backend calls once set
+    pub async fn update_tasks_due_date_batch(
+        &self,
+        task_uuids: &[Uuid],
+        due_date: Option<&str>,
+        progress: &ProgressSender,
+        cancel: &BatchCancel,
+    ) -> BatchOutcome {
+        run_batch(task_uuids, progress, cancel, |task_uuid| async move {
+            self.for_item(&task_uuid).await.update_task_due_date(&task_uuid, due_date).await
+        })
+        .await
+    }
+
+    /// Deletes several tasks, one backend call per task.
+    ///
+    /// # Arguments
+    /// * `task_uuids` - Local UUIDs of the tasks to delete
+    /// * `progress` - Receives a [`BatchProgress`] update after each task
+    /// * `cancel` - Stops issuing further backend calls once set
+    pub async fn delete_tasks_batch(
+        &self,
+        task_uuids: &[Uuid],
+        progress: &ProgressSender,
+        cancel: &BatchCancel,
+    ) -> BatchOutcome {
+        run_batch(task_uuids, progress, cancel, |task_uuid| async move {
+            self.for_item(&task_uuid).await.delete_task(&task_uuid).await
+        })
+        .await
+    }
+
+    /// Moves several tasks to the same project, one backend call per task.
+    ///
+    /// # Arguments
+    /// * `task_uuids` - Local UUIDs of the tasks to move
+    /// * `project_uuid` - Local UUID of the destination project
+    /// * `progress` - Receives a [`BatchProgress`] update after each task
+    /// * `cancel` - Stops issuing further backend calls once set
+    pub async fn move_tasks_batch(
+        &self,
+        task_uuids: &[Uuid],
+        project_uuid: &Uuid,
+        progress: &ProgressSender,
+        cancel: &BatchCancel,
+    ) -> BatchOutcome {
+        run_batch(task_uuids, progress, cancel, |task_uuid| async move {
+            self.for_item(&task_uuid).await.move_task(&task_uuid, project_uuid).await
+        })
+        .await
+    }
+
+    /// Creates one task per non-empty line of `input`, without a project (so in the
+    /// default project or the inbox).
+    ///
+    /// Each line goes through the same inline `@label` parsing as the task creation
+    /// dialog. Blank lines are skipped and a failed line doesn't stop the next ones.
+    ///
+    /// # Returns
+    /// Each attempted line (trimmed) with the result of creating its task, in input order
+    pub async fn create_tasks_from_lines(&self, input: &str) -> Vec<(String, anyhow::Result<()>)> {
+        let mut results = Vec::new();
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let result = self.create_task(line, None).await;
+            if let Err(e) = &result {
+                warn!("Batch: Failed to create task from line '{}': {}", line, e);
+            }
+            results.push((line.to_string(), result));
+        }
+        results
+    }
+
+    /// Creates the tasks read from an import file, in order.
+    ///
+    /// Projects are looked up by name (ignoring case) and created when missing. Backends
+    /// can't create sections, so a section must already exist in the task's project and is
+    /// left out otherwise; a section given without a project names a project instead. A
+    /// failed task doesn't stop the next ones.
+    ///
+    /// # Returns
+    /// The result of creating each task, in the order given
+    pub async fn import_tasks(&self, tasks: &[ImportedTask]) -> Vec<anyhow::Result<()>> {
+        let mut results = Vec::new();
+        for task in tasks {
+            let result = self.import_task(task).await;
+            if let Err(e) = &result {
+                warn!(
+                    "Import: Failed to create task from line {} '{}': {}",
+                    task.line, task.content, e
+                );
+            }
+            results.push(result);
+        }
+        results
+    }
+
+    async fn import_task(&self, task: &ImportedTask) -> anyhow::Result<()> {
+        let (project_name, section_name) = match (&task.project, &task.section) {
+            (None, Some(section)) => (Some(section), None),
+            (project, section) => (project.as_ref(), section.as_ref()),
+        };
+        let project_uuid = match project_name {
+            Some(name) => Some(self.find_or_create_project(name).await?),
+            None => None,
+        };
+        let section_uuid = match (project_uuid, section_name) {
+            (Some(project_uuid), Some(name)) => self
+                .get_sections_for_project(&project_uuid)
+                .await?
+                .into_iter()
+                .find(|section| section.name.eq_ignore_ascii_case(name))
+                .map(|section| section.uuid),
+            _ => None,
+        };
+        self.create_task_with_details(
+            &task.content,
+            project_uuid,
+            section_uuid,
+            task.due.as_deref(),
+            task.priority,
+            None,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// The project of this backend named `name` (ignoring case), created if there is none
+    async fn find_or_create_project(&self, name: &str) -> anyhow::Result<Uuid> {
+        let find = |projects: Vec<crate::entities::project::Model>| {
+            projects
+                .into_iter()
+                .filter(|project| project.backend_uuid == self.backend_uuid)
+                .find(|project| project.name.eq_ignore_ascii_case(name))
+                .map(|project| project.uuid)
+        };
+        if let Some(uuid) = find(self.get_projects().await?) {
+            return Ok(uuid);
+        }
+        info!("Import: Creating project '{}'", name);
+        self.create_project(name, None).await?;
+        find(self.get_projects().await?).with_context(|| format!("Project '{}' not found after creating it", name))
+    }
+}
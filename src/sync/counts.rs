@@ -0,0 +1,90 @@
+//! Open task counts shown next to sidebar entries, and the completion of projects.
+
+use crate::entities::task;
+use crate::repositories::{LabelRepository, TaskRepository};
+use crate::sync::SyncService;
+use anyhow::Result;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Number of open tasks behind each sidebar entry
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SidebarCounts {
+    pub today: usize,
+    pub tomorrow: usize,
+    pub upcoming: usize,
+    /// Open tasks of every backend, subtasks included
+    pub all: usize,
+    /// Open tasks per project UUID, subtasks included
+    pub projects: HashMap<Uuid, usize>,
+    /// Open tasks per label UUID
+    pub labels: HashMap<Uuid, usize>,
+    /// Completed tasks still in local storage per project UUID, subtasks included
+    pub completed_projects: HashMap<Uuid, usize>,
+}
+
+impl SidebarCounts {
+    /// Share of a project's tasks that are completed, in percent, or `None` if it has no tasks
+    pub fn project_progress(&self, project_uuid: &Uuid) -> Option<u8> {
+        let open = self.projects.get(project_uuid).copied().unwrap_or(0);
+        let completed = self.completed_projects.get(project_uuid).copied().unwrap_or(0);
+        let total = open + completed;
+        (total > 0).then(|| u8::try_from(completed * 100 / total).unwrap_or(100))
+    }
+}
+
+fn is_open(task: &task::Model) -> bool {
+    !task.is_completed && !task.is_deleted
+}
+
+impl SyncService {
+    /// Count the open tasks of the Today, Tomorrow and Upcoming views and of every project
+    /// and label, as listed when the view is opened, and the completed tasks of every project.
+    ///
+    /// Like the views themselves, the date-based counts leave out tasks carrying
+    /// `someday_label`, and Upcoming looks `upcoming_days` ahead.
+    ///
+    /// # Errors
+    /// Returns an error if local storage access fails
+    pub async fn get_sidebar_counts(&self, someday_label: &str, upcoming_days: u32) -> Result<SidebarCounts> {
+        let count_open = |tasks: Vec<task::Model>| tasks.iter().filter(|task| is_open(task)).count();
+        let today = self
+            .exclude_tasks_with_label(self.get_tasks_for_today().await?, someday_label)
+            .await?;
+        let tomorrow = self
+            .exclude_tasks_with_label(self.get_tasks_for_tomorrow().await?, someday_label)
+            .await?;
+        let upcoming = self
+            .exclude_tasks_with_label(self.get_tasks_for_upcoming(upcoming_days).await?, someday_label)
+            .await?;
+
+        let mut counts = SidebarCounts {
+            today: count_open(today),
+            tomorrow: count_open(tomorrow),
+            upcoming: count_open(upcoming),
+            ..Default::default()
+        };
+
+        let storage = self.storage.lock().await;
+        let tasks = TaskRepository::get_all(&storage.conn).await?;
+        let labels = LabelRepository::get_all(&storage.conn).await?;
+        let task_labels = LabelRepository::get_names_by_task(&storage.conn).await?;
+        drop(storage);
+
+        let label_uuids: HashMap<&str, Uuid> = labels.iter().map(|l| (l.name.as_str(), l.uuid)).collect();
+        for task in tasks.iter().filter(|task| task.is_completed && !task.is_deleted) {
+            *counts.completed_projects.entry(task.project_uuid).or_default() += 1;
+        }
+        for task in tasks.iter().filter(|task| is_open(task)) {
+            counts.all += 1;
+            *counts.projects.entry(task.project_uuid).or_default() += 1;
+            for name in task_labels.get(&task.uuid).into_iter().flatten() {
+                if let Some(label_uuid) = label_uuids.get(name.as_str()) {
+                    *counts.labels.entry(*label_uuid).or_default() += 1;
+                }
+            }
+        }
+
+        Ok(counts)
+    }
+}
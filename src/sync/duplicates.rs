@@ -0,0 +1,67 @@
+//! Duplicate task detection.
+//!
+//! Syncs and imports can leave several open tasks with the same content in the same
+//! project. This module groups such tasks so the user can review and delete the extras.
+
+use crate::entities::task;
+use crate::sync::SyncService;
+use anyhow::Result;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// A set of open tasks in one project whose content matches after normalization
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateGroup {
+    pub project_uuid: Uuid,
+    /// Normalized content shared by every task in the group
+    pub normalized_content: String,
+    /// Tasks in the group, in local order; the first one is treated as the original
+    pub tasks: Vec<task::Model>,
+}
+
+/// Normalize task content for duplicate comparison (trimmed, case-insensitive)
+pub fn normalize_content(content: &str) -> String {
+    content.trim().to_lowercase()
+}
+
+/// Group open tasks by project and normalized content, keeping only groups with duplicates.
+///
+/// Groups are returned in the order their first task appears in `tasks`.
+pub fn group_duplicate_tasks(tasks: Vec<task::Model>) -> Vec<DuplicateGroup> {
+    let mut groups: Vec<DuplicateGroup> = Vec::new();
+    let mut index_by_key: HashMap<(Uuid, String), usize> = HashMap::new();
+
+    for task in tasks.into_iter().filter(|t| !t.is_completed && !t.is_deleted) {
+        let key = (task.project_uuid, normalize_content(&task.content));
+        match index_by_key.get(&key) {
+            Some(&index) => groups[index].tasks.push(task),
+            None => {
+                index_by_key.insert(key.clone(), groups.len());
+                groups.push(DuplicateGroup {
+                    project_uuid: key.0,
+                    normalized_content: key.1,
+                    tasks: vec![task],
+                });
+            }
+        }
+    }
+
+    groups.retain(|group| group.tasks.len() > 1);
+    groups
+}
+
+impl SyncService {
+    /// Finds open tasks that share the same content within a project.
+    ///
+    /// Content is compared after trimming whitespace and ignoring case.
+    ///
+    /// # Returns
+    /// One [`DuplicateGroup`] per set of duplicates (each with at least two tasks)
+    ///
+    /// # Errors
+    /// Returns an error if local storage access fails
+    pub async fn find_duplicate_tasks(&self) -> Result<Vec<DuplicateGroup>> {
+        let tasks = self.get_all_tasks().await?;
+        Ok(group_duplicate_tasks(tasks))
+    }
+}
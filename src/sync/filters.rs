@@ -0,0 +1,303 @@
+//! Saved filter queries.
+//!
+//! Filters from the `[[filters]]` config entries use a subset of the Todoist filter
+//! syntax, such as `(today | overdue) & @work`. Supported terms:
+//!
+//! - `today`, `tomorrow`, `overdue` (or `od`): tasks due on that day, or before now
+//! - `no date`: tasks without a due date
+//! - `N days` or `next N days`: tasks due from today through the next N - 1 days
+//! - `due before: DATE`, `due after: DATE`: DATE is anything a due date prompt accepts
+//!   ("fri", "in 2 weeks", "2025-01-31")
+//! - `recurring`: recurring tasks
+//! - `p1` to `p4`: tasks with that priority
+//! - `@label`, `no labels`: tasks with the label (ignoring case), or with none
+//! - `#project`, `##project`: tasks in the project, or in it and its subprojects
+//! - `search: text`: tasks whose content contains the text (ignoring case)
+//!
+//! Terms combine with `&` (and), `|` (or), `!` (not) and parentheses; `!` binds tighter
+//! than `&`, which binds tighter than `|`. Filters only match open tasks. Other Todoist
+//! terms, wildcards and `,`-separated lists are rejected with an error.
+
+use crate::entities::{project, task};
+use crate::repositories::{LabelRepository, ProjectRepository, TaskRepository};
+use crate::sync::quick_add;
+use crate::sync::SyncService;
+use crate::utils::datetime;
+use anyhow::Result;
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// A single condition of a filter query
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterTerm {
+    Today,
+    Tomorrow,
+    Overdue,
+    NoDate,
+    /// Due from today through the given number of days (today included)
+    NextDays(u32),
+    DueBefore(NaiveDate),
+    DueAfter(NaiveDate),
+    Recurring,
+    /// Priority on the API scale (4 is p1)
+    Priority(i32),
+    Label(String),
+    NoLabels,
+    Project {
+        name: String,
+        with_subprojects: bool,
+    },
+    Search(String),
+}
+
+/// A parsed filter query
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterQuery {
+    Term(FilterTerm),
+    Not(Box<FilterQuery>),
+    And(Box<FilterQuery>, Box<FilterQuery>),
+    Or(Box<FilterQuery>, Box<FilterQuery>),
+}
+
+/// Data a filter is evaluated against besides the task itself
+pub struct FilterContext<'a> {
+    pub now: DateTime<Local>,
+    pub projects: &'a [project::Model],
+    /// Label names of each task
+    pub task_labels: &'a HashMap<Uuid, Vec<String>>,
+}
+
+impl FilterTerm {
+    /// Parse one term, such as `@work` or `due before: fri`
+    fn parse(text: &str, today: NaiveDate) -> Result<Self> {
+        let lower = text.to_lowercase();
+        match lower.as_str() {
+            "today" => return Ok(Self::Today),
+            "tomorrow" => return Ok(Self::Tomorrow),
+            "overdue" | "od" => return Ok(Self::Overdue),
+            "no date" => return Ok(Self::NoDate),
+            "recurring" => return Ok(Self::Recurring),
+            "no labels" => return Ok(Self::NoLabels),
+            _ => {}
+        }
+
+        if text.contains(',') {
+            anyhow::bail!("Filter lists separated by ',' are not supported (in '{}')", text);
+        }
+        if text.contains('*') {
+            anyhow::bail!("Wildcards are not supported in filters (in '{}')", text);
+        }
+
+        if let Some(priority) = quick_add::parse_priority(text) {
+            return Ok(Self::Priority(priority));
+        }
+        if let Some(name) = text.strip_prefix("##") {
+            return Ok(Self::Project {
+                name: required_name(name, text)?,
+                with_subprojects: true,
+            });
+        }
+        if let Some(name) = text.strip_prefix('#') {
+            return Ok(Self::Project {
+                name: required_name(name, text)?,
+                with_subprojects: false,
+            });
+        }
+        if let Some(name) = text.strip_prefix('@') {
+            return Ok(Self::Label(required_name(name, text)?));
+        }
+        if let Some(date) = lower.strip_prefix("due before:") {
+            return Ok(Self::DueBefore(parse_filter_date(date, today)?));
+        }
+        if let Some(date) = lower.strip_prefix("due after:") {
+            return Ok(Self::DueAfter(parse_filter_date(date, today)?));
+        }
+        if lower.starts_with("search:") {
+            return Ok(Self::Search(required_name(&text["search:".len()..], text)?));
+        }
+
+        // "7 days" or "next 7 days"
+        let words: Vec<&str> = lower.strip_prefix("next ").unwrap_or(&lower).split_whitespace().collect();
+        if let [amount, "day" | "days"] = words.as_slice() {
+            if let Ok(days) = amount.parse::<u32>() {
+                return Ok(Self::NextDays(days));
+            }
+        }
+
+        anyhow::bail!("Unsupported filter term '{}'", text)
+    }
+
+    fn matches(&self, task: &task::Model, context: &FilterContext) -> bool {
+        let today = context.now.date_naive();
+        let due = task.due();
+        let due_date = due.as_ref().map(|due| due.date());
+        match self {
+            Self::Today => due_date == Some(today),
+            Self::Tomorrow => due_date == Some(today + Duration::days(1)),
+            Self::Overdue => due.is_some_and(|due| due.is_overdue(context.now)),
+            Self::NoDate => due.is_none(),
+            Self::NextDays(days) => {
+                due_date.is_some_and(|date| date >= today && date < today + Duration::days(i64::from(*days)))
+            }
+            Self::DueBefore(limit) => due_date.is_some_and(|date| date < *limit),
+            Self::DueAfter(limit) => due_date.is_some_and(|date| date > *limit),
+            Self::Recurring => task.is_recurring,
+            Self::Priority(priority) => task.priority == *priority,
+            Self::Label(name) => context
+                .task_labels
+                .get(&task.uuid)
+                .is_some_and(|labels| labels.iter().any(|label| label.eq_ignore_ascii_case(name))),
+            Self::NoLabels => context.task_labels.get(&task.uuid).map_or(0, Vec::len) == 0,
+            Self::Project { name, with_subprojects } => {
+                let mut project_uuid = Some(task.project_uuid);
+                while let Some(project) = project_uuid.and_then(|uuid| context.projects.iter().find(|p| p.uuid == uuid))
+                {
+                    if project.name.eq_ignore_ascii_case(name) {
+                        return true;
+                    }
+                    if !with_subprojects {
+                        break;
+                    }
+                    project_uuid = project.parent_uuid;
+                }
+                false
+            }
+            Self::Search(text) => task.content.to_lowercase().contains(&text.to_lowercase()),
+        }
+    }
+}
+
+/// Name following a term's prefix, which can't be empty
+fn required_name(name: &str, term: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        anyhow::bail!("Filter term '{}' is missing a name", term);
+    }
+    Ok(name.to_string())
+}
+
+fn parse_filter_date(text: &str, today: NaiveDate) -> Result<NaiveDate> {
+    datetime::parse_natural_date(text.trim(), today)
+        .ok_or_else(|| anyhow::anyhow!("Could not understand date '{}' in filter", text.trim()))
+}
+
+impl FilterQuery {
+    /// Parse a filter query; relative dates in `due before:`/`due after:` are resolved
+    /// against `today`
+    ///
+    /// # Errors
+    /// Returns an error naming the problem for unsupported terms and malformed queries
+    pub fn parse(query: &str, today: NaiveDate) -> Result<Self> {
+        let mut parser = Parser { rest: query, today };
+        let filter = parser.parse_or()?;
+        parser.skip_whitespace();
+        match parser.rest.chars().next() {
+            None => Ok(filter),
+            Some(')') => anyhow::bail!("Unmatched ')' in filter '{}'", query),
+            Some(_) => anyhow::bail!("Expected '&' or '|' before '{}' in filter '{}'", parser.rest, query),
+        }
+    }
+
+    /// Whether an open task matches the query; completed and deleted tasks never match
+    pub fn matches(&self, task: &task::Model, context: &FilterContext) -> bool {
+        if task.is_completed || task.is_deleted {
+            return false;
+        }
+        self.matches_open(task, context)
+    }
+
+    fn matches_open(&self, task: &task::Model, context: &FilterContext) -> bool {
+        match self {
+            Self::Term(term) => term.matches(task, context),
+            Self::Not(inner) => !inner.matches_open(task, context),
+            Self::And(left, right) => left.matches_open(task, context) && right.matches_open(task, context),
+            Self::Or(left, right) => left.matches_open(task, context) || right.matches_open(task, context),
+        }
+    }
+}
+
+/// Recursive descent parser over the remaining query text
+struct Parser<'a> {
+    rest: &'a str,
+    today: NaiveDate,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    /// Consume `token` if it comes next
+    fn eat(&mut self, token: char) -> bool {
+        self.skip_whitespace();
+        match self.rest.strip_prefix(token) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<FilterQuery> {
+        let mut filter = self.parse_and()?;
+        while self.eat('|') {
+            filter = FilterQuery::Or(Box::new(filter), Box::new(self.parse_and()?));
+        }
+        Ok(filter)
+    }
+
+    fn parse_and(&mut self) -> Result<FilterQuery> {
+        let mut filter = self.parse_unary()?;
+        while self.eat('&') {
+            filter = FilterQuery::And(Box::new(filter), Box::new(self.parse_unary()?));
+        }
+        Ok(filter)
+    }
+
+    fn parse_unary(&mut self) -> Result<FilterQuery> {
+        if self.eat('!') {
+            return Ok(FilterQuery::Not(Box::new(self.parse_unary()?)));
+        }
+        if self.eat('(') {
+            let filter = self.parse_or()?;
+            if !self.eat(')') {
+                anyhow::bail!("Missing ')' in filter");
+            }
+            return Ok(filter);
+        }
+
+        // A term runs until the next operator or parenthesis
+        let end = self.rest.find(['&', '|', '(', ')']).unwrap_or(self.rest.len());
+        let text = self.rest[..end].trim();
+        if text.is_empty() {
+            anyhow::bail!("Expected a filter term");
+        }
+        self.rest = &self.rest[end..];
+        Ok(FilterQuery::Term(FilterTerm::parse(text, self.today)?))
+    }
+}
+
+impl SyncService {
+    /// Open tasks matching a saved filter query (see [`crate::sync::filters`] for the syntax).
+    ///
+    /// # Errors
+    /// Returns an error if the query can't be parsed or local storage access fails
+    pub async fn get_tasks_for_filter(&self, query: &str) -> Result<Vec<task::Model>> {
+        let now = datetime::now();
+        let filter = FilterQuery::parse(query, now.date_naive())?;
+
+        let storage = self.storage.lock().await;
+        let tasks = TaskRepository::get_all(&storage.conn).await?;
+        let projects = ProjectRepository::get_all(&storage.conn).await?;
+        let task_labels = LabelRepository::get_names_by_task(&storage.conn).await?;
+
+        let context = FilterContext {
+            now,
+            projects: &projects,
+            task_labels: &task_labels,
+        };
+        Ok(tasks.into_iter().filter(|task| filter.matches(task, &context)).collect())
+    }
+}
@@ -1,11 +1,46 @@
 use crate::entities::label;
 use crate::repositories::LabelRepository;
-use crate::sync::SyncService;
+use crate::sync::{validate_name, SyncService};
 use anyhow::Result;
 use log::info;
 use sea_orm::{ActiveValue, EntityTrait, IntoActiveModel};
+use std::collections::HashMap;
 use uuid::Uuid;
 
+/// Split inline `@label` tokens out of task content.
+///
+/// Returns the content with the tokens removed and the label names in order of first
+/// appearance. Names are de-duplicated case-insensitively; a lone `@` is kept as text.
+pub fn extract_inline_labels(content: &str) -> (String, Vec<String>) {
+    let mut words = Vec::new();
+    let mut names: Vec<String> = Vec::new();
+
+    for word in content.split_whitespace() {
+        match word.strip_prefix('@') {
+            Some(name) if !name.is_empty() => {
+                if !names.iter().any(|n| n.eq_ignore_ascii_case(name)) {
+                    names.push(name.to_string());
+                }
+            }
+            _ => words.push(word),
+        }
+    }
+
+    (words.join(" "), names)
+}
+
+/// Label names that match none of `labels` (compared case-insensitively), without duplicates
+pub fn unknown_label_names(names: &[String], labels: &[label::Model]) -> Vec<String> {
+    let mut unknown: Vec<String> = Vec::new();
+    for name in names {
+        let exists = labels.iter().any(|l| l.name.eq_ignore_ascii_case(name));
+        if !exists && !unknown.iter().any(|n| n.eq_ignore_ascii_case(name)) {
+            unknown.push(name.clone());
+        }
+    }
+    unknown
+}
+
 impl SyncService {
     /// Get all labels from local storage (fast)
     pub async fn get_labels(&self) -> Result<Vec<label::Model>> {
@@ -13,6 +48,18 @@ impl SyncService {
         LabelRepository::get_all(&storage.conn).await
     }
 
+    /// Get the labels of a task from local storage
+    pub async fn get_labels_for_task(&self, task_uuid: &Uuid) -> Result<Vec<label::Model>> {
+        let storage = self.storage.lock().await;
+        LabelRepository::get_for_task(&storage.conn, task_uuid).await
+    }
+
+    /// Get the label names of every task that has labels, keyed by task UUID
+    pub async fn get_label_names_by_task(&self) -> Result<HashMap<Uuid, Vec<String>>> {
+        let storage = self.storage.lock().await;
+        LabelRepository::get_names_by_task(&storage.conn).await
+    }
+
     /// Creates a new label via the remote backend and stores it locally.
     ///
     /// This method creates a label remotely and immediately stores it in local storage
@@ -23,21 +70,17 @@ impl SyncService {
     /// * `name` - The name of the new label
     ///
     /// # Errors
-    /// Returns an error if the backend call fails or local storage update fails
+    /// Returns an error if the name is empty, or if the backend call or local storage update fails
     pub async fn create_label(&self, name: &str) -> Result<()> {
+        let name = validate_name("Label name", name)?;
         info!("Backend: Creating label '{}'", name);
 
         // Create label via backend using the CreateLabelArgs structure
         let label_args = crate::backend::CreateLabelArgs {
-            name: name.to_string(),
+            name,
             is_favorite: None,
         };
-        let api_label = self
-            .get_backend()
-            .await?
-            .create_label(label_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        let api_label = self.get_backend().await?.create_label(label_args).await?;
 
         // Store the created label in local database immediately for UI refresh
         info!("Storage: Storing new label locally with ID {}", api_label.remote_id);
@@ -48,6 +91,7 @@ impl SyncService {
             backend_uuid: ActiveValue::Set(self.backend_uuid),
             remote_id: ActiveValue::Set(api_label.remote_id),
             name: ActiveValue::Set(api_label.name),
+            color: ActiveValue::Set(api_label.color),
             order_index: ActiveValue::Set(api_label.order_index),
             is_favorite: ActiveValue::Set(api_label.is_favorite),
         };
@@ -56,7 +100,12 @@ impl SyncService {
         let mut insert = label::Entity::insert(local_label);
         insert = insert.on_conflict(
             OnConflict::columns([label::Column::BackendUuid, label::Column::RemoteId])
-                .update_columns([label::Column::Name, label::Column::OrderIndex, label::Column::IsFavorite])
+                .update_columns([
+                    label::Column::Name,
+                    label::Column::Color,
+                    label::Column::OrderIndex,
+                    label::Column::IsFavorite,
+                ])
                 .to_owned(),
         );
         insert.exec(&storage.conn).await?;
@@ -64,35 +113,115 @@ impl SyncService {
         Ok(())
     }
 
-    /// Update label content (name only for now)
+    /// Maps label names to existing labels, creating the ones that don't exist yet.
+    ///
+    /// Label names are unique ignoring case, so a name matching an existing label in a
+    /// different case reuses that label instead of creating a near-duplicate.
+    ///
+    /// # Arguments
+    /// * `names` - Label names as typed by the user
+    ///
+    /// # Returns
+    /// The names of the matching labels, as stored, in the order given
+    ///
+    /// # Errors
+    /// Returns an error if local storage access or creating a missing label fails
+    pub async fn resolve_or_create_labels(&self, names: &[String]) -> Result<Vec<String>> {
+        let existing = self.get_labels().await?;
+        let mut resolved: Vec<String> = Vec::new();
+
+        for name in names {
+            let name = match existing.iter().find(|l| l.name.eq_ignore_ascii_case(name)) {
+                Some(label) => label.name.clone(),
+                None => {
+                    if resolved.iter().any(|n| n.eq_ignore_ascii_case(name)) {
+                        continue;
+                    }
+                    self.create_label(name).await?;
+                    name.clone()
+                }
+            };
+            if !resolved.contains(&name) {
+                resolved.push(name);
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// Update label content (name only)
     pub async fn update_label_content(&self, label_uuid: &Uuid, name: &str) -> Result<()> {
-        info!("Backend: Updating label name for UUID {} to '{}'", label_uuid, name);
+        self.update_label(label_uuid, Some(name), None, None).await
+    }
+
+    /// Set the color of a label (Todoist color name, e.g. "berry_red")
+    pub async fn set_label_color(&self, label_uuid: &Uuid, color: &str) -> Result<()> {
+        self.update_label(label_uuid, None, Some(color), None).await
+    }
+
+    /// Toggle the favorite status of a label
+    pub async fn toggle_label_favorite(&self, label_uuid: &Uuid) -> Result<()> {
+        let is_favorite = {
+            let storage = self.storage.lock().await;
+            LabelRepository::get_by_id(&storage.conn, label_uuid)
+                .await?
+                .map(|label| label.is_favorite)
+                .ok_or_else(|| anyhow::anyhow!("Label not found: {}", label_uuid))?
+        };
+
+        self.update_label(label_uuid, None, None, Some(!is_favorite)).await
+    }
+
+    /// Updates a label's name, color and/or favorite status with a single backend call.
+    ///
+    /// Only the fields that are `Some` are sent to the backend and updated in local storage.
+    ///
+    /// # Arguments
+    /// * `label_uuid` - The local UUID of the label to update
+    /// * `name` - Optional new name
+    /// * `color` - Optional new color name
+    /// * `is_favorite` - Optional new favorite status
+    ///
+    /// # Errors
+    /// Returns an error if the backend call fails or local storage update fails
+    pub async fn update_label(
+        &self,
+        label_uuid: &Uuid,
+        name: Option<&str>,
+        color: Option<&str>,
+        is_favorite: Option<bool>,
+    ) -> Result<()> {
+        info!(
+            "Backend: Updating label UUID {} (name: {:?}, color: {:?}, favorite: {:?})",
+            label_uuid, name, color, is_favorite
+        );
 
         // Look up the label's remote_id for backend call
         let remote_id = self.get_label_remote_id(label_uuid).await?;
 
         // Update label via backend using the UpdateLabelArgs structure
         let label_args = crate::backend::UpdateLabelArgs {
-            name: Some(name.to_string()),
-            is_favorite: None,
+            name: name.map(str::to_string),
+            color: color.map(str::to_string),
+            is_favorite,
         };
-        let _label = self
-            .get_backend()
-            .await?
-            .update_label(&remote_id, label_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        let _label = self.get_backend().await?.update_label(&remote_id, label_args).await?;
 
         // Update local storage immediately after successful backend call
-        info!(
-            "Storage: Updating local label name for UUID {} to '{}'",
-            label_uuid, name
-        );
+        info!("Storage: Updating local label UUID {}", label_uuid);
         let storage = self.storage.lock().await;
 
         if let Some(label) = LabelRepository::get_by_id(&storage.conn, label_uuid).await? {
             let mut active_model: label::ActiveModel = label.into_active_model();
-            active_model.name = ActiveValue::Set(name.to_string());
+            if let Some(name) = name {
+                active_model.name = ActiveValue::Set(name.to_string());
+            }
+            if let Some(color) = color {
+                active_model.color = ActiveValue::Set(color.to_string());
+            }
+            if let Some(is_favorite) = is_favorite {
+                active_model.is_favorite = ActiveValue::Set(is_favorite);
+            }
             LabelRepository::update(&storage.conn, active_model).await?;
         }
 
@@ -105,11 +234,7 @@ impl SyncService {
         let remote_id = self.get_label_remote_id(label_uuid).await?;
 
         // Delete label via backend
-        self.get_backend()
-            .await?
-            .delete_label(&remote_id)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        self.get_backend().await?.delete_label(&remote_id).await?;
 
         // Note: Local storage deletion will be handled by the next sync
         Ok(())
@@ -11,19 +11,33 @@
 //! - CRUD operations for tasks, projects, and labels
 //! - Business logic for special views (Today, Tomorrow, Upcoming)
 
+pub mod batch;
+pub mod counts;
+pub mod duplicates;
+pub mod filters;
 pub mod labels;
 pub mod projects;
+pub mod quick_add;
 pub mod sections;
 pub mod storage;
+pub mod summary;
 pub mod tasks;
 
 use anyhow::Result;
-use log::{error, info};
+use chrono::{DateTime, Utc};
+use log::{error, info, warn};
+use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
+use crate::backend::retry::RetryPolicy;
+use crate::backend::{BackendChanges, BackendError, BackendStatus};
+use crate::repositories::{BackendRepository, LabelRepository, ProjectRepository, SectionRepository, TaskRepository};
 use crate::storage::LocalStorage;
+use crate::utils::datetime;
+pub use summary::SyncStats;
+use summary::SyncSummary;
 
 /// Service that manages data synchronization between remote backends and local storage.
 ///
@@ -46,17 +60,10 @@ use crate::storage::LocalStorage;
 /// # Example
 /// ```rust,no_run
 /// use terminalist::sync::SyncService;
-/// use terminalist::backend_registry::BackendRegistry;
-/// use terminalist::storage::LocalStorage;
-/// use std::sync::Arc;
-/// use tokio::sync::Mutex;
 ///
 /// # async fn example() -> anyhow::Result<()> {
-/// let storage = Arc::new(Mutex::new(LocalStorage::new(false).await?));
-/// let backend_registry = Arc::new(BackendRegistry::new(storage));
-/// // ... initialize and load backends ...
-/// # let backend_uuid = uuid::Uuid::new_v4();
-/// let sync_service = SyncService::new(backend_registry, backend_uuid, false).await?;
+/// let api_token = std::env::var("TODOIST_API_TOKEN")?;
+/// let sync_service = SyncService::connect("todoist", "My Todoist", &api_token, false).await?;
 ///
 /// // Sync data from remote backend
 /// sync_service.sync().await?;
@@ -73,6 +80,14 @@ pub struct SyncService {
     storage: Arc<Mutex<LocalStorage>>,
     sync_in_progress: Arc<Mutex<bool>>,
     debug_mode: bool,
+    complete_subtasks_with_parent: bool,
+    fuzzy_search_max_tasks: usize,
+    retry_policy: RetryPolicy,
+    fail_on_section_error: bool,
+    default_project: Option<String>,
+    /// The other backends connected in the same database, with their default project
+    other_backends: Vec<(Uuid, Option<String>)>,
+    last_sync_summary: Arc<Mutex<Option<SyncSummary>>>,
 }
 
 /// Represents the current status of a synchronization operation.
@@ -85,8 +100,15 @@ pub enum SyncStatus {
     Idle,
     /// A sync operation is currently in progress
     InProgress,
-    /// The last sync operation completed successfully
-    Success,
+    /// The last sync operation completed successfully, with what it changed locally
+    Success(SyncStats),
+    /// The last sync operation stored its data, but part of it couldn't be synced
+    PartialSuccess {
+        /// What it changed locally
+        stats: SyncStats,
+        /// Human-readable description of each part that was skipped
+        warnings: Vec<String>,
+    },
     /// The last sync operation failed with an error
     Error {
         /// Human-readable error message describing what went wrong
@@ -94,12 +116,96 @@ pub enum SyncStatus {
     },
 }
 
+/// Trim a user-provided name or task content, rejecting empty or whitespace-only input.
+///
+/// `what` names the value in the error message, e.g. "Project name".
+pub fn validate_name(what: &str, value: &str) -> Result<String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        anyhow::bail!("{} cannot be empty", what);
+    }
+    Ok(trimmed.to_string())
+}
+
 impl SyncService {
+    /// Creates a `SyncService` for a single backend from its API token.
+    ///
+    /// This is the usual way to start: it opens local storage, registers the backend
+    /// in a fresh [`BackendRegistry`](crate::backend_registry::BackendRegistry) and
+    /// returns a service bound to it. Use [`SyncService::new`] instead when the
+    /// registry is already set up.
+    ///
+    /// # Arguments
+    /// * `backend_type` - Backend type, e.g. `"todoist"`
+    /// * `backend_name` - Human-readable name stored with the backend
+    /// * `api_token` - API token for the backend
+    /// * `debug_mode` - Whether to use the file-backed debug database
+    ///
+    /// # Errors
+    /// Returns an error if local storage cannot be opened or the backend type is unknown
+    pub async fn connect(backend_type: &str, backend_name: &str, api_token: &str, debug_mode: bool) -> Result<Self> {
+        let credentials = serde_json::json!({ "api_token": api_token }).to_string();
+        Self::connect_with_credentials(backend_type, backend_name, credentials, None, debug_mode).await
+    }
+
+    /// Creates a `SyncService` like [`SyncService::connect`], for backends needing more
+    /// than a token (e.g. CalDAV's URL, username and password).
+    ///
+    /// `credentials` is the JSON object described in
+    /// [`create_backend`](crate::backend::factory::create_backend). The database is
+    /// opened at `database_path`, or in the default location when `None`.
+    ///
+    /// # Errors
+    /// Returns an error if local storage cannot be opened, the backend type is unknown
+    /// or the credentials are incomplete
+    pub async fn connect_with_credentials(
+        backend_type: &str,
+        backend_name: &str,
+        credentials: String,
+        database_path: Option<&Path>,
+        debug_mode: bool,
+    ) -> Result<Self> {
+        let storage = Arc::new(Mutex::new(LocalStorage::new_at(debug_mode, database_path).await?));
+        let backend_registry = Arc::new(crate::backend_registry::BackendRegistry::new(storage));
+
+        let backend_uuid = backend_registry
+            .add_backend(
+                backend_type.to_string(),
+                backend_name.to_string(),
+                credentials,
+                "{}".to_string(),
+            )
+            .await?;
+
+        Self::new(backend_registry, backend_uuid, debug_mode).await
+    }
+
     /// Creates a new `SyncService` instance with the provided backend registry.
     ///
     /// This creates a sync service that manages synchronization for a specific backend.
     /// The backend instance is retrieved from the registry on-demand.
     ///
+    /// # Example
+    /// ```rust,no_run
+    /// use terminalist::backend_registry::BackendRegistry;
+    /// use terminalist::storage::LocalStorage;
+    /// use terminalist::sync::SyncService;
+    /// use std::sync::Arc;
+    /// use tokio::sync::Mutex;
+    ///
+    /// # async fn example() -> anyhow::Result<()> {
+    /// let storage = Arc::new(Mutex::new(LocalStorage::new(false).await?));
+    /// let backend_registry = Arc::new(BackendRegistry::new(storage));
+    /// let credentials = r#"{"api_token":"your-token"}"#.to_string();
+    /// let backend_uuid = backend_registry
+    ///     .add_backend("todoist".to_string(), "My Todoist".to_string(), credentials, "{}".to_string())
+    ///     .await?;
+    ///
+    /// let sync_service = SyncService::new(backend_registry, backend_uuid, false).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// # Arguments
     /// * `backend_registry` - Shared backend registry instance
     /// * `backend_uuid` - UUID of the backend this service will manage
@@ -126,12 +232,189 @@ impl SyncService {
             storage,
             sync_in_progress: Arc::new(Mutex::new(false)),
             debug_mode,
+            complete_subtasks_with_parent: true,
+            fuzzy_search_max_tasks: 5000,
+            retry_policy: RetryPolicy::default(),
+            fail_on_section_error: false,
+            default_project: None,
+            other_backends: Vec::new(),
+            last_sync_summary: Arc::new(Mutex::new(None)),
         })
     }
 
+    /// Connects another backend in the same database, so its data is synced and shown
+    /// alongside the data of this service's backend.
+    ///
+    /// Reads (views, search, counts) cover every connected backend; changes to an item go
+    /// to the backend it belongs to through [`for_item`](Self::for_item).
+    ///
+    /// # Arguments
+    /// * `backend_type`, `backend_name`, `credentials` - As for [`SyncService::connect_with_credentials`]
+    /// * `default_project` - As for [`set_default_project`](Self::set_default_project), for this backend
+    ///
+    /// # Returns
+    /// The UUID of the added backend
+    ///
+    /// # Errors
+    /// Returns an error if the backend type is unknown or the credentials are incomplete
+    pub async fn add_backend(
+        &mut self,
+        backend_type: &str,
+        backend_name: &str,
+        credentials: String,
+        default_project: Option<String>,
+    ) -> Result<Uuid> {
+        let backend_uuid = self
+            .backend_registry
+            .add_backend(
+                backend_type.to_string(),
+                backend_name.to_string(),
+                credentials,
+                "{}".to_string(),
+            )
+            .await?;
+        self.add_registered_backend(backend_uuid, default_project);
+        Ok(backend_uuid)
+    }
+
+    /// Connects a backend already added to this service's registry, like
+    /// [`add_backend`](Self::add_backend).
+    pub fn add_registered_backend(&mut self, backend_uuid: Uuid, default_project: Option<String>) {
+        if backend_uuid != self.backend_uuid && !self.other_backends.iter().any(|(uuid, _)| *uuid == backend_uuid) {
+            self.other_backends.push((backend_uuid, default_project));
+        }
+    }
+
+    /// UUID of the backend this service manages
+    pub fn backend_uuid(&self) -> Uuid {
+        self.backend_uuid
+    }
+
+    /// Connected backends by UUID and name, this service's backend first
+    ///
+    /// # Errors
+    /// Returns an error if reading the backends from local storage fails
+    pub async fn connected_backends(&self) -> Result<Vec<(Uuid, String)>> {
+        let stored = self.backend_registry.list_backends().await?;
+        Ok(std::iter::once(self.backend_uuid)
+            .chain(self.other_backends.iter().map(|(uuid, _)| *uuid))
+            .map(|uuid| {
+                let name = stored
+                    .iter()
+                    .find(|backend| backend.uuid == uuid)
+                    .map_or_else(|| uuid.to_string(), |backend| backend.name.clone());
+                (uuid, name)
+            })
+            .collect())
+    }
+
+    /// This service, managing the connected backend `backend_uuid` instead.
+    ///
+    /// Settings are kept and the default project is the one given for that backend.
+    /// Returns a copy of this service when `backend_uuid` isn't connected.
+    pub fn for_backend(&self, backend_uuid: &Uuid) -> Self {
+        let mut service = self.clone();
+        let Some(index) = self.other_backends.iter().position(|(uuid, _)| uuid == backend_uuid) else {
+            return service;
+        };
+        let (uuid, default_project) = service.other_backends.remove(index);
+        service
+            .other_backends
+            .insert(0, (self.backend_uuid, self.default_project.clone()));
+        service.backend_uuid = uuid;
+        service.default_project = default_project;
+        service
+    }
+
+    /// The service for the backend that a task, project, section or label belongs to,
+    /// to change it there.
+    ///
+    /// Returns a copy of this service when only one backend is connected or the item is unknown.
+    pub async fn for_item(&self, item_uuid: &Uuid) -> Self {
+        if self.other_backends.is_empty() {
+            return self.clone();
+        }
+        let backend_uuid = {
+            let storage = self.storage.lock().await;
+            let conn = &storage.conn;
+            match TaskRepository::get_by_id(conn, item_uuid).await {
+                Ok(Some(task)) => Some(task.backend_uuid),
+                _ => match ProjectRepository::get_by_id(conn, item_uuid).await {
+                    Ok(Some(project)) => Some(project.backend_uuid),
+                    _ => match SectionRepository::get_by_id(conn, item_uuid).await {
+                        Ok(Some(section)) => Some(section.backend_uuid),
+                        _ => LabelRepository::get_by_id(conn, item_uuid)
+                            .await
+                            .ok()
+                            .flatten()
+                            .map(|label| label.backend_uuid),
+                    },
+                },
+            }
+        };
+        match backend_uuid {
+            Some(backend_uuid) => self.for_backend(&backend_uuid),
+            None => self.clone(),
+        }
+    }
+
+    /// Sets the project (name or remote ID) that receives tasks created without a project.
+    ///
+    /// Without one, such tasks go to the backend's inbox, and creating them fails on
+    /// backends that have no inbox.
+    pub fn set_default_project(&mut self, default_project: Option<String>) {
+        self.default_project = default_project;
+    }
+
+    /// Changes made to local data by the last successful sync.
+    ///
+    /// `None` until a sync completes, and after a sync that filled an empty database
+    /// (everything would count as new).
+    pub async fn last_sync_summary(&self) -> Option<SyncSummary> {
+        *self.last_sync_summary.lock().await
+    }
+
+    /// When this service's backend last synced successfully, kept across restarts.
+    ///
+    /// # Errors
+    /// Returns an error if reading the backend from local storage fails
+    pub async fn last_sync_at(&self) -> Result<Option<DateTime<Utc>>> {
+        let storage = self.storage.lock().await;
+        BackendRepository::get_last_sync_at(&storage.conn, &self.backend_uuid).await
+    }
+
+    /// Sets whether completing a task also marks its subtasks completed locally.
+    ///
+    /// Enabled by default, matching backends that close subtasks together with their
+    /// parent. When disabled, subtasks stay open locally until the next sync.
+    pub fn set_complete_subtasks_with_parent(&mut self, enabled: bool) {
+        self.complete_subtasks_with_parent = enabled;
+    }
+
+    /// Sets the largest number of stored tasks [`search_tasks`](Self::search_tasks) scores
+    /// with fuzzy matching; with more tasks (or 0) it falls back to a substring search.
+    pub fn set_fuzzy_search_max_tasks(&mut self, max_tasks: usize) {
+        self.fuzzy_search_max_tasks = max_tasks;
+    }
+
+    /// Sets how backend calls are retried after a network error, for syncs and for
+    /// changes made by the user alike. Creating an item is never retried.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    /// Sets whether a sync fails when sections can't be fetched, instead of keeping the
+    /// stored sections and reporting a [`SyncStatus::PartialSuccess`].
+    pub fn set_fail_on_section_error(&mut self, enabled: bool) {
+        self.fail_on_section_error = enabled;
+    }
+
     /// Helper to get the current backend instance from the registry.
     async fn get_backend(&self) -> Result<Arc<Box<dyn crate::backend::Backend>>> {
-        self.backend_registry.get_backend(&self.backend_uuid).await
+        let backend = self.backend_registry.get_backend(&self.backend_uuid).await?;
+        // The registry may have recreated the backend (e.g. after new credentials)
+        backend.set_retry_policy(self.retry_policy);
+        Ok(backend)
     }
 
     /// Returns whether debug mode is enabled.
@@ -180,7 +463,11 @@ impl SyncService {
         // Release the lock before performing sync to avoid holding it during the long operation
         drop(sync_guard);
 
-        let result = self.perform_sync().await;
+        let result = if self.other_backends.is_empty() {
+            self.perform_sync().await
+        } else {
+            self.sync_all_backends().await
+        };
 
         // Release sync lock
         {
@@ -191,73 +478,202 @@ impl SyncService {
         result
     }
 
+    /// Syncs each connected backend in turn, merging their results.
+    ///
+    /// A backend that fails to sync is reported as a warning naming it, so the others
+    /// still update; the sync only fails when all of them do.
+    async fn sync_all_backends(&self) -> Result<SyncStatus> {
+        let mut stats = SyncStats::default();
+        let mut warnings = Vec::new();
+        let mut first_failure = None;
+        let mut synced = 0;
+        // Each backend's sync records its own summary, merged here
+        let mut summary: Option<SyncSummary> = None;
+
+        for (backend_uuid, name) in self.connected_backends().await? {
+            info!("🔄 Syncing backend '{}'", name);
+            self.last_sync_summary.lock().await.take();
+            let result = self.for_backend(&backend_uuid).perform_sync().await;
+            if let Some(backend_summary) = self.last_sync_summary.lock().await.take() {
+                summary.get_or_insert_with(SyncSummary::default).add(&backend_summary);
+            }
+            let failure = match result {
+                Ok(SyncStatus::Success(backend_stats)) => {
+                    stats.add(&backend_stats);
+                    synced += 1;
+                    continue;
+                }
+                Ok(SyncStatus::PartialSuccess {
+                    stats: backend_stats,
+                    warnings: backend_warnings,
+                }) => {
+                    stats.add(&backend_stats);
+                    warnings.extend(backend_warnings.into_iter().map(|w| format!("{}: {}", name, w)));
+                    synced += 1;
+                    continue;
+                }
+                Ok(SyncStatus::Error { message }) => {
+                    warnings.push(format!("{}: {}", name, message));
+                    Ok(SyncStatus::Error { message })
+                }
+                Ok(status) => Ok(status),
+                Err(e) => {
+                    warnings.push(format!("{}: {:#}", name, e));
+                    Err(e)
+                }
+            };
+            first_failure.get_or_insert(failure);
+        }
+        *self.last_sync_summary.lock().await = summary;
+
+        match first_failure {
+            Some(failure) if synced == 0 => failure,
+            _ if warnings.is_empty() => Ok(SyncStatus::Success(stats)),
+            _ => Ok(SyncStatus::PartialSuccess { stats, warnings }),
+        }
+    }
+
     /// Internal sync implementation
     async fn perform_sync(&self) -> Result<SyncStatus> {
         info!("🔄 Starting sync process...");
 
-        // Fetch projects from backend
-        let projects = match self.get_backend().await?.fetch_projects().await {
-            Ok(projects) => {
-                info!("✅ Fetched {} projects from backend", projects.len());
-                projects
-            }
-            Err(e) => {
-                error!("❌ Failed to fetch projects: {e}");
-                return Ok(SyncStatus::Error {
-                    message: format!("Failed to fetch projects: {e}"),
-                });
-            }
-        };
+        // Parts of the sync that were skipped, reported with the result
+        let mut warnings = Vec::new();
 
-        // Fetch all tasks from backend
-        let tasks = match self.get_backend().await?.fetch_tasks().await {
-            Ok(tasks) => {
-                info!("✅ Fetched {} tasks from backend", tasks.len());
-                tasks
-            }
-            Err(e) => {
-                error!("❌ Failed to fetch tasks: {e}");
-                return Ok(SyncStatus::Error {
-                    message: format!("Failed to fetch tasks: {e}"),
-                });
+        // Backends with incremental sync only send what changed since the last sync
+        let (changes, sections_fetched) = match self.fetch_changes().await? {
+            Some(changes) => {
+                info!(
+                    "✅ Fetched {} sync from backend: {} projects, {} tasks, {} labels, {} sections changed",
+                    if changes.full_sync { "full" } else { "incremental" },
+                    changes.projects.len(),
+                    changes.tasks.len(),
+                    changes.labels.len(),
+                    changes.sections.len()
+                );
+                (changes, true)
             }
-        };
+            None => {
+                // Fetch projects from backend
+                let projects = match self.get_backend().await?.fetch_projects().await {
+                    Ok(projects) => {
+                        info!("✅ Fetched {} projects from backend", projects.len());
+                        projects
+                    }
+                    // Rejected credentials are returned as errors so the UI can ask for a new token
+                    Err(e) if e.is_auth() => {
+                        error!("❌ Authentication failed while fetching projects: {e}");
+                        return Err(e.into());
+                    }
+                    Err(e) => {
+                        error!("❌ Failed to fetch projects: {e}");
+                        return Ok(SyncStatus::Error {
+                            message: format!("Failed to fetch projects: {e}"),
+                        });
+                    }
+                };
 
-        // Fetch all labels from backend
-        let labels = match self.get_backend().await?.fetch_labels().await {
-            Ok(labels) => {
-                info!("✅ Fetched {} labels from backend", labels.len());
-                labels
-            }
-            Err(e) => {
-                error!("❌ Failed to fetch labels: {e}");
-                return Ok(SyncStatus::Error {
-                    message: format!("Failed to fetch labels: {e}"),
-                });
-            }
-        };
+                // Fetch all tasks from backend
+                let tasks = match self.get_backend().await?.fetch_tasks().await {
+                    Ok(tasks) => {
+                        info!("✅ Fetched {} tasks from backend", tasks.len());
+                        tasks
+                    }
+                    Err(e) if e.is_auth() => {
+                        error!("❌ Authentication failed while fetching tasks: {e}");
+                        return Err(e.into());
+                    }
+                    Err(e) => {
+                        error!("❌ Failed to fetch tasks: {e}");
+                        return Ok(SyncStatus::Error {
+                            message: format!("Failed to fetch tasks: {e}"),
+                        });
+                    }
+                };
 
-        // Fetch all sections from backend
-        let sections = match self.get_backend().await?.fetch_sections().await {
-            Ok(sections) => {
-                info!("✅ Fetched {} sections from backend", sections.len());
-                sections
-            }
-            Err(e) => {
-                error!("❌ Failed to fetch sections: {e}");
-                info!("⚠️  Skipping sections sync due to backend compatibility issue");
-                // For now, skip sections sync and continue with other data
-                Vec::new()
+                // Fetch all labels from backend
+                let labels = match self.get_backend().await?.fetch_labels().await {
+                    Ok(labels) => {
+                        info!("✅ Fetched {} labels from backend", labels.len());
+                        labels
+                    }
+                    Err(e) if e.is_auth() => {
+                        error!("❌ Authentication failed while fetching labels: {e}");
+                        return Err(e.into());
+                    }
+                    Err(e) => {
+                        error!("❌ Failed to fetch labels: {e}");
+                        return Ok(SyncStatus::Error {
+                            message: format!("Failed to fetch labels: {e}"),
+                        });
+                    }
+                };
+
+                // Fetch all sections from backend
+                let sections = match self.get_backend().await?.fetch_sections().await {
+                    Ok(sections) => {
+                        info!("✅ Fetched {} sections from backend", sections.len());
+                        Some(sections)
+                    }
+                    Err(e) if e.is_auth() => {
+                        error!("❌ Authentication failed while fetching sections: {e}");
+                        return Err(e.into());
+                    }
+                    Err(e) if self.fail_on_section_error => {
+                        error!("❌ Failed to fetch sections: {e}");
+                        return Ok(SyncStatus::Error {
+                            message: format!("Failed to fetch sections: {e}"),
+                        });
+                    }
+                    Err(e) => {
+                        // Keep the stored sections and sync everything else
+                        warn!("⚠️  Failed to fetch sections, keeping the stored ones: {e}");
+                        warnings.push(format!(
+                            "Sections could not be fetched, so section changes were not synced: {e}"
+                        ));
+                        None
+                    }
+                };
+
+                let sections_fetched = sections.is_some();
+                let changes = BackendChanges {
+                    full_sync: true,
+                    projects,
+                    tasks,
+                    labels,
+                    sections: sections.unwrap_or_default(),
+                    ..BackendChanges::default()
+                };
+                (changes, sections_fetched)
             }
         };
 
-        // Store in local database
+        // Store in local database, counting what changes
+        let mut stats = SyncStats::default();
         {
             let storage = self.storage.lock().await;
             info!("💾 Storing data in local database...");
 
+            // Snapshot local data to summarize what this sync changes
+            let tasks_before = TaskRepository::get_all(&storage.conn).await?;
+            let projects_before = ProjectRepository::get_all(&storage.conn).await?;
+
+            // Forget the token until everything is stored, so a failure leads to a full sync
+            BackendRepository::set_sync_token(&storage.conn, &self.backend_uuid, None).await?;
+
+            // A full snapshot replaces everything; a delta lists what was removed
+            let prune = changes.full_sync;
+            if !prune {
+                if let Err(e) = self.remove_deleted_batch(&storage, &changes, &mut stats).await {
+                    error!("❌ Failed to remove deleted items: {e}");
+                    return Ok(SyncStatus::Error {
+                        message: format!("Failed to remove deleted items: {e}"),
+                    });
+                }
+            }
+
             // Store projects
-            if let Err(e) = self.store_projects_batch(&storage, &projects).await {
+            if let Err(e) = self.store_projects_batch(&storage, &changes.projects, prune, &mut stats).await {
                 error!("❌ Failed to store projects: {e}");
                 return Ok(SyncStatus::Error {
                     message: format!("Failed to store projects: {e}"),
@@ -266,7 +682,7 @@ impl SyncService {
             info!("✅ Stored projects in database");
 
             // Store labels BEFORE tasks so task-label relationships can be created
-            if let Err(e) = self.store_labels_batch(&storage, &labels).await {
+            if let Err(e) = self.store_labels_batch(&storage, &changes.labels, prune, &mut stats).await {
                 error!("❌ Failed to store labels: {e}");
                 return Ok(SyncStatus::Error {
                     message: format!("Failed to store labels: {e}"),
@@ -275,8 +691,8 @@ impl SyncService {
             info!("✅ Stored labels in database");
 
             // Store sections BEFORE tasks since tasks have foreign key references to sections
-            if !sections.is_empty() {
-                if let Err(e) = self.store_sections_batch(&storage, &sections).await {
+            if sections_fetched {
+                if let Err(e) = self.store_sections_batch(&storage, &changes.sections, prune, &mut stats).await {
                     error!("❌ Failed to store sections: {e}");
                     return Ok(SyncStatus::Error {
                         message: format!("Failed to store sections: {e}"),
@@ -284,19 +700,158 @@ impl SyncService {
                 }
                 info!("✅ Stored sections in database");
             } else {
-                info!("⚠️  No sections to store (skipped due to backend issue)");
+                info!("⚠️  No sections to store (fetching them failed)");
             }
 
-            if let Err(e) = self.store_tasks_batch(&storage, &tasks).await {
+            if let Err(e) = self.store_tasks_batch(&storage, &changes.tasks, prune, &mut stats).await {
                 error!("❌ Failed to store tasks: {e}");
                 return Ok(SyncStatus::Error {
                     message: format!("Failed to store tasks: {e}"),
                 });
             }
             info!("✅ Stored tasks in database");
+
+            if !changes.sync_token.is_empty() {
+                BackendRepository::set_sync_token(&storage.conn, &self.backend_uuid, Some(changes.sync_token.clone()))
+                    .await?;
+            }
+            BackendRepository::set_last_sync_at(&storage.conn, &self.backend_uuid, datetime::now().with_timezone(&Utc))
+                .await?;
+
+            let summary = if tasks_before.is_empty() && projects_before.is_empty() {
+                None
+            } else {
+                let tasks_after = TaskRepository::get_all(&storage.conn).await?;
+                let projects_after = ProjectRepository::get_all(&storage.conn).await?;
+                Some(SyncSummary::between(
+                    &tasks_before,
+                    &tasks_after,
+                    &projects_before,
+                    &projects_after,
+                ))
+            };
+            *self.last_sync_summary.lock().await = summary;
+        }
+
+        if warnings.is_empty() {
+            info!("✅ Sync complete: {:?}", stats);
+            Ok(SyncStatus::Success(stats))
+        } else {
+            warn!("⚠️  Sync complete with {} warning(s): {:?}", warnings.len(), stats);
+            Ok(SyncStatus::PartialSuccess { stats, warnings })
+        }
+    }
+
+    /// Fetches the changes since the stored sync token, or a full snapshot without one.
+    ///
+    /// A token the backend rejects is dropped in favor of a full snapshot. Returns `None`
+    /// when the backend has no incremental sync (or its snapshot failed), in which case
+    /// each resource is fetched on its own.
+    ///
+    /// # Errors
+    /// Returns an error if the backend rejects the credentials or local storage fails
+    async fn fetch_changes(&self) -> Result<Option<BackendChanges>> {
+        let backend = self.get_backend().await?;
+        let sync_token = {
+            let storage = self.storage.lock().await;
+            BackendRepository::get_by_uuid(&storage.conn, &self.backend_uuid)
+                .await?
+                .and_then(|backend| backend.sync_token)
+        };
+
+        if let Some(sync_token) = &sync_token {
+            match backend.fetch_changes(Some(sync_token)).await {
+                Ok(changes) => return Ok(changes),
+                Err(e) if e.is_auth() => {
+                    error!("❌ Authentication failed while fetching changes: {e}");
+                    return Err(e.into());
+                }
+                Err(e) => warn!("⚠️  Incremental sync failed, falling back to a full sync: {e}"),
+            }
+        }
+
+        match backend.fetch_changes(None).await {
+            Ok(changes) => Ok(changes),
+            Err(e) if e.is_auth() => {
+                error!("❌ Authentication failed while fetching changes: {e}");
+                Err(e.into())
+            }
+            Err(e) => {
+                warn!("⚠️  Full sync failed, fetching each resource instead: {e}");
+                Ok(None)
+            }
         }
+    }
 
-        Ok(SyncStatus::Success)
+    /// Replaces the API token of this service's backend.
+    ///
+    /// Used after a [`BackendError::Auth`](crate::backend::BackendError::Auth) to recover
+    /// from a rotated or revoked token without restarting. The new credentials are also
+    /// stored with the backend configuration.
+    ///
+    /// Other stored credentials (such as a CalDAV URL and username) are kept.
+    ///
+    /// # Arguments
+    /// * `api_token` - The new API token (the app password for CalDAV)
+    ///
+    /// # Errors
+    /// Returns an error if the backend rejects the credentials or the database update fails
+    pub async fn update_api_token(&self, api_token: &str) -> Result<()> {
+        let stored = self
+            .backend_registry
+            .list_backends()
+            .await?
+            .into_iter()
+            .find(|backend| backend.uuid == self.backend_uuid);
+        let (backend_type, mut credentials) = match stored {
+            Some(backend) => (
+                backend.backend_type,
+                serde_json::from_str(&backend.credentials).unwrap_or_else(|_| serde_json::json!({})),
+            ),
+            None => (String::new(), serde_json::json!({})),
+        };
+        let secret_key = if backend_type == "caldav" {
+            "password"
+        } else {
+            "api_token"
+        };
+        credentials[secret_key] = serde_json::Value::from(api_token);
+        let credentials = credentials.to_string();
+        self.backend_registry
+            .update_backend(&self.backend_uuid, None, Some(credentials), None)
+            .await?;
+        info!("🔑 Updated API token for backend {}", self.backend_uuid);
+        Ok(())
+    }
+
+    /// Checks the connection to this service's backend, without retrying.
+    ///
+    /// A rejected token is reported as connected but not authenticated, so the user can
+    /// be offered to enter a new one; other failures as not connected.
+    ///
+    /// # Errors
+    /// Returns an error if the backend isn't registered or can't be created
+    pub async fn backend_status(&self) -> Result<BackendStatus> {
+        let stored = self
+            .backend_registry
+            .list_backends()
+            .await?
+            .into_iter()
+            .find(|backend| backend.uuid == self.backend_uuid);
+        let (name, backend_type) = match stored {
+            Some(backend) => (backend.name, backend.backend_type),
+            None => anyhow::bail!("Backend {} is not registered", self.backend_uuid),
+        };
+
+        let error = self.get_backend().await?.test_connection().await.err();
+        Ok(BackendStatus {
+            name,
+            backend_type,
+            connected: !matches!(error, Some(BackendError::Network(_))),
+            authenticated: !error.as_ref().is_some_and(BackendError::is_auth),
+            last_sync: self.last_sync_at().await?,
+            sync_error: error.map(|e| e.to_string()),
+        })
     }
 
     /// Forces a full synchronization with the remote backend, bypassing any checks (e.g., last sync time).
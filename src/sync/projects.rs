@@ -1,6 +1,6 @@
 use crate::entities::project;
 use crate::repositories::ProjectRepository;
-use crate::sync::SyncService;
+use crate::sync::{validate_name, SyncService};
 use anyhow::Result;
 use log::warn;
 use sea_orm::{ActiveValue, EntityTrait, IntoActiveModel};
@@ -41,8 +41,24 @@ impl SyncService {
     /// but the GET /projects backend endpoint will only return the first 5 projects for free users.
     ///
     /// # Errors
-    /// Returns an error if the backend call fails or local storage update fails
+    /// Returns an error if the name is empty, or if the backend call or local storage update fails
     pub async fn create_project(&self, name: &str, parent_uuid: Option<Uuid>) -> Result<()> {
+        self.create_project_with_color(name, parent_uuid, None).await
+    }
+
+    /// Creates a project like [`create_project`](Self::create_project), in the given
+    /// Todoist color (e.g. "berry_red"); without one the backend picks its default.
+    ///
+    /// # Errors
+    /// Returns an error if the name is empty, or if the backend call or local storage update fails
+    pub async fn create_project_with_color(
+        &self,
+        name: &str,
+        parent_uuid: Option<Uuid>,
+        color: Option<&str>,
+    ) -> Result<()> {
+        let name = validate_name("Project name", name)?;
+
         // Look up remote_id for parent project if provided
         let remote_parent_id = if let Some(uuid) = parent_uuid {
             Some(self.get_project_remote_id(&uuid).await?)
@@ -52,16 +68,12 @@ impl SyncService {
 
         // Create project via backend using backend CreateProjectArgs
         let project_args = crate::backend::CreateProjectArgs {
-            name: name.to_string(),
+            name,
             parent_remote_id: remote_parent_id,
             is_favorite: None,
+            color: color.map(str::to_string),
         };
-        let backend_project = self
-            .get_backend()
-            .await?
-            .create_project(project_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        let backend_project = self.get_backend().await?.create_project(project_args).await?;
 
         // Store the created project in local database immediately for UI refresh
         let storage = self.storage.lock().await;
@@ -72,6 +84,7 @@ impl SyncService {
             backend_uuid: ActiveValue::Set(self.backend_uuid),
             remote_id: ActiveValue::Set(backend_project.remote_id),
             name: ActiveValue::Set(backend_project.name),
+            color: ActiveValue::Set(backend_project.color),
             is_favorite: ActiveValue::Set(backend_project.is_favorite),
             is_inbox_project: ActiveValue::Set(backend_project.is_inbox),
             order_index: ActiveValue::Set(backend_project.order_index),
@@ -84,6 +97,7 @@ impl SyncService {
             OnConflict::columns([project::Column::BackendUuid, project::Column::RemoteId])
                 .update_columns([
                     project::Column::Name,
+                    project::Column::Color,
                     project::Column::IsFavorite,
                     project::Column::IsInboxProject,
                     project::Column::OrderIndex,
@@ -95,29 +109,60 @@ impl SyncService {
         Ok(())
     }
 
-    /// Update project content (name only for now)
+    /// Update project content (name only)
     pub async fn update_project_content(&self, project_uuid: &Uuid, name: &str) -> Result<()> {
+        self.update_project(project_uuid, Some(name), None, None).await
+    }
+
+    /// Mark a project as favorite or not, listing it in the sidebar's Favorites section
+    pub async fn set_project_favorite(&self, project_uuid: &Uuid, is_favorite: bool) -> Result<()> {
+        self.update_project(project_uuid, None, None, Some(is_favorite)).await
+    }
+
+    /// Updates a project's name, color and/or favorite status with a single backend call.
+    ///
+    /// Only the fields that are `Some` are sent to the backend and updated in local storage.
+    ///
+    /// # Arguments
+    /// * `project_uuid` - The local UUID of the project to update
+    /// * `name` - Optional new name
+    /// * `color` - Optional new color name
+    /// * `is_favorite` - Optional new favorite status
+    ///
+    /// # Errors
+    /// Returns an error if the backend call fails or local storage update fails
+    pub async fn update_project(
+        &self,
+        project_uuid: &Uuid,
+        name: Option<&str>,
+        color: Option<&str>,
+        is_favorite: Option<bool>,
+    ) -> Result<()> {
         // Look up the project's remote_id for backend call
         let remote_id = self.get_project_remote_id(project_uuid).await?;
 
         // Update project via backend using the UpdateProjectArgs structure
         let project_args = crate::backend::UpdateProjectArgs {
-            name: Some(name.to_string()),
-            is_favorite: None,
+            name: name.map(str::to_string),
+            is_favorite,
+            color: color.map(str::to_string),
         };
-        let _project = self
-            .get_backend()
-            .await?
-            .update_project(&remote_id, project_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        let _project = self.get_backend().await?.update_project(&remote_id, project_args).await?;
 
         // Update local storage immediately after successful backend call
         let storage = self.storage.lock().await;
 
         if let Some(project) = ProjectRepository::get_by_id(&storage.conn, project_uuid).await? {
             let mut active_model: project::ActiveModel = project.into_active_model();
-            active_model.name = ActiveValue::Set(name.to_string());
+            if let Some(name) = name {
+                active_model.name = ActiveValue::Set(name.to_string());
+            }
+            if let Some(color) = color {
+                active_model.color = ActiveValue::Set(color.to_string());
+            }
+            if let Some(is_favorite) = is_favorite {
+                active_model.is_favorite = ActiveValue::Set(is_favorite);
+            }
             ProjectRepository::update(&storage.conn, active_model).await?;
         } else {
             warn!(
@@ -135,11 +180,7 @@ impl SyncService {
         let remote_id = self.get_project_remote_id(project_uuid).await?;
 
         // Delete project via backend
-        self.get_backend()
-            .await?
-            .delete_project(&remote_id)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        self.get_backend().await?.delete_project(&remote_id).await?;
 
         // Remove from local storage
         let storage = self.storage.lock().await;
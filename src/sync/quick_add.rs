@@ -0,0 +1,95 @@
+//! Todoist-style quick-add syntax for the task creation dialog.
+//!
+//! "Buy milk #Groceries @errands p2 for 15m tomorrow" creates "Buy milk" in the Groceries
+//! project, labeled errands, with priority 2, a 15 minute duration, due tomorrow. `@label` tokens stay in the content and
+//! are applied by [`SyncService::create_task`](crate::sync::SyncService::create_task), which
+//! also creates labels that don't exist yet.
+
+use crate::entities::project;
+use crate::sync::labels::extract_inline_labels;
+use crate::utils::datetime;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+/// Task fields parsed from quick-add input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuickAdd {
+    /// Remaining content, still holding the `@label` tokens
+    pub content: String,
+    /// Project named with `#project`, if it matched a known project
+    pub project_uuid: Option<Uuid>,
+    /// Priority from `p1`–`p4`, on the API scale (4 = p1, 1 = p4)
+    pub priority: Option<i32>,
+    /// Due date from a trailing date phrase (see [`datetime::format_due`])
+    pub due: Option<String>,
+    /// Duration from `for 45m`, in minutes
+    pub duration: Option<u32>,
+}
+
+impl QuickAdd {
+    /// Parse quick-add input, resolving `#project` against `projects` (case-insensitively).
+    ///
+    /// A `#` token naming no known project is kept in the content, as is anything that would
+    /// leave the content empty.
+    pub fn parse(input: &str, projects: &[project::Model], today: NaiveDate) -> Self {
+        let mut words = Vec::new();
+        let mut project_uuid = None;
+        let mut priority = None;
+        let mut duration = None;
+
+        let mut input_words = input.split_whitespace().peekable();
+        while let Some(word) = input_words.next() {
+            // "for" and an amount with its unit; "for 2" stays in the content
+            let for_duration = input_words
+                .peek()
+                .filter(|amount| word.eq_ignore_ascii_case("for") && amount.contains(char::is_alphabetic))
+                .and_then(|amount| datetime::parse_duration(amount));
+            if let Some(minutes) = for_duration {
+                duration = Some(minutes);
+                input_words.next();
+            } else if let Some(project) = word
+                .strip_prefix('#')
+                .and_then(|name| projects.iter().find(|p| p.name.eq_ignore_ascii_case(name)))
+            {
+                project_uuid = Some(project.uuid);
+            } else if let Some(p) = parse_priority(word) {
+                priority = Some(p);
+            } else {
+                words.push(word);
+            }
+        }
+
+        // Labels may come after the date ("Call mom tomorrow @phone"), so look for it without them
+        let (content, label_names) = extract_inline_labels(&words.join(" "));
+        let (mut content, due) = datetime::split_trailing_due(&content, today);
+        for name in label_names {
+            content.push_str(" @");
+            content.push_str(&name);
+        }
+
+        if extract_inline_labels(&content).0.is_empty() {
+            // Nothing but tokens: keep the input as typed rather than creating an empty task
+            return Self {
+                content: input.trim().to_string(),
+                project_uuid: None,
+                priority: None,
+                due: None,
+                duration: None,
+            };
+        }
+
+        Self {
+            content,
+            project_uuid,
+            priority,
+            due: due.map(|(date, time)| datetime::format_due(date, time)),
+            duration,
+        }
+    }
+}
+
+/// "p1"–"p4" (any case) as an API priority
+pub fn parse_priority(word: &str) -> Option<i32> {
+    let level: i32 = word.strip_prefix(['p', 'P'])?.parse().ok()?;
+    (1..=4).contains(&level).then_some(5 - level)
+}
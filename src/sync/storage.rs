@@ -1,9 +1,13 @@
+use crate::backend::BackendChanges;
 use crate::entities::{label, project, section, task, task_label};
 use crate::repositories::{LabelRepository, ProjectRepository, SectionRepository, TaskRepository};
 use crate::storage::LocalStorage;
-use crate::sync::SyncService;
+use crate::sync::{SyncService, SyncStats};
+use crate::utils::datetime;
 use anyhow::Result;
+use log::info;
 use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, QueryFilter, TransactionTrait};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 impl SyncService {
@@ -62,23 +66,33 @@ impl SyncService {
         }
     }
 
-    /// Store projects in batch
+    /// Store projects in batch; with `prune`, also remove the backend's projects missing from `projects`
     pub(super) async fn store_projects_batch(
         &self,
         storage: &LocalStorage,
         projects: &[crate::backend::BackendProject],
+        prune: bool,
+        stats: &mut SyncStats,
     ) -> Result<()> {
         use sea_orm::sea_query::OnConflict;
 
         let txn = storage.conn.begin().await?;
 
+        // Local copies before the upsert, to count what actually changed
+        let mut previous: HashMap<&str, Option<project::Model>> = HashMap::new();
+
         // First pass: Upsert all projects without parent_uuid relationships
         for backend_project in projects {
+            let existing =
+                ProjectRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_project.remote_id).await?;
+            previous.insert(backend_project.remote_id.as_str(), existing);
+
             let local_project = project::ActiveModel {
                 uuid: ActiveValue::Set(Uuid::new_v4()),
                 backend_uuid: ActiveValue::Set(self.backend_uuid),
                 remote_id: ActiveValue::Set(backend_project.remote_id.clone()),
                 name: ActiveValue::Set(backend_project.name.clone()),
+                color: ActiveValue::Set(backend_project.color.clone()),
                 is_favorite: ActiveValue::Set(backend_project.is_favorite),
                 is_inbox_project: ActiveValue::Set(backend_project.is_inbox),
                 order_index: ActiveValue::Set(backend_project.order_index),
@@ -90,6 +104,7 @@ impl SyncService {
                 OnConflict::columns([project::Column::BackendUuid, project::Column::RemoteId])
                     .update_columns([
                         project::Column::Name,
+                        project::Column::Color,
                         project::Column::IsFavorite,
                         project::Column::IsInboxProject,
                         project::Column::OrderIndex,
@@ -100,6 +115,17 @@ impl SyncService {
             insert.exec(&txn).await?;
         }
 
+        // Remove projects the backend no longer returns (their tasks go with them)
+        if prune {
+            let fetched_remote_ids: HashSet<&str> = projects.iter().map(|p| p.remote_id.as_str()).collect();
+            let removed =
+                ProjectRepository::delete_missing_for_backend(&txn, &self.backend_uuid, &fetched_remote_ids).await?;
+            if removed > 0 {
+                info!("🗑️  Removed {} projects no longer present on the backend", removed);
+            }
+            stats.projects_changed += removed as usize;
+        }
+
         // Second pass: Update parent_uuid references to use local UUIDs
         for backend_project in projects {
             if let Some(remote_parent_id) = &backend_project.parent_remote_id {
@@ -118,26 +144,38 @@ impl SyncService {
             }
         }
 
+        for (remote_id, existing) in previous {
+            let stored = ProjectRepository::get_by_remote_id(&txn, &self.backend_uuid, remote_id).await?;
+            if stored != existing {
+                stats.projects_changed += 1;
+            }
+        }
+
         txn.commit().await?;
         Ok(())
     }
 
-    /// Store labels in batch
+    /// Store labels in batch; with `prune`, also remove the backend's labels missing from `labels`
     pub(super) async fn store_labels_batch(
         &self,
         storage: &LocalStorage,
         labels: &[crate::backend::BackendLabel],
+        prune: bool,
+        stats: &mut SyncStats,
     ) -> Result<()> {
         use sea_orm::sea_query::OnConflict;
 
         let txn = storage.conn.begin().await?;
 
         for backend_label in labels {
+            let existing =
+                LabelRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_label.remote_id).await?;
             let local_label = label::ActiveModel {
                 uuid: ActiveValue::Set(Uuid::new_v4()),
                 backend_uuid: ActiveValue::Set(self.backend_uuid),
                 remote_id: ActiveValue::Set(backend_label.remote_id.clone()),
                 name: ActiveValue::Set(backend_label.name.clone()),
+                color: ActiveValue::Set(backend_label.color.clone()),
                 order_index: ActiveValue::Set(backend_label.order_index),
                 is_favorite: ActiveValue::Set(backend_label.is_favorite),
             };
@@ -145,21 +183,44 @@ impl SyncService {
             let mut insert = label::Entity::insert(local_label);
             insert = insert.on_conflict(
                 OnConflict::columns([label::Column::BackendUuid, label::Column::RemoteId])
-                    .update_columns([label::Column::Name, label::Column::OrderIndex, label::Column::IsFavorite])
+                    .update_columns([
+                        label::Column::Name,
+                        label::Column::Color,
+                        label::Column::OrderIndex,
+                        label::Column::IsFavorite,
+                    ])
                     .to_owned(),
             );
             insert.exec(&txn).await?;
+
+            let stored = LabelRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_label.remote_id).await?;
+            if stored != existing {
+                stats.labels_changed += 1;
+            }
+        }
+
+        // Remove labels the backend no longer returns
+        if prune {
+            let fetched_remote_ids: HashSet<&str> = labels.iter().map(|l| l.remote_id.as_str()).collect();
+            let removed =
+                LabelRepository::delete_missing_for_backend(&txn, &self.backend_uuid, &fetched_remote_ids).await?;
+            if removed > 0 {
+                info!("🗑️  Removed {} labels no longer present on the backend", removed);
+            }
+            stats.labels_changed += removed as usize;
         }
 
         txn.commit().await?;
         Ok(())
     }
 
-    /// Store tasks in batch
+    /// Store tasks in batch; with `prune`, also remove the backend's tasks missing from `tasks`
     pub(super) async fn store_tasks_batch(
         &self,
         storage: &LocalStorage,
         tasks: &[crate::backend::BackendTask],
+        prune: bool,
+        stats: &mut SyncStats,
     ) -> Result<()> {
         use sea_orm::sea_query::OnConflict;
 
@@ -167,6 +228,8 @@ impl SyncService {
 
         // Track task labels for later processing
         let mut task_labels_map: Vec<(Uuid, Vec<String>)> = Vec::new();
+        // Local copies before the upsert, to tell added tasks from updated ones
+        let mut previous: HashMap<&str, Option<task::Model>> = HashMap::new();
 
         // First pass: Upsert all tasks without parent_uuid relationships
         for backend_task in tasks {
@@ -192,6 +255,9 @@ impl SyncService {
             let section_uuid =
                 Self::lookup_section_uuid(&txn, &self.backend_uuid, backend_task.section_remote_id.as_ref()).await?;
 
+            let existing = TaskRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_task.remote_id).await?;
+            previous.insert(backend_task.remote_id.as_str(), existing);
+
             let local_task = task::ActiveModel {
                 uuid: ActiveValue::Set(Uuid::new_v4()),
                 backend_uuid: ActiveValue::Set(self.backend_uuid),
@@ -203,13 +269,18 @@ impl SyncService {
                 parent_uuid: ActiveValue::Set(None),
                 priority: ActiveValue::Set(backend_task.priority),
                 order_index: ActiveValue::Set(backend_task.order_index),
-                due_date: ActiveValue::Set(backend_task.due_date.clone()),
+                due_date: ActiveValue::Set(datetime::local_due_date(
+                    backend_task.due_date.as_deref(),
+                    backend_task.due_datetime.as_deref(),
+                )),
                 due_datetime: ActiveValue::Set(backend_task.due_datetime.clone()),
+                due_string: ActiveValue::Set(backend_task.due_string.clone()),
                 is_recurring: ActiveValue::Set(backend_task.is_recurring),
                 deadline: ActiveValue::Set(backend_task.deadline.clone()),
                 duration: ActiveValue::Set(backend_task.duration.clone()),
                 is_completed: ActiveValue::Set(backend_task.is_completed),
                 is_deleted: ActiveValue::Set(false),
+                postpone_count: ActiveValue::Set(0),
             };
 
             let mut insert = task::Entity::insert(local_task);
@@ -225,11 +296,13 @@ impl SyncService {
                         task::Column::OrderIndex,
                         task::Column::DueDate,
                         task::Column::DueDatetime,
+                        task::Column::DueString,
                         task::Column::IsRecurring,
                         task::Column::Deadline,
                         task::Column::Duration,
                         task::Column::IsCompleted,
                         task::Column::IsDeleted,
+                        // PostponeCount is local-only and must survive resyncs
                     ])
                     .to_owned(),
             );
@@ -265,6 +338,11 @@ impl SyncService {
             if let Some(task) =
                 TaskRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_task.remote_id).await?
             {
+                match previous.get(backend_task.remote_id.as_str()) {
+                    Some(None) => stats.tasks_added += 1,
+                    Some(Some(existing)) if task.changed_since(existing) => stats.tasks_updated += 1,
+                    _ => {}
+                }
                 task_label::Entity::delete_many()
                     .filter(task_label::Column::TaskUuid.eq(task.uuid))
                     .exec(&txn)
@@ -272,6 +350,17 @@ impl SyncService {
             }
         }
 
+        // Remove tasks the backend no longer returns (deleted or completed remotely)
+        if prune {
+            let fetched_remote_ids: HashSet<&str> = tasks.iter().map(|t| t.remote_id.as_str()).collect();
+            let removed =
+                TaskRepository::delete_missing_for_backend(&txn, &self.backend_uuid, &fetched_remote_ids).await?;
+            if removed > 0 {
+                info!("🗑️  Removed {} tasks no longer present on the backend", removed);
+            }
+            stats.tasks_deleted += removed as usize;
+        }
+
         // Recreate relationships
         for (task_uuid, label_names) in task_labels_map {
             if !label_names.is_empty() {
@@ -302,11 +391,13 @@ impl SyncService {
         Ok(())
     }
 
-    /// Store sections in batch
+    /// Store sections in batch; with `prune`, also remove the backend's sections missing from `sections`
     pub(super) async fn store_sections_batch(
         &self,
         storage: &LocalStorage,
         sections: &[crate::backend::BackendSection],
+        prune: bool,
+        stats: &mut SyncStats,
     ) -> Result<()> {
         use sea_orm::sea_query::OnConflict;
 
@@ -321,6 +412,8 @@ impl SyncService {
                 "section sync",
             )
             .await?;
+            let existing =
+                SectionRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_section.remote_id).await?;
 
             let local_section = section::ActiveModel {
                 uuid: ActiveValue::Set(Uuid::new_v4()),
@@ -338,6 +431,70 @@ impl SyncService {
                     .to_owned(),
             );
             insert.exec(&txn).await?;
+
+            let stored =
+                SectionRepository::get_by_remote_id(&txn, &self.backend_uuid, &backend_section.remote_id).await?;
+            if stored != existing {
+                stats.sections_changed += 1;
+            }
+        }
+
+        // Remove sections the backend no longer returns (their tasks stay, without a section)
+        if prune {
+            let fetched_remote_ids: HashSet<&str> = sections.iter().map(|s| s.remote_id.as_str()).collect();
+            let removed =
+                SectionRepository::delete_missing_for_backend(&txn, &self.backend_uuid, &fetched_remote_ids).await?;
+            if removed > 0 {
+                info!("🗑️  Removed {} sections no longer present on the backend", removed);
+            }
+            stats.sections_changed += removed as usize;
+        }
+
+        txn.commit().await?;
+        Ok(())
+    }
+
+    /// Remove the records an incremental sync reported as deleted
+    pub(super) async fn remove_deleted_batch(
+        &self,
+        storage: &LocalStorage,
+        changes: &BackendChanges,
+        stats: &mut SyncStats,
+    ) -> Result<()> {
+        let txn = storage.conn.begin().await?;
+
+        // Tasks first, then the sections, labels and projects they may refer to
+        if !changes.removed_task_ids.is_empty() {
+            let result = task::Entity::delete_many()
+                .filter(task::Column::BackendUuid.eq(self.backend_uuid))
+                .filter(task::Column::RemoteId.is_in(changes.removed_task_ids.iter().cloned()))
+                .exec(&txn)
+                .await?;
+            stats.tasks_deleted += result.rows_affected as usize;
+        }
+        if !changes.removed_section_ids.is_empty() {
+            let result = section::Entity::delete_many()
+                .filter(section::Column::BackendUuid.eq(self.backend_uuid))
+                .filter(section::Column::RemoteId.is_in(changes.removed_section_ids.iter().cloned()))
+                .exec(&txn)
+                .await?;
+            stats.sections_changed += result.rows_affected as usize;
+        }
+        if !changes.removed_label_ids.is_empty() {
+            let result = label::Entity::delete_many()
+                .filter(label::Column::BackendUuid.eq(self.backend_uuid))
+                .filter(label::Column::RemoteId.is_in(changes.removed_label_ids.iter().cloned()))
+                .exec(&txn)
+                .await?;
+            stats.labels_changed += result.rows_affected as usize;
+        }
+        if !changes.removed_project_ids.is_empty() {
+            let result = project::Entity::delete_many()
+                .filter(project::Column::BackendUuid.eq(self.backend_uuid))
+                .filter(project::Column::RemoteId.is_in(changes.removed_project_ids.iter().cloned()))
+                .exec(&txn)
+                .await?;
+            stats.projects_changed += result.rows_affected as usize;
         }
 
         txn.commit().await?;
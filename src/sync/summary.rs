@@ -0,0 +1,161 @@
+//! Summary of what a sync changed locally.
+//!
+//! Background syncs update data without asking, so the UI reports their effect as a
+//! short one-line summary (e.g. "+3 tasks, 2 closed elsewhere, 1 project added").
+//! Syncs the user asks for report the [`SyncStats`] counted while storing the data.
+
+use crate::entities::{project, task};
+use std::collections::{HashMap, HashSet};
+
+/// Counts of local changes made by one sync
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncSummary {
+    /// Tasks that did not exist locally before the sync
+    pub tasks_added: usize,
+    /// Open tasks whose synced fields changed
+    pub tasks_updated: usize,
+    /// Open tasks the backend no longer returns or reports as completed.
+    ///
+    /// The backend only returns open tasks, so tasks completed and deleted on another
+    /// client can't be told apart.
+    pub tasks_closed: usize,
+    pub projects_added: usize,
+    pub projects_removed: usize,
+}
+
+impl SyncSummary {
+    /// Compare local data before and after a sync. Records are matched by remote ID.
+    pub fn between(
+        tasks_before: &[task::Model],
+        tasks_after: &[task::Model],
+        projects_before: &[project::Model],
+        projects_after: &[project::Model],
+    ) -> Self {
+        let mut summary = Self::default();
+
+        let after_by_remote_id: HashMap<&str, &task::Model> =
+            tasks_after.iter().map(|t| (t.remote_id.as_str(), t)).collect();
+        let before_remote_ids: HashSet<&str> = tasks_before.iter().map(|t| t.remote_id.as_str()).collect();
+
+        for before in tasks_before.iter().filter(|t| !t.is_completed && !t.is_deleted) {
+            match after_by_remote_id.get(before.remote_id.as_str()) {
+                Some(after) if after.is_completed || after.is_deleted => summary.tasks_closed += 1,
+                Some(after) if after.changed_since(before) => summary.tasks_updated += 1,
+                Some(_) => {}
+                None => summary.tasks_closed += 1,
+            }
+        }
+        summary.tasks_added = tasks_after
+            .iter()
+            .filter(|t| !before_remote_ids.contains(t.remote_id.as_str()))
+            .count();
+
+        let project_ids_before: HashSet<&str> = projects_before.iter().map(|p| p.remote_id.as_str()).collect();
+        let project_ids_after: HashSet<&str> = projects_after.iter().map(|p| p.remote_id.as_str()).collect();
+        summary.projects_added = project_ids_after.difference(&project_ids_before).count();
+        summary.projects_removed = project_ids_before.difference(&project_ids_after).count();
+
+        summary
+    }
+
+    /// Add the changes of another sync, such as one of another backend
+    pub fn add(&mut self, other: &SyncSummary) {
+        self.tasks_added += other.tasks_added;
+        self.tasks_updated += other.tasks_updated;
+        self.tasks_closed += other.tasks_closed;
+        self.projects_added += other.projects_added;
+        self.projects_removed += other.projects_removed;
+    }
+
+    /// One-line description of the changes, or `None` if nothing changed
+    pub fn describe(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            (
+                self.tasks_added,
+                format!("+{}", count(self.tasks_added, "task", "tasks")),
+            ),
+            (self.tasks_updated, format!("{} updated", self.tasks_updated)),
+            (self.tasks_closed, format!("{} closed elsewhere", self.tasks_closed)),
+            (
+                self.projects_added,
+                format!("{} added", count(self.projects_added, "project", "projects")),
+            ),
+            (
+                self.projects_removed,
+                format!("{} removed", count(self.projects_removed, "project", "projects")),
+            ),
+        ]
+        .into_iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(_, text)| text)
+        .collect();
+
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(", "))
+        }
+    }
+}
+
+/// Counts of local records a completed sync added, changed or removed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncStats {
+    pub tasks_added: usize,
+    /// Existing tasks whose synced fields changed
+    pub tasks_updated: usize,
+    pub tasks_deleted: usize,
+    /// Projects added, changed or removed
+    pub projects_changed: usize,
+    /// Labels added, changed or removed
+    pub labels_changed: usize,
+    /// Sections added, changed or removed
+    pub sections_changed: usize,
+}
+
+impl SyncStats {
+    /// Add the changes of another sync, such as one of another backend
+    pub fn add(&mut self, other: &SyncStats) {
+        self.tasks_added += other.tasks_added;
+        self.tasks_updated += other.tasks_updated;
+        self.tasks_deleted += other.tasks_deleted;
+        self.projects_changed += other.projects_changed;
+        self.labels_changed += other.labels_changed;
+        self.sections_changed += other.sections_changed;
+    }
+
+    /// One-line description of the changes (e.g. "12 added, 3 updated"), or `None` if nothing changed
+    pub fn describe(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            (self.tasks_added, format!("{} added", self.tasks_added)),
+            (self.tasks_updated, format!("{} updated", self.tasks_updated)),
+            (self.tasks_deleted, format!("{} deleted", self.tasks_deleted)),
+            (
+                self.projects_changed,
+                format!("{} changed", count(self.projects_changed, "project", "projects")),
+            ),
+            (
+                self.labels_changed,
+                format!("{} changed", count(self.labels_changed, "label", "labels")),
+            ),
+            (
+                self.sections_changed,
+                format!("{} changed", count(self.sections_changed, "section", "sections")),
+            ),
+        ]
+        .into_iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(_, text)| text)
+        .collect();
+
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(", "))
+        }
+    }
+}
+
+fn count(n: usize, singular: &str, plural: &str) -> String {
+    format!("{} {}", n, if n == 1 { singular } else { plural })
+}
@@ -1,11 +1,19 @@
 use crate::entities::task;
-use crate::repositories::{ProjectRepository, SectionRepository, TaskRepository};
-use crate::sync::SyncService;
+use crate::repositories::{LabelRepository, ProjectRepository, SectionRepository, TaskRepository};
+use crate::sync::batch::{run_batch, BatchCancel, BatchOutcome, ProgressSender};
+use crate::sync::labels::extract_inline_labels;
+use crate::sync::{validate_name, SyncService};
 use crate::utils::datetime;
-use anyhow::Result;
+use crate::utils::fuzzy::fuzzy_score;
+use anyhow::{Context, Result};
 use sea_orm::{ActiveValue, EntityTrait, IntoActiveModel, TransactionTrait};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use uuid::Uuid;
 
+/// Score a search match in the description loses against one in the content
+const DESCRIPTION_MATCH_PENALTY: i64 = 20;
+
 impl SyncService {
     /// Retrieves all tasks for a specific project from local storage.
     ///
@@ -37,10 +45,13 @@ impl SyncService {
         TaskRepository::get_all(&storage.conn).await
     }
 
-    /// Searches for tasks by content using database-level filtering.
+    /// Searches for tasks by content and description.
     ///
-    /// This method performs fast text search across task content using SQL LIKE queries.
-    /// The search is case-insensitive and matches partial content.
+    /// Tasks are matched fuzzily (see [`crate::utils::fuzzy`]), so "by mlk" finds "Buy
+    /// milk", and sorted by match score with open tasks first. Content matches rank above
+    /// description matches. When more tasks are stored than the fuzzy search limit (see
+    /// [`set_fuzzy_search_max_tasks`](Self::set_fuzzy_search_max_tasks)), a
+    /// case-insensitive SQL LIKE search is used instead.
     ///
     /// # Arguments
     /// * `query` - The search term to look for in task content
@@ -52,7 +63,27 @@ impl SyncService {
     /// Returns an error if local storage access fails
     pub async fn search_tasks(&self, query: &str) -> Result<Vec<task::Model>> {
         let storage = self.storage.lock().await;
-        TaskRepository::search(&storage.conn, query).await
+        let task_count = TaskRepository::count(&storage.conn).await?;
+        if task_count > self.fuzzy_search_max_tasks as u64 {
+            return TaskRepository::search(&storage.conn, query).await;
+        }
+
+        let tasks = TaskRepository::get_all(&storage.conn).await?;
+        drop(storage);
+
+        let mut scored: Vec<(i64, task::Model)> = tasks
+            .into_iter()
+            .filter_map(|task| {
+                let score = fuzzy_score(query, &task.content).or_else(|| {
+                    let description = task.description.as_deref()?;
+                    fuzzy_score(query, description).map(|score| score - DESCRIPTION_MATCH_PENALTY)
+                })?;
+                Some((score, task))
+            })
+            .collect();
+        // Stable, so equal scores keep the storage order
+        scored.sort_by_key(|(score, task)| (task.is_deleted, task.is_completed, std::cmp::Reverse(*score)));
+        Ok(scored.into_iter().map(|(_, task)| task).collect())
     }
 
     /// Get tasks with a specific label from local storage (fast)
@@ -61,6 +92,36 @@ impl SyncService {
         TaskRepository::get_with_label(&storage.conn, label_id).await
     }
 
+    /// Removes tasks carrying the label named `label_name` (case-insensitive) from `tasks`.
+    ///
+    /// Used to keep someday/maybe tasks out of the date-based views. Returns `tasks`
+    /// unchanged if `label_name` is empty or no such label exists.
+    ///
+    /// # Errors
+    /// Returns an error if local storage access fails
+    pub async fn exclude_tasks_with_label(
+        &self,
+        tasks: Vec<task::Model>,
+        label_name: &str,
+    ) -> Result<Vec<task::Model>> {
+        if label_name.is_empty() {
+            return Ok(tasks);
+        }
+
+        let labels = self.get_labels().await?;
+        let Some(label) = labels.iter().find(|l| l.name.eq_ignore_ascii_case(label_name)) else {
+            return Ok(tasks);
+        };
+
+        let excluded: HashSet<Uuid> = self
+            .get_tasks_with_label(label.uuid)
+            .await?
+            .into_iter()
+            .map(|task| task.uuid)
+            .collect();
+        Ok(tasks.into_iter().filter(|task| !excluded.contains(&task.uuid)).collect())
+    }
+
     /// Retrieves tasks for the "Today" view with business logic.
     ///
     /// This method implements the UI business logic for the Today view by combining
@@ -97,19 +158,34 @@ impl SyncService {
     /// Retrieves tasks for the "Upcoming" view with business logic.
     ///
     /// This method implements the UI business logic for the Upcoming view by combining
-    /// overdue tasks, today's tasks, and tasks due within the next 3 months.
-    /// Tasks are ordered as: overdue → today → future (next 3 months).
+    /// overdue tasks, today's tasks, and tasks due within the next `days` days.
+    /// Tasks are ordered as: overdue → today → future.
+    ///
+    /// # Arguments
+    /// * `days` - How far ahead to look, in days from today
     ///
     /// # Returns
     /// A vector of `task::Model` objects for the Upcoming view, properly ordered
     ///
     /// # Errors
     /// Returns an error if local storage access fails
-    pub async fn get_tasks_for_upcoming(&self) -> Result<Vec<task::Model>> {
+    pub async fn get_tasks_for_upcoming(&self, days: u32) -> Result<Vec<task::Model>> {
         let storage = self.storage.lock().await;
         let today = datetime::format_today();
-        let three_months_later = datetime::format_date_with_offset(90);
-        TaskRepository::get_for_upcoming(&storage.conn, &today, &three_months_later).await
+        let end_date = datetime::format_date_with_offset(i64::from(days));
+        TaskRepository::get_for_upcoming(&storage.conn, &today, &end_date).await
+    }
+
+    /// Retrieves open tasks whose due date was pushed back at least once.
+    ///
+    /// # Returns
+    /// Tasks ordered by how often they were postponed, most postponed first
+    ///
+    /// # Errors
+    /// Returns an error if local storage access fails
+    pub async fn get_most_postponed_tasks(&self) -> Result<Vec<task::Model>> {
+        let storage = self.storage.lock().await;
+        TaskRepository::get_most_postponed(&storage.conn).await
     }
 
     /// Get a single task by ID from local storage (fast)
@@ -118,51 +194,183 @@ impl SyncService {
         TaskRepository::get_by_id(&storage.conn, task_id).await
     }
 
+    /// Checks whether a task changed locally since `snapshot` was taken, e.g. by a background
+    /// sync while the task was being edited. A task that no longer exists counts as changed.
+    ///
+    /// # Errors
+    /// Returns an error if local storage access fails
+    pub async fn task_changed_since(&self, snapshot: &task::Model) -> Result<bool> {
+        let current = self.get_task_by_id(&snapshot.uuid).await?;
+        Ok(current.map_or(true, |task| task.changed_since(snapshot)))
+    }
+
+    /// Remote ID of the project receiving tasks created without one.
+    ///
+    /// The configured default project (matched by remote ID or case-insensitive name) wins;
+    /// otherwise the synced inbox project is used. `None` means the inbox isn't known
+    /// locally yet, and lets a backend with an inbox file the task there.
+    ///
+    /// # Errors
+    /// Returns an error if the configured project doesn't exist, or if no default project is
+    /// configured for a backend without an inbox
+    async fn default_project_remote_id(&self) -> Result<Option<String>> {
+        let storage = self.storage.lock().await;
+        let Some(default_project) = &self.default_project else {
+            let inbox = ProjectRepository::get_all(&storage.conn)
+                .await?
+                .into_iter()
+                .find(|p| p.backend_uuid == self.backend_uuid && p.is_inbox_project);
+            if let Some(inbox) = inbox {
+                return Ok(Some(inbox.remote_id));
+            }
+            drop(storage);
+            if self.get_backend().await?.has_inbox() {
+                return Ok(None);
+            }
+            anyhow::bail!(
+                "This backend has no inbox: set default_project for it in the [[backends]] config to create tasks without a project"
+            );
+        };
+
+        let wanted = default_project.to_lowercase();
+        ProjectRepository::get_all(&storage.conn)
+            .await?
+            .into_iter()
+            .filter(|p| p.backend_uuid == self.backend_uuid)
+            .find(|p| p.remote_id == *default_project || p.name.to_lowercase() == wanted)
+            .map(|p| Some(p.remote_id))
+            .with_context(|| format!("Default project '{}' not found", default_project))
+    }
+
     /// Creates a new task via the remote backend and stores it locally.
     ///
     /// This method creates a task remotely and immediately stores it in local storage
     /// for instant UI updates. The task will be available in the UI without requiring
     /// a full sync operation.
     ///
+    /// Inline `@label` tokens are removed from the content and applied as labels;
+    /// labels that don't exist yet are created first.
+    ///
     /// # Arguments
     /// * `content` - The content/description of the new task
-    /// * `project_uuid` - Optional local project UUID to assign the task to a specific project
+    /// * `project_uuid` - Optional local project UUID; without one the default project or the
+    ///   backend's inbox is used
     ///
     /// # Errors
-    /// Returns an error if the backend call fails or local storage update fails
+    /// Returns an error if the content is empty, if there is no project to file the task in,
+    /// or if the backend call or local storage update fails
     pub async fn create_task(&self, content: &str, project_uuid: Option<Uuid>) -> Result<()> {
-        // Look up remote_id for project if provided
-        let remote_project_id = {
+        self.create_task_with_details(content, project_uuid, None, None, None, None)
+            .await
+            .map(|_| ())
+    }
+
+    /// Create a task with a section, due date, priority and duration, as picked in the
+    /// creation dialog or typed with quick-add syntax.
+    ///
+    /// `section_uuid` must be a section of the task's project; `due` is "YYYY-MM-DD", or an
+    /// RFC 3339 datetime for a due time (see [`datetime::format_due`]); `priority` uses the
+    /// API scale (4 = p1, 1 = p4); `duration` is "90 minute" (see [`datetime::backend_duration`]).
+    ///
+    /// # Returns
+    /// The task as stored locally
+    pub async fn create_task_with_details(
+        &self,
+        content: &str,
+        project_uuid: Option<Uuid>,
+        section_uuid: Option<Uuid>,
+        due: Option<&str>,
+        priority: Option<i32>,
+        duration: Option<&str>,
+    ) -> Result<task::Model> {
+        let content = validate_name("Task content", content)?;
+        let (content, label_names) = extract_inline_labels(&content);
+        if content.is_empty() {
+            anyhow::bail!("Task content cannot consist of labels only");
+        }
+        let labels = self.resolve_or_create_labels(&label_names).await?;
+
+        // Look up remote_id for project if provided, falling back to the default project
+        let remote_project_id = if let Some(uuid) = project_uuid {
             let storage = self.storage.lock().await;
-            if let Some(uuid) = project_uuid {
-                Some(ProjectRepository::get_remote_id(&storage.conn, &uuid).await?)
-            } else {
-                None
+            Some(ProjectRepository::get_remote_id(&storage.conn, &uuid).await?)
+        } else {
+            self.default_project_remote_id().await?
+        };
+        let remote_section_id = match section_uuid {
+            Some(uuid) => {
+                let storage = self.storage.lock().await;
+                Some(
+                    SectionRepository::get_remote_id(&storage.conn, &uuid)
+                        .await?
+                        .context("Section not found")?,
+                )
             }
-            // Lock is automatically dropped here when storage goes out of scope
+            None => None,
         };
 
         // Create task via backend using backend CreateTaskArgs (lock is not held)
         let task_args = crate::backend::CreateTaskArgs {
-            content: content.to_string(),
+            content,
             description: None,
             project_remote_id: remote_project_id.unwrap_or_default(),
-            section_remote_id: None,
+            section_remote_id: remote_section_id,
             parent_remote_id: None,
-            priority: None,
-            due_date: None,
-            due_datetime: None,
-            duration: None,
-            labels: Vec::new(),
+            priority,
+            due_date: due.filter(|d| d.len() <= 10).map(str::to_string),
+            due_datetime: due.filter(|d| d.len() > 10).map(str::to_string),
+            duration: duration.map(str::to_string),
+            labels,
+        };
+        let backend_task = self.get_backend().await?.create_task(task_args).await?;
+        self.store_created_task(backend_task).await
+    }
+
+    /// Create a copy of a task in the same project and section, with " (copy)" appended
+    /// to its content.
+    ///
+    /// The description, priority, labels and due date are copied; subtasks, comments and
+    /// the deadline are not, and the copy is never completed.
+    ///
+    /// # Errors
+    /// Returns an error if the task is not found, or if the backend call or local storage
+    /// update fails
+    pub async fn duplicate_task(&self, task_uuid: &Uuid) -> Result<()> {
+        let storage = self.storage.lock().await;
+        let task = TaskRepository::get_by_id(&storage.conn, task_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found in local storage: {}", task_uuid))?;
+        let remote_project_id = ProjectRepository::get_remote_id(&storage.conn, &task.project_uuid).await?;
+        let remote_section_id = match &task.section_uuid {
+            Some(section_uuid) => SectionRepository::get_remote_id(&storage.conn, section_uuid).await?,
+            None => None,
         };
-        let backend_task = self
-            .get_backend()
+        let labels = LabelRepository::get_for_task(&storage.conn, task_uuid)
             .await?
-            .create_task(task_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+            .into_iter()
+            .map(|label| label.name)
+            .collect();
+        drop(storage); // Release the lock before API call
 
-        // Store the created task in local database immediately for UI refresh
+        let task_args = crate::backend::CreateTaskArgs {
+            content: format!("{} (copy)", task.content),
+            description: task.description.filter(|d| !d.is_empty()),
+            project_remote_id: remote_project_id,
+            section_remote_id: remote_section_id,
+            parent_remote_id: None,
+            priority: Some(task.priority),
+            // A due time is sent on its own; the local due date is derived from it
+            due_date: task.due_date.filter(|_| task.due_datetime.is_none()),
+            due_datetime: task.due_datetime,
+            duration: task.duration,
+            labels,
+        };
+        let backend_task = self.get_backend().await?.create_task(task_args).await?;
+        self.store_created_task(backend_task).await.map(|_| ())
+    }
+
+    /// Store a task the backend just created, so it shows up before the next sync
+    async fn store_created_task(&self, backend_task: crate::backend::BackendTask) -> Result<task::Model> {
         let storage = self.storage.lock().await;
         let txn = storage.conn.begin().await?;
 
@@ -188,6 +396,7 @@ impl SyncService {
             None
         };
 
+        let remote_id = backend_task.remote_id.clone();
         let local_task = task::ActiveModel {
             uuid: ActiveValue::Set(Uuid::new_v4()),
             backend_uuid: ActiveValue::Set(self.backend_uuid),
@@ -199,13 +408,18 @@ impl SyncService {
             parent_uuid: ActiveValue::Set(parent_uuid),
             priority: ActiveValue::Set(backend_task.priority),
             order_index: ActiveValue::Set(backend_task.order_index),
-            due_date: ActiveValue::Set(backend_task.due_date),
+            due_date: ActiveValue::Set(datetime::local_due_date(
+                backend_task.due_date.as_deref(),
+                backend_task.due_datetime.as_deref(),
+            )),
             due_datetime: ActiveValue::Set(backend_task.due_datetime),
+            due_string: ActiveValue::Set(backend_task.due_string),
             is_recurring: ActiveValue::Set(backend_task.is_recurring),
             deadline: ActiveValue::Set(backend_task.deadline),
             duration: ActiveValue::Set(backend_task.duration),
             is_completed: ActiveValue::Set(backend_task.is_completed),
             is_deleted: ActiveValue::Set(false),
+            postpone_count: ActiveValue::Set(0),
         };
 
         use sea_orm::sea_query::OnConflict;
@@ -222,6 +436,7 @@ impl SyncService {
                     task::Column::OrderIndex,
                     task::Column::DueDate,
                     task::Column::DueDatetime,
+                    task::Column::DueString,
                     task::Column::IsRecurring,
                     task::Column::Deadline,
                     task::Column::Duration,
@@ -232,19 +447,53 @@ impl SyncService {
         );
         insert.exec(&txn).await?;
 
+        // The upsert may keep an existing row's UUID, so look the task up again
+        let task = TaskRepository::get_by_remote_id(&txn, &self.backend_uuid, &remote_id)
+            .await?
+            .context("Created task not found in local storage")?;
+        LabelRepository::set_for_task(&txn, &task.uuid, &backend_task.labels).await?;
+
         txn.commit().await?;
 
-        Ok(())
+        Ok(task)
     }
 
     /// Update task content
+    ///
+    /// Inline `@label` tokens are removed from the content and added to the task's
+    /// existing labels; labels that don't exist yet are created first.
     pub async fn update_task_content(&self, task_uuid: &Uuid, content: &str) -> Result<()> {
+        let (content, label_names) = extract_inline_labels(content);
+        if content.is_empty() {
+            anyhow::bail!("Task content cannot consist of labels only");
+        }
+
+        // Keep the task's current labels and append the inline ones
+        let labels = if label_names.is_empty() {
+            None
+        } else {
+            let mut names: Vec<String> = {
+                let storage = self.storage.lock().await;
+                LabelRepository::get_for_task(&storage.conn, task_uuid)
+                    .await?
+                    .into_iter()
+                    .map(|l| l.name)
+                    .collect()
+            };
+            for name in self.resolve_or_create_labels(&label_names).await? {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+            Some(names)
+        };
+
         // Look up the task's remote_id for backend call
         let remote_id = self.get_task_remote_id(task_uuid).await?;
 
         // Update task via backend using the UpdateTaskArgs structure
         let task_args = crate::backend::UpdateTaskArgs {
-            content: Some(content.to_string()),
+            content: Some(content.clone()),
             description: None,
             project_remote_id: None,
             section_remote_id: None,
@@ -252,29 +501,159 @@ impl SyncService {
             priority: None,
             due_date: None,
             due_datetime: None,
+            deadline: None,
             duration: None,
-            labels: None,
+            labels: labels.clone(),
         };
-        let _task = self
-            .get_backend()
-            .await?
-            .update_task(&remote_id, task_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        let _task = self.get_backend().await?.update_task(&remote_id, task_args).await?;
 
         // Update local storage immediately after successful backend call
         let storage = self.storage.lock().await;
 
         if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_uuid).await? {
             let mut active_model: task::ActiveModel = task.into_active_model();
-            active_model.content = ActiveValue::Set(content.to_string());
+            active_model.content = ActiveValue::Set(content);
+            TaskRepository::update(&storage.conn, active_model).await?;
+        }
+        if let Some(labels) = labels {
+            LabelRepository::set_for_task(&storage.conn, task_uuid, &labels).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Applies several changes to a task in a single backend update.
+    ///
+    /// Only the fields set in `args` are sent and stored, so values changed elsewhere since
+    /// the task was loaded are left alone. As with [`Self::update_task_content`], inline
+    /// `@label`s in new content are added to the task's labels; label names that don't
+    /// exist yet are created. `Some("")` clears the description or the due date.
+    ///
+    /// # Errors
+    /// Returns an error if the content consists of labels only, or if the backend call or
+    /// local storage update fails
+    pub async fn update_task_full(&self, task_uuid: &Uuid, mut args: crate::backend::UpdateTaskArgs) -> Result<()> {
+        if let Some(content) = args.content.take() {
+            let (content, label_names) = extract_inline_labels(&content);
+            if content.is_empty() {
+                anyhow::bail!("Task content cannot consist of labels only");
+            }
+            if !label_names.is_empty() {
+                let mut names = match args.labels.take() {
+                    Some(names) => names,
+                    None => {
+                        let storage = self.storage.lock().await;
+                        LabelRepository::get_for_task(&storage.conn, task_uuid)
+                            .await?
+                            .into_iter()
+                            .map(|l| l.name)
+                            .collect()
+                    }
+                };
+                names.extend(label_names);
+                args.labels = Some(names);
+            }
+            args.content = Some(content);
+        }
+        if let Some(names) = &args.labels {
+            args.labels = Some(self.resolve_or_create_labels(names).await?);
+        }
+
+        let remote_id = self.get_task_remote_id(task_uuid).await?;
+        let updated = self.get_backend().await?.update_task(&remote_id, args.clone()).await?;
+
+        let storage = self.storage.lock().await;
+
+        if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_uuid).await? {
+            let previous_due = task.due_date.clone();
+            let postpone_count = task.postpone_count;
+            let mut active_model: task::ActiveModel = task.into_active_model();
+            if let Some(content) = args.content {
+                active_model.content = ActiveValue::Set(content);
+            }
+            if let Some(description) = args.description {
+                active_model.description = ActiveValue::Set(Some(description).filter(|d| !d.is_empty()));
+            }
+            if let Some(priority) = args.priority {
+                active_model.priority = ActiveValue::Set(priority);
+            }
+            if args.due_date.is_some() || args.due_datetime.is_some() {
+                // A new due date replaces the time as well; an empty date clears both
+                let due_date = datetime::local_due_date(args.due_date.as_deref(), args.due_datetime.as_deref())
+                    .filter(|d| !d.is_empty());
+                if datetime::is_postponement(previous_due.as_deref(), due_date.as_deref()) {
+                    active_model.postpone_count = ActiveValue::Set(postpone_count + 1);
+                }
+                active_model.due_datetime = ActiveValue::Set(args.due_datetime.filter(|_| due_date.is_some()));
+                active_model.due_date = ActiveValue::Set(due_date);
+                // A plain date replaces the recurrence; the backend says what is left of it
+                active_model.due_string = ActiveValue::Set(updated.due_string);
+                active_model.is_recurring = ActiveValue::Set(updated.is_recurring);
+            }
+            if let Some(deadline) = args.deadline {
+                active_model.deadline = ActiveValue::Set(Some(deadline).filter(|d| !d.is_empty()));
+            }
+            if let Some(duration) = args.duration {
+                active_model.duration = ActiveValue::Set(Some(duration).filter(|d| !d.is_empty()));
+            }
             TaskRepository::update(&storage.conn, active_model).await?;
         }
+        if let Some(labels) = &args.labels {
+            LabelRepository::set_for_task(&storage.conn, task_uuid, labels).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the labels of a task.
+    ///
+    /// Label names are matched case-insensitively against existing labels; labels that
+    /// don't exist yet are created first.
+    ///
+    /// # Arguments
+    /// * `task_uuid` - The local UUID of the task
+    /// * `label_names` - The complete set of label names the task should have
+    ///
+    /// # Errors
+    /// Returns an error if creating a label, the backend call or the local storage update fails
+    pub async fn set_task_labels(&self, task_uuid: &Uuid, label_names: &[String]) -> Result<()> {
+        let labels = self.resolve_or_create_labels(label_names).await?;
+        self.update_task_labels(task_uuid, labels).await
+    }
+
+    /// Replace the labels of a task with existing labels, as picked in the task labels dialog
+    ///
+    /// The backend receives the complete list, and the task's local label links are
+    /// rewritten in one transaction so the task is never shown half relabeled.
+    ///
+    /// # Errors
+    /// Returns an error if the backend call or the local storage update fails
+    pub async fn update_task_labels(&self, task_uuid: &Uuid, labels: Vec<String>) -> Result<()> {
+        let remote_id = self.get_task_remote_id(task_uuid).await?;
+
+        let task_args = crate::backend::UpdateTaskArgs {
+            content: None,
+            description: None,
+            project_remote_id: None,
+            section_remote_id: None,
+            parent_remote_id: None,
+            priority: None,
+            due_date: None,
+            due_datetime: None,
+            deadline: None,
+            duration: None,
+            labels: Some(labels.clone()),
+        };
+        let _task = self.get_backend().await?.update_task(&remote_id, task_args).await?;
 
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+        LabelRepository::set_for_task(&txn, task_uuid, &labels).await?;
+        txn.commit().await?;
         Ok(())
     }
 
-    /// Update task due date
+    /// Update task due date ("YYYY-MM-DD"); `None` clears it
     pub async fn update_task_due_date(&self, task_uuid: &Uuid, due_date: Option<&str>) -> Result<()> {
         // Look up the task's remote_id for backend call
         let remote_id = self.get_task_remote_id(task_uuid).await?;
@@ -287,24 +666,102 @@ impl SyncService {
             section_remote_id: None,
             parent_remote_id: None,
             priority: None,
-            due_date: due_date.map(std::string::ToString::to_string),
+            // An empty date clears it; `None` would leave the backend's unchanged
+            due_date: Some(due_date.unwrap_or_default().to_string()),
             due_datetime: None,
+            deadline: None,
             duration: None,
             labels: None,
         };
-        let _task = self
-            .get_backend()
-            .await?
-            .update_task(&remote_id, task_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        let updated = self.get_backend().await?.update_task(&remote_id, task_args).await?;
 
         // Then update local storage
         let storage = self.storage.lock().await;
 
         if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_uuid).await? {
+            let postponed = datetime::is_postponement(task.due_date.as_deref(), due_date);
+            let postpone_count = task.postpone_count;
             let mut active_model: task::ActiveModel = task.into_active_model();
             active_model.due_date = ActiveValue::Set(due_date.map(|s| s.to_string()));
+            active_model.due_datetime = ActiveValue::Set(None);
+            active_model.due_string = ActiveValue::Set(updated.due_string);
+            active_model.is_recurring = ActiveValue::Set(updated.is_recurring);
+            if postponed {
+                active_model.postpone_count = ActiveValue::Set(postpone_count + 1);
+            }
+            TaskRepository::update(&storage.conn, active_model).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Update task due date and time, as an RFC 3339 datetime
+    ///
+    /// The backend gets only the datetime; locally the task also keeps the day it falls
+    /// on in `due_date`, which date-based views filter on.
+    pub async fn update_task_due_datetime(&self, task_uuid: &Uuid, due_datetime: &str) -> Result<()> {
+        let due_date = datetime::local_due_date(None, Some(due_datetime))
+            .ok_or_else(|| anyhow::anyhow!("Invalid due datetime: {}", due_datetime))?;
+
+        // Look up the task's remote_id for backend call
+        let remote_id = self.get_task_remote_id(task_uuid).await?;
+
+        let task_args = crate::backend::UpdateTaskArgs {
+            due_datetime: Some(due_datetime.to_string()),
+            ..Default::default()
+        };
+        let updated = self.get_backend().await?.update_task(&remote_id, task_args).await?;
+
+        // Then update local storage
+        let storage = self.storage.lock().await;
+
+        if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_uuid).await? {
+            let postponed = datetime::is_postponement(task.due_date.as_deref(), Some(&due_date));
+            let postpone_count = task.postpone_count;
+            let mut active_model: task::ActiveModel = task.into_active_model();
+            active_model.due_date = ActiveValue::Set(Some(due_date));
+            active_model.due_datetime = ActiveValue::Set(Some(due_datetime.to_string()));
+            active_model.due_string = ActiveValue::Set(updated.due_string);
+            active_model.is_recurring = ActiveValue::Set(updated.is_recurring);
+            if postponed {
+                active_model.postpone_count = ActiveValue::Set(postpone_count + 1);
+            }
+            TaskRepository::update(&storage.conn, active_model).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Sets or clears a task's deadline.
+    ///
+    /// Deadlines are the date a task must be finished by, tracked separately from the
+    /// scheduled due date. Passing `None` removes the deadline, which is sent to the
+    /// backend as an empty deadline.
+    ///
+    /// # Errors
+    /// Returns an error if the backend update or local storage update fails
+    pub async fn update_task_deadline(&self, task_uuid: &Uuid, deadline: Option<&str>) -> Result<()> {
+        let remote_id = self.get_task_remote_id(task_uuid).await?;
+
+        let task_args = crate::backend::UpdateTaskArgs {
+            content: None,
+            description: None,
+            project_remote_id: None,
+            section_remote_id: None,
+            parent_remote_id: None,
+            priority: None,
+            due_date: None,
+            due_datetime: None,
+            deadline: Some(deadline.unwrap_or_default().to_string()),
+            duration: None,
+            labels: None,
+        };
+        let _task = self.get_backend().await?.update_task(&remote_id, task_args).await?;
+
+        let storage = self.storage.lock().await;
+        if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_uuid).await? {
+            let mut active_model: task::ActiveModel = task.into_active_model();
+            active_model.deadline = ActiveValue::Set(deadline.map(|s| s.to_string()));
             TaskRepository::update(&storage.conn, active_model).await?;
         }
 
@@ -326,15 +783,11 @@ impl SyncService {
             priority: Some(priority),
             due_date: None,
             due_datetime: None,
+            deadline: None,
             duration: None,
             labels: None,
         };
-        let _task = self
-            .get_backend()
-            .await?
-            .update_task(&remote_id, task_args)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        let _task = self.get_backend().await?.update_task(&remote_id, task_args).await?;
 
         // Then update local storage
         let storage = self.storage.lock().await;
@@ -348,11 +801,291 @@ impl SyncService {
         Ok(())
     }
 
+    /// Files a task under a section of its project, or directly under the project.
+    ///
+    /// # Arguments
+    /// * `task_uuid` - The local UUID of the task
+    /// * `section_uuid` - A section of the task's project, or `None` to take the task out
+    ///   of its section
+    ///
+    /// # Errors
+    /// Returns an error if the section belongs to another project, or if the backend call
+    /// or local storage update fails
+    pub async fn set_task_section(&self, task_uuid: &Uuid, section_uuid: Option<Uuid>) -> Result<()> {
+        let (remote_id, project_remote_id, section_remote_id) = {
+            let storage = self.storage.lock().await;
+            let task = TaskRepository::get_by_id(&storage.conn, task_uuid)
+                .await?
+                .context("Task not found")?;
+            let section_remote_id = match section_uuid {
+                Some(uuid) => {
+                    let section = SectionRepository::get_by_id(&storage.conn, &uuid)
+                        .await?
+                        .context("Section not found")?;
+                    if section.project_uuid != task.project_uuid {
+                        anyhow::bail!("Section '{}' belongs to another project", section.name);
+                    }
+                    Some(section.remote_id)
+                }
+                None => None,
+            };
+            let project_remote_id = ProjectRepository::get_remote_id(&storage.conn, &task.project_uuid).await?;
+            (task.remote_id, project_remote_id, section_remote_id)
+        };
+
+        // Without a section the task moves to the top level of its own project
+        let task_args = crate::backend::UpdateTaskArgs {
+            content: None,
+            description: None,
+            project_remote_id: section_remote_id.is_none().then_some(project_remote_id),
+            section_remote_id,
+            parent_remote_id: None,
+            priority: None,
+            due_date: None,
+            due_datetime: None,
+            deadline: None,
+            duration: None,
+            labels: None,
+        };
+        self.get_backend().await?.update_task(&remote_id, task_args).await?;
+
+        let storage = self.storage.lock().await;
+        if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_uuid).await? {
+            let mut active_model: task::ActiveModel = task.into_active_model();
+            active_model.section_uuid = ActiveValue::Set(section_uuid);
+            TaskRepository::update(&storage.conn, active_model).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Move a task up (`offset` < 0) or down among its siblings: the open tasks of the
+    /// same project, section and parent.
+    ///
+    /// The siblings are numbered again from 1 in their new order, on the backend and
+    /// locally, so tasks that shared an `order_index` get a stable order too. Moving past
+    /// the first or last sibling does nothing.
+    ///
+    /// # Returns
+    /// Whether the task moved
+    ///
+    /// # Errors
+    /// Returns an error if the task is not found, or if the backend call or local storage
+    /// update fails
+    pub async fn reorder_task(&self, task_uuid: &Uuid, offset: i32) -> Result<bool> {
+        let storage = self.storage.lock().await;
+        let task = TaskRepository::get_by_id(&storage.conn, task_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found in local storage: {}", task_uuid))?;
+        let mut siblings: Vec<task::Model> = TaskRepository::get_for_project(&storage.conn, &task.project_uuid)
+            .await?
+            .into_iter()
+            .filter(|t| !t.is_completed && !t.is_deleted)
+            .filter(|t| t.section_uuid == task.section_uuid && t.parent_uuid == task.parent_uuid)
+            .collect();
+        drop(storage); // Release the lock before API call
+
+        let Some(position) = siblings.iter().position(|t| t.uuid == *task_uuid) else {
+            return Ok(false);
+        };
+        let target = (position as i64 + i64::from(offset)).clamp(0, siblings.len() as i64 - 1) as usize;
+        if target == position {
+            return Ok(false);
+        }
+        let moved = siblings.remove(position);
+        siblings.insert(target, moved);
+
+        let orders: Vec<(String, i32)> = siblings
+            .iter()
+            .zip(1..)
+            .map(|(sibling, order)| (sibling.remote_id.clone(), order))
+            .collect();
+        self.get_backend().await?.reorder_tasks(&orders).await?;
+
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+        for (sibling, order) in siblings.into_iter().zip(1..) {
+            let mut active_model = sibling.into_active_model();
+            active_model.order_index = ActiveValue::Set(order);
+            TaskRepository::update(&txn, active_model).await?;
+        }
+        txn.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Moves a task to another project via the remote backend, then updates local storage.
+    ///
+    /// The task leaves its section and parent, which belong to the old project. Its
+    /// subtasks (recursively) follow it into the new project, as the backend moves them
+    /// together with their parent.
+    ///
+    /// # Arguments
+    /// * `task_uuid` - The local UUID of the task to move
+    /// * `project_uuid` - The local UUID of the destination project
+    ///
+    /// # Errors
+    /// Returns an error if the project doesn't exist or belongs to another backend, the
+    /// backend call fails or the local storage update fails
+    pub async fn move_task(&self, task_uuid: &Uuid, project_uuid: &Uuid) -> Result<()> {
+        let remote_id = self.get_task_remote_id(task_uuid).await?;
+        let project_remote_id = {
+            let storage = self.storage.lock().await;
+            let project = ProjectRepository::get_by_id(&storage.conn, project_uuid)
+                .await?
+                .with_context(|| format!("Project {} not found", project_uuid))?;
+            if project.backend_uuid != self.backend_uuid {
+                anyhow::bail!("Tasks can't be moved to a project of another account");
+            }
+            project.remote_id
+        };
+
+        let task_args = crate::backend::UpdateTaskArgs {
+            content: None,
+            description: None,
+            project_remote_id: Some(project_remote_id),
+            section_remote_id: None,
+            parent_remote_id: None,
+            priority: None,
+            due_date: None,
+            due_datetime: None,
+            deadline: None,
+            duration: None,
+            labels: None,
+        };
+        self.get_backend().await?.update_task(&remote_id, task_args).await?;
+
+        let storage = self.storage.lock().await;
+        let txn = storage.conn.begin().await?;
+        if let Some(task) = TaskRepository::get_by_id(&txn, task_uuid).await? {
+            let mut active_model: task::ActiveModel = task.into_active_model();
+            active_model.project_uuid = ActiveValue::Set(*project_uuid);
+            active_model.section_uuid = ActiveValue::Set(None);
+            active_model.parent_uuid = ActiveValue::Set(None);
+            TaskRepository::update(&txn, active_model).await?;
+
+            let mut pending = vec![*task_uuid];
+            while let Some(uuid) = pending.pop() {
+                for subtask in TaskRepository::get_children(&txn, &uuid).await? {
+                    pending.push(subtask.uuid);
+                    let mut active_model: task::ActiveModel = subtask.into_active_model();
+                    active_model.project_uuid = ActiveValue::Set(*project_uuid);
+                    active_model.section_uuid = ActiveValue::Set(None);
+                    TaskRepository::update(&txn, active_model).await?;
+                }
+            }
+        }
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// Nests several tasks under one parent task, one backend call per task.
+    ///
+    /// The tasks move into the parent's project and section, and so do their own
+    /// subtasks. Tasks whose backend update fails keep their place and are counted as
+    /// failed; each of the others is updated locally right after its backend call.
+    ///
+    /// # Arguments
+    /// * `parent_uuid` - The local UUID of the new parent task
+    /// * `task_uuids` - Local UUIDs of the tasks to nest; the parent itself is ignored
+    /// * `progress` - Receives a `BatchProgress` update after each task
+    /// * `cancel` - Stops issuing further backend calls once set
+    ///
+    /// # Errors
+    /// Returns an error before contacting the backend if the parent doesn't exist or if
+    /// one of the tasks is the parent's ancestor, since nesting it would create a cycle
+    pub async fn reparent_tasks(
+        &self,
+        parent_uuid: &Uuid,
+        task_uuids: &[Uuid],
+        progress: &ProgressSender,
+        cancel: &BatchCancel,
+    ) -> Result<BatchOutcome> {
+        let task_uuids: Vec<Uuid> = task_uuids.iter().copied().filter(|uuid| uuid != parent_uuid).collect();
+
+        let parent = {
+            let storage = self.storage.lock().await;
+            let parent = TaskRepository::get_by_id(&storage.conn, parent_uuid)
+                .await?
+                .context("Parent task not found")?;
+
+            // Walk up from the parent: nesting any of its ancestors under it would form a loop
+            let mut ancestor_uuid = parent.parent_uuid;
+            while let Some(uuid) = ancestor_uuid {
+                let Some(ancestor) = TaskRepository::get_by_id(&storage.conn, &uuid).await? else {
+                    break;
+                };
+                if task_uuids.contains(&ancestor.uuid) {
+                    anyhow::bail!(
+                        "Cannot make '{}' a subtask of '{}', which is already nested under it",
+                        ancestor.content,
+                        parent.content
+                    );
+                }
+                ancestor_uuid = ancestor.parent_uuid;
+            }
+            parent
+        };
+
+        let backend = self.get_backend().await?;
+        let parent = &parent;
+        let backend = &backend;
+        let outcome = run_batch(&task_uuids, progress, cancel, |task_uuid| async move {
+            let remote_id = self.get_task_remote_id(&task_uuid).await?;
+            let task_args = crate::backend::UpdateTaskArgs {
+                content: None,
+                description: None,
+                project_remote_id: None,
+                section_remote_id: None,
+                parent_remote_id: Some(parent.remote_id.clone()),
+                priority: None,
+                due_date: None,
+                due_datetime: None,
+                deadline: None,
+                duration: None,
+                labels: None,
+            };
+            backend.update_task(&remote_id, task_args).await?;
+
+            let storage = self.storage.lock().await;
+            let txn = storage.conn.begin().await?;
+            if let Some(task) = TaskRepository::get_by_id(&txn, &task_uuid).await? {
+                let mut active_model: task::ActiveModel = task.into_active_model();
+                active_model.parent_uuid = ActiveValue::Set(Some(parent.uuid));
+                active_model.project_uuid = ActiveValue::Set(parent.project_uuid);
+                active_model.section_uuid = ActiveValue::Set(parent.section_uuid);
+                TaskRepository::update(&txn, active_model).await?;
+
+                let mut pending = vec![task_uuid];
+                while let Some(uuid) = pending.pop() {
+                    for subtask in TaskRepository::get_children(&txn, &uuid).await? {
+                        pending.push(subtask.uuid);
+                        let mut active_model: task::ActiveModel = subtask.into_active_model();
+                        active_model.project_uuid = ActiveValue::Set(parent.project_uuid);
+                        active_model.section_uuid = ActiveValue::Set(parent.section_uuid);
+                        TaskRepository::update(&txn, active_model).await?;
+                    }
+                }
+            }
+            txn.commit().await?;
+            Ok(())
+        })
+        .await;
+
+        Ok(outcome)
+    }
+
     /// Marks a task as completed via the remote backend and removes it from local storage.
     ///
     /// This method completes the task remotely (which automatically handles subtasks)
-    /// and removes it from local storage since completed tasks are not displayed in the UI.
-    /// Subtasks are automatically deleted via database CASCADE constraints.
+    /// and marks it completed in local storage. Unless disabled with
+    /// [`SyncService::set_complete_subtasks_with_parent`], its subtasks (recursively) are
+    /// marked completed as well, since the backend closes them together with the parent.
+    ///
+    /// Recurring tasks are not closed by the backend but moved to their next occurrence,
+    /// so they stay open locally with the due date reported by the backend. They are only
+    /// marked completed when the backend no longer lists them (e.g. the last occurrence).
     ///
     /// # Arguments
     /// * `task_uuid` - The local UUID of the task to complete
@@ -364,24 +1097,62 @@ impl SyncService {
         let remote_id = self.get_task_remote_id(task_uuid).await?;
 
         // Complete the task via backend using remote_id (this handles subtasks automatically)
-        self.get_backend()
-            .await?
-            .complete_task(&remote_id)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        self.get_backend().await?.complete_task(&remote_id).await?;
+
+        // Recurring tasks are moved to their next due date by the backend instead of being closed
+        let is_recurring = self.get_task_by_id(task_uuid).await?.is_some_and(|task| task.is_recurring);
+        let next_occurrence = if is_recurring {
+            self.get_backend().await?.fetch_task(&remote_id).await?
+        } else {
+            None
+        };
 
-        // Then mark as completed in local storage (soft completion)
+        // Then update local storage (soft completion or new due date)
         let storage = self.storage.lock().await;
 
         if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_uuid).await? {
             let mut active_model: task::ActiveModel = task.into_active_model();
-            active_model.is_completed = ActiveValue::Set(true);
+            let completed = next_occurrence.is_none();
+            match next_occurrence {
+                Some(next) => {
+                    active_model.due_date = ActiveValue::Set(datetime::local_due_date(
+                        next.due_date.as_deref(),
+                        next.due_datetime.as_deref(),
+                    ));
+                    active_model.due_datetime = ActiveValue::Set(next.due_datetime);
+                    active_model.due_string = ActiveValue::Set(next.due_string);
+                }
+                None => active_model.is_completed = ActiveValue::Set(true),
+            }
             TaskRepository::update(&storage.conn, active_model).await?;
+
+            if completed && self.complete_subtasks_with_parent {
+                Self::complete_local_subtasks(&storage.conn, task_uuid).await?;
+            }
         }
 
         Ok(())
     }
 
+    /// Marks every open descendant of a task completed in local storage.
+    async fn complete_local_subtasks<C>(conn: &C, parent_uuid: &Uuid) -> Result<()>
+    where
+        C: sea_orm::ConnectionTrait,
+    {
+        let mut pending = vec![*parent_uuid];
+        while let Some(uuid) = pending.pop() {
+            for subtask in TaskRepository::get_children(conn, &uuid).await? {
+                pending.push(subtask.uuid);
+                if !subtask.is_completed {
+                    let mut active_model: task::ActiveModel = subtask.into_active_model();
+                    active_model.is_completed = ActiveValue::Set(true);
+                    TaskRepository::update(conn, active_model).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Permanently deletes a task via the remote backend and removes it from local storage.
     ///
     /// This method performs a hard delete of the task remotely, soft delete locally.
@@ -397,11 +1168,7 @@ impl SyncService {
         let remote_id = self.get_task_remote_id(task_uuid).await?;
 
         // Delete the task via backend using remote_id
-        self.get_backend()
-            .await?
-            .delete_task(&remote_id)
-            .await
-            .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        self.get_backend().await?.delete_task(&remote_id).await?;
 
         // Then mark as deleted in local storage (soft deletion)
         let storage = self.storage.lock().await;
@@ -415,144 +1182,122 @@ impl SyncService {
         Ok(())
     }
 
+    /// What [`restore_deleted_task`](Self::restore_deleted_task) needs besides the stored
+    /// task, taken while the task still has its labels and dates.
+    pub async fn snapshot_task(&self, task_uuid: &Uuid) -> Result<TaskSnapshot> {
+        let storage = self.storage.lock().await;
+        let task = TaskRepository::get_by_id(&storage.conn, task_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found in local storage: {}", task_uuid))?;
+        let labels = LabelRepository::get_for_task(&storage.conn, task_uuid)
+            .await?
+            .into_iter()
+            .map(|label| label.name)
+            .collect();
+        Ok(TaskSnapshot {
+            labels,
+            due: task.due_datetime.or(task.due_date),
+            deadline: task.deadline,
+        })
+    }
+
     /// Restore a soft-deleted or completed task via the remote backend and locally
-    /// For completed tasks, reopens them. For deleted tasks, recreates them via backend.
+    /// For completed tasks, reopens them. For deleted tasks, recreates them via backend
+    /// with the labels and dates they have now.
     pub async fn restore_task(&self, task_id: &Uuid) -> Result<()> {
         // First, get the task from local storage to check its state
         let storage = self.storage.lock().await;
         let task = TaskRepository::get_by_id(&storage.conn, task_id)
             .await?
             .ok_or_else(|| anyhow::anyhow!("Task not found in local storage: {}", task_id))?;
+        drop(storage);
 
         if task.is_deleted {
-            // For deleted tasks, we need to recreate them via backend
-            // Look up remote IDs before dropping storage lock
-            let remote_project_id = ProjectRepository::get_remote_id(&storage.conn, &task.project_uuid).await?;
-            let remote_section_id = if let Some(section_uuid) = &task.section_uuid {
-                SectionRepository::get_remote_id(&storage.conn, section_uuid).await?
-            } else {
-                None
-            };
-            let remote_parent_id = if let Some(parent_uuid) = &task.parent_uuid {
-                Some(TaskRepository::get_remote_id(&storage.conn, parent_uuid).await?)
-            } else {
-                None
-            };
-
-            drop(storage); // Release the lock before API call
-
-            // Create the task again via backend
-            let task_args = crate::backend::CreateTaskArgs {
-                content: task.content.clone(),
-                description: task.description.clone().filter(|d| !d.is_empty()),
-                project_remote_id: remote_project_id,
-                section_remote_id: remote_section_id,
-                parent_remote_id: remote_parent_id,
-                priority: Some(task.priority),
-                due_date: task.due_date.clone(),
-                due_datetime: task.due_datetime.clone(),
-                duration: task.duration.clone(),
-                labels: Vec::new(), // Labels will be synced separately
-            };
-
-            let new_task = self
-                .get_backend()
-                .await?
-                .create_task(task_args)
-                .await
-                .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
-
-            // Update local storage: remove the old soft-deleted task and add the new one
-            let storage = self.storage.lock().await;
+            let snapshot = self.snapshot_task(task_id).await?;
+            return self.restore_deleted_task(task_id, &snapshot).await;
+        }
 
-            // Hard delete the old soft-deleted task
-            if let Some(old_task) = TaskRepository::get_by_id(&storage.conn, task_id).await? {
-                TaskRepository::delete(&storage.conn, old_task).await?;
-            }
+        // For completed tasks, just reopen them
+        self.get_backend().await?.reopen_task(&task.remote_id).await?;
 
-            // Store the new task (reuse the single task upsert logic)
-            let txn = storage.conn.begin().await?;
+        // Clear local completion flag
+        let storage = self.storage.lock().await;
 
-            let project_uuid =
-                Self::lookup_project_uuid(&txn, &self.backend_uuid, &new_task.project_remote_id, "task restore")
-                    .await?;
+        if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_id).await? {
+            let mut active_model: task::ActiveModel = task.into_active_model();
+            active_model.is_completed = ActiveValue::Set(false);
+            TaskRepository::update(&storage.conn, active_model).await?;
+        }
 
-            let section_uuid =
-                Self::lookup_section_uuid(&txn, &self.backend_uuid, new_task.section_remote_id.as_ref()).await?;
+        Ok(())
+    }
 
-            let parent_uuid = if let Some(remote_parent_id) = &new_task.parent_remote_id {
-                TaskRepository::get_by_remote_id(&txn, &self.backend_uuid, remote_parent_id)
-                    .await?
-                    .map(|t| t.uuid)
-            } else {
-                None
-            };
+    /// Recreate a soft-deleted task via the backend, with the labels, due date and
+    /// deadline of `snapshot` (see [`snapshot_task`](Self::snapshot_task)).
+    ///
+    /// The backend gives the task a new ID, so the soft-deleted copy is replaced by the
+    /// new task locally.
+    pub async fn restore_deleted_task(&self, task_id: &Uuid, snapshot: &TaskSnapshot) -> Result<()> {
+        // Look up remote IDs before dropping storage lock
+        let storage = self.storage.lock().await;
+        let task = TaskRepository::get_by_id(&storage.conn, task_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task not found in local storage: {}", task_id))?;
+        let remote_project_id = ProjectRepository::get_remote_id(&storage.conn, &task.project_uuid).await?;
+        let remote_section_id = if let Some(section_uuid) = &task.section_uuid {
+            SectionRepository::get_remote_id(&storage.conn, section_uuid).await?
+        } else {
+            None
+        };
+        let remote_parent_id = if let Some(parent_uuid) = &task.parent_uuid {
+            Some(TaskRepository::get_remote_id(&storage.conn, parent_uuid).await?)
+        } else {
+            None
+        };
 
-            let local_task = task::ActiveModel {
-                uuid: ActiveValue::Set(Uuid::new_v4()),
-                backend_uuid: ActiveValue::Set(self.backend_uuid),
-                remote_id: ActiveValue::Set(new_task.remote_id),
-                content: ActiveValue::Set(new_task.content),
-                description: ActiveValue::Set(new_task.description),
-                project_uuid: ActiveValue::Set(project_uuid),
-                section_uuid: ActiveValue::Set(section_uuid),
-                parent_uuid: ActiveValue::Set(parent_uuid),
-                priority: ActiveValue::Set(new_task.priority),
-                order_index: ActiveValue::Set(new_task.order_index),
-                due_date: ActiveValue::Set(new_task.due_date),
-                due_datetime: ActiveValue::Set(new_task.due_datetime),
-                is_recurring: ActiveValue::Set(new_task.is_recurring),
-                deadline: ActiveValue::Set(new_task.deadline),
-                duration: ActiveValue::Set(new_task.duration),
-                is_completed: ActiveValue::Set(new_task.is_completed),
-                is_deleted: ActiveValue::Set(false),
-            };
+        drop(storage); // Release the lock before API call
 
-            use sea_orm::sea_query::OnConflict;
-            let mut insert = task::Entity::insert(local_task);
-            insert = insert.on_conflict(
-                OnConflict::columns([task::Column::BackendUuid, task::Column::RemoteId])
-                    .update_columns([
-                        task::Column::Content,
-                        task::Column::Description,
-                        task::Column::ProjectUuid,
-                        task::Column::SectionUuid,
-                        task::Column::ParentUuid,
-                        task::Column::Priority,
-                        task::Column::OrderIndex,
-                        task::Column::DueDate,
-                        task::Column::DueDatetime,
-                        task::Column::IsRecurring,
-                        task::Column::Deadline,
-                        task::Column::Duration,
-                        task::Column::IsCompleted,
-                        task::Column::IsDeleted,
-                    ])
-                    .to_owned(),
-            );
-            insert.exec(&txn).await?;
+        // Create the task again via backend
+        let due = snapshot.due.as_deref();
+        let task_args = crate::backend::CreateTaskArgs {
+            content: task.content.clone(),
+            description: task.description.clone().filter(|d| !d.is_empty()),
+            project_remote_id: remote_project_id,
+            section_remote_id: remote_section_id,
+            parent_remote_id: remote_parent_id,
+            priority: Some(task.priority),
+            // A due time is sent on its own; the local due date is derived from it
+            due_date: due.filter(|d| d.len() <= 10).map(str::to_string),
+            due_datetime: due.filter(|d| d.len() > 10).map(str::to_string),
+            duration: task.duration.clone(),
+            labels: snapshot.labels.clone(),
+        };
 
-            txn.commit().await?;
-        } else {
-            // For completed tasks, just reopen them
-            let remote_id = task.remote_id.clone();
-            drop(storage); // Release the lock before API call
-            self.get_backend()
-                .await?
-                .reopen_task(&remote_id)
-                .await
-                .map_err(|e| anyhow::anyhow!("Backend error: {}", e))?;
+        let new_task = self.get_backend().await?.create_task(task_args).await?;
 
-            // Clear local completion flag
+        // Hard delete the old soft-deleted task, then store the new one
+        {
             let storage = self.storage.lock().await;
-
-            if let Some(task) = TaskRepository::get_by_id(&storage.conn, task_id).await? {
-                let mut active_model: task::ActiveModel = task.into_active_model();
-                active_model.is_completed = ActiveValue::Set(false);
-                TaskRepository::update(&storage.conn, active_model).await?;
+            if let Some(old_task) = TaskRepository::get_by_id(&storage.conn, task_id).await? {
+                TaskRepository::delete(&storage.conn, old_task).await?;
             }
         }
+        let restored = self.store_created_task(new_task).await?;
+
+        // Tasks are created without a deadline, so set it afterwards
+        if let Some(deadline) = &snapshot.deadline {
+            self.update_task_deadline(&restored.uuid, Some(deadline)).await?;
+        }
 
         Ok(())
     }
 }
+
+/// The labels and dates of a task, to recreate it as it was once deleted
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskSnapshot {
+    pub labels: Vec<String>,
+    /// Due date (YYYY-MM-DD), or date and time when the task has a due time
+    pub due: Option<String>,
+    pub deadline: Option<String>,
+}
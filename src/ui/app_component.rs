@@ -1,22 +1,33 @@
+use crate::backend::UpdateTaskArgs;
 use crate::config::Config;
 use crate::constants::*;
 use crate::entities::{label, project, section, task};
+use crate::sync::labels::{extract_inline_labels, unknown_label_names};
+use crate::sync::tasks::TaskSnapshot;
 use crate::sync::{SyncService, SyncStatus};
+use crate::ui::components::sidebar_component::special_view_selection;
+use crate::ui::components::task_detail::{render_task_detail, TaskDetail, DETAIL_PANE_MIN_WIDTH};
 use crate::ui::components::{DialogComponent, SidebarComponent, TaskListComponent};
-use crate::ui::core::SidebarSelection;
 use crate::ui::core::{
-    actions::{Action, DialogType},
+    actions::{Action, BatchReport, DialogType},
     event_handler::EventType,
+    keymap::{KeyAction, KeyMap, StatusContext},
     task_manager::{TaskId, TaskManager},
+    theme::Theme,
+    undo::{UndoEntry, UndoStack},
     Component,
 };
-use crate::utils::datetime;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::ui::core::{SidebarSection, SidebarSelection};
+use crate::ui_state::UiState;
+use crate::utils::{datetime, export};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use log::info;
 use ratatui::{
     layout::{Constraint, Layout, Rect},
     Frame,
 };
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use uuid::Uuid;
 
@@ -28,6 +39,8 @@ pub struct AppState {
     pub labels: Vec<label::Model>,
     pub sections: Vec<section::Model>,
     pub sidebar_selection: SidebarSelection,
+    /// Connected backends by UUID and name, the one changes go to by default first
+    pub accounts: Vec<(Uuid, String)>,
     pub loading: bool,
     pub error_message: Option<String>,
     pub info_message: Option<String>,
@@ -74,23 +87,89 @@ pub struct AppComponent {
 
     // Configuration
     config: Config,
+    ui_state: UiState,
 
     // Simple UI state
     should_quit: bool,
     active_sync_task: Option<TaskId>,
     is_initial_sync: bool,
+    // Background sync started by the auto-sync timer, and when the last sync finished
+    is_auto_sync: bool,
+    last_sync_at: Option<Instant>,
+
+    // Focus mode state (distraction-free view of a single task)
+    focus_mode: bool,
+    focus_task_uuid: Option<Uuid>,
+    focus_started_at: Option<std::time::Instant>,
+    focus_timer_visible: bool,
+
+    // Recurring tasks being completed, with their due date before completion
+    pending_reschedules: HashMap<Uuid, Option<String>>,
+
+    // Task operations that can be undone, newest last
+    undo_stack: UndoStack,
+
+    // Unobtrusive notification and when it expires (e.g. background sync summary)
+    toast: Option<(String, Instant)>,
+
+    // Keys bound to each action, from the [keybindings] config
+    keymap: KeyMap,
+
+    // Colors from the [theme] config
+    theme: Theme,
 
     // Layout state
     sidebar_visible: bool,
+    // Preferred sidebar width from the config or a drag of its border, before fitting it to the screen
+    sidebar_columns: u16,
     sidebar_width: u16,
+    resizing_sidebar: bool,
+    details_visible: bool,
+    details_width: u16,
     screen_width: u16,
     screen_height: u16,
 }
 
 impl AppComponent {
-    pub fn new(sync_service: SyncService, config: Config) -> Self {
-        let sidebar = SidebarComponent::new();
-        let task_list = TaskListComponent::new();
+    pub fn new(mut sync_service: SyncService, config: Config) -> Self {
+        sync_service.set_complete_subtasks_with_parent(config.behavior.complete_subtasks_with_parent);
+        sync_service.set_fuzzy_search_max_tasks(config.behavior.fuzzy_search_max_tasks);
+        let ui_state = UiState::load();
+        let mut sidebar = SidebarComponent::new();
+        for section in ui_state
+            .collapsed_sidebar_sections
+            .iter()
+            .filter_map(|key| SidebarSection::from_key(key))
+        {
+            sidebar.set_section_collapsed(section, true);
+        }
+        let mut task_list = TaskListComponent::new();
+        task_list.set_upcoming_days(
+            ui_state
+                .upcoming_days
+                .filter(|_| config.ui.persist_state)
+                .unwrap_or(config.views.upcoming_days),
+        );
+        task_list.set_sort(config.views.default_sort);
+        let mut dialog = DialogComponent::new();
+        let keymap = match KeyMap::from_config(&config.ui).with_keybindings(&config.keybindings) {
+            Ok(keymap) => keymap,
+            Err(e) => {
+                log::warn!("Keybindings: {}", e);
+                dialog.update(Action::ShowDialog(DialogType::Error(e.to_string())));
+                KeyMap::from_config(&config.ui)
+            }
+        };
+        dialog.set_keymap(keymap.clone());
+        // Already validated when the config was loaded
+        let theme = Theme::from_config(&config.theme).unwrap_or_default();
+        sidebar.set_theme(theme);
+        task_list.set_theme(theme);
+        dialog.set_theme(theme);
+        let icon_theme = config.ui.icon_theme();
+        sidebar.set_icon_theme(icon_theme);
+        task_list.set_icon_theme(icon_theme);
+        dialog.set_icon_theme(icon_theme);
         let (task_manager, background_action_rx) = TaskManager::new();
 
         let state = AppState {
@@ -101,17 +180,41 @@ impl AppComponent {
         Self {
             sidebar,
             task_list,
-            dialog: DialogComponent::new(),
+            dialog,
             state,
             sync_service,
             task_manager,
             background_action_rx,
-            sidebar_visible: config.ui.sidebar_visible,
+            sidebar_visible: ui_state
+                .sidebar_visible
+                .filter(|_| config.ui.persist_state)
+                .unwrap_or(config.ui.sidebar_visible),
+            sidebar_columns: ui_state
+                .sidebar_width
+                .filter(|_| config.ui.persist_state)
+                .map_or(config.ui.sidebar_width, |width| {
+                    width.clamp(SIDEBAR_MIN_WIDTH, SIDEBAR_MAX_WIDTH)
+                }),
+            details_visible: ui_state.details_visible.filter(|_| config.ui.persist_state).unwrap_or(false),
             config,
+            keymap,
+            theme,
+            ui_state,
             should_quit: false,
             active_sync_task: None,
             is_initial_sync: false,
+            is_auto_sync: false,
+            last_sync_at: None,
+            focus_mode: false,
+            focus_task_uuid: None,
+            focus_started_at: None,
+            focus_timer_visible: true,
+            pending_reschedules: HashMap::new(),
+            undo_stack: UndoStack::default(),
+            toast: None,
             sidebar_width: 30, // Default width
+            resizing_sidebar: false,
+            details_width: 0,
             screen_width: 100, // Default width
             screen_height: 50, // Default height
         }
@@ -126,6 +229,116 @@ impl AppComponent {
         self.task_manager.task_count()
     }
 
+    /// Check if the UI needs to be redrawn on every tick (e.g. for the focus timer)
+    pub fn needs_periodic_render(&self) -> bool {
+        (self.focus_mode && self.focus_timer_visible)
+            || self.task_list.has_rescheduled_highlights()
+            || self.toast.is_some()
+    }
+
+    /// Drop expired transient highlights and toasts, returning true if the UI needs a redraw
+    pub fn clear_expired_highlights(&mut self) -> bool {
+        let now = Instant::now();
+        let toast_expired = self.toast.as_ref().is_some_and(|(_, until)| *until <= now);
+        if toast_expired {
+            self.toast = None;
+        }
+        self.task_list.clear_expired_highlights(now) || toast_expired
+    }
+
+    /// Show a toast that disappears on its own after a few seconds
+    fn show_toast(&mut self, message: String) {
+        info!("Toast: {}", message);
+        self.toast = Some((message, Instant::now() + Duration::from_millis(TOAST_DURATION_MS)));
+    }
+
+    /// Name of the current view, as shown in the sidebar
+    fn view_name(&self) -> String {
+        match &self.state.sidebar_selection {
+            SidebarSelection::Today => "Today".to_string(),
+            SidebarSelection::Tomorrow => "Tomorrow".to_string(),
+            SidebarSelection::Upcoming => "Upcoming".to_string(),
+            SidebarSelection::Postponed => "Postponed".to_string(),
+            SidebarSelection::All => "All tasks".to_string(),
+            SidebarSelection::Filter(index) => self
+                .config
+                .filters
+                .get(*index)
+                .map_or_else(|| "Filter".to_string(), |filter| filter.name.clone()),
+            SidebarSelection::Label(index) => self
+                .state
+                .labels
+                .get(*index)
+                .map_or_else(|| "Label".to_string(), |label| format!("@{}", label.name)),
+            SidebarSelection::Project(index) => self
+                .state
+                .projects
+                .get(*index)
+                .map_or_else(|| "Project".to_string(), |project| project.name.clone()),
+        }
+    }
+
+    /// Write the tasks listed in the current view to `path`, returning how many were written
+    fn export_view(&self, format: export::ExportFormat, path: &str) -> anyhow::Result<usize> {
+        let groups = self.task_list.export_groups();
+        let task_labels = self.task_list.task_labels();
+        let content = match format {
+            export::ExportFormat::Markdown => {
+                export::to_markdown(&self.view_name(), &groups, &self.state.projects, task_labels)
+            }
+            export::ExportFormat::Csv => export::to_csv(&groups, &self.state.projects, task_labels),
+        };
+        std::fs::write(export::expand_home(path), content)?;
+        Ok(groups.iter().map(|group| group.tasks.len()).sum())
+    }
+
+    /// Switch to a reloaded configuration
+    ///
+    /// Display, behavior, sync, view, filter, keybinding and theme settings take effect
+    /// at once. The backend, its credentials and the database path are only read at
+    /// startup.
+    ///
+    /// # Errors
+    /// Returns an error, leaving the current configuration in place, if the keybindings
+    /// or theme are invalid
+    pub fn apply_config(&mut self, config: Config) -> anyhow::Result<()> {
+        let keymap = KeyMap::from_config(&config.ui).with_keybindings(&config.keybindings)?;
+        let theme = Theme::from_config(&config.theme)?;
+
+        self.sync_service
+            .set_complete_subtasks_with_parent(config.behavior.complete_subtasks_with_parent);
+        self.sync_service
+            .set_fuzzy_search_max_tasks(config.behavior.fuzzy_search_max_tasks);
+        self.sync_service.set_retry_policy(config.sync.retry_policy());
+        self.sync_service.set_fail_on_section_error(config.sync.fail_on_section_error);
+
+        self.dialog.set_keymap(keymap.clone());
+        self.keymap = keymap;
+        self.sidebar.set_theme(theme);
+        self.task_list.set_theme(theme);
+        self.dialog.set_theme(theme);
+        self.theme = theme;
+        let icon_theme = config.ui.icon_theme();
+        self.sidebar.set_icon_theme(icon_theme);
+        self.task_list.set_icon_theme(icon_theme);
+        self.dialog.set_icon_theme(icon_theme);
+
+        // A saved filter that was removed can't stay selected
+        if let SidebarSelection::Filter(index) = self.state.sidebar_selection {
+            if index >= config.filters.len() {
+                self.state.sidebar_selection = SidebarSelection::Today;
+            }
+        }
+        // A width changed in the file replaces the one dragged with the mouse
+        if config.ui.sidebar_width != self.config.ui.sidebar_width {
+            self.sidebar_columns = config.ui.sidebar_width;
+        }
+        self.config = config;
+        self.sidebar_width = self.calculate_sidebar_width(self.screen_width);
+        self.sync_component_data();
+        Ok(())
+    }
+
     /// Check if currently syncing
     pub fn is_syncing(&self) -> bool {
         self.active_sync_task.is_some()
@@ -141,11 +354,15 @@ impl AppComponent {
         self.state.projects.len()
     }
 
-    /// Trigger initial sync on startup (unless in debug mode)
+    /// Trigger initial sync on startup (unless in debug mode or manual-only sync)
     pub fn trigger_initial_sync(&mut self) {
-        if self.sync_service.is_debug_mode() {
-            info!("AppComponent: Skipping initial sync (debug mode)");
-            // In debug mode, just load existing data from database
+        if self.sync_service.is_debug_mode() || !self.config.sync.automatic_sync_enabled() {
+            if self.sync_service.is_debug_mode() {
+                info!("AppComponent: Skipping initial sync (debug mode)");
+            } else {
+                info!("AppComponent: Skipping initial sync (manual-only sync, press 'r' to sync)");
+            }
+            // Just load existing data from database
             self.is_initial_sync = true;
             self.schedule_initial_data_fetch();
             self.is_initial_sync = false;
@@ -160,9 +377,57 @@ impl AppComponent {
         }
     }
 
+    /// Start a background sync once the configured auto-sync interval has passed since the last sync.
+    ///
+    /// Called on every tick. Returns true if a sync was started.
+    pub fn maybe_start_auto_sync(&mut self) -> bool {
+        let Some(interval) = self.config.sync.auto_sync_interval() else {
+            return false;
+        };
+        // Wait for the first sync to finish, and don't refresh data under an open dialog
+        let due = self.last_sync_at.is_some_and(|at| at.elapsed() >= interval);
+        if !due || self.is_syncing() || self.state.loading || self.dialog.is_visible() {
+            return false;
+        }
+        if self.sync_service.is_debug_mode() {
+            return false;
+        }
+
+        info!("AppComponent: Starting auto-sync (every {}s)", interval.as_secs());
+        self.is_auto_sync = true;
+        self.start_background_sync();
+        true
+    }
+
+    /// View to open on startup: the last one used if state persistence is on, else the configured default
+    fn startup_view(&self) -> String {
+        self.ui_state
+            .last_view
+            .clone()
+            .filter(|_| self.config.ui.persist_state)
+            .unwrap_or_else(|| self.config.ui.default_project.clone())
+    }
+
+    /// Key identifying a sidebar selection across restarts (see `UiState::last_view`)
+    fn view_key(&self, selection: &SidebarSelection) -> Option<String> {
+        match selection {
+            SidebarSelection::Today => Some("today".to_string()),
+            SidebarSelection::Tomorrow => Some("tomorrow".to_string()),
+            SidebarSelection::Upcoming => Some("upcoming".to_string()),
+            SidebarSelection::Postponed => Some("postponed".to_string()),
+            SidebarSelection::All => Some("all".to_string()),
+            SidebarSelection::Filter(index) => self.config.filters.get(*index).map(|f| format!("filter:{}", f.name)),
+            SidebarSelection::Project(index) => {
+                self.state.projects.get(*index).map(|p| format!("project:{}", p.remote_id))
+            }
+            SidebarSelection::Label(index) => self.state.labels.get(*index).map(|l| format!("label:{}", l.remote_id)),
+        }
+    }
+
     /// Set initial sidebar selection based on config
     fn set_initial_sidebar_selection(&mut self) {
-        let selection = match self.config.ui.default_project.as_str() {
+        let view = self.startup_view();
+        let selection = match view.as_str() {
             "inbox" => {
                 // Find inbox project
                 if let Some(inbox_index) = self.state.projects.iter().position(|p| p.is_inbox_project) {
@@ -174,6 +439,34 @@ impl AppComponent {
             "today" => SidebarSelection::Today,
             "tomorrow" => SidebarSelection::Tomorrow,
             "upcoming" => SidebarSelection::Upcoming,
+            "postponed" => SidebarSelection::Postponed,
+            "all" => SidebarSelection::All,
+            "someday" => special_view_selection(
+                "someday",
+                &self.state.projects,
+                &self.state.labels,
+                &self.config.behavior.someday_label,
+            )
+            .map_or(SidebarSelection::Today, |(_, selection)| selection),
+            // Saved views use remote IDs since local UUIDs change with every sync into a fresh database
+            saved_view if saved_view.starts_with("project:") => self
+                .state
+                .projects
+                .iter()
+                .position(|p| p.remote_id == saved_view["project:".len()..])
+                .map_or(SidebarSelection::Today, SidebarSelection::Project),
+            saved_view if saved_view.starts_with("filter:") => self
+                .config
+                .filters
+                .iter()
+                .position(|f| f.name == saved_view["filter:".len()..])
+                .map_or(SidebarSelection::Today, SidebarSelection::Filter),
+            saved_view if saved_view.starts_with("label:") => self
+                .state
+                .labels
+                .iter()
+                .position(|l| l.remote_id == saved_view["label:".len()..])
+                .map_or(SidebarSelection::Today, SidebarSelection::Label),
             project_id_or_name => {
                 // Try to find project by ID first (parse as UUID), then by name
                 if let Ok(uuid) = Uuid::parse_str(project_id_or_name) {
@@ -196,6 +489,37 @@ impl AppComponent {
             }
         };
 
+        // A hidden special view cannot be highlighted; open the first configured view instead
+        let selection = match selection {
+            SidebarSelection::Today
+            | SidebarSelection::Tomorrow
+            | SidebarSelection::Upcoming
+            | SidebarSelection::Postponed
+            | SidebarSelection::All => {
+                let visible_views: Vec<SidebarSelection> = self
+                    .config
+                    .ui
+                    .views
+                    .iter()
+                    .filter_map(|view| {
+                        special_view_selection(
+                            view,
+                            &self.state.projects,
+                            &self.state.labels,
+                            &self.config.behavior.someday_label,
+                        )
+                    })
+                    .map(|(_, selection)| selection)
+                    .collect();
+                if visible_views.contains(&selection) {
+                    selection
+                } else {
+                    visible_views.into_iter().next().unwrap_or(selection)
+                }
+            }
+            other => other,
+        };
+
         self.state.sidebar_selection = selection;
         info!(
             "AppComponent: Set initial sidebar selection to {:?}",
@@ -206,11 +530,22 @@ impl AppComponent {
     /// Update all components with current data
     fn sync_component_data(&mut self) {
         // Update sidebar
+        self.sidebar.set_views(self.config.ui.views.clone());
+        self.sidebar
+            .set_filters(self.config.filters.iter().map(|f| f.name.clone()).collect());
+        self.sidebar.set_someday_label(self.config.behavior.someday_label.clone());
+        self.sidebar.set_group_sections(self.config.ui.sidebar_sections);
+        self.sidebar.set_project_progress(self.config.display.show_project_progress);
+        self.sidebar.set_accounts(self.state.accounts.clone());
         self.sidebar.update_data(self.state.projects.clone(), self.state.labels.clone());
         self.sidebar.selection = self.state.sidebar_selection.clone();
 
         // Update task list
         self.task_list.update_display_config(self.config.display.clone());
+        self.task_list.update_views_config(self.config.views.clone());
+        self.task_list
+            .set_selection_after_removal(self.config.ui.selection_after_removal);
+        self.task_list.set_accounts(self.state.accounts.clone());
         self.task_list.update_data(
             self.state.tasks.clone(),
             self.state.sections.clone(),
@@ -221,11 +556,15 @@ impl AppComponent {
 
         // Update dialog
         self.dialog.update_display_config(self.config.display.clone());
+        if self.config.ui.preselect_recent_project {
+            self.dialog.set_recent_projects(self.ui_state.recent_project_uuids.clone());
+        }
         self.dialog.update_data_with_tasks(
             self.state.projects.clone(),
             self.state.labels.clone(),
             self.state.tasks.clone(),
         );
+        self.dialog.set_sections(self.state.sections.clone());
         self.dialog.set_sync_service(self.sync_service.clone());
     }
 
@@ -238,43 +577,85 @@ impl AppComponent {
                 KeyCode::Down => return Action::HelpScrollDown,
                 KeyCode::Home => return Action::HelpScrollToTop,
                 KeyCode::End => return Action::HelpScrollToBottom,
-                KeyCode::Char('?') | KeyCode::Esc => return Action::ShowHelp(false),
+                KeyCode::Esc => return Action::ShowHelp(false),
+                _ if self.keymap.is(KeyAction::Help, &key) => return Action::ShowHelp(false),
                 _ => {} // Continue to other key handling
             }
         }
 
-        match key.code {
-            KeyCode::Char('b') => {
-                info!("Global key: 'b' - toggling sidebar visibility");
+        // Keys that can't be rebound
+        let Some(key_action) = self.keymap.action_for(&key) else {
+            return match key.code {
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    info!("Global key: Ctrl+C - quitting application");
+                    Action::Quit
+                }
+                KeyCode::Char('R') if self.sync_service.is_debug_mode() => {
+                    info!("Global key: 'R' - refreshing local data (debug mode)");
+                    Action::RefreshLocalData
+                }
+                KeyCode::Esc => {
+                    if self.dialog.is_visible() {
+                        info!("Global key: Esc - closing dialog");
+                        Action::HideDialog
+                    } else {
+                        info!("Global key: Esc - quitting application");
+                        Action::Quit
+                    }
+                }
+                _ => Action::None,
+            };
+        };
+
+        match key_action {
+            KeyAction::ToggleSidebar => {
+                info!("Global key: toggle_sidebar - toggling sidebar visibility");
                 Action::ToggleSidebar
             }
-            KeyCode::Char('q') => {
-                info!("Global key: 'q' - quitting application");
-                Action::Quit
+            KeyAction::ToggleDetails => {
+                info!("Global key: toggle_details - toggling task detail pane");
+                Action::ToggleDetails
             }
-            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                info!("Global key: Ctrl+C - quitting application");
+            KeyAction::JumpToProject => {
+                info!("Global key: jump_to_project - typing a project name in the sidebar");
+                self.sidebar.start_jump();
+                if self.sidebar_visible {
+                    Action::None
+                } else {
+                    Action::ToggleSidebar
+                }
+            }
+            KeyAction::Quit => {
+                info!("Global key: quit - quitting application");
                 Action::Quit
             }
-            KeyCode::Char('?') | KeyCode::Char('h') => {
-                info!("Global key: '?' or 'h' - opening help dialog");
+            KeyAction::Help => {
+                info!("Global key: help - opening help dialog");
                 Action::ShowDialog(DialogType::Help)
             }
-            KeyCode::Char('G') => {
-                info!("Global key: 'G' - opening logs dialog");
+            KeyAction::Logs => {
+                info!("Global key: logs - opening logs dialog");
                 Action::ShowDialog(DialogType::Logs)
             }
-            KeyCode::Char('A') => {
-                info!("Global key: 'A' - opening project creation dialog");
+            KeyAction::BackendStatus => {
+                info!("Global key: backend_status - opening backend status dialog");
+                Action::ShowDialog(DialogType::BackendStatus)
+            }
+            KeyAction::ReloadConfig => {
+                info!("Global key: reload_config - reloading configuration");
+                Action::ReloadConfig
+            }
+            KeyAction::CreateProject => {
+                info!("Global key: create_project - opening project creation dialog");
                 Action::ShowDialog(DialogType::ProjectCreation)
             }
-            KeyCode::Char('D') => {
+            KeyAction::DeleteItem => {
                 // Delete current project (only if a project is selected)
                 match &self.state.sidebar_selection {
                     SidebarSelection::Project(index) => {
                         if let Some(project) = self.state.projects.get(*index) {
                             info!(
-                                "Global key: 'D' - deleting project '{}' (ID: {})",
+                                "Global key: delete_item - deleting project '{}' (ID: {})",
                                 project.name, project.uuid
                             );
                             Action::ShowDialog(DialogType::DeleteConfirmation {
@@ -282,160 +663,452 @@ impl AppComponent {
                                 item_uuid: project.uuid,
                             })
                         } else {
-                            info!("Global key: 'D' - no project selected (invalid index)");
+                            info!("Global key: delete_item - no project selected (invalid index)");
                             Action::ShowDialog(DialogType::Error("No project selected to delete".to_string()))
                         }
                     }
                     SidebarSelection::Today => {
-                        info!("Global key: 'D' - cannot delete Today view");
+                        info!("Global key: delete_item - cannot delete Today view");
                         Action::ShowDialog(DialogType::Info(UI_CANNOT_DELETE_TODAY_VIEW.to_string()))
                     }
                     SidebarSelection::Tomorrow => {
-                        info!("Global key: 'D' - cannot delete Tomorrow view");
+                        info!("Global key: delete_item - cannot delete Tomorrow view");
                         Action::ShowDialog(DialogType::Info("Cannot delete the Tomorrow view".to_string()))
                     }
                     SidebarSelection::Upcoming => {
-                        info!("Global key: 'D' - cannot delete Upcoming view");
+                        info!("Global key: delete_item - cannot delete Upcoming view");
                         Action::ShowDialog(DialogType::Info("Cannot delete the Upcoming view".to_string()))
                     }
+                    SidebarSelection::Postponed => {
+                        info!("Global key: delete_item - cannot delete Postponed view");
+                        Action::ShowDialog(DialogType::Info("Cannot delete the Postponed view".to_string()))
+                    }
+                    SidebarSelection::All => {
+                        info!("Global key: delete_item - cannot delete All tasks view");
+                        Action::ShowDialog(DialogType::Info("Cannot delete the All tasks view".to_string()))
+                    }
+                    SidebarSelection::Filter(_) => {
+                        info!("Global key: delete_item - saved filters are removed in the config file");
+                        Action::ShowDialog(DialogType::Info(UI_FILTERS_EDITED_IN_CONFIG.to_string()))
+                    }
                     SidebarSelection::Label(index) => {
                         if let Some(label) = self.state.labels.get(*index) {
-                            info!("Global key: 'D' - deleting label '{}' (ID: {})", label.name, label.uuid);
+                            info!(
+                                "Global key: delete_item - deleting label '{}' (ID: {})",
+                                label.name, label.uuid
+                            );
                             Action::ShowDialog(DialogType::DeleteConfirmation {
                                 item_type: "label".to_string(),
                                 item_uuid: label.uuid,
                             })
                         } else {
-                            info!("Global key: 'D' - no label selected (invalid index)");
+                            info!("Global key: delete_item - no label selected (invalid index)");
                             Action::ShowDialog(DialogType::Error("No label selected to delete".to_string()))
                         }
                     }
                 }
             }
-            KeyCode::Char('E') => {
+            KeyAction::EditItem => {
                 // Edit current sidebar selection (project or label)
                 match &self.state.sidebar_selection {
                     SidebarSelection::Project(index) => {
                         if let Some(project) = self.state.projects.get(*index) {
                             info!(
-                                "Global key: 'E' - editing project '{}' (ID: {})",
+                                "Global key: edit_item - editing project '{}' (ID: {})",
                                 project.name, project.uuid
                             );
                             Action::ShowDialog(DialogType::ProjectEdit {
                                 project_uuid: project.uuid,
                                 name: project.name.clone(),
+                                color: project.color.clone(),
                             })
                         } else {
-                            info!("Global key: 'E' - no project selected (invalid index)");
+                            info!("Global key: edit_item - no project selected (invalid index)");
                             Action::ShowDialog(DialogType::Error("No project selected to edit".to_string()))
                         }
                     }
                     SidebarSelection::Today => {
-                        info!("Global key: 'E' - cannot edit Today view");
+                        info!("Global key: edit_item - cannot edit Today view");
                         Action::ShowDialog(DialogType::Info("Cannot edit the Today view".to_string()))
                     }
                     SidebarSelection::Tomorrow => {
-                        info!("Global key: 'E' - cannot edit Tomorrow view");
+                        info!("Global key: edit_item - cannot edit Tomorrow view");
                         Action::ShowDialog(DialogType::Info("Cannot edit the Tomorrow view".to_string()))
                     }
                     SidebarSelection::Upcoming => {
-                        info!("Global key: 'E' - cannot edit Upcoming view");
+                        info!("Global key: edit_item - cannot edit Upcoming view");
                         Action::ShowDialog(DialogType::Info("Cannot edit the Upcoming view".to_string()))
                     }
+                    SidebarSelection::Postponed => {
+                        info!("Global key: edit_item - cannot edit Postponed view");
+                        Action::ShowDialog(DialogType::Info("Cannot edit the Postponed view".to_string()))
+                    }
+                    SidebarSelection::All => {
+                        info!("Global key: edit_item - cannot edit All tasks view");
+                        Action::ShowDialog(DialogType::Info("Cannot edit the All tasks view".to_string()))
+                    }
+                    SidebarSelection::Filter(_) => {
+                        info!("Global key: edit_item - saved filters are edited in the config file");
+                        Action::ShowDialog(DialogType::Info(UI_FILTERS_EDITED_IN_CONFIG.to_string()))
+                    }
                     SidebarSelection::Label(index) => {
                         if let Some(label) = self.state.labels.get(*index) {
-                            info!("Global key: 'E' - editing label '{}' (ID: {})", label.name, label.uuid);
+                            info!(
+                                "Global key: edit_item - editing label '{}' (ID: {})",
+                                label.name, label.uuid
+                            );
                             Action::ShowDialog(DialogType::LabelEdit {
                                 label_uuid: label.uuid,
                                 name: label.name.clone(),
+                                color: label.color.clone(),
+                                is_favorite: label.is_favorite,
                             })
                         } else {
-                            info!("Global key: 'E' - no label selected (invalid index)");
+                            info!("Global key: edit_item - no label selected (invalid index)");
                             Action::ShowDialog(DialogType::Error("No label selected to edit".to_string()))
                         }
                     }
                 }
             }
-            KeyCode::Char('r') => {
-                info!("Global key: 'r' - starting manual sync");
-                Action::StartSync
-            }
-            KeyCode::Char('R') => {
-                if self.sync_service.is_debug_mode() {
-                    info!("Global key: 'R' - refreshing local data (debug mode)");
-                    Action::RefreshLocalData
-                } else {
-                    Action::None
+            KeyAction::ToggleFavorite => match &self.state.sidebar_selection {
+                SidebarSelection::Project(index) => match self.state.projects.get(*index) {
+                    Some(project) => {
+                        info!(
+                            "Global key: toggle_favorite - toggling favorite of project '{}' (ID: {})",
+                            project.name, project.uuid
+                        );
+                        Action::ToggleProjectFavorite(project.uuid)
+                    }
+                    None => Action::None,
+                },
+                SidebarSelection::Label(index) => match self.state.labels.get(*index) {
+                    Some(label) => {
+                        info!(
+                            "Global key: toggle_favorite - toggling favorite of label '{}' (ID: {})",
+                            label.name, label.uuid
+                        );
+                        Action::ToggleLabelFavorite(label.uuid)
+                    }
+                    None => Action::None,
+                },
+                _ => {
+                    info!("Global key: toggle_favorite - no project or label selected");
+                    Action::ShowDialog(DialogType::Info(
+                        "Only projects and labels can be favorites".to_string(),
+                    ))
                 }
+            },
+            KeyAction::Sync => {
+                info!("Global key: sync - starting manual sync");
+                Action::StartSync
             }
-            KeyCode::Char('/') => {
-                info!("Global key: '/' - opening task search dialog");
+            KeyAction::Search => {
+                info!("Global key: search - opening task search dialog");
                 Action::ShowDialog(DialogType::TaskSearch)
             }
-            KeyCode::Char('t') => {
+            KeyAction::DueToday => {
                 // Set task due date to today
                 if let Some(task) = self.task_list.get_selected_task() {
-                    info!("Global key: 't' - setting task '{}' due today", task.content);
-                    Action::SetTaskDueToday(task.uuid)
+                    info!("Global key: due_today - setting task '{}' due today", task.content);
+                    self.confirm_due_shortcut(Action::SetTaskDueToday(task.uuid))
                 } else {
-                    info!("Global key: 't' - no task selected");
+                    info!("Global key: due_today - no task selected");
                     Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_DUE_DATE.to_string()))
                 }
             }
-            KeyCode::Char('T') => {
+            KeyAction::DueTomorrow => {
                 // Set task due date to tomorrow
                 if let Some(task) = self.task_list.get_selected_task() {
-                    info!("Global key: 'T' - setting task '{}' due tomorrow", task.content);
-                    Action::SetTaskDueTomorrow(task.uuid)
+                    info!(
+                        "Global key: due_tomorrow - setting task '{}' due tomorrow",
+                        task.content
+                    );
+                    self.confirm_due_shortcut(Action::SetTaskDueTomorrow(task.uuid))
                 } else {
-                    info!("Global key: 'T' - no task selected");
+                    info!("Global key: due_tomorrow - no task selected");
                     Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_DUE_DATE.to_string()))
                 }
             }
-            KeyCode::Char('w') => {
+            KeyAction::DueNextWeek => {
                 // Set task due date to next week (Monday)
                 if let Some(task) = self.task_list.get_selected_task() {
-                    info!("Global key: 'w' - setting task '{}' due next week", task.content);
-                    Action::SetTaskDueNextWeek(task.uuid)
+                    info!(
+                        "Global key: due_next_week - setting task '{}' due next week",
+                        task.content
+                    );
+                    self.confirm_due_shortcut(Action::SetTaskDueNextWeek(task.uuid))
                 } else {
-                    info!("Global key: 'w' - no task selected");
+                    info!("Global key: due_next_week - no task selected");
                     Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_DUE_DATE.to_string()))
                 }
             }
-            KeyCode::Char('W') => {
+            KeyAction::DueWeekEnd => {
                 // Set task due date to weekend (Saturday)
                 if let Some(task) = self.task_list.get_selected_task() {
-                    info!("Global key: 'W' - setting task '{}' due weekend", task.content);
-                    Action::SetTaskDueWeekEnd(task.uuid)
+                    info!("Global key: due_weekend - setting task '{}' due weekend", task.content);
+                    self.confirm_due_shortcut(Action::SetTaskDueWeekEnd(task.uuid))
                 } else {
-                    info!("Global key: 'W' - no task selected");
+                    info!("Global key: due_weekend - no task selected");
                     Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_DUE_DATE.to_string()))
                 }
             }
-            KeyCode::Esc => {
-                if self.dialog.is_visible() {
-                    info!("Global key: Esc - closing dialog");
-                    Action::HideDialog
+            KeyAction::DueDate => {
+                // Set any due date, typed in plain English, or clear it
+                if let Some(task) = self.task_list.get_selected_task() {
+                    info!(
+                        "Global key: due_date - opening due date prompt for task '{}'",
+                        task.content
+                    );
+                    Action::ShowDialog(DialogType::SetDueDate {
+                        task_uuid: task.uuid,
+                        content: task.content.clone(),
+                        due: task.due().map(|due| due.display("%Y-%m-%d", "%H:%M")),
+                    })
                 } else {
-                    info!("Global key: Esc - quitting application");
-                    Action::Quit
+                    info!("Global key: due_date - no task selected");
+                    Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_DUE_DATE.to_string()))
+                }
+            }
+            KeyAction::PostponeDay | KeyAction::AdvanceDay => {
+                let days = if key_action == KeyAction::PostponeDay { 1 } else { -1 };
+                if let Some(task) = self.task_list.get_selected_task() {
+                    info!(
+                        "Global key: {} - moving due date of task '{}' by {} days",
+                        key_action.name(),
+                        task.content,
+                        days
+                    );
+                    Action::PostponeTask {
+                        task_uuid: task.uuid,
+                        days,
+                    }
+                } else {
+                    info!("Global key: {} - no task selected", key_action.name());
+                    Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_DUE_DATE.to_string()))
+                }
+            }
+            KeyAction::Deadline => {
+                // Set or clear the task deadline ("until"), typed in plain English
+                if let Some(task) = self.task_list.get_selected_task() {
+                    info!(
+                        "Global key: deadline - opening deadline prompt for task '{}'",
+                        task.content
+                    );
+                    Action::ShowDialog(DialogType::DeadlinePrompt {
+                        task_uuid: task.uuid,
+                        content: task.content.clone(),
+                        deadline: task.deadline.clone(),
+                    })
+                } else {
+                    info!("Global key: deadline - no task selected");
+                    Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_DEADLINE.to_string()))
                 }
             }
+            KeyAction::Focus => {
+                if let Some(task) = self.task_list.get_selected_task() {
+                    info!("Global key: focus - entering focus mode for task '{}'", task.content);
+                    Action::ToggleFocusMode
+                } else {
+                    info!("Global key: focus - no task selected");
+                    Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_FOCUS.to_string()))
+                }
+            }
+            KeyAction::Undo => {
+                info!("Global key: undo - undoing last task operation");
+                Action::UndoLastOperation
+            }
+            KeyAction::TaskLabels => {
+                if let Some(task) = self.task_list.get_selected_task() {
+                    info!(
+                        "Global key: task_labels - opening label picker for task '{}'",
+                        task.content
+                    );
+                    Action::ShowDialog(DialogType::TaskLabels {
+                        task_uuid: task.uuid,
+                        content: task.content.clone(),
+                        labels: Vec::new(),
+                    })
+                } else {
+                    info!("Global key: task_labels - no task selected");
+                    Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_LABELS.to_string()))
+                }
+            }
+            KeyAction::MoveTaskUp | KeyAction::MoveTaskDown => {
+                let offset = if key_action == KeyAction::MoveTaskUp { -1 } else { 1 };
+                if let Some(task) = self.task_list.get_selected_task() {
+                    info!("Global key: {} - moving task '{}'", key_action.name(), task.content);
+                    Action::ReorderTask {
+                        task_uuid: task.uuid,
+                        offset,
+                    }
+                } else {
+                    info!("Global key: {} - no task selected", key_action.name());
+                    Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_REORDER.to_string()))
+                }
+            }
+            KeyAction::ExportView => {
+                info!("Global key: export_view - opening export dialog");
+                Action::ShowDialog(DialogType::ExportView {
+                    path: export::default_path(
+                        &self.config.behavior.export_dir,
+                        &self.view_name(),
+                        export::ExportFormat::Markdown,
+                        datetime::today(),
+                    ),
+                })
+            }
+            KeyAction::DuplicateTask => {
+                if let Some(task) = self.task_list.get_selected_task() {
+                    info!("Global key: duplicate_task - duplicating task '{}'", task.content);
+                    Action::DuplicateTask(task.uuid)
+                } else {
+                    info!("Global key: duplicate_task - no task selected");
+                    Action::ShowDialog(DialogType::Info(UI_NO_TASK_SELECTED_DUPLICATE.to_string()))
+                }
+            }
+            KeyAction::FindDuplicates => {
+                info!("Global key: find_duplicates - opening duplicate tasks dialog");
+                Action::ShowDialog(DialogType::DuplicateTasks)
+            }
+            KeyAction::RescheduleOverdue => {
+                // Reschedule every overdue task in the current view to today
+                let today = datetime::today();
+                let overdue: Vec<Uuid> = self
+                    .state
+                    .tasks
+                    .iter()
+                    .filter(|t| !t.is_completed && !t.is_deleted)
+                    .filter(|t| t.due().is_some_and(|due| due.date() < today))
+                    .map(|t| t.uuid)
+                    .collect();
+                if overdue.is_empty() {
+                    info!("Global key: reschedule_overdue - no overdue tasks in current view");
+                    Action::ShowDialog(DialogType::Info(UI_NO_OVERDUE_TASKS.to_string()))
+                } else {
+                    info!(
+                        "Global key: reschedule_overdue - rescheduling {} overdue tasks to today",
+                        overdue.len()
+                    );
+                    Action::RescheduleOverdueTasks(overdue)
+                }
+            }
+            // Component actions the sidebar and task list had no use for, and quick add
             _ => Action::None,
         }
     }
 
+    /// Handle keys while focus mode is active
+    fn handle_focus_key(&mut self, key: KeyEvent) -> Action {
+        match key.code {
+            _ if key.code == KeyCode::Esc || self.keymap.is(KeyAction::Focus, &key) => {
+                info!("Focus: exiting focus mode");
+                Action::ToggleFocusMode
+            }
+            KeyCode::Char(' ') | KeyCode::Enter => match self.focus_task_uuid {
+                Some(task_uuid) => {
+                    info!("Focus: completing focused task {}", task_uuid);
+                    self.exit_focus_mode();
+                    Action::CompleteTask(task_uuid.to_string())
+                }
+                None => Action::None,
+            },
+            KeyCode::Char('t') => {
+                self.focus_timer_visible = !self.focus_timer_visible;
+                Action::None
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::Quit,
+            _ if self.keymap.is(KeyAction::Quit, &key) => Action::Quit,
+            _ => Action::None,
+        }
+    }
+
+    fn exit_focus_mode(&mut self) {
+        self.focus_mode = false;
+        self.focus_task_uuid = None;
+        self.focus_started_at = None;
+    }
+
+    /// Open task creation targeting the inbox, regardless of the current view
+    fn quick_add_to_inbox(&self) -> Action {
+        let inbox_uuid = self.state.projects.iter().find(|p| p.is_inbox_project).map(|p| p.uuid);
+        info!("Global key: quick_add - opening quick add task dialog (inbox)");
+        Action::ShowDialog(DialogType::TaskCreation {
+            default_project_uuid: inbox_uuid,
+        })
+    }
+
     /// Handle app-level actions that require business logic
     pub async fn handle_app_action(&mut self, action: Action) -> Action {
         match action {
             Action::ToggleSidebar => {
                 self.sidebar_visible = !self.sidebar_visible;
+                if self.config.ui.persist_state {
+                    self.ui_state.sidebar_visible = Some(self.sidebar_visible);
+                    if let Err(e) = self.ui_state.save() {
+                        log::warn!("Sidebar: Failed to save sidebar visibility: {:#}", e);
+                    }
+                }
+                Action::None
+            }
+            Action::ToggleDetails => {
+                self.details_visible = !self.details_visible;
+                if self.config.ui.persist_state {
+                    self.ui_state.details_visible = Some(self.details_visible);
+                    if let Err(e) = self.ui_state.save() {
+                        log::warn!("Details: Failed to save detail pane visibility: {:#}", e);
+                    }
+                }
+                Action::None
+            }
+            Action::SetUpcomingDays(days) => {
+                info!("Upcoming: Showing the next {} days", days);
+                self.task_list.set_upcoming_days(days);
+                if self.config.ui.persist_state {
+                    self.ui_state.upcoming_days = Some(days);
+                    if let Err(e) = self.ui_state.save() {
+                        log::warn!("Upcoming: Failed to save horizon: {:#}", e);
+                    }
+                }
+                self.schedule_data_fetch();
+                Action::None
+            }
+            Action::ToggleFocusMode => {
+                if self.focus_mode {
+                    self.exit_focus_mode();
+                } else if let Some(task) = self.task_list.get_selected_task() {
+                    self.focus_task_uuid = Some(task.uuid);
+                    self.focus_started_at = Some(std::time::Instant::now());
+                    self.focus_mode = true;
+                }
+                info!("Focus: focus mode {}", if self.focus_mode { "on" } else { "off" });
                 Action::None
             }
             Action::Quit => {
+                if let Some(confirmation) = Self::quit_confirmation(self.task_manager.task_count()) {
+                    info!("App: Operations still running, asking before quitting");
+                    self.dialog.update(Action::ShowDialog(confirmation));
+                } else {
+                    self.should_quit = true;
+                }
+                Action::None
+            }
+            Action::ForceQuit => {
                 self.should_quit = true;
                 Action::None
             }
+            Action::ReloadConfig => match Config::load().and_then(|config| self.apply_config(config)) {
+                Ok(()) => {
+                    info!("Config: Reloaded configuration");
+                    self.show_toast(UI_CONFIG_RELOADED.to_string());
+                    // Filters and views may have changed, so reload the current one
+                    self.schedule_data_fetch();
+                    Action::None
+                }
+                Err(e) => {
+                    log::warn!("Config: Failed to reload configuration: {:#}", e);
+                    Action::ShowDialog(DialogType::Error(format!("{}: {:#}", ERROR_CONFIG_RELOAD_FAILED, e)))
+                }
+            },
             Action::StartSync => {
                 if self.active_sync_task.is_none() {
                     info!("Starting background sync");
@@ -456,12 +1129,57 @@ impl AppComponent {
                 info!("Sync: Completed with status {:?}", status);
                 self.active_sync_task = None;
                 self.state.loading = false;
+                self.last_sync_at = Some(Instant::now());
+                // The initial sync and auto-syncs are the ones the user didn't ask for
+                let auto_sync = std::mem::take(&mut self.is_auto_sync);
+                let background = self.is_initial_sync || auto_sync;
+
+                // A resync brings back completed tasks dismissed from the view
+                self.task_list.reset_dismissed_completed();
 
                 // Extract data from sync status and update components
+                let (stats, warnings) = match &status {
+                    SyncStatus::Success(stats) => (Some(*stats), Vec::new()),
+                    SyncStatus::PartialSuccess { stats, warnings } => (Some(*stats), warnings.clone()),
+                    _ => (None, Vec::new()),
+                };
+                let succeeded = stats.is_some();
                 self.update_data_from_sync(status);
                 self.sync_component_data();
+                if succeeded {
+                    self.forget_removed_undo_tasks().await;
+                    match self.sync_service.last_sync_at().await {
+                        Ok(last_sync_at) => self.sidebar.set_last_sync_at(last_sync_at),
+                        Err(e) => log::warn!("Sync: Failed to read the last sync time: {:#}", e),
+                    }
+                }
+
+                // A partial sync is shown even after a background sync, except for the
+                // periodic ones that would keep interrupting
+                if !warnings.is_empty() {
+                    let message = format!("Synced with warnings:\n\n{}", warnings.join("\n"));
+                    if auto_sync {
+                        self.show_toast(format!("Synced with {} warning(s), see the logs", warnings.len()));
+                        return Action::None;
+                    }
+                    return Action::ShowDialog(DialogType::Warning(message));
+                }
+
+                if background {
+                    // Report background changes without interrupting with a dialog
+                    if succeeded {
+                        if let Some(message) = self.sync_service.last_sync_summary().await.and_then(|s| s.describe()) {
+                            self.show_toast(format!("Synced: {}", message));
+                        }
+                    }
+                    return Action::None;
+                }
 
-                self.state.info_message = Some(SUCCESS_SYNC_COMPLETED.to_string());
+                let message = stats
+                    .and_then(|stats| stats.describe())
+                    .map(|changes| format!("Synced: {}", changes))
+                    .unwrap_or_else(|| SUCCESS_SYNC_COMPLETED.to_string());
+                self.state.info_message = Some(message);
                 info!("Sync: Showing completion info dialog");
                 Action::ShowDialog(DialogType::Info(self.state.info_message.clone().unwrap()))
             }
@@ -469,10 +1187,45 @@ impl AppComponent {
                 info!("Sync: Failed with error: {}", error);
                 self.active_sync_task = None;
                 self.state.loading = false;
+                self.last_sync_at = Some(Instant::now());
                 self.is_initial_sync = false; // Reset flag on failure
+                if std::mem::take(&mut self.is_auto_sync) {
+                    // Don't interrupt with a dialog for a sync the user didn't ask for
+                    self.show_toast(format!("Auto-sync failed: {}", error));
+                    return Action::None;
+                }
                 self.state.error_message = Some(error);
                 Action::ShowDialog(DialogType::Error(self.state.error_message.clone().unwrap_or_default()))
             }
+            Action::SetSidebarSectionCollapsed { section, collapsed } => {
+                info!(
+                    "Sidebar: {} section '{}'",
+                    if collapsed { "Collapsed" } else { "Expanded" },
+                    section.title()
+                );
+                // The sidebar already applied the change; remember it for the next session
+                self.ui_state.collapsed_sidebar_sections = self
+                    .sidebar
+                    .collapsed_sections()
+                    .iter()
+                    .map(|section| section.key().to_string())
+                    .collect();
+                if let Err(e) = self.ui_state.save() {
+                    log::warn!("Sidebar: Failed to save collapsed sections: {:#}", e);
+                }
+                Action::None
+            }
+            Action::UpdateApiToken { token, retry } => {
+                info!("Auth: Updating API token");
+                self.task_manager
+                    .spawn_token_update(self.sync_service.clone(), token, retry.map(|action| *action));
+                Action::None
+            }
+            Action::RetryOperation { operation, info } => {
+                info!("Auth: Retrying '{}' with the new token", operation);
+                self.spawn_task_operation(operation, info);
+                Action::None
+            }
             Action::ShowDialog(ref dialog_type) => {
                 info!("Dialog: Showing dialog {:?}", dialog_type);
                 // Dialog component will handle the actual dialog setup
@@ -489,6 +1242,12 @@ impl AppComponent {
                     SidebarSelection::Today => "Today".to_string(),
                     SidebarSelection::Tomorrow => "Tomorrow".to_string(),
                     SidebarSelection::Upcoming => "Upcoming".to_string(),
+                    SidebarSelection::Postponed => "Postponed".to_string(),
+                    SidebarSelection::All => "All tasks".to_string(),
+                    SidebarSelection::Filter(index) => match self.config.filters.get(*index) {
+                        Some(filter) => format!("Filter({}) '{}'", index, filter.name),
+                        None => format!("Filter({}) [unknown]", index),
+                    },
                     SidebarSelection::Project(index) => {
                         if let Some(project) = self.state.projects.get(*index) {
                             format!("Project({}) '{}'", index, project.name)
@@ -507,25 +1266,44 @@ impl AppComponent {
 
                 info!("Navigation: Sidebar selection changed to {}", selection_desc);
                 self.state.sidebar_selection = selection.clone();
+                if self.config.ui.persist_state {
+                    let view = self.view_key(&selection);
+                    if view.is_some() && view != self.ui_state.last_view {
+                        self.ui_state.last_view = view;
+                        if let Err(e) = self.ui_state.save() {
+                            log::warn!("Navigation: Failed to save last view: {:#}", e);
+                        }
+                    }
+                }
                 // Reload data for the new selection
                 self.schedule_data_fetch();
                 info!("Navigation: Scheduled data fetch for new selection");
                 Action::None
             }
             // Task operations with background execution
-            Action::CreateTask { content, project_uuid } => {
-                let project_desc = match &project_uuid {
-                    Some(uuid) => format!(" in project {}", uuid),
-                    None => " in inbox".to_string(),
-                };
-                info!("Task: Creating task with content '{}'{}", content, project_desc);
-
-                // Format task info to include both content and project_uuid
-                let task_info = match project_uuid {
-                    Some(pid) => format!("{}|{}", content, pid),
-                    None => content,
-                };
-                self.spawn_task_operation("Create task".to_string(), task_info);
+            Action::CreateTask {
+                content,
+                project_uuid,
+                section_uuid,
+                due,
+                priority,
+                duration,
+            } => {
+                let (_, label_names) = extract_inline_labels(&content);
+                if self.confirm_new_labels(
+                    &label_names,
+                    Action::CreateTask {
+                        content: content.clone(),
+                        project_uuid,
+                        section_uuid,
+                        due: due.clone(),
+                        priority,
+                        duration,
+                    },
+                ) {
+                    return Action::None;
+                }
+                self.spawn_create_task(content, project_uuid, section_uuid, due, priority, duration);
                 Action::None
             }
             Action::CompleteTask(task_id) => {
@@ -537,8 +1315,16 @@ impl AppComponent {
 
                         info!("Task: Completing task {}", task_desc);
 
+                        // Recurring tasks move to their next occurrence; highlight them once reloaded
+                        if task.is_recurring && self.config.display.rescheduled_highlight_ms > 0 {
+                            self.pending_reschedules.insert(task_uuid, task.due_date.clone());
+                        }
+                        // A recurring task stays open on its next occurrence, so there is nothing to reopen
+                        let undo = (!task.is_recurring && !task.is_completed)
+                            .then_some(Action::RecordUndo(UndoEntry::Complete { task_uuid }));
+
                         // Todoist API automatically handles subtasks when parent is completed
-                        self.spawn_task_operation("Complete task".to_string(), task_id);
+                        self.spawn_task_operation_then("Complete task".to_string(), task_id, undo);
                     } else {
                         info!("Task: Cannot complete - task {} not found", task_id);
                     }
@@ -562,9 +1348,14 @@ impl AppComponent {
                             task_id, task.content, task.priority, new_priority
                         );
                         info!("Task: Cycling priority for task {}", task_desc);
-                        self.spawn_task_operation(
+                        let undo = Action::RecordUndo(UndoEntry::Priority {
+                            task_uuid,
+                            previous: task.priority,
+                        });
+                        self.spawn_task_operation_then(
                             "Cycle priority".to_string(),
                             format!("{}|{}", task_id, new_priority),
+                            Some(undo),
                         );
                     } else {
                         info!("Task: Cannot cycle priority - task {} not found", task_id);
@@ -577,8 +1368,17 @@ impl AppComponent {
             Action::DeleteTask(task_id) => {
                 // Find task name for better logging
                 let sync_service = self.sync_service.clone();
+                let mut undo = None;
                 let task_desc = if let Ok(task_uuid) = Uuid::parse_str(&task_id) {
                     if let Ok(Some(task)) = sync_service.get_task_by_id(&task_uuid).await {
+                        if !task.is_deleted {
+                            match sync_service.snapshot_task(&task_uuid).await {
+                                Ok(snapshot) => {
+                                    undo = Some(Action::RecordUndo(UndoEntry::Delete { task_uuid, snapshot }))
+                                }
+                                Err(e) => info!("Task: Deletion of {} can't be undone: {}", task_id, e),
+                            }
+                        }
                         format!("ID {} '{}'", task_id, task.content)
                     } else {
                         format!("ID {} [unknown]", task_id)
@@ -587,7 +1387,7 @@ impl AppComponent {
                     format!("ID {} [invalid UUID]", task_id)
                 };
                 info!("Task: Deleting task {}", task_desc);
-                self.spawn_task_operation("Delete task".to_string(), task_id);
+                self.spawn_task_operation_then("Delete task".to_string(), task_id, undo);
                 Action::None
             }
             Action::SetTaskDueToday(task_id) => {
@@ -632,40 +1432,311 @@ impl AppComponent {
                 );
                 Action::None
             }
-            Action::SetTaskDueWeekEnd(task_id) => {
-                // Find task name for better logging
+            Action::SetTaskDueWeekEnd(task_id) => {
+                // Find task name for better logging
+                let sync_service = self.sync_service.clone();
+                let task_id_str = task_id.to_string();
+                let task_desc = if let Ok(Some(task)) = sync_service.get_task_by_id(&task_id).await {
+                    format!("ID {} '{}'", task_id, task.content)
+                } else {
+                    format!("ID {} [unknown]", task_id)
+                };
+                info!("Task: Setting due date to weekend for task {}", task_desc);
+                self.spawn_task_operation("Set task due weekend".to_string(), format!("{}|weekend", task_id_str));
+                Action::None
+            }
+            Action::SetTaskDueDate { task_uuid, date } => {
+                info!("Task: Setting due date of task {} to {:?}", task_uuid, date);
+                self.spawn_task_operation(
+                    "Set task due date".to_string(),
+                    format!("{}|{}", task_uuid, date.unwrap_or_default()),
+                );
+                Action::None
+            }
+            Action::PostponeTask { task_uuid, days } => {
+                let Ok(Some(task)) = self.sync_service.get_task_by_id(&task_uuid).await else {
+                    info!("Task: Cannot move due date of unknown task {}", task_uuid);
+                    return Action::None;
+                };
+                // An undated task is counted from today rather than left without a date
+                let due = datetime::shift_due(task.due(), days, datetime::today());
+                info!(
+                    "Task: Moving due date of task {} by {} days to {}",
+                    task_uuid, days, due
+                );
+                self.spawn_task_operation("Set task due date".to_string(), format!("{}|{}", task_uuid, due));
+                Action::None
+            }
+            Action::SetTaskDeadline { task_uuid, deadline } => {
+                info!("Task: Setting deadline of task {} to {:?}", task_uuid, deadline);
+                self.spawn_task_operation(
+                    "Set task deadline".to_string(),
+                    format!("{}|{}", task_uuid, deadline.unwrap_or_default()),
+                );
+                Action::None
+            }
+            Action::EditTask {
+                task_uuid,
+                changes,
+                section_change,
+                snapshot,
+            } => {
+                if let Some(snapshot) = snapshot {
+                    match self.sync_service.task_changed_since(&snapshot).await {
+                        Ok(true) => {
+                            info!(
+                                "Task: Task {} changed while editing, asking before overwriting",
+                                task_uuid
+                            );
+                            self.dialog.update(Action::ShowDialog(DialogType::Confirmation {
+                                message: UI_TASK_CHANGED_WHILE_EDITING.to_string(),
+                                action: Box::new(Action::EditTask {
+                                    task_uuid,
+                                    changes,
+                                    section_change,
+                                    snapshot: None,
+                                }),
+                            }));
+                            return Action::None;
+                        }
+                        Ok(false) => {}
+                        Err(e) => log::warn!(
+                            "Task: Could not check task {} for concurrent changes: {:#}",
+                            task_uuid,
+                            e
+                        ),
+                    }
+                }
+                // Both inline `@label`s in new content and the labels field can create labels
+                let mut label_names = changes
+                    .content
+                    .as_deref()
+                    .map(|content| extract_inline_labels(content).1)
+                    .unwrap_or_default();
+                label_names.extend(changes.labels.iter().flatten().cloned());
+                if self.confirm_new_labels(
+                    &label_names,
+                    Action::EditTask {
+                        task_uuid,
+                        changes: changes.clone(),
+                        section_change,
+                        snapshot: None,
+                    },
+                ) {
+                    return Action::None;
+                }
+                self.spawn_edit_task(task_uuid, *changes, section_change);
+                Action::None
+            }
+            Action::ConfirmNewLabels(action) => {
+                info!("Task: New labels confirmed");
+                match *action {
+                    Action::CreateTask {
+                        content,
+                        project_uuid,
+                        section_uuid,
+                        due,
+                        priority,
+                        duration,
+                    } => self.spawn_create_task(content, project_uuid, section_uuid, due, priority, duration),
+                    Action::EditTask {
+                        task_uuid,
+                        changes,
+                        section_change,
+                        ..
+                    } => self.spawn_edit_task(task_uuid, *changes, section_change),
+                    other => info!("Task: Ignoring unexpected confirmed action {:?}", other),
+                }
+                Action::None
+            }
+            Action::RestoreTask(task_id) => {
+                info!("Task: Restoring task {}", task_id);
+                self.spawn_task_operation("Restore task".to_string(), task_id);
+                Action::None
+            }
+            Action::SetTaskLabels { task_uuid, labels } => {
+                info!("Task: Setting labels of task {} to {:?}", task_uuid, labels);
+                match serde_json::to_string(&labels) {
+                    // task_info format: "task_id|label names as a JSON array"
+                    Ok(labels) => {
+                        self.spawn_task_operation("Set task labels".to_string(), format!("{}|{}", task_uuid, labels))
+                    }
+                    Err(e) => log::error!("Task: Failed to encode labels for task {}: {}", task_uuid, e),
+                }
+                Action::None
+            }
+            Action::ReorderTask { task_uuid, offset } => {
+                info!("Task: Moving task {} by {} in its project's order", task_uuid, offset);
+                // task_info format: "task_id|offset"
+                self.spawn_task_operation("Reorder task".to_string(), format!("{}|{}", task_uuid, offset));
+                Action::None
+            }
+            Action::ExportView { format, path } => {
+                info!("Export: Writing {} to {} as {:?}", self.view_name(), path, format);
+                match self.export_view(format, &path) {
+                    Ok(count) => {
+                        self.show_toast(format!(
+                            "Exported {} {} to {}",
+                            count,
+                            if count == 1 { "task" } else { "tasks" },
+                            path
+                        ));
+                        Action::None
+                    }
+                    Err(e) => {
+                        log::warn!("Export: Failed to write {}: {:#}", path, e);
+                        Action::ShowDialog(DialogType::Error(format!("{}: {}", ERROR_EXPORT_FAILED, e)))
+                    }
+                }
+            }
+            Action::DuplicateTask(task_uuid) => {
+                info!("Task: Duplicating task {}", task_uuid);
+                self.spawn_task_operation("Duplicate task".to_string(), task_uuid.to_string());
+                Action::None
+            }
+            Action::UndoLastOperation => {
+                let Some(entry) = self.undo_stack.pop() else {
+                    info!("Task: Nothing to undo");
+                    self.show_toast(UI_NOTHING_TO_UNDO.to_string());
+                    return Action::None;
+                };
+                info!("Task: Undoing {:?}", entry);
+                // Like the original operation, the inverse runs in the background and refreshes the
+                // view; the toast only confirms it once it has gone through
+                let (operation, info) = entry.inverse();
+                let confirmation = Action::ShowToast(entry.describe().to_string());
+                self.spawn_task_operation_then(operation.to_string(), info, Some(confirmation));
+                Action::None
+            }
+            Action::RecordUndo(entry) => {
+                self.undo_stack.push(entry);
+                Action::None
+            }
+            Action::ShowToast(message) => {
+                self.show_toast(message);
+                Action::None
+            }
+            Action::RescheduleOverdueTasks(task_uuids) => {
+                info!("Task: Rescheduling {} overdue tasks to today", task_uuids.len());
+                let sync_service = self.sync_service.clone();
+                let today = datetime::format_today();
+                let spawned = self.task_manager.spawn_batch_operation(
+                    format!("Rescheduling {} overdue tasks", task_uuids.len()),
+                    BatchReport::Dialog,
+                    move |progress, cancel| async move {
+                        sync_service
+                            .update_tasks_due_date_batch(&task_uuids, Some(&today), &progress, &cancel)
+                            .await
+                            .summary("Rescheduled")
+                    },
+                );
+                if spawned.is_none() {
+                    info!("Task: Cannot reschedule - a batch operation is already running");
+                }
+                Action::None
+            }
+            Action::BulkComplete(task_uuids) => {
+                info!("Task: Completing {} marked tasks", task_uuids.len());
+                let sync_service = self.sync_service.clone();
+                let spawned = self.task_manager.spawn_batch_operation(
+                    format!("Completing {} tasks", task_uuids.len()),
+                    BatchReport::Dialog,
+                    move |progress, cancel| async move {
+                        sync_service
+                            .complete_tasks_batch(&task_uuids, &progress, &cancel)
+                            .await
+                            .summary("Completed")
+                    },
+                );
+                if spawned.is_none() {
+                    info!("Task: Cannot complete - a batch operation is already running");
+                }
+                Action::None
+            }
+            Action::BulkDelete(task_uuids) => {
+                info!("Task: Deleting {} marked tasks", task_uuids.len());
+                let sync_service = self.sync_service.clone();
+                let spawned = self.task_manager.spawn_batch_operation(
+                    format!("Deleting {} tasks", task_uuids.len()),
+                    BatchReport::Dialog,
+                    move |progress, cancel| async move {
+                        sync_service
+                            .delete_tasks_batch(&task_uuids, &progress, &cancel)
+                            .await
+                            .summary("Deleted")
+                    },
+                );
+                if spawned.is_none() {
+                    info!("Task: Cannot delete - a batch operation is already running");
+                }
+                Action::None
+            }
+            Action::BulkMove { tasks, project_uuid } => {
+                info!("Task: Moving {} tasks to project {}", tasks.len(), project_uuid);
+                let sync_service = self.sync_service.clone();
+                let spawned = self.task_manager.spawn_batch_operation(
+                    format!("Moving {} tasks", tasks.len()),
+                    BatchReport::Dialog,
+                    move |progress, cancel| async move {
+                        sync_service
+                            .move_tasks_batch(&tasks, &project_uuid, &progress, &cancel)
+                            .await
+                            .summary("Moved")
+                    },
+                );
+                if spawned.is_none() {
+                    info!("Task: Cannot move - a batch operation is already running");
+                }
+                Action::None
+            }
+            Action::BulkNest { tasks, parent_uuid } => {
+                info!("Task: Nesting {} tasks under task {}", tasks.len(), parent_uuid);
                 let sync_service = self.sync_service.clone();
-                let task_id_str = task_id.to_string();
-                let task_desc = if let Ok(Some(task)) = sync_service.get_task_by_id(&task_id).await {
-                    format!("ID {} '{}'", task_id, task.content)
-                } else {
-                    format!("ID {} [unknown]", task_id)
-                };
-                info!("Task: Setting due date to weekend for task {}", task_desc);
-                self.spawn_task_operation("Set task due weekend".to_string(), format!("{}|weekend", task_id_str));
+                let spawned = self.task_manager.spawn_batch_operation(
+                    format!("Nesting {} tasks", tasks.len()),
+                    BatchReport::Toast,
+                    move |progress, cancel| async move {
+                        match sync_service.reparent_tasks(&parent_uuid, &tasks, &progress, &cancel).await {
+                            Ok(outcome) => outcome.summary("Nested"),
+                            Err(e) => format!("Cannot nest tasks: {}", e),
+                        }
+                    },
+                );
+                if spawned.is_none() {
+                    info!("Task: Cannot nest - a batch operation is already running");
+                }
                 Action::None
             }
-            Action::EditTask { task_uuid, content } => {
-                info!("Task: Editing task UUID {} with new content '{}'", task_uuid, content);
-                self.spawn_task_operation("Edit task".to_string(), format!("{}: {}", task_uuid, content));
+            Action::CancelBatch => {
+                if self.task_manager.cancel_batch() {
+                    info!("Batch: Cancellation requested");
+                } else {
+                    info!("Batch: No batch operation to cancel");
+                }
                 Action::None
             }
-            Action::RestoreTask(task_id) => {
-                info!("Task: Restoring task {}", task_id);
-                self.spawn_task_operation("Restore task".to_string(), task_id);
+            Action::BatchCompleted { summary, report } => {
+                info!("Batch: {}", summary);
+                if report == BatchReport::Toast {
+                    self.show_toast(summary);
+                }
                 Action::None
             }
-            Action::CreateProject { name, parent_uuid } => {
+            Action::CreateProject {
+                name,
+                parent_uuid,
+                color,
+            } => {
                 let parent_desc = match &parent_uuid {
                     Some(uuid) => format!(" with parent {}", uuid),
                     None => "".to_string(),
                 };
-                info!("Project: Creating project '{}'{}", name, parent_desc);
+                info!("Project: Creating project '{}'{} (color: {})", name, parent_desc, color);
 
-                // Format project info to include both name and parent_uuid
+                // Format project info to include the color, parent_uuid and name
                 let project_info = match parent_uuid {
-                    Some(pid) => format!("{}|{}", name, pid),
-                    None => name,
+                    Some(pid) => format!("{}|{}: {}", color, pid, name),
+                    None => format!("{}: {}", color, name),
                 };
                 self.spawn_task_operation("Create project".to_string(), project_info);
                 Action::None
@@ -681,6 +1752,26 @@ impl AppComponent {
                 self.spawn_task_operation("Delete project".to_string(), project_id.to_string());
                 Action::None
             }
+            Action::ToggleProjectFavorite(project_uuid) => {
+                let Some(project) = self.state.projects.iter().find(|p| p.uuid == project_uuid) else {
+                    return Action::None;
+                };
+                let is_favorite = !project.is_favorite;
+                info!(
+                    "Project: Setting favorite of project UUID {} '{}' to {}",
+                    project_uuid, project.name, is_favorite
+                );
+                self.spawn_task_operation(
+                    "Set project favorite".to_string(),
+                    format!("{}|{}", project_uuid, is_favorite),
+                );
+                Action::None
+            }
+            Action::ToggleLabelFavorite(label_uuid) => {
+                info!("Label: Toggling favorite of label UUID {}", label_uuid);
+                self.spawn_task_operation("Toggle label favorite".to_string(), label_uuid.to_string());
+                Action::None
+            }
             Action::DeleteLabel(label_id) => {
                 // Find label name for better logging
                 let label_desc = if let Some(label) = self.state.labels.iter().find(|l| l.uuid == label_id) {
@@ -697,26 +1788,44 @@ impl AppComponent {
                 self.spawn_task_operation("Create label".to_string(), name);
                 Action::None
             }
-            Action::EditProject { project_uuid, name } => {
+            Action::EditProject {
+                project_uuid,
+                name,
+                color,
+            } => {
                 // Find project name for better logging
                 let project_desc = if let Some(project) = self.state.projects.iter().find(|p| p.uuid == project_uuid) {
                     format!("UUID {} '{}' -> '{}'", project_uuid, project.name, name)
                 } else {
                     format!("UUID {} [unknown] -> '{}'", project_uuid, name)
                 };
-                info!("Project: Editing project {}", project_desc);
-                self.spawn_task_operation("Edit project".to_string(), format!("{}: {}", project_uuid, name));
+                info!("Project: Editing project {} (color: {})", project_desc, color);
+                self.spawn_task_operation(
+                    "Edit project".to_string(),
+                    format!("{}|{}: {}", project_uuid, color, name),
+                );
                 Action::None
             }
-            Action::EditLabel { label_uuid, name } => {
+            Action::EditLabel {
+                label_uuid,
+                name,
+                color,
+                is_favorite,
+            } => {
                 // Find label name for better logging
                 let label_desc = if let Some(label) = self.state.labels.iter().find(|l| l.uuid == label_uuid) {
                     format!("UUID {} '{}' -> '{}'", label_uuid, label.name, name)
                 } else {
                     format!("UUID {} [unknown] -> '{}'", label_uuid, name)
                 };
-                info!("Label: Editing label {}", label_desc);
-                self.spawn_task_operation("Edit label".to_string(), format!("{}: {}", label_uuid, name));
+                info!(
+                    "Label: Editing label {} (color: {}, favorite: {})",
+                    label_desc, color, is_favorite
+                );
+                self.spawn_task_operation(
+                    "Edit label".to_string(),
+                    format!("{}|{}|{}: {}", label_uuid, color, is_favorite, name),
+                );
                 Action::None
             }
             Action::InitialDataLoaded {
@@ -735,6 +1844,12 @@ impl AppComponent {
 
                 // Update app state with loaded data
                 self.state.update_data(projects, labels, sections, tasks);
+                match self.sync_service.connected_backends().await {
+                    Ok(accounts) => self.state.accounts = accounts,
+                    Err(e) => log::warn!("InitialData: Failed to list the connected backends: {:#}", e),
+                }
+                self.load_task_labels().await;
+                self.load_sidebar_counts().await;
 
                 // Set initial sidebar selection based on config (now we have projects loaded)
                 self.set_initial_sidebar_selection();
@@ -764,6 +1879,9 @@ impl AppComponent {
 
                 // Update app state with loaded data
                 self.state.update_data(projects, labels, sections, tasks);
+                self.load_task_labels().await;
+                self.load_sidebar_counts().await;
+                self.apply_rescheduled_highlights().await;
                 self.sync_component_data();
                 info!("Data: Updated all component data after data load");
                 Action::None
@@ -780,6 +1898,31 @@ impl AppComponent {
                 self.dialog.update_search_results(&query, results);
                 Action::None
             }
+            Action::FindDuplicateTasks => {
+                info!("Duplicates: Scanning for duplicate tasks");
+                let sync_service = self.sync_service.clone();
+                let _task_id = self.task_manager.spawn_duplicate_scan(sync_service);
+                Action::None
+            }
+            Action::DuplicateTasksLoaded(groups) => {
+                info!("Duplicates: Found {} groups of duplicate tasks", groups.len());
+                self.dialog.update_duplicate_groups(groups);
+                Action::None
+            }
+            Action::CheckBackendStatus => {
+                info!("Backend: Checking connection");
+                let sync_service = self.sync_service.clone();
+                let _task_id = self.task_manager.spawn_backend_status(sync_service);
+                Action::None
+            }
+            Action::BackendStatusLoaded(status) => {
+                info!(
+                    "Backend: {} connected={} authenticated={}",
+                    status.name, status.connected, status.authenticated
+                );
+                self.dialog.update_backend_status(status);
+                Action::None
+            }
             Action::NextTask => {
                 info!("Navigation: Next task (j/down)");
                 action
@@ -792,6 +1935,10 @@ impl AppComponent {
                 info!("Data: Refreshing UI data after task operation");
                 // Schedule a data fetch to reload current view with updated data
                 self.schedule_data_fetch();
+                // A task deleted from the duplicates list leaves it to be rescanned
+                if matches!(self.dialog.dialog_type, Some(DialogType::DuplicateTasks)) {
+                    let _task_id = self.task_manager.spawn_duplicate_scan(self.sync_service.clone());
+                }
                 Action::None
             }
             // Help panel scrolling actions
@@ -838,29 +1985,203 @@ impl AppComponent {
         self.active_sync_task = Some(task_id);
     }
 
+    /// Wrap a due date shortcut in a confirmation showing the date it resolves to.
+    ///
+    /// Only active with `behavior.confirm_due_shortcuts`; otherwise `action` is returned as is.
+    fn confirm_due_shortcut(&self, action: Action) -> Action {
+        if !self.config.behavior.confirm_due_shortcuts {
+            return action;
+        }
+
+        let today = datetime::today();
+        let due = match action {
+            Action::SetTaskDueToday(_) => today,
+            Action::SetTaskDueTomorrow(_) => today + chrono::Duration::days(1),
+            Action::SetTaskDueNextWeek(_) => datetime::next_weekday(today, chrono::Weekday::Mon),
+            Action::SetTaskDueWeekEnd(_) => datetime::next_weekday(today, chrono::Weekday::Sat),
+            _ => return action,
+        };
+
+        Action::ShowDialog(DialogType::Confirmation {
+            message: format!("Set due to {}?", due.format("%a %Y-%m-%d")),
+            action: Box::new(action),
+        })
+    }
+
+    /// Ask before a create/edit creates labels that don't exist yet.
+    ///
+    /// Only active with `behavior.confirm_new_label`. Returns `true` when the confirmation
+    /// dialog was opened and `action` will run once the user accepts it.
+    fn confirm_new_labels(&mut self, label_names: &[String], action: Action) -> bool {
+        if !self.config.behavior.confirm_new_label {
+            return false;
+        }
+
+        let labels = unknown_label_names(label_names, &self.state.labels);
+        if labels.is_empty() {
+            return false;
+        }
+
+        info!("Task: Asking before creating new labels {:?}", labels);
+        self.dialog.update(Action::ShowDialog(DialogType::NewLabelConfirmation {
+            labels,
+            action: Box::new(action),
+        }));
+        true
+    }
+
+    fn spawn_create_task(
+        &mut self,
+        content: String,
+        project_uuid: Option<Uuid>,
+        section_uuid: Option<Uuid>,
+        due: Option<String>,
+        priority: Option<i32>,
+        duration: Option<u32>,
+    ) {
+        if let Some(uuid) = project_uuid {
+            self.remember_recent_project(uuid);
+        }
+
+        let project_desc = match &project_uuid {
+            Some(uuid) => format!(" in project {}", uuid),
+            None => " in inbox".to_string(),
+        };
+        info!(
+            "Task: Creating task with content '{}'{} due {:?} priority {:?} duration {:?}",
+            content, project_desc, due, priority, duration
+        );
+
+        // Format task info as "content|project_id|section_id|due|priority|duration", with empty
+        // fields for the inbox, no section, no due date, the default priority and no duration
+        let task_info = format!(
+            "{}|{}|{}|{}|{}|{}",
+            content,
+            project_uuid.map(|pid| pid.to_string()).unwrap_or_default(),
+            section_uuid.map(|sid| sid.to_string()).unwrap_or_default(),
+            due.unwrap_or_default(),
+            priority.map(|p| p.to_string()).unwrap_or_default(),
+            duration.map(|d| d.to_string()).unwrap_or_default()
+        );
+        self.spawn_task_operation("Create task".to_string(), task_info);
+    }
+
+    /// Fill in the labels of the task a task edit form or label picker is opened for.
+    ///
+    /// The task list doesn't load task labels, so they are looked up when the dialog opens.
+    async fn with_task_edit_labels(&self, action: Action) -> Action {
+        match action {
+            Action::ShowDialog(DialogType::TaskEdit {
+                task_uuid,
+                content,
+                project_uuid,
+                snapshot,
+                ..
+            }) => Action::ShowDialog(DialogType::TaskEdit {
+                task_uuid,
+                content,
+                project_uuid,
+                labels: self.task_label_names(&task_uuid).await,
+                snapshot,
+            }),
+            Action::ShowDialog(DialogType::TaskLabels { task_uuid, content, .. }) => {
+                Action::ShowDialog(DialogType::TaskLabels {
+                    task_uuid,
+                    content,
+                    labels: self.task_label_names(&task_uuid).await,
+                })
+            }
+            action => action,
+        }
+    }
+
+    /// Names of a task's labels; none when they can't be loaded
+    async fn task_label_names(&self, task_uuid: &Uuid) -> Vec<String> {
+        match self.sync_service.get_labels_for_task(task_uuid).await {
+            Ok(labels) => labels.into_iter().map(|l| l.name).collect(),
+            Err(e) => {
+                log::warn!("Task: Failed to load labels of task {}: {:#}", task_uuid, e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Record a project used for task creation so the next creation dialog can preselect it
+    fn remember_recent_project(&mut self, project_uuid: Uuid) {
+        if !self.config.ui.preselect_recent_project {
+            return;
+        }
+        // The inbox is the implicit default already, so it never displaces a real project
+        if self.state.projects.iter().any(|p| p.uuid == project_uuid && p.is_inbox_project) {
+            return;
+        }
+        self.ui_state.record_recent_project(project_uuid);
+        self.dialog.set_recent_projects(self.ui_state.recent_project_uuids.clone());
+        if let Err(e) = self.ui_state.save() {
+            log::warn!("Task: Failed to save recent projects: {:#}", e);
+        }
+    }
+
+    fn spawn_edit_task(&mut self, task_uuid: Uuid, changes: UpdateTaskArgs, section_change: Option<Option<Uuid>>) {
+        if !changes.is_empty() {
+            info!("Task: Editing task UUID {} with changes {:?}", task_uuid, changes);
+            match serde_json::to_string(&changes) {
+                // task_info format: "task_id|changes as JSON"
+                Ok(changes) => self.spawn_task_operation("Edit task".to_string(), format!("{}|{}", task_uuid, changes)),
+                Err(e) => log::error!("Task: Failed to encode changes for task {}: {}", task_uuid, e),
+            }
+        }
+        if let Some(section_uuid) = section_change {
+            info!("Task: Moving task UUID {} to section {:?}", task_uuid, section_uuid);
+            // task_info format: "task_id|section_id", or "task_id|" to take it out of its section
+            self.spawn_task_operation(
+                "Set task section".to_string(),
+                format!(
+                    "{}|{}",
+                    task_uuid,
+                    section_uuid.map(|sid| sid.to_string()).unwrap_or_default()
+                ),
+            );
+        }
+    }
+
     /// Spawn a generic task operation (now with actual API calls and data refresh)
     fn spawn_task_operation(&mut self, operation_name: String, task_info: String) {
+        self.spawn_task_operation_then(operation_name, task_info, None);
+    }
+
+    /// Like `spawn_task_operation`, dispatching `on_success` once the operation succeeds
+    fn spawn_task_operation_then(&mut self, operation_name: String, task_info: String, on_success: Option<Action>) {
         let description = format!("{}: {}", operation_name, task_info);
+        let retry = Action::RetryOperation {
+            operation: operation_name.clone(),
+            info: task_info.clone(),
+        };
         let op_name = operation_name.clone();
         let sync_service = self.sync_service.clone();
         info!("Background: Spawning task operation '{}'", description);
 
         let _task_id = self.task_manager.spawn_task_operation(
             move || async move {
-                let result = match op_name.as_str() {
+                // With several backends connected, the change goes to the one owning the first item named
+                let sync_service = match task_info.split('|').find_map(|field| Uuid::parse_str(field).ok()) {
+                    Some(item_uuid) => sync_service.for_item(&item_uuid).await,
+                    None => sync_service,
+                };
+                let result: anyhow::Result<String> = match op_name.as_str() {
                     "Complete task" => match Uuid::parse_str(&task_info) {
                         Ok(task_uuid) => match sync_service.complete_task(&task_uuid).await {
                             Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_COMPLETED, task_info)),
-                            Err(e) => Err(format!("{}: {}", ERROR_TASK_COMPLETION_FAILED, e)),
+                            Err(e) => Err(e.context(ERROR_TASK_COMPLETION_FAILED)),
                         },
-                        Err(e) => Err(format!("Invalid task UUID: {}", e)),
+                        Err(e) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
                     },
                     "Delete task" => match Uuid::parse_str(&task_info) {
                         Ok(task_uuid) => match sync_service.delete_task(&task_uuid).await {
                             Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_DELETED, task_info)),
-                            Err(e) => Err(format!("{}: {}", ERROR_TASK_DELETE_FAILED, e)),
+                            Err(e) => Err(e.context(ERROR_TASK_DELETE_FAILED)),
                         },
-                        Err(e) => Err(format!("Invalid task UUID: {}", e)),
+                        Err(e) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
                     },
                     "Cycle priority" => {
                         // task_info format: "task_id|new_priority"
@@ -873,16 +2194,16 @@ impl AppComponent {
                                                 "{}{}: {}",
                                                 SUCCESS_TASK_PRIORITY_UPDATED, priority, task_id_str
                                             )),
-                                            Err(e) => Err(format!("{}: {}", ERROR_TASK_PRIORITY_FAILED, e)),
+                                            Err(e) => Err(e.context(ERROR_TASK_PRIORITY_FAILED)),
                                         }
                                     } else {
-                                        Err(ERROR_INVALID_PRIORITY_FORMAT.to_string())
+                                        Err(anyhow::anyhow!(ERROR_INVALID_PRIORITY_FORMAT))
                                     }
                                 }
-                                Err(e) => Err(format!("Invalid task UUID: {}", e)),
+                                Err(e) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
                             }
                         } else {
-                            Err(ERROR_INVALID_PRIORITY_INFO.to_string())
+                            Err(anyhow::anyhow!(ERROR_INVALID_PRIORITY_INFO))
                         }
                     }
                     "Set task due today" => {
@@ -893,13 +2214,13 @@ impl AppComponent {
                                     let today = datetime::format_today();
                                     match sync_service.update_task_due_date(&task_uuid, Some(&today)).await {
                                         Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_DUE_TODAY, task_id_str)),
-                                        Err(e) => Err(format!("{}: {}", ERROR_TASK_DUE_DATE_FAILED, e)),
+                                        Err(e) => Err(e.context(ERROR_TASK_DUE_DATE_FAILED)),
                                     }
                                 }
-                                Err(e) => Err(format!("Invalid task UUID: {}", e)),
+                                Err(e) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
                             }
                         } else {
-                            Err(ERROR_INVALID_DATE_FORMAT.to_string())
+                            Err(anyhow::anyhow!(ERROR_INVALID_DATE_FORMAT))
                         }
                     }
                     "Set task due tomorrow" => {
@@ -910,13 +2231,13 @@ impl AppComponent {
                                     let tomorrow = datetime::format_date_with_offset(1);
                                     match sync_service.update_task_due_date(&task_uuid, Some(&tomorrow)).await {
                                         Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_DUE_TOMORROW, task_id_str)),
-                                        Err(e) => Err(format!("{}: {}", ERROR_TASK_DUE_DATE_FAILED, e)),
+                                        Err(e) => Err(e.context(ERROR_TASK_DUE_DATE_FAILED)),
                                     }
                                 }
-                                Err(e) => Err(format!("Invalid task UUID: {}", e)),
+                                Err(e) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
                             }
                         } else {
-                            Err(ERROR_INVALID_DATE_FORMAT.to_string())
+                            Err(anyhow::anyhow!(ERROR_INVALID_DATE_FORMAT))
                         }
                     }
                     "Set task due next week" => {
@@ -924,18 +2245,18 @@ impl AppComponent {
                         if let Some((task_id_str, _)) = task_info.split_once('|') {
                             match Uuid::parse_str(task_id_str) {
                                 Ok(task_uuid) => {
-                                    let today = chrono::Local::now().date_naive();
+                                    let today = datetime::today();
                                     let next_monday = crate::utils::datetime::next_weekday(today, chrono::Weekday::Mon);
                                     let next_monday_str = crate::utils::datetime::format_ymd(next_monday);
                                     match sync_service.update_task_due_date(&task_uuid, Some(&next_monday_str)).await {
                                         Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_DUE_MONDAY, task_id_str)),
-                                        Err(e) => Err(format!("{}: {}", ERROR_TASK_DUE_DATE_FAILED, e)),
+                                        Err(e) => Err(e.context(ERROR_TASK_DUE_DATE_FAILED)),
                                     }
                                 }
-                                Err(e) => Err(format!("Invalid task UUID: {}", e)),
+                                Err(e) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
                             }
                         } else {
-                            Err(ERROR_INVALID_DATE_FORMAT.to_string())
+                            Err(anyhow::anyhow!(ERROR_INVALID_DATE_FORMAT))
                         }
                     }
                     "Set task due weekend" => {
@@ -943,89 +2264,246 @@ impl AppComponent {
                         if let Some((task_id_str, _)) = task_info.split_once('|') {
                             match Uuid::parse_str(task_id_str) {
                                 Ok(task_uuid) => {
-                                    let today = chrono::Local::now().date_naive();
+                                    let today = datetime::today();
                                     let next_saturday =
                                         crate::utils::datetime::next_weekday(today, chrono::Weekday::Sat);
                                     let next_saturday_str = crate::utils::datetime::format_ymd(next_saturday);
                                     match sync_service.update_task_due_date(&task_uuid, Some(&next_saturday_str)).await
                                     {
                                         Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_DUE_SATURDAY, task_id_str)),
-                                        Err(e) => Err(format!("{}: {}", ERROR_TASK_DUE_DATE_FAILED, e)),
+                                        Err(e) => Err(e.context(ERROR_TASK_DUE_DATE_FAILED)),
                                     }
                                 }
-                                Err(e) => Err(format!("Invalid task UUID: {}", e)),
+                                Err(e) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
                             }
                         } else {
-                            Err(ERROR_INVALID_DATE_FORMAT.to_string())
+                            Err(anyhow::anyhow!(ERROR_INVALID_DATE_FORMAT))
                         }
                     }
-                    "Create task" => {
-                        // task_info format: "content|project_id" or just "content" for inbox
-                        if let Some((content, project_id_str)) = task_info.split_once('|') {
-                            // Task has a specific project - parse the UUID
-                            match Uuid::parse_str(project_id_str) {
-                                Ok(project_uuid) => match sync_service.create_task(content, Some(project_uuid)).await {
-                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_CREATED_PROJECT, content)),
-                                    Err(e) => Err(format!("{}: {}", ERROR_TASK_CREATE_FAILED, e)),
-                                },
-                                Err(e) => Err(format!("Invalid project UUID: {}", e)),
+                    "Set task due date" => {
+                        // task_info format: "task_id|YYYY-MM-DD", "task_id|<RFC 3339 datetime>", or
+                        // "task_id|" to clear the due date
+                        if let Some((task_id_str, date)) = task_info.split_once('|') {
+                            match Uuid::parse_str(task_id_str) {
+                                Ok(task_uuid) => {
+                                    let date = Some(date).filter(|d| !d.is_empty());
+                                    let updated = match date {
+                                        Some(due_datetime) if due_datetime.len() > 10 => {
+                                            sync_service.update_task_due_datetime(&task_uuid, due_datetime).await
+                                        }
+                                        date => sync_service.update_task_due_date(&task_uuid, date).await,
+                                    };
+                                    match updated {
+                                        Ok(()) if date.is_some() => {
+                                            Ok(format!("{}: {}", SUCCESS_TASK_DUE_DATE_SET, task_id_str))
+                                        }
+                                        Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_DUE_DATE_CLEARED, task_id_str)),
+                                        Err(e) => Err(e.context(ERROR_TASK_DUE_DATE_FAILED)),
+                                    }
+                                }
+                                Err(e) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
+                            }
+                        } else {
+                            Err(anyhow::anyhow!(ERROR_INVALID_DATE_FORMAT))
+                        }
+                    }
+                    "Set task deadline" => {
+                        // task_info format: "task_id|YYYY-MM-DD", or "task_id|" to clear the deadline
+                        if let Some((task_id_str, deadline)) = task_info.split_once('|') {
+                            match Uuid::parse_str(task_id_str) {
+                                Ok(task_uuid) => {
+                                    let deadline = Some(deadline).filter(|d| !d.is_empty());
+                                    match sync_service.update_task_deadline(&task_uuid, deadline).await {
+                                        Ok(()) if deadline.is_some() => {
+                                            Ok(format!("{}: {}", SUCCESS_TASK_DEADLINE_SET, task_id_str))
+                                        }
+                                        Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_DEADLINE_CLEARED, task_id_str)),
+                                        Err(e) => Err(e.context(ERROR_TASK_DEADLINE_FAILED)),
+                                    }
+                                }
+                                Err(e) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
+                            }
+                        } else {
+                            Err(anyhow::anyhow!(ERROR_INVALID_DATE_FORMAT))
+                        }
+                    }
+                    "Set task section" => {
+                        // task_info format: "task_id|section_id", or "task_id|" for no section
+                        if let Some((task_id_str, section_id_str)) = task_info.split_once('|') {
+                            let section_uuid = match section_id_str {
+                                "" => Ok(None),
+                                id => Uuid::parse_str(id).map(Some),
+                            };
+                            match (Uuid::parse_str(task_id_str), section_uuid) {
+                                (Ok(task_uuid), Ok(section_uuid)) => {
+                                    match sync_service.set_task_section(&task_uuid, section_uuid).await {
+                                        Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_SECTION_SET, task_id_str)),
+                                        Err(e) => Err(e.context(ERROR_TASK_SECTION_FAILED)),
+                                    }
+                                }
+                                (Err(e), _) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
+                                (_, Err(e)) => Err(anyhow::anyhow!("Invalid section UUID: {}", e)),
                             }
                         } else {
-                            // Task goes to inbox (no project_id)
-                            match sync_service.create_task(&task_info, None).await {
-                                Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_CREATED_INBOX, task_info)),
-                                Err(e) => Err(format!("{}: {}", ERROR_TASK_CREATE_FAILED, e)),
+                            Err(anyhow::anyhow!(ERROR_INVALID_TASK_EDIT_FORMAT))
+                        }
+                    }
+                    "Create task" => {
+                        // task_info format: "content|project_id|section_id|due|priority|duration", split
+                        // from the right since the content may contain '|'; an empty project_id means the inbox
+                        let mut fields = task_info.rsplitn(6, '|');
+                        let duration = fields
+                            .next()
+                            .and_then(|d| d.parse::<u32>().ok())
+                            .map(datetime::backend_duration);
+                        let priority = fields.next().and_then(|p| p.parse::<i32>().ok());
+                        let due = fields.next().filter(|d| !d.is_empty());
+                        let section_id_str = fields.next().unwrap_or_default();
+                        let project_id_str = fields.next().unwrap_or_default();
+                        let content = fields.next().unwrap_or_default();
+                        let parse_optional = |id: &str| match id {
+                            "" => Ok(None),
+                            id => Uuid::parse_str(id).map(Some),
+                        };
+                        match (parse_optional(project_id_str), parse_optional(section_id_str)) {
+                            (Ok(project_uuid), Ok(section_uuid)) => {
+                                match sync_service
+                                    .create_task_with_details(
+                                        content,
+                                        project_uuid,
+                                        section_uuid,
+                                        due,
+                                        priority,
+                                        duration.as_deref(),
+                                    )
+                                    .await
+                                {
+                                    Ok(_) if project_uuid.is_some() => {
+                                        Ok(format!("{}: {}", SUCCESS_TASK_CREATED_PROJECT, content))
+                                    }
+                                    Ok(_) => Ok(format!("{}: {}", SUCCESS_TASK_CREATED_INBOX, content)),
+                                    Err(e) => Err(e.context(ERROR_TASK_CREATE_FAILED)),
+                                }
                             }
+                            (Err(e), _) => Err(anyhow::anyhow!("Invalid project UUID: {}", e)),
+                            (_, Err(e)) => Err(anyhow::anyhow!("Invalid section UUID: {}", e)),
                         }
                     }
                     "Edit task" => {
-                        // task_info format: "task_id: new_content"
-                        if let Some((task_id_str, content)) = task_info.split_once(": ") {
-                            match Uuid::parse_str(task_id_str) {
-                                Ok(task_uuid) => match sync_service.update_task_content(&task_uuid, content).await {
-                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_UPDATED, task_id_str)),
-                                    Err(e) => Err(format!("{}: {}", ERROR_TASK_UPDATE_FAILED, e)),
-                                },
-                                Err(e) => Err(format!("Invalid task UUID: {}", e)),
+                        // task_info format: "task_id|changes as JSON"
+                        if let Some((task_id_str, changes)) = task_info.split_once('|') {
+                            match (
+                                Uuid::parse_str(task_id_str),
+                                serde_json::from_str::<UpdateTaskArgs>(changes),
+                            ) {
+                                (Ok(task_uuid), Ok(changes)) => {
+                                    match sync_service.update_task_full(&task_uuid, changes).await {
+                                        Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_UPDATED, task_id_str)),
+                                        Err(e) => Err(e.context(ERROR_TASK_UPDATE_FAILED)),
+                                    }
+                                }
+                                (Err(e), _) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
+                                (_, Err(e)) => Err(anyhow::anyhow!("Invalid task changes: {}", e)),
                             }
                         } else {
-                            Err(ERROR_INVALID_TASK_EDIT_FORMAT.to_string())
+                            Err(anyhow::anyhow!(ERROR_INVALID_TASK_EDIT_FORMAT))
                         }
                     }
                     "Restore task" => match Uuid::parse_str(&task_info) {
                         Ok(task_uuid) => match sync_service.restore_task(&task_uuid).await {
                             Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_RESTORED, task_info)),
-                            Err(e) => Err(format!("{}: {}", ERROR_TASK_RESTORE_FAILED, e)),
+                            Err(e) => Err(e.context(ERROR_TASK_RESTORE_FAILED)),
                         },
-                        Err(e) => Err(format!("Invalid task UUID: {}", e)),
+                        Err(e) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
                     },
-                    "Create project" => {
-                        // project_info format: "name|parent_id" or just "name" for root project
-                        if let Some((name, parent_id_str)) = task_info.split_once('|') {
-                            // Project has a parent - parse the UUID
-                            match Uuid::parse_str(parent_id_str) {
-                                Ok(parent_uuid) => match sync_service.create_project(name, Some(parent_uuid)).await {
-                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_PROJECT_CREATED_PARENT, name)),
-                                    Err(e) => Err(format!("{}: {}", ERROR_PROJECT_CREATE_FAILED, e)),
-                                },
-                                Err(e) => Err(format!("Invalid parent project UUID: {}", e)),
+                    "Restore deleted task" => match task_info.split_once('|') {
+                        Some((task_id_str, snapshot)) => {
+                            match (
+                                Uuid::parse_str(task_id_str),
+                                serde_json::from_str::<TaskSnapshot>(snapshot),
+                            ) {
+                                (Ok(task_uuid), Ok(snapshot)) => {
+                                    match sync_service.restore_deleted_task(&task_uuid, &snapshot).await {
+                                        Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_RESTORED, task_id_str)),
+                                        Err(e) => Err(e.context(ERROR_TASK_RESTORE_FAILED)),
+                                    }
+                                }
+                                (Err(e), _) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
+                                (_, Err(e)) => Err(anyhow::anyhow!("Invalid task snapshot: {}", e)),
                             }
-                        } else {
-                            // Root project (no parent)
-                            match sync_service.create_project(&task_info, None).await {
-                                Ok(()) => Ok(format!("{}: {}", SUCCESS_PROJECT_CREATED_ROOT, task_info)),
-                                Err(e) => Err(format!("{}: {}", ERROR_PROJECT_CREATE_FAILED, e)),
+                        }
+                        None => Err(anyhow::anyhow!("Invalid task snapshot format")),
+                    },
+                    "Set task labels" => match task_info.split_once('|') {
+                        Some((task_id_str, labels)) => {
+                            match (
+                                Uuid::parse_str(task_id_str),
+                                serde_json::from_str::<Vec<String>>(labels),
+                            ) {
+                                (Ok(task_uuid), Ok(labels)) => {
+                                    match sync_service.update_task_labels(&task_uuid, labels).await {
+                                        Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_LABELS_SET, task_id_str)),
+                                        Err(e) => Err(e.context(ERROR_TASK_LABELS_FAILED)),
+                                    }
+                                }
+                                (Err(e), _) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
+                                (_, Err(e)) => Err(anyhow::anyhow!("Invalid task labels: {}", e)),
                             }
                         }
+                        None => Err(anyhow::anyhow!("Invalid task labels format")),
+                    },
+                    "Reorder task" => match task_info.split_once('|') {
+                        Some((task_id_str, offset)) => match (Uuid::parse_str(task_id_str), offset.parse::<i32>()) {
+                            (Ok(task_uuid), Ok(offset)) => match sync_service.reorder_task(&task_uuid, offset).await {
+                                Ok(_) => Ok(format!("{}: {}", SUCCESS_TASK_REORDERED, task_id_str)),
+                                Err(e) => Err(e.context(ERROR_TASK_REORDER_FAILED)),
+                            },
+                            (Err(e), _) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
+                            (_, Err(e)) => Err(anyhow::anyhow!("Invalid task offset: {}", e)),
+                        },
+                        None => Err(anyhow::anyhow!("Invalid task reorder format")),
+                    },
+                    "Duplicate task" => match Uuid::parse_str(&task_info) {
+                        Ok(task_uuid) => match sync_service.duplicate_task(&task_uuid).await {
+                            Ok(()) => Ok(format!("{}: {}", SUCCESS_TASK_DUPLICATED, task_info)),
+                            Err(e) => Err(e.context(ERROR_TASK_DUPLICATE_FAILED)),
+                        },
+                        Err(e) => Err(anyhow::anyhow!("Invalid task UUID: {}", e)),
+                    },
+                    "Create project" => {
+                        // project_info format: "color|parent_id: name" or "color: name" for root project
+                        match task_info.split_once(": ") {
+                            Some((header, name)) => match header.split_once('|') {
+                                Some((color, parent_id_str)) => {
+                                    // Project has a parent - parse the UUID
+                                    match Uuid::parse_str(parent_id_str) {
+                                        Ok(parent_uuid) => match sync_service
+                                            .create_project_with_color(name, Some(parent_uuid), Some(color))
+                                            .await
+                                        {
+                                            Ok(()) => Ok(format!("{}: {}", SUCCESS_PROJECT_CREATED_PARENT, name)),
+                                            Err(e) => Err(e.context(ERROR_PROJECT_CREATE_FAILED)),
+                                        },
+                                        Err(e) => Err(anyhow::anyhow!("Invalid parent project UUID: {}", e)),
+                                    }
+                                }
+                                // Root project (no parent)
+                                None => match sync_service.create_project_with_color(name, None, Some(header)).await {
+                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_PROJECT_CREATED_ROOT, name)),
+                                    Err(e) => Err(e.context(ERROR_PROJECT_CREATE_FAILED)),
+                                },
+                            },
+                            None => Err(anyhow::anyhow!(ERROR_INVALID_PROJECT_EDIT_FORMAT)),
+                        }
                     }
                     "Delete project" => {
                         // task_info is a UUID string
                         match Uuid::parse_str(&task_info) {
                             Ok(project_uuid) => match sync_service.delete_project(&project_uuid).await {
                                 Ok(()) => Ok(format!("{}: {}", SUCCESS_PROJECT_DELETED, task_info)),
-                                Err(e) => Err(format!("{}: {}", ERROR_PROJECT_DELETE_FAILED, e)),
+                                Err(e) => Err(e.context(ERROR_PROJECT_DELETE_FAILED)),
                             },
-                            Err(e) => Err(format!("Invalid project UUID: {}", e)),
+                            Err(e) => Err(anyhow::anyhow!("Invalid project UUID: {}", e)),
                         }
                     }
                     "Delete label" => {
@@ -1033,57 +2511,98 @@ impl AppComponent {
                         match Uuid::parse_str(&task_info) {
                             Ok(label_uuid) => match sync_service.delete_label(&label_uuid).await {
                                 Ok(()) => Ok(format!("{}: {}", SUCCESS_LABEL_DELETED, task_info)),
-                                Err(e) => Err(format!("{}: {}", ERROR_LABEL_DELETE_FAILED, e)),
+                                Err(e) => Err(e.context(ERROR_LABEL_DELETE_FAILED)),
                             },
-                            Err(e) => Err(format!("Invalid label UUID: {}", e)),
+                            Err(e) => Err(anyhow::anyhow!("Invalid label UUID: {}", e)),
                         }
                     }
                     "Create label" => match sync_service.create_label(&task_info).await {
                         Ok(()) => Ok(format!("{}: {}", SUCCESS_LABEL_CREATED, task_info)),
-                        Err(e) => Err(format!("{}: {}", ERROR_LABEL_CREATE_FAILED, e)),
+                        Err(e) => Err(e.context(ERROR_LABEL_CREATE_FAILED)),
                     },
                     "Edit project" => {
-                        // task_info format: "project_id: new_name"
-                        if let Some((project_id_str, name)) = task_info.split_once(": ") {
+                        // task_info format: "project_id|color: new_name"
+                        let parsed = task_info
+                            .split_once(": ")
+                            .and_then(|(header, name)| Some((header.split_once('|')?, name)));
+                        if let Some(((project_id_str, color), name)) = parsed {
                             match Uuid::parse_str(project_id_str) {
                                 Ok(project_uuid) => {
-                                    match sync_service.update_project_content(&project_uuid, name).await {
+                                    match sync_service
+                                        .update_project(&project_uuid, Some(name), Some(color), None)
+                                        .await
+                                    {
                                         Ok(()) => Ok(format!("{}: {}", SUCCESS_PROJECT_UPDATED, project_id_str)),
-                                        Err(e) => Err(format!("{}: {}", ERROR_PROJECT_UPDATE_FAILED, e)),
+                                        Err(e) => Err(e.context(ERROR_PROJECT_UPDATE_FAILED)),
                                     }
                                 }
-                                Err(e) => Err(format!("Invalid project UUID: {}", e)),
+                                Err(e) => Err(anyhow::anyhow!("Invalid project UUID: {}", e)),
                             }
                         } else {
-                            Err(ERROR_INVALID_PROJECT_EDIT_FORMAT.to_string())
+                            Err(anyhow::anyhow!(ERROR_INVALID_PROJECT_EDIT_FORMAT))
                         }
                     }
+                    "Set project favorite" => {
+                        // task_info format: "project_id|is_favorite"
+                        let parsed = task_info
+                            .split_once('|')
+                            .and_then(|(id, is_favorite)| Some((id, is_favorite.parse::<bool>().ok()?)));
+                        match parsed.map(|(id, is_favorite)| (Uuid::parse_str(id), is_favorite)) {
+                            Some((Ok(project_uuid), is_favorite)) => {
+                                match sync_service.set_project_favorite(&project_uuid, is_favorite).await {
+                                    Ok(()) => Ok(format!("{}: {}", SUCCESS_PROJECT_UPDATED, project_uuid)),
+                                    Err(e) => Err(e.context(ERROR_PROJECT_UPDATE_FAILED)),
+                                }
+                            }
+                            Some((Err(e), _)) => Err(anyhow::anyhow!("Invalid project UUID: {}", e)),
+                            None => Err(anyhow::anyhow!(ERROR_INVALID_PROJECT_EDIT_FORMAT)),
+                        }
+                    }
+                    "Toggle label favorite" => match Uuid::parse_str(&task_info) {
+                        Ok(label_uuid) => match sync_service.toggle_label_favorite(&label_uuid).await {
+                            Ok(()) => Ok(format!("{}: {}", SUCCESS_LABEL_UPDATED, task_info)),
+                            Err(e) => Err(e.context(ERROR_LABEL_UPDATE_FAILED)),
+                        },
+                        Err(e) => Err(anyhow::anyhow!("Invalid label UUID: {}", e)),
+                    },
                     "Edit label" => {
-                        // task_info format: "label_id: new_name"
-                        if let Some((label_id_str, name)) = task_info.split_once(": ") {
+                        // task_info format: "label_id|color|is_favorite: new_name"
+                        let parsed = task_info.split_once(": ").and_then(|(header, name)| {
+                            let mut parts = header.splitn(3, '|');
+                            let label_id_str = parts.next()?;
+                            let color = parts.next()?;
+                            let is_favorite = parts.next()?.parse::<bool>().ok()?;
+                            Some((label_id_str, color, is_favorite, name))
+                        });
+                        if let Some((label_id_str, color, is_favorite, name)) = parsed {
                             match Uuid::parse_str(label_id_str) {
-                                Ok(label_uuid) => match sync_service.update_label_content(&label_uuid, name).await {
+                                Ok(label_uuid) => match sync_service
+                                    .update_label(&label_uuid, Some(name), Some(color), Some(is_favorite))
+                                    .await
+                                {
                                     Ok(()) => Ok(format!("{}: {}", SUCCESS_LABEL_UPDATED, label_id_str)),
-                                    Err(e) => Err(format!("{}: {}", ERROR_LABEL_UPDATE_FAILED, e)),
+                                    Err(e) => Err(e.context(ERROR_LABEL_UPDATE_FAILED)),
                                 },
-                                Err(e) => Err(format!("Invalid label UUID: {}", e)),
+                                Err(e) => Err(anyhow::anyhow!("Invalid label UUID: {}", e)),
                             }
                         } else {
-                            Err(ERROR_INVALID_LABEL_EDIT_FORMAT.to_string())
+                            Err(anyhow::anyhow!(ERROR_INVALID_LABEL_EDIT_FORMAT))
                         }
                     }
-                    _ => Err(format!("{}: {}", ERROR_UNKNOWN_OPERATION, op_name)),
+                    _ => Err(anyhow::anyhow!("{}: {}", ERROR_UNKNOWN_OPERATION, op_name)),
                 };
 
-                result.map_err(|e: String| anyhow::anyhow!(e))
+                result
             },
             description,
+            Some(retry),
+            on_success,
         );
     }
 
     fn update_data_from_sync(&mut self, status: SyncStatus) {
-        // Only proceed if sync was successful
-        if matches!(status, SyncStatus::Success) {
+        // Only proceed if sync stored its data
+        if matches!(status, SyncStatus::Success(_) | SyncStatus::PartialSuccess { .. }) {
             if self.is_initial_sync {
                 // For initial sync, use initial data fetch which sets default selection
                 self.schedule_initial_data_fetch();
@@ -1097,16 +2616,96 @@ impl AppComponent {
 
     /// Schedule a background task to fetch initial data after sync completion
     fn schedule_initial_data_fetch(&mut self) {
-        let _task_id =
-            self.task_manager
-                .spawn_data_load(self.sync_service.clone(), self.state.sidebar_selection.clone(), true);
+        let _task_id = self.task_manager.spawn_data_load(
+            self.sync_service.clone(),
+            self.state.sidebar_selection.clone(),
+            self.config.filters.iter().map(|f| f.query.clone()).collect(),
+            self.config.behavior.someday_label.clone(),
+            self.task_list.upcoming_days(),
+            true,
+        );
+    }
+
+    /// Drop the undo history of tasks a sync removed from local storage (completed or
+    /// deleted elsewhere and pruned by the full fetch), which could no longer be restored
+    async fn forget_removed_undo_tasks(&mut self) {
+        let mut removed = HashSet::new();
+        for task_uuid in self.undo_stack.task_uuids().collect::<Vec<_>>() {
+            if let Ok(None) = self.sync_service.get_task_by_id(&task_uuid).await {
+                removed.insert(task_uuid);
+            }
+        }
+        if !removed.is_empty() {
+            info!(
+                "Undo: Forgetting {} operation(s) on tasks removed by the sync",
+                removed.len()
+            );
+            self.undo_stack.forget_tasks(&removed);
+        }
+    }
+
+    /// Load the labels of each task for grouping the task list by label
+    async fn load_task_labels(&mut self) {
+        match self.sync_service.get_label_names_by_task().await {
+            Ok(task_labels) => self.task_list.set_task_labels(task_labels),
+            Err(e) => info!("Data: Failed to load task labels: {}", e),
+        }
+    }
+
+    /// Load the open task counts shown in the sidebar
+    async fn load_sidebar_counts(&mut self) {
+        let counts = self
+            .sync_service
+            .get_sidebar_counts(&self.config.behavior.someday_label, self.task_list.upcoming_days())
+            .await;
+        match counts {
+            Ok(counts) => self.sidebar.set_counts(counts),
+            Err(e) => info!("Data: Failed to load sidebar counts: {}", e),
+        }
+    }
+
+    /// Highlight recurring tasks whose completion moved them to a new due date.
+    ///
+    /// Tasks that left the current view (e.g. rescheduled out of Today) are kept in the
+    /// task data so they stay visible until their highlight expires.
+    async fn apply_rescheduled_highlights(&mut self) {
+        if self.pending_reschedules.is_empty() {
+            return;
+        }
+
+        let until = Instant::now() + Duration::from_millis(self.config.display.rescheduled_highlight_ms);
+        for (task_uuid, previous_due_date) in std::mem::take(&mut self.pending_reschedules) {
+            let task = match self.state.tasks.iter().find(|t| t.uuid == task_uuid) {
+                Some(task) => Some(task.clone()),
+                None => self.sync_service.get_task_by_id(&task_uuid).await.ok().flatten(),
+            };
+
+            match task {
+                Some(task) if !task.is_completed && task.due_date != previous_due_date => {
+                    if !self.state.tasks.iter().any(|t| t.uuid == task_uuid) {
+                        self.state.tasks.push(task);
+                    }
+                    self.task_list.highlight_rescheduled(task_uuid, until);
+                }
+                // Completion not stored yet (data reloaded for another reason), check again later
+                Some(task) if !task.is_completed => {
+                    self.pending_reschedules.insert(task_uuid, previous_due_date);
+                }
+                _ => {}
+            }
+        }
     }
 
     /// Schedule a background task to fetch data after navigation or changes
     fn schedule_data_fetch(&mut self) {
-        let _task_id =
-            self.task_manager
-                .spawn_data_load(self.sync_service.clone(), self.state.sidebar_selection.clone(), false);
+        let _task_id = self.task_manager.spawn_data_load(
+            self.sync_service.clone(),
+            self.state.sidebar_selection.clone(),
+            self.config.filters.iter().map(|f| f.query.clone()).collect(),
+            self.config.behavior.someday_label.clone(),
+            self.task_list.upcoming_days(),
+            false,
+        );
     }
 
     /// Process background actions from task manager
@@ -1134,18 +2733,45 @@ impl AppComponent {
         self.task_manager.task_count() > 0
     }
 
+    /// Confirmation shown when quitting with `operations` background operations still
+    /// running, or `None` when nothing would be lost by quitting right away
+    pub fn quit_confirmation(operations: usize) -> Option<DialogType> {
+        let noun = match operations {
+            0 => return None,
+            1 => "operation",
+            _ => "operations",
+        };
+        Some(DialogType::Confirmation {
+            message: format!("{} {} in progress, quit anyway?", operations, noun),
+            action: Box::new(Action::ForceQuit),
+        })
+    }
+
     /// Process an event through the component hierarchy
     pub async fn handle_event(&mut self, event_type: EventType) -> anyhow::Result<()> {
         let action = match event_type {
             EventType::Mouse(mouse) => {
-                if !self.dialog.is_visible() {
-                    if self.sidebar_visible && mouse.column < self.sidebar_width {
+                if self.dialog.is_visible() {
+                    self.dialog.handle_mouse(mouse)
+                } else if !self.focus_mode {
+                    if self.handle_sidebar_drag(mouse) {
+                        Action::None
+                    } else if self.sidebar_visible && mouse.column < self.sidebar_width {
                         // Mouse is in sidebar area
                         let sidebar_area = Rect::new(0, 0, self.sidebar_width, self.screen_height);
                         self.sidebar.handle_mouse(mouse, sidebar_area)
+                    } else if self.details_width > 0
+                        && mouse.column >= self.screen_width.saturating_sub(self.details_width)
+                    {
+                        // The detail pane only displays the selected task
+                        Action::None
                     } else {
                         // Mouse is in task list area - calculate proper width
-                        let task_list_width = self.screen_width.saturating_sub(self.sidebar_width).max(1);
+                        let task_list_width = self
+                            .screen_width
+                            .saturating_sub(self.sidebar_width)
+                            .saturating_sub(self.details_width)
+                            .max(1);
                         let task_list_area = Rect::new(self.sidebar_width, 0, task_list_width, self.screen_height);
                         self.task_list.handle_mouse(mouse, task_list_area)
                     }
@@ -1154,19 +2780,35 @@ impl AppComponent {
                 }
             }
             EventType::Key(key) => {
+                // Any key dismisses a toast without being swallowed by it
+                self.toast = None;
+
                 // Route keyboard events to components or handle globally
                 if self.dialog.is_visible() {
                     // Dialog has priority when visible
                     self.dialog.handle_key_events(key)
+                } else if self.focus_mode {
+                    // Focus mode only reacts to its own keys
+                    self.handle_focus_key(key)
+                } else if self.sidebar.is_jumping() {
+                    // Typing a project name to jump to
+                    self.sidebar.handle_jump_key(key)
+                } else if self.keymap.is(KeyAction::QuickAdd, &key) {
+                    // Quick add takes precedence over component keys so it works from any view
+                    self.quick_add_to_inbox()
                 } else {
+                    // Components match their default keys, so rebound keys are translated first
+                    let component_key = self.keymap.component_key(key);
+
                     // Try sidebar first (for J/K navigation)
-                    let sidebar_action = self.sidebar.handle_key_events(key);
+                    let sidebar_action = component_key.map_or(Action::None, |key| self.sidebar.handle_key_events(key));
 
                     if !matches!(sidebar_action, Action::None) {
                         sidebar_action
                     } else {
                         // Then try task list (for j/k and other task operations)
-                        let task_list_action = self.task_list.handle_key_events(key);
+                        let task_list_action =
+                            component_key.map_or(Action::None, |key| self.task_list.handle_key_events(key));
 
                         if !matches!(task_list_action, Action::None) {
                             task_list_action
@@ -1196,6 +2838,7 @@ impl AppComponent {
         };
 
         // Process action through component hierarchy
+        let action = self.with_task_edit_labels(action).await;
         let action = self.dialog.update(action);
         let action = self.sidebar.update(action);
         let action = self.task_list.update(action);
@@ -1211,12 +2854,68 @@ impl AppComponent {
 }
 
 impl AppComponent {
-    /// Calculate sidebar width based on configured columns
+    /// Calculate sidebar width from the configured or dragged columns, leaving the main area its minimum
     fn calculate_sidebar_width(&self, screen_width: u16) -> u16 {
-        let sidebar_columns = self.config.ui.sidebar_width;
+        let sidebar_columns = self.sidebar_columns;
         let max_sidebar_width = screen_width.saturating_sub(MAIN_AREA_MIN_WIDTH);
         sidebar_columns.min(max_sidebar_width)
     }
+
+    /// Resize the sidebar by dragging its border; returns whether the event belonged to a drag
+    fn handle_sidebar_drag(&mut self, mouse: MouseEvent) -> bool {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                // The sidebar's right border or the task list's left border next to it
+                self.resizing_sidebar = self.sidebar_visible
+                    && self.sidebar_width > 0
+                    && mouse.column.saturating_add(1) >= self.sidebar_width
+                    && mouse.column <= self.sidebar_width;
+                self.resizing_sidebar
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.resizing_sidebar => {
+                self.sidebar_columns = mouse.column.saturating_add(1).clamp(SIDEBAR_MIN_WIDTH, SIDEBAR_MAX_WIDTH);
+                self.sidebar_width = self.calculate_sidebar_width(self.screen_width);
+                true
+            }
+            MouseEventKind::Up(MouseButton::Left) if self.resizing_sidebar => {
+                self.resizing_sidebar = false;
+                info!("Sidebar: Resized to {} columns", self.sidebar_columns);
+                if self.config.ui.persist_state {
+                    self.ui_state.sidebar_width = Some(self.sidebar_columns);
+                    if let Err(e) = self.ui_state.save() {
+                        log::warn!("Sidebar: Failed to save sidebar width: {:#}", e);
+                    }
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Width of the detail pane next to a main area of `main_width` columns: two fifths of
+    /// it, or none when too narrow to leave the task list its minimum width
+    fn calculate_details_width(&self, main_width: u16) -> u16 {
+        if !self.details_visible || main_width < MAIN_AREA_MIN_WIDTH + DETAIL_PANE_MIN_WIDTH {
+            return 0;
+        }
+        (main_width / 5 * 2).max(DETAIL_PANE_MIN_WIDTH)
+    }
+
+    /// Render the detail pane for the task selected in the list
+    fn render_details_impl(&self, f: &mut Frame, rect: Rect) {
+        let lines = self.task_list.get_selected_task().map(|task| {
+            let label_names = self.task_list.task_labels().get(&task.uuid).map_or(&[][..], Vec::as_slice);
+            TaskDetail {
+                task,
+                projects: &self.state.projects,
+                sections: &self.state.sections,
+                labels: &self.state.labels,
+                label_names,
+            }
+            .lines(&self.theme, &self.config.display)
+        });
+        render_task_detail(f, rect, &self.theme, lines);
+    }
 }
 
 impl Component for AppComponent {
@@ -1235,31 +2934,65 @@ impl Component for AppComponent {
     }
 
     fn render(&mut self, f: &mut Frame, rect: Rect) {
-        // Create layout: sidebar (configurable width) | task list (remainder)
+        // Focus mode replaces the whole layout with the focused task
+        if self.focus_mode {
+            self.render_focus_mode_impl(f, rect);
+            if self.dialog.is_visible() {
+                self.dialog.render(f, rect);
+            }
+            return;
+        }
+
+        // The status bar takes the bottom line, when there is room to spare
+        let (main_area, status_area) = if self.config.ui.status_bar && rect.height > STATUS_BAR_MIN_SCREEN_HEIGHT {
+            let rows = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).split(rect);
+            (rows[0], Some(rows[1]))
+        } else {
+            (rect, None)
+        };
+
+        // Create layout: sidebar (configurable width) | task list (remainder) | details
         let sidebar_width = if self.sidebar_visible {
             self.calculate_sidebar_width(rect.width)
         } else {
             0
         };
+        let details_width = self.calculate_details_width(rect.width.saturating_sub(sidebar_width));
 
         // Update cached dimensions for mouse event handling
         self.sidebar_width = sidebar_width;
+        self.details_width = details_width;
         self.screen_width = rect.width;
-        self.screen_height = rect.height;
+        self.screen_height = main_area.height;
 
-        let main_chunks = Layout::horizontal([Constraint::Length(sidebar_width), Constraint::Min(0)]).split(rect);
+        let main_chunks = Layout::horizontal([
+            Constraint::Length(sidebar_width),
+            Constraint::Min(0),
+            Constraint::Length(details_width),
+        ])
+        .split(main_area);
 
         // Render components
         if self.sidebar_visible {
             self.sidebar.render(f, main_chunks[0]);
         }
         self.task_list.render(f, main_chunks[1]);
+        if details_width > 0 {
+            self.render_details_impl(f, main_chunks[2]);
+        }
+        if let Some(status_area) = status_area {
+            self.render_status_bar_impl(f, status_area);
+        }
 
         // Render sync status if syncing or loading
         if self.state.loading || self.is_syncing() {
             AppComponent::render_sync_status_impl(self, f, rect);
         }
 
+        if let Some((message, _)) = &self.toast {
+            AppComponent::render_toast_impl(f, main_area, message, &self.theme);
+        }
+
         // Render dialog on top if visible (includes help dialog)
         if self.dialog.is_visible() {
             self.dialog.render(f, rect);
@@ -1272,7 +3005,7 @@ impl AppComponent {
     fn render_sync_status_impl(&self, f: &mut Frame, rect: Rect) {
         use ratatui::{
             layout::{Alignment, Constraint, Layout},
-            style::{Color, Style},
+            style::Style,
             text::{Line, Span},
             widgets::{Block, Borders, Clear, Paragraph},
         };
@@ -1296,12 +3029,171 @@ impl AppComponent {
         let spinner = "⟳";
         let content = Paragraph::new(Line::from(Span::styled(
             format!("{} {}…", spinner, title),
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(self.theme.warning),
         )))
         .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Yellow)));
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(self.theme.warning)),
+        );
 
         f.render_widget(Clear, popup_area);
         f.render_widget(content, popup_area);
     }
+
+    /// Render the status bar: the view with its task count, then shortcuts for what has the keyboard
+    fn render_status_bar_impl(&self, f: &mut Frame, rect: Rect) {
+        use ratatui::{
+            style::{Modifier, Style},
+            text::{Line, Span},
+            widgets::Paragraph,
+        };
+
+        let context = if self.dialog.is_visible() {
+            StatusContext::Dialog
+        } else if self.task_list.has_marks() {
+            StatusContext::Marked
+        } else {
+            StatusContext::Tasks
+        };
+        let count = self.task_list.task_count();
+        let mut spans = vec![Span::styled(
+            format!(
+                " {} · {} task{} ",
+                self.view_name(),
+                count,
+                if count == 1 { "" } else { "s" }
+            ),
+            Style::default().fg(self.theme.accent).add_modifier(Modifier::BOLD),
+        )];
+        for (keys, description) in self.keymap.status_hints(context) {
+            spans.push(Span::styled(
+                format!(" {}", keys),
+                Style::default().fg(self.theme.accent),
+            ));
+            spans.push(Span::styled(
+                format!(" {} ", description),
+                Style::default().fg(self.theme.muted),
+            ));
+        }
+        f.render_widget(Paragraph::new(Line::from(spans)), rect);
+    }
+
+    /// Render a one-line toast in the bottom-right corner
+    fn render_toast_impl(f: &mut Frame, rect: Rect, message: &str, theme: &Theme) {
+        use ratatui::{
+            style::Style,
+            widgets::{Block, BorderType, Borders, Clear, Paragraph},
+        };
+
+        let width = (message.chars().count() as u16 + 4).min(rect.width);
+        let height = 3.min(rect.height);
+        let area = Rect::new(
+            rect.x + rect.width - width,
+            rect.y + rect.height - height,
+            width,
+            height,
+        );
+
+        let content = Paragraph::new(message.to_string())
+            .style(Style::default().fg(theme.text))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .style(Style::default().fg(theme.accent)),
+            );
+
+        f.render_widget(Clear, area);
+        f.render_widget(content, area);
+    }
+
+    /// Render the focus mode view: only the focused task, centered on screen
+    fn render_focus_mode_impl(&self, f: &mut Frame, rect: Rect) {
+        use ratatui::{
+            layout::{Alignment, Constraint, Layout},
+            style::{Modifier, Style},
+            text::{Line, Span},
+            widgets::{Clear, Paragraph, Wrap},
+        };
+
+        f.render_widget(Clear, rect);
+
+        let task = self
+            .focus_task_uuid
+            .and_then(|uuid| self.state.tasks.iter().find(|t| t.uuid == uuid));
+
+        let mut lines = Vec::new();
+        match task {
+            Some(task) => {
+                if let Some(project) = self.state.projects.iter().find(|p| p.uuid == task.project_uuid) {
+                    lines.push(Line::from(Span::styled(
+                        project.name.clone(),
+                        Style::default().fg(self.theme.dim),
+                    )));
+                    lines.push(Line::from(""));
+                }
+                lines.push(Line::from(Span::styled(
+                    task.content.clone(),
+                    Style::default().fg(self.theme.text).add_modifier(Modifier::BOLD),
+                )));
+                if let Some(due) = task.due() {
+                    lines.push(Line::from(Span::styled(
+                        due.humanize(datetime::now()),
+                        Style::default().fg(self.theme.warning),
+                    )));
+                }
+                if let Some(description) = task.description.as_deref().filter(|d| !d.trim().is_empty()) {
+                    lines.push(Line::from(""));
+                    for line in description.lines() {
+                        lines.push(Line::from(Span::styled(
+                            line.to_string(),
+                            Style::default().fg(self.theme.muted),
+                        )));
+                    }
+                }
+            }
+            None => lines.push(Line::from(Span::styled(
+                "Task no longer available",
+                Style::default().fg(self.theme.dim),
+            ))),
+        }
+
+        if self.focus_timer_visible {
+            if let Some(started_at) = self.focus_started_at {
+                let elapsed = started_at.elapsed().as_secs();
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(
+                    format!("⏱ {:02}:{:02}", elapsed / 60, elapsed % 60),
+                    Style::default().fg(self.theme.accent),
+                )));
+            }
+        }
+
+        let content_height = (lines.len() as u16).min(rect.height);
+        let chunks = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(content_height),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .split(rect);
+        let content_area = Layout::horizontal([
+            Constraint::Percentage(15),
+            Constraint::Percentage(70),
+            Constraint::Percentage(15),
+        ])
+        .split(chunks[1])[1];
+
+        let content = Paragraph::new(lines).alignment(Alignment::Center).wrap(Wrap { trim: false });
+        f.render_widget(content, content_area);
+
+        let hints = Paragraph::new(Line::from(Span::styled(
+            "Space complete • t timer • Esc/f exit",
+            Style::default().fg(self.theme.dim),
+        )))
+        .alignment(Alignment::Center);
+        f.render_widget(hints, chunks[3]);
+    }
 }
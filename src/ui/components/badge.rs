@@ -1,4 +1,7 @@
+use crate::config::LabelStyle;
 use crate::entities::label;
+use crate::ui::core::theme::Theme;
+use crate::utils::color;
 use ratatui::{
     style::{Color, Modifier, Style},
     text::Span,
@@ -16,6 +19,24 @@ pub fn create_paren_badge(text: &str) -> Span<'static> {
     )
 }
 
+/// Create the open task count badge of a sidebar entry ("12", "999+")
+#[must_use]
+pub fn create_count_badge(count: usize) -> Span<'static> {
+    let text = if count > 999 {
+        "999+".to_string()
+    } else {
+        count.to_string()
+    };
+    Span::styled(text, Style::default().fg(Color::DarkGray))
+}
+
+/// Create the completion bar of a project, in the success color once every task is done
+#[must_use]
+pub fn create_progress_badge(bar: String, percent: u8, theme: &Theme) -> Span<'static> {
+    let color = if percent >= 100 { theme.success } else { theme.dim };
+    Span::styled(bar, Style::default().fg(color))
+}
+
 /// Create a label badge with custom color
 #[must_use]
 pub fn create_label_badge(name: &str) -> Span<'static> {
@@ -24,48 +45,80 @@ pub fn create_label_badge(name: &str) -> Span<'static> {
     Span::styled(format!("@{}", name), style)
 }
 
-/// Create task badges optimized for terminal compatibility
+/// Create the deadline badge ("⇥ by Jan 31") with the theme's deadline `icon`
+///
+/// A missed deadline is drawn reversed in the overdue color, so it stands out more than
+/// an overdue due date.
 #[must_use]
-pub fn create_task_badges(
-    is_recurring: bool,
-    _has_deadline: bool,
-    duration: Option<&str>,
-    labels: &[label::Model],
-) -> Vec<Span<'static>> {
-    let mut badges = Vec::new();
+pub fn create_deadline_badge(icon: &str, when: &str, missed: bool, theme: &Theme) -> Span<'static> {
+    let style = if missed {
+        Style::default()
+            .fg(theme.overdue)
+            .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+    } else {
+        Style::default().fg(theme.secondary)
+    };
+
+    Span::styled(format!("{} by {}", icon, when), style)
+}
+
+/// Number of characters kept from a label name in the `short` label style
+pub const SHORT_LABEL_LENGTH: usize = 2;
 
-    if is_recurring {
-        badges.push(Span::styled("🔄", Style::default()));
+/// Create the badge for a label in the given style.
+///
+/// `Dot` renders a single `●` in the label color, so each label takes one column.
+#[must_use]
+pub fn create_styled_label_badge(label: &label::Model, label_style: LabelStyle) -> Span<'static> {
+    match label_style {
+        LabelStyle::Full => create_label_badge(&label.name),
+        LabelStyle::Short => {
+            let short_name: String = label.name.chars().take(SHORT_LABEL_LENGTH).collect();
+            create_label_badge(&short_name)
+        }
+        LabelStyle::Dot => Span::styled("●", Style::default().fg(color::to_terminal_color(&label.color))),
     }
+}
+
+/// Create task badges optimized for terminal compatibility
+#[must_use]
+pub fn create_task_badges(_has_deadline: bool, duration: Option<&str>) -> Vec<Span<'static>> {
+    let mut badges = Vec::new();
 
     if let Some(duration) = duration {
         badges.push(create_paren_badge(duration));
     }
 
-    for label in labels {
-        badges.push(create_label_badge(&label.name));
-    }
-
     badges
 }
 
-/// Create priority badges with flag symbols
+/// Create the label badges of a task, each preceded by its separator.
+///
+/// Names are separated by spaces; dots are packed together after a single space so
+/// that `n` labels take `n + 1` columns.
 #[must_use]
-pub fn create_priority_badge(priority: i32) -> Option<Span<'static>> {
+pub fn create_label_badges(labels: &[label::Model], label_style: LabelStyle) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    for (index, label) in labels.iter().enumerate() {
+        if index == 0 || label_style != LabelStyle::Dot {
+            spans.push(Span::raw(" "));
+        }
+        spans.push(create_styled_label_badge(label, label_style));
+    }
+    spans
+}
+
+/// Create priority badges with flag symbols, in the theme's priority colors
+#[must_use]
+pub fn create_priority_badge(priority: i32, theme: &Theme) -> Option<Span<'static>> {
+    let color = theme.priority_color(priority);
     match priority {
-        4 => Some(Span::styled(
-            "⚑",
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-        )), // P1 = red flag
-        3 => Some(Span::styled(
-            "⚑",
-            Style::default().fg(Color::Rgb(255, 165, 0)).add_modifier(Modifier::BOLD),
-        )), // P2 = orange flag
-        2 => Some(Span::styled(
+        // P1 to P3: filled flag
+        2..=4 => Some(Span::styled(
             "⚑",
-            Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD),
-        )), // P3 = blue flag
-        1 => Some(Span::styled("⚐", Style::default().fg(Color::White))), // P4 = white flag (default color)
-        _ => Some(Span::styled("⚐", Style::default().fg(Color::White))), // Unknown priority = P4 = white flag
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )),
+        // P4 (and unknown priorities): outlined flag
+        _ => Some(Span::styled("⚐", Style::default().fg(color))),
     }
 }
@@ -0,0 +1,65 @@
+use crate::entities::project;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Separator placed between project names in a breadcrumb
+pub const BREADCRUMB_SEPARATOR: &str = " › ";
+
+/// Placeholder for ancestors left out of an abbreviated breadcrumb
+pub const BREADCRUMB_ELLIPSIS: &str = "…";
+
+/// Project names from the root down to `project_uuid`, following `parent_uuid` links.
+///
+/// The walk stops at a parent that isn't loaded and at the first project seen twice,
+/// so inconsistent data with a parent cycle can't loop forever.
+#[must_use]
+pub fn project_path(projects: &[project::Model], project_uuid: &Uuid) -> Vec<String> {
+    let mut path = Vec::new();
+    let mut visited = HashSet::new();
+    let mut current = Some(*project_uuid);
+
+    while let Some(uuid) = current {
+        if !visited.insert(uuid) {
+            break;
+        }
+        let Some(project) = projects.iter().find(|p| p.uuid == uuid) else {
+            break;
+        };
+        path.push(project.name.clone());
+        current = project.parent_uuid;
+    }
+
+    path.reverse();
+    path
+}
+
+/// Join a project path into a breadcrumb of at most `max_width` characters.
+///
+/// Long chains keep the root and as many of the nearest ancestors as fit, replacing
+/// the ones in between with an ellipsis ("Work › … › Auth"). The project's own
+/// name is always kept, even if it alone exceeds `max_width`.
+#[must_use]
+pub fn format_breadcrumb(path: &[String], max_width: usize) -> String {
+    let full = path.join(BREADCRUMB_SEPARATOR);
+    if path.len() <= 2 || full.chars().count() <= max_width {
+        return full;
+    }
+
+    // Keep the root plus the last `kept` names, dropping middle ancestors first
+    for kept in (1..path.len() - 1).rev() {
+        let mut parts = vec![path[0].as_str(), BREADCRUMB_ELLIPSIS];
+        parts.extend(path[path.len() - kept..].iter().map(String::as_str));
+        let abbreviated = parts.join(BREADCRUMB_SEPARATOR);
+        if abbreviated.chars().count() <= max_width {
+            return abbreviated;
+        }
+    }
+
+    let leaf = &path[path.len() - 1];
+    let short = format!("{BREADCRUMB_ELLIPSIS}{BREADCRUMB_SEPARATOR}{leaf}");
+    if short.chars().count() <= max_width {
+        short
+    } else {
+        leaf.clone()
+    }
+}
@@ -4,21 +4,165 @@
 //! types of user interactions including task creation/editing, project management,
 //! label management, and system functions like search and debugging.
 
+use crate::backend::{BackendStatus, UpdateTaskArgs};
 use crate::config::DisplayConfig;
-use crate::entities::{label, project, task};
-use crate::icons::IconService;
+use crate::entities::{label, project, section, task};
+use crate::icons::{IconService, IconTheme};
+use crate::sync::duplicates::DuplicateGroup;
+use crate::sync::quick_add::{self, QuickAdd};
 use crate::sync::SyncService;
 use crate::ui::components::task_list_item_component::{ListItem as TaskListItem, TaskItem};
 use crate::ui::core::{
-    actions::{Action, DialogType},
+    actions::{Action, BatchReport, DialogType},
+    keymap::{KeyAction, KeyMap},
+    theme::Theme,
     Component,
 };
-use crossterm::event::{KeyCode, KeyEvent};
+use crate::utils::export::ExportFormat;
+use crate::utils::{color, datetime};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 use ratatui::{layout::Rect, widgets::ScrollbarState, Frame};
 use uuid::Uuid;
 
 use crate::ui::components::dialogs::{label_dialogs, project_dialogs, scroll_behavior, system_dialogs, task_dialogs};
 
+/// Field of the task edit form, in `Tab` order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskEditField {
+    Content,
+    Description,
+    Priority,
+    Labels,
+    Due,
+    Deadline,
+    Duration,
+}
+
+impl TaskEditField {
+    pub const ALL: [TaskEditField; 7] = [
+        TaskEditField::Content,
+        TaskEditField::Description,
+        TaskEditField::Priority,
+        TaskEditField::Labels,
+        TaskEditField::Due,
+        TaskEditField::Deadline,
+        TaskEditField::Duration,
+    ];
+
+    /// Title of the field's input box
+    pub fn title(&self) -> &'static str {
+        match self {
+            TaskEditField::Content => "Task Content",
+            TaskEditField::Description => "Description",
+            TaskEditField::Priority => "Priority (p1-p4)",
+            TaskEditField::Labels => "Labels (comma separated)",
+            TaskEditField::Due => "Due (e.g. 'next tuesday 3pm', empty clears)",
+            TaskEditField::Deadline => "Deadline (e.g. 'end of month', empty clears)",
+            TaskEditField::Duration => "Duration (e.g. '45m', '1h30m', empty clears)",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    fn previous(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// Text shown in each task edit form field when the form opens
+fn task_edit_initial_values(task: &task::Model, labels: &[String]) -> [String; 7] {
+    [
+        task.content.clone(),
+        task.description.clone().unwrap_or_default(),
+        format!("p{}", 5 - task.priority),
+        labels.join(", "),
+        task.due().map(|due| due.display("%Y-%m-%d", "%H:%M")).unwrap_or_default(),
+        task.deadline.clone().unwrap_or_default(),
+        task.duration
+            .as_deref()
+            .map(|d| datetime::parse_duration(d).map_or_else(|| d.to_string(), datetime::format_duration))
+            .unwrap_or_default(),
+    ]
+}
+
+/// Label names typed in the labels field, separated by commas or spaces
+fn parse_label_list(text: &str) -> Vec<String> {
+    text.split(|c: char| c == ',' || c.is_whitespace())
+        .map(|name| name.trim_start_matches('@'))
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Backend update for the fields that differ from the form's initial values
+///
+/// Unchanged fields stay `None` so the update doesn't overwrite values on the server.
+/// Returns the message to show if a priority, due date, deadline or duration can't be understood.
+fn task_edit_changes(initial: &[String; 7], values: &[String; 7]) -> Result<UpdateTaskArgs, String> {
+    let changed = |field: TaskEditField| {
+        let value = values[field.index()].trim();
+        (value != initial[field.index()].trim()).then_some(value)
+    };
+
+    let mut changes = UpdateTaskArgs {
+        content: changed(TaskEditField::Content).map(str::to_string),
+        description: changed(TaskEditField::Description).map(str::to_string),
+        ..Default::default()
+    };
+    if let Some(priority) = changed(TaskEditField::Priority) {
+        // An empty priority resets it to the default (p4)
+        changes.priority = Some(match priority {
+            "" => 1,
+            priority => quick_add::parse_priority(priority)
+                .ok_or_else(|| format!("Could not understand priority '{}' (use p1-p4)", priority))?,
+        });
+    }
+    // Compare names rather than text, so "a, b" and "a b" are the same labels
+    let labels = parse_label_list(&values[TaskEditField::Labels.index()]);
+    if labels != parse_label_list(&initial[TaskEditField::Labels.index()]) {
+        changes.labels = Some(labels);
+    }
+    match changed(TaskEditField::Due) {
+        None => {}
+        Some("") => changes.due_date = Some(String::new()),
+        Some(due) => {
+            let (date, time) = datetime::parse_natural_due(due, datetime::today())
+                .ok_or_else(|| format!("Could not understand due date '{}'", due))?;
+            let due = datetime::format_due(date, time);
+            if time.is_some() {
+                changes.due_datetime = Some(due);
+            } else {
+                changes.due_date = Some(due);
+            }
+        }
+    }
+    match changed(TaskEditField::Deadline) {
+        None => {}
+        Some("") => changes.deadline = Some(String::new()),
+        Some(deadline) => {
+            let date = datetime::parse_natural_date(deadline, datetime::today())
+                .ok_or_else(|| format!("Could not understand deadline '{}'", deadline))?;
+            changes.deadline = Some(datetime::format_ymd(date));
+        }
+    }
+    match changed(TaskEditField::Duration) {
+        None => {}
+        Some("") => changes.duration = Some(String::new()),
+        Some(duration) => {
+            let minutes = datetime::parse_duration(duration)
+                .ok_or_else(|| format!("Could not understand duration '{}' (e.g. 45m, 1h30m)", duration))?;
+            changes.duration = Some(datetime::backend_duration(minutes));
+        }
+    }
+    Ok(changes)
+}
+
 /// Modal dialog component that handles various user interactions.
 ///
 /// This component serves as a container for different types of dialogs:
@@ -47,19 +191,37 @@ pub struct DialogComponent {
     pub projects: Vec<project::Model>,
     pub labels: Vec<label::Model>,
     pub tasks: Vec<task::Model>,
+    pub sections: Vec<section::Model>,
     pub selected_project_index: usize,
     pub selected_parent_project_index: Option<usize>, // For project creation parent selection
     pub selected_task_project_index: Option<usize>,   // For task creation project selection (None = no project/inbox)
     pub selected_task_project_uuid: Option<Uuid>,     // Store the actual UUID to avoid index issues
     pub task_project_explicitly_selected: bool,       // Track if user explicitly selected a project via Tab
+    pub selected_task_section_uuid: Option<Uuid>,     // For task creation/edit section selection (None = no section)
+    pub task_edit_field: TaskEditField,               // Task edit form field being typed in (held in input_buffer)
+    pub task_edit_values: [String; 7],                // Task edit form values, indexed by TaskEditField
+    pub recent_project_uuids: Vec<Uuid>,              // Recently used projects, most recent first
+    pub color_index: usize, // Color picked in label and project dialogs (index into COLOR_NAMES)
+    pub label_is_favorite: bool, // For label editing favorite toggle
+    pub checked_labels: Vec<String>, // Label names ticked in the task labels dialog
+    pub selected_label_index: usize, // Highlighted row of the task labels dialog
+    pub selected_parent_task_index: usize, // Highlighted row of the nest tasks dialog
     pub icons: IconService,
     // Scrolling support for long content dialogs
     pub scroll_offset: usize,
     pub scrollbar_state: ScrollbarState,
     // Task search state
     pub search_results: Vec<task::Model>,
+    // Duplicate tasks state
+    pub duplicate_groups: Vec<DuplicateGroup>,
+    pub selected_duplicate_index: usize, // Index into the flattened list of duplicate tasks
+    pub return_to_duplicates: bool,      // A delete confirmation goes back to the duplicates list
+    // Backend status state (None while the check runs)
+    pub backend_status: Option<BackendStatus>,
     pub sync_service: Option<SyncService>,
     pub display_config: DisplayConfig,
+    pub keymap: KeyMap,
+    pub theme: Theme,
 }
 
 impl Default for DialogComponent {
@@ -77,17 +239,33 @@ impl DialogComponent {
             projects: Vec::new(),
             labels: Vec::new(),
             tasks: Vec::new(),
+            sections: Vec::new(),
             selected_project_index: 0,
             selected_parent_project_index: None,
             selected_task_project_index: None, // Default to "None" for tasks (no project)
             selected_task_project_uuid: None,  // No project selected initially
             task_project_explicitly_selected: false, // User hasn't used Tab yet
+            selected_task_section_uuid: None,
+            task_edit_field: TaskEditField::Content,
+            task_edit_values: Default::default(),
+            recent_project_uuids: Vec::new(),
+            color_index: 0,
+            label_is_favorite: false,
+            checked_labels: Vec::new(),
+            selected_label_index: 0,
+            selected_parent_task_index: 0,
             icons: IconService::default(),
             scroll_offset: 0,
             scrollbar_state: ScrollbarState::new(0),
             search_results: Vec::new(),
+            duplicate_groups: Vec::new(),
+            selected_duplicate_index: 0,
+            return_to_duplicates: false,
+            backend_status: None,
             sync_service: None,
             display_config: DisplayConfig::default(),
+            keymap: KeyMap::default(),
+            theme: Theme::default(),
         }
     }
 
@@ -95,6 +273,19 @@ impl DialogComponent {
         self.display_config = display_config;
     }
 
+    /// Set the key bindings listed in the help dialog
+    pub fn set_keymap(&mut self, keymap: KeyMap) {
+        self.keymap = keymap;
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    pub fn set_icon_theme(&mut self, icon_theme: IconTheme) {
+        self.icons.set_theme(icon_theme);
+    }
+
     pub fn update_data(&mut self, projects: Vec<project::Model>, labels: Vec<label::Model>) {
         self.projects = projects;
         self.labels = labels;
@@ -111,6 +302,79 @@ impl DialogComponent {
         self.tasks = tasks;
     }
 
+    pub fn set_sections(&mut self, sections: Vec<section::Model>) {
+        self.sections = sections;
+    }
+
+    /// Project of the task being created or edited, as currently selected in the dialog
+    fn task_dialog_project(&self) -> Option<Uuid> {
+        match &self.dialog_type {
+            Some(DialogType::TaskCreation { default_project_uuid }) => {
+                if self.task_project_explicitly_selected {
+                    self.selected_task_project_uuid
+                } else {
+                    *default_project_uuid
+                }
+            }
+            Some(DialogType::TaskEdit { project_uuid, .. }) => Some(*project_uuid),
+            _ => None,
+        }
+    }
+
+    /// Sections offered for the task being created or edited, in display order
+    pub fn task_dialog_sections(&self) -> Vec<&section::Model> {
+        let Some(project_uuid) = self.task_dialog_project() else {
+            return Vec::new();
+        };
+        let mut sections: Vec<&section::Model> =
+            self.sections.iter().filter(|s| s.project_uuid == project_uuid).collect();
+        sections.sort_by_key(|s| s.order_index);
+        sections
+    }
+
+    /// Select the next section of the task's project, cycling back to "no section"
+    fn cycle_task_section(&mut self) {
+        let sections = self.task_dialog_sections();
+        let next = match self.selected_task_section_uuid {
+            None => sections.first().map(|s| s.uuid),
+            Some(current) => sections
+                .iter()
+                .position(|s| s.uuid == current)
+                .and_then(|index| sections.get(index + 1))
+                .map(|s| s.uuid),
+        };
+        self.selected_task_section_uuid = next;
+    }
+
+    /// Task edit form values, including the one being typed in
+    fn task_edit_form_values(&self) -> [String; 7] {
+        let mut values = self.task_edit_values.clone();
+        values[self.task_edit_field.index()] = self.input_buffer.clone();
+        values
+    }
+
+    /// Move the task edit form's input to another field, keeping what was typed
+    fn focus_task_edit_field(&mut self, field: TaskEditField) {
+        self.task_edit_values[self.task_edit_field.index()] = std::mem::take(&mut self.input_buffer);
+        self.task_edit_field = field;
+        self.input_buffer = std::mem::take(&mut self.task_edit_values[field.index()]);
+        self.cursor_position = self.input_buffer.chars().count();
+    }
+
+    /// Set the recently used projects offered as the default for new tasks (empty disables it)
+    pub fn set_recent_projects(&mut self, recent_project_uuids: Vec<Uuid>) {
+        self.recent_project_uuids = recent_project_uuids;
+    }
+
+    /// Most recently used project that still exists and can hold tasks
+    fn recent_task_project(&self) -> Option<Uuid> {
+        let task_projects = self.get_task_projects();
+        self.recent_project_uuids
+            .iter()
+            .find(|uuid| task_projects.iter().any(|p| &p.uuid == *uuid))
+            .copied()
+    }
+
     pub fn set_sync_service(&mut self, sync_service: SyncService) {
         self.sync_service = Some(sync_service);
     }
@@ -139,6 +403,49 @@ impl DialogComponent {
         }
     }
 
+    /// Update duplicate task groups from a background scan
+    pub fn update_duplicate_groups(&mut self, groups: Vec<DuplicateGroup>) {
+        if matches!(self.dialog_type, Some(DialogType::DuplicateTasks)) {
+            self.duplicate_groups = groups;
+            let count = self.duplicate_task_count();
+            self.selected_duplicate_index = self.selected_duplicate_index.min(count.saturating_sub(1));
+        }
+    }
+
+    /// Show the result of a backend connection check
+    pub fn update_backend_status(&mut self, status: BackendStatus) {
+        if matches!(self.dialog_type, Some(DialogType::BackendStatus)) {
+            self.backend_status = Some(status);
+        }
+    }
+
+    fn duplicate_task_count(&self) -> usize {
+        self.duplicate_groups.iter().map(|group| group.tasks.len()).sum()
+    }
+
+    /// Go back to the duplicates list after a delete confirmation, without `deleted_uuid`
+    /// until the rescan that follows the deletion refreshes the list
+    fn return_to_duplicates(&mut self, deleted_uuid: Option<Uuid>) {
+        if let Some(uuid) = deleted_uuid {
+            for group in &mut self.duplicate_groups {
+                group.tasks.retain(|task| task.uuid != uuid);
+            }
+            self.duplicate_groups.retain(|group| group.tasks.len() > 1);
+        }
+        let count = self.duplicate_task_count();
+        self.selected_duplicate_index = self.selected_duplicate_index.min(count.saturating_sub(1));
+        self.return_to_duplicates = false;
+        self.dialog_type = Some(DialogType::DuplicateTasks);
+    }
+
+    /// Task currently selected in the duplicate tasks dialog
+    fn selected_duplicate_task(&self) -> Option<&task::Model> {
+        self.duplicate_groups
+            .iter()
+            .flat_map(|group| group.tasks.iter())
+            .nth(self.selected_duplicate_index)
+    }
+
     pub fn is_visible(&self) -> bool {
         self.dialog_type.is_some()
     }
@@ -169,9 +476,24 @@ impl DialogComponent {
                         log::info!("Creating task in inbox (no project)");
                     }
 
+                    // Quick-add syntax: "#project" and "p1"-"p4" tokens and a trailing date phrase
+                    // ("Call mom #Family p2 next tuesday 3pm"); a named project wins over the selection
+                    let quick_add = QuickAdd::parse(&self.input_buffer, &self.projects, datetime::today());
+                    log::info!("Quick add parsed: {:?}", quick_add);
+                    let project_uuid = quick_add.project_uuid.or(project_uuid);
+                    // The section only applies if a "#project" token didn't switch projects
+                    let section_uuid = self.selected_task_section_uuid.filter(|section_uuid| {
+                        self.sections
+                            .iter()
+                            .any(|s| s.uuid == *section_uuid && Some(s.project_uuid) == project_uuid)
+                    });
                     let action = Action::CreateTask {
-                        content: self.input_buffer.clone(),
+                        content: quick_add.content,
                         project_uuid,
+                        section_uuid,
+                        due: quick_add.due,
+                        priority: quick_add.priority,
+                        duration: quick_add.duration,
                     };
                     self.clear_dialog();
                     action
@@ -179,17 +501,34 @@ impl DialogComponent {
                     Action::None
                 }
             }
-            Some(DialogType::TaskEdit { task_uuid, .. }) => {
-                if !self.input_buffer.is_empty() {
-                    let action = Action::EditTask {
-                        task_uuid: *task_uuid,
-                        content: self.input_buffer.clone(),
-                    };
-                    self.clear_dialog();
-                    action
-                } else {
-                    Action::None
+            Some(DialogType::TaskEdit {
+                task_uuid,
+                labels,
+                snapshot,
+                ..
+            }) => {
+                let values = self.task_edit_form_values();
+                if values[TaskEditField::Content.index()].trim().is_empty() {
+                    return Action::None;
                 }
+                let changes = match task_edit_changes(&task_edit_initial_values(snapshot, labels), &values) {
+                    Ok(changes) => changes,
+                    Err(message) => return Action::ShowDialog(DialogType::Error(message)),
+                };
+                let section_change =
+                    Some(self.selected_task_section_uuid).filter(|section| *section != snapshot.section_uuid);
+                let action = if changes.is_empty() && section_change.is_none() {
+                    Action::None
+                } else {
+                    Action::EditTask {
+                        task_uuid: *task_uuid,
+                        changes: Box::new(changes),
+                        section_change,
+                        snapshot: Some(snapshot.clone()),
+                    }
+                };
+                self.clear_dialog();
+                action
             }
             Some(DialogType::ProjectCreation) => {
                 if !self.input_buffer.is_empty() {
@@ -207,6 +546,7 @@ impl DialogComponent {
                     let action = Action::CreateProject {
                         name: self.input_buffer.clone(),
                         parent_uuid,
+                        color: self.selected_color().to_string(),
                     };
                     self.clear_dialog();
                     action
@@ -219,6 +559,7 @@ impl DialogComponent {
                     let action = Action::EditProject {
                         project_uuid: *project_uuid,
                         name: self.input_buffer.clone(),
+                        color: self.selected_color().to_string(),
                     };
                     self.clear_dialog();
                     action
@@ -242,6 +583,77 @@ impl DialogComponent {
                     let action = Action::EditLabel {
                         label_uuid: *label_uuid,
                         name: self.input_buffer.clone(),
+                        color: self.selected_color().to_string(),
+                        is_favorite: self.label_is_favorite,
+                    };
+                    self.clear_dialog();
+                    action
+                } else {
+                    Action::None
+                }
+            }
+            Some(DialogType::DeadlinePrompt { task_uuid, .. }) => {
+                let input = self.input_buffer.trim();
+                let deadline = if input.is_empty() {
+                    None
+                } else {
+                    match datetime::parse_natural_date(input, datetime::today()) {
+                        Some(date) => Some(datetime::format_ymd(date)),
+                        None => {
+                            return Action::ShowDialog(DialogType::Error(format!(
+                                "Could not understand deadline '{}'",
+                                input
+                            )))
+                        }
+                    }
+                };
+                let action = Action::SetTaskDeadline {
+                    task_uuid: *task_uuid,
+                    deadline,
+                };
+                self.clear_dialog();
+                action
+            }
+            Some(DialogType::SetDueDate { task_uuid, .. }) => {
+                let input = self.input_buffer.trim();
+                let date = if input.is_empty() {
+                    None
+                } else {
+                    match datetime::parse_natural_due(input, datetime::today()) {
+                        Some((date, time)) => Some(datetime::format_due(date, time)),
+                        None => {
+                            return Action::ShowDialog(DialogType::Error(format!(
+                                "Could not understand due date '{}'",
+                                input
+                            )))
+                        }
+                    }
+                };
+                let action = Action::SetTaskDueDate {
+                    task_uuid: *task_uuid,
+                    date,
+                };
+                self.clear_dialog();
+                action
+            }
+            Some(DialogType::ExportView { .. }) => {
+                let path = self.input_buffer.trim().to_string();
+                if path.is_empty() {
+                    return Action::None;
+                }
+                let action = Action::ExportView {
+                    format: ExportFormat::from_path(&path),
+                    path,
+                };
+                self.clear_dialog();
+                action
+            }
+            Some(DialogType::TokenPrompt { retry }) => {
+                let token = self.input_buffer.trim().to_string();
+                if !token.is_empty() {
+                    let action = Action::UpdateApiToken {
+                        token,
+                        retry: retry.clone(),
                     };
                     self.clear_dialog();
                     action
@@ -250,6 +662,11 @@ impl DialogComponent {
                 }
             }
             Some(DialogType::DeleteConfirmation { item_type, item_uuid }) => match item_type.as_str() {
+                "task" if self.return_to_duplicates => {
+                    let item_uuid = *item_uuid;
+                    self.return_to_duplicates(Some(item_uuid));
+                    Action::DeleteTask(item_uuid.to_string())
+                }
                 "task" => {
                     let action = Action::DeleteTask(item_uuid.to_string());
                     self.clear_dialog();
@@ -267,10 +684,36 @@ impl DialogComponent {
                 }
                 _ => Action::None,
             },
+            Some(DialogType::NewLabelConfirmation { action, .. }) => {
+                let action = Action::ConfirmNewLabels(action.clone());
+                self.clear_dialog();
+                action
+            }
+            Some(DialogType::Confirmation { action, .. }) => {
+                let action = (**action).clone();
+                self.clear_dialog();
+                action
+            }
             _ => Action::None,
         }
     }
 
+    /// Color name currently selected in the label or project dialogs
+    fn selected_color(&self) -> &'static str {
+        color::COLOR_NAMES
+            .get(self.color_index)
+            .copied()
+            .unwrap_or(color::DEFAULT_COLOR)
+    }
+
+    /// Open tasks the marked ones can be nested under, in list order
+    fn nest_parent_candidates(&self, task_uuids: &[Uuid]) -> Vec<&task::Model> {
+        self.tasks
+            .iter()
+            .filter(|task| !task.is_completed && !task.is_deleted && !task_uuids.contains(&task.uuid))
+            .collect()
+    }
+
     fn clear_dialog(&mut self) {
         self.dialog_type = None;
         self.input_buffer.clear();
@@ -280,9 +723,21 @@ impl DialogComponent {
         self.selected_task_project_index = None; // Reset to "None" for task creation
         self.selected_task_project_uuid = None; // Reset stored UUID
         self.task_project_explicitly_selected = false; // Reset selection flag
+        self.selected_task_section_uuid = None;
+        self.task_edit_field = TaskEditField::Content;
+        self.task_edit_values = Default::default();
+        self.color_index = 0;
+        self.label_is_favorite = false;
+        self.checked_labels.clear();
+        self.selected_label_index = 0;
+        self.selected_parent_task_index = 0;
         self.scroll_offset = 0;
         self.scrollbar_state = ScrollbarState::new(0);
         self.search_results.clear();
+        self.duplicate_groups.clear();
+        self.selected_duplicate_index = 0;
+        self.return_to_duplicates = false;
+        self.backend_status = None;
     }
 
     fn scroll_up(&mut self) {
@@ -309,76 +764,163 @@ impl DialogComponent {
         scroll_behavior::scroll_to_bottom(&mut self.scroll_offset, &mut self.scrollbar_state);
     }
 
+    /// Scroll the content of the help, logs and message dialogs with the mouse wheel
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> Action {
+        let scrollable = matches!(
+            self.dialog_type,
+            Some(
+                DialogType::Info(_)
+                    | DialogType::Warning(_)
+                    | DialogType::Error(_)
+                    | DialogType::Help
+                    | DialogType::Logs
+            )
+        );
+        if scrollable {
+            match mouse.kind {
+                MouseEventKind::ScrollUp => self.scroll_up(),
+                MouseEventKind::ScrollDown => self.scroll_down(),
+                _ => {}
+            }
+        }
+        Action::None
+    }
+
     fn render_task_creation_dialog(&self, f: &mut Frame, area: Rect) {
         let task_projects = self.get_task_projects();
         task_dialogs::render_task_creation_dialog(
             f,
             area,
+            &self.theme,
             &self.icons,
             &self.input_buffer,
             self.cursor_position,
             &task_projects,
             self.selected_task_project_index,
+            self.task_section_field(),
         );
     }
 
+    /// Value of the section field, or `None` to hide it when the project has no sections
+    fn task_section_field(&self) -> Option<String> {
+        let sections = self.task_dialog_sections();
+        if sections.is_empty() {
+            return None;
+        }
+        let name = self
+            .selected_task_section_uuid
+            .and_then(|uuid| sections.iter().find(|s| s.uuid == uuid))
+            .map_or_else(|| "None".to_string(), |s| s.name.clone());
+        Some(name)
+    }
+
     fn render_project_creation_dialog(&self, f: &mut Frame, area: Rect) {
         let root_projects = self.get_root_projects();
         project_dialogs::render_project_creation_dialog(
             f,
             area,
+            &self.theme,
             &self.icons,
             &self.input_buffer,
             self.cursor_position,
             &root_projects,
             self.selected_parent_project_index,
+            self.selected_color(),
         );
     }
 
     fn render_project_edit_dialog(&self, f: &mut Frame, area: Rect) {
-        project_dialogs::render_project_edit_dialog(f, area, &self.icons, &self.input_buffer, self.cursor_position);
+        project_dialogs::render_project_edit_dialog(
+            f,
+            area,
+            &self.theme,
+            &self.icons,
+            &self.input_buffer,
+            self.cursor_position,
+            self.selected_color(),
+        );
     }
 
     fn render_label_creation_dialog(&self, f: &mut Frame, area: Rect) {
-        label_dialogs::render_label_creation_dialog(f, area, &self.icons, &self.input_buffer, self.cursor_position);
+        label_dialogs::render_label_creation_dialog(
+            f,
+            area,
+            &self.theme,
+            &self.icons,
+            &self.input_buffer,
+            self.cursor_position,
+        );
     }
 
     fn render_label_edit_dialog(&self, f: &mut Frame, area: Rect) {
-        label_dialogs::render_label_edit_dialog(f, area, &self.icons, &self.input_buffer, self.cursor_position);
+        label_dialogs::render_label_edit_dialog(
+            f,
+            area,
+            &self.theme,
+            &self.icons,
+            &self.input_buffer,
+            self.cursor_position,
+            self.selected_color(),
+            self.label_is_favorite,
+        );
     }
 
     fn render_task_edit_dialog(&self, f: &mut Frame, area: Rect) {
-        let task_projects = self.get_task_projects();
+        let project_name = self
+            .task_dialog_project()
+            .and_then(|uuid| self.projects.iter().find(|p| p.uuid == uuid))
+            .map(|p| p.name.clone())
+            .unwrap_or_else(|| "None (Inbox)".to_string());
 
-        // Find the current project index for the task being edited
-        let current_project_index = if let Some(DialogType::TaskEdit { project_uuid, .. }) = &self.dialog_type {
-            task_projects.iter().position(|p| p.uuid == *project_uuid)
-        } else {
-            None
-        };
+        let values = self.task_edit_form_values();
+        let fields: Vec<(&str, &str)> = TaskEditField::ALL
+            .iter()
+            .map(|field| (field.title(), values[field.index()].as_str()))
+            .collect();
 
         task_dialogs::render_task_edit_dialog(
             f,
             area,
+            &self.theme,
             &self.icons,
-            &self.input_buffer,
+            &fields,
+            self.task_edit_field.index(),
             self.cursor_position,
-            &task_projects,
-            current_project_index,
+            project_name,
+            self.task_section_field(),
         );
     }
 
     fn render_delete_confirmation_dialog(&self, f: &mut Frame, area: Rect, item_type: &str) {
-        system_dialogs::render_delete_confirmation_dialog(f, area, &self.icons, item_type);
+        system_dialogs::render_delete_confirmation_dialog(f, area, &self.theme, &self.icons, item_type);
+    }
+
+    fn render_new_label_confirmation_dialog(&self, f: &mut Frame, area: Rect, labels: &[String]) {
+        system_dialogs::render_new_label_confirmation_dialog(f, area, &self.theme, &self.icons, labels);
     }
 
     fn render_info_dialog(&mut self, f: &mut Frame, area: Rect, message: &str) {
         system_dialogs::render_info_dialog(
             f,
             area,
+            &self.theme,
             &self.icons,
             message,
-            self.scroll_offset,
+            self.display_config.wrap_dialogs,
+            &mut self.scroll_offset,
+            &mut self.scrollbar_state,
+        );
+    }
+
+    fn render_warning_dialog(&mut self, f: &mut Frame, area: Rect, message: &str) {
+        system_dialogs::render_warning_dialog(
+            f,
+            area,
+            &self.theme,
+            &self.icons,
+            message,
+            self.display_config.wrap_dialogs,
+            &mut self.scroll_offset,
             &mut self.scrollbar_state,
         );
     }
@@ -387,21 +929,43 @@ impl DialogComponent {
         system_dialogs::render_error_dialog(
             f,
             area,
+            &self.theme,
             &self.icons,
             message,
-            self.scroll_offset,
+            self.display_config.wrap_dialogs,
+            &mut self.scroll_offset,
             &mut self.scrollbar_state,
         );
     }
 
     fn render_help_dialog(&mut self, f: &mut Frame, area: Rect) {
-        system_dialogs::render_help_dialog(f, area, self.scroll_offset, &mut self.scrollbar_state);
+        system_dialogs::render_help_dialog(
+            f,
+            area,
+            &self.theme,
+            &self.keymap,
+            self.display_config.wrap_dialogs,
+            &mut self.scroll_offset,
+            &mut self.scrollbar_state,
+        );
+    }
+
+    fn render_progress_dialog(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        title: &str,
+        processed: usize,
+        total: usize,
+        cancelling: bool,
+    ) {
+        system_dialogs::render_progress_dialog(f, area, &self.theme, title, processed, total, cancelling);
     }
 
     fn render_task_search_dialog(&self, f: &mut Frame, area: Rect) {
         use ratatui::{
             layout::{Constraint, Layout, Margin},
-            style::{Color, Style},
+            style::Style,
             widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
         };
 
@@ -434,7 +998,7 @@ impl DialogComponent {
         let main_block = Block::default()
             .title(" Search Tasks ")
             .borders(Borders::ALL)
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(self.theme.border));
         f.render_widget(main_block, popup_area);
 
         // Render input field
@@ -442,7 +1006,7 @@ impl DialogComponent {
             Block::default()
                 .borders(Borders::ALL)
                 .title("Query")
-                .style(Style::default().fg(Color::Gray)),
+                .style(Style::default().fg(self.theme.border)),
         );
         f.render_widget(input_paragraph, layout[0]);
 
@@ -478,21 +1042,51 @@ impl DialogComponent {
                 );
 
                 // Use the same render method as main task list
-                TaskListItem::render(&task_item, false, &self.display_config)
+                TaskListItem::render(&task_item, false, &self.display_config, &self.theme)
             })
             .collect();
 
         let results_block = Block::default()
             .borders(Borders::ALL)
             .title(results_text)
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(self.theme.border));
 
         let results_list_widget = List::new(results_list).block(results_block);
         f.render_widget(results_list_widget, layout[1]);
     }
 
+    fn render_duplicate_tasks_dialog(&self, f: &mut Frame, area: Rect) {
+        system_dialogs::render_duplicate_tasks_dialog(
+            f,
+            area,
+            &self.theme,
+            &self.icons,
+            &self.duplicate_groups,
+            &self.projects,
+            self.selected_duplicate_index,
+        );
+    }
+
+    fn render_token_prompt_dialog(&self, f: &mut Frame, area: Rect) {
+        system_dialogs::render_token_prompt_dialog(
+            f,
+            area,
+            &self.theme,
+            &self.icons,
+            &self.input_buffer,
+            self.cursor_position,
+        );
+    }
+
     fn render_logs_dialog(&mut self, f: &mut Frame, area: Rect) {
-        system_dialogs::render_logs_dialog(f, area, self.scroll_offset, &mut self.scrollbar_state);
+        system_dialogs::render_logs_dialog(
+            f,
+            area,
+            &self.theme,
+            self.display_config.wrap_dialogs,
+            &mut self.scroll_offset,
+            &mut self.scrollbar_state,
+        );
     }
 }
 
@@ -503,8 +1097,8 @@ impl Component for DialogComponent {
         }
 
         match &self.dialog_type {
-            Some(DialogType::Info(_)) | Some(DialogType::Error(_)) => {
-                // Info/error dialogs with scrolling support
+            Some(DialogType::Info(_)) | Some(DialogType::Warning(_)) | Some(DialogType::Error(_)) => {
+                // Info/warning/error dialogs with scrolling support
                 match key.code {
                     KeyCode::Up | KeyCode::Char('k') => {
                         self.scroll_up();
@@ -536,7 +1130,8 @@ impl Component for DialogComponent {
             Some(DialogType::Help) => {
                 // Help dialog with scrolling support
                 match key.code {
-                    KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('h') => Action::HideDialog,
+                    KeyCode::Esc => Action::HideDialog,
+                    _ if self.keymap.is(KeyAction::Help, &key) => Action::HideDialog,
                     KeyCode::Up | KeyCode::Char('k') => {
                         self.scroll_up();
                         Action::None
@@ -567,7 +1162,10 @@ impl Component for DialogComponent {
             Some(DialogType::Logs) => {
                 // Logs dialog with scrolling support (same as help dialog)
                 match key.code {
-                    KeyCode::Esc | KeyCode::Char('G') | KeyCode::Char('q') => Action::HideDialog,
+                    KeyCode::Esc => Action::HideDialog,
+                    _ if self.keymap.is(KeyAction::Logs, &key) || self.keymap.is(KeyAction::Quit, &key) => {
+                        Action::HideDialog
+                    }
                     KeyCode::Up | KeyCode::Char('k') => {
                         self.scroll_up();
                         Action::None
@@ -595,9 +1193,155 @@ impl Component for DialogComponent {
                     _ => Action::None,
                 }
             }
-            Some(DialogType::DeleteConfirmation { .. }) => match key.code {
-                KeyCode::Esc => Action::HideDialog,
-                KeyCode::Enter => self.handle_submit(),
+            Some(DialogType::DuplicateTasks) => match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => Action::HideDialog,
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if self.selected_duplicate_index + 1 < self.duplicate_task_count() {
+                        self.selected_duplicate_index += 1;
+                    }
+                    Action::None
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.selected_duplicate_index = self.selected_duplicate_index.saturating_sub(1);
+                    Action::None
+                }
+                KeyCode::Delete | KeyCode::Char('d') => match self.selected_duplicate_task() {
+                    Some(task) => {
+                        let item_uuid = task.uuid;
+                        self.return_to_duplicates = true;
+                        Action::ShowDialog(DialogType::DeleteConfirmation {
+                            item_type: "task".to_string(),
+                            item_uuid,
+                        })
+                    }
+                    None => Action::None,
+                },
+                _ => Action::None,
+            },
+            Some(DialogType::BackendStatus) => match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => Action::HideDialog,
+                KeyCode::Char('r') if self.backend_status.is_some() => {
+                    self.backend_status = None;
+                    Action::CheckBackendStatus
+                }
+                // Offer a new token once the backend has rejected the current one
+                KeyCode::Enter | KeyCode::Char('t')
+                    if self.backend_status.as_ref().is_some_and(|status| !status.authenticated) =>
+                {
+                    Action::ShowDialog(DialogType::TokenPrompt {
+                        retry: Some(Box::new(Action::ShowDialog(DialogType::BackendStatus))),
+                    })
+                }
+                _ => Action::None,
+            },
+            Some(DialogType::MoveTasks { task_uuids }) => match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => Action::HideDialog,
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if self.selected_project_index + 1 < self.projects.len() {
+                        self.selected_project_index += 1;
+                    }
+                    Action::None
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.selected_project_index = self.selected_project_index.saturating_sub(1);
+                    Action::None
+                }
+                KeyCode::Enter => match self.projects.get(self.selected_project_index) {
+                    Some(project) => {
+                        let action = Action::BulkMove {
+                            tasks: task_uuids.clone(),
+                            project_uuid: project.uuid,
+                        };
+                        self.clear_dialog();
+                        action
+                    }
+                    None => Action::None,
+                },
+                _ => Action::None,
+            },
+            Some(DialogType::NestTasks { task_uuids }) => match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => Action::HideDialog,
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if self.selected_parent_task_index + 1 < self.nest_parent_candidates(task_uuids).len() {
+                        self.selected_parent_task_index += 1;
+                    }
+                    Action::None
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.selected_parent_task_index = self.selected_parent_task_index.saturating_sub(1);
+                    Action::None
+                }
+                KeyCode::Enter => match self.nest_parent_candidates(task_uuids).get(self.selected_parent_task_index) {
+                    Some(parent) => {
+                        let action = Action::BulkNest {
+                            tasks: task_uuids.clone(),
+                            parent_uuid: parent.uuid,
+                        };
+                        self.clear_dialog();
+                        action
+                    }
+                    None => Action::None,
+                },
+                _ => Action::None,
+            },
+            Some(DialogType::TaskLabels { task_uuid, .. }) => match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => Action::HideDialog,
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if self.selected_label_index + 1 < self.labels.len() {
+                        self.selected_label_index += 1;
+                    }
+                    Action::None
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.selected_label_index = self.selected_label_index.saturating_sub(1);
+                    Action::None
+                }
+                KeyCode::Char(' ') => {
+                    if let Some(label) = self.labels.get(self.selected_label_index) {
+                        if let Some(position) = self.checked_labels.iter().position(|name| *name == label.name) {
+                            self.checked_labels.remove(position);
+                        } else {
+                            self.checked_labels.push(label.name.clone());
+                        }
+                    }
+                    Action::None
+                }
+                KeyCode::Enter => {
+                    // Labels are sent in the order they are listed, not the order they were ticked
+                    let labels = self
+                        .labels
+                        .iter()
+                        .filter(|label| self.checked_labels.contains(&label.name))
+                        .map(|label| label.name.clone())
+                        .collect();
+                    let action = Action::SetTaskLabels {
+                        task_uuid: *task_uuid,
+                        labels,
+                    };
+                    self.clear_dialog();
+                    action
+                }
+                _ => Action::None,
+            },
+            Some(DialogType::Progress { cancelling, .. }) => match key.code {
+                // Progress can't be dismissed, only cancelled
+                KeyCode::Esc | KeyCode::Char('c') if !cancelling => Action::CancelBatch,
+                _ => Action::None,
+            },
+            Some(DialogType::DeleteConfirmation { .. }) | Some(DialogType::NewLabelConfirmation { .. }) => {
+                match key.code {
+                    KeyCode::Esc if self.return_to_duplicates => {
+                        self.return_to_duplicates(None);
+                        Action::None
+                    }
+                    KeyCode::Esc => Action::HideDialog,
+                    KeyCode::Enter => self.handle_submit(),
+                    _ => Action::None,
+                }
+            }
+            Some(DialogType::Confirmation { .. }) => match key.code {
+                KeyCode::Esc | KeyCode::Char('n') => Action::HideDialog,
+                KeyCode::Enter | KeyCode::Char('y') => self.handle_submit(),
                 _ => Action::None,
             },
             Some(DialogType::TaskSearch) => match key.code {
@@ -668,6 +1412,49 @@ impl Component for DialogComponent {
                 match key.code {
                     KeyCode::Esc => Action::HideDialog,
                     KeyCode::Enter => self.handle_submit(),
+                    KeyCode::Char('f')
+                        if key.modifiers.contains(KeyModifiers::CONTROL)
+                            && matches!(self.dialog_type, Some(DialogType::LabelEdit { .. })) =>
+                    {
+                        self.label_is_favorite = !self.label_is_favorite;
+                        Action::None
+                    }
+                    KeyCode::Char('s')
+                        if key.modifiers.contains(KeyModifiers::CONTROL)
+                            && matches!(
+                                self.dialog_type,
+                                Some(DialogType::TaskCreation { .. }) | Some(DialogType::TaskEdit { .. })
+                            ) =>
+                    {
+                        self.cycle_task_section();
+                        Action::None
+                    }
+                    KeyCode::Tab | KeyCode::BackTab
+                        if matches!(self.dialog_type, Some(DialogType::TaskEdit { .. })) =>
+                    {
+                        let field = if key.code == KeyCode::Tab {
+                            self.task_edit_field.next()
+                        } else {
+                            self.task_edit_field.previous()
+                        };
+                        self.focus_task_edit_field(field);
+                        Action::None
+                    }
+                    KeyCode::BackTab
+                        if matches!(
+                            self.dialog_type,
+                            Some(DialogType::LabelEdit { .. })
+                                | Some(DialogType::ProjectCreation)
+                                | Some(DialogType::ProjectEdit { .. })
+                        ) =>
+                    {
+                        self.color_index = if self.color_index == 0 {
+                            color::COLOR_NAMES.len() - 1
+                        } else {
+                            self.color_index - 1
+                        };
+                        Action::None
+                    }
                     KeyCode::Char(c) => {
                         let byte_pos: usize = self
                             .input_buffer
@@ -734,6 +1521,8 @@ impl Component for DialogComponent {
 
                                 // Mark that user has explicitly selected a project via Tab
                                 self.task_project_explicitly_selected = true;
+                                // Sections belong to the previous project
+                                self.selected_task_section_uuid = None;
 
                                 self.selected_task_project_index = match self.selected_task_project_index {
                                     None => {
@@ -766,6 +1555,11 @@ impl Component for DialogComponent {
                                     }
                                 };
                             }
+                        } else if matches!(
+                            self.dialog_type,
+                            Some(DialogType::LabelEdit { .. }) | Some(DialogType::ProjectEdit { .. })
+                        ) {
+                            self.color_index = (self.color_index + 1) % color::COLOR_NAMES.len();
                         } else if matches!(self.dialog_type, Some(DialogType::ProjectCreation)) {
                             let root_projects = self.get_root_projects();
                             if !root_projects.is_empty() {
@@ -792,27 +1586,94 @@ impl Component for DialogComponent {
 
     fn update(&mut self, action: Action) -> Action {
         match action {
-            Action::ShowDialog(dialog_type) => {
+            Action::ShowDialog(ref dialog_type)
+                if matches!(self.dialog_type, Some(DialogType::Progress { .. }))
+                    && !matches!(dialog_type, DialogType::Progress { .. } | DialogType::Error(_)) =>
+            {
+                // Keep the progress dialog up until the batch completes
+                log::info!("Dialog: Ignoring {:?} while a batch operation is running", dialog_type);
+                Action::None
+            }
+            Action::ShowDialog(DialogType::TokenPrompt { .. })
+                if matches!(self.dialog_type, Some(DialogType::TokenPrompt { .. })) =>
+            {
+                // Don't wipe a token the user is typing; the first failed operation is retried
+                log::info!("Dialog: Token prompt already open");
+                Action::None
+            }
+            Action::ShowDialog(mut dialog_type) => {
                 // Check if this is a task creation dialog before moving the value
                 let is_task_creation = matches!(dialog_type, DialogType::TaskCreation { .. });
 
+                // Without a view-provided default, fall back to the most recently used project
+                if let DialogType::TaskCreation { default_project_uuid } = &mut dialog_type {
+                    if default_project_uuid.is_none() {
+                        *default_project_uuid = self.recent_task_project();
+                    }
+                }
+
                 // Pre-populate input for edit dialogs
                 match &dialog_type {
-                    DialogType::TaskEdit { content, .. } => {
+                    DialogType::TaskEdit {
+                        content,
+                        labels,
+                        snapshot,
+                        ..
+                    } => {
+                        // The content field starts focused, so its text lives in the input buffer
+                        self.task_edit_values = task_edit_initial_values(snapshot, labels);
+                        self.task_edit_values[TaskEditField::Content.index()].clear();
+                        self.task_edit_field = TaskEditField::Content;
                         self.input_buffer = content.clone();
                         self.cursor_position = content.chars().count();
+                        self.selected_task_section_uuid = snapshot.section_uuid;
                     }
-                    DialogType::ProjectEdit { name, .. } => {
+                    DialogType::ProjectCreation => {
+                        self.color_index = color::index_of(color::DEFAULT_COLOR);
+                    }
+                    DialogType::ProjectEdit {
+                        name,
+                        color: project_color,
+                        ..
+                    } => {
                         self.input_buffer = name.clone();
                         self.cursor_position = name.chars().count();
+                        self.color_index = color::index_of(project_color);
+                    }
+                    DialogType::DeadlinePrompt {
+                        deadline: Some(deadline),
+                        ..
+                    } => {
+                        self.input_buffer = deadline.clone();
+                        self.cursor_position = deadline.chars().count();
+                    }
+                    DialogType::SetDueDate { due: Some(due), .. } => {
+                        self.input_buffer = due.clone();
+                        self.cursor_position = due.chars().count();
+                    }
+                    DialogType::ExportView { path } => {
+                        self.input_buffer = path.clone();
+                        self.cursor_position = path.chars().count();
+                    }
+                    DialogType::TaskLabels { labels, .. } => {
+                        self.checked_labels = labels.clone();
+                        self.selected_label_index = 0;
                     }
-                    DialogType::LabelEdit { name, .. } => {
+                    DialogType::LabelEdit {
+                        name,
+                        color: label_color,
+                        is_favorite,
+                        ..
+                    } => {
                         self.input_buffer = name.clone();
                         self.cursor_position = name.chars().count();
+                        self.color_index = color::index_of(label_color);
+                        self.label_is_favorite = *is_favorite;
                     }
                     DialogType::TaskCreation { default_project_uuid } => {
                         self.input_buffer.clear();
                         self.cursor_position = 0;
+                        self.selected_task_section_uuid = None;
                         // Set the selected task project index and UUID if a default project is provided
                         if let Some(project_uuid) = default_project_uuid {
                             let task_projects = self.get_task_projects();
@@ -836,6 +1697,13 @@ impl Component for DialogComponent {
                         self.cursor_position = 0;
                         self.search_results.clear();
                     }
+                    DialogType::DuplicateTasks => {
+                        self.duplicate_groups.clear();
+                        self.selected_duplicate_index = 0;
+                    }
+                    DialogType::BackendStatus => {
+                        self.backend_status = None;
+                    }
                     _ => {
                         self.input_buffer.clear();
                         self.cursor_position = 0;
@@ -852,12 +1720,49 @@ impl Component for DialogComponent {
                     return self.trigger_search();
                 }
 
+                // Scan for duplicates when the dedup dialog opens
+                if matches!(dialog_type, DialogType::DuplicateTasks) {
+                    return Action::FindDuplicateTasks;
+                }
+
+                // Check the connection when the backend status dialog opens
+                if matches!(dialog_type, DialogType::BackendStatus) {
+                    return Action::CheckBackendStatus;
+                }
+
                 Action::None
             }
             Action::HideDialog => {
                 self.clear_dialog();
                 Action::None
             }
+            Action::BatchProgress { processed, total } => {
+                if let Some(DialogType::Progress {
+                    processed: current,
+                    total: current_total,
+                    ..
+                }) = &mut self.dialog_type
+                {
+                    *current = processed;
+                    *current_total = total;
+                }
+                Action::None
+            }
+            Action::CancelBatch => {
+                if let Some(DialogType::Progress { cancelling, .. }) = &mut self.dialog_type {
+                    *cancelling = true;
+                }
+                action
+            }
+            Action::BatchCompleted { ref summary, report } => {
+                // Replace the progress dialog with the batch summary, or just close it when
+                // the summary goes to a toast
+                self.clear_dialog();
+                if report == BatchReport::Dialog {
+                    self.dialog_type = Some(DialogType::Info(summary.clone()));
+                }
+                action
+            }
             _ => action,
         }
     }
@@ -882,12 +1787,53 @@ impl Component for DialogComponent {
                 DialogType::DeleteConfirmation { item_type, .. } => {
                     self.render_delete_confirmation_dialog(f, rect, &item_type);
                 }
+                DialogType::NewLabelConfirmation { labels, .. } => {
+                    self.render_new_label_confirmation_dialog(f, rect, &labels);
+                }
+                DialogType::DeadlinePrompt { content, .. } => {
+                    task_dialogs::render_deadline_dialog(
+                        f,
+                        rect,
+                        &self.theme,
+                        &content,
+                        &self.input_buffer,
+                        self.cursor_position,
+                    );
+                }
+                DialogType::SetDueDate { content, .. } => {
+                    task_dialogs::render_due_date_dialog(
+                        f,
+                        rect,
+                        &self.theme,
+                        &content,
+                        &self.input_buffer,
+                        self.cursor_position,
+                    );
+                }
+                DialogType::ExportView { .. } => {
+                    system_dialogs::render_export_dialog(
+                        f,
+                        rect,
+                        &self.theme,
+                        &self.input_buffer,
+                        self.cursor_position,
+                    );
+                }
+                DialogType::Confirmation { message, .. } => {
+                    system_dialogs::render_confirmation_dialog(f, rect, &self.theme, &message);
+                }
                 DialogType::Info(message) => {
                     self.render_info_dialog(f, rect, &message);
                 }
+                DialogType::Warning(message) => {
+                    self.render_warning_dialog(f, rect, &message);
+                }
                 DialogType::Error(message) => {
                     self.render_error_dialog(f, rect, &message);
                 }
+                DialogType::TokenPrompt { .. } => {
+                    self.render_token_prompt_dialog(f, rect);
+                }
                 DialogType::Help => {
                     self.render_help_dialog(f, rect);
                 }
@@ -897,6 +1843,51 @@ impl Component for DialogComponent {
                 DialogType::TaskSearch => {
                     self.render_task_search_dialog(f, rect);
                 }
+                DialogType::DuplicateTasks => {
+                    self.render_duplicate_tasks_dialog(f, rect);
+                }
+                DialogType::BackendStatus => {
+                    system_dialogs::render_backend_status_dialog(f, rect, &self.theme, self.backend_status.as_ref());
+                }
+                DialogType::TaskLabels { content, .. } => {
+                    task_dialogs::render_task_labels_dialog(
+                        f,
+                        rect,
+                        &self.theme,
+                        &content,
+                        &self.labels,
+                        &self.checked_labels,
+                        self.selected_label_index,
+                    );
+                }
+                DialogType::MoveTasks { task_uuids } => {
+                    task_dialogs::render_move_tasks_dialog(
+                        f,
+                        rect,
+                        &self.theme,
+                        &self.projects,
+                        task_uuids.len(),
+                        self.selected_project_index,
+                    );
+                }
+                DialogType::NestTasks { task_uuids } => {
+                    task_dialogs::render_nest_tasks_dialog(
+                        f,
+                        rect,
+                        &self.theme,
+                        &self.nest_parent_candidates(&task_uuids),
+                        task_uuids.len(),
+                        self.selected_parent_task_index,
+                    );
+                }
+                DialogType::Progress {
+                    title,
+                    processed,
+                    total,
+                    cancelling,
+                } => {
+                    self.render_progress_dialog(f, rect, &title, processed, total, cancelling);
+                }
             }
         }
     }
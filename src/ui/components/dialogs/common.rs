@@ -1,3 +1,5 @@
+use crate::ui::core::theme::Theme;
+use crate::utils::color;
 use ratatui::{
     layout::Alignment,
     style::{Color, Modifier, Style},
@@ -15,44 +17,56 @@ pub fn create_dialog_block<'a>(title: &'a str, theme_color: Color) -> Block<'a>
         .style(Style::default().fg(theme_color))
 }
 
-/// Creates an input field block with a visual cursor
-pub fn create_input_paragraph<'a>(input_buffer: &'a str, _cursor_position: usize, field_title: &str) -> Paragraph<'a> {
-    let input_block = Block::default()
+/// Creates a bordered field block with a title
+pub fn create_field_block(field_title: &str, border_color: Color, theme: &Theme) -> Block<'static> {
+    Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .title(format!(" {} ", field_title))
-        .title_style(Style::default().fg(Color::White))
-        .style(Style::default().fg(Color::Gray));
+        .title_style(Style::default().fg(theme.text))
+        .style(Style::default().fg(border_color))
+}
 
+/// Creates an input field block with a visual cursor
+pub fn create_input_paragraph<'a>(
+    input_buffer: &'a str,
+    _cursor_position: usize,
+    field_title: &str,
+    theme: &Theme,
+) -> Paragraph<'a> {
     Paragraph::new(input_buffer)
-        .block(input_block)
-        .style(Style::default().fg(Color::White))
+        .block(create_field_block(field_title, theme.border, theme))
+        .style(Style::default().fg(theme.text))
 }
 
 /// Creates a selection field block (read-only display with title)
-pub fn create_selection_paragraph(value: String, field_title: &str) -> Paragraph<'static> {
-    let block = Block::default()
-        .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .title(format!(" {} ", field_title))
-        .title_style(Style::default().fg(Color::White))
-        .style(Style::default().fg(Color::Gray));
+pub fn create_selection_paragraph(value: String, field_title: &str, theme: &Theme) -> Paragraph<'static> {
+    Paragraph::new(value)
+        .block(create_field_block(field_title, theme.border, theme))
+        .style(Style::default().fg(theme.text))
+}
 
-    Paragraph::new(value).block(block).style(Style::default().fg(Color::White))
+/// Creates a color field showing a swatch in the named Todoist color next to its name
+pub fn create_color_paragraph(color_name: &str, theme: &Theme) -> Paragraph<'static> {
+    Paragraph::new(Line::from(vec![
+        Span::styled("● ", Style::default().fg(color::to_terminal_color(color_name))),
+        Span::styled(color::display_name(color_name), Style::default().fg(theme.text)),
+    ]))
+    .block(create_field_block("Color", theme.border, theme))
 }
 
 /// Instruction shortcut definition: (key, color, description)
 pub type InstructionShortcut = (&'static str, Color, &'static str);
 
 /// Creates a paragraph with color-coded instruction shortcuts
-pub fn create_instructions_paragraph<'a>(instructions: &[InstructionShortcut]) -> Paragraph<'a> {
+pub fn create_instructions_paragraph<'a>(instructions: &[InstructionShortcut], theme: &Theme) -> Paragraph<'a> {
     let mut instruction_text = Vec::new();
     for (key, color, desc) in instructions {
         instruction_text.push(Span::styled(
             *key,
             Style::default().fg(*color).add_modifier(Modifier::BOLD),
         ));
-        instruction_text.push(Span::styled(*desc, Style::default().fg(Color::Gray)));
+        instruction_text.push(Span::styled(*desc, Style::default().fg(theme.muted)));
     }
 
     Paragraph::new(Line::from(instruction_text)).alignment(Alignment::Center)
@@ -62,7 +76,15 @@ pub fn create_instructions_paragraph<'a>(instructions: &[InstructionShortcut]) -
 pub mod shortcuts {
     use super::*;
 
-    pub const SEPARATOR: InstructionShortcut = (" • ", Color::Gray, "");
-    pub const ESC_CANCEL: InstructionShortcut = ("Esc", Color::Red, " Cancel");
-    pub const TAB_SELECT: InstructionShortcut = ("Tab", Color::Cyan, " Select");
+    pub fn separator(theme: &Theme) -> InstructionShortcut {
+        (" • ", theme.muted, "")
+    }
+
+    pub fn esc_cancel(theme: &Theme) -> InstructionShortcut {
+        ("Esc", theme.error, " Cancel")
+    }
+
+    pub fn tab_select(theme: &Theme) -> InstructionShortcut {
+        ("Tab", theme.accent, " Select")
+    }
 }
@@ -1,26 +1,25 @@
 use super::common::{self, shortcuts};
 use crate::icons::IconService;
+use crate::ui::core::theme::Theme;
 use crate::ui::layout::LayoutManager;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::Color,
     widgets::Clear,
     Frame,
 };
 
-fn render_label_dialog(
+pub fn render_label_creation_dialog(
     f: &mut Frame,
     area: Rect,
+    theme: &Theme,
     _icons: &IconService,
     input_buffer: &str,
     cursor_position: usize,
-    is_editing: bool,
 ) {
     let dialog_area = LayoutManager::centered_rect_lines(65, 9, area);
     f.render_widget(Clear, dialog_area);
 
-    let title = if is_editing { "Edit Label" } else { "New Label" };
-    let main_block = common::create_dialog_block(title, Color::Cyan);
+    let main_block = common::create_dialog_block("New Label", theme.accent);
 
     // Create layout for content
     let inner_area = main_block.inner(dialog_area);
@@ -34,17 +33,14 @@ fn render_label_dialog(
         ])
         .split(inner_area);
 
-    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "Label Name");
-
-    // Instructions based on mode
-    let action = if is_editing {
-        ("Enter", Color::Green, " Save Label")
-    } else {
-        ("Enter", Color::Green, " Create Label")
-    };
+    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "Label Name", theme);
 
-    let instructions = [action, shortcuts::SEPARATOR, shortcuts::ESC_CANCEL];
-    let instructions_paragraph = common::create_instructions_paragraph(&instructions);
+    let instructions = [
+        ("Enter", theme.success, " Create Label"),
+        shortcuts::separator(theme),
+        shortcuts::esc_cancel(theme),
+    ];
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
 
     // Render all components
     f.render_widget(main_block, dialog_area);
@@ -59,22 +55,69 @@ fn render_label_dialog(
     f.set_cursor_position((final_x, final_y));
 }
 
-pub fn render_label_creation_dialog(
-    f: &mut Frame,
-    area: Rect,
-    icons: &IconService,
-    input_buffer: &str,
-    cursor_position: usize,
-) {
-    render_label_dialog(f, area, icons, input_buffer, cursor_position, false);
-}
-
+#[allow(clippy::too_many_arguments)]
 pub fn render_label_edit_dialog(
     f: &mut Frame,
     area: Rect,
+    theme: &Theme,
     icons: &IconService,
     input_buffer: &str,
     cursor_position: usize,
+    color_name: &str,
+    is_favorite: bool,
 ) {
-    render_label_dialog(f, area, icons, input_buffer, cursor_position, true);
+    let dialog_area = LayoutManager::centered_rect_lines(65, 15, area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = common::create_dialog_block("Edit Label", theme.accent);
+
+    // Create layout for content
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3), // Label name input field (borders + content)
+            Constraint::Length(3), // Color selection field (borders + content)
+            Constraint::Length(3), // Favorite field (borders + content)
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "Label Name", theme);
+
+    let color_paragraph = common::create_color_paragraph(color_name, theme);
+
+    let favorite_value = if is_favorite {
+        format!("{} Yes", icons.project_favorite())
+    } else {
+        "No".to_string()
+    };
+    let favorite_paragraph = common::create_selection_paragraph(favorite_value, "Favorite", theme);
+
+    let instructions = [
+        ("Enter", theme.success, " Save Label"),
+        shortcuts::separator(theme),
+        ("Tab/S-Tab", theme.accent, " Color"),
+        shortcuts::separator(theme),
+        ("Ctrl+F", theme.warning, " Favorite"),
+        shortcuts::separator(theme),
+        shortcuts::esc_cancel(theme),
+    ];
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
+
+    // Render all components
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(input_paragraph, chunks[0]);
+    f.render_widget(color_paragraph, chunks[1]);
+    f.render_widget(favorite_paragraph, chunks[2]);
+    f.render_widget(instructions_paragraph, chunks[4]);
+
+    // Set terminal cursor position with safe u16 conversion and overflow protection
+    let base_x = chunks[0].x.saturating_add(1);
+    let cursor_u16 = u16::try_from(cursor_position).unwrap_or(u16::MAX.saturating_sub(base_x));
+    let final_x = base_x.saturating_add(cursor_u16);
+    let final_y = chunks[0].y.saturating_add(1);
+    f.set_cursor_position((final_x, final_y));
 }
@@ -1,26 +1,29 @@
 use super::common::{self, shortcuts};
 use crate::icons::IconService;
+use crate::ui::core::theme::Theme;
 use crate::ui::layout::LayoutManager;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::Color,
     widgets::Clear,
     Frame,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub fn render_project_creation_dialog(
     f: &mut Frame,
     area: Rect,
+    theme: &Theme,
     _icons: &IconService,
     input_buffer: &str,
     cursor_position: usize,
     root_projects: &[&crate::entities::project::Model],
     selected_parent_index: Option<usize>,
+    color_name: &str,
 ) {
-    let dialog_area = LayoutManager::centered_rect_lines(65, 12, area);
+    let dialog_area = LayoutManager::centered_rect_lines(65, 15, area);
     f.render_widget(Clear, dialog_area);
 
-    let main_block = common::create_dialog_block("New Project", Color::Magenta);
+    let main_block = common::create_dialog_block("New Project", theme.secondary);
 
     // Create layout for content
     let inner_area = main_block.inner(dialog_area);
@@ -30,12 +33,13 @@ pub fn render_project_creation_dialog(
         .constraints([
             Constraint::Length(4), // Project name input field (borders + content)
             Constraint::Length(4), // Parent project field (borders + content)
+            Constraint::Length(3), // Color selection field (borders + content)
             Constraint::Length(1), // Spacer
             Constraint::Length(1), // Instructions
         ])
         .split(inner_area);
 
-    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "Project Name");
+    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "Project Name", theme);
 
     // Parent project selection field
     let parent_project_name = match selected_parent_index {
@@ -49,23 +53,26 @@ pub fn render_project_creation_dialog(
         }
     };
 
-    let parent_paragraph = common::create_selection_paragraph(parent_project_name, "Parent Project");
+    let parent_paragraph = common::create_selection_paragraph(parent_project_name, "Parent Project", theme);
 
     let instructions = [
-        ("Enter", Color::Green, " Create Project"),
-        shortcuts::SEPARATOR,
-        shortcuts::TAB_SELECT,
-        (" Parent", Color::Gray, ""),
-        shortcuts::SEPARATOR,
-        shortcuts::ESC_CANCEL,
+        ("Enter", theme.success, " Create Project"),
+        shortcuts::separator(theme),
+        shortcuts::tab_select(theme),
+        (" Parent", theme.muted, ""),
+        shortcuts::separator(theme),
+        ("S-Tab", theme.accent, " Color"),
+        shortcuts::separator(theme),
+        shortcuts::esc_cancel(theme),
     ];
-    let instructions_paragraph = common::create_instructions_paragraph(&instructions);
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
 
     // Render all components
     f.render_widget(main_block, dialog_area);
     f.render_widget(input_paragraph, chunks[0]);
     f.render_widget(parent_paragraph, chunks[1]);
-    f.render_widget(instructions_paragraph, chunks[3]);
+    f.render_widget(common::create_color_paragraph(color_name, theme), chunks[2]);
+    f.render_widget(instructions_paragraph, chunks[4]);
 
     // Set terminal cursor position with safe u16 conversion and overflow protection
     let base_x = chunks[0].x.saturating_add(1);
@@ -78,14 +85,16 @@ pub fn render_project_creation_dialog(
 pub fn render_project_edit_dialog(
     f: &mut Frame,
     area: Rect,
+    theme: &Theme,
     _icons: &IconService,
     input_buffer: &str,
     cursor_position: usize,
+    color_name: &str,
 ) {
-    let dialog_area = LayoutManager::centered_rect_lines(65, 9, area);
+    let dialog_area = LayoutManager::centered_rect_lines(65, 12, area);
     f.render_widget(Clear, dialog_area);
 
-    let main_block = common::create_dialog_block("Edit Project", Color::Yellow);
+    let main_block = common::create_dialog_block("Edit Project", theme.warning);
 
     // Create layout for content
     let inner_area = main_block.inner(dialog_area);
@@ -94,24 +103,28 @@ pub fn render_project_edit_dialog(
         .margin(1)
         .constraints([
             Constraint::Length(4), // Input field (borders + content)
+            Constraint::Length(3), // Color selection field (borders + content)
             Constraint::Length(1), // Spacer
             Constraint::Length(1), // Instructions
         ])
         .split(inner_area);
 
-    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "Project Name");
+    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "Project Name", theme);
 
     let instructions = [
-        ("Enter", Color::Green, " Save Changes"),
-        shortcuts::SEPARATOR,
-        shortcuts::ESC_CANCEL,
+        ("Enter", theme.success, " Save Changes"),
+        shortcuts::separator(theme),
+        ("Tab/S-Tab", theme.accent, " Color"),
+        shortcuts::separator(theme),
+        shortcuts::esc_cancel(theme),
     ];
-    let instructions_paragraph = common::create_instructions_paragraph(&instructions);
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
 
     // Render all components
     f.render_widget(main_block, dialog_area);
     f.render_widget(input_paragraph, chunks[0]);
-    f.render_widget(instructions_paragraph, chunks[2]);
+    f.render_widget(common::create_color_paragraph(color_name, theme), chunks[1]);
+    f.render_widget(instructions_paragraph, chunks[3]);
 
     // Set terminal cursor position with safe u16 conversion and overflow protection
     let base_x = chunks[0].x.saturating_add(1);
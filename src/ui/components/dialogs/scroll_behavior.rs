@@ -1,4 +1,40 @@
-use ratatui::widgets::ScrollbarState;
+use ratatui::widgets::{Paragraph, ScrollbarState, Wrap};
+
+/// Paragraph for scrollable dialog content, wrapped to the available width when `wrap` is set
+pub fn content_paragraph(text: &str, wrap: bool) -> Paragraph<'static> {
+    let paragraph = Paragraph::new(text.to_string());
+    if wrap {
+        paragraph.wrap(Wrap { trim: false })
+    } else {
+        paragraph
+    }
+}
+
+/// Number of rows `text` takes up when rendered `width` columns wide.
+///
+/// With `wrap`, long lines count once per wrapped row, so scroll bounds match what is
+/// on screen rather than the number of logical lines.
+pub fn wrapped_line_count(text: &str, width: u16, wrap: bool) -> usize {
+    content_paragraph(text, wrap).line_count(width)
+}
+
+/// Clamp `scroll_offset` so the last page ends on the last content row, and update the scrollbar.
+///
+/// Keeping the clamped value (instead of e.g. `usize::MAX` after `scroll_to_bottom`) lets the
+/// next `scroll_up` move immediately.
+pub fn clamp_to_content(
+    scroll_offset: &mut usize,
+    scrollbar_state: &mut ScrollbarState,
+    content_rows: usize,
+    viewport_rows: usize,
+) {
+    let max_scroll = content_rows.saturating_sub(viewport_rows);
+    *scroll_offset = (*scroll_offset).min(max_scroll);
+    *scrollbar_state = scrollbar_state
+        .content_length(content_rows)
+        .viewport_content_length(viewport_rows)
+        .position(*scroll_offset);
+}
 
 pub fn scroll_up(scroll_offset: &mut usize, scrollbar_state: &mut ScrollbarState) {
     *scroll_offset = scroll_offset.saturating_sub(1);
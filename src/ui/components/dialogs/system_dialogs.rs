@@ -1,10 +1,22 @@
+use super::{common, scroll_behavior};
+use crate::backend::BackendStatus;
+use crate::constants::{TODOIST_TOKEN_URL, UI_TOKEN_PROMPT_MESSAGE};
+use crate::entities::project;
 use crate::icons::IconService;
 use crate::logger;
+use crate::sync::duplicates::DuplicateGroup;
+use crate::ui::core::keymap::{KeyCategory, KeyMap};
+use crate::ui::core::theme::Theme;
 use crate::ui::layout::LayoutManager;
+use crate::utils::datetime;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, BorderType, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    text::{Line, Span},
+    widgets::{
+        Block, BorderType, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Wrap,
+    },
     Frame,
 };
 
@@ -17,12 +29,15 @@ struct ScrollableDialogConfig {
 }
 
 /// Helper function to render a scrollable message dialog with consistent styling
+#[allow(clippy::too_many_arguments)]
 fn render_scrollable_message_dialog(
     f: &mut Frame,
     area: Rect,
+    theme: &Theme,
     config: ScrollableDialogConfig,
     message: &str,
-    scroll_offset: usize,
+    wrap: bool,
+    scroll_offset: &mut usize,
     scrollbar_state: &mut ScrollbarState,
 ) {
     let dialog_area = LayoutManager::centered_rect_lines(config.width_percent, config.height_lines, area);
@@ -42,6 +57,14 @@ fn render_scrollable_message_dialog(
         dialog_area.height.saturating_sub(4),
     );
 
+    // Leave the rightmost column to the scrollbar so it never covers text
+    let text_area = Rect::new(
+        content_area.x,
+        content_area.y,
+        content_area.width.saturating_sub(1),
+        content_area.height,
+    );
+
     let instructions_area = Rect::new(
         dialog_area.x + 1,
         dialog_area.y + dialog_area.height.saturating_sub(2),
@@ -49,52 +72,95 @@ fn render_scrollable_message_dialog(
         1,
     );
 
-    let lines: Vec<&str> = message.lines().collect();
-    let total_lines = lines.len();
-    let visible_height = content_area.height as usize;
-
-    let message_text = if total_lines > visible_height {
-        let max_scroll = total_lines.saturating_sub(visible_height);
-        let clamped_offset = scroll_offset.min(max_scroll);
+    let total_rows = scroll_behavior::wrapped_line_count(message, text_area.width, wrap);
+    let visible_height = text_area.height as usize;
+    scroll_behavior::clamp_to_content(scroll_offset, scrollbar_state, total_rows, visible_height);
 
-        *scrollbar_state = scrollbar_state
-            .content_length(total_lines)
-            .viewport_content_length(visible_height)
-            .position(clamped_offset);
-
-        let visible_lines: Vec<&str> = lines.iter().skip(clamped_offset).take(visible_height).copied().collect();
-        visible_lines.join("\n")
-    } else {
-        message.to_string()
-    };
-
-    let message_paragraph = Paragraph::new(message_text)
-        .style(Style::default().fg(Color::White))
+    let message_paragraph = scroll_behavior::content_paragraph(message, wrap)
+        .style(Style::default().fg(theme.text))
         .alignment(Alignment::Left)
-        .wrap(ratatui::widgets::Wrap { trim: true });
+        .scroll((u16::try_from(*scroll_offset).unwrap_or(u16::MAX), 0));
 
     let instructions_paragraph = Paragraph::new(instructions)
-        .style(Style::default().fg(Color::Gray))
+        .style(Style::default().fg(theme.muted))
         .alignment(Alignment::Center);
 
     f.render_widget(block, dialog_area);
-    f.render_widget(message_paragraph, content_area);
+    f.render_widget(message_paragraph, text_area);
     f.render_widget(instructions_paragraph, instructions_area);
 
-    if total_lines > visible_height {
-        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-            .begin_symbol(Some("↑"))
-            .end_symbol(Some("↓"))
-            .track_symbol(Some("│"))
-            .thumb_symbol("▐")
-            .style(Style::default().fg(Color::Gray))
-            .thumb_style(Style::default().fg(Color::White));
+    if total_rows > visible_height {
+        render_scrollbar(f, content_area, theme, scrollbar_state);
+    }
+}
+
+/// Render the vertical scrollbar shared by the scrollable text dialogs
+fn render_scrollbar(f: &mut Frame, area: Rect, theme: &Theme, scrollbar_state: &mut ScrollbarState) {
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(Some("↑"))
+        .end_symbol(Some("↓"))
+        .track_symbol(Some("│"))
+        .thumb_symbol("▐")
+        .style(Style::default().fg(theme.border))
+        .thumb_style(Style::default().fg(theme.text));
+
+    f.render_stateful_widget(scrollbar, area, scrollbar_state);
+}
+
+/// Render a large bordered dialog with scrollable text (help, logs)
+#[allow(clippy::too_many_arguments)]
+fn render_scrollable_text_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    title: &str,
+    text: &str,
+    wrap: bool,
+    scroll_offset: &mut usize,
+    scrollbar_state: &mut ScrollbarState,
+) {
+    let dialog_area = LayoutManager::centered_rect(90, 90, area);
+    f.render_widget(Clear, dialog_area);
+
+    let margin_x = 2;
+    let margin_y = 1;
+    let content_area = Rect::new(
+        dialog_area.x + margin_x,
+        dialog_area.y + margin_y,
+        dialog_area.width.saturating_sub(margin_x * 2),
+        dialog_area.height.saturating_sub(margin_y * 2),
+    );
+
+    // Rows are counted for the area inside the borders, where the text is laid out
+    let total_rows = scroll_behavior::wrapped_line_count(text, content_area.width.saturating_sub(2), wrap);
+    let visible_height = content_area.height.saturating_sub(2) as usize;
+    scroll_behavior::clamp_to_content(scroll_offset, scrollbar_state, total_rows, visible_height);
+
+    let paragraph = scroll_behavior::content_paragraph(text, wrap)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title.to_string())
+                .title_alignment(Alignment::Center),
+        )
+        .style(Style::default().fg(theme.text))
+        .alignment(Alignment::Left)
+        .scroll((u16::try_from(*scroll_offset).unwrap_or(u16::MAX), 0));
 
-        f.render_stateful_widget(scrollbar, content_area, scrollbar_state);
+    f.render_widget(paragraph, content_area);
+
+    if total_rows > visible_height {
+        render_scrollbar(f, content_area, theme, scrollbar_state);
     }
 }
 
-pub fn render_delete_confirmation_dialog(f: &mut Frame, area: Rect, icons: &IconService, item_type: &str) {
+pub fn render_delete_confirmation_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    icons: &IconService,
+    item_type: &str,
+) {
     let dialog_area = LayoutManager::centered_rect_lines(60, 8, area);
     f.render_widget(Clear, dialog_area);
 
@@ -104,8 +170,8 @@ pub fn render_delete_confirmation_dialog(f: &mut Frame, area: Rect, icons: &Icon
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .title(title)
-        .title_style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
-        .style(Style::default().fg(Color::Red));
+        .title_style(Style::default().fg(theme.error).add_modifier(Modifier::BOLD))
+        .style(Style::default().fg(theme.error));
 
     // Create layout for content
     let inner_area = main_block.inner(dialog_area);
@@ -122,27 +188,17 @@ pub fn render_delete_confirmation_dialog(f: &mut Frame, area: Rect, icons: &Icon
     // Confirmation message
     let message = format!("Are you sure you want to delete this {}?", item_type);
     let message_paragraph = Paragraph::new(message)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme.text))
         .alignment(Alignment::Center);
 
     // Enhanced instructions with color-coded shortcuts
-    let instructions = vec![
-        ("Enter", Color::Red, " Delete"),
-        (" • ", Color::Gray, ""),
-        ("Esc", Color::Green, " Cancel"),
+    let instructions = [
+        ("Enter", theme.error, " Delete"),
+        common::shortcuts::separator(theme),
+        ("Esc", theme.success, " Cancel"),
     ];
 
-    let mut instruction_text = Vec::new();
-    for (key, color, desc) in instructions {
-        instruction_text.push(ratatui::text::Span::styled(
-            key,
-            Style::default().fg(color).add_modifier(Modifier::BOLD),
-        ));
-        instruction_text.push(ratatui::text::Span::styled(desc, Style::default().fg(Color::Gray)));
-    }
-
-    let instructions_paragraph =
-        Paragraph::new(ratatui::text::Line::from(instruction_text)).alignment(Alignment::Center);
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
 
     // Render all components
     f.render_widget(main_block, dialog_area);
@@ -150,93 +206,537 @@ pub fn render_delete_confirmation_dialog(f: &mut Frame, area: Rect, icons: &Icon
     f.render_widget(instructions_paragraph, chunks[2]);
 }
 
+/// Render a generic yes/no confirmation for an action that is only run once confirmed.
+pub fn render_confirmation_dialog(f: &mut Frame, area: Rect, theme: &Theme, message: &str) {
+    let dialog_area = LayoutManager::centered_rect_lines(50, 7, area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title("Confirm")
+        .title_style(Style::default().fg(theme.warning).add_modifier(Modifier::BOLD))
+        .style(Style::default().fg(theme.warning));
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(1), // Confirmation message
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let message_paragraph = Paragraph::new(message.to_string())
+        .style(Style::default().fg(theme.text))
+        .alignment(Alignment::Center);
+
+    let instructions = Line::from(vec![
+        Span::styled(
+            "Enter/y",
+            Style::default().fg(theme.success).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" Confirm", Style::default().fg(theme.muted)),
+        Span::styled(" • ", Style::default().fg(theme.muted)),
+        Span::styled("Esc/n", Style::default().fg(theme.error).add_modifier(Modifier::BOLD)),
+        Span::styled(" Cancel", Style::default().fg(theme.muted)),
+    ]);
+    let instructions_paragraph = Paragraph::new(instructions).alignment(Alignment::Center);
+
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(message_paragraph, chunks[0]);
+    f.render_widget(instructions_paragraph, chunks[2]);
+}
+
+/// Render the confirmation shown before inline `@label` syntax creates new labels.
+pub fn render_new_label_confirmation_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    icons: &IconService,
+    labels: &[String],
+) {
+    let dialog_area = LayoutManager::centered_rect_lines(60, 8, area);
+    f.render_widget(Clear, dialog_area);
+
+    let title = format!("{} Create Labels", icons.label());
+    let main_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .title_style(Style::default().fg(theme.warning).add_modifier(Modifier::BOLD))
+        .style(Style::default().fg(theme.warning));
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(2), // Confirmation message
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let names: Vec<String> = labels.iter().map(|name| format!("@{}", name)).collect();
+    let message = format!("Create new labels {}?", names.join(", "));
+    let message_paragraph = Paragraph::new(message)
+        .style(Style::default().fg(theme.text))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    let instructions = [
+        ("Enter", theme.success, " Create"),
+        common::shortcuts::separator(theme),
+        ("Esc", theme.error, " Cancel"),
+    ];
+
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
+
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(message_paragraph, chunks[0]);
+    f.render_widget(instructions_paragraph, chunks[2]);
+}
+
+/// Render the prompt asking for a new API token after an authentication failure.
+///
+/// The token is masked while typing.
+pub fn render_token_prompt_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    icons: &IconService,
+    input_buffer: &str,
+    cursor_position: usize,
+) {
+    let dialog_area = LayoutManager::centered_rect_lines(70, 12, area);
+    f.render_widget(Clear, dialog_area);
+
+    let title = format!("{} Authentication Required", icons.warning());
+    let main_block = common::create_dialog_block(&title, theme.warning);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(2), // Explanation
+            Constraint::Length(3), // Input field
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let explanation = Paragraph::new(format!(
+        "{}\nPaste a new token from {}",
+        UI_TOKEN_PROMPT_MESSAGE, TODOIST_TOKEN_URL
+    ))
+    .style(Style::default().fg(theme.text))
+    .wrap(Wrap { trim: true });
+
+    let masked_token = "•".repeat(input_buffer.chars().count());
+    let input_paragraph = common::create_input_paragraph(&masked_token, cursor_position, "API Token", theme);
+
+    let instructions = [
+        ("Enter", theme.success, " Save & Retry"),
+        common::shortcuts::separator(theme),
+        common::shortcuts::esc_cancel(theme),
+    ];
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
+
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(explanation, chunks[0]);
+    f.render_widget(input_paragraph, chunks[1]);
+    f.render_widget(instructions_paragraph, chunks[3]);
+
+    let base_x = chunks[1].x.saturating_add(1);
+    let cursor_u16 = u16::try_from(cursor_position).unwrap_or(u16::MAX.saturating_sub(base_x));
+    f.set_cursor_position((base_x.saturating_add(cursor_u16), chunks[1].y.saturating_add(1)));
+}
+
+/// Render the prompt for the file a view is exported to
+pub fn render_export_dialog(f: &mut Frame, area: Rect, theme: &Theme, input_buffer: &str, cursor_position: usize) {
+    let dialog_area = LayoutManager::centered_rect_lines(70, 11, area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = common::create_dialog_block(" Export View ", theme.accent);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(2), // Hint
+            Constraint::Length(3), // Input field
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let hint = Paragraph::new(
+        "Tasks as listed, with their project, due date, priority and labels
+A .csv file is written as CSV, anything else as a Markdown checklist",
+    )
+    .style(Style::default().fg(theme.text))
+    .wrap(Wrap { trim: true });
+
+    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "File", theme);
+
+    let instructions = [
+        ("Enter", theme.success, " Export"),
+        common::shortcuts::separator(theme),
+        common::shortcuts::esc_cancel(theme),
+    ];
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
+
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(hint, chunks[0]);
+    f.render_widget(input_paragraph, chunks[1]);
+    f.render_widget(instructions_paragraph, chunks[2]);
+
+    let base_x = chunks[1].x.saturating_add(1);
+    let cursor_u16 = u16::try_from(cursor_position).unwrap_or(u16::MAX.saturating_sub(base_x));
+    f.set_cursor_position((base_x.saturating_add(cursor_u16), chunks[1].y.saturating_add(1)));
+}
+
+/// Render the connection check of the active backend; `status` is `None` while it runs.
+///
+/// A new token can be entered once the backend has rejected the current one.
+pub fn render_backend_status_dialog(f: &mut Frame, area: Rect, theme: &Theme, status: Option<&BackendStatus>) {
+    let dialog_area = LayoutManager::centered_rect_lines(70, 12, area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = common::create_dialog_block(" Backend Status ", theme.accent);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(1),    // Status fields
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let field = |name: &str, value: String, color: Color| {
+        Line::from(vec![
+            Span::styled(format!("{:<16}", name), Style::default().fg(theme.muted)),
+            Span::styled(value, Style::default().fg(color)),
+        ])
+    };
+    let check = |ok: bool, yes: &str, no: &str| {
+        if ok {
+            (format!("✓ {}", yes), theme.success)
+        } else {
+            (format!("✗ {}", no), theme.error)
+        }
+    };
+
+    let lines = match status {
+        None => vec![Line::from(Span::styled(
+            "Checking connection...",
+            Style::default().fg(theme.muted),
+        ))],
+        Some(status) => {
+            let (connection, connection_color) = check(status.connected, "Connected", "Unreachable");
+            let (auth, auth_color) = if status.connected {
+                check(status.authenticated, "Credentials accepted", "Credentials rejected")
+            } else {
+                ("Unknown".to_string(), theme.muted)
+            };
+            let mut lines = vec![
+                field(
+                    "Backend",
+                    format!("{} ({})", status.name, status.backend_type),
+                    theme.text,
+                ),
+                field("Connection", connection, connection_color),
+                field("Authentication", auth, auth_color),
+                field(
+                    "Last sync",
+                    status.last_sync.map_or_else(|| "Never".to_string(), datetime::format_ago),
+                    theme.text,
+                ),
+            ];
+            if let Some(error) = &status.sync_error {
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(
+                    error.clone(),
+                    Style::default().fg(theme.error),
+                )));
+            }
+            lines
+        }
+    };
+    let fields = Paragraph::new(lines).wrap(Wrap { trim: true });
+
+    let mut instructions = Vec::new();
+    if status.is_some_and(|status| !status.authenticated) {
+        instructions.push(("Enter", theme.success, " New token"));
+        instructions.push(common::shortcuts::separator(theme));
+    }
+    if status.is_some() {
+        instructions.push(("r", theme.accent, " Check again"));
+        instructions.push(common::shortcuts::separator(theme));
+    }
+    instructions.push(("Esc", theme.success, " Close"));
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
+
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(fields, chunks[0]);
+    f.render_widget(instructions_paragraph, chunks[1]);
+}
+
+/// Render the progress dialog for a running batch operation
+pub fn render_progress_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    title: &str,
+    processed: usize,
+    total: usize,
+    cancelling: bool,
+) {
+    let dialog_area = LayoutManager::centered_rect_lines(60, 9, area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", title))
+        .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
+        .style(Style::default().fg(theme.accent));
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(1), // Status message
+            Constraint::Length(1), // Progress bar
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let status = if cancelling {
+        format!("Cancelling after current item... ({}/{})", processed, total)
+    } else {
+        format!("Processed {}/{}", processed, total)
+    };
+    let status_paragraph = Paragraph::new(status)
+        .style(Style::default().fg(theme.text))
+        .alignment(Alignment::Center);
+
+    let ratio = if total == 0 {
+        0.0
+    } else {
+        (processed as f64 / total as f64).clamp(0.0, 1.0)
+    };
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(theme.accent).bg(theme.selected_bg))
+        .ratio(ratio);
+
+    let instructions = if cancelling {
+        Paragraph::new("Waiting for the current item to finish")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center)
+    } else {
+        let instruction_text = vec![
+            Span::styled("Esc", Style::default().fg(theme.error).add_modifier(Modifier::BOLD)),
+            Span::styled(" Cancel remaining", Style::default().fg(theme.muted)),
+        ];
+        Paragraph::new(Line::from(instruction_text)).alignment(Alignment::Center)
+    };
+
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(status_paragraph, chunks[0]);
+    f.render_widget(gauge, chunks[1]);
+    f.render_widget(instructions, chunks[3]);
+}
+
+/// Render the duplicate tasks dialog.
+///
+/// Each group shows a header with the project name, followed by its tasks. The first
+/// task of a group is marked as the one to keep; `selected_index` indexes into the
+/// flattened list of tasks across all groups.
+pub fn render_duplicate_tasks_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    icons: &IconService,
+    groups: &[DuplicateGroup],
+    projects: &[project::Model],
+    selected_index: usize,
+) {
+    let dialog_area = LayoutManager::centered_rect(80, 70, area);
+    f.render_widget(Clear, dialog_area);
+
+    let extra_count: usize = groups.iter().map(|group| group.tasks.len() - 1).sum();
+    let title = format!(" {} Duplicate Tasks ({} extra) ", icons.warning(), extra_count);
+    let main_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .title_style(Style::default().fg(theme.warning).add_modifier(Modifier::BOLD))
+        .style(Style::default().fg(theme.warning));
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(1),    // Duplicate list
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    f.render_widget(main_block, dialog_area);
+
+    if groups.is_empty() {
+        let message = Paragraph::new("No duplicate tasks found.")
+            .style(Style::default().fg(theme.text))
+            .alignment(Alignment::Center);
+        f.render_widget(message, chunks[0]);
+    } else {
+        let mut items = Vec::new();
+        let mut selected_row = None;
+        let mut task_index = 0;
+
+        for group in groups {
+            let project_name = projects
+                .iter()
+                .find(|p| p.uuid == group.project_uuid)
+                .map(|p| p.name.as_str())
+                .unwrap_or("Unknown project");
+            items.push(ListItem::new(Line::from(vec![
+                Span::styled(
+                    project_name.to_string(),
+                    Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(" • {} copies", group.tasks.len()),
+                    Style::default().fg(theme.muted),
+                ),
+            ])));
+
+            for (position, task) in group.tasks.iter().enumerate() {
+                if task_index == selected_index {
+                    selected_row = Some(items.len());
+                }
+                let marker = if position == 0 {
+                    Span::styled("  keep   ", Style::default().fg(theme.success))
+                } else {
+                    Span::styled("  extra  ", Style::default().fg(theme.error))
+                };
+                let due = task.due_date.as_deref().map(|d| format!(" ({})", d)).unwrap_or_default();
+                items.push(ListItem::new(Line::from(vec![
+                    marker,
+                    Span::styled(task.content.clone(), Style::default().fg(theme.text)),
+                    Span::styled(due, Style::default().fg(theme.muted)),
+                ])));
+                task_index += 1;
+            }
+        }
+
+        let list =
+            List::new(items).highlight_style(Style::default().bg(theme.selected_bg).add_modifier(Modifier::BOLD));
+        let mut list_state = ListState::default();
+        list_state.select(selected_row);
+        f.render_stateful_widget(list, chunks[0], &mut list_state);
+    }
+
+    let instructions = [
+        ("j/k", theme.accent, " Navigate"),
+        common::shortcuts::separator(theme),
+        ("d", theme.error, " Delete task"),
+        common::shortcuts::separator(theme),
+        ("Esc", theme.success, " Close"),
+    ];
+    f.render_widget(common::create_instructions_paragraph(&instructions, theme), chunks[1]);
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn render_info_dialog(
     f: &mut Frame,
     area: Rect,
+    theme: &Theme,
     icons: &IconService,
     message: &str,
-    scroll_offset: usize,
+    wrap: bool,
+    scroll_offset: &mut usize,
     scrollbar_state: &mut ScrollbarState,
 ) {
     let config = ScrollableDialogConfig {
         title: format!("{} Info", icons.info()),
-        color: Color::Blue,
+        color: theme.info,
         width_percent: 60,
         height_lines: 10,
     };
-    render_scrollable_message_dialog(f, area, config, message, scroll_offset, scrollbar_state);
+    render_scrollable_message_dialog(f, area, theme, config, message, wrap, scroll_offset, scrollbar_state);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn render_warning_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    icons: &IconService,
+    message: &str,
+    wrap: bool,
+    scroll_offset: &mut usize,
+    scrollbar_state: &mut ScrollbarState,
+) {
+    let config = ScrollableDialogConfig {
+        title: format!("{} Warning", icons.warning()),
+        color: theme.warning,
+        width_percent: 70,
+        height_lines: 12,
+    };
+    render_scrollable_message_dialog(f, area, theme, config, message, wrap, scroll_offset, scrollbar_state);
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn render_error_dialog(
     f: &mut Frame,
     area: Rect,
+    theme: &Theme,
     icons: &IconService,
     message: &str,
-    scroll_offset: usize,
+    wrap: bool,
+    scroll_offset: &mut usize,
     scrollbar_state: &mut ScrollbarState,
 ) {
     let config = ScrollableDialogConfig {
         title: format!("{} Error", icons.warning()),
-        color: Color::Red,
+        color: theme.error,
         width_percent: 70,
         height_lines: 12,
     };
-    render_scrollable_message_dialog(f, area, config, message, scroll_offset, scrollbar_state);
+    render_scrollable_message_dialog(f, area, theme, config, message, wrap, scroll_offset, scrollbar_state);
 }
 
-pub fn render_help_dialog(f: &mut Frame, area: Rect, scroll_offset: usize, scrollbar_state: &mut ScrollbarState) {
-    let help_content = r"
-TERMINALIST - Todoist Terminal Client
-====================================
-
-NAVIGATION
-----------
-j/k         Navigate tasks (down/up)
-J/K         Navigate projects (down/up)
-Mouse       Click sidebar items to navigate
-Enter       Select project/task or confirm action
-Esc         Cancel action or close dialogs
-
-PROJECT & LABEL MANAGEMENT
---------------------------
-A           Create new project
-E           Edit selected item (project or label)
-D           Delete selected item (project or label)
-
-TASK MANAGEMENT
---------------
-Space       Toggle task completion
-a           Create new task
-e           Edit selected task
-d           Delete task (with confirmation)
-p           Cycle task priority
-t           Set task due date to today
-T           Set task due date to tomorrow
-w           Set task due date to next week (Monday)
-W           Set task due date to next week end (Saturday)
-
-SYNC & DATA
------------
-r           Force sync with Todoist
-Ctrl+C      Quit application
-
-GENERAL CONTROLS
-----------------
-?           Toggle help panel
-h           Toggle help panel
-b           Toggle sidebar visibility
-q           Quit application
-i           Change icon theme
-
-HELP PANEL SCROLLING
---------------------
-j/k         Scroll help content down/up
-↑↓          Scroll help content up/down
-PageUp/Down Page through help content
-Home        Jump to top of help
-End         Jump to bottom of help
-
-TASK STATUS INDICATORS
+/// Help dialog content: the key bindings of `keymap` by category, then general notes
+pub fn help_text(keymap: &KeyMap) -> String {
+    let bindings = keymap.describe();
+    let mut text = String::from("\nTERMINALIST - Todoist Terminal Client\n====================================\n");
+
+    for category in KeyCategory::ALL {
+        let title = category.title();
+        text.push_str(&format!("\n{}\n{}\n", title, "-".repeat(title.chars().count())));
+        for binding in bindings.iter().filter(|b| b.category == category) {
+            text.push_str(&format!("{:<12}{}\n", binding.keys, binding.description));
+        }
+    }
+
+    text.push('\n');
+    text.push_str(HELP_NOTES);
+    text
+}
+
+/// Help dialog text that doesn't depend on key bindings
+const HELP_NOTES: &str = "TASK STATUS INDICATORS
 ----------------------
 🔳          Pending task
 ✅          Completed task
@@ -252,75 +752,40 @@ NOTES
 -----
 Tasks are ordered: pending, then completed, then deleted
 
-Press 'Esc', '?' or 'h' to close this help panel
+Press 'Esc' or the help panel key to close this help panel
 ";
 
-    let help_area = LayoutManager::centered_rect(90, 90, area);
-    f.render_widget(Clear, help_area);
-
-    let margin_x = 2;
-    let margin_y = 1;
-    let help_content_area = Rect::new(
-        help_area.x + margin_x,
-        help_area.y + margin_y,
-        help_area.width.saturating_sub(margin_x * 2),
-        help_area.height.saturating_sub(margin_y * 2),
+pub fn render_help_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    keymap: &KeyMap,
+    wrap: bool,
+    scroll_offset: &mut usize,
+    scrollbar_state: &mut ScrollbarState,
+) {
+    let help_content = help_text(keymap);
+
+    render_scrollable_text_dialog(
+        f,
+        area,
+        theme,
+        "📖 Help - Press 'Esc', '?' or 'h' to close",
+        &help_content,
+        wrap,
+        scroll_offset,
+        scrollbar_state,
     );
-
-    let lines: Vec<&str> = help_content.lines().collect();
-    let total_lines = lines.len();
-    let visible_height = help_content_area.height.saturating_sub(2) as usize;
-
-    let max_scroll = total_lines.saturating_sub(visible_height);
-    let clamped_offset = scroll_offset.min(max_scroll);
-
-    *scrollbar_state = scrollbar_state
-        .content_length(total_lines)
-        .viewport_content_length(visible_height)
-        .position(clamped_offset);
-
-    let visible_lines: Vec<&str> = lines.iter().skip(clamped_offset).take(visible_height).copied().collect();
-
-    let help_text = visible_lines.join("\n");
-
-    let help_paragraph = Paragraph::new(help_text)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("📖 Help - Press 'Esc', '?' or 'h' to close")
-                .title_alignment(Alignment::Center),
-        )
-        .style(Style::default().fg(Color::White))
-        .alignment(Alignment::Left);
-
-    f.render_widget(help_paragraph, help_content_area);
-
-    if total_lines > visible_height {
-        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-            .begin_symbol(Some("↑"))
-            .end_symbol(Some("↓"))
-            .track_symbol(Some("│"))
-            .thumb_symbol("▐")
-            .style(Style::default().fg(Color::Gray))
-            .thumb_style(Style::default().fg(Color::White));
-
-        f.render_stateful_widget(scrollbar, help_content_area, scrollbar_state);
-    }
 }
 
-pub fn render_logs_dialog(f: &mut Frame, area: Rect, scroll_offset: usize, scrollbar_state: &mut ScrollbarState) {
-    let logs_area = LayoutManager::centered_rect(90, 90, area);
-    f.render_widget(Clear, logs_area);
-
-    let margin_x = 2;
-    let margin_y = 1;
-    let logs_content_area = Rect::new(
-        logs_area.x + margin_x,
-        logs_area.y + margin_y,
-        logs_area.width.saturating_sub(margin_x * 2),
-        logs_area.height.saturating_sub(margin_y * 2),
-    );
-
+pub fn render_logs_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    wrap: bool,
+    scroll_offset: &mut usize,
+    scrollbar_state: &mut ScrollbarState,
+) {
     let logs = logger::get_memory_logs();
 
     let logs_content = if logs.is_empty() {
@@ -329,43 +794,14 @@ pub fn render_logs_dialog(f: &mut Frame, area: Rect, scroll_offset: usize, scrol
         logs.join("\n")
     };
 
-    let lines: Vec<&str> = logs_content.lines().collect();
-    let total_lines = lines.len();
-    let visible_height = logs_content_area.height.saturating_sub(2) as usize;
-
-    let max_scroll = total_lines.saturating_sub(visible_height);
-    let clamped_offset = scroll_offset.min(max_scroll);
-
-    *scrollbar_state = scrollbar_state
-        .content_length(total_lines)
-        .viewport_content_length(visible_height)
-        .position(clamped_offset);
-
-    let visible_lines: Vec<&str> = lines.iter().skip(clamped_offset).take(visible_height).copied().collect();
-
-    let logs_text = visible_lines.join("\n");
-
-    let logs_paragraph = Paragraph::new(logs_text)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("🔍 Debug Logs - Press 'Esc', 'G' or 'q' to close")
-                .title_alignment(Alignment::Center),
-        )
-        .style(Style::default().fg(Color::White))
-        .alignment(Alignment::Left);
-
-    f.render_widget(logs_paragraph, logs_content_area);
-
-    if total_lines > visible_height {
-        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-            .begin_symbol(Some("↑"))
-            .end_symbol(Some("↓"))
-            .track_symbol(Some("│"))
-            .thumb_symbol("▐")
-            .style(Style::default().fg(Color::Gray))
-            .thumb_style(Style::default().fg(Color::White));
-
-        f.render_stateful_widget(scrollbar, logs_content_area, scrollbar_state);
-    }
+    render_scrollable_text_dialog(
+        f,
+        area,
+        theme,
+        "🔍 Debug Logs - Press 'Esc', 'G' or 'q' to close",
+        &logs_content,
+        wrap,
+        scroll_offset,
+        scrollbar_state,
+    );
 }
@@ -1,11 +1,14 @@
 use super::common::{self, shortcuts};
-use crate::entities::project;
+use crate::entities::{label, project, task};
 use crate::icons::IconService;
+use crate::ui::core::theme::Theme;
 use crate::ui::layout::LayoutManager;
+use crate::utils::color;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::Color,
-    widgets::Clear,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
@@ -13,18 +16,22 @@ use ratatui::{
 pub fn render_task_dialog(
     f: &mut Frame,
     area: Rect,
+    theme: &Theme,
     _icons: &IconService,
     input_buffer: &str,
     cursor_position: usize,
     task_projects: &[&project::Model],
     selected_project_index: Option<usize>,
+    section_name: Option<String>,
     is_editing: bool,
 ) {
     let title = if is_editing { "Edit Task" } else { "New Task" };
-    let dialog_area = LayoutManager::centered_rect_lines(65, 12, area);
+    // The section field only shows when the project has sections
+    let section_height = if section_name.is_some() { 4 } else { 0 };
+    let dialog_area = LayoutManager::centered_rect_lines(65, 12 + section_height, area);
     f.render_widget(Clear, dialog_area);
 
-    let main_block = common::create_dialog_block(title, Color::Cyan);
+    let main_block = common::create_dialog_block(title, theme.accent);
 
     // Create layout for content
     let inner_area = main_block.inner(dialog_area);
@@ -32,14 +39,15 @@ pub fn render_task_dialog(
         .direction(Direction::Vertical)
         .margin(1)
         .constraints([
-            Constraint::Length(4), // Task content input field (borders + content)
-            Constraint::Length(4), // Project selection field (borders + content)
-            Constraint::Length(1), // Spacer
-            Constraint::Length(1), // Instructions
+            Constraint::Length(4),              // Task content input field (borders + content)
+            Constraint::Length(4),              // Project selection field (borders + content)
+            Constraint::Length(section_height), // Section selection field, if any
+            Constraint::Length(1),              // Spacer
+            Constraint::Length(1),              // Instructions
         ])
         .split(inner_area);
 
-    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "Task Content");
+    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "Task Content", theme);
 
     // Project selection field
     let project_name = match selected_project_index {
@@ -53,74 +61,428 @@ pub fn render_task_dialog(
         }
     };
 
-    let project_paragraph = common::create_selection_paragraph(project_name, "Project");
+    let project_paragraph = common::create_selection_paragraph(project_name, "Project", theme);
 
     // Instructions based on mode
     let action = if is_editing {
-        ("Enter", Color::Green, " Save Task")
+        ("Enter", theme.success, " Save Task")
     } else {
-        ("Enter", Color::Green, " Create Task")
+        ("Enter", theme.success, " Create Task")
     };
 
-    let instructions = [
+    let mut instructions = vec![
         action,
-        shortcuts::SEPARATOR,
-        shortcuts::TAB_SELECT,
-        (" Project", Color::Gray, ""),
-        shortcuts::SEPARATOR,
-        shortcuts::ESC_CANCEL,
+        shortcuts::separator(theme),
+        shortcuts::tab_select(theme),
+        (" Project", theme.muted, ""),
+        shortcuts::separator(theme),
     ];
-    let instructions_paragraph = common::create_instructions_paragraph(&instructions);
+    if section_name.is_some() {
+        instructions.extend([("Ctrl+S", theme.accent, " Section"), shortcuts::separator(theme)]);
+    }
+    instructions.push(shortcuts::esc_cancel(theme));
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
 
     // Render all components
     f.render_widget(main_block, dialog_area);
     f.render_widget(input_paragraph, chunks[0]);
     f.render_widget(project_paragraph, chunks[1]);
-    f.render_widget(instructions_paragraph, chunks[3]);
+    if let Some(section_name) = section_name {
+        f.render_widget(
+            common::create_selection_paragraph(section_name, "Section", theme),
+            chunks[2],
+        );
+    }
+    f.render_widget(instructions_paragraph, chunks[4]);
 
     // Set terminal cursor position
     f.set_cursor_position((chunks[0].x + 1 + cursor_position as u16, chunks[0].y + 1));
 }
 
 // Legacy wrapper functions for backward compatibility
+#[allow(clippy::too_many_arguments)]
 pub fn render_task_creation_dialog(
     f: &mut Frame,
     area: Rect,
+    theme: &Theme,
     icons: &IconService,
     input_buffer: &str,
     cursor_position: usize,
     task_projects: &[&project::Model],
     selected_task_project_index: Option<usize>,
+    section_name: Option<String>,
 ) {
     render_task_dialog(
         f,
         area,
+        theme,
         icons,
         input_buffer,
         cursor_position,
         task_projects,
         selected_task_project_index,
+        section_name,
         false, // is_editing = false for creation
     );
 }
 
+/// Render the task edit form: one input per field, with the focused field highlighted
+///
+/// `fields` holds each field's title and text in `Tab` order.
+#[allow(clippy::too_many_arguments)]
 pub fn render_task_edit_dialog(
     f: &mut Frame,
     area: Rect,
-    icons: &IconService,
+    theme: &Theme,
+    _icons: &IconService,
+    fields: &[(&str, &str)],
+    focused_field: usize,
+    cursor_position: usize,
+    project_name: String,
+    section_name: Option<String>,
+) {
+    // The section field only shows when the project has sections
+    let has_section = section_name.is_some();
+    let section_height = if has_section { 3 } else { 0 };
+    let field_count = u16::try_from(fields.len()).unwrap_or(u16::MAX);
+    let dialog_area = LayoutManager::centered_rect_lines(65, field_count * 3 + 9 + section_height, area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = common::create_dialog_block("Edit Task", theme.accent);
+
+    let inner_area = main_block.inner(dialog_area);
+    let mut constraints = vec![Constraint::Length(3); fields.len()];
+    constraints.extend([
+        Constraint::Length(3),              // Project (read-only)
+        Constraint::Length(section_height), // Section selection field, if any
+        Constraint::Length(1),              // Spacer
+        Constraint::Length(1),              // Instructions
+    ]);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints(constraints)
+        .split(inner_area);
+
+    f.render_widget(main_block, dialog_area);
+    for (index, (title, value)) in fields.iter().enumerate() {
+        let color = if index == focused_field {
+            theme.accent
+        } else {
+            theme.border
+        };
+        let block = common::create_field_block(title, color, theme);
+        let paragraph = Paragraph::new(*value).block(block).style(Style::default().fg(theme.text));
+        f.render_widget(paragraph, chunks[index]);
+    }
+
+    f.render_widget(
+        common::create_selection_paragraph(project_name, "Project", theme),
+        chunks[fields.len()],
+    );
+    if let Some(section_name) = section_name {
+        f.render_widget(
+            common::create_selection_paragraph(section_name, "Section", theme),
+            chunks[fields.len() + 1],
+        );
+    }
+
+    let mut instructions = vec![
+        ("Enter", theme.success, " Save Task"),
+        shortcuts::separator(theme),
+        ("Tab", theme.accent, " Next Field"),
+        shortcuts::separator(theme),
+    ];
+    if has_section {
+        instructions.extend([("Ctrl+S", theme.accent, " Section"), shortcuts::separator(theme)]);
+    }
+    instructions.push(shortcuts::esc_cancel(theme));
+    f.render_widget(
+        common::create_instructions_paragraph(&instructions, theme),
+        chunks[fields.len() + 3],
+    );
+
+    if let Some(field_chunk) = chunks.get(focused_field) {
+        let base_x = field_chunk.x.saturating_add(1);
+        let cursor_u16 = u16::try_from(cursor_position).unwrap_or(u16::MAX.saturating_sub(base_x));
+        f.set_cursor_position((base_x.saturating_add(cursor_u16), field_chunk.y.saturating_add(1)));
+    }
+}
+
+/// Render the prompt for a task's deadline, typed as a date or in plain English
+pub fn render_deadline_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    task_content: &str,
     input_buffer: &str,
     cursor_position: usize,
-    task_projects: &[&project::Model],
-    selected_task_project_index: Option<usize>,
 ) {
-    render_task_dialog(
-        f,
-        area,
-        icons,
-        input_buffer,
-        cursor_position,
-        task_projects,
-        selected_task_project_index,
-        true, // is_editing = true for editing
+    let dialog_area = LayoutManager::centered_rect_lines(60, 11, area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = common::create_dialog_block(" Deadline ", theme.secondary);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(2), // Task and hint
+            Constraint::Length(3), // Input field
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let hint = Paragraph::new(format!(
+        "{}\ne.g. \"by end of month\", \"fri\", \"in 2 weeks\", 2025-01-31 (empty clears)",
+        task_content
+    ))
+    .style(Style::default().fg(theme.text))
+    .wrap(Wrap { trim: true });
+
+    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "Deadline", theme);
+
+    let instructions = [
+        ("Enter", theme.success, " Set"),
+        shortcuts::separator(theme),
+        shortcuts::esc_cancel(theme),
+    ];
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
+
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(hint, chunks[0]);
+    f.render_widget(input_paragraph, chunks[1]);
+    f.render_widget(instructions_paragraph, chunks[2]);
+
+    let base_x = chunks[1].x.saturating_add(1);
+    let cursor_u16 = u16::try_from(cursor_position).unwrap_or(u16::MAX.saturating_sub(base_x));
+    f.set_cursor_position((base_x.saturating_add(cursor_u16), chunks[1].y.saturating_add(1)));
+}
+
+/// Render the prompt for setting any due date, with an optional time, typed in plain English
+pub fn render_due_date_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    task_content: &str,
+    input_buffer: &str,
+    cursor_position: usize,
+) {
+    let dialog_area = LayoutManager::centered_rect_lines(60, 11, area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = common::create_dialog_block(" Due Date ", theme.accent);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(2), // Task and hint
+            Constraint::Length(3), // Input field
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let hint = Paragraph::new(format!(
+        "{}\ne.g. \"next fri\", \"tomorrow 14:30\", \"in 3 days\", 2025-01-31 (empty clears)",
+        task_content
+    ))
+    .style(Style::default().fg(theme.text))
+    .wrap(Wrap { trim: true });
+
+    let input_paragraph = common::create_input_paragraph(input_buffer, cursor_position, "Due date", theme);
+
+    let instructions = [
+        ("Enter", theme.success, " Set"),
+        shortcuts::separator(theme),
+        shortcuts::esc_cancel(theme),
+    ];
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
+
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(hint, chunks[0]);
+    f.render_widget(input_paragraph, chunks[1]);
+    f.render_widget(instructions_paragraph, chunks[2]);
+
+    let base_x = chunks[1].x.saturating_add(1);
+    let cursor_u16 = u16::try_from(cursor_position).unwrap_or(u16::MAX.saturating_sub(base_x));
+    f.set_cursor_position((base_x.saturating_add(cursor_u16), chunks[1].y.saturating_add(1)));
+}
+
+/// Render the label picker of a task: every label with a checkbox, in its own color
+pub fn render_task_labels_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    task_content: &str,
+    labels: &[label::Model],
+    checked: &[String],
+    selected_index: usize,
+) {
+    let dialog_area = LayoutManager::centered_rect(60, 60, area);
+    f.render_widget(Clear, dialog_area);
+
+    let main_block = common::create_dialog_block(" Labels ", theme.secondary);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(2), // Task
+            Constraint::Min(1),    // Label list
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let task = Paragraph::new(task_content.to_string())
+        .style(Style::default().fg(theme.text))
+        .wrap(Wrap { trim: true });
+
+    let items: Vec<ListItem> = labels
+        .iter()
+        .map(|label| {
+            let checkbox = if checked.contains(&label.name) { "[x] " } else { "[ ] " };
+            ListItem::new(Line::from(vec![
+                Span::styled(checkbox, Style::default().fg(theme.text)),
+                Span::styled(
+                    label.name.clone(),
+                    Style::default().fg(color::to_terminal_color(&label.color)),
+                ),
+            ]))
+        })
+        .collect();
+    let list = List::new(items).highlight_style(Style::default().bg(theme.selected_bg).add_modifier(Modifier::BOLD));
+    let mut list_state = ListState::default();
+    list_state.select(Some(selected_index.min(labels.len().saturating_sub(1))));
+
+    let instructions = [
+        ("j/k", theme.accent, " Navigate"),
+        shortcuts::separator(theme),
+        ("Space", theme.warning, " Toggle"),
+        shortcuts::separator(theme),
+        ("Enter", theme.success, " Save"),
+        shortcuts::separator(theme),
+        shortcuts::esc_cancel(theme),
+    ];
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
+
+    f.render_widget(main_block, dialog_area);
+    f.render_widget(task, chunks[0]);
+    if labels.is_empty() {
+        let empty = Paragraph::new("No labels yet").style(Style::default().fg(theme.muted));
+        f.render_widget(empty, chunks[1]);
+    } else {
+        f.render_stateful_widget(list, chunks[1], &mut list_state);
+    }
+    f.render_widget(instructions_paragraph, chunks[2]);
+}
+
+/// Render the project picker for moving the marked tasks to another project
+pub fn render_move_tasks_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    projects: &[project::Model],
+    task_count: usize,
+    selected_index: usize,
+) {
+    let dialog_area = LayoutManager::centered_rect(60, 60, area);
+    f.render_widget(Clear, dialog_area);
+
+    let title = format!(
+        " Move {} {} ",
+        task_count,
+        if task_count == 1 { "task" } else { "tasks" }
+    );
+    let main_block = common::create_dialog_block(&title, theme.secondary);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(1),    // Project list
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let items: Vec<ListItem> = projects
+        .iter()
+        .map(|project| ListItem::new(project.name.clone()).style(Style::default().fg(theme.text)))
+        .collect();
+    let list = List::new(items).highlight_style(Style::default().bg(theme.selected_bg).add_modifier(Modifier::BOLD));
+    let mut list_state = ListState::default();
+    list_state.select(Some(selected_index.min(projects.len().saturating_sub(1))));
+
+    let instructions = [
+        ("j/k", theme.accent, " Navigate"),
+        shortcuts::separator(theme),
+        ("Enter", theme.success, " Move"),
+        shortcuts::separator(theme),
+        shortcuts::esc_cancel(theme),
+    ];
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
+
+    f.render_widget(main_block, dialog_area);
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+    f.render_widget(instructions_paragraph, chunks[1]);
+}
+
+/// Render the task picker for making the marked tasks subtasks of another task
+pub fn render_nest_tasks_dialog(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    candidates: &[&task::Model],
+    task_count: usize,
+    selected_index: usize,
+) {
+    let dialog_area = LayoutManager::centered_rect(60, 60, area);
+    f.render_widget(Clear, dialog_area);
+
+    let title = format!(
+        " Make {} {} subtasks of ",
+        task_count,
+        if task_count == 1 { "task" } else { "tasks" }
     );
+    let main_block = common::create_dialog_block(&title, theme.secondary);
+
+    let inner_area = main_block.inner(dialog_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(1),    // Task list
+            Constraint::Length(1), // Instructions
+        ])
+        .split(inner_area);
+
+    let items: Vec<ListItem> = candidates
+        .iter()
+        .map(|task| ListItem::new(task.content.clone()).style(Style::default().fg(theme.text)))
+        .collect();
+    let list = List::new(items).highlight_style(Style::default().bg(theme.selected_bg).add_modifier(Modifier::BOLD));
+    let mut list_state = ListState::default();
+    list_state.select(Some(selected_index.min(candidates.len().saturating_sub(1))));
+
+    let instructions = [
+        ("j/k", theme.accent, " Navigate"),
+        shortcuts::separator(theme),
+        ("Enter", theme.success, " Nest"),
+        shortcuts::separator(theme),
+        shortcuts::esc_cancel(theme),
+    ];
+    let instructions_paragraph = common::create_instructions_paragraph(&instructions, theme);
+
+    f.render_widget(main_block, dialog_area);
+    if candidates.is_empty() {
+        let empty = Paragraph::new("No other open tasks in this view").style(Style::default().fg(theme.muted));
+        f.render_widget(empty, chunks[0]);
+    } else {
+        f.render_stateful_widget(list, chunks[0], &mut list_state);
+    }
+    f.render_widget(instructions_paragraph, chunks[1]);
 }
@@ -9,6 +9,8 @@
 //!
 //! ## Visual Elements
 //! - [`badge`] - Small status indicators and labels
+//! - [`breadcrumb`] - Ancestor paths for nested projects
+//! - [`task_detail`] - Detail pane for the selected task
 //!
 //! ## Interactive Components
 //! - [`dialog_component`] - Modal dialog framework
@@ -35,6 +37,8 @@
 
 // Visual element components
 pub mod badge;
+pub mod breadcrumb;
+pub mod task_detail;
 
 // Utility components
 pub mod scrollbar_helper;
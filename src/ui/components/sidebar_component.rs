@@ -3,27 +3,70 @@
 //! This component provides the main navigation interface, allowing users to switch
 //! between different views (Today, Tomorrow, Upcoming) and browse projects and labels.
 //! It handles keyboard and mouse navigation with proper visual feedback.
-
+//!
+//! Which special views are shown, and in what order, comes from `config.ui.views`.
+//! Views, projects and labels show how many open tasks they hold. Saved filters from
+//! `config.filters` are listed after the views. With
+//! `config.ui.sidebar_sections`, rows are grouped under collapsible headers. Favorite
+//! projects and labels are also listed under a "Favorites" header at the top.
+//! The bottom border tells how long ago the backend last synced, or shows the name
+//! typed to jump to a project.
+
+use crate::config::{BehaviorConfig, UiConfig};
 use crate::entities::{label, project};
-use crate::icons::IconService;
+use crate::icons::{IconService, IconTheme};
+use crate::sync::counts::SidebarCounts;
 use crate::ui::components::scrollbar_helper::ScrollbarHelper;
 use crate::ui::components::sidebar_item_component::{SidebarItem, SidebarItemType};
-use crate::ui::core::SidebarSelection;
 use crate::ui::core::{actions::Action, Component};
-use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use crate::ui::core::{theme::Theme, SidebarSection, SidebarSelection};
+use crate::utils::{datetime, fuzzy};
+use chrono::{DateTime, Utc};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
+    text::Line,
     widgets::{Block, BorderType, Borders, List, ListItem, ListState},
     Frame,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
+/// Resolve a special view name from `config.ui.views` to its display name and selection.
+///
+/// "someday" is backed by the label named `someday_label` (case-insensitive).
+/// Returns `None` for unknown names, for "inbox" while no inbox project is loaded, and
+/// for "someday" while no matching label exists.
+pub fn special_view_selection(
+    view: &str,
+    projects: &[project::Model],
+    labels: &[label::Model],
+    someday_label: &str,
+) -> Option<(&'static str, SidebarSelection)> {
+    match view {
+        "inbox" => projects
+            .iter()
+            .position(|p| p.is_inbox_project)
+            .map(|index| ("Inbox", SidebarSelection::Project(index))),
+        "someday" if !someday_label.is_empty() => labels
+            .iter()
+            .position(|l| l.name.eq_ignore_ascii_case(someday_label))
+            .map(|index| ("Someday", SidebarSelection::Label(index))),
+        "today" => Some(("Today", SidebarSelection::Today)),
+        "tomorrow" => Some(("Tomorrow", SidebarSelection::Tomorrow)),
+        "upcoming" => Some(("Upcoming", SidebarSelection::Upcoming)),
+        "postponed" => Some(("Postponed", SidebarSelection::Postponed)),
+        "all" => Some(("All tasks", SidebarSelection::All)),
+        _ => None,
+    }
+}
+
 /// Navigation sidebar component for switching between views, projects, and labels.
 ///
 /// The sidebar provides a hierarchical navigation structure:
-/// - Special views (Today, Tomorrow, Upcoming)
+/// - Favorites (favorite projects and labels, also listed in their own place)
+/// - Special views (configurable subset of Inbox, Today, Tomorrow, Upcoming)
 /// - Projects (user-created project list)
 /// - Labels (for filtering tasks by label)
 ///
@@ -37,7 +80,23 @@ pub struct SidebarComponent {
     pub selection: SidebarSelection,
     pub projects: Vec<project::Model>,
     pub labels: Vec<label::Model>,
+    /// Connected backends by UUID and name; with more than one, projects are grouped by backend
+    accounts: Vec<(Uuid, String)>,
     pub icons: IconService,
+    theme: Theme,
+    views: Vec<String>,
+    filters: Vec<String>,
+    counts: SidebarCounts,
+    last_sync_at: Option<DateTime<Utc>>,
+    someday_label: String,
+    group_sections: bool,
+    /// Show a completion bar next to projects
+    project_progress: bool,
+    collapsed_sections: HashSet<SidebarSection>,
+    /// Name typed to jump to a project, while jumping
+    jump_query: Option<String>,
+    /// Position of the highlighted project among the jump matches
+    jump_cursor: usize,
     items: Vec<SidebarItemType>,
     folder_states: HashMap<String, bool>,
     list_state: ListState,
@@ -59,7 +118,19 @@ impl SidebarComponent {
             selection: SidebarSelection::Today,
             projects: Vec::new(),
             labels: Vec::new(),
+            accounts: Vec::new(),
             icons: IconService::default(),
+            theme: Theme::default(),
+            views: UiConfig::default().views,
+            filters: Vec::new(),
+            counts: SidebarCounts::default(),
+            last_sync_at: None,
+            someday_label: BehaviorConfig::default().someday_label,
+            group_sections: false,
+            project_progress: false,
+            collapsed_sections: HashSet::new(),
+            jump_query: None,
+            jump_cursor: 0,
             items: Vec::new(),
             folder_states: HashMap::new(),
             list_state,
@@ -78,41 +149,379 @@ impl SidebarComponent {
         self.update_list_state();
     }
 
+    /// Set the connected backends, in display order
+    pub fn set_accounts(&mut self, accounts: Vec<(Uuid, String)>) {
+        if self.accounts != accounts {
+            self.accounts = accounts;
+            self.build_item_list();
+        }
+    }
+
+    /// Set which special views are shown, in display order (from `config.ui.views`)
+    pub fn set_views(&mut self, views: Vec<String>) {
+        if self.views != views {
+            self.views = views;
+            self.build_item_list();
+            self.update_list_state();
+        }
+    }
+
+    /// Set the names of the saved filters, in `config.filters` order
+    pub fn set_filters(&mut self, filters: Vec<String>) {
+        if self.filters != filters {
+            self.filters = filters;
+            self.build_item_list();
+            self.update_list_state();
+        }
+    }
+
+    /// Set the open task counts shown next to views, projects and labels
+    pub fn set_counts(&mut self, counts: SidebarCounts) {
+        self.counts = counts;
+    }
+
+    /// Open tasks behind a selection, if it is counted
+    pub fn count_for(&self, selection: &SidebarSelection) -> Option<usize> {
+        match selection {
+            SidebarSelection::Today => Some(self.counts.today),
+            SidebarSelection::Tomorrow => Some(self.counts.tomorrow),
+            SidebarSelection::Upcoming => Some(self.counts.upcoming),
+            SidebarSelection::All => Some(self.counts.all),
+            SidebarSelection::Postponed | SidebarSelection::Filter(_) => None,
+            SidebarSelection::Project(index) => {
+                let project = self.projects.get(*index)?;
+                Some(self.counts.projects.get(&project.uuid).copied().unwrap_or(0))
+            }
+            SidebarSelection::Label(index) => {
+                let label = self.labels.get(*index)?;
+                Some(self.counts.labels.get(&label.uuid).copied().unwrap_or(0))
+            }
+        }
+    }
+
+    /// Set when the backend last synced, shown in the bottom border
+    pub fn set_last_sync_at(&mut self, last_sync_at: Option<DateTime<Utc>>) {
+        self.last_sync_at = last_sync_at;
+    }
+
+    /// Set the colors used to draw the sidebar
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Set the icon set used to draw the sidebar
+    pub fn set_icon_theme(&mut self, icon_theme: IconTheme) {
+        self.icons.set_theme(icon_theme);
+    }
+
+    /// Set the label backing the "someday" view (from `config.behavior.someday_label`)
+    pub fn set_someday_label(&mut self, someday_label: String) {
+        if self.someday_label != someday_label {
+            self.someday_label = someday_label;
+            self.build_item_list();
+            self.update_list_state();
+        }
+    }
+
+    /// Show or hide the completion bar of projects (from `config.display.show_project_progress`)
+    pub fn set_project_progress(&mut self, project_progress: bool) {
+        if self.project_progress != project_progress {
+            self.project_progress = project_progress;
+            self.build_item_list();
+        }
+    }
+
+    /// Start jumping to a project by typing its name
+    pub fn start_jump(&mut self) {
+        self.jump_query = Some(String::new());
+        self.jump_cursor = 0;
+    }
+
+    /// Whether a project name is being typed; the sidebar then takes every key
+    pub fn is_jumping(&self) -> bool {
+        self.jump_query.is_some()
+    }
+
+    /// Indexes of the projects matching the typed name, best match first
+    ///
+    /// Names match fuzzily like task search; equal scores keep the sidebar order.
+    pub fn jump_matches(&self) -> Vec<usize> {
+        let query = self.jump_query.as_deref().unwrap_or_default();
+        let mut matches: Vec<(i64, usize)> = self
+            .get_sorted_projects()
+            .into_iter()
+            .filter_map(|(index, project)| fuzzy::fuzzy_score(query, &project.name).map(|score| (score, index)))
+            .collect();
+        // Stable, so ties stay in sidebar order
+        matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        matches.into_iter().map(|(_, index)| index).collect()
+    }
+
+    /// Handle a key while jumping: typing narrows the projects, Up/Down/Tab pick among them,
+    /// Enter opens the highlighted one and Esc goes back to the current view
+    pub fn handle_jump_key(&mut self, key: KeyEvent) -> Action {
+        let Some(query) = self.jump_query.as_mut() else {
+            return Action::None;
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.jump_query = None;
+                self.update_list_state();
+                return Action::None;
+            }
+            KeyCode::Enter => {
+                let target = self.jump_matches().get(self.jump_cursor).copied();
+                self.jump_query = None;
+                self.update_list_state();
+                return target.map_or(Action::None, |index| {
+                    Action::NavigateToSidebar(SidebarSelection::Project(index))
+                });
+            }
+            KeyCode::Backspace => {
+                query.pop();
+                self.jump_cursor = 0;
+            }
+            KeyCode::Down | KeyCode::Tab => self.jump_cursor += 1,
+            KeyCode::Up | KeyCode::BackTab => self.jump_cursor = self.jump_cursor.wrapping_sub(1),
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                query.push(c);
+                self.jump_cursor = 0;
+            }
+            _ => return Action::None,
+        }
+
+        // Highlight the picked project, wrapping around the matches
+        let matches = self.jump_matches();
+        if matches.is_empty() {
+            self.jump_cursor = 0;
+            return Action::None;
+        }
+        if self.jump_cursor == usize::MAX {
+            self.jump_cursor = matches.len() - 1;
+        }
+        self.jump_cursor %= matches.len();
+        let picked = SidebarSelection::Project(matches[self.jump_cursor]);
+        if let Some(row) = self
+            .items
+            .iter()
+            .position(|item| item.get_selection().as_ref() == Some(&picked))
+        {
+            self.list_state.select(Some(row));
+        }
+        Action::None
+    }
+
+    /// Enable or disable grouping rows under collapsible section headers
+    pub fn set_group_sections(&mut self, group_sections: bool) {
+        if self.group_sections != group_sections {
+            self.group_sections = group_sections;
+            self.build_item_list();
+            self.update_list_state();
+        }
+    }
+
+    /// Collapse or expand a section; collapsed sections only show their header
+    pub fn set_section_collapsed(&mut self, section: SidebarSection, collapsed: bool) {
+        let changed = if collapsed {
+            self.collapsed_sections.insert(section)
+        } else {
+            self.collapsed_sections.remove(&section)
+        };
+        if changed {
+            self.build_item_list();
+            self.update_list_state();
+        }
+    }
+
+    pub fn is_section_collapsed(&self, section: SidebarSection) -> bool {
+        self.collapsed_sections.contains(&section)
+    }
+
+    /// Collapsed sections in display order
+    pub fn collapsed_sections(&self) -> Vec<SidebarSection> {
+        SidebarSection::ALL
+            .into_iter()
+            .filter(|section| self.collapsed_sections.contains(section))
+            .collect()
+    }
+
+    /// Row showing the given selection: the highlighted row when it does (favorites have
+    /// two rows), otherwise the first one
+    fn row_of(&self, selection: &SidebarSelection) -> Option<usize> {
+        let row_matches = |row: usize| {
+            self.items
+                .get(row)
+                .is_some_and(|item| item.get_selection().as_ref() == Some(selection))
+        };
+        self.list_state
+            .selected()
+            .filter(|row| row_matches(*row))
+            .or_else(|| (0..self.items.len()).find(|row| row_matches(*row)))
+    }
+
+    /// Section the given selection's row belongs to
+    fn section_of(&self, selection: &SidebarSelection) -> SidebarSection {
+        // A visible row belongs to the closest header above it (inbox and someday rows can be views)
+        if let Some(row) = self.row_of(selection) {
+            let header = self.items[..row].iter().rev().find_map(|item| match item {
+                SidebarItemType::SectionHeader { section, .. } => Some(*section),
+                _ => None,
+            });
+            if let Some(section) = header {
+                return section;
+            }
+        }
+
+        match selection {
+            SidebarSelection::Today
+            | SidebarSelection::Tomorrow
+            | SidebarSelection::Upcoming
+            | SidebarSelection::Postponed
+            | SidebarSelection::All => SidebarSection::Views,
+            SidebarSelection::Filter(_) => SidebarSection::Filters,
+            SidebarSelection::Label(_) => SidebarSection::Labels,
+            SidebarSelection::Project(_) => SidebarSection::Projects,
+        }
+    }
+
+    /// Selection of the first configured special view, if any is shown
+    pub fn first_view_selection(&self) -> Option<SidebarSelection> {
+        self.views
+            .iter()
+            .find_map(|view| special_view_selection(view, &self.projects, &self.labels, &self.someday_label))
+            .map(|(_, selection)| selection)
+    }
+
     /// Build the flattened list of sidebar items, respecting folder expanded/collapsed states
     fn build_item_list(&mut self) {
         self.items.clear();
 
-        // Add special views (always visible)
-        self.items.push(SidebarItemType::SpecialView {
-            name: "Today".to_string(),
-            selection: SidebarSelection::Today,
-        });
-        self.items.push(SidebarItemType::SpecialView {
-            name: "Tomorrow".to_string(),
-            selection: SidebarSelection::Tomorrow,
-        });
-        self.items.push(SidebarItemType::SpecialView {
-            name: "Upcoming".to_string(),
-            selection: SidebarSelection::Upcoming,
-        });
+        // Configured special views in order
+        let view_items: Vec<SidebarItemType> = self
+            .views
+            .iter()
+            .filter_map(|view| special_view_selection(view, &self.projects, &self.labels, &self.someday_label))
+            .map(|(name, selection)| SidebarItemType::SpecialView {
+                name: name.to_string(),
+                selection,
+            })
+            .collect();
 
-        // Use placeholder account ID for now
-        let account_id = "main".to_string();
+        let filter_items: Vec<SidebarItemType> = self
+            .filters
+            .iter()
+            .enumerate()
+            .map(|(index, name)| SidebarItemType::Filter {
+                name: name.clone(),
+                original_index: index,
+            })
+            .collect();
 
-        // Add labels
-        for (index, label) in self.labels.iter().enumerate() {
-            self.items.push(SidebarItemType::Label {
+        let label_items: Vec<SidebarItemType> = self
+            .labels
+            .iter()
+            .enumerate()
+            .map(|(index, label)| SidebarItemType::Label {
                 label: label.clone(),
-                account_id: account_id.clone(),
+                account_id: label.backend_uuid.to_string(),
+                original_index: index,
+            })
+            .collect();
+
+        // With several backends, each one's projects are listed under a foldable header
+        let project_items = if self.accounts.len() > 1 {
+            let mut items = Vec::new();
+            for (backend_uuid, name) in &self.accounts {
+                let account_id = backend_uuid.to_string();
+                let is_expanded = self.folder_states.get(&account_id).copied().unwrap_or(true);
+                items.push(SidebarItemType::AccountFolder {
+                    name: name.clone(),
+                    account_id,
+                    is_expanded,
+                });
+                if is_expanded {
+                    items.extend(self.build_project_items(Some(backend_uuid)));
+                }
+            }
+            items
+        } else {
+            self.build_project_items(None)
+        };
+
+        // Favorites keep the sidebar order of projects, then labels
+        let favorite_items: Vec<SidebarItemType> = self
+            .get_sorted_projects()
+            .into_iter()
+            .filter(|(_, project)| project.is_favorite)
+            .map(|(index, project)| SidebarItemType::Project {
+                project: project.clone(),
+                account_id: project.backend_uuid.to_string(),
                 original_index: index,
+                depth: 0,
+                is_last_sibling: true,
+                has_children: false,
+                is_expanded: true,
+                progress: None,
+            })
+            .chain(
+                label_items
+                    .iter()
+                    .filter(|item| matches!(item, SidebarItemType::Label { label, .. } if label.is_favorite))
+                    .cloned(),
+            )
+            .collect();
+        if !favorite_items.is_empty() {
+            let is_expanded = !self.collapsed_sections.contains(&SidebarSection::Favorites);
+            self.items.push(SidebarItemType::SectionHeader {
+                section: SidebarSection::Favorites,
+                is_expanded,
+                count: favorite_items.len(),
+            });
+            if is_expanded {
+                self.items.extend(favorite_items);
+            }
+        }
+
+        if !self.group_sections {
+            self.items.extend(view_items);
+            self.items.extend(filter_items);
+            self.items.extend(label_items);
+            self.items.extend(project_items);
+            return;
+        }
+
+        let sections = [
+            (SidebarSection::Views, view_items.len(), view_items),
+            (SidebarSection::Filters, self.filters.len(), filter_items),
+            (SidebarSection::Labels, self.labels.len(), label_items),
+            (SidebarSection::Projects, self.projects.len(), project_items),
+        ];
+        for (section, count, section_items) in sections {
+            if count == 0 {
+                continue;
+            }
+            let is_expanded = !self.collapsed_sections.contains(&section);
+            self.items.push(SidebarItemType::SectionHeader {
+                section,
+                is_expanded,
+                count,
             });
+            if is_expanded {
+                self.items.extend(section_items);
+            }
         }
+    }
+
+    /// Build project rows (sorted hierarchically), respecting folder fold states
+    ///
+    /// With `backend_uuid`, only the projects of that backend are listed.
+    fn build_project_items(&self, backend_uuid: Option<&Uuid>) -> Vec<SidebarItemType> {
+        let mut items = Vec::new();
 
-        // Add projects (sorted hierarchically), respecting fold states
-        // Clone the data we need before mutating self.items
         let sorted_projects: Vec<_> = self
             .get_sorted_projects()
             .into_iter()
+            .filter(|(_, proj)| backend_uuid.map_or(true, |uuid| proj.backend_uuid == *uuid))
             .map(|(idx, proj)| (idx, proj.clone()))
             .collect();
 
@@ -142,16 +551,22 @@ impl SidebarComponent {
             let has_children = has_children_map.get(&project.uuid).copied().unwrap_or(false);
             let is_expanded = self.folder_states.get(&project.uuid.to_string()).copied().unwrap_or(true); // Default to expanded
 
-            self.items.push(SidebarItemType::Project {
+            items.push(SidebarItemType::Project {
                 project: project.clone(),
-                account_id: account_id.clone(),
+                account_id: project.backend_uuid.to_string(),
                 original_index: *original_index,
                 depth,
                 is_last_sibling,
                 has_children,
                 is_expanded,
+                progress: self
+                    .project_progress
+                    .then(|| self.counts.project_progress(&project.uuid))
+                    .flatten(),
             });
         }
+
+        items
     }
 
     /// Toggle the expanded/collapsed state of a project folder
@@ -174,6 +589,10 @@ impl SidebarComponent {
                     SidebarItemType::AccountFolder { account_id, .. } => {
                         return Some(account_id.clone());
                     }
+                    SidebarItemType::SectionHeader { .. } => {
+                        // Section headers are folded through set_section_collapsed
+                        return None;
+                    }
                     SidebarItemType::Project {
                         project, has_children, ..
                     } => {
@@ -292,20 +711,27 @@ impl SidebarComponent {
                 return selection;
             }
         }
-        // Default to Today if index is out of bounds or item is not selectable
-        SidebarSelection::Today
+        // Default to the first special view if index is out of bounds or item is not selectable
+        self.first_view_selection().unwrap_or(SidebarSelection::Today)
     }
 
     /// Convert SidebarSelection to list index
     fn selection_to_index(&self, selection: &SidebarSelection) -> usize {
-        for (index, item) in self.items.iter().enumerate() {
-            if let Some(item_selection) = item.get_selection() {
-                if &item_selection == selection {
-                    return index;
-                }
+        if let Some(index) = self.row_of(selection) {
+            return index;
+        }
+        // A row hidden in a collapsed section maps to that section's header
+        if self.group_sections {
+            let section = self.section_of(selection);
+            if let Some(index) = self
+                .items
+                .iter()
+                .position(|item| matches!(item, SidebarItemType::SectionHeader { section: s, .. } if *s == section))
+            {
+                return index;
             }
         }
-        // If not found, default to 0 (Today)
+        // If not found, default to the first item
         0
     }
 
@@ -333,6 +759,17 @@ impl SidebarComponent {
                         return Action::None;
                     }
 
+                    // Clicking a section header toggles it
+                    if let Some(SidebarItemType::SectionHeader {
+                        section, is_expanded, ..
+                    }) = self.items.get(clicked_index)
+                    {
+                        return Action::SetSidebarSectionCollapsed {
+                            section: *section,
+                            collapsed: *is_expanded,
+                        };
+                    }
+
                     let selection = self.index_to_selection(clicked_index);
                     self.list_state.select(Some(clicked_index));
                     Action::NavigateToSidebar(selection)
@@ -384,8 +821,6 @@ impl SidebarComponent {
 
 impl Component for SidebarComponent {
     fn handle_key_events(&mut self, key: KeyEvent) -> Action {
-        use crossterm::event::KeyModifiers;
-
         match key.code {
             KeyCode::Char('H') => {
                 // H key: collapse/fold folder if cursor is on a folder
@@ -394,6 +829,15 @@ impl Component for SidebarComponent {
                         // Set folder to collapsed
                         self.folder_states.insert(account_id, false);
                         self.build_item_list();
+                    } else if self.group_sections {
+                        // Otherwise collapse the section containing the selection
+                        let section = self.section_of(&self.selection);
+                        if !self.is_section_collapsed(section) {
+                            return Action::SetSidebarSectionCollapsed {
+                                section,
+                                collapsed: true,
+                            };
+                        }
                     }
                 }
                 Action::None
@@ -405,6 +849,21 @@ impl Component for SidebarComponent {
                         // Set folder to expanded
                         self.folder_states.insert(account_id, true);
                         self.build_item_list();
+                    } else if self.group_sections {
+                        // Otherwise expand the selection's section, or the first collapsed one
+                        // so that every section stays reachable from the keyboard
+                        let section = self.section_of(&self.selection);
+                        let target = if self.is_section_collapsed(section) {
+                            Some(section)
+                        } else {
+                            self.collapsed_sections().into_iter().next()
+                        };
+                        if let Some(section) = target {
+                            return Action::SetSidebarSectionCollapsed {
+                                section,
+                                collapsed: false,
+                            };
+                        }
                     }
                 }
                 Action::None
@@ -469,6 +928,11 @@ impl Component for SidebarComponent {
                 // Pass the action through to AppComponent for further processing
                 Action::NavigateToSidebar(selection)
             }
+            Action::SetSidebarSectionCollapsed { section, collapsed } => {
+                self.set_section_collapsed(section, collapsed);
+                // Pass through so AppComponent can persist the new state
+                action
+            }
             _ => action,
         }
     }
@@ -480,33 +944,50 @@ impl Component for SidebarComponent {
         // Ensure list state is synced with current selection (do this before borrowing items)
         self.update_list_state();
 
-        // Render all items using their render() method
+        // Calculate areas for list and scrollbar using helper
+        let total_items = self.items.len();
+        let (list_area, scrollbar_area) = ScrollbarHelper::calculate_areas(rect, total_items);
+
+        // Render all items using their render() method, with counts aligned inside the borders
+        let width = usize::from(list_area.width.saturating_sub(2));
+        let counts: Vec<Option<usize>> = self
+            .items
+            .iter()
+            .map(|item| item.get_selection().and_then(|selection| self.count_for(&selection)))
+            .collect();
         let all_items: Vec<ListItem> = self
             .items
             .iter()
-            .map(|item| item.render(&self.icons, &self.selection, false))
+            .zip(counts)
+            .map(|(item, count)| item.render(&self.icons, &self.theme, &self.selection, false, count, width))
             .collect();
 
-        // Calculate areas for list and scrollbar using helper
-        let total_items = all_items.len();
-        let (list_area, scrollbar_area) = ScrollbarHelper::calculate_areas(rect, total_items);
-
         // Update scrollbar state with current position and viewport info
         let available_height = rect.height.saturating_sub(2) as usize;
         let current_position = self.list_state.selected().unwrap_or(0);
         self.scrollbar_helper
             .update_state(total_items, current_position, Some(available_height));
 
-        let list = List::new(all_items)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .title("Navigation")
-                    .title_style(Style::default().fg(Color::White))
-                    .border_style(Style::default().fg(Color::DarkGray)),
-            )
-            .style(Style::default().fg(Color::White));
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title("Navigation")
+            .title_style(Style::default().fg(self.theme.text))
+            .border_style(Style::default().fg(self.theme.dim));
+        if let Some(query) = &self.jump_query {
+            let matches = self.jump_matches().len();
+            block = block.title_bottom(
+                Line::from(format!(" Jump: {}▏ ({}) ", query, matches)).style(Style::default().fg(self.theme.accent)),
+            );
+        } else if let Some(last_sync_at) = self.last_sync_at {
+            // Formatted on each frame so the age keeps up between syncs
+            block = block.title_bottom(
+                Line::from(format!(" Last synced {} ", datetime::format_ago(last_sync_at)))
+                    .style(Style::default().fg(self.theme.muted)),
+            );
+        }
+
+        let list = List::new(all_items).block(block).style(Style::default().fg(self.theme.text));
 
         f.render_stateful_widget(list, list_area, &mut self.list_state);
 
@@ -5,9 +5,11 @@
 
 use crate::entities::{label, project};
 use crate::icons::IconService;
-use crate::ui::core::SidebarSelection;
+use crate::ui::components::badge::{create_count_badge, create_progress_badge};
+use crate::ui::core::{theme::Theme, SidebarSection, SidebarSelection};
+use crate::utils::color;
 use ratatui::{
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::ListItem,
 };
@@ -32,13 +34,23 @@ pub enum SidebarItemType {
         is_last_sibling: bool,
         has_children: bool,
         is_expanded: bool,
+        /// Completed share of the project's tasks in percent, when shown
+        progress: Option<u8>,
     },
+    /// Saved filter from `config.filters`
+    Filter { name: String, original_index: usize },
     /// Label item (with account affiliation)
     Label {
         label: label::Model,
         account_id: String,
         original_index: usize,
     },
+    /// Collapsible section header ("Views", "Labels", "Projects")
+    SectionHeader {
+        section: SidebarSection,
+        is_expanded: bool,
+        count: usize,
+    },
     /// Visual separator
     Separator { indent: usize },
 }
@@ -46,11 +58,17 @@ pub enum SidebarItemType {
 /// Trait for sidebar items that can be rendered and navigated
 pub trait SidebarItem {
     /// Render the item as a ListItem with appropriate styling
+    ///
+    /// `count` is the number of open tasks behind the item, shown right-aligned within
+    /// `width` columns when it is not zero.
     fn render<'a>(
         &'a self,
         icons: &'a IconService,
+        theme: &'a Theme,
         current_selection: &'a SidebarSelection,
         is_selected: bool,
+        count: Option<usize>,
+        width: usize,
     ) -> ListItem<'a>;
 
     /// Whether this item can be selected (navigated to)
@@ -66,37 +84,76 @@ pub trait SidebarItem {
     fn get_selection(&self) -> Option<SidebarSelection>;
 }
 
+/// Cells of the progress bar shown next to projects
+const PROGRESS_BAR_CELLS: usize = 5;
+
+/// Line of an item, with its open task count right-aligned when there is room for it
+fn line_with_count(spans: Vec<Span<'_>>, count: Option<usize>, width: usize) -> Line<'_> {
+    let badges = count.filter(|count| *count > 0).map(create_count_badge).into_iter().collect();
+    line_with_badges(spans, badges, width)
+}
+
+/// Line of an item with `badges` right-aligned, leaving out the first ones when they don't fit
+fn line_with_badges<'a>(spans: Vec<Span<'a>>, mut badges: Vec<Span<'a>>, width: usize) -> Line<'a> {
+    let mut line = Line::from(spans);
+    while !badges.is_empty() {
+        let badges_width = badges.iter().map(Span::width).sum::<usize>() + badges.len() - 1;
+        let used = line.width() + badges_width;
+        // Keep at least one space between the name and the badges
+        if used < width {
+            line.spans.push(Span::raw(" ".repeat(width - used)));
+            for (index, badge) in badges.into_iter().enumerate() {
+                if index > 0 {
+                    line.spans.push(Span::raw(" "));
+                }
+                line.spans.push(badge);
+            }
+            break;
+        }
+        badges.remove(0);
+    }
+    line
+}
+
 impl SidebarItem for SidebarItemType {
     fn render<'a>(
         &'a self,
         icons: &'a IconService,
+        theme: &'a Theme,
         current_selection: &'a SidebarSelection,
         _is_selected: bool,
+        count: Option<usize>,
+        width: usize,
     ) -> ListItem<'a> {
         match self {
             SidebarItemType::SpecialView { name, selection } => {
                 let is_selected = current_selection == selection;
                 let style = if is_selected {
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)
                 } else {
-                    Style::default().fg(Color::White)
+                    Style::default().fg(theme.text)
                 };
 
                 let icon = match selection {
                     SidebarSelection::Today => icons.today(),
                     SidebarSelection::Tomorrow => icons.tomorrow(),
                     SidebarSelection::Upcoming => icons.upcoming(),
-                    _ => "",
+                    SidebarSelection::Postponed => icons.postponed(),
+                    SidebarSelection::All => icons.all_tasks(),
+                    SidebarSelection::Filter(_) => icons.filter(),
+                    SidebarSelection::Project(_) => icons.project_regular(),
+                    SidebarSelection::Label(_) => icons.label(),
                 };
 
-                ListItem::new(Line::from(vec![
-                    Span::styled(icon.to_string(), style),
-                    Span::styled(name.clone(), style),
-                ]))
+                ListItem::new(line_with_count(
+                    vec![Span::styled(icon.to_string(), style), Span::styled(name.clone(), style)],
+                    count,
+                    width,
+                ))
             }
 
             SidebarItemType::AccountFolder { name, is_expanded, .. } => {
-                let style = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+                let style = Style::default().fg(theme.accent).add_modifier(Modifier::BOLD);
                 let arrow = if *is_expanded { "▼" } else { "▶" };
                 let icon = "📦";
 
@@ -114,6 +171,7 @@ impl SidebarItem for SidebarItemType {
                 is_last_sibling,
                 has_children,
                 is_expanded,
+                progress,
                 ..
             } => {
                 let is_selected = matches!(
@@ -121,9 +179,9 @@ impl SidebarItem for SidebarItemType {
                     SidebarSelection::Project(idx) if idx == original_index
                 );
                 let style = if is_selected {
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)
                 } else {
-                    Style::default().fg(Color::White)
+                    Style::default().fg(theme.text)
                 };
 
                 let tree_prefix = if *depth > 0 {
@@ -151,12 +209,42 @@ impl SidebarItem for SidebarItemType {
                 }
 
                 if !tree_prefix.is_empty() {
-                    spans.push(Span::styled(tree_prefix, Style::default().fg(Color::DarkGray)));
+                    spans.push(Span::styled(tree_prefix, Style::default().fg(theme.dim)));
                 }
+                spans.push(Span::styled(
+                    format!("{} ", icons.color_swatch()),
+                    Style::default().fg(color::to_terminal_color(&project.color)),
+                ));
                 spans.push(Span::styled(icon.to_string(), style));
                 spans.push(Span::styled(project.name.clone(), style));
 
-                ListItem::new(Line::from(spans))
+                let mut badges = Vec::new();
+                if let Some(percent) = progress {
+                    badges.push(create_progress_badge(
+                        icons.progress_bar(*percent, PROGRESS_BAR_CELLS),
+                        *percent,
+                        theme,
+                    ));
+                }
+                badges.extend(count.filter(|count| *count > 0).map(create_count_badge));
+                ListItem::new(line_with_badges(spans, badges, width))
+            }
+
+            SidebarItemType::Filter { name, original_index } => {
+                let is_selected = matches!(
+                    current_selection,
+                    SidebarSelection::Filter(idx) if idx == original_index
+                );
+                let style = if is_selected {
+                    Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text)
+                };
+
+                ListItem::new(Line::from(vec![
+                    Span::styled(icons.filter().to_string(), style),
+                    Span::styled(name.clone(), style),
+                ]))
             }
 
             SidebarItemType::Label {
@@ -167,15 +255,48 @@ impl SidebarItem for SidebarItemType {
                     SidebarSelection::Label(idx) if idx == original_index
                 );
                 let style = if is_selected {
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)
                 } else {
-                    Style::default().fg(Color::White)
+                    Style::default().fg(theme.text)
                 };
 
-                ListItem::new(Line::from(vec![
-                    Span::styled(icons.label().to_string(), style),
-                    Span::styled(label.name.clone(), style),
-                ]))
+                // Favorite labels use the favorite icon; the icon carries the label color
+                let icon = if label.is_favorite {
+                    icons.project_favorite()
+                } else {
+                    icons.label()
+                };
+                let icon_style = if is_selected {
+                    style
+                } else {
+                    Style::default().fg(color::to_terminal_color(&label.color))
+                };
+
+                ListItem::new(line_with_count(
+                    vec![
+                        Span::styled(icon.to_string(), icon_style),
+                        Span::styled(label.name.clone(), style),
+                    ],
+                    count,
+                    width,
+                ))
+            }
+
+            SidebarItemType::SectionHeader {
+                section,
+                is_expanded,
+                count,
+            } => {
+                let style = Style::default().fg(theme.accent).add_modifier(Modifier::BOLD);
+                let arrow = if *is_expanded { "▼" } else { "▶" };
+
+                let mut spans = vec![Span::styled(format!("{} ", arrow), style), Span::styled(section.title(), style)];
+                // Show how much is hidden when collapsed
+                if !*is_expanded {
+                    spans.push(Span::styled(format!(" ({})", count), Style::default().fg(theme.dim)));
+                }
+
+                ListItem::new(Line::from(spans))
             }
 
             SidebarItemType::Separator { indent } => {
@@ -190,7 +311,9 @@ impl SidebarItem for SidebarItemType {
             SidebarItemType::SpecialView { .. } => true,
             SidebarItemType::AccountFolder { .. } => false, // Folders are not selectable, only foldable
             SidebarItemType::Project { .. } => true,
+            SidebarItemType::Filter { .. } => true,
             SidebarItemType::Label { .. } => true,
+            SidebarItemType::SectionHeader { .. } => false, // Headers are only foldable, like folders
             SidebarItemType::Separator { .. } => false,
         }
     }
@@ -200,7 +323,9 @@ impl SidebarItem for SidebarItemType {
             SidebarItemType::SpecialView { .. } => 0,
             SidebarItemType::AccountFolder { .. } => 0,
             SidebarItemType::Project { depth, .. } => *depth,
+            SidebarItemType::Filter { .. } => 0,
             SidebarItemType::Label { .. } => 0,
+            SidebarItemType::SectionHeader { .. } => 0,
             SidebarItemType::Separator { indent } => *indent,
         }
     }
@@ -208,6 +333,7 @@ impl SidebarItem for SidebarItemType {
     fn is_foldable(&self) -> bool {
         match self {
             SidebarItemType::AccountFolder { .. } => true,
+            SidebarItemType::SectionHeader { .. } => true,
             SidebarItemType::Project { has_children, .. } => *has_children,
             _ => false,
         }
@@ -218,7 +344,9 @@ impl SidebarItem for SidebarItemType {
             SidebarItemType::SpecialView { selection, .. } => Some(selection.clone()),
             SidebarItemType::AccountFolder { .. } => None,
             SidebarItemType::Project { original_index, .. } => Some(SidebarSelection::Project(*original_index)),
+            SidebarItemType::Filter { original_index, .. } => Some(SidebarSelection::Filter(*original_index)),
             SidebarItemType::Label { original_index, .. } => Some(SidebarSelection::Label(*original_index)),
+            SidebarItemType::SectionHeader { .. } => None,
             SidebarItemType::Separator { .. } => None,
         }
     }
@@ -0,0 +1,165 @@
+//! Detail pane for the selected task.
+//!
+//! Shown as a third column next to the task list, it spells out what a task row only
+//! hints at: the full description, the project path and section, due date, recurrence,
+//! deadline, duration, priority and labels in their colors, and renders the Markdown of
+//! the description. Everything comes from the data the task list already holds.
+
+use crate::config::DisplayConfig;
+use crate::entities::{label, project, section, task};
+use crate::ui::components::breadcrumb::{project_path, BREADCRUMB_SEPARATOR};
+use crate::ui::core::theme::Theme;
+use crate::utils::markdown::{self, MarkdownStyle};
+use crate::utils::{color, datetime};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Narrowest detail pane, in columns; a narrower screen doesn't show it
+pub const DETAIL_PANE_MIN_WIDTH: u16 = 30;
+
+/// Width of the field names column ("Deadline ")
+const FIELD_NAME_WIDTH: usize = 10;
+
+/// A task with the data its detail pane refers to
+pub struct TaskDetail<'a> {
+    pub task: &'a task::Model,
+    pub projects: &'a [project::Model],
+    pub sections: &'a [section::Model],
+    /// All labels, for their colors
+    pub labels: &'a [label::Model],
+    /// Names of the task's labels
+    pub label_names: &'a [String],
+}
+
+impl TaskDetail<'_> {
+    /// The pane's lines: the content, one line per field that is set, then the description
+    pub fn lines(&self, theme: &Theme, display: &DisplayConfig) -> Vec<Line<'static>> {
+        let task = self.task;
+        let now = datetime::now();
+        let field = |name: &str, value: Vec<Span<'static>>| {
+            let mut spans = vec![Span::styled(
+                format!("{:<width$}", name, width = FIELD_NAME_WIDTH),
+                Style::default().fg(theme.muted),
+            )];
+            spans.extend(value);
+            Line::from(spans)
+        };
+        let text = |value: String| vec![Span::styled(value, Style::default().fg(theme.text))];
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                task.content.clone(),
+                Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+        ];
+
+        let path = project_path(self.projects, &task.project_uuid);
+        if !path.is_empty() {
+            lines.push(field("Project", text(path.join(BREADCRUMB_SEPARATOR))));
+        }
+        if let Some(section) = task
+            .section_uuid
+            .and_then(|uuid| self.sections.iter().find(|section| section.uuid == uuid))
+        {
+            lines.push(field("Section", text(section.name.clone())));
+        }
+        if let Some(due) = task.due() {
+            let overdue = due.is_overdue(now) && !task.is_completed;
+            lines.push(field(
+                "Due",
+                vec![
+                    Span::styled(
+                        due.humanize(now),
+                        Style::default().fg(if overdue { theme.overdue } else { theme.due }),
+                    ),
+                    Span::styled(
+                        format!(" ({})", due.display(&display.date_format, &display.time_format)),
+                        Style::default().fg(theme.dim),
+                    ),
+                ],
+            ));
+        }
+        if task.is_recurring {
+            let recurrence = task.due_string.clone().unwrap_or_else(|| "yes".to_string());
+            lines.push(field("Repeats", text(recurrence)));
+        }
+        if let Some(deadline) = datetime::Due::parse(task.deadline.as_deref(), None) {
+            let missed = deadline.is_overdue(now) && !task.is_completed;
+            lines.push(field(
+                "Deadline",
+                vec![Span::styled(
+                    deadline.display(&display.date_format, &display.time_format),
+                    Style::default().fg(if missed { theme.overdue } else { theme.text }),
+                )],
+            ));
+        }
+        if let Some(duration) = task.duration.as_deref().filter(|d| !d.is_empty()) {
+            let amount =
+                datetime::parse_duration(duration).map_or_else(|| duration.to_string(), datetime::format_duration);
+            lines.push(field("Duration", text(amount)));
+        }
+        lines.push(field(
+            "Priority",
+            vec![Span::styled(
+                format!("p{}", 5 - task.priority.clamp(1, 4)),
+                Style::default().fg(theme.priority_color(task.priority)),
+            )],
+        ));
+        if !self.label_names.is_empty() {
+            let mut spans = Vec::new();
+            for (index, name) in self.label_names.iter().enumerate() {
+                if index > 0 {
+                    spans.push(Span::raw(" "));
+                }
+                let label_color = self
+                    .labels
+                    .iter()
+                    .find(|label| label.name == *name)
+                    .map_or(theme.text, |label| color::to_terminal_color(&label.color));
+                spans.push(Span::styled(format!("@{}", name), Style::default().fg(label_color)));
+            }
+            lines.push(field("Labels", spans));
+        }
+
+        lines.push(Line::from(""));
+        match task.description.as_deref().filter(|d| !d.trim().is_empty()) {
+            Some(description) => {
+                let style = MarkdownStyle {
+                    text: Style::default().fg(theme.text),
+                    link: Style::default().fg(theme.accent).add_modifier(Modifier::UNDERLINED),
+                    marker: Style::default().fg(theme.dim),
+                    code: Style::default().fg(theme.info),
+                };
+                lines.extend(markdown::to_text(description, &style).lines);
+            }
+            None => lines.push(Line::from(Span::styled(
+                "No description",
+                Style::default().fg(theme.dim),
+            ))),
+        }
+        lines
+    }
+}
+
+/// Render the detail pane, with `lines` from [`TaskDetail::lines`] or a hint when no task is selected
+///
+/// Long lines are wrapped to the pane's width.
+pub fn render_task_detail(f: &mut Frame, area: Rect, theme: &Theme, lines: Option<Vec<Line<'static>>>) {
+    let lines =
+        lines.unwrap_or_else(|| vec![Line::from(Span::styled("No task selected", Style::default().fg(theme.dim)))]);
+    let pane = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(" Details ")
+            .title_style(Style::default().fg(theme.text))
+            .border_style(Style::default().fg(theme.dim)),
+    );
+    f.render_widget(pane, area);
+}
@@ -4,26 +4,33 @@
 //! It supports multiple view modes (Today, Tomorrow, Upcoming, Projects, Labels) and
 //! handles task selection, keyboard navigation, and user interactions.
 
-use crate::config::DisplayConfig;
-use crate::constants::{HEADER_OVERDUE, HEADER_TODAY, HEADER_TOMORROW};
+use crate::config::{DisplayConfig, SelectionAfterRemoval, TaskSort, ViewsConfig};
+use crate::constants::{
+    HEADER_NO_LABELS, HEADER_OVERDUE, HEADER_TODAY, HEADER_TOMORROW, UPCOMING_DEFAULT_DAYS, UPCOMING_ZOOM_STEPS,
+};
 use crate::entities::{label, project, section, task};
-use crate::icons::IconService;
+use crate::icons::{IconService, IconTheme};
+use crate::ui::components::breadcrumb::{format_breadcrumb, project_path};
 use crate::ui::components::scrollbar_helper::ScrollbarHelper;
 use crate::ui::components::task_list_item_component::{ListItem, TaskItem, TaskListItemType};
+use crate::ui::core::theme::Theme;
 use crate::ui::core::SidebarSelection;
 use crate::ui::core::{
     actions::{Action, DialogType},
     Component,
 };
 use crate::utils::datetime;
-use chrono::{Duration, Local};
-use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use crate::utils::export::ExportGroup;
+use chrono::Duration;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Block, BorderType, Borders, List, ListItem as RatatuiListItem, ListState},
     Frame,
 };
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
 use uuid::Uuid;
 
 /// Main task list component that displays tasks in various view modes.
@@ -45,13 +52,107 @@ pub struct TaskListComponent {
     pub sections: Vec<section::Model>,
     pub projects: Vec<project::Model>,
     pub labels: Vec<label::Model>,
+    /// Connected backends by UUID and name, for the All tasks view's headers
+    accounts: Vec<(Uuid, String)>,
     pub icons: IconService,
     // Keep raw task data for building items
     pub tasks: Vec<task::Model>,
     pub display_config: DisplayConfig,
+    theme: Theme,
+    pub selection_after_removal: SelectionAfterRemoval,
+    pub views_config: ViewsConfig,
+    /// Temporarily show every priority in Today despite `views_config.today_min_priority`
+    show_all_priorities: bool,
+    /// How many days ahead the Upcoming view currently looks
+    upcoming_days: u32,
+    /// Order of tasks within each group, cycled with `o`
+    sort: TaskSort,
+    /// How tasks are grouped under headers, cycled with `g`
+    grouping: TaskGrouping,
+    /// Label names of each task, for grouping by label
+    task_labels: HashMap<Uuid, Vec<String>>,
+    /// Recently rescheduled recurring tasks and when their highlight expires
+    rescheduled_highlights: HashMap<Uuid, Instant>,
+    /// Completed tasks hidden from the current view until it changes or data is resynced
+    dismissed_completed: HashSet<Uuid>,
+    /// Tasks marked for a bulk complete, delete or move
+    marked_tasks: HashSet<Uuid>,
+    /// Sections and parent tasks folded with `z`, whose tasks are hidden in every view
+    collapsed: HashSet<Uuid>,
+    /// Digits typed so far for a numbered jump and when the last one was typed
+    jump_digits: String,
+    jump_typed_at: Option<Instant>,
     scrollbar_helper: ScrollbarHelper,
 }
 
+/// Next Upcoming horizon after `days` in [`UPCOMING_ZOOM_STEPS`], wider or narrower.
+///
+/// A configured horizon between two steps zooms to the neighbouring step; at either end
+/// the horizon stays as it is.
+pub fn upcoming_zoom(days: u32, wider: bool) -> u32 {
+    if wider {
+        UPCOMING_ZOOM_STEPS.iter().copied().find(|&step| step > days).unwrap_or(days)
+    } else {
+        UPCOMING_ZOOM_STEPS
+            .iter()
+            .rev()
+            .copied()
+            .find(|&step| step < days)
+            .unwrap_or(days)
+    }
+}
+
+/// How the tasks of a view are grouped under headers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskGrouping {
+    /// The view's own grouping: sections in projects, dates in Today and Upcoming
+    #[default]
+    View,
+    /// One group per priority, P1 first
+    Priority,
+    /// One group per label, then tasks without labels; tasks are listed under each of their labels
+    Label,
+}
+
+impl TaskGrouping {
+    /// Grouping after this one, wrapping around to the view's own grouping
+    pub fn next(self) -> Self {
+        match self {
+            TaskGrouping::View => TaskGrouping::Priority,
+            TaskGrouping::Priority => TaskGrouping::Label,
+            TaskGrouping::Label => TaskGrouping::View,
+        }
+    }
+}
+
+/// Reorder tasks for a sort mode, e.g. the results of `SyncService::get_tasks_for_project`.
+///
+/// The sort is stable, so tasks that compare equal keep their storage order, and
+/// `TaskSort::Default` leaves the order unchanged. Completed and deleted tasks stay after
+/// open ones. Dated tasks sort by day, with all-day tasks before timed ones on the same day.
+pub fn sort_tasks(tasks: &mut [task::Model], sort: TaskSort) {
+    let is_closed = |task: &task::Model| task.is_completed || task.is_deleted;
+    match sort {
+        TaskSort::Default => {}
+        TaskSort::Priority => tasks.sort_by_key(|task| (is_closed(task), std::cmp::Reverse(task.priority))),
+        TaskSort::DueDate => tasks.sort_by_key(|task| {
+            let due = task.due().map(|due| {
+                let time = match due {
+                    datetime::Due::Date(_) => None,
+                    datetime::Due::DateTime(at) => Some(at.time()),
+                };
+                (due.date(), time)
+            });
+            // Tasks without a due date go last
+            (is_closed(task), due.is_none(), due)
+        }),
+        TaskSort::Content => tasks.sort_by_cached_key(|task| (is_closed(task), task.content.to_lowercase())),
+    }
+}
+
+/// How long a typed task number waits for another digit before a new number starts
+pub const TASK_JUMP_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(1000);
+
 impl Default for TaskListComponent {
     fn default() -> Self {
         Self::new()
@@ -69,8 +170,23 @@ impl TaskListComponent {
             sections: Vec::new(),
             projects: Vec::new(),
             labels: Vec::new(),
+            accounts: Vec::new(),
             icons: IconService::default(),
             display_config: DisplayConfig::default(),
+            theme: Theme::default(),
+            selection_after_removal: SelectionAfterRemoval::default(),
+            views_config: ViewsConfig::default(),
+            show_all_priorities: false,
+            upcoming_days: UPCOMING_DEFAULT_DAYS,
+            sort: TaskSort::default(),
+            grouping: TaskGrouping::default(),
+            task_labels: HashMap::new(),
+            rescheduled_highlights: HashMap::new(),
+            dismissed_completed: HashSet::new(),
+            marked_tasks: HashSet::new(),
+            collapsed: HashSet::new(),
+            jump_digits: String::new(),
+            jump_typed_at: None,
             scrollbar_helper: ScrollbarHelper::new(),
         }
     }
@@ -79,6 +195,195 @@ impl TaskListComponent {
         self.display_config = display_config;
     }
 
+    /// Set the colors used to draw the task list
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Set the icon set used to draw the task list
+    pub fn set_icon_theme(&mut self, icon_theme: IconTheme) {
+        self.icons.set_theme(icon_theme);
+    }
+
+    pub fn update_views_config(&mut self, views_config: ViewsConfig) {
+        self.views_config = views_config;
+    }
+
+    /// Set the connected backends, in display order
+    pub fn set_accounts(&mut self, accounts: Vec<(Uuid, String)>) {
+        self.accounts = accounts;
+    }
+
+    /// Whether Today currently hides tasks below `today_min_priority`
+    pub fn is_priority_filter_active(&self) -> bool {
+        matches!(self.sidebar_selection, SidebarSelection::Today)
+            && self.views_config.today_min_priority > 0
+            && !self.show_all_priorities
+    }
+
+    /// Switch Today between the configured minimum priority and all priorities.
+    ///
+    /// Returns `true` if all priorities are shown afterwards.
+    pub fn toggle_show_all_priorities(&mut self) -> bool {
+        self.show_all_priorities = !self.show_all_priorities;
+        self.rebuild_keeping_selection();
+        self.show_all_priorities
+    }
+
+    pub fn upcoming_days(&self) -> u32 {
+        self.upcoming_days
+    }
+
+    pub fn set_upcoming_days(&mut self, days: u32) {
+        self.upcoming_days = days;
+    }
+
+    pub fn sort(&self) -> TaskSort {
+        self.sort
+    }
+
+    /// Change the order of tasks within each group, keeping the selected task selected
+    pub fn set_sort(&mut self, sort: TaskSort) {
+        if self.sort != sort {
+            self.sort = sort;
+            self.rebuild_keeping_selection();
+        }
+    }
+
+    pub fn grouping(&self) -> TaskGrouping {
+        self.grouping
+    }
+
+    /// Change how tasks are grouped, keeping the selected task selected
+    pub fn set_grouping(&mut self, grouping: TaskGrouping) {
+        if self.grouping != grouping {
+            self.grouping = grouping;
+            self.rebuild_keeping_selection();
+        }
+    }
+
+    /// Set the label names of each task, used when grouping by label
+    pub fn set_task_labels(&mut self, task_labels: HashMap<Uuid, Vec<String>>) {
+        self.task_labels = task_labels;
+        if self.grouping == TaskGrouping::Label {
+            self.rebuild_keeping_selection();
+        }
+    }
+
+    pub fn set_selection_after_removal(&mut self, behavior: SelectionAfterRemoval) {
+        self.selection_after_removal = behavior;
+    }
+
+    /// Highlight a completed recurring task until `until`, so its move to the next
+    /// occurrence is visible instead of a silent re-sort
+    pub fn highlight_rescheduled(&mut self, task_uuid: Uuid, until: Instant) {
+        self.rescheduled_highlights.insert(task_uuid, until);
+        self.rebuild_keeping_selection();
+    }
+
+    /// Whether a task is currently highlighted as rescheduled
+    pub fn is_rescheduled_highlighted(&self, task_uuid: &Uuid) -> bool {
+        self.rescheduled_highlights.contains_key(task_uuid)
+    }
+
+    /// Whether any rescheduled highlight is still active
+    pub fn has_rescheduled_highlights(&self) -> bool {
+        !self.rescheduled_highlights.is_empty()
+    }
+
+    /// Drop highlights that expired at `now`, returning true if any was removed
+    pub fn clear_expired_highlights(&mut self, now: Instant) -> bool {
+        let before = self.rescheduled_highlights.len();
+        self.rescheduled_highlights.retain(|_, until| *until > now);
+        if self.rescheduled_highlights.len() == before {
+            return false;
+        }
+
+        // Tasks kept visible only by their highlight may leave the view now
+        self.rebuild_keeping_selection();
+        true
+    }
+
+    /// The tasks as currently listed, under their headers, for exporting the view
+    ///
+    /// Grouping, sorting, filters and folds apply as on screen.
+    pub fn export_groups(&self) -> Vec<ExportGroup> {
+        let mut groups: Vec<ExportGroup> = Vec::new();
+        for item in &self.items {
+            match item {
+                TaskListItemType::Header(header) => groups.push(ExportGroup {
+                    header: Some(header.text.clone()),
+                    tasks: Vec::new(),
+                }),
+                TaskListItemType::Task(task_item) => match groups.last_mut() {
+                    Some(group) => group.tasks.push(task_item.task.clone()),
+                    None => groups.push(ExportGroup {
+                        header: None,
+                        tasks: vec![task_item.task.clone()],
+                    }),
+                },
+                TaskListItemType::Separator(_) => {}
+            }
+        }
+        groups
+    }
+
+    /// Number of tasks listed, folded subtasks excepted
+    pub fn task_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item| matches!(item, TaskListItemType::Task(_)))
+            .count()
+    }
+
+    /// Label names of each task, as loaded for grouping by label
+    pub fn task_labels(&self) -> &HashMap<Uuid, Vec<String>> {
+        &self.task_labels
+    }
+
+    /// Fold or unfold the selected task's subtasks, or its section when it has none
+    ///
+    /// Returns whether anything changed: a task without subtasks outside a section has
+    /// nothing to fold.
+    pub fn toggle_collapse_selected(&mut self) -> bool {
+        let Some(task) = self.get_selected_task() else {
+            return false;
+        };
+        let target = if self.get_child_task_count(&task.uuid) > 0 {
+            task.uuid
+        } else {
+            match (task.section_uuid, &self.sidebar_selection) {
+                (Some(section_uuid), SidebarSelection::Project(_)) => section_uuid,
+                _ => return false,
+            }
+        };
+        if !self.collapsed.remove(&target) {
+            self.collapsed.insert(target);
+        }
+        self.rebuild_keeping_selection();
+        true
+    }
+
+    /// Unfold every collapsed section and parent task
+    pub fn expand_all(&mut self) {
+        if !self.collapsed.is_empty() {
+            self.collapsed.clear();
+            self.rebuild_keeping_selection();
+        }
+    }
+
+    pub fn is_collapsed(&self, uuid: &Uuid) -> bool {
+        self.collapsed.contains(uuid)
+    }
+
+    /// Rebuild the item list from the current data, keeping the selected task selected
+    fn rebuild_keeping_selection(&mut self) {
+        let previous_task_uuid = self.get_selected_task().map(|task| task.uuid);
+        self.build_item_list();
+        self.restore_selection(previous_task_uuid);
+        self.update_list_state();
+    }
+
     pub fn update_data(
         &mut self,
         tasks: Vec<task::Model>,
@@ -87,6 +392,21 @@ impl TaskListComponent {
         labels: Vec<label::Model>,
         sidebar_selection: SidebarSelection,
     ) {
+        // Remember the selected task so the selection can follow it across reloads
+        let previous_task_uuid = self.get_selected_task().map(|task| task.uuid);
+
+        // Dismissed completed tasks only stay hidden while the same view is shown
+        if self.sidebar_selection != sidebar_selection {
+            self.dismissed_completed.clear();
+            self.marked_tasks.clear();
+        }
+        // Marks only apply to open tasks that are still around
+        self.marked_tasks.retain(|uuid| {
+            tasks
+                .iter()
+                .any(|task| task.uuid == *uuid && !task.is_completed && !task.is_deleted)
+        });
+
         self.tasks = tasks;
         self.sections = sections;
         self.projects = projects;
@@ -95,9 +415,207 @@ impl TaskListComponent {
 
         // Build the flat list of items from the hierarchical task data
         self.build_item_list();
+        self.restore_selection(previous_task_uuid);
         self.update_list_state();
     }
 
+    /// Hide the completed tasks of the current view without touching the backend.
+    ///
+    /// Returns the number of tasks hidden. They come back when another view is selected
+    /// or after the next sync (see [`Self::reset_dismissed_completed`]).
+    pub fn dismiss_completed(&mut self) -> usize {
+        let completed: Vec<Uuid> = self
+            .visible_tasks()
+            .filter(|task| task.is_completed)
+            .map(|task| task.uuid)
+            .collect();
+        self.dismissed_completed.extend(completed.iter().copied());
+        if !completed.is_empty() {
+            self.rebuild_keeping_selection();
+        }
+        completed.len()
+    }
+
+    /// Show previously dismissed completed tasks again
+    pub fn reset_dismissed_completed(&mut self) {
+        if !self.dismissed_completed.is_empty() {
+            self.dismissed_completed.clear();
+            self.rebuild_keeping_selection();
+        }
+    }
+
+    /// Mark or unmark the selected task for a bulk action
+    pub fn toggle_mark_selected(&mut self) {
+        let Some(task) = self.get_selected_task() else {
+            return;
+        };
+        if task.is_completed || task.is_deleted {
+            return;
+        }
+        let task_uuid = task.uuid;
+        if !self.marked_tasks.remove(&task_uuid) {
+            self.marked_tasks.insert(task_uuid);
+        }
+        self.refresh_marks();
+    }
+
+    /// Mark every open task shown in the current view
+    pub fn mark_all(&mut self) {
+        let open_tasks: Vec<Uuid> = self
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                TaskListItemType::Task(task_item) if !task_item.task.is_completed && !task_item.task.is_deleted => {
+                    Some(task_item.task.uuid)
+                }
+                _ => None,
+            })
+            .collect();
+        self.marked_tasks.extend(open_tasks);
+        self.refresh_marks();
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.marked_tasks.clear();
+        self.refresh_marks();
+    }
+
+    pub fn has_marks(&self) -> bool {
+        !self.marked_tasks.is_empty()
+    }
+
+    /// Marked tasks in the order they are listed
+    ///
+    /// A task listed more than once (under several labels) is only included once.
+    pub fn marked_task_uuids(&self) -> Vec<Uuid> {
+        let mut seen = HashSet::new();
+        self.items
+            .iter()
+            .filter_map(|item| match item {
+                TaskListItemType::Task(task_item) if self.marked_tasks.contains(&task_item.task.uuid) => {
+                    Some(task_item.task.uuid)
+                }
+                _ => None,
+            })
+            .filter(|uuid| seen.insert(*uuid))
+            .collect()
+    }
+
+    /// Update the mark shown on each row after the marks changed
+    fn refresh_marks(&mut self) {
+        for item in &mut self.items {
+            if let TaskListItemType::Task(task_item) = item {
+                task_item.marked = self.marked_tasks.contains(&task_item.task.uuid);
+            }
+        }
+    }
+
+    /// Panel title for a panel `width` columns wide.
+    ///
+    /// Nested projects get a breadcrumb of their ancestors ("Tasks: Work › Backend › Auth"),
+    /// abbreviated when it doesn't fit between the panel borders. Today shows whether
+    /// `today_min_priority` is hiding tasks and the key that toggles it; Upcoming shows
+    /// how many days ahead it looks. A grouping or sort mode other than the default is named
+    /// at the end. While tasks are marked, the title lists the bulk actions instead.
+    pub fn title(&self, width: u16) -> String {
+        if self.has_marks() {
+            return self.view_title(width);
+        }
+        let mut suffix = String::new();
+        match self.grouping {
+            TaskGrouping::View => {}
+            TaskGrouping::Priority => suffix.push_str(" · grouped by priority (g: group)"),
+            TaskGrouping::Label => suffix.push_str(" · grouped by label (g: group)"),
+        }
+        if self.sort != TaskSort::Default {
+            suffix.push_str(&format!(" · by {} (o: sort)", self.sort.label()));
+        }
+        let width = width.saturating_sub(u16::try_from(suffix.chars().count()).unwrap_or(u16::MAX));
+        format!("{}{}", self.view_title(width), suffix)
+    }
+
+    /// Title of the current view, without the grouping and sort modes
+    fn view_title(&self, width: u16) -> String {
+        const PREFIX: &str = "Tasks: ";
+        if self.has_marks() {
+            return format!(
+                "Tasks: {} marked (Space: complete, d: delete, M: move, Esc: clear)",
+                self.marked_tasks.len()
+            );
+        }
+
+        let min_priority = self.views_config.today_min_priority;
+        if matches!(self.sidebar_selection, SidebarSelection::Today) && min_priority > 0 {
+            return if self.show_all_priorities {
+                "Tasks: all priorities (P: filter)".to_string()
+            } else if min_priority == 1 {
+                "Tasks: P1 only (P: show all)".to_string()
+            } else {
+                format!("Tasks: P1-P{} only (P: show all)", min_priority)
+            };
+        }
+
+        if matches!(self.sidebar_selection, SidebarSelection::Upcoming) {
+            let days = self.upcoming_days;
+            return format!(
+                "Tasks: Upcoming · next {} {} (+/-: zoom)",
+                days,
+                if days == 1 { "day" } else { "days" }
+            );
+        }
+
+        let SidebarSelection::Project(index) = &self.sidebar_selection else {
+            return "Tasks".to_string();
+        };
+        let Some(project) = self.projects.get(*index) else {
+            return "Tasks".to_string();
+        };
+        let path = project_path(&self.projects, &project.uuid);
+        if path.len() < 2 {
+            return "Tasks".to_string();
+        }
+
+        // Leave room for the borders and rounded corners on both sides
+        let max_width = (width as usize).saturating_sub(4 + PREFIX.chars().count());
+        format!("{}{}", PREFIX, format_breadcrumb(&path, max_width))
+    }
+
+    /// Tasks of the current view that were not dismissed or filtered out by priority
+    fn visible_tasks(&self) -> impl Iterator<Item = &task::Model> {
+        let priority_filter = self.is_priority_filter_active();
+        self.tasks.iter().filter(move |task| {
+            !self.dismissed_completed.contains(&task.uuid)
+                && (!priority_filter || self.views_config.today_includes_priority(task.priority))
+        })
+    }
+
+    /// Re-select the previously selected task after the item list was rebuilt.
+    ///
+    /// If the task is gone (completed or deleted by a sync), the selection falls back
+    /// according to `selection_after_removal`.
+    fn restore_selection(&mut self, previous_task_uuid: Option<Uuid>) {
+        let Some(task_uuid) = previous_task_uuid else {
+            return;
+        };
+
+        match self.logical_index_of_task(&task_uuid) {
+            Some(logical_index) => self.selected_index = logical_index,
+            None => match self.selection_after_removal {
+                // Keep the previous index; update_list_state clamps it to the last task
+                SelectionAfterRemoval::Nearest => {}
+                SelectionAfterRemoval::Top => self.selected_index = 0,
+            },
+        }
+    }
+
+    /// Find the logical selection index of a task in the current item list
+    fn logical_index_of_task(&self, task_uuid: &Uuid) -> Option<usize> {
+        self.items
+            .iter()
+            .filter(|item| item.is_selectable())
+            .position(|item| matches!(item, TaskListItemType::Task(task_item) if task_item.task.uuid == *task_uuid))
+    }
+
     /// Build the flat list of items from task data
     fn build_item_list(&mut self) {
         self.items.clear();
@@ -106,11 +624,18 @@ impl TaskListComponent {
             return;
         }
 
+        if self.grouping != TaskGrouping::View {
+            self.build_grouped_items();
+            return;
+        }
+
         // Handle different sidebar selections with appropriate sectioning
         match &self.sidebar_selection {
             SidebarSelection::Today => self.build_today_items(),
             SidebarSelection::Tomorrow => self.build_tomorrow_items(),
             SidebarSelection::Upcoming => self.build_upcoming_items(),
+            SidebarSelection::Postponed | SidebarSelection::Filter(_) => self.build_postponed_items(),
+            SidebarSelection::All => self.build_all_items(),
             SidebarSelection::Project(index) => {
                 if let Some(project) = self.projects.get(*index) {
                     let project_id = project.uuid;
@@ -134,23 +659,25 @@ impl TaskListComponent {
     fn build_today_items(&mut self) {
         use crate::ui::components::task_list_item_component::{HeaderItem, SeparatorItem};
 
-        let now = chrono::Local::now().date_naive();
+        let now = datetime::today();
         let mut overdue_tasks = Vec::new();
         let mut today_tasks = Vec::new();
 
         // Separate tasks by date (only root tasks - subtasks will be added recursively)
-        for task in self.tasks.iter().filter(|t| t.parent_uuid.is_none()) {
-            if let Some(due_date_str) = &task.due_date {
-                if let Ok(due_date) = datetime::parse_date(due_date_str) {
-                    if due_date < now {
-                        overdue_tasks.push(task.clone());
-                    } else if due_date == now {
-                        today_tasks.push(task.clone());
-                    }
+        for task in self.visible_tasks().filter(|t| t.parent_uuid.is_none()) {
+            if let Some(due_date) = task.due().map(|due| due.date()) {
+                if due_date < now {
+                    overdue_tasks.push(task.clone());
+                } else if due_date == now || self.is_rescheduled_highlighted(&task.uuid) {
+                    // Rescheduled recurring tasks stay visible until their highlight expires
+                    today_tasks.push(task.clone());
                 }
             }
         }
 
+        sort_tasks(&mut overdue_tasks, self.sort);
+        sort_tasks(&mut today_tasks, self.sort);
+
         // Add overdue section if there are overdue tasks
         if !overdue_tasks.is_empty() {
             self.items
@@ -187,29 +714,23 @@ impl TaskListComponent {
         )));
 
         // Calculate tomorrow's date
-        let today = Local::now().date_naive();
+        let today = datetime::today();
         let tomorrow = today + Duration::days(1);
 
         // Filter for root tasks due tomorrow
-        let tasks: Vec<task::Model> = self
+        let mut tasks: Vec<task::Model> = self
             .tasks
             .iter()
             .filter(|t| t.parent_uuid.is_none())
             .filter(|t| {
-                if let Some(due_date_str) = &t.due_date {
-                    if let Ok(due_date) = datetime::parse_date(due_date_str) {
-                        due_date == tomorrow
-                    } else {
-                        false
-                    }
-                } else {
-                    false
-                }
+                t.due()
+                    .is_some_and(|due| due.date() == tomorrow || self.is_rescheduled_highlighted(&t.uuid))
             })
             .cloned()
             .collect();
 
         // SQL already provides proper ordering (completion status -> priority -> order_index)
+        sort_tasks(&mut tasks, self.sort);
 
         for task in tasks {
             self.add_task_and_children_to_items(task, 0);
@@ -221,23 +742,26 @@ impl TaskListComponent {
         use crate::ui::components::task_list_item_component::{HeaderItem, SeparatorItem};
         use std::collections::BTreeMap;
 
-        let today = chrono::Local::now().date_naive();
+        let today = datetime::today();
         let mut overdue_tasks = Vec::new();
         let mut future_tasks_by_date: BTreeMap<chrono::NaiveDate, Vec<task::Model>> = BTreeMap::new();
 
         // Group tasks by date (only root tasks - subtasks will be added recursively)
-        for task in self.tasks.iter().filter(|t| t.parent_uuid.is_none()) {
-            if let Some(due_date_str) = &task.due_date {
-                if let Ok(due_date) = datetime::parse_date(due_date_str) {
-                    if due_date < today {
-                        overdue_tasks.push(task.clone());
-                    } else {
-                        future_tasks_by_date.entry(due_date).or_default().push(task.clone());
-                    }
+        for task in self.visible_tasks().filter(|t| t.parent_uuid.is_none()) {
+            if let Some(due_date) = task.due().map(|due| due.date()) {
+                if due_date < today {
+                    overdue_tasks.push(task.clone());
+                } else {
+                    future_tasks_by_date.entry(due_date).or_default().push(task.clone());
                 }
             }
         }
 
+        sort_tasks(&mut overdue_tasks, self.sort);
+        for tasks in future_tasks_by_date.values_mut() {
+            sort_tasks(tasks, self.sort);
+        }
+
         // Add overdue section first
         if !overdue_tasks.is_empty() {
             self.items
@@ -276,7 +800,7 @@ impl TaskListComponent {
 
     /// Build items for Project view (with section headers)
     fn build_project_items(&mut self, project_id: &Uuid) {
-        use crate::ui::components::task_list_item_component::{HeaderItem, SeparatorItem};
+        use crate::ui::components::task_list_item_component::{HeaderItem, SeparatorItem, COLLAPSED_GLYPH};
         use std::collections::HashMap;
 
         // Get sections for the current project
@@ -289,11 +813,14 @@ impl TaskListComponent {
 
         // Group tasks by section (only root tasks - subtasks will be added recursively)
         let mut tasks_by_section: HashMap<Option<Uuid>, Vec<task::Model>> = HashMap::new();
-        for task in self.tasks.iter().filter(|t| t.parent_uuid.is_none()) {
+        for task in self.visible_tasks().filter(|t| t.parent_uuid.is_none()) {
             if &task.project_uuid == project_id {
                 tasks_by_section.entry(task.section_uuid).or_default().push(task.clone());
             }
         }
+        for tasks in tasks_by_section.values_mut() {
+            sort_tasks(tasks, self.sort);
+        }
 
         // Add tasks without sections first
         if let Some(tasks_without_section) = tasks_by_section.get(&None) {
@@ -310,6 +837,16 @@ impl TaskListComponent {
                     self.items.push(TaskListItemType::Separator(SeparatorItem::new(0)));
                 }
 
+                // A collapsed section keeps its header, with the number of tasks it hides
+                if self.collapsed.contains(&section.uuid) {
+                    let hidden = self.visible_tasks().filter(|t| t.section_uuid == Some(section.uuid)).count();
+                    self.items.push(TaskListItemType::Header(HeaderItem::new(
+                        format!("{} {} ({} hidden)", COLLAPSED_GLYPH, section.name, hidden),
+                        0,
+                    )));
+                    continue;
+                }
+
                 // Add section header
                 self.items
                     .push(TaskListItemType::Header(HeaderItem::new(section.name.clone(), 0)));
@@ -324,22 +861,164 @@ impl TaskListComponent {
     /// Build items for Label view
     fn build_label_items(&mut self, _label_id: &Uuid) {
         // Filter tasks that have the specific label (only root tasks - subtasks will be added recursively)
-        let filtered_tasks: Vec<task::Model> = self
-            .tasks
-            .iter()
+        let mut filtered_tasks: Vec<task::Model> = self
+            .visible_tasks()
             .filter(|task| task.parent_uuid.is_none()) // TODO: Add label filtering
             .cloned()
             .collect();
+        sort_tasks(&mut filtered_tasks, self.sort);
 
         for task in filtered_tasks {
             self.add_task_and_children_to_items(task, 0);
         }
     }
 
+    /// Build items for the All tasks view
+    ///
+    /// Tasks are grouped under a header per project, in sidebar order. With several
+    /// backends, projects are ordered by backend and the headers name it too.
+    fn build_all_items(&mut self) {
+        use crate::ui::components::task_list_item_component::{HeaderItem, SeparatorItem};
+
+        let mut projects = self.projects.clone();
+        let several_accounts = self.accounts.len() > 1;
+        if several_accounts {
+            projects.sort_by_key(|project| {
+                self.accounts
+                    .iter()
+                    .position(|(uuid, _)| *uuid == project.backend_uuid)
+                    .unwrap_or(usize::MAX)
+            });
+        }
+
+        for project in projects {
+            let mut tasks: Vec<task::Model> = self
+                .visible_tasks()
+                .filter(|t| t.parent_uuid.is_none() && t.project_uuid == project.uuid)
+                .cloned()
+                .collect();
+            if tasks.is_empty() {
+                continue;
+            }
+            sort_tasks(&mut tasks, self.sort);
+
+            let header = match self.accounts.iter().find(|(uuid, _)| *uuid == project.backend_uuid) {
+                Some((_, account)) if several_accounts => format!("{} › {}", account, project.name),
+                _ => project.name.clone(),
+            };
+            if !self.items.is_empty() {
+                self.items.push(TaskListItemType::Separator(SeparatorItem::new(0)));
+            }
+            self.items.push(TaskListItemType::Header(HeaderItem::new(header, 0)));
+            for task in tasks {
+                self.add_task_and_children_to_items(task, 0);
+            }
+        }
+    }
+
+    /// Build items for the Postponed view and saved filters
+    ///
+    /// Tasks are listed flat in storage order (most postponed first for Postponed),
+    /// subtasks included, so each task appears once at its own rank.
+    fn build_postponed_items(&mut self) {
+        let mut tasks: Vec<task::Model> = self.visible_tasks().cloned().collect();
+        sort_tasks(&mut tasks, self.sort);
+        for task in tasks {
+            self.add_flat_task_to_items(task);
+        }
+    }
+
+    /// Add a task without its children, as in the Postponed view
+    fn add_flat_task_to_items(&mut self, task: task::Model) {
+        let child_count = self.get_child_task_count(&task.uuid);
+        let mut task_item = TaskItem::new(
+            task.clone(),
+            0,
+            child_count,
+            self.icons.clone(),
+            self.projects.clone(),
+            Vec::new(),
+        );
+        task_item.rescheduled = self.is_rescheduled_highlighted(&task.uuid);
+        task_item.marked = self.marked_tasks.contains(&task.uuid);
+        task_item.in_aggregate_view = true;
+        self.items.push(TaskListItemType::Task(Box::new(task_item)));
+    }
+
+    /// Build items grouped by priority or label instead of the view's own grouping
+    ///
+    /// Empty groups are left out. Views that list subtasks flat (Postponed, saved filters)
+    /// group every task; the others group root tasks, with subtasks under their parent.
+    fn build_grouped_items(&mut self) {
+        use crate::ui::components::task_list_item_component::{HeaderItem, SeparatorItem};
+
+        let flat = matches!(
+            self.sidebar_selection,
+            SidebarSelection::Postponed | SidebarSelection::Filter(_)
+        );
+        let mut tasks: Vec<task::Model> = self
+            .visible_tasks()
+            .filter(|t| flat || t.parent_uuid.is_none())
+            .cloned()
+            .collect();
+        sort_tasks(&mut tasks, self.sort);
+
+        let has_label = |task: &task::Model, name: &str| {
+            self.task_labels
+                .get(&task.uuid)
+                .is_some_and(|names| names.iter().any(|n| n == name))
+        };
+        let groups: Vec<(String, Vec<task::Model>)> = match self.grouping {
+            TaskGrouping::View => Vec::new(),
+            TaskGrouping::Priority => (1..=4)
+                .map(|level| {
+                    let group = tasks.iter().filter(|t| t.priority == 5 - level).cloned().collect();
+                    (format!("Priority {}", level), group)
+                })
+                .collect(),
+            TaskGrouping::Label => {
+                let mut groups: Vec<(String, Vec<task::Model>)> = self
+                    .labels
+                    .iter()
+                    .map(|label| {
+                        let group = tasks.iter().filter(|&t| has_label(t, &label.name)).cloned().collect();
+                        (format!("@{}", label.name), group)
+                    })
+                    .collect();
+                let unlabeled = tasks
+                    .iter()
+                    .filter(|&t| !self.labels.iter().any(|label| has_label(t, &label.name)))
+                    .cloned()
+                    .collect();
+                groups.push((HEADER_NO_LABELS.to_string(), unlabeled));
+                groups
+            }
+        };
+
+        for (name, group) in groups {
+            if group.is_empty() {
+                continue;
+            }
+            if !self.items.is_empty() {
+                self.items.push(TaskListItemType::Separator(SeparatorItem::new(0)));
+            }
+            self.items.push(TaskListItemType::Header(HeaderItem::new(name, 0)));
+            for task in group {
+                if flat {
+                    self.add_flat_task_to_items(task);
+                } else {
+                    self.add_task_and_children_to_items(task, 0);
+                }
+            }
+        }
+    }
+
     /// Build simple items (no sectioning)
     fn build_simple_items(&mut self) {
         // SQL already provides proper ordering (completion status -> priority -> order_index)
-        let root_tasks: Vec<task::Model> = self.tasks.iter().filter(|t| t.parent_uuid.is_none()).cloned().collect();
+        let mut root_tasks: Vec<task::Model> =
+            self.visible_tasks().filter(|t| t.parent_uuid.is_none()).cloned().collect();
+        sort_tasks(&mut root_tasks, self.sort);
 
         // Add each root task and its children recursively
         for task in root_tasks {
@@ -357,7 +1036,7 @@ impl TaskListComponent {
         let task_labels = Vec::new();
 
         // Create and add the task item
-        let task_item = TaskItem::new(
+        let mut task_item = TaskItem::new(
             task.clone(),
             depth,
             child_count,
@@ -365,16 +1044,24 @@ impl TaskListComponent {
             self.projects.clone(),
             task_labels,
         );
+        task_item.rescheduled = self.is_rescheduled_highlighted(&task.uuid);
+        task_item.marked = self.marked_tasks.contains(&task.uuid);
+        task_item.in_aggregate_view = !matches!(self.sidebar_selection, SidebarSelection::Project(_));
+        task_item.collapsed = child_count > 0 && self.collapsed.contains(&task.uuid);
+        let collapsed = task_item.collapsed;
         self.items.push(TaskListItemType::Task(Box::new(task_item)));
+        if collapsed {
+            return;
+        }
 
         // Find and add children
         let task_id = task.uuid;
-        let children: Vec<task::Model> = self
-            .tasks
-            .iter()
+        let mut children: Vec<task::Model> = self
+            .visible_tasks()
             .filter(|t| t.parent_uuid.as_ref() == Some(&task_id))
             .cloned()
             .collect();
+        sort_tasks(&mut children, self.sort);
 
         // Children are already ordered by SQL query (completion status -> priority -> order_index)
 
@@ -484,13 +1171,18 @@ impl TaskListComponent {
                 }
                 Action::None
             }
-            // Mouse wheel for scrolling
+            // Mouse wheel moves the selection, stopping at the ends of the list
             MouseEventKind::ScrollUp => {
-                self.previous_task();
+                if self.selected_index > 0 {
+                    self.previous_task();
+                }
                 Action::None
             }
             MouseEventKind::ScrollDown => {
-                self.next_task();
+                let selectable_count = self.items.iter().filter(|item| item.is_selectable()).count();
+                if self.selected_index + 1 < selectable_count {
+                    self.next_task();
+                }
                 Action::None
             }
             _ => Action::None,
@@ -504,14 +1196,67 @@ impl TaskListComponent {
 
     /// Create the list items for rendering
     fn create_list_items(&self, _rect: Rect) -> Vec<RatatuiListItem<'static>> {
+        if !self.display_config.show_task_numbers {
+            return self
+                .items
+                .iter()
+                .map(|item| {
+                    item.render(false, &self.display_config, &self.theme) // Selection styling handled by List widget
+                })
+                .collect();
+        }
+
+        // Number the tasks as currently shown, so the numbers follow grouping and filtering
+        let task_count = self.items.iter().filter(|item| item.is_selectable()).count();
+        let width = task_count.to_string().len();
+        let mut number = 0;
         self.items
             .iter()
-            .map(|item| {
-                item.render(false, &self.display_config) // Selection styling handled by List widget
+            .map(|item| match item {
+                TaskListItemType::Task(task_item) => {
+                    number += 1;
+                    task_item.render_numbered(number, width, false, &self.display_config, &self.theme)
+                }
+                other => other.render(false, &self.display_config, &self.theme),
             })
             .collect()
     }
 
+    /// Select the task with the number being typed, extending it with `digit`.
+    ///
+    /// Digits typed within [`TASK_JUMP_TIMEOUT`] of each other form one number, so `1`
+    /// then `2` jumps to task 12. The selection moves with every digit; a digit that
+    /// would point past the last task starts a new number instead.
+    pub fn jump_to_task_digit(&mut self, digit: char, now: Instant) {
+        let Some(value) = digit.to_digit(10) else {
+            return;
+        };
+        let task_count = self.items.iter().filter(|item| item.is_selectable()).count();
+
+        let continues_number = self.jump_typed_at.is_some_and(|at| now.duration_since(at) <= TASK_JUMP_TIMEOUT);
+        if !continues_number {
+            self.jump_digits.clear();
+        }
+        self.jump_typed_at = Some(now);
+        self.jump_digits.push(digit);
+
+        let mut number: usize = self.jump_digits.parse().unwrap_or(0);
+        if number == 0 || number > task_count {
+            self.jump_digits = digit.to_string();
+            number = value as usize;
+        }
+
+        if (1..=task_count).contains(&number) {
+            self.selected_index = number - 1;
+            self.update_list_state();
+        }
+
+        // No further digit can extend this number, so the next one starts over
+        if number == 0 || number * 10 > task_count {
+            self.jump_digits.clear();
+        }
+    }
+
     /// Navigate to the next selectable item
     fn next_task(&mut self) {
         let selectable_count = self.items.iter().filter(|item| item.is_selectable()).count();
@@ -546,6 +1291,76 @@ impl Component for TaskListComponent {
                 self.next_task();
                 Action::None
             }
+            KeyCode::Char('C') => {
+                self.dismiss_completed();
+                Action::None
+            }
+            KeyCode::Char(c @ ('+' | '=' | '-')) if matches!(self.sidebar_selection, SidebarSelection::Upcoming) => {
+                let days = upcoming_zoom(self.upcoming_days, c != '-');
+                if days == self.upcoming_days {
+                    Action::None
+                } else {
+                    Action::SetUpcomingDays(days)
+                }
+            }
+            KeyCode::Char('o') => {
+                self.set_sort(self.sort.next());
+                Action::None
+            }
+            KeyCode::Char('g') => {
+                self.set_grouping(self.grouping.next());
+                Action::None
+            }
+            KeyCode::Char('z') => {
+                self.toggle_collapse_selected();
+                Action::None
+            }
+            KeyCode::Char('Z') => {
+                self.expand_all();
+                Action::None
+            }
+            KeyCode::Char('P') if self.views_config.today_min_priority > 0 => {
+                self.toggle_show_all_priorities();
+                Action::None
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() && self.display_config.show_task_numbers => {
+                self.jump_to_task_digit(c, Instant::now());
+                Action::None
+            }
+            KeyCode::Char('v') => Action::ToggleTaskMark,
+            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::MarkAllTasks,
+            KeyCode::Esc if self.has_marks() => Action::ClearTaskMarks,
+            KeyCode::Enter | KeyCode::Char(' ') if self.has_marks() => Action::BulkComplete(self.marked_task_uuids()),
+            KeyCode::Delete | KeyCode::Char('d') if self.has_marks() => {
+                let task_uuids = self.marked_task_uuids();
+                Action::ShowDialog(DialogType::Confirmation {
+                    message: format!(
+                        "Delete {} marked {}?",
+                        task_uuids.len(),
+                        if task_uuids.len() == 1 { "task" } else { "tasks" }
+                    ),
+                    action: Box::new(Action::BulkDelete(task_uuids)),
+                })
+            }
+            KeyCode::Char('N') if self.has_marks() => Action::ShowDialog(DialogType::NestTasks {
+                task_uuids: self.marked_task_uuids(),
+            }),
+            KeyCode::Char('M') => {
+                // Move the marked tasks, or the selected one when nothing is marked
+                let task_uuids = if self.has_marks() {
+                    self.marked_task_uuids()
+                } else {
+                    self.get_selected_task()
+                        .filter(|task| !task.is_deleted)
+                        .map(|task| vec![task.uuid])
+                        .unwrap_or_default()
+                };
+                if task_uuids.is_empty() {
+                    Action::None
+                } else {
+                    Action::ShowDialog(DialogType::MoveTasks { task_uuids })
+                }
+            }
             KeyCode::Enter | KeyCode::Char(' ') => {
                 if let Some(task) = self.get_selected_task() {
                     // Smart toggle: restore if deleted/completed, otherwise complete
@@ -572,6 +1387,8 @@ impl Component for TaskListComponent {
                         task_uuid: task.uuid,
                         content: task.content.clone(),
                         project_uuid: task.project_uuid,
+                        labels: Vec::new(),
+                        snapshot: Box::new(task.clone()),
                     })
                 } else {
                     Action::None
@@ -613,11 +1430,30 @@ impl Component for TaskListComponent {
                 self.previous_task();
                 Action::None
             }
+            Action::ToggleTaskMark => {
+                self.toggle_mark_selected();
+                Action::None
+            }
+            Action::MarkAllTasks => {
+                self.mark_all();
+                Action::None
+            }
+            Action::ClearTaskMarks => {
+                self.clear_marks();
+                Action::None
+            }
+            Action::BulkComplete(_) | Action::BulkDelete(_) | Action::BulkMove { .. } | Action::BulkNest { .. } => {
+                // The marks are used up once a bulk action runs
+                self.clear_marks();
+                action
+            }
             _ => action,
         }
     }
 
     fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let title = self.title(rect.width);
+
         // Calculate areas for list and scrollbar using helper
         let total_items = self.items.len();
 
@@ -627,8 +1463,13 @@ impl Component for TaskListComponent {
         let tasks_list = if self.items.is_empty() {
             // Show contextual empty state message
             let empty_message = match &self.sidebar_selection {
+                SidebarSelection::Today if self.is_priority_filter_active() => {
+                    "No high-priority tasks due today. Press 'P' to show all priorities."
+                }
                 SidebarSelection::Today => "No tasks due today. Press 'a' to create a task or 'r' to sync.",
                 SidebarSelection::Tomorrow => "No tasks due tomorrow. Press 'a' to create a task or 'r' to sync.",
+                SidebarSelection::Postponed => "No postponed tasks. Nice work!",
+                SidebarSelection::Filter(_) => "No tasks match this filter",
                 _ if self.projects.is_empty() => "No projects available. Press 'r' to sync or 'A' to create a project.",
                 _ => "No tasks in this view. Press 'a' to create a task.",
             };
@@ -636,15 +1477,15 @@ impl Component for TaskListComponent {
             List::new(vec![RatatuiListItem::new(empty_message)])
         } else {
             List::new(self.create_list_items(list_area))
-                .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+                .highlight_style(Style::default().bg(self.theme.selected_bg).add_modifier(Modifier::BOLD))
         }
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title("Tasks")
-                .title_style(Style::default().fg(Color::White))
-                .border_style(Style::default().fg(Color::DarkGray)),
+                .title(title)
+                .title_style(Style::default().fg(self.theme.text))
+                .border_style(Style::default().fg(self.theme.dim)),
         );
 
         // Update scrollbar state with current position and viewport info
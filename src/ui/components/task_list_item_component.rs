@@ -1,10 +1,14 @@
 use crate::config::DisplayConfig;
+use crate::config::{LabelStyle, PriorityStyle};
 use crate::entities::{project, task};
 use crate::icons::IconService;
-use crate::ui::components::badge::{create_priority_badge, create_task_badges};
-use crate::utils::datetime::{format_human_date, format_human_datetime};
+use crate::ui::components::badge::{
+    create_deadline_badge, create_label_badges, create_priority_badge, create_task_badges,
+};
+use crate::ui::core::theme::Theme;
+use crate::utils::{color, datetime};
 use ratatui::{
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::ListItem as RatatuiListItem,
 };
@@ -12,10 +16,22 @@ use ratatui::{
 /// Width of indentation per depth level in characters
 const INDENT_WIDTH: usize = 2;
 
+/// Gutter bar drawn in the project's color with `project_color_coding`
+pub const PROJECT_BAR: &str = "▎";
+
+/// Bar in the priority color at the start of a row with the `bar` priority style
+pub const PRIORITY_BAR: &str = "▌";
+
+/// Glyph in front of tasks marked for a bulk action
+pub const MARK_GLYPH: &str = "●";
+
+/// Glyph on collapsed sections and parent tasks whose subtasks are hidden
+pub const COLLAPSED_GLYPH: &str = "▸";
+
 /// Trait for items that can be displayed in a task list
 pub trait ListItem {
     /// Render this item as a ratatui ListItem
-    fn render(&self, selected: bool, display_config: &DisplayConfig) -> RatatuiListItem<'static>;
+    fn render(&self, selected: bool, display_config: &DisplayConfig, theme: &Theme) -> RatatuiListItem<'static>;
 
     /// Whether this item can be selected by the user
     fn is_selectable(&self) -> bool;
@@ -33,11 +49,11 @@ pub enum TaskListItemType {
 }
 
 impl ListItem for TaskListItemType {
-    fn render(&self, selected: bool, display_config: &DisplayConfig) -> RatatuiListItem<'static> {
+    fn render(&self, selected: bool, display_config: &DisplayConfig, theme: &Theme) -> RatatuiListItem<'static> {
         match self {
-            Self::Task(item) => item.render(selected, display_config),
-            Self::Header(item) => item.render(selected, display_config),
-            Self::Separator(item) => item.render(selected, display_config),
+            Self::Task(item) => item.render(selected, display_config, theme),
+            Self::Header(item) => item.render(selected, display_config, theme),
+            Self::Separator(item) => item.render(selected, display_config, theme),
         }
     }
 
@@ -67,6 +83,14 @@ pub struct TaskItem {
     pub icons: IconService,
     pub projects: Vec<project::Model>,
     pub labels: Vec<crate::entities::label::Model>,
+    /// Recurring task that was just completed and moved to its next occurrence
+    pub rescheduled: bool,
+    /// Marked for a bulk complete, delete or move
+    pub marked: bool,
+    /// Listed in a view mixing projects, where `project_color_coding` marks its project
+    pub in_aggregate_view: bool,
+    /// Subtasks folded away with `z`
+    pub collapsed: bool,
 }
 
 impl TaskItem {
@@ -85,31 +109,71 @@ impl TaskItem {
             icons,
             projects,
             labels,
+            rescheduled: false,
+            marked: false,
+            in_aggregate_view: false,
+            collapsed: false,
         }
     }
 
-    fn format_due_date(&self, due_date: &str) -> String {
-        // Use human-readable date formatting similar to Todoist
-        format_human_date(due_date)
-    }
-
-    /// Format due datetime with time information if available
-    fn format_due_datetime(&self, due_datetime: &str) -> String {
-        format_human_datetime(due_datetime)
+    /// Render this task with its jump number in a left gutter of `width` digits
+    pub fn render_numbered(
+        &self,
+        number: usize,
+        width: usize,
+        selected: bool,
+        display_config: &DisplayConfig,
+        theme: &Theme,
+    ) -> RatatuiListItem<'static> {
+        let mut line_spans = vec![Span::styled(
+            format!("{:>width$} ", number, width = width),
+            Style::default().fg(theme.dim),
+        )];
+        line_spans.extend(self.line_spans(selected, display_config, theme));
+        RatatuiListItem::new(Line::from(line_spans))
     }
-}
 
-impl ListItem for TaskItem {
-    fn render(&self, selected: bool, display_config: &DisplayConfig) -> RatatuiListItem<'static> {
+    fn line_spans(&self, selected: bool, display_config: &DisplayConfig, theme: &Theme) -> Vec<Span<'static>> {
         // Choose the appropriate icon based on task state
         let status_icon = if self.task.is_deleted {
             self.icons.task_deleted()
-        } else if self.task.is_completed {
+        } else if self.rescheduled || self.task.is_completed {
+            // Rescheduled recurring tasks show as completed until their highlight expires
             self.icons.task_completed()
         } else {
             self.icons.task_pending()
         };
         let mut line_spans = Vec::new();
+        let project = self.projects.iter().find(|p| p.uuid == self.task.project_uuid);
+
+        if self.marked {
+            line_spans.push(Span::styled(
+                format!("{} ", MARK_GLYPH),
+                Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        // Priority bar; p4 gets a blank one so that contents stay aligned
+        if display_config.priority_style == PriorityStyle::Bar {
+            let bar = if (2..=4).contains(&self.task.priority) {
+                PRIORITY_BAR
+            } else {
+                " "
+            };
+            line_spans.push(Span::styled(
+                bar,
+                Style::default().fg(theme.priority_color(self.task.priority)),
+            ));
+        }
+
+        // Thin bar in the project's color, kept apart from the selection and priority colors
+        if display_config.project_color_coding && self.in_aggregate_view {
+            let bar_color = project.map_or(theme.dim, |p| color::to_terminal_color(&p.color));
+            line_spans.push(Span::styled(
+                format!("{} ", PROJECT_BAR),
+                Style::default().fg(bar_color),
+            ));
+        }
 
         // Add hierarchical indentation for subtasks
         if self.depth > 0 {
@@ -122,100 +186,170 @@ impl ListItem for TaskItem {
             // Add tree connector for the current level
             indent_str.push_str("└─");
 
-            line_spans.push(Span::styled(indent_str, Style::default().fg(Color::DarkGray)));
+            line_spans.push(Span::styled(indent_str, Style::default().fg(theme.dim)));
         }
 
         // Status icon with state-based styling
         let status_style = if self.task.is_deleted {
             // Deleted tasks: red icon
-            Style::default().fg(Color::Red)
-        } else if self.task.is_completed {
+            Style::default().fg(theme.error)
+        } else if self.task.is_completed || self.rescheduled {
             // Completed tasks: green icon for the tick mark
-            Style::default().fg(Color::Green)
+            Style::default().fg(theme.success)
         } else if selected {
             // Selected active tasks: yellow and bold
-            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)
         } else {
             // Normal active tasks: white
-            Style::default().fg(Color::White)
+            Style::default().fg(theme.text)
         };
         line_spans.push(Span::styled(format!("{} ", status_icon), status_style));
 
-        // Priority badge (if any)
-        if let Some(priority_badge) = create_priority_badge(self.task.priority) {
-            line_spans.push(priority_badge);
-            line_spans.push(Span::raw(" "));
+        // Priority flag
+        if display_config.priority_style == PriorityStyle::Flag {
+            if let Some(priority_badge) = create_priority_badge(self.task.priority, theme) {
+                line_spans.push(priority_badge);
+                line_spans.push(Span::raw(" "));
+            }
         }
 
         // Task content with selection styling and deleted/completed styling
         let content_style = if self.task.is_deleted {
             // Deleted tasks: red with strikethrough
-            Style::default().fg(Color::Red).add_modifier(Modifier::CROSSED_OUT)
+            Style::default().fg(theme.error).add_modifier(Modifier::CROSSED_OUT)
         } else if self.task.is_completed {
             // Completed tasks: gray with strikethrough
-            Style::default().fg(Color::DarkGray).add_modifier(Modifier::CROSSED_OUT)
+            Style::default().fg(theme.dim).add_modifier(Modifier::CROSSED_OUT)
+        } else if self.rescheduled {
+            // Just-completed recurring tasks: brief green flash
+            Style::default().fg(theme.success).add_modifier(Modifier::BOLD)
         } else if selected {
             // Selected active tasks: yellow and bold
-            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)
+        } else if display_config.priority_style == PriorityStyle::Content && (2..=4).contains(&self.task.priority) {
+            // Prioritized active tasks in their priority color
+            Style::default().fg(theme.priority_color(self.task.priority))
         } else {
             // Normal active tasks: white
-            Style::default().fg(Color::White)
+            Style::default().fg(theme.text)
         };
         line_spans.push(Span::styled(self.task.content.clone(), content_style));
 
         // Child task count (for tasks with children)
         if self.child_count > 0 {
-            let progress_text = format!(" ({})", self.child_count);
-            let progress_style = Style::default().fg(Color::Gray);
+            let progress_text = if self.collapsed {
+                format!(" ({} {})", COLLAPSED_GLYPH, self.child_count)
+            } else {
+                format!(" ({})", self.child_count)
+            };
+            let progress_style = Style::default().fg(theme.muted);
             line_spans.push(Span::styled(progress_text, progress_style));
         }
 
         // Project display (with optional colors)
-        if let Some(project) = self.projects.iter().find(|p| p.uuid == self.task.project_uuid) {
+        if let Some(project) = project {
             line_spans.push(Span::raw(" "));
             let project_style = if display_config.show_project_colors {
-                // Use project color if available, otherwise cyan
-                Style::default().fg(Color::Cyan)
+                Style::default().fg(color::to_terminal_color(&project.color))
             } else {
-                Style::default().fg(Color::Cyan)
+                Style::default().fg(theme.accent)
             };
             line_spans.push(Span::styled(format!("#{}", project.name), project_style));
         }
 
         // Due date/datetime display
-        if let Some(due_date) = &self.task.due_date {
+        if let Some(due) = self.task.due() {
             line_spans.push(Span::raw(" "));
 
-            // Use datetime formatting if available, otherwise use date formatting
-            let formatted_date = if let Some(due_datetime) = &self.task.due_datetime {
-                self.format_due_datetime(due_datetime)
+            // Relative date ("Tomorrow", "Fri"), with the time for datetimes
+            let formatted_date = due.relative(
+                datetime::now(),
+                &display_config.date_format,
+                &display_config.time_format,
+            );
+
+            if self.rescheduled {
+                line_spans.push(Span::styled(
+                    format!(
+                        "{} rescheduled to {}",
+                        self.icons.icons().status.recurring,
+                        formatted_date
+                    ),
+                    Style::default().fg(theme.success),
+                ));
             } else {
-                self.format_due_date(due_date)
-            };
+                let due_color = if due.is_overdue(datetime::now()) && !self.task.is_completed {
+                    theme.overdue
+                } else {
+                    theme.due
+                };
+                line_spans.push(Span::styled(formatted_date, Style::default().fg(due_color)));
+                if self.task.is_recurring {
+                    // The selected task spells out its recurrence ("every monday")
+                    let recurrence = match self.task.due_string.as_deref() {
+                        Some(due_string) if selected => {
+                            format!(" {} {}", self.icons.icons().status.recurring, due_string)
+                        }
+                        _ => format!(" {}", self.icons.icons().status.recurring),
+                    };
+                    line_spans.push(Span::styled(recurrence, Style::default().fg(theme.muted)));
+                }
+            }
+        }
+
+        // Deadline, shown apart from the scheduled due date
+        if let Some(deadline) = datetime::Due::parse(self.task.deadline.as_deref(), None) {
+            let now = datetime::now();
+            line_spans.push(Span::raw(" "));
+            line_spans.push(create_deadline_badge(
+                self.icons.icons().status.deadline,
+                &deadline.relative(now, &display_config.date_format, &display_config.time_format),
+                deadline.is_overdue(now) && !self.task.is_completed,
+                theme,
+            ));
+        }
 
+        // Subtle badge on chronically postponed tasks
+        let threshold = display_config.postponed_badge_threshold;
+        if threshold > 0 && self.task.postpone_count >= threshold {
+            line_spans.push(Span::raw(" "));
             line_spans.push(Span::styled(
-                formatted_date,
-                Style::default().fg(Color::Rgb(255, 165, 0)), // Orange color
+                format!("{}{}", self.icons.icons().status.postponed, self.task.postpone_count),
+                Style::default().fg(theme.dim),
             ));
         }
 
         // Metadata badges (only if configured to show)
         if display_config.show_durations || display_config.show_labels {
+            // "⏱ 1h30m"; a duration in a format we don't know is shown as the backend sent it
+            let duration = self
+                .task
+                .duration
+                .as_deref()
+                .filter(|d| display_config.show_durations && !d.is_empty())
+                .map(|d| {
+                    let amount = datetime::parse_duration(d).map_or_else(|| d.to_string(), datetime::format_duration);
+                    format!("{} {}", self.icons.icons().status.duration, amount)
+                });
             let metadata_badges = create_task_badges(
-                self.task.is_recurring,
                 self.task.due_date.is_some() || self.task.deadline.is_some(),
-                if display_config.show_durations {
-                    self.task.duration.as_deref()
-                } else {
-                    None
-                },
-                if display_config.show_labels { &self.labels } else { &[] },
+                duration.as_deref(),
             );
 
             for badge in metadata_badges {
                 line_spans.push(Span::raw(" "));
                 line_spans.push(badge);
             }
+
+            if display_config.show_labels {
+                // The selected task spells out abbreviated labels
+                let label_style = if selected {
+                    LabelStyle::Full
+                } else {
+                    display_config.label_style
+                };
+                line_spans.extend(create_label_badges(&self.labels, label_style));
+            }
         }
 
         // Add description excerpt if available and configured to show
@@ -230,13 +364,19 @@ impl ListItem for TaskItem {
                     line_spans.push(Span::raw(" - "));
                     line_spans.push(Span::styled(
                         description_line.to_string(),
-                        Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+                        Style::default().fg(theme.dim).add_modifier(Modifier::ITALIC),
                     ));
                 }
             }
         }
 
-        RatatuiListItem::new(Line::from(line_spans))
+        line_spans
+    }
+}
+
+impl ListItem for TaskItem {
+    fn render(&self, selected: bool, display_config: &DisplayConfig, theme: &Theme) -> RatatuiListItem<'static> {
+        RatatuiListItem::new(Line::from(self.line_spans(selected, display_config, theme)))
     }
 
     fn is_selectable(&self) -> bool {
@@ -262,11 +402,11 @@ impl HeaderItem {
 }
 
 impl ListItem for HeaderItem {
-    fn render(&self, _selected: bool, _display_config: &DisplayConfig) -> RatatuiListItem<'static> {
+    fn render(&self, _selected: bool, _display_config: &DisplayConfig, theme: &Theme) -> RatatuiListItem<'static> {
         let indent_str = " ".repeat(self.indent * INDENT_WIDTH);
         RatatuiListItem::new(Line::from(Span::styled(
             format!("{}{}", indent_str, self.text),
-            Style::default().add_modifier(Modifier::BOLD).fg(Color::Cyan),
+            Style::default().add_modifier(Modifier::BOLD).fg(theme.accent),
         )))
     }
 
@@ -292,13 +432,13 @@ impl SeparatorItem {
 }
 
 impl ListItem for SeparatorItem {
-    fn render(&self, _selected: bool, _display_config: &DisplayConfig) -> RatatuiListItem<'static> {
+    fn render(&self, _selected: bool, _display_config: &DisplayConfig, theme: &Theme) -> RatatuiListItem<'static> {
         let indent_str = " ".repeat(self.indent * INDENT_WIDTH);
         let separator = " ";
 
         RatatuiListItem::new(Line::from(Span::styled(
             format!("{}{}", indent_str, separator),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.dim),
         )))
     }
 
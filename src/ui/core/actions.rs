@@ -1,4 +1,9 @@
+use crate::backend::{BackendStatus, UpdateTaskArgs};
+use crate::entities::task;
+use crate::sync::duplicates::DuplicateGroup;
 use crate::sync::SyncStatus;
+use crate::ui::core::undo::UndoEntry;
+use crate::utils::export::ExportFormat;
 use uuid::Uuid;
 
 /// Represents the currently selected item in the sidebar
@@ -8,16 +13,83 @@ pub enum SidebarSelection {
     Today, // Today view (special view)
     Tomorrow,       // Tomorrow view (special view)
     Upcoming,       // Upcoming view (tasks with future due dates)
+    Postponed,      // Postponed view (open tasks ordered by postpone count)
+    All,            // All tasks view (open tasks of every backend)
+    Filter(usize),  // Index into config.filters
     Label(usize),   // Index into labels vector
     Project(usize), // Index into projects vector
 }
 
+/// Collapsible group of sidebar rows, shown under a header when sections are enabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SidebarSection {
+    /// Favorited projects and labels, also listed in their own section
+    Favorites,
+    Views,
+    Filters,
+    Labels,
+    Projects,
+}
+
+impl SidebarSection {
+    /// All sections in display order
+    pub const ALL: [SidebarSection; 5] = [
+        SidebarSection::Favorites,
+        SidebarSection::Views,
+        SidebarSection::Filters,
+        SidebarSection::Labels,
+        SidebarSection::Projects,
+    ];
+
+    /// Header text shown in the sidebar
+    pub fn title(&self) -> &'static str {
+        match self {
+            SidebarSection::Favorites => "Favorites",
+            SidebarSection::Views => "Views",
+            SidebarSection::Filters => "Filters",
+            SidebarSection::Labels => "Labels",
+            SidebarSection::Projects => "Projects",
+        }
+    }
+
+    /// Stable identifier used when persisting collapse state
+    pub fn key(&self) -> &'static str {
+        match self {
+            SidebarSection::Favorites => "favorites",
+            SidebarSection::Views => "views",
+            SidebarSection::Filters => "filters",
+            SidebarSection::Labels => "labels",
+            SidebarSection::Projects => "projects",
+        }
+    }
+
+    /// Parse a section from its [`key`](Self::key)
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|section| section.key() == key)
+    }
+}
+
+/// Where the summary of a finished batch operation is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchReport {
+    Dialog, // Replaces the progress dialog with an info dialog
+    Toast,  // Closes the progress dialog and shows a toast
+}
+
 #[derive(Debug, Clone)]
 pub enum Action {
     // Navigation
     NavigateToSidebar(SidebarSelection),
+    SetSidebarSectionCollapsed {
+        section: SidebarSection,
+        collapsed: bool,
+    },
     NextTask,
     PreviousTask,
+    SetUpcomingDays(u32),
+    ToggleTaskMark, // Mark or unmark the selected task for a bulk action
+    MarkAllTasks,
+    ClearTaskMarks,
 
     // Task operations
     CompleteTask(String),
@@ -27,26 +99,74 @@ pub enum Action {
     SetTaskDueTomorrow(Uuid),
     SetTaskDueNextWeek(Uuid),
     SetTaskDueWeekEnd(Uuid),
+    SetTaskDeadline {
+        task_uuid: Uuid,
+        deadline: Option<String>, // YYYY-MM-DD, None clears the deadline
+    },
+    PostponeTask {
+        task_uuid: Uuid,
+        days: i64, // Added to the current due date; negative moves it earlier
+    },
+    SetTaskLabels {
+        task_uuid: Uuid,
+        labels: Vec<String>, // Complete set of label names, empty removes all labels
+    },
+    SetTaskDueDate {
+        task_uuid: Uuid,
+        date: Option<String>, // YYYY-MM-DD or an RFC 3339 UTC datetime, None clears the due date
+    },
     CreateTask {
         content: String,
         project_uuid: Option<Uuid>,
+        section_uuid: Option<Uuid>,
+        due: Option<String>,   // YYYY-MM-DD or an RFC 3339 UTC datetime, from a trailing date phrase
+        priority: Option<i32>, // From quick-add p1-p4, on the API scale
+        duration: Option<u32>, // Minutes, from a quick-add "for 45m"
     },
     EditTask {
         task_uuid: Uuid,
-        content: String,
+        changes: Box<UpdateTaskArgs>,         // Only the fields edited in the form are set
+        section_change: Option<Option<Uuid>>, // New section (None = no section) when it was changed
+        snapshot: Option<Box<task::Model>>,   // Task as it was when the edit form opened
     },
+    ConfirmNewLabels(Box<Action>), // Create/edit task confirmed despite creating new labels
     RestoreTask(String),
+    DuplicateTask(Uuid),
+    ExportView {
+        format: ExportFormat,
+        path: String, // As typed; a leading ~ stands for the home directory
+    },
+    ReorderTask {
+        task_uuid: Uuid,
+        offset: i32, // Positions to move among its siblings; negative moves it up
+    },
+    UndoLastOperation,
+    RecordUndo(UndoEntry), // Sent once the operation it reverts has succeeded
+    RescheduleOverdueTasks(Vec<Uuid>),
+    BulkComplete(Vec<Uuid>),
+    BulkDelete(Vec<Uuid>),
+    BulkMove {
+        tasks: Vec<Uuid>,
+        project_uuid: Uuid,
+    },
+    BulkNest {
+        tasks: Vec<Uuid>,
+        parent_uuid: Uuid,
+    },
 
     // Project operations
     CreateProject {
         name: String,
         parent_uuid: Option<Uuid>,
+        color: String, // Todoist color name, e.g. "berry_red"
     },
     EditProject {
         project_uuid: Uuid,
         name: String,
+        color: String,
     },
     DeleteProject(Uuid),
+    ToggleProjectFavorite(Uuid),
 
     // Label operations
     CreateLabel {
@@ -55,14 +175,25 @@ pub enum Action {
     EditLabel {
         label_uuid: Uuid,
         name: String,
+        color: String,
+        is_favorite: bool,
     },
     DeleteLabel(Uuid),
+    ToggleLabelFavorite(Uuid),
 
     // Sync operations
     StartSync,
     RefreshLocalData, // Debug mode: refresh from local DB without API sync
     SyncCompleted(SyncStatus),
     SyncFailed(String),
+    UpdateApiToken {
+        token: String,
+        retry: Option<Box<Action>>, // Operation that failed with an authentication error
+    },
+    RetryOperation {
+        operation: String,
+        info: String,
+    },
     InitialDataLoaded {
         projects: Vec<crate::entities::project::Model>,
         labels: Vec<crate::entities::label::Model>,
@@ -80,23 +211,43 @@ pub enum Action {
         query: String,
         results: Vec<crate::entities::task::Model>,
     },
+    FindDuplicateTasks,
+    DuplicateTasksLoaded(Vec<DuplicateGroup>),
+    CheckBackendStatus,
+    BackendStatusLoaded(BackendStatus),
 
     // Data refresh after task operations
     RefreshData,
 
+    // Batch operations
+    BatchProgress {
+        processed: usize,
+        total: usize,
+    },
+    BatchCompleted {
+        summary: String, // Summary of the finished (or cancelled) batch
+        report: BatchReport,
+    },
+    CancelBatch,
+
     // UI operations
     ToggleSidebar,
+    ToggleDetails,
+    ToggleFocusMode,
     ShowHelp(bool),
     ShowDebug(bool),
     ShowDialog(DialogType),
     HideDialog,
+    ShowToast(String),
     HelpScrollUp,
     HelpScrollDown,
     HelpScrollToTop,
     HelpScrollToBottom,
 
     // App control
-    Quit,
+    ReloadConfig, // Re-read the config file and apply it, keeping the current one if invalid
+    Quit,         // Asks first while background operations are running
+    ForceQuit,    // Quit without asking, e.g. once the user confirmed
     None,
 }
 
@@ -109,24 +260,74 @@ pub enum DialogType {
         task_uuid: Uuid,
         content: String,
         project_uuid: Uuid,
+        labels: Vec<String>,        // Label names, looked up by the app when the form opens
+        snapshot: Box<task::Model>, // Used to detect changes made while the form is open
     },
     ProjectCreation,
     ProjectEdit {
         project_uuid: Uuid,
         name: String,
+        color: String,
     },
     LabelCreation,
     LabelEdit {
         label_uuid: Uuid,
         name: String,
+        color: String,
+        is_favorite: bool,
     },
     DeleteConfirmation {
         item_type: String,
         item_uuid: Uuid,
     },
+    NewLabelConfirmation {
+        labels: Vec<String>,
+        action: Box<Action>, // Task creation or edit to run once confirmed
+    },
+    DeadlinePrompt {
+        task_uuid: Uuid,
+        content: String,          // Task content, shown for context
+        deadline: Option<String>, // Current deadline (YYYY-MM-DD), prefilled in the input
+    },
+    TaskLabels {
+        task_uuid: Uuid,
+        content: String,     // Task content, shown for context
+        labels: Vec<String>, // Label names the task has, looked up by the app when the dialog opens
+    },
+    SetDueDate {
+        task_uuid: Uuid,
+        content: String,     // Task content, shown for context
+        due: Option<String>, // Current due date ("YYYY-MM-DD", with " HH:MM" for a time), prefilled in the input
+    },
+    ExportView {
+        path: String, // Default file path, prefilled in the input
+    },
+    Confirmation {
+        message: String,
+        action: Box<Action>, // Action to run once confirmed
+    },
     Error(String),
+    /// Something went wrong without stopping the operation (e.g. a partial sync)
+    Warning(String),
     Info(String),
+    TokenPrompt {
+        retry: Option<Box<Action>>,
+    },
     Help,
     Logs,
     TaskSearch,
+    DuplicateTasks,
+    BackendStatus, // Connection check of the active backend, run when the dialog opens
+    MoveTasks {
+        task_uuids: Vec<Uuid>, // Marked tasks to move once a project is picked
+    },
+    NestTasks {
+        task_uuids: Vec<Uuid>, // Marked tasks to nest once a parent task is picked
+    },
+    Progress {
+        title: String,
+        processed: usize,
+        total: usize,
+        cancelling: bool,
+    },
 }
@@ -2,6 +2,7 @@ use crate::{
     entities::{label, project, section},
     icons::IconService,
     sync::SyncService,
+    ui::core::theme::Theme,
 };
 
 pub struct AppContext {
@@ -10,6 +11,7 @@ pub struct AppContext {
     pub labels: Vec<label::Model>,
     pub sections: Vec<section::Model>,
     pub icons: IconService,
+    pub theme: Theme,
 }
 
 impl AppContext {
@@ -20,6 +22,7 @@ impl AppContext {
             labels: Vec::new(),
             sections: Vec::new(),
             icons: IconService::default(),
+            theme: Theme::default(),
         }
     }
 }
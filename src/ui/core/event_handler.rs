@@ -1,12 +1,71 @@
 use crossterm::event::{poll, Event, KeyEvent, MouseEvent};
 use tokio::time::{interval, Duration, Instant};
 
+/// Quiet period after the last resize event before the final size is applied
+pub const RESIZE_DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// Coalesces bursts of terminal resize events.
+///
+/// The first resize after a quiet period is applied immediately so a single resize
+/// feels instant. Further resizes within [`RESIZE_DEBOUNCE`] of each other are held
+/// back, and only the last size is applied once the terminal stops changing.
+#[derive(Debug, Default)]
+pub struct ResizeDebouncer {
+    last_resize_at: Option<Instant>,
+    pending: Option<(u16, u16)>,
+}
+
+impl ResizeDebouncer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a resize; returns the size to apply now, if any
+    pub fn on_resize(&mut self, width: u16, height: u16, now: Instant) -> Option<(u16, u16)> {
+        let in_burst = self.last_resize_at.is_some_and(|at| now.duration_since(at) < RESIZE_DEBOUNCE);
+        self.last_resize_at = Some(now);
+
+        if in_burst {
+            self.pending = Some((width, height));
+            None
+        } else {
+            self.pending = None;
+            Some((width, height))
+        }
+    }
+
+    /// Returns the held-back size once the quiet period has elapsed
+    pub fn poll_settled(&mut self, now: Instant) -> Option<(u16, u16)> {
+        let settled = self.last_resize_at.is_some_and(|at| now.duration_since(at) >= RESIZE_DEBOUNCE);
+        if settled {
+            self.pending.take()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the held-back size immediately, e.g. before handling input that depends on it
+    pub fn flush(&mut self) -> Option<(u16, u16)> {
+        self.pending.take()
+    }
+
+    /// Time left until the held-back size settles, if one is pending
+    pub fn time_until_settled(&self, now: Instant) -> Option<Duration> {
+        self.pending?;
+        let at = self.last_resize_at?;
+        Some(RESIZE_DEBOUNCE.saturating_sub(now.duration_since(at)))
+    }
+}
+
 pub struct EventHandler {
     #[allow(dead_code)]
     tick_interval: tokio::time::Interval,
     #[allow(dead_code)]
     render_interval: tokio::time::Interval,
     last_render_time: Instant,
+    resize_debouncer: ResizeDebouncer,
+    /// Input event read while a resize was pending, delivered right after the flushed resize
+    queued_event: Option<EventType>,
 }
 
 impl EventHandler {
@@ -15,27 +74,50 @@ impl EventHandler {
             tick_interval: interval(Duration::from_millis(100)), // 10 Hz for application ticks
             render_interval: interval(Duration::from_millis(16)), // ~60 FPS render rate
             last_render_time: Instant::now(),
+            resize_debouncer: ResizeDebouncer::new(),
+            queued_event: None,
         }
     }
 
     pub async fn next_event(&mut self) -> anyhow::Result<EventType> {
-        // Check for terminal events without blocking first
-        if poll(Duration::from_millis(0))? {
-            match crossterm::event::read()? {
-                Event::Key(key) => {
-                    return Ok(EventType::Key(key));
+        if let Some(event) = self.queued_event.take() {
+            return Ok(event);
+        }
+
+        loop {
+            // Check for terminal events without blocking first
+            if poll(Duration::from_millis(0))? {
+                let event = match crossterm::event::read()? {
+                    Event::Key(key) => EventType::Key(key),
+                    Event::Mouse(mouse) => EventType::Mouse(mouse),
+                    Event::Resize(w, h) => match self.resize_debouncer.on_resize(w, h, Instant::now()) {
+                        Some((w, h)) => return Ok(EventType::Resize(w, h)),
+                        None => continue,
+                    },
+                    _ => EventType::Other,
+                };
+
+                // Input is handled with the final dimensions, so apply a held-back resize first
+                if let Some((w, h)) = self.resize_debouncer.flush() {
+                    self.queued_event = Some(event);
+                    return Ok(EventType::Resize(w, h));
                 }
-                Event::Mouse(mouse) => {
-                    return Ok(EventType::Mouse(mouse));
+                return Ok(event);
+            }
+
+            if let Some((w, h)) = self.resize_debouncer.poll_settled(Instant::now()) {
+                return Ok(EventType::Resize(w, h));
+            }
+
+            // Wake up when a held-back resize settles, otherwise wait a bit and return tick
+            match self.resize_debouncer.time_until_settled(Instant::now()) {
+                Some(remaining) => tokio::time::sleep(remaining).await,
+                None => {
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    return Ok(EventType::Tick);
                 }
-                Event::Resize(w, h) => return Ok(EventType::Resize(w, h)),
-                _ => return Ok(EventType::Other),
             }
         }
-
-        // If no immediate event, wait a bit and return tick
-        tokio::time::sleep(Duration::from_millis(100)).await;
-        Ok(EventType::Tick)
     }
 
     /// Get the time since last render for frame timing
@@ -0,0 +1,790 @@
+//! Key bindings: which key triggers which action, and how they are listed in the help dialog.
+//!
+//! Most actions can be rebound in the `[keybindings]` configuration table (see
+//! [`KeyAction::name`] for the names); the rest, such as `Esc`, `Enter` or the arrow
+//! keys, are fixed. The help dialog is generated from [`KeyMap::describe`] so it always
+//! shows the keys that are actually bound.
+
+use crate::config::UiConfig;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Help dialog section a key binding is listed under
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyCategory {
+    Navigation,
+    Projects,
+    Tasks,
+    Sync,
+    General,
+    HelpScrolling,
+}
+
+impl KeyCategory {
+    /// All categories in the order the help dialog lists them
+    pub const ALL: [KeyCategory; 6] = [
+        KeyCategory::Navigation,
+        KeyCategory::Projects,
+        KeyCategory::Tasks,
+        KeyCategory::Sync,
+        KeyCategory::General,
+        KeyCategory::HelpScrolling,
+    ];
+
+    /// Section heading in the help dialog
+    pub fn title(&self) -> &'static str {
+        match self {
+            KeyCategory::Navigation => "NAVIGATION",
+            KeyCategory::Projects => "PROJECT & LABEL MANAGEMENT",
+            KeyCategory::Tasks => "TASK MANAGEMENT",
+            KeyCategory::Sync => "SYNC & DATA",
+            KeyCategory::General => "GENERAL CONTROLS",
+            KeyCategory::HelpScrolling => "HELP PANEL SCROLLING",
+        }
+    }
+}
+
+/// What the keyboard is being used for, which decides the shortcuts in the status bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusContext {
+    /// Browsing the sidebar and task list
+    Tasks,
+    /// Tasks are marked for a bulk action
+    Marked,
+    /// A dialog is open
+    Dialog,
+}
+
+/// An action that can be bound to keys in the `[keybindings]` table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAction {
+    // Handled by the sidebar and task list
+    TaskDown,
+    TaskUp,
+    ProjectDown,
+    ProjectUp,
+    Collapse,
+    Expand,
+    CompleteTask,
+    CreateTask,
+    EditTask,
+    DeleteTask,
+    CyclePriority,
+    ToggleMark,
+    MoveTasks,
+    NestTasks,
+    HideCompleted,
+    CycleSort,
+    CycleGrouping,
+    Fold,
+    UnfoldAll,
+    // Handled by the application
+    QuickAdd,
+    ToggleSidebar,
+    Quit,
+    Help,
+    Logs,
+    CreateProject,
+    EditItem,
+    DeleteItem,
+    Sync,
+    Search,
+    DueToday,
+    DueTomorrow,
+    DueNextWeek,
+    DueWeekEnd,
+    DueDate,
+    Deadline,
+    Focus,
+    FindDuplicates,
+    RescheduleOverdue,
+    Undo,
+    DuplicateTask,
+    PostponeDay,
+    AdvanceDay,
+    TaskLabels,
+    MoveTaskUp,
+    MoveTaskDown,
+    ExportView,
+    ReloadConfig,
+    BackendStatus,
+    ToggleDetails,
+    JumpToProject,
+    ToggleFavorite,
+}
+
+impl KeyAction {
+    pub const ALL: [KeyAction; 51] = [
+        KeyAction::TaskDown,
+        KeyAction::TaskUp,
+        KeyAction::ProjectDown,
+        KeyAction::ProjectUp,
+        KeyAction::Collapse,
+        KeyAction::Expand,
+        KeyAction::CompleteTask,
+        KeyAction::CreateTask,
+        KeyAction::EditTask,
+        KeyAction::DeleteTask,
+        KeyAction::CyclePriority,
+        KeyAction::ToggleMark,
+        KeyAction::MoveTasks,
+        KeyAction::NestTasks,
+        KeyAction::HideCompleted,
+        KeyAction::CycleSort,
+        KeyAction::CycleGrouping,
+        KeyAction::Fold,
+        KeyAction::UnfoldAll,
+        KeyAction::QuickAdd,
+        KeyAction::ToggleSidebar,
+        KeyAction::Quit,
+        KeyAction::Help,
+        KeyAction::Logs,
+        KeyAction::CreateProject,
+        KeyAction::EditItem,
+        KeyAction::DeleteItem,
+        KeyAction::Sync,
+        KeyAction::Search,
+        KeyAction::DueToday,
+        KeyAction::DueTomorrow,
+        KeyAction::DueNextWeek,
+        KeyAction::DueWeekEnd,
+        KeyAction::DueDate,
+        KeyAction::Deadline,
+        KeyAction::Focus,
+        KeyAction::FindDuplicates,
+        KeyAction::RescheduleOverdue,
+        KeyAction::Undo,
+        KeyAction::DuplicateTask,
+        KeyAction::PostponeDay,
+        KeyAction::AdvanceDay,
+        KeyAction::TaskLabels,
+        KeyAction::MoveTaskUp,
+        KeyAction::MoveTaskDown,
+        KeyAction::ExportView,
+        KeyAction::ReloadConfig,
+        KeyAction::BackendStatus,
+        KeyAction::ToggleDetails,
+        KeyAction::JumpToProject,
+        KeyAction::ToggleFavorite,
+    ];
+
+    /// Name used in the `[keybindings]` table
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyAction::TaskDown => "task_down",
+            KeyAction::TaskUp => "task_up",
+            KeyAction::ProjectDown => "project_down",
+            KeyAction::ProjectUp => "project_up",
+            KeyAction::Collapse => "collapse",
+            KeyAction::Expand => "expand",
+            KeyAction::CompleteTask => "complete_task",
+            KeyAction::CreateTask => "create_task",
+            KeyAction::EditTask => "edit_task",
+            KeyAction::DeleteTask => "delete_task",
+            KeyAction::CyclePriority => "cycle_priority",
+            KeyAction::ToggleMark => "toggle_mark",
+            KeyAction::MoveTasks => "move_tasks",
+            KeyAction::NestTasks => "nest_tasks",
+            KeyAction::HideCompleted => "hide_completed",
+            KeyAction::CycleSort => "cycle_sort",
+            KeyAction::CycleGrouping => "cycle_grouping",
+            KeyAction::Fold => "fold",
+            KeyAction::UnfoldAll => "unfold_all",
+            KeyAction::QuickAdd => "quick_add",
+            KeyAction::ToggleSidebar => "toggle_sidebar",
+            KeyAction::Quit => "quit",
+            KeyAction::Help => "help",
+            KeyAction::Logs => "logs",
+            KeyAction::CreateProject => "create_project",
+            KeyAction::EditItem => "edit_item",
+            KeyAction::DeleteItem => "delete_item",
+            KeyAction::Sync => "sync",
+            KeyAction::Search => "search",
+            KeyAction::DueToday => "due_today",
+            KeyAction::DueTomorrow => "due_tomorrow",
+            KeyAction::DueNextWeek => "due_next_week",
+            KeyAction::DueWeekEnd => "due_weekend",
+            KeyAction::DueDate => "due_date",
+            KeyAction::Deadline => "deadline",
+            KeyAction::Focus => "focus",
+            KeyAction::FindDuplicates => "find_duplicates",
+            KeyAction::RescheduleOverdue => "reschedule_overdue",
+            KeyAction::Undo => "undo",
+            KeyAction::DuplicateTask => "duplicate_task",
+            KeyAction::PostponeDay => "postpone_day",
+            KeyAction::AdvanceDay => "advance_day",
+            KeyAction::TaskLabels => "task_labels",
+            KeyAction::MoveTaskUp => "move_task_up",
+            KeyAction::MoveTaskDown => "move_task_down",
+            KeyAction::ExportView => "export_view",
+            KeyAction::ReloadConfig => "reload_config",
+            KeyAction::BackendStatus => "backend_status",
+            KeyAction::ToggleDetails => "toggle_details",
+            KeyAction::JumpToProject => "jump_to_project",
+            KeyAction::ToggleFavorite => "toggle_favorite",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.name() == name)
+    }
+
+    /// Keys bound when the configuration doesn't say otherwise (quick add comes from `ui.quick_add_key`)
+    fn default_keys(&self) -> &'static [&'static str] {
+        match self {
+            KeyAction::TaskDown => &["j"],
+            KeyAction::TaskUp => &["k"],
+            KeyAction::ProjectDown => &["J"],
+            KeyAction::ProjectUp => &["K"],
+            KeyAction::Collapse => &["H"],
+            KeyAction::Expand => &["L"],
+            KeyAction::CompleteTask => &["Space"],
+            KeyAction::CreateTask => &["a"],
+            KeyAction::EditTask => &["e"],
+            KeyAction::DeleteTask => &["d"],
+            KeyAction::CyclePriority => &["p"],
+            KeyAction::ToggleMark => &["v"],
+            KeyAction::MoveTasks => &["M"],
+            KeyAction::NestTasks => &["N"],
+            KeyAction::HideCompleted => &["C"],
+            KeyAction::CycleSort => &["o"],
+            KeyAction::CycleGrouping => &["g"],
+            KeyAction::Fold => &["z"],
+            KeyAction::UnfoldAll => &["Z"],
+            KeyAction::QuickAdd => &[],
+            KeyAction::ToggleSidebar => &["b"],
+            KeyAction::Quit => &["q"],
+            KeyAction::Help => &["?", "h"],
+            KeyAction::Logs => &["G"],
+            KeyAction::CreateProject => &["A"],
+            KeyAction::EditItem => &["E"],
+            KeyAction::DeleteItem => &["D"],
+            KeyAction::Sync => &["r"],
+            KeyAction::Search => &["/"],
+            KeyAction::DueToday => &["t"],
+            KeyAction::DueTomorrow => &["T"],
+            KeyAction::DueNextWeek => &["w"],
+            KeyAction::DueWeekEnd => &["W"],
+            KeyAction::DueDate => &["s"],
+            KeyAction::Deadline => &["u"],
+            KeyAction::Focus => &["f"],
+            KeyAction::FindDuplicates => &["X"],
+            KeyAction::RescheduleOverdue => &["O"],
+            // "u" already sets deadlines
+            KeyAction::Undo => &["U"],
+            KeyAction::DuplicateTask => &["y"],
+            KeyAction::PostponeDay => &[">"],
+            KeyAction::AdvanceDay => &["<"],
+            KeyAction::TaskLabels => &["@"],
+            KeyAction::MoveTaskUp => &["Ctrl+k"],
+            KeyAction::MoveTaskDown => &["Ctrl+j"],
+            KeyAction::ExportView => &["x"],
+            KeyAction::ReloadConfig => &["Ctrl+r"],
+            KeyAction::BackendStatus => &["S"],
+            // Enter already completes tasks
+            KeyAction::ToggleDetails => &["Tab"],
+            KeyAction::JumpToProject => &["P"],
+            KeyAction::ToggleFavorite => &["F"],
+        }
+    }
+
+    /// Whether the sidebar or task list handles the action, rather than the application
+    pub fn is_component_action(&self) -> bool {
+        Self::ALL[..19].contains(self)
+    }
+}
+
+/// A key with its modifiers, such as `q`, `Ctrl+d` or `PageDown`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyPress {
+    pub code: KeyCode,
+    pub ctrl: bool,
+    pub alt: bool,
+}
+
+impl KeyPress {
+    /// Parse a key written as in the configuration: a character, or a key name like
+    /// `Space`, `Enter`, `Esc`, `Tab`, `Up` or `F5`, optionally prefixed with `Ctrl+`
+    /// and/or `Alt+`. Names and modifiers ignore case; characters don't.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut rest = text.trim();
+        let (mut ctrl, mut alt) = (false, false);
+        loop {
+            let lower = rest.to_lowercase();
+            if lower.starts_with("ctrl+") && rest.len() > "ctrl+".len() {
+                ctrl = true;
+                rest = &rest["ctrl+".len()..];
+            } else if lower.starts_with("alt+") && rest.len() > "alt+".len() {
+                alt = true;
+                rest = &rest["alt+".len()..];
+            } else {
+                break;
+            }
+        }
+
+        let mut chars = rest.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) if !c.is_whitespace() && !c.is_control() => {
+                // Terminals report Ctrl+letter as the lowercase letter
+                KeyCode::Char(if ctrl { c.to_ascii_lowercase() } else { c })
+            }
+            _ => match rest.to_lowercase().as_str() {
+                "space" => KeyCode::Char(' '),
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                name => KeyCode::F(name.strip_prefix('f')?.parse().ok().filter(|n| (1..=12).contains(n))?),
+            },
+        };
+        Some(Self { code, ctrl, alt })
+    }
+
+    /// Whether `key` is this key press; Shift is part of the character, so it is ignored
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        let code = match key.code {
+            KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::CONTROL) => KeyCode::Char(c.to_ascii_lowercase()),
+            code => code,
+        };
+        code == self.code
+            && key.modifiers.contains(KeyModifiers::CONTROL) == self.ctrl
+            && key.modifiers.contains(KeyModifiers::ALT) == self.alt
+    }
+
+    /// The key event a terminal sends for this key press
+    pub fn to_event(self) -> KeyEvent {
+        let mut modifiers = KeyModifiers::NONE;
+        if self.ctrl {
+            modifiers |= KeyModifiers::CONTROL;
+        }
+        if self.alt {
+            modifiers |= KeyModifiers::ALT;
+        }
+        if matches!(self.code, KeyCode::Char(c) if c.is_uppercase()) {
+            modifiers |= KeyModifiers::SHIFT;
+        }
+        KeyEvent::new(self.code, modifiers)
+    }
+}
+
+impl fmt::Display for KeyPress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+        if self.alt {
+            write!(f, "Alt+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) if self.ctrl => write!(f, "{}", c.to_ascii_uppercase()),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Delete => write!(f, "Delete"),
+            KeyCode::Up => write!(f, "Up"),
+            KeyCode::Down => write!(f, "Down"),
+            KeyCode::Left => write!(f, "Left"),
+            KeyCode::Right => write!(f, "Right"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::PageUp => write!(f, "PageUp"),
+            KeyCode::PageDown => write!(f, "PageDown"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+/// A status bar shortcut: the key (or the action it is bound to) and what it does
+type StatusHint<K> = (K, &'static str);
+
+/// One line of the help dialog: the keys and what they do
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub category: KeyCategory,
+    pub keys: String,
+    pub description: &'static str,
+}
+
+/// Resolved key bindings, including the ones set in the configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyMap {
+    bindings: HashMap<KeyAction, Vec<KeyPress>>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self::from_config(&UiConfig::default())
+    }
+}
+
+impl KeyMap {
+    /// Default bindings, with quick add on `ui.quick_add_key`
+    pub fn from_config(ui: &UiConfig) -> Self {
+        let mut bindings: HashMap<KeyAction, Vec<KeyPress>> = KeyAction::ALL
+            .into_iter()
+            .map(|action| {
+                let keys = action
+                    .default_keys()
+                    .iter()
+                    .map(|key| KeyPress::parse(key).expect("default keys are valid"))
+                    .collect();
+                (action, keys)
+            })
+            .collect();
+        bindings.insert(
+            KeyAction::QuickAdd,
+            vec![KeyPress {
+                code: KeyCode::Char(ui.quick_add_key),
+                ctrl: false,
+                alt: false,
+            }],
+        );
+        Self { bindings }
+    }
+
+    /// Apply the `[keybindings]` table, which maps action names to keys; several keys for
+    /// one action are separated by spaces (`help = "? F1"`).
+    ///
+    /// # Errors
+    /// Returns an error listing unknown action names, keys that can't be parsed and keys
+    /// bound to more than one action
+    pub fn with_keybindings(mut self, keybindings: &BTreeMap<String, String>) -> anyhow::Result<Self> {
+        let mut problems = Vec::new();
+        for (name, keys) in keybindings {
+            let Some(action) = KeyAction::from_name(name) else {
+                problems.push(format!("Unknown action '{}'", name));
+                continue;
+            };
+            let parsed: Vec<Option<KeyPress>> = keys.split_whitespace().map(KeyPress::parse).collect();
+            if parsed.is_empty() {
+                problems.push(format!("No key given for '{}'", name));
+            } else if parsed.iter().any(Option::is_none) {
+                problems.push(format!("Invalid key '{}' for '{}'", keys, name));
+            } else {
+                self.bindings.insert(action, parsed.into_iter().flatten().collect());
+            }
+        }
+
+        for (index, first) in KeyAction::ALL.iter().enumerate() {
+            for second in &KeyAction::ALL[index + 1..] {
+                for key in &self.bindings[first] {
+                    if self.bindings[second].contains(key) {
+                        problems.push(format!(
+                            "Key '{}' is bound to both '{}' and '{}'",
+                            key,
+                            first.name(),
+                            second.name()
+                        ));
+                    }
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(self)
+        } else {
+            anyhow::bail!(
+                "Invalid [keybindings], using the default keys:\n{}",
+                problems.join("\n")
+            )
+        }
+    }
+
+    /// Keys bound to `action`
+    pub fn keys(&self, action: KeyAction) -> &[KeyPress] {
+        &self.bindings[&action]
+    }
+
+    /// Action bound to the pressed key, if any
+    pub fn action_for(&self, key: &KeyEvent) -> Option<KeyAction> {
+        KeyAction::ALL
+            .into_iter()
+            .find(|action| self.keys(*action).iter().any(|bound| bound.matches(key)))
+    }
+
+    /// Whether the pressed key is bound to `action`
+    pub fn is(&self, action: KeyAction, key: &KeyEvent) -> bool {
+        self.keys(action).iter().any(|bound| bound.matches(key))
+    }
+
+    /// The key the sidebar and task list should see for a pressed key.
+    ///
+    /// Those components match their default keys, so a key bound to one of their actions
+    /// becomes that action's default key. Keys bound to application actions, and default
+    /// keys that were rebound to something else, are hidden from them (`None`).
+    pub fn component_key(&self, key: KeyEvent) -> Option<KeyEvent> {
+        if let Some(action) = self.action_for(&key) {
+            if !action.is_component_action() {
+                return None;
+            }
+            let default = action.default_keys().first().and_then(|key| KeyPress::parse(key))?;
+            return Some(default.to_event());
+        }
+
+        let rebound_default = KeyAction::ALL
+            .iter()
+            .filter(|action| action.is_component_action())
+            .flat_map(|action| action.default_keys())
+            .filter_map(|default| KeyPress::parse(default))
+            .any(|default| default.matches(&key));
+        (!rebound_default).then_some(key)
+    }
+
+    /// Keys of `action` for the help dialog and status bar, such as `?/h`
+    pub fn label(&self, action: KeyAction) -> String {
+        let keys: Vec<String> = self.keys(action).iter().map(ToString::to_string).collect();
+        keys.join("/")
+    }
+
+    /// Keys of two opposite actions for the help dialog, such as `j/k`
+    fn pair_label(&self, first: KeyAction, second: KeyAction) -> String {
+        format!("{}/{}", self.label(first), self.label(second))
+    }
+
+    /// Shortcuts shown in the status bar in `context`, most useful first, as keys and what they do
+    pub fn status_hints(&self, context: StatusContext) -> Vec<(String, &'static str)> {
+        use KeyAction as K;
+
+        let (actions, fixed): (&[StatusHint<KeyAction>], &[StatusHint<&str>]) = match context {
+            StatusContext::Tasks => (
+                &[
+                    (K::CreateTask, "add"),
+                    (K::CompleteTask, "complete"),
+                    (K::EditTask, "edit"),
+                    (K::DueDate, "due date"),
+                    (K::Search, "search"),
+                    (K::ToggleSidebar, "sidebar"),
+                    (K::Help, "help"),
+                    (K::Quit, "quit"),
+                ],
+                &[],
+            ),
+            StatusContext::Marked => (
+                &[
+                    (K::CompleteTask, "complete"),
+                    (K::DeleteTask, "delete"),
+                    (K::MoveTasks, "move"),
+                    (K::NestTasks, "nest"),
+                    (K::ToggleMark, "mark"),
+                ],
+                &[("Esc", "clear marks")],
+            ),
+            StatusContext::Dialog => (&[], &[("Esc", "close")]),
+        };
+
+        actions
+            .iter()
+            .map(|(action, description)| (self.label(*action), *description))
+            .chain(fixed.iter().map(|(keys, description)| (keys.to_string(), *description)))
+            .collect()
+    }
+
+    /// All key bindings, grouped by category in help dialog order
+    pub fn describe(&self) -> Vec<KeyBinding> {
+        use KeyAction as K;
+        use KeyCategory::*;
+
+        let bindings: Vec<(KeyCategory, String, &'static str)> = vec![
+            (
+                Navigation,
+                self.pair_label(K::TaskDown, K::TaskUp),
+                "Navigate tasks (down/up)",
+            ),
+            (
+                Navigation,
+                self.pair_label(K::ProjectDown, K::ProjectUp),
+                "Navigate projects (down/up)",
+            ),
+            (
+                Navigation,
+                self.pair_label(K::Collapse, K::Expand),
+                "Collapse/expand project folder or sidebar section",
+            ),
+            (
+                Navigation,
+                self.label(K::JumpToProject),
+                "Jump to a project by typing its name",
+            ),
+            (Navigation, "Mouse".into(), "Click sidebar items to navigate"),
+            (Navigation, "Enter".into(), "Select project/task or confirm action"),
+            (Navigation, "Esc".into(), "Cancel action or close dialogs"),
+            (Projects, self.label(K::CreateProject), "Create new project"),
+            (
+                Projects,
+                self.label(K::EditItem),
+                "Edit selected item (project or label)",
+            ),
+            (
+                Projects,
+                self.label(K::DeleteItem),
+                "Delete selected item (project or label)",
+            ),
+            (
+                Projects,
+                self.label(K::ToggleFavorite),
+                "Add/remove selected project or label from Favorites",
+            ),
+            (Tasks, self.label(K::CompleteTask), "Toggle task completion"),
+            (Tasks, self.label(K::CreateTask), "Create new task"),
+            (Tasks, self.label(K::QuickAdd), "Quick add task to inbox (any view)"),
+            (
+                Tasks,
+                self.label(K::EditTask),
+                "Edit selected task (Tab moves between fields)",
+            ),
+            (Tasks, self.label(K::DeleteTask), "Delete task (with confirmation)"),
+            (Tasks, self.label(K::CyclePriority), "Cycle task priority"),
+            (Tasks, self.label(K::ToggleMark), "Mark/unmark task for a bulk action"),
+            (Tasks, "Ctrl+A".into(), "Mark all open tasks in view (Esc clears marks)"),
+            (
+                Tasks,
+                self.pair_label(K::CompleteTask, K::DeleteTask),
+                "Complete/delete marked tasks",
+            ),
+            (
+                Tasks,
+                self.label(K::MoveTasks),
+                "Move marked (or selected) tasks to a project",
+            ),
+            (
+                Tasks,
+                self.label(K::NestTasks),
+                "Make marked tasks subtasks of another task",
+            ),
+            (Tasks, self.label(K::DueToday), "Set task due date to today"),
+            (Tasks, self.label(K::DueTomorrow), "Set task due date to tomorrow"),
+            (
+                Tasks,
+                self.label(K::DueNextWeek),
+                "Set task due date to next week (Monday)",
+            ),
+            (
+                Tasks,
+                self.label(K::DueWeekEnd),
+                "Set task due date to next week end (Saturday)",
+            ),
+            (
+                Tasks,
+                self.label(K::DueDate),
+                "Set any due date (e.g. 'next fri', empty clears)",
+            ),
+            (
+                Tasks,
+                self.label(K::PostponeDay),
+                "Move due date one day later (undated: tomorrow)",
+            ),
+            (Tasks, self.label(K::AdvanceDay), "Move due date one day earlier"),
+            (
+                Tasks,
+                self.label(K::Deadline),
+                "Set task deadline (e.g. 'by end of month', empty clears)",
+            ),
+            (
+                Tasks,
+                self.label(K::RescheduleOverdue),
+                "Reschedule overdue tasks in view to today",
+            ),
+            (
+                Tasks,
+                self.label(K::Undo),
+                "Undo last completion, deletion or priority change",
+            ),
+            (Tasks, self.label(K::TaskLabels), "Pick the labels of a task"),
+            (
+                Tasks,
+                self.pair_label(K::MoveTaskUp, K::MoveTaskDown),
+                "Move task up/down in its project's manual order",
+            ),
+            (
+                Tasks,
+                self.label(K::DuplicateTask),
+                "Duplicate task in the same project and section",
+            ),
+            (
+                Tasks,
+                self.label(K::HideCompleted),
+                "Hide completed tasks in view (until view change or sync)",
+            ),
+            (
+                Tasks,
+                "P".into(),
+                "Show all priorities in Today (with today_min_priority)",
+            ),
+            (
+                Tasks,
+                self.label(K::FindDuplicates),
+                "Find duplicate tasks (d to delete extras)",
+            ),
+            (
+                Tasks,
+                self.label(K::Focus),
+                "Focus mode (selected task only, Esc to exit)",
+            ),
+            (Tasks, "0-9".into(), "Jump to numbered task (with show_task_numbers)"),
+            (
+                Tasks,
+                "+/-".into(),
+                "Show more/fewer days in Upcoming (7, 14, 30, 90, 365)",
+            ),
+            (
+                Tasks,
+                self.label(K::CycleSort),
+                "Cycle sort order (default, priority, due date, name)",
+            ),
+            (
+                Tasks,
+                self.label(K::CycleGrouping),
+                "Group tasks by view, priority or label",
+            ),
+            (
+                Tasks,
+                self.pair_label(K::Fold, K::UnfoldAll),
+                "Fold subtasks or section of task / unfold all",
+            ),
+            (Sync, self.label(K::Sync), "Force sync with Todoist"),
+            (Sync, self.label(K::ExportView), "Export the view to Markdown or CSV"),
+            (
+                Sync,
+                self.label(K::BackendStatus),
+                "Check the backend connection (enter a new token if rejected)",
+            ),
+            (Sync, "Ctrl+C".into(), "Quit application"),
+            (General, self.label(K::Help), "Toggle help panel"),
+            (General, self.label(K::ToggleSidebar), "Toggle sidebar visibility"),
+            (General, self.label(K::ToggleDetails), "Toggle the task detail pane"),
+            (General, self.label(K::Search), "Search tasks"),
+            (General, self.label(K::Logs), "Show logs"),
+            (General, self.label(K::ReloadConfig), "Reload the configuration file"),
+            (General, self.label(K::Quit), "Quit application"),
+            (General, "i".into(), "Change icon theme"),
+            (HelpScrolling, "j/k".into(), "Scroll help content down/up"),
+            (HelpScrolling, "↑↓".into(), "Scroll help content up/down"),
+            (HelpScrolling, "PageUp/Down".into(), "Page through help content"),
+            (HelpScrolling, "Home".into(), "Jump to top of help"),
+            (HelpScrolling, "End".into(), "Jump to bottom of help"),
+        ];
+
+        bindings
+            .into_iter()
+            .map(|(category, keys, description)| KeyBinding {
+                category,
+                keys,
+                description,
+            })
+            .collect()
+    }
+}
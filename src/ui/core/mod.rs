@@ -10,7 +10,10 @@
 //! - [`component`] - Base component trait and rendering abstractions
 //! - [`context`] - Application context and shared state management
 //! - [`event_handler`] - Event processing and keyboard/mouse input handling
+//! - [`keymap`] - Configurable key bindings, also listed in the help dialog
 //! - [`task_manager`] - Background task management and async operation handling
+//! - [`theme`] - Color themes selected in the configuration
+//! - [`undo`] - Undo history for task operations
 //!
 //! # Architecture
 //!
@@ -30,11 +33,17 @@ pub mod actions;
 pub mod component;
 pub mod context;
 pub mod event_handler;
+pub mod keymap;
 pub mod task_manager;
+pub mod theme;
+pub mod undo;
 
 // Re-export core types for easier access from other modules
-pub use actions::{Action, DialogType, SidebarSelection};
+pub use actions::{Action, BatchReport, DialogType, SidebarSection, SidebarSelection};
 pub use component::Component;
 pub use context::AppContext;
 pub use event_handler::{EventHandler, EventType};
+pub use keymap::{KeyAction, KeyBinding, KeyCategory, KeyMap, KeyPress, StatusContext};
 pub use task_manager::{TaskId, TaskManager, TaskResult};
+pub use theme::Theme;
+pub use undo::{UndoEntry, UndoStack};
@@ -1,5 +1,9 @@
-use super::actions::{Action, SidebarSelection};
-use crate::constants::UI_LOADING_DATA_FROM_STORAGE;
+use super::actions::{Action, BatchReport, DialogType, SidebarSelection};
+use crate::backend::is_auth_error;
+use crate::constants::{
+    ERROR_API_TOKEN_UPDATE_FAILED, ERROR_FILTER_FAILED, SUCCESS_API_TOKEN_UPDATED, UI_LOADING_DATA_FROM_STORAGE,
+};
+use crate::sync::batch::{BatchCancel, BatchProgress, ProgressSender};
 use crate::sync::{SyncService, SyncStatus};
 use std::collections::HashMap;
 use tokio::sync::mpsc;
@@ -30,6 +34,7 @@ pub enum TaskResult {
         query: String,
         results: Vec<crate::entities::task::Model>,
     },
+    DuplicateScanCompleted(usize),
     Other(String),
 }
 
@@ -37,6 +42,7 @@ pub struct TaskManager {
     tasks: HashMap<TaskId, BackgroundTask>,
     next_task_id: TaskId,
     action_sender: mpsc::UnboundedSender<Action>,
+    active_batch: Option<(TaskId, BatchCancel)>,
 }
 
 impl TaskManager {
@@ -48,6 +54,7 @@ impl TaskManager {
                 tasks: HashMap::new(),
                 next_task_id: 1,
                 action_sender: tx,
+                active_batch: None,
             },
             rx,
         )
@@ -75,6 +82,11 @@ impl TaskManager {
                     let error_msg = e.to_string();
                     let result = TaskResult::SyncFailed(error_msg.clone());
                     let _ = action_sender.send(Action::SyncFailed(error_msg));
+                    if is_auth_error(&e) {
+                        let _ = action_sender.send(Action::ShowDialog(DialogType::TokenPrompt {
+                            retry: Some(Box::new(Action::StartSync)),
+                        }));
+                    }
                     Ok(result)
                 }
             }
@@ -92,7 +104,18 @@ impl TaskManager {
     }
 
     /// Spawn a background task operation (create, update, delete)
-    pub fn spawn_task_operation<F, Fut>(&mut self, operation: F, description: String) -> TaskId
+    ///
+    /// `on_success` is dispatched only if the operation succeeds, e.g. to record how to
+    /// undo it. If the operation fails because the backend rejected the API token, a
+    /// token prompt is shown instead of an error, and `retry` is dispatched once a new
+    /// token has been saved.
+    pub fn spawn_task_operation<F, Fut>(
+        &mut self,
+        operation: F,
+        description: String,
+        retry: Option<Action>,
+        on_success: Option<Action>,
+    ) -> TaskId
     where
         F: FnOnce() -> Fut + Send + 'static,
         Fut: std::future::Future<Output = anyhow::Result<String>> + Send + 'static,
@@ -108,6 +131,9 @@ impl TaskManager {
             match operation().await {
                 Ok(message) => {
                     let result = TaskResult::TaskOperationCompleted(message.clone());
+                    if let Some(action) = on_success {
+                        let _ = action_sender.send(action);
+                    }
                     // Send refresh action to update UI with latest data from database
                     let _ = action_sender.send(Action::RefreshData);
 
@@ -119,11 +145,15 @@ impl TaskManager {
                     Ok(result)
                 }
                 Err(e) => {
-                    let error_msg = format!("Operation failed: {}", e);
+                    let error_msg = format!("Operation failed: {:#}", e);
                     let result = TaskResult::Other(error_msg.clone());
-                    let _ = action_sender.send(Action::ShowDialog(crate::ui::core::actions::DialogType::Error(
-                        error_msg,
-                    )));
+                    if is_auth_error(&e) {
+                        let _ = action_sender.send(Action::ShowDialog(DialogType::TokenPrompt {
+                            retry: retry.map(Box::new),
+                        }));
+                    } else {
+                        let _ = action_sender.send(Action::ShowDialog(DialogType::Error(error_msg)));
+                    }
                     Ok(result)
                 }
             }
@@ -140,6 +170,130 @@ impl TaskManager {
         task_id
     }
 
+    /// Spawn a background update of the backend's API token.
+    ///
+    /// On success the new token is in use immediately and `retry` (the operation that
+    /// failed with an authentication error) is dispatched again.
+    pub fn spawn_token_update(&mut self, sync_service: SyncService, token: String, retry: Option<Action>) -> TaskId {
+        let task_id = self.next_task_id;
+        self.next_task_id += 1;
+
+        let action_sender = self.action_sender.clone();
+        let description = "Update API token".to_string();
+
+        let handle = tokio::spawn(async move {
+            match sync_service.update_api_token(&token).await {
+                Ok(()) => {
+                    let _ = action_sender.send(Action::ShowDialog(DialogType::Info(
+                        SUCCESS_API_TOKEN_UPDATED.to_string(),
+                    )));
+                    if let Some(retry) = retry {
+                        let _ = action_sender.send(retry);
+                    }
+                    Ok(TaskResult::TaskOperationCompleted(
+                        SUCCESS_API_TOKEN_UPDATED.to_string(),
+                    ))
+                }
+                Err(e) => {
+                    let error_msg = format!("{}: {}", ERROR_API_TOKEN_UPDATE_FAILED, e);
+                    let _ = action_sender.send(Action::ShowDialog(DialogType::Error(error_msg.clone())));
+                    Ok(TaskResult::Other(error_msg))
+                }
+            }
+        });
+
+        let task = BackgroundTask {
+            id: task_id,
+            handle,
+            description,
+            started_at: std::time::Instant::now(),
+        };
+
+        self.tasks.insert(task_id, task);
+        task_id
+    }
+
+    /// Spawn a batch operation that reports progress and can be cancelled.
+    ///
+    /// A progress dialog is shown immediately and updated with every
+    /// [`BatchProgress`](crate::sync::batch::BatchProgress) the operation emits. The
+    /// string returned by the operation is shown as a summary once it finishes, as
+    /// `report` says. Only one batch can run at a time; `None` is returned if one is
+    /// already running.
+    pub fn spawn_batch_operation<F, Fut>(&mut self, title: String, report: BatchReport, operation: F) -> Option<TaskId>
+    where
+        F: FnOnce(ProgressSender, BatchCancel) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = String> + Send + 'static,
+    {
+        if self.active_batch.is_some() {
+            return None;
+        }
+
+        let task_id = self.next_task_id;
+        self.next_task_id += 1;
+
+        let action_sender = self.action_sender.clone();
+        let cancel = BatchCancel::new();
+        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel::<BatchProgress>();
+
+        let _ = action_sender.send(Action::ShowDialog(DialogType::Progress {
+            title: title.clone(),
+            processed: 0,
+            total: 0,
+            cancelling: false,
+        }));
+
+        let batch_cancel = cancel.clone();
+        let handle = tokio::spawn(async move {
+            // Forward progress updates as actions until the operation drops its sender
+            let progress_sender = action_sender.clone();
+            let forward_progress = async move {
+                while let Some(progress) = progress_rx.recv().await {
+                    let _ = progress_sender.send(Action::BatchProgress {
+                        processed: progress.processed,
+                        total: progress.total,
+                    });
+                }
+            };
+
+            let (summary, ()) = tokio::join!(operation(progress_tx, batch_cancel), forward_progress);
+
+            let _ = action_sender.send(Action::BatchCompleted {
+                summary: summary.clone(),
+                report,
+            });
+            let _ = action_sender.send(Action::RefreshData);
+            Ok(TaskResult::TaskOperationCompleted(summary))
+        });
+
+        let task = BackgroundTask {
+            id: task_id,
+            handle,
+            description: title,
+            started_at: std::time::Instant::now(),
+        };
+
+        self.tasks.insert(task_id, task);
+        self.active_batch = Some((task_id, cancel));
+        Some(task_id)
+    }
+
+    /// Request cancellation of the running batch operation, if any
+    pub fn cancel_batch(&self) -> bool {
+        match &self.active_batch {
+            Some((_, cancel)) => {
+                cancel.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Check if a batch operation is currently running
+    pub fn is_batch_running(&self) -> bool {
+        self.active_batch.is_some()
+    }
+
     /// Check for completed tasks and clean them up
     pub fn cleanup_finished_tasks(&mut self) -> Vec<(TaskId, anyhow::Result<TaskResult>)> {
         let mut completed = Vec::new();
@@ -152,6 +306,9 @@ impl TaskManager {
         }
 
         for task_id in to_remove {
+            if self.active_batch.as_ref().is_some_and(|(batch_id, _)| *batch_id == task_id) {
+                self.active_batch = None;
+            }
             if let Some(_task) = self.tasks.remove(&task_id) {
                 // Since the task is finished, we'll just mark it as completed
                 // The actual result was already sent via the action channel
@@ -181,10 +338,15 @@ impl TaskManager {
     }
 
     /// Spawn a background data loading operation
+    ///
+    /// `filter_queries` are the saved filter queries that `SidebarSelection::Filter` indexes.
     pub fn spawn_data_load(
         &mut self,
         sync_service: SyncService,
         sidebar_selection: SidebarSelection,
+        filter_queries: Vec<String>,
+        someday_label: String,
+        upcoming_days: u32,
         is_initial_load: bool,
     ) -> TaskId {
         let task_id = self.next_task_id;
@@ -204,7 +366,27 @@ impl TaskManager {
                     let tasks = match sidebar_selection {
                         SidebarSelection::Today => sync_service.get_tasks_for_today().await.unwrap_or_default(),
                         SidebarSelection::Tomorrow => sync_service.get_tasks_for_tomorrow().await.unwrap_or_default(),
-                        SidebarSelection::Upcoming => sync_service.get_tasks_for_upcoming().await.unwrap_or_default(),
+                        SidebarSelection::Upcoming => {
+                            sync_service.get_tasks_for_upcoming(upcoming_days).await.unwrap_or_default()
+                        }
+                        SidebarSelection::Postponed => {
+                            sync_service.get_most_postponed_tasks().await.unwrap_or_default()
+                        }
+                        SidebarSelection::All => sync_service.get_all_tasks().await.unwrap_or_default(),
+                        SidebarSelection::Filter(index) => match filter_queries.get(index) {
+                            Some(query) => match sync_service.get_tasks_for_filter(query).await {
+                                Ok(tasks) => tasks,
+                                Err(e) => {
+                                    // Unsupported terms are a config problem worth telling the user about
+                                    let _ = action_sender.send(Action::ShowDialog(DialogType::Error(format!(
+                                        "{}: {:#}",
+                                        ERROR_FILTER_FAILED, e
+                                    ))));
+                                    Vec::new()
+                                }
+                            },
+                            None => Vec::new(),
+                        },
                         SidebarSelection::Project(index) => {
                             if let Some(project) = projects.get(index) {
                                 sync_service.get_tasks_for_project(&project.uuid).await.unwrap_or_default()
@@ -221,6 +403,17 @@ impl TaskManager {
                         }
                     };
 
+                    // Someday/maybe tasks stay out of the date-based planning views
+                    let tasks = match sidebar_selection {
+                        SidebarSelection::Today | SidebarSelection::Tomorrow | SidebarSelection::Upcoming => {
+                            sync_service
+                                .exclude_tasks_with_label(tasks, &someday_label)
+                                .await
+                                .unwrap_or_default()
+                        }
+                        _ => tasks,
+                    };
+
                     let result = TaskResult::DataLoadCompleted {
                         projects: projects.clone(),
                         labels: labels.clone(),
@@ -306,6 +499,74 @@ impl TaskManager {
         self.tasks.insert(task_id, task);
         task_id
     }
+
+    /// Spawn a background scan for duplicate tasks
+    pub fn spawn_duplicate_scan(&mut self, sync_service: SyncService) -> TaskId {
+        let task_id = self.next_task_id;
+        self.next_task_id += 1;
+
+        let action_sender = self.action_sender.clone();
+        let description = "Scanning for duplicate tasks".to_string();
+
+        let handle = tokio::spawn(async move {
+            match sync_service.find_duplicate_tasks().await {
+                Ok(groups) => {
+                    let result = TaskResult::DuplicateScanCompleted(groups.len());
+                    let _ = action_sender.send(Action::DuplicateTasksLoaded(groups));
+                    Ok(result)
+                }
+                Err(e) => {
+                    let error_msg = format!("Failed to scan for duplicate tasks: {}", e);
+                    let _ = action_sender.send(Action::ShowDialog(DialogType::Error(error_msg.clone())));
+                    Ok(TaskResult::Other(error_msg))
+                }
+            }
+        });
+
+        let task = BackgroundTask {
+            id: task_id,
+            handle,
+            description,
+            started_at: std::time::Instant::now(),
+        };
+
+        self.tasks.insert(task_id, task);
+        task_id
+    }
+
+    /// Spawn a connection check of the active backend
+    pub fn spawn_backend_status(&mut self, sync_service: SyncService) -> TaskId {
+        let task_id = self.next_task_id;
+        self.next_task_id += 1;
+
+        let action_sender = self.action_sender.clone();
+        let description = "Checking backend status".to_string();
+
+        let handle = tokio::spawn(async move {
+            match sync_service.backend_status().await {
+                Ok(status) => {
+                    let result = TaskResult::Other(format!("Backend {} checked", status.name));
+                    let _ = action_sender.send(Action::BackendStatusLoaded(status));
+                    Ok(result)
+                }
+                Err(e) => {
+                    let error_msg = format!("Failed to check backend status: {}", e);
+                    let _ = action_sender.send(Action::ShowDialog(DialogType::Error(error_msg.clone())));
+                    Ok(TaskResult::Other(error_msg))
+                }
+            }
+        });
+
+        let task = BackgroundTask {
+            id: task_id,
+            handle,
+            description,
+            started_at: std::time::Instant::now(),
+        };
+
+        self.tasks.insert(task_id, task);
+        task_id
+    }
 }
 
 impl Drop for TaskManager {
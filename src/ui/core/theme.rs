@@ -0,0 +1,177 @@
+//! Color themes.
+//!
+//! Components and dialogs take their colors from a [`Theme`] by role (accent, error,
+//! muted text...) rather than naming terminal colors. The theme is picked by name in the
+//! `[theme]` configuration section, and any role can be overridden there with a color
+//! name (`"light-blue"`), an ANSI index (`"244"`) or a hex value (`"#268bd2"`).
+
+use crate::config::ThemeConfig;
+use anyhow::Result;
+use ratatui::style::Color;
+use std::str::FromStr;
+
+/// Colors used across the UI, by role
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Main dialog frames, headers and navigation shortcuts
+    pub accent: Color,
+    /// Project, deadline and move dialogs
+    pub secondary: Color,
+    /// Confirmations, the sync indicator and the selected sidebar entry
+    pub warning: Color,
+    /// Destructive actions and errors
+    pub error: Color,
+    /// Confirming actions and completed tasks
+    pub success: Color,
+    /// Info dialogs
+    pub info: Color,
+    /// Regular text
+    pub text: Color,
+    /// Secondary text, such as shortcut descriptions
+    pub muted: Color,
+    /// Borders of dialog fields and scrollbar tracks
+    pub border: Color,
+    /// Panel borders and hints that should stay in the background
+    pub dim: Color,
+    /// Background of the selected row
+    pub selected_bg: Color,
+    /// Due dates of tasks
+    pub due: Color,
+    /// Overdue due dates and missed deadlines
+    pub overdue: Color,
+    /// Priority flags, from p1 to p4
+    pub priority: [Color; 4],
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            accent: Color::Cyan,
+            secondary: Color::Magenta,
+            warning: Color::Yellow,
+            error: Color::Red,
+            success: Color::Green,
+            info: Color::Blue,
+            text: Color::White,
+            muted: Color::Gray,
+            border: Color::Gray,
+            dim: Color::DarkGray,
+            selected_bg: Color::DarkGray,
+            due: Color::Rgb(255, 165, 0),
+            overdue: Color::Red,
+            priority: [Color::Red, Color::Rgb(255, 165, 0), Color::Blue, Color::White],
+        }
+    }
+}
+
+impl Theme {
+    /// Names of the built-in themes
+    pub const NAMES: [&'static str; 2] = ["default", "solarized-dark"];
+
+    /// Roles that can be overridden in the `[theme]` section; priorities are `priority_1` to `priority_4`
+    pub const ROLES: [&'static str; 17] = [
+        "accent",
+        "secondary",
+        "warning",
+        "error",
+        "success",
+        "info",
+        "text",
+        "muted",
+        "border",
+        "dim",
+        "selected_bg",
+        "due",
+        "overdue",
+        "priority_1",
+        "priority_2",
+        "priority_3",
+        "priority_4",
+    ];
+
+    /// A built-in theme by name
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default()),
+            "solarized-dark" => Some(Self::solarized_dark()),
+            _ => None,
+        }
+    }
+
+    /// The Solarized dark palette
+    pub fn solarized_dark() -> Self {
+        let base01 = Color::Rgb(0x58, 0x6e, 0x75);
+        let base02 = Color::Rgb(0x07, 0x36, 0x42);
+        let base0 = Color::Rgb(0x83, 0x94, 0x96);
+        let base1 = Color::Rgb(0x93, 0xa1, 0xa1);
+        let red = Color::Rgb(0xdc, 0x32, 0x2f);
+        Self {
+            accent: Color::Rgb(0x2a, 0xa1, 0x98),
+            secondary: Color::Rgb(0xd3, 0x36, 0x82),
+            warning: Color::Rgb(0xb5, 0x89, 0x00),
+            error: red,
+            success: Color::Rgb(0x85, 0x99, 0x00),
+            info: Color::Rgb(0x26, 0x8b, 0xd2),
+            text: base1,
+            muted: base0,
+            border: base01,
+            dim: base01,
+            selected_bg: base02,
+            due: Color::Rgb(0xcb, 0x4b, 0x16),
+            overdue: red,
+            priority: [red, Color::Rgb(0xcb, 0x4b, 0x16), Color::Rgb(0x6c, 0x71, 0xc4), base0],
+        }
+    }
+
+    /// The theme selected in the configuration, with its color overrides applied
+    ///
+    /// # Errors
+    /// Returns an error for unknown theme names and roles, and colors that can't be parsed
+    pub fn from_config(config: &ThemeConfig) -> Result<Self> {
+        let mut theme = Self::builtin(&config.name).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unknown theme '{}' (built-in themes: {})",
+                config.name,
+                Self::NAMES.join(", ")
+            )
+        })?;
+
+        for (role, value) in &config.colors {
+            let color = Color::from_str(value)
+                .map_err(|_| anyhow::anyhow!("Invalid color '{}' for theme color '{}'", value, role))?;
+            let slot = match role.as_str() {
+                "accent" => &mut theme.accent,
+                "secondary" => &mut theme.secondary,
+                "warning" => &mut theme.warning,
+                "error" => &mut theme.error,
+                "success" => &mut theme.success,
+                "info" => &mut theme.info,
+                "text" => &mut theme.text,
+                "muted" => &mut theme.muted,
+                "border" => &mut theme.border,
+                "dim" => &mut theme.dim,
+                "selected_bg" => &mut theme.selected_bg,
+                "due" => &mut theme.due,
+                "overdue" => &mut theme.overdue,
+                "priority_1" => &mut theme.priority[0],
+                "priority_2" => &mut theme.priority[1],
+                "priority_3" => &mut theme.priority[2],
+                "priority_4" => &mut theme.priority[3],
+                _ => anyhow::bail!("Unknown theme color '{}' (colors: {})", role, Self::ROLES.join(", ")),
+            };
+            *slot = color;
+        }
+
+        Ok(theme)
+    }
+
+    /// Flag color of a task priority on the API scale (4 is p1); unknown values use p4's
+    pub fn priority_color(&self, priority: i32) -> Color {
+        match priority {
+            4 => self.priority[0],
+            3 => self.priority[1],
+            2 => self.priority[2],
+            _ => self.priority[3],
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Undo history for task operations.
+//!
+//! Completing, deleting and changing the priority of a task record how to revert them
+//! once they have succeeded. Undoing takes the most recent entry and re-issues its
+//! inverse as a regular task operation, so it goes through the backend like any other
+//! change.
+
+use crate::sync::tasks::TaskSnapshot;
+use std::collections::{HashSet, VecDeque};
+use uuid::Uuid;
+
+/// How many operations can be undone; older ones are forgotten
+pub const UNDO_HISTORY_LIMIT: usize = 50;
+
+/// A task operation that can be reverted, with the values it replaced
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UndoEntry {
+    /// The task was completed; undoing reopens it
+    Complete { task_uuid: Uuid },
+    /// The task was deleted; undoing creates it again with the labels and dates it had
+    Delete { task_uuid: Uuid, snapshot: TaskSnapshot },
+    /// The task's priority was changed from `previous`; undoing sets it back
+    Priority { task_uuid: Uuid, previous: i32 },
+}
+
+impl UndoEntry {
+    /// The task the operation changed
+    pub fn task_uuid(&self) -> Uuid {
+        match self {
+            UndoEntry::Complete { task_uuid }
+            | UndoEntry::Delete { task_uuid, .. }
+            | UndoEntry::Priority { task_uuid, .. } => *task_uuid,
+        }
+    }
+
+    /// The task operation that reverts this one, as its name and info
+    pub fn inverse(&self) -> (&'static str, String) {
+        match self {
+            UndoEntry::Complete { task_uuid } => ("Restore task", task_uuid.to_string()),
+            UndoEntry::Delete { task_uuid, snapshot } => (
+                "Restore deleted task",
+                format!("{}|{}", task_uuid, serde_json::to_string(snapshot).unwrap_or_default()),
+            ),
+            UndoEntry::Priority { task_uuid, previous } => ("Cycle priority", format!("{}|{}", task_uuid, previous)),
+        }
+    }
+
+    /// What was undone, for the confirmation toast
+    pub fn describe(&self) -> &'static str {
+        match self {
+            UndoEntry::Complete { .. } => "Undid completion",
+            UndoEntry::Delete { .. } => "Undid deletion",
+            UndoEntry::Priority { .. } => "Undid priority change",
+        }
+    }
+}
+
+/// Most recent task operations, newest last
+#[derive(Debug, Clone)]
+pub struct UndoStack {
+    entries: VecDeque<UndoEntry>,
+    capacity: usize,
+}
+
+impl Default for UndoStack {
+    fn default() -> Self {
+        Self::new(UNDO_HISTORY_LIMIT)
+    }
+}
+
+impl UndoStack {
+    /// An empty history remembering up to `capacity` operations
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    /// Record an operation, forgetting the oldest one when the history is full
+    pub fn push(&mut self, entry: UndoEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Take the most recent operation
+    pub fn pop(&mut self) -> Option<UndoEntry> {
+        self.entries.pop_back()
+    }
+
+    /// Tasks changed by the remembered operations, oldest first
+    pub fn task_uuids(&self) -> impl Iterator<Item = Uuid> + '_ {
+        self.entries.iter().map(UndoEntry::task_uuid)
+    }
+
+    /// Forget the operations on tasks that can't be restored anymore, e.g. because a full
+    /// sync removed them from local storage
+    pub fn forget_tasks(&mut self, task_uuids: &HashSet<Uuid>) {
+        self.entries.retain(|entry| !task_uuids.contains(&entry.task_uuid()));
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
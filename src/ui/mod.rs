@@ -29,18 +29,11 @@
 //! use terminalist::ui::run_app;
 //! use terminalist::sync::SyncService;
 //! use terminalist::config::Config;
-//! use terminalist::backend_registry::BackendRegistry;
-//! use terminalist::storage::LocalStorage;
-//! use std::sync::Arc;
-//! use tokio::sync::Mutex;
 //!
 //! # async fn example() -> anyhow::Result<()> {
 //! let config = Config::load()?;
-//! let storage = Arc::new(Mutex::new(LocalStorage::new(false).await?));
-//! let backend_registry = Arc::new(BackendRegistry::new(storage));
-//! // ... initialize and load backends ...
-//! # let backend_uuid = uuid::Uuid::new_v4();
-//! let sync_service = SyncService::new(backend_registry, backend_uuid, false).await?;
+//! let api_token = std::env::var("TODOIST_API_TOKEN")?;
+//! let sync_service = SyncService::connect("todoist", "My Todoist", &api_token, false).await?;
 //!
 //! run_app(sync_service, config).await?;
 //! # Ok(())
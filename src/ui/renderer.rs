@@ -110,7 +110,19 @@ where
                         }
                     }
                 }
+                // Keep views fresh while terminalist stays open
+                if app.maybe_start_auto_sync() {
+                    needs_render = true;
+                }
+                // Expired highlights (e.g. rescheduled recurring tasks) are cleared on tick
+                if app.clear_expired_highlights() {
+                    needs_render = true;
+                }
                 // Don't render on every tick - only when there are actual background actions
+                // or when a live element (like the focus timer) is on screen
+                if app.needs_periodic_render() {
+                    needs_render = true;
+                }
             }
             EventType::Render => {
                 needs_render = true;
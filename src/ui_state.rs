@@ -0,0 +1,87 @@
+//! Persisted UI state for Terminalist
+//!
+//! The local database is rebuilt from the backend on every start, so UI choices that
+//! should survive a restart (such as collapsed sidebar sections) are kept in a small
+//! TOML file in the XDG data directory instead.
+
+use anyhow::{Context, Result};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Maximum number of projects kept in the recently used list
+pub const RECENT_PROJECTS_LIMIT: usize = 5;
+
+/// UI state saved between sessions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiState {
+    /// Sidebar sections the user collapsed ("views", "labels", "projects")
+    pub collapsed_sidebar_sections: Vec<String>,
+    /// Projects recently used for task creation, most recent first
+    pub recent_project_uuids: Vec<Uuid>,
+    /// Whether the sidebar was shown when last toggled (restored when `ui.persist_state` is on)
+    pub sidebar_visible: Option<bool>,
+    /// Whether the task detail pane was shown when last toggled (restored when `ui.persist_state` is on)
+    pub details_visible: Option<bool>,
+    /// Sidebar width in columns last set by dragging its border (restored when `ui.persist_state` is on)
+    pub sidebar_width: Option<u16>,
+    /// Last opened view: a special view name, `project:<remote id>` or `label:<remote id>`
+    /// (restored when `ui.persist_state` is on)
+    pub last_view: Option<String>,
+    /// Upcoming view horizon in days (restored when `ui.persist_state` is on)
+    pub upcoming_days: Option<u32>,
+}
+
+impl UiState {
+    /// Path of the state file (`<XDG data dir>/terminalist/ui_state.toml`)
+    pub fn default_path() -> Result<PathBuf> {
+        let data_dir = dirs::data_dir().context("Failed to get XDG data directory")?;
+        Ok(data_dir.join("terminalist").join("ui_state.toml"))
+    }
+
+    /// Load the saved state, falling back to defaults if it is missing or unreadable
+    pub fn load() -> Self {
+        let result = Self::default_path().and_then(|path| Self::load_from_file(&path));
+        result.unwrap_or_else(|e| {
+            warn!("UI state: Using defaults, failed to load saved state: {:#}", e);
+            Self::default()
+        })
+    }
+
+    /// Load state from a specific file; a missing file yields the default state
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        if !path.as_ref().exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read UI state file: {}", path.as_ref().display()))?;
+        toml::from_str(&content).with_context(|| format!("Failed to parse UI state file: {}", path.as_ref().display()))
+    }
+
+    /// Move a project to the front of the recently used list, dropping the oldest entries
+    pub fn record_recent_project(&mut self, project_uuid: Uuid) {
+        self.recent_project_uuids.retain(|uuid| *uuid != project_uuid);
+        self.recent_project_uuids.insert(0, project_uuid);
+        self.recent_project_uuids.truncate(RECENT_PROJECTS_LIMIT);
+    }
+
+    /// Save the state to its default location
+    pub fn save(&self) -> Result<()> {
+        self.save_to_file(Self::default_path()?)
+    }
+
+    /// Save the state to a specific file, creating parent directories as needed
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create UI state directory: {}", parent.display()))?;
+        }
+
+        let content = toml::to_string_pretty(self).context("Failed to serialize UI state")?;
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write UI state file: {}", path.as_ref().display()))
+    }
+}
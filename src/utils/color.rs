@@ -0,0 +1,88 @@
+//! Named color palette shared with the backend.
+//!
+//! Todoist identifies project and label colors by name (e.g. `"berry_red"`).
+//! This module lists the available names and maps them to terminal colors.
+
+use ratatui::style::Color;
+
+/// Default color assigned by Todoist to new labels and projects
+pub const DEFAULT_COLOR: &str = "charcoal";
+
+/// All color names supported by Todoist, in the order shown by its color picker
+pub const COLOR_NAMES: &[&str] = &[
+    "berry_red",
+    "red",
+    "orange",
+    "yellow",
+    "olive_green",
+    "lime_green",
+    "green",
+    "mint_green",
+    "teal",
+    "sky_blue",
+    "light_blue",
+    "blue",
+    "grape",
+    "violet",
+    "lavender",
+    "magenta",
+    "salmon",
+    "charcoal",
+    "grey",
+    "taupe",
+];
+
+/// Position of a color name in [`COLOR_NAMES`], or of [`DEFAULT_COLOR`] for unknown names
+#[must_use]
+pub fn index_of(name: &str) -> usize {
+    COLOR_NAMES
+        .iter()
+        .position(|c| *c == name)
+        .or_else(|| COLOR_NAMES.iter().position(|c| *c == DEFAULT_COLOR))
+        .unwrap_or(0)
+}
+
+/// Convert a Todoist color name to a terminal color.
+///
+/// Unknown names fall back to gray so that new backend colors never break rendering.
+#[must_use]
+pub fn to_terminal_color(name: &str) -> Color {
+    match name {
+        "berry_red" => Color::Rgb(0xb8, 0x25, 0x6f),
+        "red" => Color::Rgb(0xdb, 0x40, 0x35),
+        "orange" => Color::Rgb(0xff, 0x99, 0x33),
+        "yellow" => Color::Rgb(0xfa, 0xd0, 0x00),
+        "olive_green" => Color::Rgb(0xaf, 0xb8, 0x3b),
+        "lime_green" => Color::Rgb(0x7e, 0xcc, 0x49),
+        "green" => Color::Rgb(0x29, 0x94, 0x38),
+        "mint_green" => Color::Rgb(0x6a, 0xcc, 0xbc),
+        "teal" => Color::Rgb(0x15, 0x8f, 0xad),
+        "sky_blue" => Color::Rgb(0x14, 0xaa, 0xf5),
+        "light_blue" => Color::Rgb(0x96, 0xc3, 0xeb),
+        "blue" => Color::Rgb(0x40, 0x73, 0xff),
+        "grape" => Color::Rgb(0x88, 0x4d, 0xff),
+        "violet" => Color::Rgb(0xaf, 0x38, 0xeb),
+        "lavender" => Color::Rgb(0xeb, 0x96, 0xeb),
+        "magenta" => Color::Rgb(0xe0, 0x51, 0x94),
+        "salmon" => Color::Rgb(0xff, 0x8d, 0x85),
+        "charcoal" => Color::Rgb(0x80, 0x80, 0x80),
+        "grey" => Color::Rgb(0xb8, 0xb8, 0xb8),
+        "taupe" => Color::Rgb(0xcc, 0xac, 0x93),
+        _ => Color::Gray,
+    }
+}
+
+/// Human-readable name for a Todoist color (e.g. `"berry_red"` -> `"Berry Red"`)
+#[must_use]
+pub fn display_name(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
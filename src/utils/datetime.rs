@@ -2,12 +2,62 @@
 //!
 //! This module provides functions for date manipulation and human-readable formatting,
 //! similar to how Todoist displays dates (e.g., "yesterday", "today", "tomorrow").
+//!
+//! Code that depends on the current date reads it through [`now`] / [`today`] rather than
+//! `chrono::Local::now()`, so tests can pin the time with [`freeze_time`].
+//!
+//! All calendar days are local: "today", due dates and the `due_date` column the views
+//! filter on (see [`local_due_date`]) follow the system timezone, which can be changed
+//! with the `TZ` environment variable. Datetimes are stored in UTC and only converted
+//! here, so a task due late in the evening never lands on a different day depending on
+//! the code path that looks at it.
 
-use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, TimeZone, Weekday};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{
+    DateTime, Datelike, Duration, Local, Month, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday,
+};
+use std::cell::Cell;
 
 /// Standard date format used throughout the application for Todoist API compatibility
 pub const TODOIST_DATE_FORMAT: &str = "%Y-%m-%d";
 
+thread_local! {
+    /// Time reported by [`now`] on this thread instead of the system clock
+    static FROZEN_NOW: Cell<Option<DateTime<Local>>> = const { Cell::new(None) };
+}
+
+/// Current local time, from the system clock unless frozen with [`freeze_time`]
+pub fn now() -> DateTime<Local> {
+    FROZEN_NOW.with(Cell::get).unwrap_or_else(Local::now)
+}
+
+/// Current local date (see [`now`])
+pub fn today() -> NaiveDate {
+    now().date_naive()
+}
+
+/// Guard returned by [`freeze_time`]; restores the previous clock when dropped
+#[must_use = "the clock is unfrozen as soon as the guard is dropped"]
+pub struct FrozenTime {
+    previous: Option<DateTime<Local>>,
+}
+
+impl Drop for FrozenTime {
+    fn drop(&mut self) {
+        FROZEN_NOW.with(|frozen| frozen.set(self.previous));
+    }
+}
+
+/// Make [`now`] return `at` on the current thread until the returned guard is dropped
+///
+/// Intended for tests of date-dependent behavior. Only the calling thread is affected,
+/// so tests running in parallel (and background tasks on other threads) keep the
+/// system clock.
+pub fn freeze_time(at: DateTime<Local>) -> FrozenTime {
+    let previous = FROZEN_NOW.with(|frozen| frozen.replace(Some(at)));
+    FrozenTime { previous }
+}
+
 /// Parse a date string in YYYY-MM-DD format to NaiveDate
 ///
 /// # Arguments
@@ -19,6 +69,171 @@ pub fn parse_date(date_str: &str) -> Result<NaiveDate, chrono::ParseError> {
     NaiveDate::parse_from_str(date_str, TODOIST_DATE_FORMAT)
 }
 
+/// Parse a datetime string into local time
+///
+/// Accepts RFC 3339 with a timezone (e.g. "2025-01-15T14:30:00Z"), which is converted
+/// to local time, and timezone-less "YYYY-MM-DDTHH:MM:SS" or "YYYY-MM-DD HH:MM:SS"
+/// (optionally with fractional seconds), which is taken as local time already.
+pub fn parse_datetime(datetime_str: &str) -> Option<DateTime<Local>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(datetime_str) {
+        return Some(dt.with_timezone(&Local));
+    }
+
+    ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(datetime_str, format).ok())
+        .map(|dt| {
+            Local
+                .from_local_datetime(&dt)
+                .single()
+                .unwrap_or_else(|| Local.from_utc_datetime(&dt))
+        })
+}
+
+/// A task due date, parsed once from the `due_date` / `due_datetime` strings stored on a task
+///
+/// Use this instead of comparing the raw strings: a datetime is stored in UTC, so its
+/// calendar day can differ from the local one. The same parsing works for other date
+/// fields such as deadlines (`Due::parse(deadline, None)`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Due {
+    /// Due some time during a calendar day
+    Date(NaiveDate),
+    /// Due at a specific moment, in local time
+    DateTime(DateTime<Local>),
+}
+
+impl Due {
+    /// Parse the stored due fields, preferring the precise datetime when it is valid
+    ///
+    /// Returns `None` if neither field holds a recognizable date.
+    pub fn parse(due_date: Option<&str>, due_datetime: Option<&str>) -> Option<Self> {
+        if let Some(dt) = due_datetime.and_then(parse_datetime) {
+            return Some(Self::DateTime(dt));
+        }
+        let due_date = due_date?;
+        match parse_date(due_date) {
+            Ok(date) => Some(Self::Date(date)),
+            Err(_) => parse_datetime(due_date).map(Self::DateTime),
+        }
+    }
+
+    /// Local calendar day the task is due on
+    pub fn date(&self) -> NaiveDate {
+        match self {
+            Self::Date(date) => *date,
+            Self::DateTime(dt) => dt.date_naive(),
+        }
+    }
+
+    /// Whether the due date has passed: an earlier day, or an earlier time today
+    pub fn is_overdue(&self, now: DateTime<Local>) -> bool {
+        match self {
+            Self::Date(date) => *date < now.date_naive(),
+            Self::DateTime(dt) => *dt < now,
+        }
+    }
+
+    /// Whether the task is due on the same local day as `now`
+    pub fn is_today(&self, now: DateTime<Local>) -> bool {
+        self.date() == now.date_naive()
+    }
+
+    /// Todoist-style text relative to `now`, e.g. "tomorrow" or "next Friday at 14:30"
+    pub fn humanize(&self, now: DateTime<Local>) -> String {
+        let day = human_day(self.date(), now.date_naive());
+        match self {
+            Self::Date(_) => day,
+            Self::DateTime(dt) => format!("{} at {}", day, dt.format("%H:%M")),
+        }
+    }
+
+    /// Short text relative to `now` (see [`format_relative`]), with the time for datetimes
+    pub fn relative(&self, now: DateTime<Local>, date_format: &str, time_format: &str) -> String {
+        let day = format_relative(self.date(), now.date_naive(), date_format);
+        match self {
+            Self::Date(_) => day,
+            Self::DateTime(dt) => format!("{} {}", day, dt.format(time_format)),
+        }
+    }
+
+    /// Absolute text using strftime formats, e.g. `display("%Y-%m-%d", "%H:%M")`
+    ///
+    /// The time is only shown for datetimes.
+    pub fn display(&self, date_format: &str, time_format: &str) -> String {
+        match self {
+            Self::Date(date) => date.format(date_format).to_string(),
+            Self::DateTime(dt) => format!("{} {}", dt.format(date_format), dt.format(time_format)),
+        }
+    }
+}
+
+/// Short text for a due date relative to `today`, as shown in task rows
+///
+/// "Today", "Tomorrow" and "Yesterday", the weekday ("Mon") within the coming week, the
+/// number of days ("3d ago") within the past week, and `date_format` beyond that.
+pub fn format_relative(date: NaiveDate, today: NaiveDate, date_format: &str) -> String {
+    match (date - today).num_days() {
+        0 => "Today".to_string(),
+        1 => "Tomorrow".to_string(),
+        -1 => "Yesterday".to_string(),
+        2..=6 => date.format("%a").to_string(),
+        days @ -6..=-2 => format!("{}d ago", -days),
+        _ => date.format(date_format).to_string(),
+    }
+}
+
+/// How long ago `then` was, as "just now", "4m ago", "2h ago" or "3d ago"
+///
+/// Times in the future (a clock that moved back) count as just now.
+pub fn format_ago(then: DateTime<Utc>) -> String {
+    let elapsed = now().with_timezone(&Utc) - then;
+    match elapsed.num_minutes() {
+        minutes if minutes < 1 => "just now".to_string(),
+        minutes if minutes < 60 => format!("{minutes}m ago"),
+        minutes if minutes < 60 * 24 => format!("{}h ago", minutes / 60),
+        minutes => format!("{}d ago", minutes / (60 * 24)),
+    }
+}
+
+/// Whether `format` is a valid strftime format string
+pub fn is_valid_format(format: &str) -> bool {
+    !StrftimeItems::new(format).any(|item| matches!(item, Item::Error))
+}
+
+/// The local day a task is due on, as stored in the `due_date` column
+///
+/// A datetime is kept in UTC, so its own date can be a day off from the local one;
+/// the local day of the datetime is used when there is one, otherwise `due_date` as is.
+pub fn local_due_date(due_date: Option<&str>, due_datetime: Option<&str>) -> Option<String> {
+    due_datetime
+        .and_then(parse_datetime)
+        .map(|dt| format_ymd(dt.date_naive()))
+        .or_else(|| due_date.map(str::to_string))
+}
+
+/// Whether changing a due date from `previous` to `new` pushes the task later
+///
+/// Setting a first due date or clearing it does not count as postponing.
+pub fn is_postponement(previous: Option<&str>, new: Option<&str>) -> bool {
+    match (Due::parse(previous, None), Due::parse(new, None)) {
+        (Some(previous), Some(new)) => new.date() > previous.date(),
+        _ => false,
+    }
+}
+
+/// Due value for moving a due date by `days` (negative moves it earlier), ready for a backend
+///
+/// A due time keeps its local time of day on the new date. A task without a due date is
+/// counted from `today`, so postponing it by one day makes it due tomorrow.
+pub fn shift_due(due: Option<Due>, days: i64, today: NaiveDate) -> String {
+    match due {
+        None => format_ymd(today + Duration::days(days)),
+        Some(Due::Date(date)) => format_ymd(date + Duration::days(days)),
+        Some(Due::DateTime(dt)) => format_due(dt.date_naive() + Duration::days(days), Some(dt.time())),
+    }
+}
+
 /// Format a NaiveDate to YYYY-MM-DD string
 pub fn format_ymd(d: NaiveDate) -> String {
     d.format(TODOIST_DATE_FORMAT).to_string()
@@ -26,7 +241,7 @@ pub fn format_ymd(d: NaiveDate) -> String {
 
 /// Format current local date to YYYY-MM-DD string
 pub fn format_today() -> String {
-    format_ymd(Local::now().date_naive())
+    format_ymd(today())
 }
 
 /// Format date with offset from today to YYYY-MM-DD string
@@ -37,7 +252,7 @@ pub fn format_today() -> String {
 /// # Returns
 /// * `String` - Date string in YYYY-MM-DD format
 pub fn format_date_with_offset(days_offset: i64) -> String {
-    let target_date = Local::now().date_naive() + Duration::days(days_offset);
+    let target_date = today() + Duration::days(days_offset);
     format_ymd(target_date)
 }
 
@@ -52,6 +267,251 @@ pub fn next_weekday(from: NaiveDate, target: Weekday) -> NaiveDate {
     from + Duration::days(delta)
 }
 
+/// Parse a date typed in plain English, relative to `today`.
+///
+/// Understands "today", "tomorrow", "next week" (Monday), "weekend" (Saturday), weekday
+/// names ("fri", "next friday"), "end of week" (Sunday), "end of month", "end of year",
+/// "in 3 days" / "2 weeks" / "1 month", YYYY-MM-DD and month-day dates ("mar 14", "14 march",
+/// the next time that day comes around). A leading "by" or "on" is ignored, so "by end of
+/// month" works too. Returns `None` for anything else.
+pub fn parse_natural_date(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let text = input.trim().to_lowercase();
+    let text = text
+        .strip_prefix("by ")
+        .or_else(|| text.strip_prefix("on "))
+        .unwrap_or(&text)
+        .trim();
+
+    match text {
+        "today" | "tod" => return Some(today),
+        "tomorrow" | "tom" => return Some(today + Duration::days(1)),
+        "next week" => return Some(next_weekday(today, Weekday::Mon)),
+        "weekend" | "this weekend" => return Some(next_weekday(today, Weekday::Sat)),
+        "end of week" | "eow" => {
+            let days_to_sunday = 6 - i64::from(today.weekday().num_days_from_monday());
+            return Some(today + Duration::days(days_to_sunday));
+        }
+        "end of month" | "eom" => {
+            let first_of_month = today.with_day(1)?;
+            return first_of_month.checked_add_months(Months::new(1)).map(|d| d - Duration::days(1));
+        }
+        "end of year" | "eoy" => return NaiveDate::from_ymd_opt(today.year(), 12, 31),
+        _ => {}
+    }
+
+    if let Ok(date) = parse_date(text) {
+        return Some(date);
+    }
+
+    if let Ok(weekday) = text.strip_prefix("next ").unwrap_or(text).parse::<Weekday>() {
+        return Some(next_weekday(today, weekday));
+    }
+
+    if let Some(date) = parse_month_day(text, today) {
+        return Some(date);
+    }
+
+    // "in 3 days", "2 weeks", "1 month"
+    let mut words = text.strip_prefix("in ").unwrap_or(text).split_whitespace();
+    let amount: u32 = words.next()?.parse().ok()?;
+    let unit = words.next()?;
+    if words.next().is_some() {
+        return None;
+    }
+    match unit.trim_end_matches('s') {
+        "day" => Some(today + Duration::days(i64::from(amount))),
+        "week" => Some(today + Duration::weeks(i64::from(amount))),
+        "month" => today.checked_add_months(Months::new(amount)),
+        _ => None,
+    }
+}
+
+/// Parse "mar 14", "march 14th" or "14 mar" as the next such day on or after `today`
+fn parse_month_day(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let (month, day) = match words.as_slice() {
+        [first, second] => match first.parse::<Month>() {
+            Ok(month) => (month, *second),
+            Err(_) => (second.parse::<Month>().ok()?, *first),
+        },
+        _ => return None,
+    };
+    let day: u32 = day.trim_end_matches(|c: char| c.is_ascii_alphabetic()).parse().ok()?;
+
+    let this_year = NaiveDate::from_ymd_opt(today.year(), month.number_from_month(), day);
+    match this_year {
+        Some(date) if date >= today => Some(date),
+        _ => NaiveDate::from_ymd_opt(today.year() + 1, month.number_from_month(), day),
+    }
+}
+
+/// Parse a time of day: "3pm", "3:30 pm", "15:00" or "noon".
+///
+/// Without "am"/"pm" a colon is required, so a bare number is never taken as a time.
+pub fn parse_time_of_day(input: &str) -> Option<NaiveTime> {
+    let text = input.trim().to_lowercase();
+    if text == "noon" {
+        return NaiveTime::from_hms_opt(12, 0, 0);
+    }
+
+    let (clock, meridiem) = if let Some(clock) = text.strip_suffix("am") {
+        (clock.trim_end(), Some(false))
+    } else if let Some(clock) = text.strip_suffix("pm") {
+        (clock.trim_end(), Some(true))
+    } else {
+        (text.as_str(), None)
+    };
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => (hour.parse::<u32>().ok()?, minute.parse::<u32>().ok()?),
+        Some(_) => return None,
+        None if meridiem.is_some() => (clock.parse::<u32>().ok()?, 0),
+        None => return None,
+    };
+
+    let hour = match meridiem {
+        Some(_) if !(1..=12).contains(&hour) => return None,
+        Some(true) => hour % 12 + 12,
+        Some(false) => hour % 12,
+        None => hour,
+    };
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+/// Parse a due date with an optional time of day, relative to `today`.
+///
+/// Accepts any date [`parse_natural_date`] understands, with a time before or after it
+/// ("next tuesday 3pm", "tomorrow at 9:30", "3pm fri"). A time on its own ("at 5pm")
+/// means today.
+pub fn parse_natural_due(input: &str, today: NaiveDate) -> Option<(NaiveDate, Option<NaiveTime>)> {
+    let text = input.trim().to_lowercase();
+    if let Some(date) = parse_natural_date(&text, today) {
+        return Some((date, None));
+    }
+
+    let words: Vec<&str> = text.split_whitespace().collect();
+    // The time is one word ("3pm") or two ("3 pm")
+    for time_len in [1, 2] {
+        if words.len() < time_len {
+            break;
+        }
+
+        let (date_words, time_words) = words.split_at(words.len() - time_len);
+        if let Some(time) = parse_time_of_day(&time_words.join(" ")) {
+            let date_words = match date_words {
+                [rest @ .., "at"] => rest,
+                rest => rest,
+            };
+            if date_words.is_empty() {
+                return Some((today, Some(time)));
+            }
+            if let Some(date) = parse_natural_date(&date_words.join(" "), today) {
+                return Some((date, Some(time)));
+            }
+        }
+
+        let (time_words, date_words) = words.split_at(time_len);
+        if let Some(time) = parse_time_of_day(&time_words.join(" ")) {
+            if let Some(date) = parse_natural_date(&date_words.join(" "), today) {
+                return Some((date, Some(time)));
+            }
+        }
+    }
+    None
+}
+
+/// Longest due phrase looked for at the end of task content, in words
+const MAX_DUE_PHRASE_WORDS: usize = 5;
+
+/// Split a trailing due phrase off task content, e.g. "Call mom next tuesday 3pm".
+///
+/// Returns the content without the phrase and the parsed due date. Content without a
+/// recognizable phrase is returned unchanged (trimmed) with no due date. The phrase is never
+/// the whole content, and amounts need an "in" ("Rent a car for 2 days" keeps its text).
+pub fn split_trailing_due(content: &str, today: NaiveDate) -> (String, Option<(NaiveDate, Option<NaiveTime>)>) {
+    let words: Vec<&str> = content.split_whitespace().collect();
+
+    for phrase_len in (1..=MAX_DUE_PHRASE_WORDS.min(words.len().saturating_sub(1))).rev() {
+        let (rest, phrase) = words.split_at(words.len() - phrase_len);
+        let first = phrase[0].to_lowercase();
+        // Bare amounts ("2 days") and the "tod"/"tom" shorthands are too likely to be part of the content
+        let bare_amount = first.parse::<u32>().is_ok()
+            && phrase
+                .get(1)
+                .is_some_and(|unit| matches!(unit.to_lowercase().trim_end_matches('s'), "day" | "week" | "month"));
+        if bare_amount {
+            continue;
+        }
+        if phrase_len == 1 && matches!(first.as_str(), "tod" | "tom") {
+            continue;
+        }
+
+        if let Some(due) = parse_natural_due(&phrase.join(" "), today) {
+            return (rest.join(" "), Some(due));
+        }
+    }
+    (content.trim().to_string(), None)
+}
+
+/// Format a parsed due date for a backend: "YYYY-MM-DD", or an RFC 3339 UTC datetime with a time
+pub fn format_due(date: NaiveDate, time: Option<NaiveTime>) -> String {
+    match time {
+        None => format_ymd(date),
+        Some(time) => {
+            let local = Local
+                .from_local_datetime(&date.and_time(time))
+                .earliest()
+                .unwrap_or_else(|| Local.from_utc_datetime(&date.and_time(time)));
+            local.with_timezone(&Utc).format("%Y-%m-%dT%H:%M:%SZ").to_string()
+        }
+    }
+}
+
+/// Parse a task duration into minutes
+///
+/// Accepts amounts with units ("45m", "45 min", "2h", "1h30m", "1.5h", "1d"), a bare
+/// number of minutes ("90"), and the stored "90 minute" or "1 day" that backends report.
+pub fn parse_duration(input: &str) -> Option<u32> {
+    let text: String = input.to_lowercase().split_whitespace().collect();
+    if let Ok(minutes) = text.parse::<u32>() {
+        return (minutes > 0).then_some(minutes);
+    }
+
+    let mut total = 0.0;
+    let mut rest = text.as_str();
+    while !rest.is_empty() {
+        let amount_len = rest.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(rest.len());
+        let amount: f64 = rest[..amount_len].parse().ok()?;
+        rest = &rest[amount_len..];
+        let unit_len = rest.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len());
+        let minutes_per_unit = match &rest[..unit_len] {
+            "m" | "min" | "mins" | "minute" | "minutes" => 1.0,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 60.0,
+            "d" | "day" | "days" => 1440.0,
+            _ => return None,
+        };
+        total += amount * minutes_per_unit;
+        rest = &rest[unit_len..];
+    }
+    let minutes = total.round();
+    (1.0..=f64::from(u32::MAX)).contains(&minutes).then_some(minutes as u32)
+}
+
+/// Format a duration in minutes compactly: "45m", "2h", "1h30m", or "1d" for whole days
+pub fn format_duration(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        _ if minutes > 0 && minutes % 1440 == 0 => format!("{}d", minutes / 1440),
+        (0, minutes) => format!("{}m", minutes),
+        (hours, 0) => format!("{}h", hours),
+        (hours, minutes) => format!("{}h{}m", hours, minutes),
+    }
+}
+
+/// A duration as stored and sent to backends: "90 minute", Todoist's amount and unit
+pub fn backend_duration(minutes: u32) -> String {
+    format!("{} minute", minutes)
+}
+
 /// Format a date string in Todoist-style human-readable format
 ///
 /// # Arguments
@@ -60,16 +520,14 @@ pub fn next_weekday(from: NaiveDate, target: Weekday) -> NaiveDate {
 /// # Returns
 /// * `String` - Human-readable date format
 pub fn format_human_date(date_str: &str) -> String {
-    // Parse the input date string
-    let input_date = match parse_date(date_str) {
-        Ok(date) => date,
-        Err(_) => return date_str.to_string(), // Return original if parsing fails
-    };
-
-    // Get current local date
-    let now = Local::now();
-    let today = now.date_naive();
+    match parse_date(date_str) {
+        Ok(date) => human_day(date, today()),
+        Err(_) => date_str.to_string(), // Return original if parsing fails
+    }
+}
 
+/// Describe `input_date` relative to `today` ("yesterday", "next Monday", "in 12 days", "Jan 15")
+fn human_day(input_date: NaiveDate, today: NaiveDate) -> String {
     // Calculate the difference in days
     let days_diff = (input_date - today).num_days();
 
@@ -118,45 +576,11 @@ pub fn format_human_date(date_str: &str) -> String {
 /// # Returns
 /// * `String` - Human-readable datetime format
 pub fn format_human_datetime(datetime_str: &str) -> String {
-    // Try multiple datetime parsing strategies
-    let parsed_dt = if let Ok(dt) = DateTime::parse_from_rfc3339(datetime_str) {
-        // RFC3339 with timezone (e.g., "2025-01-15T14:30:00Z")
-        Some(dt.with_timezone(&Local))
-    } else if let Ok(dt) =
-        chrono::NaiveDateTime::parse_from_str(datetime_str, &format!("{}T%H:%M:%S", TODOIST_DATE_FORMAT))
-    {
-        // ISO 8601 without timezone (e.g., "2025-01-15T14:30:00")
-        Some(
-            Local
-                .from_local_datetime(&dt)
-                .single()
-                .unwrap_or_else(|| Local.from_utc_datetime(&dt)),
-        )
-    } else if let Ok(dt) =
-        chrono::NaiveDateTime::parse_from_str(datetime_str, &format!("{} %H:%M:%S", TODOIST_DATE_FORMAT))
-    {
-        // Space-separated format (e.g., "2025-01-15 14:30:00")
-        Some(
-            Local
-                .from_local_datetime(&dt)
-                .single()
-                .unwrap_or_else(|| Local.from_utc_datetime(&dt)),
-        )
-    } else {
-        None
-    };
-
-    if let Some(local_dt) = parsed_dt {
-        let date_str = local_dt.format(TODOIST_DATE_FORMAT).to_string();
-        let time_str = local_dt.format("%H:%M").to_string();
-
-        let human_date = format_human_date(&date_str);
-
+    match parse_datetime(datetime_str) {
         // Always show time for datetime strings
-        format!("{} at {}", human_date, time_str)
-    } else {
+        Some(local_dt) => Due::DateTime(local_dt).humanize(now()),
         // Fallback to date-only parsing
-        format_human_date(datetime_str)
+        None => format_human_date(datetime_str),
     }
 }
 
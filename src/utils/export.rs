@@ -0,0 +1,190 @@
+//! Export of the tasks listed in a view.
+//!
+//! A view is exported the way it is listed: its groups (sections, dates, priorities or
+//! labels) in order, each with its tasks. Markdown gives a checklist with subtasks
+//! indented under their parent, for notes and status reports; CSV gives one row per
+//! task, for spreadsheets.
+
+use crate::entities::{project, task};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Columns of a CSV export, in order
+pub const CSV_HEADER: [&str; 7] = ["group", "content", "project", "due", "priority", "labels", "completed"];
+
+/// File format of an export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// A `- [ ]` checklist under the view's headers
+    Markdown,
+    /// One row per task with the [`CSV_HEADER`] columns
+    Csv,
+}
+
+impl ExportFormat {
+    /// The format for a file path: `.csv` files are CSV, anything else is Markdown
+    pub fn from_path(path: &str) -> Self {
+        if path.to_lowercase().ends_with(".csv") {
+            Self::Csv
+        } else {
+            Self::Markdown
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+/// Tasks listed under one header of a view; tasks above the first header have none
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportGroup {
+    pub header: Option<String>,
+    pub tasks: Vec<task::Model>,
+}
+
+/// Render the groups as a Markdown checklist titled `title`
+///
+/// Completed tasks are checked and the default priority (p4) is left out. A subtask
+/// listed after its parent is indented under it.
+pub fn to_markdown(
+    title: &str,
+    groups: &[ExportGroup],
+    projects: &[project::Model],
+    task_labels: &HashMap<Uuid, Vec<String>>,
+) -> String {
+    let mut out = format!("# {}\n", title);
+    for group in groups {
+        out.push('\n');
+        if let Some(header) = &group.header {
+            out.push_str(&format!("## {}\n\n", header));
+        }
+
+        let mut depths: HashMap<Uuid, usize> = HashMap::new();
+        for task in &group.tasks {
+            let depth = task
+                .parent_uuid
+                .and_then(|parent| depths.get(&parent))
+                .map_or(0, |depth| depth + 1);
+            depths.insert(task.uuid, depth);
+
+            let mut line = format!(
+                "{}- [{}] {}",
+                "  ".repeat(depth),
+                if task.is_completed { "x" } else { " " },
+                task.content
+            );
+            for detail in [
+                project_name(task, projects).map(|name| format!("#{}", name)),
+                due(task).map(|due| format!("due {}", due)),
+                (task.priority > 1).then(|| priority(task)),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                line.push_str(" · ");
+                line.push_str(&detail);
+            }
+            let labels = labels(task, task_labels);
+            if !labels.is_empty() {
+                line.push_str(" · ");
+                line.push_str(&labels.iter().map(|name| format!("@{}", name)).collect::<Vec<_>>().join(" "));
+            }
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Render the groups as CSV with a [`CSV_HEADER`] row, one row per task
+///
+/// Labels are separated by spaces within their column; fields are quoted as needed.
+pub fn to_csv(groups: &[ExportGroup], projects: &[project::Model], task_labels: &HashMap<Uuid, Vec<String>>) -> String {
+    let mut out = csv_row(CSV_HEADER.iter().map(|column| column.to_string()));
+    for group in groups {
+        for task in &group.tasks {
+            out.push_str(&csv_row([
+                group.header.clone().unwrap_or_default(),
+                task.content.clone(),
+                project_name(task, projects).unwrap_or_default().to_string(),
+                due(task).unwrap_or_default(),
+                priority(task),
+                labels(task, task_labels).join(" "),
+                task.is_completed.to_string(),
+            ]));
+        }
+    }
+    out
+}
+
+/// Path offered for exporting `view_name` on `today`, such as
+/// `~/terminalist-work-2025-03-12.md`, in `directory` or the home directory when empty
+pub fn default_path(directory: &str, view_name: &str, format: ExportFormat, today: NaiveDate) -> String {
+    let slug: String = view_name
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    let directory = if directory.is_empty() {
+        "~"
+    } else {
+        directory.trim_end_matches('/')
+    };
+    format!(
+        "{}/terminalist-{}-{}.{}",
+        directory,
+        slug,
+        today.format("%Y-%m-%d"),
+        format.extension()
+    )
+}
+
+/// Expand a leading `~` to the home directory
+pub fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix('~'), dirs::home_dir()) {
+        (Some(rest), Some(home)) if rest.is_empty() || rest.starts_with('/') => home.join(rest.trim_start_matches('/')),
+        _ => PathBuf::from(path),
+    }
+}
+
+fn csv_row(fields: impl IntoIterator<Item = String>) -> String {
+    let fields: Vec<String> = fields.into_iter().map(|field| csv_field(&field)).collect();
+    format!("{}\n", fields.join(","))
+}
+
+/// Quote a field containing a comma, quote or line break, doubling its quotes
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn project_name<'a>(task: &task::Model, projects: &'a [project::Model]) -> Option<&'a str> {
+    projects
+        .iter()
+        .find(|project| project.uuid == task.project_uuid)
+        .map(|project| project.name.as_str())
+}
+
+/// Due date as "YYYY-MM-DD", with the local time for datetimes
+fn due(task: &task::Model) -> Option<String> {
+    task.due().map(|due| due.display("%Y-%m-%d", "%H:%M"))
+}
+
+/// "p1" to "p4", from the API scale where 4 is p1
+fn priority(task: &task::Model) -> String {
+    format!("p{}", 5 - task.priority.clamp(1, 4))
+}
+
+fn labels<'a>(task: &task::Model, task_labels: &'a HashMap<Uuid, Vec<String>>) -> &'a [String] {
+    task_labels.get(&task.uuid).map_or(&[], Vec::as_slice)
+}
@@ -0,0 +1,85 @@
+//! Fuzzy text matching for task search.
+//!
+//! A query matches when each of its words appears in the text as a subsequence, in any
+//! order and ignoring case: "by mlk" matches "Buy milk". A word with two swapped
+//! neighbouring letters ("mlik") still matches, with a lower score. Scores reward
+//! consecutive letters and letters at the start of a word, so closer matches rank first.
+
+/// Points for each matched character
+const MATCH_SCORE: i64 = 1;
+/// Extra points when a character directly follows the previous match
+const CONSECUTIVE_BONUS: i64 = 5;
+/// Extra points when a character starts a word in the text
+const WORD_START_BONUS: i64 = 8;
+/// Points lost per skipped character between two matches (capped per gap)
+const GAP_PENALTY: i64 = 1;
+const MAX_GAP_PENALTY: i64 = 5;
+/// Points lost when a word only matches with two neighbouring letters swapped
+const TRANSPOSITION_PENALTY: i64 = 10;
+
+/// Score how well `query` matches `text`, or `None` if it doesn't match.
+///
+/// Every word of the query must match; higher scores are better matches. An empty
+/// query matches everything with a score of 0.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    query
+        .to_lowercase()
+        .split_whitespace()
+        .map(|word| word_score(&word.chars().collect::<Vec<_>>(), &text))
+        .sum()
+}
+
+/// Best score of one query word, allowing one swap of neighbouring letters
+fn word_score(word: &[char], text: &[char]) -> Option<i64> {
+    if let Some(score) = subsequence_score(word, text) {
+        return Some(score);
+    }
+
+    (1..word.len())
+        .filter(|&i| word[i - 1] != word[i])
+        .filter_map(|i| {
+            let mut swapped = word.to_vec();
+            swapped.swap(i - 1, i);
+            subsequence_score(&swapped, text)
+        })
+        .max()
+        .map(|score| score - TRANSPOSITION_PENALTY)
+}
+
+/// Best score of `word` as a subsequence of `text`, trying each place its first letter occurs
+fn subsequence_score(word: &[char], text: &[char]) -> Option<i64> {
+    let first = *word.first()?;
+    text.iter()
+        .enumerate()
+        .filter(|(_, c)| **c == first)
+        .filter_map(|(start, _)| greedy_score(word, text, start))
+        .max()
+}
+
+/// Score of matching `word` greedily from `start`, where `text[start]` is its first letter
+fn greedy_score(word: &[char], text: &[char], start: usize) -> Option<i64> {
+    let mut score = 0;
+    let mut previous: Option<usize> = None;
+    let mut position = start;
+
+    for letter in word {
+        let offset = text[position..].iter().position(|c| c == letter)?;
+        let index = position + offset;
+
+        score += MATCH_SCORE;
+        if index == 0 || !text[index - 1].is_alphanumeric() {
+            score += WORD_START_BONUS;
+        }
+        match previous {
+            Some(previous) if index == previous + 1 => score += CONSECUTIVE_BONUS,
+            Some(previous) => score -= (GAP_PENALTY * (index - previous - 1) as i64).min(MAX_GAP_PENALTY),
+            None => {}
+        }
+
+        previous = Some(index);
+        position = index + 1;
+    }
+
+    Some(score)
+}
@@ -0,0 +1,291 @@
+//! Parsing of task lists to import, from a Markdown checklist or a Todoist JSON export.
+//!
+//! Parsing only reads the file: it yields the tasks to create with the names of their
+//! project and section, and the lines that couldn't be understood. Resolving the names
+//! and creating the tasks is left to [`SyncService::import_tasks`](crate::sync::SyncService::import_tasks).
+//!
+//! The Markdown format reads back what [`export::to_markdown`](super::export::to_markdown)
+//! writes: `- [ ] Task · #Project · due 2025-03-12 · p1 · @label`. Plain `- task` bullets
+//! work too, and `#`/`##` headers name the project and section of the tasks below them.
+
+use crate::utils::datetime;
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A task to create, with its project and section by name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedTask {
+    /// Line of a Markdown file, or position of the item in a JSON export (from 1)
+    pub line: usize,
+    /// Content with inline `@label` words
+    pub content: String,
+    /// Project name; `None` files the task in the default project or the inbox
+    pub project: Option<String>,
+    /// Section name within the project, or a project name when no project is given
+    pub section: Option<String>,
+    /// "YYYY-MM-DD", or an RFC 3339 datetime for a due time
+    pub due: Option<String>,
+    /// API scale (4 = p1)
+    pub priority: Option<i32>,
+}
+
+/// A line or item that couldn't be imported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportFailure {
+    pub line: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+/// What a file holds to import
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedImport {
+    pub tasks: Vec<ImportedTask>,
+    pub failures: Vec<ImportFailure>,
+    /// Completed items, which aren't imported
+    pub skipped: usize,
+}
+
+/// Parse a file's content by its extension: `.json` is a Todoist export, anything else Markdown
+///
+/// # Errors
+/// Returns an error if a JSON file isn't valid JSON or holds no task list
+pub fn parse_file(path: &str, content: &str) -> Result<ParsedImport> {
+    if path.to_lowercase().ends_with(".json") {
+        parse_todoist_json(content)
+    } else {
+        Ok(parse_markdown(content))
+    }
+}
+
+/// Parse a Markdown checklist
+///
+/// `#` headers name the project of the tasks below them and `##` headers their section;
+/// every list item (`-`, `*` or `+`, with or without a `[ ]` box) is a task, and nested
+/// items are imported as top-level tasks. Checked items are skipped. Other non-blank
+/// lines are reported as failures.
+pub fn parse_markdown(input: &str) -> ParsedImport {
+    let mut parsed = ParsedImport::default();
+    let mut project: Option<String> = None;
+    let mut section: Option<String> = None;
+
+    for (index, raw) in input.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let line_number = index + 1;
+        let fail = |reason: &str| ImportFailure {
+            line: line_number,
+            text: line.to_string(),
+            reason: reason.to_string(),
+        };
+
+        if let Some(name) = line.strip_prefix("## ") {
+            section = Some(name.trim().to_string()).filter(|name| !name.is_empty());
+            continue;
+        }
+        if let Some(name) = line.strip_prefix("# ") {
+            project = Some(name.trim().to_string()).filter(|name| !name.is_empty());
+            section = None;
+            continue;
+        }
+
+        let Some(item) = ["- ", "* ", "+ "].iter().find_map(|bullet| line.strip_prefix(bullet)) else {
+            parsed.failures.push(fail("Not a list item or header"));
+            continue;
+        };
+        let item = item.trim_start();
+        let (checked, text) = if let Some(text) = item.strip_prefix("[ ]") {
+            (false, text)
+        } else if let Some(text) = item.strip_prefix("[x]").or_else(|| item.strip_prefix("[X]")) {
+            (true, text)
+        } else {
+            (false, item)
+        };
+        if checked {
+            parsed.skipped += 1;
+            continue;
+        }
+
+        match parse_item(text.trim()) {
+            Ok(details) => parsed.tasks.push(ImportedTask {
+                line: line_number,
+                content: details.content,
+                project: details.project.or_else(|| project.clone()),
+                section: section.clone(),
+                due: details.due,
+                priority: details.priority,
+            }),
+            Err(reason) => parsed.failures.push(fail(&reason)),
+        }
+    }
+    parsed
+}
+
+/// Details of a checklist item, as written by the Markdown export
+struct ItemDetails {
+    content: String,
+    project: Option<String>,
+    due: Option<String>,
+    priority: Option<i32>,
+}
+
+/// Split `Task · #Project · due 2025-03-12 09:00 · p1 · @a @b` into its details
+///
+/// Parts that aren't a known detail stay in the content, so a task whose content
+/// contains " · " keeps it.
+fn parse_item(text: &str) -> std::result::Result<ItemDetails, String> {
+    let mut parts = text.split(" · ");
+    let mut content = parts.next().unwrap_or_default().trim().to_string();
+    let mut details = ItemDetails {
+        content: String::new(),
+        project: None,
+        due: None,
+        priority: None,
+    };
+
+    for part in parts.map(str::trim) {
+        if let Some(name) = part.strip_prefix('#').filter(|name| !name.is_empty()) {
+            details.project = Some(name.to_string());
+        } else if let Some(due) = part.strip_prefix("due ") {
+            details.due = Some(parse_due(due).ok_or_else(|| format!("Invalid due date '{}'", due))?);
+        } else if let Some(level) = parse_priority(part) {
+            details.priority = Some(level);
+        } else if part.starts_with('@') {
+            content.push(' ');
+            content.push_str(part);
+        } else {
+            content.push_str(" · ");
+            content.push_str(part);
+        }
+    }
+
+    if content.is_empty() {
+        return Err("Empty task".to_string());
+    }
+    details.content = content;
+    Ok(details)
+}
+
+/// "YYYY-MM-DD", optionally followed by a local "HH:MM"
+fn parse_due(text: &str) -> Option<String> {
+    let mut words = text.split_whitespace();
+    let date = datetime::parse_date(words.next()?).ok()?;
+    let time = match words.next() {
+        Some(time) => Some(datetime::parse_time_of_day(time)?),
+        None => None,
+    };
+    if words.next().is_some() {
+        return None;
+    }
+    Some(datetime::format_due(date, time))
+}
+
+/// "p1" to "p4" on the API scale
+fn parse_priority(text: &str) -> Option<i32> {
+    match text {
+        "p1" => Some(4),
+        "p2" => Some(3),
+        "p3" => Some(2),
+        "p4" => Some(1),
+        _ => None,
+    }
+}
+
+/// Parse a Todoist JSON export
+///
+/// Accepts a list of tasks, or an object with the tasks under `items` (Sync API) or
+/// `tasks`, and optionally the `projects` and `sections` they refer to by `id`. Each task
+/// needs a `content`; its `priority`, `due` (`date` or `datetime`) and `labels` are kept.
+/// Completed tasks (`checked` or `is_completed`) are skipped.
+///
+/// # Errors
+/// Returns an error if the content isn't JSON or holds no task list
+pub fn parse_todoist_json(input: &str) -> Result<ParsedImport> {
+    let root: Value = serde_json::from_str(input).context("Invalid JSON")?;
+    let items = match &root {
+        Value::Array(items) => items,
+        Value::Object(object) => object
+            .get("items")
+            .or_else(|| object.get("tasks"))
+            .and_then(Value::as_array)
+            .context("No \"items\" or \"tasks\" list in the JSON export")?,
+        _ => anyhow::bail!("The JSON export is neither a list of tasks nor an object"),
+    };
+    let names = |key: &str| -> HashMap<String, String> {
+        root.get(key)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| Some((id_of(entry.get("id")?)?, entry.get("name")?.as_str()?.to_string())))
+            .collect()
+    };
+    let projects = names("projects");
+    let sections = names("sections");
+
+    let mut parsed = ParsedImport::default();
+    for (index, item) in items.iter().enumerate() {
+        let done = ["checked", "is_completed", "completed"]
+            .iter()
+            .any(|key| item.get(key).and_then(Value::as_bool) == Some(true));
+        if done {
+            parsed.skipped += 1;
+            continue;
+        }
+
+        let Some(content) = item
+            .get("content")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|content| !content.is_empty())
+        else {
+            parsed.failures.push(ImportFailure {
+                line: index + 1,
+                text: item.to_string(),
+                reason: "Task without content".to_string(),
+            });
+            continue;
+        };
+
+        let mut content = content.to_string();
+        for label in item.get("labels").and_then(Value::as_array).into_iter().flatten() {
+            if let Some(label) = label.as_str() {
+                content.push_str(&format!(" @{}", label));
+            }
+        }
+        let due = item.get("due").and_then(|due| {
+            due.get("datetime")
+                .and_then(Value::as_str)
+                .or_else(|| due.get("date").and_then(Value::as_str))
+                .map(str::to_string)
+        });
+        let lookup = |key: &str, names: &HashMap<String, String>| {
+            item.get(key).and_then(id_of).and_then(|id| names.get(&id).cloned())
+        };
+
+        parsed.tasks.push(ImportedTask {
+            line: index + 1,
+            content,
+            project: lookup("project_id", &projects),
+            section: lookup("section_id", &sections),
+            due,
+            priority: item
+                .get("priority")
+                .and_then(Value::as_i64)
+                .and_then(|priority| i32::try_from(priority).ok())
+                .filter(|priority| (1..=4).contains(priority)),
+        });
+    }
+    Ok(parsed)
+}
+
+/// Ids are strings in current exports and numbers in older ones
+fn id_of(value: &Value) -> Option<String> {
+    match value {
+        Value::String(id) => Some(id.clone()),
+        Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,215 @@
+//! Rendering of Markdown task descriptions as styled terminal text.
+//!
+//! Todoist descriptions are written in Markdown. This converts the subset people use in
+//! notes and checklists: headings, bullet, numbered and checkbox lists, quotes, code
+//! blocks, and inline bold, italic, code and links. Anything else stays plain text, so
+//! an unclosed `**` or an unsupported construct shows as written instead of being lost.
+
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+
+/// Styles of the rendered elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarkdownStyle {
+    /// Plain text; emphasis and headings add modifiers to it
+    pub text: Style,
+    /// Link text
+    pub link: Style,
+    /// List markers, quote bars and link targets
+    pub marker: Style,
+    /// Inline code and code blocks
+    pub code: Style,
+}
+
+/// Render Markdown with one line per source line
+///
+/// Lines aren't wrapped; the paragraph showing them wraps to its width.
+pub fn to_text(input: &str, style: &MarkdownStyle) -> Text<'static> {
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+    for raw in input.lines() {
+        let trimmed = raw.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            lines.push(Line::from(Span::styled(format!("  {}", raw), style.code)));
+        } else {
+            lines.push(block_line(raw, style));
+        }
+    }
+    Text::from(lines)
+}
+
+/// A line outside code blocks: heading, rule, quote, list item or paragraph text
+fn block_line(raw: &str, style: &MarkdownStyle) -> Line<'static> {
+    let trimmed = raw.trim_start();
+    let indent = &raw[..raw.len() - trimmed.len()];
+    let mut spans = Vec::new();
+
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    if let Some(title) = trimmed[level..].strip_prefix(' ').filter(|_| (1..=6).contains(&level)) {
+        inline(title.trim(), style.text.add_modifier(Modifier::BOLD), style, &mut spans);
+        return Line::from(spans);
+    }
+
+    let is_rule = trimmed.len() >= 3 && ["-", "*", "_"].iter().any(|c| trimmed.replace(c, "").is_empty());
+    if is_rule {
+        return Line::from(Span::styled("─".repeat(20), style.marker));
+    }
+
+    if let Some(quote) = trimmed.strip_prefix('>') {
+        spans.push(Span::styled(format!("{}│ ", indent), style.marker));
+        inline(
+            quote.trim_start(),
+            style.text.add_modifier(Modifier::ITALIC),
+            style,
+            &mut spans,
+        );
+        return Line::from(spans);
+    }
+
+    if let Some(item) = ["- ", "* ", "+ "].iter().find_map(|bullet| trimmed.strip_prefix(bullet)) {
+        let (marker, item, text_style) = if let Some(item) = item.strip_prefix("[ ] ") {
+            ("[ ] ", item, style.text)
+        } else if let Some(item) = item.strip_prefix("[x] ").or_else(|| item.strip_prefix("[X] ")) {
+            ("[x] ", item, style.text.add_modifier(Modifier::CROSSED_OUT))
+        } else {
+            ("• ", item, style.text)
+        };
+        spans.push(Span::styled(format!("{}{}", indent, marker), style.marker));
+        inline(item, text_style, style, &mut spans);
+        return Line::from(spans);
+    }
+
+    let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+    if let Some(item) = trimmed[digits..].strip_prefix(". ").filter(|_| digits > 0) {
+        spans.push(Span::styled(
+            format!("{}{}. ", indent, &trimmed[..digits]),
+            style.marker,
+        ));
+        inline(item, style.text, style, &mut spans);
+        return Line::from(spans);
+    }
+
+    if !indent.is_empty() {
+        spans.push(Span::styled(indent.to_string(), style.text));
+    }
+    inline(trimmed, style.text, style, &mut spans);
+    Line::from(spans)
+}
+
+/// Append the spans of inline Markdown `text`, in `base` style outside of emphasis
+fn inline(text: &str, base: Style, style: &MarkdownStyle, spans: &mut Vec<Span<'static>>) {
+    let mut plain = String::new();
+    let mut rest = text;
+    let mut previous: Option<char> = None;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = rest[1..].chars().next().filter(char::is_ascii_punctuation) {
+                    plain.push(escaped);
+                    rest = &rest[2..];
+                    previous = Some(escaped);
+                    continue;
+                }
+            }
+            '`' => {
+                if let Some(end) = rest[1..].find('`') {
+                    flush(&mut plain, base, spans);
+                    spans.push(Span::styled(rest[1..=end].to_string(), style.code));
+                    rest = &rest[end + 2..];
+                    previous = Some(c);
+                    continue;
+                }
+            }
+            '[' => {
+                if let Some((label, url, length)) = link(rest) {
+                    flush(&mut plain, base, spans);
+                    inline(label, base.patch(style.link), style, spans);
+                    if url != label {
+                        spans.push(Span::styled(format!(" ({})", url), style.marker));
+                    }
+                    rest = &rest[length..];
+                    previous = Some(')');
+                    continue;
+                }
+            }
+            '*' | '_' => {
+                let delimiter = if rest[1..].starts_with(c) {
+                    &rest[..2]
+                } else {
+                    &rest[..1]
+                };
+                let inner_start = delimiter.len();
+                // Emphasis opens before text; `_` inside words (snake_case) is kept as is
+                let opens = rest[inner_start..].chars().next().is_some_and(|next| !next.is_whitespace())
+                    && (c == '*' || !previous.is_some_and(char::is_alphanumeric));
+                if let Some(end) = closing(&rest[inner_start..], delimiter).filter(|_| opens) {
+                    let modifier = if delimiter.len() == 2 {
+                        Modifier::BOLD
+                    } else {
+                        Modifier::ITALIC
+                    };
+                    flush(&mut plain, base, spans);
+                    inline(
+                        &rest[inner_start..inner_start + end],
+                        base.add_modifier(modifier),
+                        style,
+                        spans,
+                    );
+                    rest = &rest[inner_start * 2 + end..];
+                    previous = Some(c);
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        plain.push(c);
+        rest = &rest[c.len_utf8()..];
+        previous = Some(c);
+    }
+    flush(&mut plain, base, spans);
+}
+
+fn flush(plain: &mut String, base: Style, spans: &mut Vec<Span<'static>>) {
+    if !plain.is_empty() {
+        spans.push(Span::styled(std::mem::take(plain), base));
+    }
+}
+
+/// Offset in `text` of the delimiter closing an emphasis, if any
+///
+/// A closing delimiter follows text, and a single one isn't half of a double one.
+fn closing(text: &str, delimiter: &str) -> Option<usize> {
+    let marker = delimiter.chars().next()?;
+    let mut from = 0;
+    while let Some(found) = text[from..].find(delimiter) {
+        let at = from + found;
+        let before = text[..at].chars().next_back();
+        let after = text[at + delimiter.len()..].chars().next();
+        let half_of_double = delimiter.len() == 1 && (before == Some(marker) || after == Some(marker));
+        let closes = before.is_some_and(|c| !c.is_whitespace())
+            && !half_of_double
+            && (marker != '_' || !after.is_some_and(char::is_alphanumeric));
+        if closes {
+            return Some(at);
+        }
+        from = at + delimiter.len();
+    }
+    None
+}
+
+/// `[label](url)` at the start of `text`, with the length it spans
+fn link(text: &str) -> Option<(&str, &str, usize)> {
+    let label_end = text.find("](")?;
+    let label = &text[1..label_end];
+    let url_start = label_end + 2;
+    let url = &text[url_start..url_start + text[url_start..].find(')')?];
+    if label.is_empty() || label.contains(']') || url.is_empty() || url.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((label, url, url_start + url.len() + 1))
+}
@@ -6,7 +6,12 @@
 //!
 //! # Available Utilities
 //!
+//! - [`color`] - Named color palette and terminal color conversion
 //! - [`datetime`] - Date and time formatting, parsing, and manipulation functions
+//! - [`export`] - Markdown and CSV export of the tasks listed in a view
+//! - [`fuzzy`] - Fuzzy text matching for task search
+//! - [`import`] - Parsing of Markdown checklists and Todoist JSON exports to import
+//! - [`markdown`] - Styled rendering of Markdown task descriptions
 //!
 //! # Purpose
 //!
@@ -26,4 +31,9 @@
 //! - **Performance** - Efficient implementations suitable for frequent use
 //! - **Testability** - Easy to unit test with clear inputs and outputs
 
+pub mod color;
 pub mod datetime;
+pub mod export;
+pub mod fuzzy;
+pub mod import;
+pub mod markdown;
@@ -0,0 +1,14 @@
+#[path = "backend/caldav.rs"]
+mod caldav;
+
+#[path = "backend/local.rs"]
+mod local;
+
+#[path = "backend/rate_limit.rs"]
+mod rate_limit;
+
+#[path = "backend/retry.rs"]
+mod retry;
+
+#[path = "backend/todoist_sync.rs"]
+mod todoist_sync;
@@ -0,0 +1,147 @@
+use terminalist::backend::caldav::{parse_calendar_list, parse_todo_list, TodoResource};
+
+const CALENDARS: &str = r#"<?xml version="1.0"?>
+<d:multistatus xmlns:d="DAV:" xmlns:cal="urn:ietf:params:xml:ns:caldav">
+  <d:response>
+    <d:href>/dav/calendars/me/</d:href>
+    <d:propstat><d:prop><d:resourcetype><d:collection/></d:resourcetype></d:prop></d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/dav/calendars/me/tasks/</d:href>
+    <d:propstat><d:prop>
+      <d:displayname>Errands &amp; chores</d:displayname>
+      <d:resourcetype><d:collection/><cal:calendar/></d:resourcetype>
+      <cal:supported-calendar-component-set><cal:comp name="VTODO"/></cal:supported-calendar-component-set>
+    </d:prop></d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/dav/calendars/me/events/</d:href>
+    <d:propstat><d:prop>
+      <d:displayname>Events</d:displayname>
+      <d:resourcetype><d:collection/><cal:calendar/></d:resourcetype>
+      <cal:supported-calendar-component-set><cal:comp name="VEVENT"/></cal:supported-calendar-component-set>
+    </d:prop></d:propstat>
+  </d:response>
+</d:multistatus>"#;
+
+const TODO: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//Example//EN\r\n\
+BEGIN:VTODO\r\n\
+UID:child-1\r\n\
+SUMMARY:Buy milk\\, eggs\r\n\
+DESCRIPTION:From the corner \r\n shop\r\n\
+DUE;VALUE=DATE:20260320\r\n\
+PRIORITY:1\r\n\
+CATEGORIES:home,errands\r\n\
+RELATED-TO:parent-1\r\n\
+X-CLIENT-COLOR:blue\r\n\
+BEGIN:VALARM\r\n\
+ACTION:DISPLAY\r\n\
+SUMMARY:Alarm\r\n\
+END:VALARM\r\n\
+END:VTODO\r\n\
+END:VCALENDAR\r\n";
+
+#[test]
+fn test_calendar_list_keeps_task_calendars() {
+    let projects = parse_calendar_list(CALENDARS);
+
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].remote_id, "/dav/calendars/me/tasks/");
+    assert_eq!(projects[0].name, "Errands & chores");
+    assert!(!projects[0].is_inbox);
+}
+
+#[test]
+fn test_vtodo_maps_to_backend_task() {
+    let todo = TodoResource::parse(TODO).unwrap();
+    let task = todo.to_backend(
+        "/dav/calendars/me/tasks/child-1.ics",
+        "/dav/calendars/me/tasks/",
+        Some("/dav/calendars/me/tasks/parent-1.ics".to_string()),
+    );
+
+    assert_eq!(todo.uid().as_deref(), Some("child-1"));
+    assert_eq!(todo.parent_uid().as_deref(), Some("parent-1"));
+    assert_eq!(task.content, "Buy milk, eggs");
+    assert_eq!(task.description.as_deref(), Some("From the corner shop"));
+    assert_eq!(task.project_remote_id, "/dav/calendars/me/tasks/");
+    assert_eq!(task.due_date.as_deref(), Some("2026-03-20"));
+    assert_eq!(task.due_datetime, None);
+    // iCalendar priority 1 is the most urgent, like P1
+    assert_eq!(task.priority, 4);
+    assert_eq!(task.labels, vec!["home", "errands"]);
+    assert!(!task.is_recurring);
+    assert!(!task.is_completed);
+}
+
+#[test]
+fn test_vtodo_due_datetime() {
+    let ics = TODO.replace("DUE;VALUE=DATE:20260320", "DUE:20260320T173000Z");
+    let task = TodoResource::parse(&ics).unwrap().to_backend("a.ics", "/", None);
+
+    assert_eq!(task.due_date.as_deref(), Some("2026-03-20"));
+    assert_eq!(task.due_datetime.as_deref(), Some("2026-03-20T17:30:00Z"));
+}
+
+#[test]
+fn test_vtodo_recurrence() {
+    let task_with_rule = |rule: &str| {
+        let ics = TODO.replace("PRIORITY:1", &format!("RRULE:{}", rule));
+        TodoResource::parse(&ics).unwrap().to_backend("a.ics", "/", None)
+    };
+
+    let weekly = task_with_rule("FREQ=WEEKLY;BYDAY=MO");
+    assert!(weekly.is_recurring);
+    assert_eq!(weekly.due_string.as_deref(), Some("every monday"));
+    assert_eq!(
+        task_with_rule("FREQ=DAILY;INTERVAL=3").due_string.as_deref(),
+        Some("every 3 days")
+    );
+    assert_eq!(
+        task_with_rule("FREQ=MONTHLY").due_string.as_deref(),
+        Some("every month")
+    );
+    // Rules that can't be put in words still recur
+    let hourly = task_with_rule("FREQ=HOURLY");
+    assert!(hourly.is_recurring);
+    assert_eq!(hourly.due_string, None);
+}
+
+#[test]
+fn test_completed_vtodo_is_closed() {
+    let ics = TODO.replace("PRIORITY:1", "STATUS:COMPLETED");
+    let todo = TodoResource::parse(&ics).unwrap();
+
+    assert!(todo.is_closed());
+    assert_eq!(todo.to_backend("a.ics", "/", None).priority, 1);
+}
+
+#[test]
+fn test_serialization_keeps_unknown_properties() {
+    let todo = TodoResource::parse(TODO).unwrap();
+    let ics = todo.to_ics();
+
+    assert!(ics.contains("X-CLIENT-COLOR:blue\r\n"));
+    assert!(ics.contains("BEGIN:VALARM\r\n"));
+    assert_eq!(TodoResource::parse(&ics), Some(todo));
+}
+
+#[test]
+fn test_todo_list_from_report() {
+    let report = format!(
+        "<d:multistatus xmlns:d=\"DAV:\" xmlns:c=\"urn:ietf:params:xml:ns:caldav\">\
+         <d:response><d:href>/dav/calendars/me/tasks/child-1.ics</d:href>\
+         <d:propstat><d:prop><c:calendar-data>{}</c:calendar-data></d:prop></d:propstat></d:response>\
+         <d:response><d:href>/dav/calendars/me/tasks/event.ics</d:href>\
+         <d:propstat><d:prop><c:calendar-data>BEGIN:VCALENDAR\r\nEND:VCALENDAR</c:calendar-data></d:prop></d:propstat></d:response>\
+         </d:multistatus>",
+        TODO
+    );
+    let todos = parse_todo_list(&report);
+
+    assert_eq!(todos.len(), 1);
+    assert_eq!(todos[0].0, "/dav/calendars/me/tasks/child-1.ics");
+    assert_eq!(todos[0].1.uid().as_deref(), Some("child-1"));
+}
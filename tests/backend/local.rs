@@ -0,0 +1,191 @@
+use std::sync::Arc;
+use terminalist::backend::local::LocalBackend;
+use terminalist::backend::{Backend, CreateProjectArgs, CreateTaskArgs, UpdateTaskArgs};
+use terminalist::backend_registry::BackendRegistry;
+use terminalist::storage::LocalStorage;
+use terminalist::sync::{SyncService, SyncStatus};
+use tokio::sync::Mutex;
+
+fn task_args(content: &str, project_remote_id: &str, labels: &[&str]) -> CreateTaskArgs {
+    CreateTaskArgs {
+        content: content.to_string(),
+        description: None,
+        project_remote_id: project_remote_id.to_string(),
+        section_remote_id: None,
+        parent_remote_id: None,
+        priority: None,
+        due_date: None,
+        due_datetime: None,
+        duration: None,
+        labels: labels.iter().map(|label| label.to_string()).collect(),
+    }
+}
+
+#[tokio::test]
+async fn test_local_backend_starts_with_an_inbox() {
+    let backend = LocalBackend::in_memory();
+
+    let projects = backend.fetch_projects().await.unwrap();
+    assert_eq!(projects.len(), 1);
+    assert!(projects[0].is_inbox);
+    assert!(backend.has_inbox());
+}
+
+#[tokio::test]
+async fn test_local_backend_task_lifecycle() {
+    let backend = LocalBackend::in_memory();
+    let project = backend
+        .create_project(CreateProjectArgs {
+            name: "Home".to_string(),
+            is_favorite: None,
+            parent_remote_id: None,
+            color: None,
+        })
+        .await
+        .unwrap();
+
+    let task = backend
+        .create_task(task_args("Water plants", &project.remote_id, &["chores"]))
+        .await
+        .unwrap();
+    assert_eq!(task.project_remote_id, project.remote_id);
+    assert_eq!(task.labels, vec!["chores"]);
+    // Unknown label names are created on the fly
+    assert_eq!(backend.fetch_labels().await.unwrap()[0].name, "chores");
+
+    let updated = backend
+        .update_task(
+            &task.remote_id,
+            UpdateTaskArgs {
+                content: None,
+                description: None,
+                project_remote_id: None,
+                section_remote_id: None,
+                parent_remote_id: None,
+                priority: Some(4),
+                due_date: Some("2026-05-01".to_string()),
+                due_datetime: None,
+                deadline: None,
+                duration: None,
+                labels: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.remote_id, task.remote_id);
+    assert_eq!(updated.priority, 4);
+    assert_eq!(updated.due_date.as_deref(), Some("2026-05-01"));
+
+    // Completed tasks are no longer fetched, and come back when reopened
+    backend.complete_task(&task.remote_id).await.unwrap();
+    assert!(backend.fetch_tasks().await.unwrap().is_empty());
+    backend.reopen_task(&task.remote_id).await.unwrap();
+    assert_eq!(backend.fetch_tasks().await.unwrap().len(), 1);
+
+    // Deleting the project takes its tasks with it
+    backend.delete_project(&project.remote_id).await.unwrap();
+    assert!(backend.fetch_tasks().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_local_backend_fetches_a_single_open_task() {
+    let backend = LocalBackend::in_memory();
+    let task = backend.create_task(task_args("Call the bank", "", &[])).await.unwrap();
+
+    let fetched = backend.fetch_task(&task.remote_id).await.unwrap().unwrap();
+    assert_eq!(fetched.content, "Call the bank");
+
+    backend.complete_task(&task.remote_id).await.unwrap();
+    assert!(backend.fetch_task(&task.remote_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_local_backend_keeps_data_between_runs() {
+    let path = std::env::temp_dir().join(format!("terminalist-local-{}.db", uuid::Uuid::new_v4()));
+
+    let backend = LocalBackend::open(path.clone());
+    let task = backend.create_task(task_args("Call the bank", "", &[])).await.unwrap();
+    drop(backend);
+
+    let reopened = LocalBackend::open(path.clone());
+    let tasks = reopened.fetch_tasks().await.unwrap();
+    let projects = reopened.fetch_projects().await.unwrap();
+    drop(reopened);
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].remote_id, task.remote_id);
+    assert_eq!(tasks[0].content, "Call the bank");
+    // The inbox is only created once
+    assert_eq!(projects.len(), 1);
+}
+
+#[tokio::test]
+async fn test_sync_service_over_local_backend() {
+    let storage = Arc::new(Mutex::new(LocalStorage::new_in_memory().await.unwrap()));
+    let registry = Arc::new(BackendRegistry::new(storage));
+    let backend_uuid = registry
+        .add_backend_instance(
+            "Local".to_string(),
+            "{}".to_string(),
+            Box::new(LocalBackend::in_memory()),
+        )
+        .await
+        .unwrap();
+    let sync_service = SyncService::new(registry, backend_uuid, false).await.unwrap();
+
+    assert!(matches!(sync_service.sync().await.unwrap(), SyncStatus::Success(_)));
+    sync_service.create_task("Buy milk @errands", None).await.unwrap();
+
+    // Syncing again finds the same task instead of a copy
+    assert!(matches!(sync_service.sync().await.unwrap(), SyncStatus::Success(_)));
+    let tasks = sync_service.get_all_tasks().await.unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].content, "Buy milk");
+
+    let inbox = sync_service.get_projects().await.unwrap();
+    assert!(inbox[0].is_inbox_project);
+    assert_eq!(tasks[0].project_uuid, inbox[0].uuid);
+}
+
+#[tokio::test]
+async fn test_sync_prunes_items_deleted_on_the_backend() {
+    let storage = Arc::new(Mutex::new(LocalStorage::new_in_memory().await.unwrap()));
+    let registry = Arc::new(BackendRegistry::new(storage));
+    let backend_uuid = registry
+        .add_backend_instance(
+            "Local".to_string(),
+            "{}".to_string(),
+            Box::new(LocalBackend::in_memory()),
+        )
+        .await
+        .unwrap();
+    let sync_service = SyncService::new(registry.clone(), backend_uuid, false).await.unwrap();
+
+    sync_service.sync().await.unwrap();
+    sync_service.create_project("Garden", None).await.unwrap();
+    sync_service.create_task("Buy seeds @shopping", None).await.unwrap();
+    assert_eq!(sync_service.get_projects().await.unwrap().len(), 2);
+
+    // Delete behind the service's back, as another client would
+    let backend = registry.get_backend(&backend_uuid).await.unwrap();
+    let garden = backend
+        .fetch_projects()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|p| p.name == "Garden")
+        .unwrap();
+    backend.delete_project(&garden.remote_id).await.unwrap();
+    let task = backend.fetch_tasks().await.unwrap().remove(0);
+    backend.delete_task(&task.remote_id).await.unwrap();
+    let label = backend.fetch_labels().await.unwrap().remove(0);
+    backend.delete_label(&label.remote_id).await.unwrap();
+
+    assert!(matches!(sync_service.sync().await.unwrap(), SyncStatus::Success(_)));
+    let projects = sync_service.get_projects().await.unwrap();
+    assert_eq!(projects.len(), 1);
+    assert!(projects[0].is_inbox_project);
+    assert!(sync_service.get_all_tasks().await.unwrap().is_empty());
+    assert!(sync_service.get_labels().await.unwrap().is_empty());
+}
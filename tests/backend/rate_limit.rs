@@ -0,0 +1,34 @@
+use std::time::{Duration, Instant};
+use terminalist::backend::rate_limit::{parse_retry_after, RateLimiter};
+
+#[test]
+fn test_parse_retry_after() {
+    assert_eq!(parse_retry_after("30"), Some(Duration::from_secs(30)));
+    assert_eq!(parse_retry_after(" 5 "), Some(Duration::from_secs(5)));
+    // HTTP dates aren't supported, the caller backs off on its own
+    assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+}
+
+#[tokio::test]
+async fn test_rate_limiter_spaces_requests_after_a_burst() {
+    // Bursts of 2, then one request every 20 ms
+    let limiter = RateLimiter::new(2, 50, Duration::from_secs(1));
+
+    let start = Instant::now();
+    limiter.acquire().await;
+    limiter.acquire().await;
+    assert!(start.elapsed() < Duration::from_millis(15));
+
+    limiter.acquire().await;
+    assert!(start.elapsed() >= Duration::from_millis(15));
+}
+
+#[tokio::test]
+async fn test_rate_limiter_pause() {
+    let limiter = RateLimiter::new(10, 1000, Duration::from_secs(1));
+    limiter.pause_for(Duration::from_millis(30)).await;
+
+    let start = Instant::now();
+    limiter.acquire().await;
+    assert!(start.elapsed() >= Duration::from_millis(25));
+}
@@ -0,0 +1,115 @@
+use std::cell::Cell;
+use std::time::Duration;
+use terminalist::backend::retry::RetryPolicy;
+use terminalist::backend::BackendError;
+
+fn quick_policy(max_retries: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_retries,
+        base_delay: Duration::from_millis(1),
+    }
+}
+
+#[test]
+fn test_backoff_doubles_up_to_a_cap() {
+    let policy = RetryPolicy::default();
+    assert_eq!(policy.backoff(0), Duration::from_millis(500));
+    assert_eq!(policy.backoff(1), Duration::from_secs(1));
+    assert_eq!(policy.backoff(2), Duration::from_secs(2));
+    assert_eq!(policy.backoff(20), Duration::from_secs(30));
+    assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(30));
+}
+
+#[tokio::test]
+async fn test_network_errors_are_retried() {
+    let attempts = &Cell::new(0);
+    let result = quick_policy(3)
+        .run("fetch tasks", || async move {
+            attempts.set(attempts.get() + 1);
+            if attempts.get() < 3 {
+                Err(BackendError::Network("connection reset".to_string()))
+            } else {
+                Ok(attempts.get())
+            }
+        })
+        .await;
+    assert_eq!(result.unwrap(), 3);
+}
+
+#[tokio::test]
+async fn test_retries_stop_after_max_retries() {
+    let attempts = &Cell::new(0);
+    let result: Result<(), _> = quick_policy(2)
+        .run("fetch tasks", || async move {
+            attempts.set(attempts.get() + 1);
+            Err(BackendError::Network("timed out".to_string()))
+        })
+        .await;
+    assert!(matches!(result, Err(BackendError::Network(_))));
+    // The first attempt and two retries
+    assert_eq!(attempts.get(), 3);
+}
+
+#[tokio::test]
+async fn test_fatal_errors_are_not_retried() {
+    for error in [
+        BackendError::Auth("401".to_string()),
+        BackendError::InvalidData("bad response".to_string()),
+    ] {
+        let attempts = &Cell::new(0);
+        let mut error = Some(error);
+        let result: Result<(), _> = quick_policy(3)
+            .run("create task", || {
+                attempts.set(attempts.get() + 1);
+                let error = error.take().unwrap();
+                async move { Err(error) }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 1);
+    }
+
+    // No retries at all with the `none` policy
+    let attempts = &Cell::new(0);
+    let _: Result<(), _> = RetryPolicy::none()
+        .run("sync", || async move {
+            attempts.set(attempts.get() + 1);
+            Err(BackendError::Network("offline".to_string()))
+        })
+        .await;
+    assert_eq!(attempts.get(), 1);
+}
+
+#[tokio::test]
+async fn test_rate_limits_wait_for_retry_after() {
+    let attempts = &Cell::new(0);
+    let result = quick_policy(1)
+        .run("fetch tasks", || async move {
+            attempts.set(attempts.get() + 1);
+            if attempts.get() == 1 {
+                Err(BackendError::RateLimited {
+                    message: "429".to_string(),
+                    retry_after: Some(Duration::from_millis(5)),
+                })
+            } else {
+                Ok(())
+            }
+        })
+        .await;
+    assert!(result.is_ok());
+    assert_eq!(attempts.get(), 2);
+
+    // Waiting for minutes would look like a hang, so the error is reported instead
+    let attempts = &Cell::new(0);
+    let result: Result<(), _> = quick_policy(3)
+        .run("fetch tasks", || async move {
+            attempts.set(attempts.get() + 1);
+            Err(BackendError::RateLimited {
+                message: "429".to_string(),
+                retry_after: Some(Duration::from_secs(900)),
+            })
+        })
+        .await;
+    assert!(matches!(result, Err(BackendError::RateLimited { .. })));
+    assert_eq!(attempts.get(), 1);
+}
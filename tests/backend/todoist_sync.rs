@@ -0,0 +1,75 @@
+use terminalist::backend::todoist::SyncResponse;
+
+fn parse(json: &str) -> SyncResponse {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn test_full_sync_response_maps_to_backend_items() {
+    let response = parse(
+        r#"{
+            "sync_token": "token-1",
+            "full_sync": true,
+            "projects": [
+                {"id": "p1", "name": "Inbox", "color": "grey", "inbox_project": true, "child_order": 0},
+                {"id": "p2", "name": "Work", "color": "blue", "parent_id": null, "child_order": 1}
+            ],
+            "items": [
+                {"id": "t1", "content": "Call", "description": "", "project_id": "p2", "priority": 4,
+                 "child_order": 2, "labels": ["phone"],
+                 "due": {"date": "2025-03-01T09:30:00", "is_recurring": true},
+                 "duration": {"amount": 30, "unit": "minute"}},
+                {"id": "t2", "content": "Read", "project_id": "p1", "due": {"date": "2025-03-02"}}
+            ],
+            "labels": [{"id": "l1", "name": "phone", "color": "red", "item_order": 3}],
+            "sections": [{"id": "s1", "name": "Later", "project_id": "p2", "section_order": 1}]
+        }"#,
+    );
+
+    let changes = response.into_changes();
+    assert_eq!(changes.sync_token, "token-1");
+    assert!(changes.full_sync);
+    assert_eq!(changes.projects.len(), 2);
+    assert!(changes.projects[0].is_inbox);
+    assert_eq!(changes.projects[1].order_index, 1);
+
+    let call = &changes.tasks[0];
+    assert_eq!(call.due_date.as_deref(), Some("2025-03-01"));
+    assert_eq!(call.due_datetime.as_deref(), Some("2025-03-01T09:30:00"));
+    assert!(call.is_recurring);
+    assert_eq!(call.duration.as_deref(), Some("30 minute"));
+    assert_eq!(call.labels, vec!["phone".to_string()]);
+
+    let read = &changes.tasks[1];
+    assert_eq!(read.due_date.as_deref(), Some("2025-03-02"));
+    assert_eq!(read.due_datetime, None);
+
+    assert_eq!(changes.labels[0].order_index, 3);
+    assert_eq!(changes.sections[0].project_remote_id, "p2");
+}
+
+#[test]
+fn test_incremental_response_reports_removed_items() {
+    let response = parse(
+        r#"{
+            "sync_token": "token-2",
+            "full_sync": false,
+            "projects": [{"id": "p2", "name": "Work", "is_archived": true}],
+            "items": [
+                {"id": "t1", "content": "Call", "project_id": "p2", "checked": true},
+                {"id": "t2", "content": "Read", "project_id": "p1", "is_deleted": true}
+            ],
+            "labels": [{"id": "l1", "name": "phone", "is_deleted": true}],
+            "sections": [{"id": "s1", "name": "Later", "project_id": "p2", "is_deleted": true}]
+        }"#,
+    );
+
+    let changes = response.into_changes();
+    assert!(!changes.full_sync);
+    assert!(changes.projects.is_empty());
+    assert!(changes.tasks.is_empty());
+    assert_eq!(changes.removed_project_ids, vec!["p2".to_string()]);
+    assert_eq!(changes.removed_task_ids, vec!["t1".to_string(), "t2".to_string()]);
+    assert_eq!(changes.removed_label_ids, vec!["l1".to_string()]);
+    assert_eq!(changes.removed_section_ids, vec!["s1".to_string()]);
+}
@@ -0,0 +1,74 @@
+//! Model factories shared by the test crates.
+//!
+//! Each builds a minimal open model with a fresh UUID and the name (or content) doubling as
+//! its remote ID; tests override the fields they care about with struct update syntax.
+
+// Each test crate uses only some of the factories
+#![allow(dead_code)]
+
+use terminalist::entities::{label, project, task};
+use uuid::Uuid;
+
+pub fn make_project(name: &str) -> project::Model {
+    project::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: Uuid::nil(),
+        remote_id: name.to_string(),
+        name: name.to_string(),
+        color: "charcoal".to_string(),
+        is_favorite: false,
+        is_inbox_project: false,
+        order_index: 0,
+        parent_uuid: None,
+    }
+}
+
+pub fn make_subproject(name: &str, parent_uuid: Uuid) -> project::Model {
+    project::Model {
+        parent_uuid: Some(parent_uuid),
+        ..make_project(name)
+    }
+}
+
+pub fn make_inbox() -> project::Model {
+    project::Model {
+        is_inbox_project: true,
+        ..make_project("Inbox")
+    }
+}
+
+pub fn make_task(content: &str, project_uuid: Uuid) -> task::Model {
+    task::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: Uuid::nil(),
+        remote_id: content.to_string(),
+        content: content.to_string(),
+        description: None,
+        project_uuid,
+        section_uuid: None,
+        parent_uuid: None,
+        priority: 1,
+        order_index: 0,
+        due_date: None,
+        due_datetime: None,
+        due_string: None,
+        is_recurring: false,
+        deadline: None,
+        duration: None,
+        is_completed: false,
+        is_deleted: false,
+        postpone_count: 0,
+    }
+}
+
+pub fn make_label(name: &str, color: &str) -> label::Model {
+    label::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: Uuid::nil(),
+        remote_id: name.to_string(),
+        name: name.to_string(),
+        color: color.to_string(),
+        order_index: 0,
+        is_favorite: false,
+    }
+}
@@ -1,4 +1,6 @@
-use terminalist::config::Config;
+use std::time::Duration;
+use terminalist::config::{BackendConfig, Config, LabelStyle, PriorityStyle};
+use terminalist::icons::IconTheme;
 use terminalist::utils::datetime;
 
 #[test]
@@ -109,3 +111,349 @@ fn test_generate_config_creates_directory() {
     // Clean up
     let _ = fs::remove_dir_all(&temp_dir);
 }
+
+#[test]
+fn test_logging_format_deserialization() {
+    use terminalist::config::LogFormat;
+
+    assert_eq!(Config::default().logging.format, LogFormat::Pretty);
+
+    let config: Config = toml::from_str("[logging]\nenabled = true\nformat = \"json\"\n").unwrap();
+    assert_eq!(config.logging.format, LogFormat::Json);
+
+    assert!(toml::from_str::<Config>("[logging]\nformat = \"xml\"\n").is_err());
+}
+
+#[test]
+fn test_quick_add_key_config() {
+    let config: Config = toml::from_str("[ui]\nquick_add_key = \"n\"\n").unwrap();
+    assert_eq!(config.ui.quick_add_key, 'n');
+    assert_eq!(Config::default().ui.quick_add_key, 'I');
+
+    let mut config = Config::default();
+    config.ui.quick_add_key = ' ';
+    assert!(config.validate().is_err());
+
+    // Keys of other actions would be shadowed by quick add
+    config.ui.quick_add_key = 'a';
+    let error = config.validate().unwrap_err().to_string();
+    assert_eq!(error, "quick_add_key 'a' is already bound to 'create_task'");
+
+    // Unless the action was moved to another key
+    config.keybindings.insert("create_task".to_string(), "c".to_string());
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_icon_mode_config() {
+    let config: Config = toml::from_str("[ui]\nicon_mode = \"ascii\"\n").unwrap();
+    assert_eq!(config.ui.icon_mode, Some(IconTheme::Ascii));
+    assert_eq!(config.ui.icon_theme(), IconTheme::Ascii);
+    assert_eq!(Config::default().ui.icon_mode, None);
+    assert!(toml::from_str::<Config>("[ui]\nicon_mode = \"nerdfont\"\n").is_err());
+}
+
+#[test]
+fn test_views_config() {
+    assert_eq!(Config::default().ui.views, vec!["today", "tomorrow", "upcoming"]);
+
+    let config: Config = toml::from_str("[ui]\nviews = [\"upcoming\", \"inbox\"]\n").unwrap();
+    assert_eq!(config.ui.views, vec!["upcoming", "inbox"]);
+    assert!(config.validate().is_ok());
+
+    let config: Config = toml::from_str("[ui]\nviews = []\n").unwrap();
+    assert!(config.validate().is_ok());
+
+    let config: Config = toml::from_str("[ui]\nviews = [\"today\", \"later\"]\n").unwrap();
+    assert!(config.validate().is_err());
+
+    let config: Config = toml::from_str("[ui]\nviews = [\"today\", \"today\"]\n").unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_backend_selection() {
+    let config: Config = toml::from_str(
+        "default_backend = \"work\"\n\n[[backends]]\nname = \"personal\"\n\n[[backends]]\nname = \"work\"\ntoken_env = \"TODOIST_WORK_TOKEN\"\n",
+    )
+    .unwrap();
+    assert!(config.validate().is_ok());
+
+    let backend = config.select_backend(None).unwrap().unwrap();
+    assert_eq!(backend.name, "work");
+    assert_eq!(backend.token_env, "TODOIST_WORK_TOKEN");
+
+    let backend = config.select_backend(Some("personal")).unwrap().unwrap();
+    assert_eq!(backend.backend_type, "todoist");
+    assert_eq!(backend.token_env, "TODOIST_API_TOKEN");
+
+    let error = config.select_backend(Some("missing")).unwrap_err().to_string();
+    assert!(error.contains("personal, work"));
+
+    // Without backends the legacy single-account setup is used
+    assert!(Config::default().select_backend(None).unwrap().is_none());
+    assert!(Config::default().select_backend(Some("work")).is_err());
+}
+
+#[test]
+fn test_session_backends() {
+    let content = "default_backend = \"work\"\n\n[[backends]]\nname = \"personal\"\n\n[[backends]]\nname = \"work\"\n";
+    let config: Config = toml::from_str(content).unwrap();
+    let names = |backends: Vec<&BackendConfig>| backends.iter().map(|b| b.name.clone()).collect::<Vec<_>>();
+    assert_eq!(names(config.session_backends(None).unwrap()), vec!["work"]);
+
+    let config: Config = toml::from_str(&format!("connect_all_backends = true\n{}", content)).unwrap();
+    assert_eq!(names(config.session_backends(None).unwrap()), vec!["work", "personal"]);
+    // Asking for one backend connects only that one
+    assert_eq!(
+        names(config.session_backends(Some("personal")).unwrap()),
+        vec!["personal"]
+    );
+
+    assert!(Config::default().session_backends(None).unwrap().is_empty());
+}
+
+#[test]
+fn test_backend_validation() {
+    let config: Config = toml::from_str("[[backends]]\nname = \"a\"\n\n[[backends]]\nname = \"a\"\n").unwrap();
+    assert!(config.validate().is_err());
+
+    let config: Config = toml::from_str("default_backend = \"b\"\n\n[[backends]]\nname = \"a\"\n").unwrap();
+    assert!(config.validate().is_err());
+
+    let config: Config = toml::from_str("[[backends]]\nname = \"a\"\ntype = \"unknown\"\n").unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_filter_config() {
+    let config: Config = toml::from_str(
+        "[[filters]]\nname = \"Work now\"\nquery = \"(today | overdue) & #Work\"\n\n[[filters]]\nname = \"Quick wins\"\nquery = \"p1 & no date\"\n",
+    )
+    .unwrap();
+    assert!(config.validate().is_ok());
+    assert_eq!(config.filters.len(), 2);
+    assert_eq!(config.filters[0].name, "Work now");
+    assert_eq!(config.filters[1].query, "p1 & no date");
+
+    let config: Config =
+        toml::from_str("[[filters]]\nname = \"a\"\nquery = \"today\"\n\n[[filters]]\nname = \"a\"\nquery = \"p1\"\n")
+            .unwrap();
+    assert!(config.validate().is_err());
+
+    let config: Config = toml::from_str("[[filters]]\nname = \"a\"\nquery = \" \"\n").unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_keybindings_config() {
+    let config: Config = toml::from_str("[keybindings]\nquit = \"Ctrl+q\"\nhelp = \"? F1\"\n").unwrap();
+    // Conflicts are reported by the key map in the app, not when loading
+    assert!(config.validate().is_ok());
+    assert_eq!(config.keybindings.len(), 2);
+    assert_eq!(config.keybindings["help"], "? F1");
+
+    assert!(Config::default().keybindings.is_empty());
+}
+
+#[test]
+fn test_date_format_config() {
+    let config: Config = toml::from_str("[display]\ndate_format = \"%d/%m/%Y\"\ntime_format = \"%I:%M %p\"\n").unwrap();
+    assert!(config.validate().is_ok());
+
+    let config: Config = toml::from_str("[display]\ndate_format = \"%Q\"\n").unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_theme_config() {
+    let config: Config = toml::from_str("[theme]\nname = \"solarized-dark\"\naccent = \"#268bd2\"\n").unwrap();
+    assert!(config.validate().is_ok());
+    assert_eq!(config.theme.name, "solarized-dark");
+    assert_eq!(config.theme.colors["accent"], "#268bd2");
+
+    // Unknown themes and invalid colors are rejected when loading
+    let config: Config = toml::from_str("[theme]\nname = \"nord\"\n").unwrap();
+    assert!(config.validate().is_err());
+    let config: Config = toml::from_str("[theme]\nerror = \"bright-ish\"\n").unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_caldav_backend_config() {
+    let config: Config = toml::from_str(
+        "[[backends]]\nname = \"tasks\"\ntype = \"caldav\"\nurl = \"https://dav.example.com/calendars/me/\"\nusername = \"me\"\ntoken_env = \"CALDAV_PASSWORD\"\n",
+    )
+    .unwrap();
+    assert!(config.validate().is_ok());
+
+    let backend = config.select_backend(None).unwrap().unwrap();
+    assert_eq!(backend.backend_type, "caldav");
+    assert_eq!(backend.url.as_deref(), Some("https://dav.example.com/calendars/me/"));
+    assert_eq!(backend.username.as_deref(), Some("me"));
+
+    // The server and account are required
+    let config: Config =
+        toml::from_str("[[backends]]\nname = \"tasks\"\ntype = \"caldav\"\nusername = \"me\"\n").unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_someday_label_config() {
+    assert_eq!(Config::default().behavior.someday_label, "someday");
+
+    let config: Config =
+        toml::from_str("[ui]\nviews = [\"today\", \"someday\"]\n\n[behavior]\nsomeday_label = \"maybe\"\n").unwrap();
+    assert!(config.validate().is_ok());
+    assert_eq!(config.behavior.someday_label, "maybe");
+}
+
+#[test]
+fn test_confirm_due_shortcuts_config() {
+    assert!(!Config::default().behavior.confirm_due_shortcuts);
+
+    let config: Config = toml::from_str("[behavior]\nconfirm_due_shortcuts = true\n").unwrap();
+    assert!(config.behavior.confirm_due_shortcuts);
+}
+
+#[test]
+fn test_confirm_new_label_config() {
+    assert!(!Config::default().behavior.confirm_new_label);
+
+    let config: Config = toml::from_str("[behavior]\nconfirm_new_label = true\n").unwrap();
+    assert!(config.behavior.confirm_new_label);
+    assert_eq!(config.behavior.someday_label, "someday");
+}
+
+#[test]
+fn test_manual_only_sync_config() {
+    let config = Config::default();
+    assert!(!config.sync.manual_only);
+    assert!(config.sync.automatic_sync_enabled());
+
+    let config: Config = toml::from_str("[sync]\nmanual_only = true\n").unwrap();
+    assert!(!config.sync.automatic_sync_enabled());
+    assert_eq!(config.sync.auto_sync_interval_minutes, 5);
+}
+
+#[test]
+fn test_retry_config() {
+    let policy = Config::default().sync.retry_policy();
+    assert_eq!(policy.max_retries, 3);
+    assert_eq!(policy.base_delay, Duration::from_millis(500));
+
+    let config: Config = toml::from_str("[sync]\nmax_retries = 0\nbase_delay_ms = 100\n").unwrap();
+    assert_eq!(config.sync.retry_policy().max_retries, 0);
+    assert_eq!(config.sync.retry_policy().base_delay, Duration::from_millis(100));
+}
+
+#[test]
+fn test_today_min_priority_config() {
+    let views = Config::default().views;
+    assert_eq!(views.today_min_priority, 0);
+    assert!(views.today_includes_priority(1));
+
+    let config: Config = toml::from_str("[views]\ntoday_min_priority = 2\n").unwrap();
+    assert!(config.validate().is_ok());
+    assert!(config.views.today_includes_priority(4)); // P1
+    assert!(config.views.today_includes_priority(3)); // P2
+    assert!(!config.views.today_includes_priority(2)); // P3
+
+    let config: Config = toml::from_str("[views]\ntoday_min_priority = 5\n").unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_upcoming_days_config() {
+    assert_eq!(Config::default().views.upcoming_days, 90);
+
+    let config: Config = toml::from_str("[views]\nupcoming_days = 14\n").unwrap();
+    assert!(config.validate().is_ok());
+    assert_eq!(config.views.upcoming_days, 14);
+
+    let config: Config = toml::from_str("[views]\nupcoming_days = 0\n").unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_label_style_config() {
+    assert_eq!(Config::default().display.label_style, LabelStyle::Full);
+
+    let config: Config = toml::from_str("[display]\nlabel_style = \"dot\"\n").unwrap();
+    assert_eq!(config.display.label_style, LabelStyle::Dot);
+
+    assert!(toml::from_str::<Config>("[display]\nlabel_style = \"tiny\"\n").is_err());
+}
+
+#[test]
+fn test_priority_style_config() {
+    assert_eq!(Config::default().display.priority_style, PriorityStyle::Flag);
+
+    let config: Config = toml::from_str("[display]\npriority_style = \"bar\"\n").unwrap();
+    assert_eq!(config.display.priority_style, PriorityStyle::Bar);
+
+    assert!(toml::from_str::<Config>("[display]\npriority_style = \"color\"\n").is_err());
+}
+
+#[test]
+fn test_backend_default_project_config() {
+    let config: Config = toml::from_str(
+        "[[backends]]\nname = \"work\"\ndefault_project = \"Triage\"\n\n[[backends]]\nname = \"home\"\n",
+    )
+    .unwrap();
+    assert_eq!(config.backends[0].default_project.as_deref(), Some("Triage"));
+    assert_eq!(config.backends[1].default_project, None);
+}
+
+#[test]
+fn test_persist_state_config() {
+    let config = Config::default();
+    assert!(!config.ui.persist_state);
+
+    let config: Config = toml::from_str("[ui]\npersist_state = true\n").unwrap();
+    assert!(config.ui.persist_state);
+}
+
+#[test]
+fn test_project_color_coding_config() {
+    assert!(!Config::default().display.project_color_coding);
+
+    let config: Config = toml::from_str("[display]\nproject_color_coding = true\n").unwrap();
+    assert!(config.display.project_color_coding);
+}
+
+#[test]
+fn test_auto_sync_interval() {
+    let config = Config::default();
+    assert_eq!(config.sync.auto_sync_interval(), Some(Duration::from_secs(300)));
+
+    // Seconds take precedence over minutes
+    let config: Config = toml::from_str("[sync]\nauto_sync_interval_secs = 45\n").unwrap();
+    assert_eq!(config.sync.auto_sync_interval(), Some(Duration::from_secs(45)));
+    assert!(config.validate().is_ok());
+
+    let config: Config = toml::from_str("[sync]\nauto_sync_interval_minutes = 0\n").unwrap();
+    assert_eq!(config.sync.auto_sync_interval(), None);
+
+    let config: Config = toml::from_str("[sync]\nmanual_only = true\n").unwrap();
+    assert_eq!(config.sync.auto_sync_interval(), None);
+
+    let config: Config = toml::from_str("[sync]\nauto_sync_interval_secs = 3\n").unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_database_path_config() {
+    assert!(Config::default().sync.database_path().is_none());
+
+    let config: Config = toml::from_str("[sync]\ndatabase_path = \"/var/lib/terminalist/tasks.db\"\n").unwrap();
+    assert_eq!(
+        config.sync.database_path(),
+        Some(std::path::PathBuf::from("/var/lib/terminalist/tasks.db"))
+    );
+
+    let config: Config = toml::from_str("[sync]\ndatabase_path = \"~/tasks.db\"\n").unwrap();
+    if let Some(home) = dirs::home_dir() {
+        assert_eq!(config.sync.database_path(), Some(home.join("tasks.db")));
+    }
+}
@@ -68,3 +68,34 @@ fn test_theme_cycling() {
     service.cycle_icon_theme();
     assert_eq!(service.theme(), IconTheme::Ascii);
 }
+
+#[test]
+fn test_theme_detection() {
+    assert_eq!(
+        IconTheme::detect_from(Some("xterm-256color"), Some("en_US.UTF-8")),
+        IconTheme::Unicode
+    );
+    assert_eq!(IconTheme::detect_from(Some("xterm-256color"), None), IconTheme::Unicode);
+    assert_eq!(
+        IconTheme::detect_from(Some("linux"), Some("en_US.UTF-8")),
+        IconTheme::Ascii
+    );
+    assert_eq!(IconTheme::detect_from(Some("xterm"), Some("C")), IconTheme::Ascii);
+    assert_eq!(
+        IconTheme::detect_from(Some("xterm"), Some("de_DE.utf8")),
+        IconTheme::Unicode
+    );
+}
+
+#[test]
+fn test_progress_bar() {
+    let unicode = IconService::new(IconTheme::Unicode);
+    assert_eq!(unicode.progress_bar(0, 5), "░░░░░");
+    assert_eq!(unicode.progress_bar(40, 5), "██░░░");
+    // Only a finished project fills the bar
+    assert_eq!(unicode.progress_bar(99, 5), "████░");
+    assert_eq!(unicode.progress_bar(100, 5), "█████");
+
+    let ascii = IconService::new(IconTheme::Ascii);
+    assert_eq!(ascii.progress_bar(60, 5), "###--");
+}
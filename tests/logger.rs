@@ -18,3 +18,20 @@ fn test_log_file_path() {
     let path = path.unwrap();
     assert!(path.to_string_lossy().contains("terminalist.log"));
 }
+
+#[test]
+fn test_json_log_line() {
+    let line = logger::format_json_line(
+        "2025-01-01T12:00:00+00:00",
+        log::Level::Warn,
+        "terminalist::sync",
+        "Sync \"failed\"",
+    );
+    assert!(!line.contains('\n'));
+
+    let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+    assert_eq!(value["timestamp"], "2025-01-01T12:00:00+00:00");
+    assert_eq!(value["level"], "WARN");
+    assert_eq!(value["target"], "terminalist::sync");
+    assert_eq!(value["message"], "Sync \"failed\"");
+}
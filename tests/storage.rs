@@ -1,2 +1,8 @@
 #[path = "storage/db.rs"]
 mod db;
+
+#[path = "storage/task_reconciliation.rs"]
+mod task_reconciliation;
+
+#[path = "storage/migrations.rs"]
+mod migrations;
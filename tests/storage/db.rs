@@ -1,4 +1,4 @@
-use terminalist::storage::LocalStorage;
+use terminalist::storage::{LocalStorage, DB_PATH_ENV};
 
 #[tokio::test]
 async fn test_local_storage_creation() {
@@ -6,3 +6,17 @@ async fn test_local_storage_creation() {
     let result = LocalStorage::new(false).await;
     assert!(result.is_ok(), "LocalStorage should be created successfully");
 }
+
+#[tokio::test]
+async fn test_database_at_configured_path_creates_directory() {
+    if std::env::var_os(DB_PATH_ENV).is_some() {
+        return;
+    }
+    let dir = std::env::temp_dir().join(format!("terminalist-db-{}", uuid::Uuid::new_v4()));
+    let path = dir.join("nested").join("tasks.db");
+
+    let storage = LocalStorage::new_at(true, Some(&path)).await.unwrap();
+    assert!(path.exists());
+    drop(storage);
+    let _ = std::fs::remove_dir_all(&dir);
+}
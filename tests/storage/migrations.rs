@@ -0,0 +1,127 @@
+use sea_orm::{ConnectionTrait, DbBackend, Statement};
+use terminalist::storage::{migrations, LocalStorage};
+
+async fn task_columns(storage: &LocalStorage) -> Vec<String> {
+    columns(storage, "tasks").await
+}
+
+async fn columns(storage: &LocalStorage, table: &str) -> Vec<String> {
+    storage
+        .conn
+        .query_all(Statement::from_string(
+            DbBackend::Sqlite,
+            format!("PRAGMA table_info({table})"),
+        ))
+        .await
+        .unwrap()
+        .iter()
+        .map(|row| row.try_get::<String>("", "name").unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_new_database_is_at_latest_version() {
+    let storage = LocalStorage::new_in_memory().await.unwrap();
+    assert_eq!(storage.schema_version().await.unwrap(), migrations::latest_version());
+    assert!(task_columns(&storage).await.contains(&"due_string".to_string()));
+}
+
+#[tokio::test]
+async fn test_kept_database_is_upgraded_in_place() {
+    let path = std::env::temp_dir().join(format!("terminalist-migrations-{}.db", uuid::Uuid::new_v4()));
+
+    // A database from before versioning: tables without the newer column, no version
+    let storage = LocalStorage::open(&path).await.unwrap();
+    for sql in [
+        "DROP INDEX idx_tasks_backend_remote",
+        "ALTER TABLE tasks DROP COLUMN due_string",
+        "DROP TABLE schema_version",
+    ] {
+        storage
+            .conn
+            .execute(Statement::from_string(DbBackend::Sqlite, sql.to_owned()))
+            .await
+            .unwrap();
+    }
+    assert!(!task_columns(&storage).await.contains(&"due_string".to_string()));
+    drop(storage);
+
+    // Every step runs again without failing on what already exists
+    let storage = LocalStorage::open(&path).await.unwrap();
+    assert_eq!(storage.schema_version().await.unwrap(), migrations::latest_version());
+    assert!(task_columns(&storage).await.contains(&"due_string".to_string()));
+    drop(storage);
+
+    // Reopening an up-to-date database applies nothing
+    let storage = LocalStorage::open(&path).await.unwrap();
+    assert_eq!(storage.schema_version().await.unwrap(), migrations::latest_version());
+    drop(storage);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn test_baseline_database_gets_every_later_column() {
+    let path = std::env::temp_dir().join(format!("terminalist-baseline-{}.db", uuid::Uuid::new_v4()));
+
+    // The schema as the first release created it, with a row per table
+    let conn = sea_orm::Database::connect(format!("sqlite://{}?mode=rwc", path.display()))
+        .await
+        .unwrap();
+    for sql in [
+        "CREATE TABLE backends (uuid TEXT NOT NULL PRIMARY KEY, backend_type TEXT NOT NULL, name TEXT NOT NULL, \
+         is_enabled INTEGER NOT NULL, credentials TEXT NOT NULL, settings TEXT NOT NULL)",
+        "CREATE TABLE projects (uuid TEXT NOT NULL PRIMARY KEY, backend_uuid TEXT NOT NULL, remote_id TEXT NOT NULL, \
+         name TEXT NOT NULL, is_favorite INTEGER NOT NULL, is_inbox_project INTEGER NOT NULL, \
+         order_index INTEGER NOT NULL, parent_uuid TEXT)",
+        "CREATE TABLE labels (uuid TEXT NOT NULL PRIMARY KEY, backend_uuid TEXT NOT NULL, remote_id TEXT NOT NULL, \
+         name TEXT NOT NULL, order_index INTEGER NOT NULL, is_favorite INTEGER NOT NULL)",
+        "CREATE TABLE tasks (uuid TEXT NOT NULL PRIMARY KEY, backend_uuid TEXT NOT NULL, remote_id TEXT NOT NULL, \
+         content TEXT NOT NULL, description TEXT, project_uuid TEXT NOT NULL, section_uuid TEXT, parent_uuid TEXT, \
+         priority INTEGER NOT NULL, order_index INTEGER NOT NULL, due_date TEXT, due_datetime TEXT, \
+         is_recurring INTEGER NOT NULL, deadline TEXT, duration TEXT, is_completed INTEGER NOT NULL, \
+         is_deleted INTEGER NOT NULL)",
+        "INSERT INTO backends VALUES ('b', 'todoist', 'Todoist', 1, '{}', '{}')",
+        "INSERT INTO projects VALUES ('p', 'b', '1', 'Inbox', 0, 1, 0, NULL)",
+        "INSERT INTO labels VALUES ('l', 'b', '2', 'errands', 0, 0)",
+        "INSERT INTO tasks VALUES ('t', 'b', '3', 'Buy milk', NULL, 'p', NULL, NULL, 1, 0, NULL, NULL, 0, NULL, NULL, 0, 0)",
+    ] {
+        conn.execute(Statement::from_string(DbBackend::Sqlite, sql.to_owned()))
+            .await
+            .unwrap();
+    }
+    drop(conn);
+
+    let storage = LocalStorage::open(&path).await.unwrap();
+    assert_eq!(storage.schema_version().await.unwrap(), migrations::latest_version());
+    for (table, column) in [
+        ("backends", "last_sync_at"),
+        ("backends", "sync_token"),
+        ("labels", "color"),
+        ("projects", "color"),
+        ("tasks", "due_string"),
+        ("tasks", "postpone_count"),
+    ] {
+        assert!(
+            columns(&storage, table).await.contains(&column.to_string()),
+            "{table}.{column} is missing"
+        );
+    }
+
+    // Existing rows get the defaults of the new required columns
+    let row = storage
+        .conn
+        .query_one(Statement::from_string(
+            DbBackend::Sqlite,
+            "SELECT labels.color AS label_color, projects.color AS project_color, tasks.postpone_count \
+             FROM labels, projects, tasks"
+                .to_owned(),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.try_get::<String>("", "label_color").unwrap(), "charcoal");
+    assert_eq!(row.try_get::<String>("", "project_color").unwrap(), "charcoal");
+    assert_eq!(row.try_get::<i32>("", "postpone_count").unwrap(), 0);
+    drop(storage);
+    let _ = std::fs::remove_file(&path);
+}
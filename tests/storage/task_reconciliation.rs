@@ -0,0 +1,171 @@
+use sea_orm::{ActiveValue, EntityTrait};
+use std::collections::HashSet;
+use terminalist::entities::{backend, project, task};
+use terminalist::repositories::{ProjectRepository, TaskRepository};
+use terminalist::storage::LocalStorage;
+use uuid::Uuid;
+
+async fn insert_task(storage: &LocalStorage, backend_uuid: Uuid, project_uuid: Uuid, remote_id: &str) -> Uuid {
+    let uuid = Uuid::new_v4();
+    task::Entity::insert(task::ActiveModel {
+        uuid: ActiveValue::Set(uuid),
+        backend_uuid: ActiveValue::Set(backend_uuid),
+        remote_id: ActiveValue::Set(remote_id.to_string()),
+        content: ActiveValue::Set(format!("Task {}", remote_id)),
+        description: ActiveValue::Set(None),
+        project_uuid: ActiveValue::Set(project_uuid),
+        section_uuid: ActiveValue::Set(None),
+        parent_uuid: ActiveValue::Set(None),
+        priority: ActiveValue::Set(1),
+        order_index: ActiveValue::Set(0),
+        due_date: ActiveValue::Set(None),
+        due_datetime: ActiveValue::Set(None),
+        due_string: ActiveValue::Set(None),
+        is_recurring: ActiveValue::Set(false),
+        deadline: ActiveValue::Set(None),
+        duration: ActiveValue::Set(None),
+        is_completed: ActiveValue::Set(false),
+        is_deleted: ActiveValue::Set(false),
+        postpone_count: ActiveValue::Set(0),
+    })
+    .exec(&storage.conn)
+    .await
+    .unwrap();
+    uuid
+}
+
+async fn insert_backend(storage: &LocalStorage) -> Uuid {
+    let backend_uuid = Uuid::new_v4();
+    backend::Entity::insert(backend::ActiveModel {
+        uuid: ActiveValue::Set(backend_uuid),
+        backend_type: ActiveValue::Set("todoist".to_string()),
+        name: ActiveValue::Set("Test".to_string()),
+        is_enabled: ActiveValue::Set(true),
+        credentials: ActiveValue::Set("{}".to_string()),
+        settings: ActiveValue::Set("{}".to_string()),
+        sync_token: ActiveValue::Set(None),
+        last_sync_at: ActiveValue::Set(None),
+    })
+    .exec(&storage.conn)
+    .await
+    .unwrap();
+    backend_uuid
+}
+
+async fn insert_project(storage: &LocalStorage, backend_uuid: Uuid, remote_id: &str) -> Uuid {
+    let uuid = Uuid::new_v4();
+    project::Entity::insert(project::ActiveModel {
+        uuid: ActiveValue::Set(uuid),
+        backend_uuid: ActiveValue::Set(backend_uuid),
+        remote_id: ActiveValue::Set(remote_id.to_string()),
+        name: ActiveValue::Set(format!("Project {}", remote_id)),
+        color: ActiveValue::Set("charcoal".to_string()),
+        is_favorite: ActiveValue::Set(false),
+        is_inbox_project: ActiveValue::Set(false),
+        order_index: ActiveValue::Set(0),
+        parent_uuid: ActiveValue::Set(None),
+    })
+    .exec(&storage.conn)
+    .await
+    .unwrap();
+    uuid
+}
+
+/// In-memory storage with one backend and an inbox project
+async fn setup_storage() -> (LocalStorage, Uuid, Uuid) {
+    let storage = LocalStorage::new_in_memory().await.unwrap();
+    let backend_uuid = insert_backend(&storage).await;
+    let project_uuid = Uuid::new_v4();
+
+    project::Entity::insert(project::ActiveModel {
+        uuid: ActiveValue::Set(project_uuid),
+        backend_uuid: ActiveValue::Set(backend_uuid),
+        remote_id: ActiveValue::Set("p1".to_string()),
+        name: ActiveValue::Set("Inbox".to_string()),
+        color: ActiveValue::Set("charcoal".to_string()),
+        is_favorite: ActiveValue::Set(false),
+        is_inbox_project: ActiveValue::Set(true),
+        order_index: ActiveValue::Set(0),
+        parent_uuid: ActiveValue::Set(None),
+    })
+    .exec(&storage.conn)
+    .await
+    .unwrap();
+
+    (storage, backend_uuid, project_uuid)
+}
+
+#[tokio::test]
+async fn test_tasks_missing_from_fetch_are_removed() {
+    let (storage, backend_uuid, project_uuid) = setup_storage().await;
+
+    // First sync stored two tasks, plus one local task not yet created remotely
+    let kept = insert_task(&storage, backend_uuid, project_uuid, "t1").await;
+    let removed = insert_task(&storage, backend_uuid, project_uuid, "t2").await;
+    let pending = insert_task(&storage, backend_uuid, project_uuid, "").await;
+
+    // Second sync only returns the first task
+    let fetched: HashSet<&str> = ["t1"].into_iter().collect();
+    let count = TaskRepository::delete_missing_for_backend(&storage.conn, &backend_uuid, &fetched)
+        .await
+        .unwrap();
+
+    assert_eq!(count, 1);
+    assert!(TaskRepository::get_by_id(&storage.conn, &kept).await.unwrap().is_some());
+    assert!(TaskRepository::get_by_id(&storage.conn, &removed).await.unwrap().is_none());
+    assert!(TaskRepository::get_by_id(&storage.conn, &pending).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_projects_missing_from_fetch_are_removed_for_that_backend_only() {
+    let (storage, backend_uuid, inbox_uuid) = setup_storage().await;
+    let other_backend_uuid = insert_backend(&storage).await;
+
+    let removed = insert_project(&storage, backend_uuid, "p2").await;
+    let removed_task = insert_task(&storage, backend_uuid, removed, "t1").await;
+    // Same remote ID on another backend, which this fetch says nothing about
+    let other = insert_project(&storage, other_backend_uuid, "p2").await;
+
+    let fetched: HashSet<&str> = ["p1"].into_iter().collect();
+    let count = ProjectRepository::delete_missing_for_backend(&storage.conn, &backend_uuid, &fetched)
+        .await
+        .unwrap();
+
+    assert_eq!(count, 1);
+    assert!(ProjectRepository::get_by_id(&storage.conn, &inbox_uuid)
+        .await
+        .unwrap()
+        .is_some());
+    assert!(ProjectRepository::get_by_id(&storage.conn, &removed).await.unwrap().is_none());
+    assert!(ProjectRepository::get_by_id(&storage.conn, &other).await.unwrap().is_some());
+    // Tasks of a removed project go with it
+    assert!(TaskRepository::get_by_id(&storage.conn, &removed_task).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_most_postponed_tasks_are_ordered_by_count() {
+    let (storage, backend_uuid, project_uuid) = setup_storage().await;
+
+    let once = insert_task(&storage, backend_uuid, project_uuid, "t1").await;
+    let never = insert_task(&storage, backend_uuid, project_uuid, "t2").await;
+    let often = insert_task(&storage, backend_uuid, project_uuid, "t3").await;
+    for (uuid, count) in [(once, 1), (often, 5)] {
+        task::Entity::update(task::ActiveModel {
+            uuid: ActiveValue::Unchanged(uuid),
+            postpone_count: ActiveValue::Set(count),
+            ..Default::default()
+        })
+        .exec(&storage.conn)
+        .await
+        .unwrap();
+    }
+
+    let postponed: Vec<Uuid> = TaskRepository::get_most_postponed(&storage.conn)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|t| t.uuid)
+        .collect();
+    assert_eq!(postponed, vec![often, once]);
+    assert!(!postponed.contains(&never));
+}
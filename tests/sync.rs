@@ -0,0 +1,95 @@
+#[path = "common/fixtures.rs"]
+mod fixtures;
+
+#[path = "sync/batch.rs"]
+mod batch;
+
+#[path = "sync/duplicates.rs"]
+mod duplicates;
+
+#[path = "sync/auth_retry.rs"]
+mod auth_retry;
+
+#[path = "sync/lost_response.rs"]
+mod lost_response;
+
+#[path = "sync/backend_status.rs"]
+mod backend_status;
+
+#[path = "sync/labels.rs"]
+mod labels;
+
+#[path = "sync/validation.rs"]
+mod validation;
+
+#[path = "sync/mock_backend.rs"]
+mod mock_backend;
+
+#[path = "sync/complete_subtasks.rs"]
+mod complete_subtasks;
+
+#[path = "sync/concurrent_edit.rs"]
+mod concurrent_edit;
+
+#[path = "sync/summary.rs"]
+mod summary;
+
+#[path = "sync/deadline.rs"]
+mod deadline;
+
+#[path = "sync/default_project.rs"]
+mod default_project;
+
+#[path = "sync/reparent.rs"]
+mod reparent;
+
+#[path = "sync/stdin_tasks.rs"]
+mod stdin_tasks;
+
+#[path = "sync/import.rs"]
+mod import;
+
+#[path = "sync/incremental.rs"]
+mod incremental;
+
+#[path = "sync/due.rs"]
+mod due;
+
+#[path = "sync/quick_add.rs"]
+mod quick_add;
+
+#[path = "sync/sections.rs"]
+mod sections;
+
+#[path = "sync/update_task.rs"]
+mod update_task;
+
+#[path = "sync/filters.rs"]
+mod filters;
+
+#[path = "sync/search.rs"]
+mod search;
+
+#[path = "sync/counts.rs"]
+mod counts;
+
+#[path = "sync/recurring.rs"]
+mod recurring;
+
+#[path = "sync/duplicate_task.rs"]
+mod duplicate_task;
+
+#[path = "sync/restore_task.rs"]
+mod restore_task;
+
+#[path = "sync/reorder.rs"]
+mod reorder;
+
+#[path = "sync/multi_backend.rs"]
+mod multi_backend;
+
+#[path = "sync/projects.rs"]
+mod projects;
+
+#[path = "sync/cli.rs"]
+mod cli;
@@ -0,0 +1,185 @@
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex as StdMutex};
+use terminalist::backend::{
+    is_auth_error, Backend, BackendError, BackendLabel, BackendProject, BackendSection, BackendTask, CreateLabelArgs,
+    CreateProjectArgs, CreateTaskArgs, UpdateLabelArgs, UpdateProjectArgs, UpdateTaskArgs,
+};
+use terminalist::backend_registry::BackendRegistry;
+use terminalist::storage::LocalStorage;
+use terminalist::sync::{SyncService, SyncStatus};
+use tokio::sync::Mutex;
+
+const VALID_TOKEN: &str = "fresh-token";
+
+/// Backend that rejects every request until its token is replaced with `VALID_TOKEN`
+struct RotatingTokenBackend {
+    api_token: StdMutex<String>,
+}
+
+impl RotatingTokenBackend {
+    fn check_token(&self) -> Result<(), BackendError> {
+        if *self.api_token.lock().unwrap() == VALID_TOKEN {
+            Ok(())
+        } else {
+            Err(BackendError::Auth("401 Unauthorized".to_string()))
+        }
+    }
+}
+
+fn unsupported<T>() -> Result<T, BackendError> {
+    Err(BackendError::Other("not supported by mock".to_string()))
+}
+
+#[async_trait]
+impl Backend for RotatingTokenBackend {
+    fn backend_type(&self) -> &str {
+        "mock"
+    }
+
+    fn set_credentials(&self, credentials: &str) -> Result<(), BackendError> {
+        let creds: serde_json::Value =
+            serde_json::from_str(credentials).map_err(|e| BackendError::InvalidData(e.to_string()))?;
+        *self.api_token.lock().unwrap() = creds["api_token"].as_str().unwrap_or_default().to_string();
+        Ok(())
+    }
+
+    async fn fetch_projects(&self) -> Result<Vec<BackendProject>, BackendError> {
+        self.check_token()?;
+        Ok(Vec::new())
+    }
+
+    async fn fetch_tasks(&self) -> Result<Vec<BackendTask>, BackendError> {
+        self.check_token()?;
+        Ok(Vec::new())
+    }
+
+    async fn fetch_labels(&self) -> Result<Vec<BackendLabel>, BackendError> {
+        self.check_token()?;
+        Ok(Vec::new())
+    }
+
+    async fn fetch_sections(&self) -> Result<Vec<BackendSection>, BackendError> {
+        self.check_token()?;
+        Ok(Vec::new())
+    }
+
+    async fn create_project(&self, _args: CreateProjectArgs) -> Result<BackendProject, BackendError> {
+        unsupported()
+    }
+
+    async fn update_project(&self, _remote_id: &str, _args: UpdateProjectArgs) -> Result<BackendProject, BackendError> {
+        unsupported()
+    }
+
+    async fn delete_project(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unsupported()
+    }
+
+    async fn create_task(&self, _args: CreateTaskArgs) -> Result<BackendTask, BackendError> {
+        unsupported()
+    }
+
+    async fn update_task(&self, _remote_id: &str, _args: UpdateTaskArgs) -> Result<BackendTask, BackendError> {
+        unsupported()
+    }
+
+    async fn delete_task(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unsupported()
+    }
+
+    async fn complete_task(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unsupported()
+    }
+
+    async fn reopen_task(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unsupported()
+    }
+
+    async fn create_label(&self, _args: CreateLabelArgs) -> Result<BackendLabel, BackendError> {
+        unsupported()
+    }
+
+    async fn update_label(&self, _remote_id: &str, _args: UpdateLabelArgs) -> Result<BackendLabel, BackendError> {
+        unsupported()
+    }
+
+    async fn delete_label(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unsupported()
+    }
+}
+
+#[tokio::test]
+async fn test_sync_succeeds_after_token_update() {
+    let storage = Arc::new(Mutex::new(LocalStorage::new_in_memory().await.unwrap()));
+    let registry = Arc::new(BackendRegistry::new(storage));
+    let backend = RotatingTokenBackend {
+        api_token: StdMutex::new("expired-token".to_string()),
+    };
+    let backend_uuid = registry
+        .add_backend_instance(
+            "Mock".to_string(),
+            r#"{"api_token":"expired-token"}"#.to_string(),
+            Box::new(backend),
+        )
+        .await
+        .unwrap();
+    let sync_service = SyncService::new(registry.clone(), backend_uuid, false).await.unwrap();
+
+    // The expired token surfaces as an authentication error, not a generic sync failure
+    let error = sync_service.sync().await.unwrap_err();
+    assert!(is_auth_error(&error));
+
+    sync_service.update_api_token(VALID_TOKEN).await.unwrap();
+
+    // Retrying the same operation now succeeds without recreating the service
+    let status = sync_service.sync().await.unwrap();
+    assert!(matches!(status, SyncStatus::Success(_)));
+
+    // The new credentials are persisted with the backend configuration
+    let stored = registry.list_backends().await.unwrap();
+    assert!(stored[0].credentials.contains(VALID_TOKEN));
+}
+
+#[tokio::test]
+async fn test_backend_status_reports_rejected_token() {
+    let storage = Arc::new(Mutex::new(LocalStorage::new_in_memory().await.unwrap()));
+    let registry = Arc::new(BackendRegistry::new(storage));
+    let backend = RotatingTokenBackend {
+        api_token: StdMutex::new("expired-token".to_string()),
+    };
+    let backend_uuid = registry
+        .add_backend_instance(
+            "Mock".to_string(),
+            r#"{"api_token":"expired-token"}"#.to_string(),
+            Box::new(backend),
+        )
+        .await
+        .unwrap();
+    let sync_service = SyncService::new(registry, backend_uuid, false).await.unwrap();
+
+    let status = sync_service.backend_status().await.unwrap();
+    assert_eq!(status.name, "Mock");
+    assert!(status.connected);
+    assert!(!status.authenticated);
+    assert!(status.sync_error.unwrap().contains("401"));
+    assert!(status.last_sync.is_none());
+
+    sync_service.update_api_token(VALID_TOKEN).await.unwrap();
+    sync_service.sync().await.unwrap();
+
+    let status = sync_service.backend_status().await.unwrap();
+    assert!(status.connected && status.authenticated);
+    assert!(status.sync_error.is_none());
+    assert!(status.last_sync.is_some());
+}
+
+#[test]
+fn test_is_auth_error_only_matches_auth() {
+    assert!(is_auth_error(&anyhow::Error::from(BackendError::Auth(
+        "401".to_string()
+    ))));
+    assert!(!is_auth_error(&anyhow::Error::from(BackendError::Network(
+        "timeout".to_string()
+    ))));
+    assert!(!is_auth_error(&anyhow::anyhow!("Authentication failed: 401")));
+}
@@ -0,0 +1,22 @@
+use super::mock_backend::setup_service;
+use std::time::Duration;
+use terminalist::backend::retry::RetryPolicy;
+
+#[tokio::test]
+async fn test_backend_status_keeps_the_retry_policy() {
+    let (mut sync_service, backend, _storage) = setup_service().await;
+    let policy = RetryPolicy {
+        max_retries: 5,
+        base_delay: Duration::from_millis(100),
+    };
+    sync_service.set_retry_policy(policy);
+
+    let status = sync_service.backend_status().await.unwrap();
+    assert!(status.connected && status.authenticated);
+    assert_eq!(status.name, "Mock");
+
+    // The check doesn't swap the shared backend's policy, which other calls may be using
+    let policies = backend.retry_policies.lock().unwrap();
+    assert!(!policies.is_empty());
+    assert!(policies.iter().all(|set| *set == policy));
+}
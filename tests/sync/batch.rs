@@ -0,0 +1,82 @@
+use super::mock_backend::setup_service;
+use terminalist::sync::batch::{BatchCancel, BatchOutcome};
+use tokio::sync::mpsc;
+
+#[test]
+fn test_batch_cancel_is_shared_between_clones() {
+    let cancel = BatchCancel::new();
+    let handle = cancel.clone();
+    assert!(!handle.is_cancelled());
+
+    cancel.cancel();
+    assert!(handle.is_cancelled());
+}
+
+#[test]
+fn test_batch_outcome_summary() {
+    let outcome = BatchOutcome {
+        succeeded: 5,
+        failed: 0,
+        total: 5,
+        cancelled: false,
+    };
+    assert_eq!(outcome.summary("Completed"), "Completed 5/5 tasks");
+
+    let outcome = BatchOutcome {
+        succeeded: 2,
+        failed: 1,
+        total: 6,
+        cancelled: true,
+    };
+    assert_eq!(
+        outcome.summary("Rescheduled"),
+        "Rescheduled 2/6 tasks (1 failed, cancelled)"
+    );
+}
+
+#[tokio::test]
+async fn test_move_and_delete_tasks_batch() {
+    let (sync_service, backend, _storage) = setup_service().await;
+    sync_service.create_project("Home", None).await.unwrap();
+    sync_service.create_project("Work", None).await.unwrap();
+    let projects = sync_service.get_projects().await.unwrap();
+    let home = projects.iter().find(|p| p.name == "Home").unwrap().uuid;
+    let work = projects.iter().find(|p| p.name == "Work").unwrap().uuid;
+    sync_service.create_task("Buy milk", Some(home)).await.unwrap();
+    sync_service.create_task("Call plumber", Some(home)).await.unwrap();
+    let task_uuids: Vec<_> = sync_service
+        .get_all_tasks()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|task| task.uuid)
+        .collect();
+
+    let (progress, mut progress_rx) = mpsc::unbounded_channel();
+    let outcome = sync_service
+        .move_tasks_batch(&task_uuids, &work, &progress, &BatchCancel::new())
+        .await;
+    assert_eq!(outcome.summary("Moved"), "Moved 2/2 tasks");
+    assert_eq!(sync_service.get_tasks_for_project(&work).await.unwrap().len(), 2);
+    assert!(backend
+        .task_updates
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|args| args.project_remote_id.is_some()));
+
+    let mut last_progress = None;
+    while let Ok(update) = progress_rx.try_recv() {
+        last_progress = Some((update.processed, update.total));
+    }
+    assert_eq!(last_progress, Some((2, 2)));
+
+    let outcome = sync_service
+        .delete_tasks_batch(&task_uuids, &progress, &BatchCancel::new())
+        .await;
+    assert_eq!(outcome.succeeded, 2);
+    for task_uuid in &task_uuids {
+        let task = sync_service.get_task_by_id(task_uuid).await.unwrap().unwrap();
+        assert!(task.is_deleted);
+    }
+}
@@ -0,0 +1,159 @@
+use super::mock_backend::{open_task, setup_service};
+use terminalist::cli::{self, Command, JsonOutput, JSON_SCHEMA_VERSION};
+use terminalist::utils::datetime;
+
+fn args(line: &str) -> Vec<String> {
+    std::iter::once("terminalist")
+        .chain(line.split_whitespace())
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn test_parse_commands() {
+    assert_eq!(Command::parse(&args("--debug")).unwrap(), None);
+    assert_eq!(
+        Command::parse(&args("--backend work add Buy milk --project Home")).unwrap(),
+        Some(Command::Add {
+            content: "Buy milk".to_string(),
+            project: Some("Home".to_string())
+        })
+    );
+    assert_eq!(Command::parse(&args("today --json")).unwrap(), Some(Command::Today));
+    assert_eq!(
+        Command::parse(&args("list --project=Work")).unwrap(),
+        Some(Command::List {
+            project: Some("Work".to_string())
+        })
+    );
+    assert_eq!(
+        Command::parse(&args("complete 123")).unwrap(),
+        Some(Command::Complete { id: "123".to_string() })
+    );
+
+    // Words after -- are content even when they start with a dash, and options stop there
+    assert_eq!(
+        Command::parse(&args("add --project Home -- Fix -v --json handling")).unwrap(),
+        Some(Command::Add {
+            content: "Fix -v --json handling".to_string(),
+            project: Some("Home".to_string())
+        })
+    );
+    assert_eq!(
+        Command::parse(&args("add -- --project")).unwrap(),
+        Some(Command::Add {
+            content: "--project".to_string(),
+            project: None
+        })
+    );
+
+    assert!(Command::parse(&args("add")).is_err());
+    assert_eq!(
+        Command::parse(&args("add Fix -v flag")).unwrap_err().to_string(),
+        "Unknown option '-v' (put it after -- to use it as text)"
+    );
+    assert!(Command::parse(&args("today --jsn")).is_err());
+    assert!(Command::parse(&args("list --project=Work --verbose")).is_err());
+    assert!(Command::parse(&args("complete 1 2")).is_err());
+    assert!(Command::parse(&args("today extra")).is_err());
+    assert_eq!(
+        Command::parse(&args("someday")).unwrap_err().to_string(),
+        "Unknown command 'someday'"
+    );
+}
+
+#[tokio::test]
+async fn test_run_lists_adds_and_completes_tasks() {
+    let (sync_service, backend, _storage) = setup_service().await;
+    let today = datetime::format_today();
+    backend
+        .open_tasks
+        .lock()
+        .unwrap()
+        .extend([open_task("t1", "Pay rent", Some(today.clone())), open_task("t2", "Read", None)]);
+
+    let output = cli::run(&sync_service, &Command::Today, false).await.unwrap();
+    assert_eq!(output, format!("t1\t{}\tPay rent", today));
+
+    let output = cli::run(
+        &sync_service,
+        &Command::List {
+            project: Some("inbox".to_string()),
+        },
+        true,
+    )
+    .await
+    .unwrap();
+    let listed: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(listed["schema_version"], JSON_SCHEMA_VERSION);
+    assert_eq!(listed["command"], "list");
+    assert_eq!(listed["tasks"].as_array().unwrap().len(), 2);
+    // The project of the tasks comes along, once
+    assert_eq!(listed["projects"].as_array().unwrap().len(), 1);
+    assert_eq!(listed["projects"][0]["is_inbox_project"], true);
+    assert!(listed.get("error").is_none());
+
+    let missing = cli::run(
+        &sync_service,
+        &Command::List {
+            project: Some("Work".to_string()),
+        },
+        false,
+    )
+    .await;
+    assert_eq!(missing.unwrap_err().to_string(), "Project 'Work' not found");
+
+    let add = Command::Add {
+        content: "Buy milk".to_string(),
+        project: None,
+    };
+    let output = cli::run(&sync_service, &add, false).await.unwrap();
+    assert!(output.ends_with("\t\tBuy milk"));
+
+    let complete = Command::Complete { id: "t2".to_string() };
+    let output = cli::run(&sync_service, &complete, true).await.unwrap();
+    let completed: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(completed["tasks"][0]["is_completed"], true);
+}
+
+#[test]
+fn test_json_error_keeps_the_envelope() {
+    let error = anyhow::anyhow!("Task '42' not found");
+    let output = JsonOutput::error(&Command::Complete { id: "42".to_string() }, &error)
+        .to_json()
+        .unwrap();
+    let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    assert_eq!(output["schema_version"], JSON_SCHEMA_VERSION);
+    assert_eq!(output["command"], "complete");
+    assert_eq!(output["tasks"], serde_json::json!([]));
+    assert_eq!(output["error"], "Task '42' not found");
+}
+
+#[test]
+fn test_json_usage_error_names_the_command_as_typed() {
+    let typed = args("frobnicate --json");
+    let error = Command::parse(&typed).unwrap_err();
+    let output = JsonOutput::usage_error(&typed, &error).to_json().unwrap();
+    let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    assert_eq!(output["schema_version"], JSON_SCHEMA_VERSION);
+    assert_eq!(output["command"], "frobnicate");
+    assert_eq!(output["error"], "Unknown command 'frobnicate'");
+}
+
+#[test]
+fn test_unknown_option_exits_with_usage_status() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_terminalist"))
+        .args(["add", "Fix", "-v", "flag", "--json"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let printed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(printed["command"], "add");
+    assert_eq!(
+        printed["error"],
+        "Unknown option '-v' (put it after -- to use it as text)"
+    );
+}
@@ -0,0 +1,74 @@
+use super::mock_backend::setup_service;
+use sea_orm::{ActiveValue, IntoActiveModel};
+use std::sync::Arc;
+use terminalist::repositories::TaskRepository;
+use terminalist::storage::LocalStorage;
+use terminalist::sync::SyncService;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+/// Creates a task through the service and makes it a subtask of `parent_uuid`
+async fn create_subtask(
+    sync_service: &SyncService,
+    storage: &Arc<Mutex<LocalStorage>>,
+    content: &str,
+    project_uuid: Uuid,
+    parent_uuid: Option<Uuid>,
+) -> Uuid {
+    sync_service.create_task(content, Some(project_uuid)).await.unwrap();
+    let task = sync_service
+        .get_all_tasks()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|t| t.content == content)
+        .unwrap();
+    let uuid = task.uuid;
+
+    let mut active_model = task.into_active_model();
+    active_model.parent_uuid = ActiveValue::Set(parent_uuid);
+    TaskRepository::update(&storage.lock().await.conn, active_model).await.unwrap();
+    uuid
+}
+
+/// Parent with two subtasks, the first of which has its own subtask
+async fn create_task_tree(sync_service: &SyncService, storage: &Arc<Mutex<LocalStorage>>) -> [Uuid; 4] {
+    sync_service.create_project("Home", None).await.unwrap();
+    let project_uuid = sync_service.get_projects().await.unwrap()[0].uuid;
+
+    let parent = create_subtask(sync_service, storage, "Clean house", project_uuid, None).await;
+    let first = create_subtask(sync_service, storage, "Kitchen", project_uuid, Some(parent)).await;
+    let second = create_subtask(sync_service, storage, "Bathroom", project_uuid, Some(parent)).await;
+    let nested = create_subtask(sync_service, storage, "Fridge", project_uuid, Some(first)).await;
+    [parent, first, second, nested]
+}
+
+async fn is_completed(sync_service: &SyncService, uuid: &Uuid) -> bool {
+    sync_service.get_task_by_id(uuid).await.unwrap().unwrap().is_completed
+}
+
+#[tokio::test]
+async fn test_completing_parent_completes_subtasks() {
+    let (sync_service, _backend, storage) = setup_service().await;
+    let tasks = create_task_tree(&sync_service, &storage).await;
+
+    sync_service.complete_task(&tasks[0]).await.unwrap();
+
+    for uuid in &tasks {
+        assert!(is_completed(&sync_service, uuid).await);
+    }
+}
+
+#[tokio::test]
+async fn test_subtasks_stay_open_when_disabled() {
+    let (mut sync_service, _backend, storage) = setup_service().await;
+    sync_service.set_complete_subtasks_with_parent(false);
+    let tasks = create_task_tree(&sync_service, &storage).await;
+
+    sync_service.complete_task(&tasks[0]).await.unwrap();
+
+    assert!(is_completed(&sync_service, &tasks[0]).await);
+    for uuid in &tasks[1..] {
+        assert!(!is_completed(&sync_service, uuid).await);
+    }
+}
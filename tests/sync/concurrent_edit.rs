@@ -0,0 +1,37 @@
+use super::mock_backend::setup_service;
+use sea_orm::{ActiveValue, IntoActiveModel};
+use terminalist::repositories::TaskRepository;
+
+#[tokio::test]
+async fn test_task_changed_since_detects_update_while_editing() {
+    let (sync_service, _backend, storage) = setup_service().await;
+    sync_service.create_project("Home", None).await.unwrap();
+    let project_uuid = sync_service.get_projects().await.unwrap()[0].uuid;
+    sync_service.create_task("Water plants", Some(project_uuid)).await.unwrap();
+
+    // The edit form opens on the task as it is now
+    let snapshot = sync_service.get_all_tasks().await.unwrap().remove(0);
+    assert!(!sync_service.task_changed_since(&snapshot).await.unwrap());
+
+    // A background sync updates the same task before the form is saved
+    let mut active_model = snapshot.clone().into_active_model();
+    active_model.content = ActiveValue::Set("Water plants and herbs".to_string());
+    TaskRepository::update(&storage.lock().await.conn, active_model).await.unwrap();
+
+    assert!(sync_service.task_changed_since(&snapshot).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_task_changed_since_ignores_local_postpone_count() {
+    let (sync_service, _backend, storage) = setup_service().await;
+    sync_service.create_project("Home", None).await.unwrap();
+    let project_uuid = sync_service.get_projects().await.unwrap()[0].uuid;
+    sync_service.create_task("Water plants", Some(project_uuid)).await.unwrap();
+    let snapshot = sync_service.get_all_tasks().await.unwrap().remove(0);
+
+    let mut active_model = snapshot.clone().into_active_model();
+    active_model.postpone_count = ActiveValue::Set(snapshot.postpone_count + 1);
+    TaskRepository::update(&storage.lock().await.conn, active_model).await.unwrap();
+
+    assert!(!sync_service.task_changed_since(&snapshot).await.unwrap());
+}
@@ -0,0 +1,50 @@
+use super::mock_backend::setup_service;
+use chrono::{Local, NaiveDate, TimeZone};
+use terminalist::utils::datetime::freeze_time;
+
+#[tokio::test]
+async fn test_sidebar_counts_only_include_open_tasks() {
+    let noon = NaiveDate::from_ymd_opt(2025, 4, 9).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let _frozen = freeze_time(Local.from_local_datetime(&noon).single().unwrap());
+
+    let (sync_service, _backend, _storage) = setup_service().await;
+    sync_service.create_project("Work", None).await.unwrap();
+    sync_service.create_project("Home", None).await.unwrap();
+    let projects = sync_service.get_projects().await.unwrap();
+    let work = projects.iter().find(|p| p.name == "Work").unwrap().uuid;
+    let home = projects.iter().find(|p| p.name == "Home").unwrap().uuid;
+
+    for (content, project, due) in [
+        ("Report @urgent", work, Some("2025-04-09")),
+        ("Invoices @urgent", work, Some("2025-04-01")),
+        ("Slides", work, Some("2025-04-10")),
+        ("Paint fence @someday", home, Some("2025-04-09")),
+        ("Groceries", home, None),
+    ] {
+        sync_service
+            .create_task_with_details(content, Some(project), None, due, None, None)
+            .await
+            .unwrap();
+    }
+    let tasks = sync_service.get_all_tasks().await.unwrap();
+    let invoices = tasks.iter().find(|t| t.content == "Invoices").unwrap().uuid;
+    sync_service.complete_task(&invoices).await.unwrap();
+
+    let counts = sync_service.get_sidebar_counts("someday", 7).await.unwrap();
+    // Completed tasks and someday tasks stay out of the date views
+    assert_eq!(counts.today, 1);
+    assert_eq!(counts.tomorrow, 1);
+    assert_eq!(counts.upcoming, 2);
+    assert_eq!(counts.projects.get(&work), Some(&2));
+    assert_eq!(counts.projects.get(&home), Some(&2));
+    // One of Work's three tasks is done
+    assert_eq!(counts.project_progress(&work), Some(33));
+    assert_eq!(counts.project_progress(&home), Some(0));
+    assert_eq!(counts.project_progress(&uuid::Uuid::new_v4()), None);
+
+    let labels = sync_service.get_labels().await.unwrap();
+    let urgent = labels.iter().find(|l| l.name == "urgent").unwrap().uuid;
+    let someday = labels.iter().find(|l| l.name == "someday").unwrap().uuid;
+    assert_eq!(counts.labels.get(&urgent), Some(&1));
+    assert_eq!(counts.labels.get(&someday), Some(&1));
+}
@@ -0,0 +1,24 @@
+use super::mock_backend::setup_service;
+
+#[tokio::test]
+async fn test_update_task_deadline_sets_and_clears() {
+    let (sync_service, backend, _storage) = setup_service().await;
+    sync_service.create_project("Work", None).await.unwrap();
+    let project_uuid = sync_service.get_projects().await.unwrap()[0].uuid;
+    sync_service.create_task("File taxes", Some(project_uuid)).await.unwrap();
+    let task_uuid = sync_service.get_all_tasks().await.unwrap()[0].uuid;
+
+    sync_service.update_task_deadline(&task_uuid, Some("2025-04-15")).await.unwrap();
+    let task = sync_service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.deadline.as_deref(), Some("2025-04-15"));
+
+    sync_service.update_task_deadline(&task_uuid, None).await.unwrap();
+    let task = sync_service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.deadline, None);
+
+    // Clearing sends an empty deadline rather than leaving the field untouched
+    let updates = backend.task_updates.lock().unwrap();
+    let deadlines: Vec<Option<&str>> = updates.iter().map(|args| args.deadline.as_deref()).collect();
+    assert_eq!(deadlines, vec![Some("2025-04-15"), Some("")]);
+    assert!(updates.iter().all(|args| args.due_date.is_none()));
+}
@@ -0,0 +1,69 @@
+use super::mock_backend::{setup_service, setup_service_with, RecordingBackend, INBOX_REMOTE_ID};
+
+#[tokio::test]
+async fn test_task_without_project_goes_to_configured_default_project() {
+    let (mut sync_service, _backend, _storage) = setup_service().await;
+    sync_service.create_project("Work", None).await.unwrap();
+    let work = sync_service
+        .get_projects()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|p| p.name == "Work")
+        .unwrap();
+
+    // Names match case-insensitively
+    sync_service.set_default_project(Some("work".to_string()));
+    sync_service.create_task("Send report", None).await.unwrap();
+
+    let task = sync_service.get_all_tasks().await.unwrap().remove(0);
+    assert_eq!(task.project_uuid, work.uuid);
+}
+
+#[tokio::test]
+async fn test_backend_without_inbox_requires_default_project() {
+    let backend = RecordingBackend {
+        no_inbox: true,
+        ..Default::default()
+    };
+    let (mut sync_service, backend, _storage) = setup_service_with(backend).await;
+    sync_service.create_project("Tasks", None).await.unwrap();
+
+    let error = sync_service.create_task("Buy milk", None).await.unwrap_err();
+    assert!(error.to_string().contains("default_project"));
+    // Nothing was sent to the backend besides the project
+    assert_eq!(backend.created.lock().unwrap().len(), 1);
+
+    sync_service.set_default_project(Some("Tasks".to_string()));
+    sync_service.create_task("Buy milk", None).await.unwrap();
+    assert_eq!(sync_service.get_all_tasks().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_unknown_default_project_is_reported() {
+    let (mut sync_service, _backend, _storage) = setup_service().await;
+    sync_service.set_default_project(Some("Nowhere".to_string()));
+
+    let error = sync_service.create_task("Buy milk", None).await.unwrap_err();
+    assert_eq!(error.to_string(), "Default project 'Nowhere' not found");
+}
+
+#[tokio::test]
+async fn test_task_without_project_goes_to_inbox() {
+    let (sync_service, _backend, _storage) = setup_service().await;
+    sync_service.sync().await.unwrap();
+    sync_service.create_project("Work", None).await.unwrap();
+    let inbox = sync_service
+        .get_projects()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|p| p.is_inbox_project)
+        .unwrap();
+    assert_eq!(inbox.remote_id, INBOX_REMOTE_ID);
+
+    sync_service.create_task("Buy milk", None).await.unwrap();
+
+    let task = sync_service.get_all_tasks().await.unwrap().remove(0);
+    assert_eq!(task.project_uuid, inbox.uuid);
+}
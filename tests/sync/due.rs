@@ -0,0 +1,85 @@
+use super::mock_backend::setup_service;
+use terminalist::utils::datetime;
+
+#[tokio::test]
+async fn test_create_task_with_due_and_priority() {
+    let (sync_service, _backend, _storage) = setup_service().await;
+    sync_service.sync().await.unwrap();
+
+    sync_service
+        .create_task_with_details("Pay rent", None, None, Some("2025-03-01"), None, None)
+        .await
+        .unwrap();
+    sync_service
+        .create_task_with_details("Dentist", None, None, Some("2025-03-04T14:30:00Z"), Some(4), None)
+        .await
+        .unwrap();
+    sync_service.create_task("Read", None).await.unwrap();
+
+    let tasks = sync_service.get_all_tasks().await.unwrap();
+    let task = |content: &str| tasks.iter().find(|t| t.content == content).unwrap();
+
+    assert_eq!(task("Pay rent").due_date.as_deref(), Some("2025-03-01"));
+    assert_eq!(task("Pay rent").due_datetime, None);
+    // A due time is also stored as the local day it falls on
+    assert_eq!(
+        task("Dentist").due_date,
+        datetime::local_due_date(None, Some("2025-03-04T14:30:00Z"))
+    );
+    assert_eq!(task("Dentist").due_datetime.as_deref(), Some("2025-03-04T14:30:00Z"));
+    assert_eq!(task("Dentist").priority, 4);
+    assert_eq!(task("Read").due_date, None);
+}
+
+#[tokio::test]
+async fn test_clearing_due_date_is_sent_to_backend() {
+    let (sync_service, backend, _storage) = setup_service().await;
+    sync_service.sync().await.unwrap();
+    sync_service
+        .create_task_with_details("Pay rent", None, None, Some("2025-03-01"), None, None)
+        .await
+        .unwrap();
+    let task_uuid = sync_service.get_all_tasks().await.unwrap()[0].uuid;
+
+    sync_service.update_task_due_date(&task_uuid, None).await.unwrap();
+
+    // An empty date clears it on the backend; no date at all would leave it unchanged
+    let updates = backend.task_updates.lock().unwrap().clone();
+    assert_eq!(updates.last().unwrap().due_date.as_deref(), Some(""));
+    let task = sync_service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.due_date, None);
+    assert_eq!(task.due_datetime, None);
+}
+
+#[tokio::test]
+async fn test_setting_due_time_sends_only_the_datetime() {
+    let (sync_service, backend, _storage) = setup_service().await;
+    sync_service.sync().await.unwrap();
+    sync_service
+        .create_task_with_details("Call dentist", None, None, Some("2025-03-01"), None, None)
+        .await
+        .unwrap();
+    let task_uuid = sync_service.get_all_tasks().await.unwrap()[0].uuid;
+
+    sync_service
+        .update_task_due_datetime(&task_uuid, "2025-03-04T14:30:00Z")
+        .await
+        .unwrap();
+
+    let updates = backend.task_updates.lock().unwrap().clone();
+    let last = updates.last().unwrap();
+    assert_eq!(last.due_datetime.as_deref(), Some("2025-03-04T14:30:00Z"));
+    assert_eq!(last.due_date, None);
+    let task = sync_service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.due_datetime.as_deref(), Some("2025-03-04T14:30:00Z"));
+    assert_eq!(
+        task.due_date,
+        datetime::local_due_date(None, task.due_datetime.as_deref())
+    );
+
+    // Going back to a plain date drops the time
+    sync_service.update_task_due_date(&task_uuid, Some("2025-03-05")).await.unwrap();
+    let task = sync_service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.due_date.as_deref(), Some("2025-03-05"));
+    assert_eq!(task.due_datetime, None);
+}
@@ -0,0 +1,57 @@
+use super::mock_backend::setup_service;
+use sea_orm::{ActiveModelTrait, ActiveValue};
+use terminalist::entities::section;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_duplicate_task_copies_it_into_the_same_section() {
+    let (sync_service, _backend, storage) = setup_service().await;
+    sync_service.create_project("Home", None).await.unwrap();
+    let home = sync_service.get_projects().await.unwrap().remove(0);
+    let kitchen = Uuid::new_v4();
+    section::ActiveModel {
+        uuid: ActiveValue::Set(kitchen),
+        backend_uuid: ActiveValue::Set(home.backend_uuid),
+        remote_id: ActiveValue::Set("section-kitchen".to_string()),
+        name: ActiveValue::Set("Kitchen".to_string()),
+        project_uuid: ActiveValue::Set(home.uuid),
+        order_index: ActiveValue::Set(0),
+    }
+    .insert(&storage.lock().await.conn)
+    .await
+    .unwrap();
+
+    sync_service
+        .create_task_with_details(
+            "Fix tap @errands",
+            Some(home.uuid),
+            Some(kitchen),
+            Some("2025-05-02"),
+            Some(3),
+            None,
+        )
+        .await
+        .unwrap();
+    let original = sync_service.get_all_tasks().await.unwrap().remove(0);
+
+    sync_service.duplicate_task(&original.uuid).await.unwrap();
+
+    let tasks = sync_service.get_all_tasks().await.unwrap();
+    assert_eq!(tasks.len(), 2);
+    let copy = tasks.iter().find(|t| t.uuid != original.uuid).unwrap();
+    assert_eq!(copy.content, "Fix tap (copy)");
+    assert_eq!(copy.project_uuid, home.uuid);
+    assert_eq!(copy.section_uuid, Some(kitchen));
+    assert_eq!(copy.priority, 3);
+    assert_eq!(copy.due_date.as_deref(), Some("2025-05-02"));
+    let labels: Vec<String> = sync_service
+        .get_labels_for_task(&copy.uuid)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|l| l.name)
+        .collect();
+    assert_eq!(labels, vec!["errands".to_string()]);
+
+    assert!(sync_service.duplicate_task(&Uuid::new_v4()).await.is_err());
+}
@@ -0,0 +1,31 @@
+use crate::fixtures::make_task;
+use terminalist::sync::duplicates::{group_duplicate_tasks, normalize_content};
+use uuid::Uuid;
+
+#[test]
+fn test_normalize_content() {
+    assert_eq!(normalize_content("  Buy Milk \t"), "buy milk");
+}
+
+#[test]
+fn test_group_duplicate_tasks_within_project() {
+    let inbox = Uuid::new_v4();
+    let work = Uuid::new_v4();
+    let mut completed = make_task("buy milk", inbox);
+    completed.is_completed = true;
+
+    let tasks = vec![
+        make_task("Buy milk", inbox),
+        make_task("Write report", work),
+        make_task(" buy MILK ", inbox),
+        make_task("Buy milk", work),
+        completed,
+    ];
+
+    let groups = group_duplicate_tasks(tasks);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].project_uuid, inbox);
+    assert_eq!(groups[0].normalized_content, "buy milk");
+    assert_eq!(groups[0].tasks.len(), 2);
+    assert_eq!(groups[0].tasks[0].content, "Buy milk");
+}
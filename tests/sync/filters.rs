@@ -0,0 +1,99 @@
+use super::mock_backend::setup_service;
+use chrono::{Local, NaiveDate, TimeZone};
+use terminalist::sync::filters::{FilterQuery, FilterTerm};
+use terminalist::utils::datetime::freeze_time;
+
+fn date(text: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+}
+
+#[test]
+fn test_filter_query_precedence() {
+    let today = date("2025-04-09");
+    let term = |term| Box::new(FilterQuery::Term(term));
+
+    // `&` binds tighter than `|`, and `!` tighter than both
+    let query = FilterQuery::parse("today | overdue & !@waiting", today).unwrap();
+    assert_eq!(
+        query,
+        FilterQuery::Or(
+            term(FilterTerm::Today),
+            Box::new(FilterQuery::And(
+                term(FilterTerm::Overdue),
+                Box::new(FilterQuery::Not(term(FilterTerm::Label("waiting".to_string())))),
+            )),
+        )
+    );
+
+    let query = FilterQuery::parse("(today | od) & p1 & due before: 2025-05-01", today).unwrap();
+    assert_eq!(
+        query,
+        FilterQuery::And(
+            Box::new(FilterQuery::And(
+                Box::new(FilterQuery::Or(term(FilterTerm::Today), term(FilterTerm::Overdue))),
+                term(FilterTerm::Priority(4)),
+            )),
+            term(FilterTerm::DueBefore(date("2025-05-01"))),
+        )
+    );
+}
+
+#[test]
+fn test_filter_query_rejects_unsupported_terms() {
+    let today = date("2025-04-09");
+    let error = |query| format!("{:#}", FilterQuery::parse(query, today).unwrap_err());
+
+    assert!(error("today & assigned to: me").contains("Unsupported filter term 'assigned to: me'"));
+    assert!(error("@home, @work").contains("','"));
+    assert!(error("#Work*").contains("Wildcards"));
+    assert!(error("(today | overdue").contains("Missing ')'"));
+    assert!(error("today)").contains("Unmatched ')'"));
+    assert!(error("today &").contains("Expected a filter term"));
+}
+
+#[tokio::test]
+async fn test_get_tasks_for_filter() {
+    let noon = date("2025-04-09").and_hms_opt(12, 0, 0).unwrap();
+    let _frozen = freeze_time(Local.from_local_datetime(&noon).single().unwrap());
+
+    let (sync_service, _backend, _storage) = setup_service().await;
+    sync_service.create_project("Work", None).await.unwrap();
+    let work_uuid = sync_service.get_projects().await.unwrap()[0].uuid;
+    sync_service
+        .create_task_with_details(
+            "Report @urgent",
+            Some(work_uuid),
+            None,
+            Some("2025-04-09"),
+            Some(4),
+            None,
+        )
+        .await
+        .unwrap();
+    sync_service
+        .create_task_with_details("Invoices", Some(work_uuid), None, Some("2025-04-01"), None, None)
+        .await
+        .unwrap();
+    sync_service
+        .create_task_with_details("Someday idea", Some(work_uuid), None, None, None, None)
+        .await
+        .unwrap();
+
+    let contents = |tasks: Vec<terminalist::entities::task::Model>| {
+        let mut contents: Vec<String> = tasks.into_iter().map(|t| t.content).collect();
+        contents.sort();
+        contents
+    };
+
+    let tasks = sync_service.get_tasks_for_filter("today | overdue").await.unwrap();
+    assert_eq!(contents(tasks), vec!["Invoices", "Report"]);
+
+    let tasks = sync_service.get_tasks_for_filter("#work & !@urgent").await.unwrap();
+    assert_eq!(contents(tasks), vec!["Invoices", "Someday idea"]);
+
+    let tasks = sync_service.get_tasks_for_filter("no date | p1").await.unwrap();
+    assert_eq!(contents(tasks), vec!["Report", "Someday idea"]);
+
+    let error = sync_service.get_tasks_for_filter("view all").await.unwrap_err();
+    assert!(format!("{:#}", error).contains("Unsupported filter term 'view all'"));
+}
@@ -0,0 +1,63 @@
+use super::mock_backend::setup_service;
+use sea_orm::{ActiveModelTrait, ActiveValue};
+use terminalist::entities::section;
+use terminalist::utils::import;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_import_files_tasks_under_projects_and_sections() {
+    let (mut sync_service, backend, storage) = setup_service().await;
+    sync_service.create_project("Inbox", None).await.unwrap();
+    sync_service.create_project("Home", None).await.unwrap();
+    sync_service.set_default_project(Some("Inbox".to_string()));
+    let home = sync_service
+        .get_projects()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|p| p.name == "Home")
+        .unwrap();
+    let kitchen = Uuid::new_v4();
+    section::ActiveModel {
+        uuid: ActiveValue::Set(kitchen),
+        backend_uuid: ActiveValue::Set(home.backend_uuid),
+        remote_id: ActiveValue::Set("section-kitchen".to_string()),
+        name: ActiveValue::Set("Kitchen".to_string()),
+        project_uuid: ActiveValue::Set(home.uuid),
+        order_index: ActiveValue::Set(0),
+    }
+    .insert(&storage.lock().await.conn)
+    .await
+    .unwrap();
+
+    let parsed = import::parse_markdown(
+        "- Loose end\n\
+         # home\n\
+         ## kitchen\n\
+         - [ ] Fix tap · p2\n\
+         ## Garden\n\
+         - [ ] Mow lawn\n\
+         # Errands\n\
+         - [ ] Buy milk @shop\n",
+    );
+    let results = sync_service.import_tasks(&parsed.tasks).await;
+    assert!(results.iter().all(|result| result.is_ok()));
+
+    // Existing projects and sections are matched ignoring case; missing projects are created
+    let projects = sync_service.get_projects().await.unwrap();
+    assert_eq!(projects.iter().filter(|p| p.name == "Home").count(), 1);
+    let errands = projects.iter().find(|p| p.name == "Errands").unwrap();
+    let inbox = projects.iter().find(|p| p.name == "Inbox").unwrap();
+
+    let tasks = sync_service.get_all_tasks().await.unwrap();
+    let task = |content: &str| tasks.iter().find(|t| t.content == content).unwrap().clone();
+    assert_eq!(task("Loose end").project_uuid, inbox.uuid);
+    let fix_tap = task("Fix tap");
+    assert_eq!((fix_tap.project_uuid, fix_tap.section_uuid), (home.uuid, Some(kitchen)));
+    assert_eq!(fix_tap.priority, 3);
+    // Sections can't be created, so the task stays at the top of its project
+    let mow = task("Mow lawn");
+    assert_eq!((mow.project_uuid, mow.section_uuid), (home.uuid, None));
+    assert_eq!(task("Buy milk").project_uuid, errands.uuid);
+    assert!(backend.created.lock().unwrap().contains(&"Errands".to_string()));
+}
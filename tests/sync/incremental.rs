@@ -0,0 +1,130 @@
+use super::mock_backend::setup_service_with;
+use super::mock_backend::RecordingBackend;
+use chrono::{Local, TimeZone, Utc};
+use terminalist::backend::{BackendChanges, BackendProject, BackendTask};
+use terminalist::repositories::BackendRepository;
+use terminalist::sync::{SyncStats, SyncStatus};
+use terminalist::utils::datetime;
+
+fn project(remote_id: &str, name: &str) -> BackendProject {
+    BackendProject {
+        remote_id: remote_id.to_string(),
+        name: name.to_string(),
+        color: "grey".to_string(),
+        is_favorite: false,
+        is_inbox: remote_id == "inbox",
+        order_index: 0,
+        parent_remote_id: None,
+    }
+}
+
+fn task(remote_id: &str, content: &str) -> BackendTask {
+    BackendTask {
+        remote_id: remote_id.to_string(),
+        content: content.to_string(),
+        description: None,
+        project_remote_id: "inbox".to_string(),
+        section_remote_id: None,
+        parent_remote_id: None,
+        priority: 1,
+        order_index: 0,
+        due_date: None,
+        due_datetime: None,
+        due_string: None,
+        is_recurring: false,
+        deadline: None,
+        duration: None,
+        is_completed: false,
+        labels: Vec::new(),
+    }
+}
+
+#[tokio::test]
+async fn test_incremental_sync_applies_only_the_changes() {
+    let backend = RecordingBackend::default();
+    backend.changes.lock().unwrap().extend([
+        BackendChanges {
+            sync_token: "token-1".to_string(),
+            full_sync: true,
+            projects: vec![project("inbox", "Inbox")],
+            tasks: vec![task("t1", "Buy milk"), task("t2", "Call mom")],
+            ..BackendChanges::default()
+        },
+        BackendChanges {
+            sync_token: "token-2".to_string(),
+            full_sync: false,
+            tasks: vec![task("t3", "Water plants")],
+            removed_task_ids: vec!["t2".to_string()],
+            ..BackendChanges::default()
+        },
+    ]);
+    let (sync_service, backend, storage) = setup_service_with(backend).await;
+
+    let first = sync_service.sync().await.unwrap();
+    let second = sync_service.sync().await.unwrap();
+
+    let SyncStatus::Success(first) = first else {
+        panic!("first sync failed: {:?}", first);
+    };
+    assert_eq!(first.tasks_added, 2);
+    assert_eq!(first.projects_changed, 1);
+    let SyncStatus::Success(second) = second else {
+        panic!("second sync failed: {:?}", second);
+    };
+    assert_eq!(
+        second,
+        SyncStats {
+            tasks_added: 1,
+            tasks_deleted: 1,
+            ..SyncStats::default()
+        }
+    );
+
+    // The first sync starts from scratch, the second one continues from its token
+    assert_eq!(
+        *backend.sync_tokens.lock().unwrap(),
+        vec![None, Some("token-1".to_string())]
+    );
+
+    // Tasks missing from the delta are kept, removed ones are dropped
+    let mut contents: Vec<String> = sync_service
+        .get_all_tasks()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|t| t.content)
+        .collect();
+    contents.sort();
+    assert_eq!(contents, vec!["Buy milk".to_string(), "Water plants".to_string()]);
+
+    let storage = storage.lock().await;
+    let stored = BackendRepository::get_first(&storage.conn).await.unwrap().unwrap();
+    assert_eq!(stored.sync_token.as_deref(), Some("token-2"));
+}
+
+#[tokio::test]
+async fn test_backend_without_incremental_sync_keeps_no_token() {
+    let (sync_service, backend, storage) = setup_service_with(RecordingBackend::default()).await;
+
+    sync_service.sync().await.unwrap();
+
+    assert_eq!(*backend.sync_tokens.lock().unwrap(), vec![None]);
+    let storage = storage.lock().await;
+    let stored = BackendRepository::get_first(&storage.conn).await.unwrap().unwrap();
+    assert_eq!(stored.sync_token, None);
+}
+
+#[tokio::test]
+async fn test_successful_sync_records_last_sync_time() {
+    let (sync_service, _backend, _storage) = setup_service_with(RecordingBackend::default()).await;
+    assert_eq!(sync_service.last_sync_at().await.unwrap(), None);
+
+    let now = Local.with_ymd_and_hms(2025, 3, 14, 12, 0, 0).unwrap();
+    let _frozen = datetime::freeze_time(now);
+    sync_service.sync().await.unwrap();
+
+    assert_eq!(
+        sync_service.last_sync_at().await.unwrap(),
+        Some(now.with_timezone(&Utc))
+    );
+}
@@ -0,0 +1,27 @@
+use crate::fixtures::make_label;
+use terminalist::sync::labels::{extract_inline_labels, unknown_label_names};
+
+#[test]
+fn test_extract_inline_labels() {
+    let (content, labels) = extract_inline_labels("Call @Phone mom @errands about @phone dinner");
+    assert_eq!(content, "Call mom about dinner");
+    assert_eq!(labels, vec!["Phone".to_string(), "errands".to_string()]);
+
+    // Addresses and a lone "@" are kept as text
+    let (content, labels) = extract_inline_labels("Mail bob@example.com @ noon");
+    assert_eq!(content, "Mail bob@example.com @ noon");
+    assert!(labels.is_empty());
+}
+
+#[test]
+fn test_unknown_label_names_ignores_case() {
+    let labels = vec![make_label("urgent", "grey"), make_label("home", "grey")];
+    let names = vec![
+        "Urgent".to_string(),
+        "errands".to_string(),
+        "Errands".to_string(),
+        "home".to_string(),
+    ];
+
+    assert_eq!(unknown_label_names(&names, &labels), vec!["errands".to_string()]);
+}
@@ -0,0 +1,26 @@
+use super::mock_backend::{setup_service_with, RecordingBackend};
+use std::time::Duration;
+use terminalist::backend::retry::RetryPolicy;
+
+#[tokio::test]
+async fn test_create_is_not_repeated_after_a_lost_response() {
+    let backend = RecordingBackend::default();
+    *backend.lost_create_responses.lock().unwrap() = 1;
+    let (mut sync_service, backend, _storage) = setup_service_with(backend).await;
+    // Even with retries enabled, a create that may have gone through is sent once
+    sync_service.set_retry_policy(RetryPolicy {
+        max_retries: 3,
+        base_delay: Duration::ZERO,
+    });
+
+    sync_service.create_project("Home", None).await.unwrap();
+    let home = sync_service.get_projects().await.unwrap()[0].uuid;
+
+    let error = sync_service.create_task("Pay rent", Some(home)).await.unwrap_err();
+    assert!(error.to_string().contains("timed out"));
+    assert_eq!(*backend.created.lock().unwrap(), vec!["Home", "Pay rent"]);
+
+    // The next create goes through normally
+    sync_service.create_task("Read", Some(home)).await.unwrap();
+    assert_eq!(*backend.created.lock().unwrap(), vec!["Home", "Pay rent", "Read"]);
+}
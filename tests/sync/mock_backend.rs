@@ -0,0 +1,277 @@
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex as StdMutex};
+use terminalist::backend::retry::RetryPolicy;
+use terminalist::backend::{
+    Backend, BackendChanges, BackendError, BackendLabel, BackendProject, BackendSection, BackendTask, CreateLabelArgs,
+    CreateProjectArgs, CreateTaskArgs, UpdateLabelArgs, UpdateProjectArgs, UpdateTaskArgs,
+};
+use terminalist::backend_registry::BackendRegistry;
+use terminalist::storage::LocalStorage;
+use terminalist::sync::SyncService;
+use tokio::sync::Mutex;
+
+/// Remote ID of the inbox listed by `fetch_projects`, which also receives tasks created without a project
+pub const INBOX_REMOTE_ID: &str = "inbox";
+
+/// Remote task IDs with their new order, as sent in one `reorder_tasks` call
+pub type TaskOrder = Vec<(String, i32)>;
+
+/// Backend that echoes created items back and records the names and task updates it was sent
+#[derive(Clone, Default)]
+pub struct RecordingBackend {
+    pub created: Arc<StdMutex<Vec<String>>>,
+    pub task_updates: Arc<StdMutex<Vec<UpdateTaskArgs>>>,
+    pub project_updates: Arc<StdMutex<Vec<UpdateProjectArgs>>>,
+    /// Behave like a backend without an inbox
+    pub no_inbox: bool,
+    /// Fail `fetch_sections`, like a backend that can't list sections
+    pub fail_sections: bool,
+    /// Results of `fetch_changes`, handed out in order; when empty the backend has no incremental sync
+    pub changes: Arc<StdMutex<Vec<BackendChanges>>>,
+    /// Sync tokens `fetch_changes` was called with
+    pub sync_tokens: Arc<StdMutex<Vec<Option<String>>>>,
+    /// Open tasks listed by `fetch_tasks`
+    pub open_tasks: Arc<StdMutex<Vec<BackendTask>>>,
+    /// Task orders sent with `reorder_tasks`
+    pub task_orders: Arc<StdMutex<Vec<TaskOrder>>>,
+    /// Remote IDs of the tasks completed with `complete_task`
+    pub completed: Arc<StdMutex<Vec<String>>>,
+    /// Arguments of every `create_task` call
+    pub created_tasks: Arc<StdMutex<Vec<CreateTaskArgs>>>,
+    /// Number of upcoming `create_task` calls that create the task but then fail with a
+    /// network error, like a response lost to a timeout
+    pub lost_create_responses: Arc<StdMutex<u32>>,
+    /// Retry policies set with `set_retry_policy`
+    pub retry_policies: Arc<StdMutex<Vec<RetryPolicy>>>,
+}
+
+impl RecordingBackend {
+    fn record(&self, name: &str) -> String {
+        let mut created = self.created.lock().unwrap();
+        created.push(name.to_string());
+        format!("remote-{}", created.len())
+    }
+}
+
+fn unsupported<T>() -> Result<T, BackendError> {
+    Err(BackendError::Other("not supported by mock".to_string()))
+}
+
+#[async_trait]
+impl Backend for RecordingBackend {
+    fn backend_type(&self) -> &str {
+        "mock"
+    }
+
+    fn set_credentials(&self, _credentials: &str) -> Result<(), BackendError> {
+        Ok(())
+    }
+
+    fn has_inbox(&self) -> bool {
+        !self.no_inbox
+    }
+
+    fn set_retry_policy(&self, policy: RetryPolicy) {
+        self.retry_policies.lock().unwrap().push(policy);
+    }
+
+    async fn fetch_changes(&self, sync_token: Option<&str>) -> Result<Option<BackendChanges>, BackendError> {
+        self.sync_tokens.lock().unwrap().push(sync_token.map(str::to_string));
+        let mut changes = self.changes.lock().unwrap();
+        if changes.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(changes.remove(0)))
+        }
+    }
+
+    async fn fetch_projects(&self) -> Result<Vec<BackendProject>, BackendError> {
+        if self.no_inbox {
+            return Ok(Vec::new());
+        }
+        Ok(vec![BackendProject {
+            remote_id: INBOX_REMOTE_ID.to_string(),
+            name: "Inbox".to_string(),
+            color: "charcoal".to_string(),
+            is_favorite: false,
+            is_inbox: true,
+            order_index: 0,
+            parent_remote_id: None,
+        }])
+    }
+
+    async fn fetch_tasks(&self) -> Result<Vec<BackendTask>, BackendError> {
+        Ok(self.open_tasks.lock().unwrap().clone())
+    }
+
+    async fn fetch_labels(&self) -> Result<Vec<BackendLabel>, BackendError> {
+        Ok(Vec::new())
+    }
+
+    async fn fetch_sections(&self) -> Result<Vec<BackendSection>, BackendError> {
+        if self.fail_sections {
+            return Err(BackendError::Other("sections endpoint unavailable".to_string()));
+        }
+        Ok(Vec::new())
+    }
+
+    async fn create_project(&self, args: CreateProjectArgs) -> Result<BackendProject, BackendError> {
+        Ok(BackendProject {
+            remote_id: self.record(&args.name),
+            name: args.name,
+            color: args.color.unwrap_or_else(|| "charcoal".to_string()),
+            is_favorite: false,
+            is_inbox: false,
+            order_index: 0,
+            parent_remote_id: args.parent_remote_id,
+        })
+    }
+
+    async fn update_project(&self, remote_id: &str, args: UpdateProjectArgs) -> Result<BackendProject, BackendError> {
+        self.project_updates.lock().unwrap().push(args.clone());
+        Ok(BackendProject {
+            remote_id: remote_id.to_string(),
+            name: args.name.unwrap_or_default(),
+            color: args.color.unwrap_or_else(|| "charcoal".to_string()),
+            is_favorite: args.is_favorite.unwrap_or(false),
+            is_inbox: false,
+            order_index: 0,
+            parent_remote_id: None,
+        })
+    }
+
+    async fn delete_project(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unsupported()
+    }
+
+    async fn create_task(&self, args: CreateTaskArgs) -> Result<BackendTask, BackendError> {
+        self.created_tasks.lock().unwrap().push(args.clone());
+        let remote_id = self.record(&args.content);
+        let mut lost_responses = self.lost_create_responses.lock().unwrap();
+        if *lost_responses > 0 {
+            *lost_responses -= 1;
+            return Err(BackendError::Network("operation timed out".to_string()));
+        }
+        Ok(BackendTask {
+            remote_id,
+            content: args.content,
+            description: None,
+            project_remote_id: if args.project_remote_id.is_empty() {
+                INBOX_REMOTE_ID.to_string()
+            } else {
+                args.project_remote_id
+            },
+            section_remote_id: args.section_remote_id,
+            parent_remote_id: None,
+            priority: args.priority.unwrap_or(1),
+            order_index: 0,
+            due_date: args.due_date,
+            due_datetime: args.due_datetime,
+            due_string: None,
+            is_recurring: false,
+            deadline: None,
+            duration: None,
+            is_completed: false,
+            labels: args.labels,
+        })
+    }
+
+    async fn update_task(&self, remote_id: &str, args: UpdateTaskArgs) -> Result<BackendTask, BackendError> {
+        self.task_updates.lock().unwrap().push(args.clone());
+        Ok(BackendTask {
+            remote_id: remote_id.to_string(),
+            content: args.content.unwrap_or_default(),
+            description: None,
+            project_remote_id: String::new(),
+            section_remote_id: None,
+            parent_remote_id: None,
+            priority: 1,
+            order_index: 0,
+            due_date: args.due_date,
+            due_datetime: None,
+            due_string: None,
+            is_recurring: false,
+            deadline: args.deadline,
+            duration: None,
+            is_completed: false,
+            labels: Vec::new(),
+        })
+    }
+
+    async fn delete_task(&self, _remote_id: &str) -> Result<(), BackendError> {
+        Ok(())
+    }
+
+    async fn complete_task(&self, remote_id: &str) -> Result<(), BackendError> {
+        self.completed.lock().unwrap().push(remote_id.to_string());
+        Ok(())
+    }
+
+    async fn reopen_task(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unsupported()
+    }
+
+    async fn reorder_tasks(&self, orders: &[(String, i32)]) -> Result<(), BackendError> {
+        self.task_orders.lock().unwrap().push(orders.to_vec());
+        Ok(())
+    }
+
+    async fn create_label(&self, args: CreateLabelArgs) -> Result<BackendLabel, BackendError> {
+        Ok(BackendLabel {
+            remote_id: self.record(&args.name),
+            name: args.name,
+            color: "grey".to_string(),
+            order_index: 0,
+            is_favorite: false,
+        })
+    }
+
+    async fn update_label(&self, _remote_id: &str, _args: UpdateLabelArgs) -> Result<BackendLabel, BackendError> {
+        unsupported()
+    }
+
+    async fn delete_label(&self, _remote_id: &str) -> Result<(), BackendError> {
+        unsupported()
+    }
+}
+
+/// An open task in the inbox, as the backend lists it
+pub fn open_task(remote_id: &str, content: &str, due_date: Option<String>) -> BackendTask {
+    BackendTask {
+        remote_id: remote_id.to_string(),
+        content: content.to_string(),
+        description: None,
+        project_remote_id: INBOX_REMOTE_ID.to_string(),
+        section_remote_id: None,
+        parent_remote_id: None,
+        priority: 1,
+        order_index: 0,
+        due_date,
+        due_datetime: None,
+        due_string: None,
+        is_recurring: false,
+        deadline: None,
+        duration: None,
+        is_completed: false,
+        labels: Vec::new(),
+    }
+}
+
+/// Sync service over in-memory storage, backed by a fresh `RecordingBackend`
+pub async fn setup_service() -> (SyncService, RecordingBackend, Arc<Mutex<LocalStorage>>) {
+    setup_service_with(RecordingBackend::default()).await
+}
+
+/// Sync service over in-memory storage, backed by the given `RecordingBackend`
+pub async fn setup_service_with(
+    backend: RecordingBackend,
+) -> (SyncService, RecordingBackend, Arc<Mutex<LocalStorage>>) {
+    let storage = Arc::new(Mutex::new(LocalStorage::new_in_memory().await.unwrap()));
+    let registry = Arc::new(BackendRegistry::new(storage.clone()));
+    let backend_uuid = registry
+        .add_backend_instance("Mock".to_string(), "{}".to_string(), Box::new(backend.clone()))
+        .await
+        .unwrap();
+    let sync_service = SyncService::new(registry, backend_uuid, false).await.unwrap();
+    (sync_service, backend, storage)
+}
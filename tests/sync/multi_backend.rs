@@ -0,0 +1,121 @@
+use super::mock_backend::{open_task, RecordingBackend};
+use std::sync::Arc;
+use terminalist::backend_registry::BackendRegistry;
+use terminalist::cli::{self, Command};
+use terminalist::storage::LocalStorage;
+use terminalist::sync::{SyncService, SyncStatus};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+/// Sync service for a "Personal" backend, with a "Work" backend connected alongside it
+async fn setup_two_backends() -> (SyncService, RecordingBackend, RecordingBackend, Uuid) {
+    setup_two_backends_with(RecordingBackend::default(), RecordingBackend::default()).await
+}
+
+/// Like `setup_two_backends`, with the given backends
+async fn setup_two_backends_with(
+    personal: RecordingBackend,
+    work: RecordingBackend,
+) -> (SyncService, RecordingBackend, RecordingBackend, Uuid) {
+    let storage = Arc::new(Mutex::new(LocalStorage::new_in_memory().await.unwrap()));
+    let registry = Arc::new(BackendRegistry::new(storage));
+    let personal_uuid = registry
+        .add_backend_instance("Personal".to_string(), "{}".to_string(), Box::new(personal.clone()))
+        .await
+        .unwrap();
+    let work_uuid = registry
+        .add_backend_instance("Work".to_string(), "{}".to_string(), Box::new(work.clone()))
+        .await
+        .unwrap();
+    let mut service = SyncService::new(registry, personal_uuid, false).await.unwrap();
+    service.add_registered_backend(work_uuid, None);
+    (service, personal, work, work_uuid)
+}
+
+#[tokio::test]
+async fn test_sync_covers_every_connected_backend() {
+    let (service, personal, work, work_uuid) = setup_two_backends().await;
+
+    let backends = service.connected_backends().await.unwrap();
+    assert_eq!(backends.len(), 2);
+    assert_eq!(backends[0].1, "Personal");
+    assert_eq!(backends[1], (work_uuid, "Work".to_string()));
+
+    assert!(matches!(service.sync().await.unwrap(), SyncStatus::Success(_)));
+    assert_eq!(personal.sync_tokens.lock().unwrap().len(), 1);
+    assert_eq!(work.sync_tokens.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_changes_go_to_the_backend_owning_the_item() {
+    let (service, personal, work, work_uuid) = setup_two_backends().await;
+
+    service.create_project("Home", None).await.unwrap();
+    service.for_backend(&work_uuid).create_project("Office", None).await.unwrap();
+    assert_eq!(*personal.created.lock().unwrap(), vec!["Home"]);
+    assert_eq!(*work.created.lock().unwrap(), vec!["Office"]);
+
+    // Both backends' projects are listed together
+    let projects = service.get_projects().await.unwrap();
+    let home = projects.iter().find(|p| p.name == "Home").unwrap().clone();
+    let office = projects.iter().find(|p| p.name == "Office").unwrap().clone();
+    assert_eq!(office.backend_uuid, work_uuid);
+
+    // A task created in a project of the other backend goes to that backend
+    let routed = service.for_item(&office.uuid).await;
+    assert_eq!(routed.backend_uuid(), work_uuid);
+    routed
+        .create_task_with_details("Write report", Some(office.uuid), None, None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(work.created.lock().unwrap().last().unwrap(), "Write report");
+
+    // Tasks can't move to a project of another backend
+    let task = service.get_all_tasks().await.unwrap().pop().unwrap();
+    let error = service
+        .for_item(&task.uuid)
+        .await
+        .move_task(&task.uuid, &home.uuid)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("another account"));
+    assert!(work.task_updates.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_sync_summary_covers_every_backend() {
+    let (service, personal, work, _work_uuid) = setup_two_backends().await;
+    service.sync().await.unwrap();
+
+    personal.open_tasks.lock().unwrap().push(open_task("p1", "Water plants", None));
+    work.open_tasks.lock().unwrap().push(open_task("w1", "Write report", None));
+    service.sync().await.unwrap();
+
+    let summary = service.last_sync_summary().await.unwrap();
+    assert_eq!(summary.tasks_added, 2);
+    assert_eq!(summary.describe().as_deref(), Some("+2 tasks"));
+}
+
+#[tokio::test]
+async fn test_cli_changes_go_to_the_backend_owning_the_item() {
+    // Only the work backend has an inbox, so "--project inbox" names a work project
+    let personal = RecordingBackend {
+        no_inbox: true,
+        ..Default::default()
+    };
+    let (service, personal, work, _work_uuid) = setup_two_backends_with(personal, RecordingBackend::default()).await;
+    work.open_tasks.lock().unwrap().push(open_task("w1", "Write report", None));
+
+    let add = Command::Add {
+        content: "Book room".to_string(),
+        project: Some("inbox".to_string()),
+    };
+    cli::run(&service, &add, false).await.unwrap();
+    assert_eq!(*work.created.lock().unwrap(), vec!["Book room"]);
+    assert!(personal.created.lock().unwrap().is_empty());
+
+    let complete = Command::Complete { id: "w1".to_string() };
+    cli::run(&service, &complete, false).await.unwrap();
+    assert_eq!(*work.completed.lock().unwrap(), vec!["w1"]);
+    assert!(personal.completed.lock().unwrap().is_empty());
+}
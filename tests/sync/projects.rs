@@ -0,0 +1,45 @@
+use super::mock_backend::setup_service;
+
+#[tokio::test]
+async fn test_set_project_favorite_updates_backend_and_storage() {
+    let (sync_service, backend, _storage) = setup_service().await;
+    sync_service.create_project("Work", None).await.unwrap();
+    let work = sync_service.get_projects().await.unwrap()[0].uuid;
+
+    sync_service.set_project_favorite(&work, true).await.unwrap();
+    assert!(sync_service.get_projects().await.unwrap()[0].is_favorite);
+
+    sync_service.set_project_favorite(&work, false).await.unwrap();
+    assert!(!sync_service.get_projects().await.unwrap()[0].is_favorite);
+
+    // Only the favorite status is sent, so the name is left alone
+    let updates = backend.project_updates.lock().unwrap();
+    assert_eq!(updates.len(), 2);
+    assert!(updates.iter().all(|args| args.name.is_none()));
+    assert_eq!(updates[0].is_favorite, Some(true));
+    assert_eq!(updates[1].is_favorite, Some(false));
+}
+
+#[tokio::test]
+async fn test_project_color_is_sent_on_create_and_update() {
+    let (sync_service, backend, _storage) = setup_service().await;
+    sync_service
+        .create_project_with_color("Work", None, Some("berry_red"))
+        .await
+        .unwrap();
+    let work = sync_service.get_projects().await.unwrap().remove(0);
+    assert_eq!(work.color, "berry_red");
+
+    sync_service
+        .update_project(&work.uuid, Some("Job"), Some("teal"), None)
+        .await
+        .unwrap();
+    let job = sync_service.get_projects().await.unwrap().remove(0);
+    assert_eq!(job.name, "Job");
+    assert_eq!(job.color, "teal");
+    assert!(!job.is_favorite);
+
+    let updates = backend.project_updates.lock().unwrap();
+    assert_eq!(updates[0].color.as_deref(), Some("teal"));
+    assert_eq!(updates[0].is_favorite, None);
+}
@@ -0,0 +1,65 @@
+use crate::fixtures::make_project;
+use chrono::NaiveDate;
+use terminalist::sync::quick_add::QuickAdd;
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 1, 15).unwrap() // Wednesday
+}
+
+#[test]
+fn test_quick_add_extracts_project_priority_and_due() {
+    let projects = vec![make_project("Groceries"), make_project("Work")];
+
+    let parsed = QuickAdd::parse("Buy milk #groceries @errands p2 tomorrow", &projects, today());
+    assert_eq!(
+        parsed,
+        QuickAdd {
+            content: "Buy milk @errands".to_string(),
+            project_uuid: Some(projects[0].uuid),
+            priority: Some(3),
+            due: Some("2025-01-16".to_string()),
+            duration: None,
+        }
+    );
+}
+
+#[test]
+fn test_quick_add_extracts_duration() {
+    let parsed = QuickAdd::parse("Write report for 1h30m tomorrow", &[], today());
+    assert_eq!(parsed.content, "Write report");
+    assert_eq!(parsed.duration, Some(90));
+    assert_eq!(parsed.due.as_deref(), Some("2025-01-16"));
+
+    // "for" followed by anything but an amount with a unit is content
+    let parsed = QuickAdd::parse("Shop for milk for 2 people", &[], today());
+    assert_eq!(parsed.content, "Shop for milk for 2 people");
+    assert_eq!(parsed.duration, None);
+}
+
+#[test]
+fn test_quick_add_finds_date_before_labels() {
+    let parsed = QuickAdd::parse("Call mom fri @phone P1", &[], today());
+    assert_eq!(parsed.content, "Call mom @phone");
+    assert_eq!(parsed.priority, Some(4));
+    assert_eq!(parsed.due.as_deref(), Some("2025-01-17"));
+}
+
+#[test]
+fn test_quick_add_keeps_unknown_tokens() {
+    let projects = vec![make_project("Work")];
+
+    let parsed = QuickAdd::parse("Fix bug #1234 in p5 module", &projects, today());
+    assert_eq!(parsed.content, "Fix bug #1234 in p5 module");
+    assert_eq!(parsed.project_uuid, None);
+    assert_eq!(parsed.priority, None);
+    assert_eq!(parsed.due, None);
+}
+
+#[test]
+fn test_quick_add_never_empties_the_content() {
+    let projects = vec![make_project("Work")];
+
+    let parsed = QuickAdd::parse("#work p1", &projects, today());
+    assert_eq!(parsed.content, "#work p1");
+    assert_eq!(parsed.project_uuid, None);
+}
@@ -0,0 +1,71 @@
+use super::mock_backend::setup_service;
+use sea_orm::{ActiveValue, IntoActiveModel};
+use std::sync::Arc;
+use terminalist::backend::BackendTask;
+use terminalist::repositories::TaskRepository;
+use terminalist::storage::LocalStorage;
+use terminalist::sync::SyncService;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+/// Creates a task through the service and makes it recur every monday
+async fn create_weekly_task(sync_service: &SyncService, storage: &Arc<Mutex<LocalStorage>>) -> (Uuid, String) {
+    sync_service.create_project("Home", None).await.unwrap();
+    let project_uuid = sync_service.get_projects().await.unwrap()[0].uuid;
+    sync_service.create_task("Water plants", Some(project_uuid)).await.unwrap();
+    let task = sync_service.get_all_tasks().await.unwrap().remove(0);
+    let (uuid, remote_id) = (task.uuid, task.remote_id.clone());
+
+    let mut active_model = task.into_active_model();
+    active_model.due_date = ActiveValue::Set(Some("2025-03-10".to_string()));
+    active_model.due_string = ActiveValue::Set(Some("every monday".to_string()));
+    active_model.is_recurring = ActiveValue::Set(true);
+    TaskRepository::update(&storage.lock().await.conn, active_model).await.unwrap();
+    (uuid, remote_id)
+}
+
+#[tokio::test]
+async fn test_completing_recurring_task_moves_it_to_next_occurrence() {
+    let (sync_service, backend, storage) = setup_service().await;
+    let (uuid, remote_id) = create_weekly_task(&sync_service, &storage).await;
+
+    // The backend keeps the task open on its next date
+    let mut next = sync_service.get_task_by_id(&uuid).await.unwrap().unwrap();
+    next.due_date = Some("2025-03-17".to_string());
+    backend.open_tasks.lock().unwrap().push(BackendTask {
+        remote_id,
+        content: next.content,
+        description: None,
+        project_remote_id: String::new(),
+        section_remote_id: None,
+        parent_remote_id: None,
+        priority: 1,
+        order_index: 0,
+        due_date: next.due_date,
+        due_datetime: None,
+        due_string: Some("every monday".to_string()),
+        is_recurring: true,
+        deadline: None,
+        duration: None,
+        is_completed: false,
+        labels: Vec::new(),
+    });
+
+    sync_service.complete_task(&uuid).await.unwrap();
+
+    let task = sync_service.get_task_by_id(&uuid).await.unwrap().unwrap();
+    assert!(!task.is_completed);
+    assert_eq!(task.due_date.as_deref(), Some("2025-03-17"));
+    assert_eq!(task.due_string.as_deref(), Some("every monday"));
+}
+
+#[tokio::test]
+async fn test_completing_last_occurrence_closes_recurring_task() {
+    let (sync_service, _backend, storage) = setup_service().await;
+    let (uuid, _) = create_weekly_task(&sync_service, &storage).await;
+
+    // The backend no longer lists the task as open
+    sync_service.complete_task(&uuid).await.unwrap();
+
+    assert!(sync_service.get_task_by_id(&uuid).await.unwrap().unwrap().is_completed);
+}
@@ -0,0 +1,41 @@
+use super::mock_backend::setup_service;
+use terminalist::entities::task;
+
+fn contents(tasks: &[task::Model]) -> Vec<&str> {
+    tasks.iter().map(|t| t.content.as_str()).collect()
+}
+
+#[tokio::test]
+async fn test_reorder_task_moves_it_among_its_siblings() {
+    let (sync_service, backend, _storage) = setup_service().await;
+    sync_service.create_project("Home", None).await.unwrap();
+    let home = sync_service.get_projects().await.unwrap().remove(0);
+    for content in ["Dishes", "Laundry", "Groceries"] {
+        sync_service.create_task(content, Some(home.uuid)).await.unwrap();
+    }
+    // The mock gives every task the same order, so start from whatever order storage returns
+    let initial = sync_service.get_tasks_for_project(&home.uuid).await.unwrap();
+    let (first, second, last) = (&initial[0].content, &initial[1].content, &initial[2]);
+
+    assert!(sync_service.reorder_task(&last.uuid, -1).await.unwrap());
+    let tasks = sync_service.get_tasks_for_project(&home.uuid).await.unwrap();
+    assert_eq!(
+        contents(&tasks),
+        vec![first.as_str(), last.content.as_str(), second.as_str()]
+    );
+    // Every sibling is numbered again, on the backend and locally
+    let orders = backend.task_orders.lock().unwrap().clone();
+    let positions: Vec<i32> = orders[0].iter().map(|(_, order)| *order).collect();
+    assert_eq!(positions, vec![1, 2, 3]);
+    assert_eq!(tasks.iter().map(|t| t.order_index).collect::<Vec<_>>(), vec![1, 2, 3]);
+
+    // Moving past the first task does nothing
+    assert!(sync_service.reorder_task(&last.uuid, -1).await.unwrap());
+    assert!(!sync_service.reorder_task(&last.uuid, -1).await.unwrap());
+    assert_eq!(backend.task_orders.lock().unwrap().len(), 2);
+    let tasks = sync_service.get_tasks_for_project(&home.uuid).await.unwrap();
+    assert_eq!(
+        contents(&tasks),
+        vec![last.content.as_str(), first.as_str(), second.as_str()]
+    );
+}
@@ -0,0 +1,113 @@
+use super::mock_backend::setup_service;
+use terminalist::sync::batch::{BatchCancel, BatchOutcome};
+use terminalist::sync::SyncService;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+async fn task_uuid(sync_service: &SyncService, content: &str) -> Uuid {
+    sync_service
+        .get_all_tasks()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|t| t.content == content)
+        .unwrap()
+        .uuid
+}
+
+async fn reparent(sync_service: &SyncService, parent: &Uuid, tasks: &[Uuid]) -> anyhow::Result<BatchOutcome> {
+    let (progress, _progress_rx) = mpsc::unbounded_channel();
+    sync_service.reparent_tasks(parent, tasks, &progress, &BatchCancel::new()).await
+}
+
+#[tokio::test]
+async fn test_reparent_tasks_nests_under_parent() {
+    let (sync_service, backend, _storage) = setup_service().await;
+    sync_service.create_project("Home", None).await.unwrap();
+    sync_service.create_project("Work", None).await.unwrap();
+    let projects = sync_service.get_projects().await.unwrap();
+    let home = projects.iter().find(|p| p.name == "Home").unwrap().uuid;
+    let work = projects.iter().find(|p| p.name == "Work").unwrap().uuid;
+    sync_service.create_task("Move house", Some(home)).await.unwrap();
+    sync_service.create_task("Book van", Some(home)).await.unwrap();
+    sync_service.create_task("Update address", Some(work)).await.unwrap();
+
+    let parent = task_uuid(&sync_service, "Move house").await;
+    let van = task_uuid(&sync_service, "Book van").await;
+    let address = task_uuid(&sync_service, "Update address").await;
+
+    // The parent itself is ignored rather than nested under itself
+    let outcome = reparent(&sync_service, &parent, &[parent, van, address]).await.unwrap();
+    assert_eq!(outcome.succeeded, 2);
+    assert_eq!(outcome.failed, 0);
+    assert_eq!(outcome.summary("Nested"), "Nested 2/2 tasks");
+
+    for uuid in [van, address] {
+        let task = sync_service.get_task_by_id(&uuid).await.unwrap().unwrap();
+        assert_eq!(task.parent_uuid, Some(parent));
+        assert_eq!(task.project_uuid, home);
+    }
+
+    let updates = backend.task_updates.lock().unwrap();
+    assert_eq!(updates.len(), 2);
+    assert!(updates
+        .iter()
+        .all(|args| args.parent_remote_id.is_some() && args.content.is_none()));
+}
+
+#[tokio::test]
+async fn test_reparent_tasks_rejects_cycles() {
+    let (sync_service, backend, _storage) = setup_service().await;
+    sync_service.create_project("Home", None).await.unwrap();
+    let home = sync_service.get_projects().await.unwrap()[0].uuid;
+    sync_service.create_task("Move house", Some(home)).await.unwrap();
+    sync_service.create_task("Pack", Some(home)).await.unwrap();
+    sync_service.create_task("Buy boxes", Some(home)).await.unwrap();
+
+    let root = task_uuid(&sync_service, "Move house").await;
+    let pack = task_uuid(&sync_service, "Pack").await;
+    let boxes = task_uuid(&sync_service, "Buy boxes").await;
+    reparent(&sync_service, &root, &[pack]).await.unwrap();
+    reparent(&sync_service, &pack, &[boxes]).await.unwrap();
+
+    // Nesting the root under its own grandchild would form a loop
+    let error = reparent(&sync_service, &boxes, &[root]).await.unwrap_err();
+    assert!(error.to_string().contains("Move house"));
+
+    // Nothing was sent for the rejected batch
+    assert_eq!(backend.task_updates.lock().unwrap().len(), 2);
+    let root_task = sync_service.get_task_by_id(&root).await.unwrap().unwrap();
+    assert_eq!(root_task.parent_uuid, None);
+}
+
+#[tokio::test]
+async fn test_reparent_tasks_moves_subtasks_along() {
+    let (sync_service, _backend, _storage) = setup_service().await;
+    sync_service.create_project("Home", None).await.unwrap();
+    sync_service.create_project("Work", None).await.unwrap();
+    let projects = sync_service.get_projects().await.unwrap();
+    let home = projects.iter().find(|p| p.name == "Home").unwrap().uuid;
+    let work = projects.iter().find(|p| p.name == "Work").unwrap().uuid;
+    sync_service.create_task("Move house", Some(home)).await.unwrap();
+    sync_service.create_task("Change address", Some(work)).await.unwrap();
+    sync_service.create_task("Tell payroll", Some(work)).await.unwrap();
+    sync_service.create_task("Find the form", Some(work)).await.unwrap();
+
+    let parent = task_uuid(&sync_service, "Move house").await;
+    let address = task_uuid(&sync_service, "Change address").await;
+    let payroll = task_uuid(&sync_service, "Tell payroll").await;
+    let form = task_uuid(&sync_service, "Find the form").await;
+    reparent(&sync_service, &address, &[payroll]).await.unwrap();
+    reparent(&sync_service, &payroll, &[form]).await.unwrap();
+
+    reparent(&sync_service, &parent, &[address]).await.unwrap();
+
+    // The whole subtree follows the nested task into the parent's project
+    for uuid in [payroll, form] {
+        let task = sync_service.get_task_by_id(&uuid).await.unwrap().unwrap();
+        assert_eq!(task.project_uuid, home);
+        assert_eq!(task.section_uuid, None);
+    }
+    let payroll_task = sync_service.get_task_by_id(&payroll).await.unwrap().unwrap();
+    assert_eq!(payroll_task.parent_uuid, Some(address));
+}
@@ -0,0 +1,58 @@
+use super::mock_backend::setup_service;
+
+#[tokio::test]
+async fn test_restore_deleted_task_brings_back_labels_and_dates() {
+    let (sync_service, backend, _storage) = setup_service().await;
+    sync_service.create_project("Home", None).await.unwrap();
+    let home = sync_service.get_projects().await.unwrap()[0].uuid;
+    sync_service
+        .create_task_with_details(
+            "Fix tap @errands",
+            Some(home),
+            None,
+            Some("2025-05-02T09:30:00"),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    let original = sync_service.get_all_tasks().await.unwrap().remove(0);
+    sync_service
+        .update_task_deadline(&original.uuid, Some("2025-05-09"))
+        .await
+        .unwrap();
+
+    let snapshot = sync_service.snapshot_task(&original.uuid).await.unwrap();
+    assert_eq!(snapshot.labels, vec!["errands".to_string()]);
+    assert_eq!(snapshot.due.as_deref(), Some("2025-05-02T09:30:00"));
+    assert_eq!(snapshot.deadline.as_deref(), Some("2025-05-09"));
+
+    sync_service.delete_task(&original.uuid).await.unwrap();
+    sync_service.restore_deleted_task(&original.uuid, &snapshot).await.unwrap();
+
+    // The due time is sent on its own, like when the task was created
+    let created = backend.created_tasks.lock().unwrap().last().unwrap().clone();
+    assert_eq!(created.content, "Fix tap");
+    assert_eq!(created.labels, vec!["errands".to_string()]);
+    assert_eq!(created.due_date, None);
+    assert_eq!(created.due_datetime.as_deref(), Some("2025-05-02T09:30:00"));
+    let deadline_update = backend.task_updates.lock().unwrap().last().unwrap().clone();
+    assert_eq!(deadline_update.deadline.as_deref(), Some("2025-05-09"));
+
+    // The soft-deleted copy is replaced by the recreated task
+    let tasks = sync_service.get_all_tasks().await.unwrap();
+    assert_eq!(tasks.len(), 1);
+    let restored = &tasks[0];
+    assert_ne!(restored.uuid, original.uuid);
+    assert!(!restored.is_deleted);
+    assert_eq!(restored.deadline.as_deref(), Some("2025-05-09"));
+    assert_eq!(restored.due_datetime.as_deref(), Some("2025-05-02T09:30:00"));
+    let labels: Vec<String> = sync_service
+        .get_labels_for_task(&restored.uuid)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|l| l.name)
+        .collect();
+    assert_eq!(labels, vec!["errands".to_string()]);
+}
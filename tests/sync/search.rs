@@ -0,0 +1,28 @@
+use super::mock_backend::setup_service;
+
+#[tokio::test]
+async fn test_search_tasks_ranks_fuzzy_matches() {
+    let (mut sync_service, _backend, _storage) = setup_service().await;
+    sync_service.create_project("Home", None).await.unwrap();
+    let project_uuid = sync_service.get_projects().await.unwrap()[0].uuid;
+    for content in ["Make invoices for lake house", "Call mom", "Buy milk"] {
+        sync_service.create_task(content, Some(project_uuid)).await.unwrap();
+    }
+
+    let contents = |tasks: Vec<terminalist::entities::task::Model>| -> Vec<String> {
+        tasks.into_iter().map(|t| t.content).collect()
+    };
+
+    let results = sync_service.search_tasks("by mlk").await.unwrap();
+    assert_eq!(contents(results), vec!["Buy milk"]);
+
+    // Closer matches come first
+    let results = sync_service.search_tasks("milk").await.unwrap();
+    assert_eq!(contents(results), vec!["Buy milk", "Make invoices for lake house"]);
+
+    // Over the limit, only exact substrings match
+    sync_service.set_fuzzy_search_max_tasks(2);
+    assert!(sync_service.search_tasks("by mlk").await.unwrap().is_empty());
+    let results = sync_service.search_tasks("MILK").await.unwrap();
+    assert_eq!(contents(results), vec!["Buy milk"]);
+}
@@ -0,0 +1,77 @@
+use super::mock_backend::{setup_service, setup_service_with, RecordingBackend};
+use sea_orm::{ActiveModelTrait, ActiveValue};
+use terminalist::entities::section;
+use terminalist::sync::SyncStatus;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_tasks_are_filed_under_sections() {
+    let (sync_service, backend, storage) = setup_service().await;
+    sync_service.create_project("Home", None).await.unwrap();
+    sync_service.create_project("Work", None).await.unwrap();
+    let projects = sync_service.get_projects().await.unwrap();
+    let home = projects.iter().find(|p| p.name == "Home").unwrap().clone();
+    let work = projects.iter().find(|p| p.name == "Work").unwrap().clone();
+
+    let mut section_uuids = Vec::new();
+    for (name, project) in [("Kitchen", &home), ("Meetings", &work)] {
+        let uuid = Uuid::new_v4();
+        section::ActiveModel {
+            uuid: ActiveValue::Set(uuid),
+            backend_uuid: ActiveValue::Set(project.backend_uuid),
+            remote_id: ActiveValue::Set(format!("section-{}", name)),
+            name: ActiveValue::Set(name.to_string()),
+            project_uuid: ActiveValue::Set(project.uuid),
+            order_index: ActiveValue::Set(0),
+        }
+        .insert(&storage.lock().await.conn)
+        .await
+        .unwrap();
+        section_uuids.push(uuid);
+    }
+    let (kitchen, meetings) = (section_uuids[0], section_uuids[1]);
+    assert_eq!(
+        sync_service.get_sections_for_project(&home.uuid).await.unwrap().len(),
+        1
+    );
+
+    sync_service
+        .create_task_with_details("Fix tap", Some(home.uuid), Some(kitchen), None, None, None)
+        .await
+        .unwrap();
+    let task = sync_service.get_all_tasks().await.unwrap().remove(0);
+    assert_eq!(task.section_uuid, Some(kitchen));
+
+    // A section of another project is rejected before contacting the backend
+    assert!(sync_service.set_task_section(&task.uuid, Some(meetings)).await.is_err());
+    assert!(backend.task_updates.lock().unwrap().is_empty());
+
+    // Leaving the section moves the task to the top level of its project
+    sync_service.set_task_section(&task.uuid, None).await.unwrap();
+    let task = sync_service.get_task_by_id(&task.uuid).await.unwrap().unwrap();
+    assert_eq!(task.section_uuid, None);
+    let updates = backend.task_updates.lock().unwrap();
+    assert_eq!(updates[0].section_remote_id, None);
+    assert_eq!(updates[0].project_remote_id.as_deref(), Some(home.remote_id.as_str()));
+}
+
+#[tokio::test]
+async fn test_section_fetch_failure_is_reported() {
+    let backend = RecordingBackend {
+        fail_sections: true,
+        ..RecordingBackend::default()
+    };
+    let (mut sync_service, _, _) = setup_service_with(backend).await;
+
+    // The rest of the data is synced, with a warning about the sections
+    let SyncStatus::PartialSuccess { warnings, .. } = sync_service.sync().await.unwrap() else {
+        panic!("expected a partial sync");
+    };
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("sections endpoint unavailable"));
+
+    // Or the whole sync fails when asked to
+    sync_service.set_fail_on_section_error(true);
+    let status = sync_service.sync().await.unwrap();
+    assert!(matches!(status, SyncStatus::Error { message } if message.contains("sections")));
+}
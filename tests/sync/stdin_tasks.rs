@@ -0,0 +1,23 @@
+use super::mock_backend::setup_service;
+
+#[tokio::test]
+async fn test_create_tasks_from_lines_skips_blanks_and_continues_after_failures() {
+    let (mut sync_service, backend, _storage) = setup_service().await;
+    sync_service.create_project("Inbox", None).await.unwrap();
+    sync_service.set_default_project(Some("Inbox".to_string()));
+
+    let results = sync_service
+        .create_tasks_from_lines("Buy milk @errands\n\n   \n@only-labels\n  Call mom  \n")
+        .await;
+
+    let lines: Vec<&str> = results.iter().map(|(line, _)| line.as_str()).collect();
+    assert_eq!(lines, vec!["Buy milk @errands", "@only-labels", "Call mom"]);
+    assert!(results[0].1.is_ok());
+    assert!(results[1].1.is_err());
+    assert!(results[2].1.is_ok());
+
+    // The failed line didn't stop the next one, and inline labels were parsed
+    let created = backend.created.lock().unwrap().clone();
+    assert!(created.contains(&"Buy milk".to_string()));
+    assert!(created.contains(&"Call mom".to_string()));
+}
@@ -0,0 +1,66 @@
+use crate::fixtures::{make_project, make_task};
+use terminalist::sync::summary::{SyncStats, SyncSummary};
+use uuid::Uuid;
+
+#[test]
+fn test_summary_counts_task_and_project_changes() {
+    let kept = make_task("Buy milk", Uuid::nil());
+    let edited = make_task("Call mom", Uuid::nil());
+    let completed_elsewhere = make_task("Pay rent", Uuid::nil());
+    let mut already_completed = make_task("Old task", Uuid::nil());
+    already_completed.is_completed = true;
+
+    let mut edited_after = edited.clone();
+    edited_after.content = "Call mom tonight".to_string();
+
+    let before = vec![kept.clone(), edited, completed_elsewhere, already_completed];
+    let after = vec![
+        kept,
+        edited_after,
+        make_task("New task", Uuid::nil()),
+        make_task("Another", Uuid::nil()),
+    ];
+    let projects_before = vec![make_project("inbox")];
+    let projects_after = vec![make_project("inbox"), make_project("work")];
+
+    let summary = SyncSummary::between(&before, &after, &projects_before, &projects_after);
+    assert_eq!(
+        summary,
+        SyncSummary {
+            tasks_added: 2,
+            tasks_updated: 1,
+            tasks_closed: 1,
+            projects_added: 1,
+            projects_removed: 0,
+        }
+    );
+    assert_eq!(
+        summary.describe().as_deref(),
+        Some("+2 tasks, 1 updated, 1 closed elsewhere, 1 project added")
+    );
+}
+
+#[test]
+fn test_summary_without_changes_has_no_description() {
+    let tasks = vec![make_task("Buy milk", Uuid::nil())];
+    let projects = vec![make_project("inbox")];
+
+    let summary = SyncSummary::between(&tasks, &tasks, &projects, &projects);
+    assert_eq!(summary, SyncSummary::default());
+    assert_eq!(summary.describe(), None);
+}
+
+#[test]
+fn test_sync_stats_description() {
+    let stats = SyncStats {
+        tasks_added: 12,
+        tasks_updated: 3,
+        labels_changed: 1,
+        ..SyncStats::default()
+    };
+    assert_eq!(
+        stats.describe().as_deref(),
+        Some("12 added, 3 updated, 1 label changed")
+    );
+    assert_eq!(SyncStats::default().describe(), None);
+}
@@ -0,0 +1,112 @@
+use super::mock_backend::setup_service;
+use terminalist::backend::UpdateTaskArgs;
+
+#[tokio::test]
+async fn test_update_task_full_only_changes_given_fields() {
+    let (sync_service, backend, _storage) = setup_service().await;
+    sync_service.create_project("Work", None).await.unwrap();
+    let project_uuid = sync_service.get_projects().await.unwrap()[0].uuid;
+    sync_service.create_task("Write report", Some(project_uuid)).await.unwrap();
+    let task_uuid = sync_service.get_all_tasks().await.unwrap()[0].uuid;
+    sync_service.update_task_deadline(&task_uuid, Some("2025-04-15")).await.unwrap();
+
+    let args = UpdateTaskArgs {
+        content: Some("Write quarterly report @work".to_string()),
+        description: Some("Numbers from finance".to_string()),
+        priority: Some(4),
+        due_date: Some("2025-04-10".to_string()),
+        labels: Some(vec!["urgent".to_string()]),
+        ..Default::default()
+    };
+    sync_service.update_task_full(&task_uuid, args).await.unwrap();
+
+    let task = sync_service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.content, "Write quarterly report");
+    assert_eq!(task.description.as_deref(), Some("Numbers from finance"));
+    assert_eq!(task.priority, 4);
+    assert_eq!(task.due_date.as_deref(), Some("2025-04-10"));
+    // Fields that weren't given keep their value
+    assert_eq!(task.deadline.as_deref(), Some("2025-04-15"));
+
+    // Inline labels are added to the ones from the labels field, and missing labels are created
+    let labels: Vec<String> = sync_service
+        .get_labels_for_task(&task_uuid)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|l| l.name)
+        .collect();
+    assert_eq!(labels.len(), 2);
+    assert!(labels.contains(&"urgent".to_string()) && labels.contains(&"work".to_string()));
+
+    let updates = backend.task_updates.lock().unwrap();
+    let last = updates.last().unwrap();
+    assert_eq!(last.content.as_deref(), Some("Write quarterly report"));
+    assert!(last.deadline.is_none() && last.duration.is_none() && last.project_remote_id.is_none());
+}
+
+#[tokio::test]
+async fn test_update_task_full_clears_description_and_due_date() {
+    let (sync_service, _backend, _storage) = setup_service().await;
+    sync_service.create_project("Work", None).await.unwrap();
+    let project_uuid = sync_service.get_projects().await.unwrap()[0].uuid;
+    sync_service.create_task("Write report", Some(project_uuid)).await.unwrap();
+    let task_uuid = sync_service.get_all_tasks().await.unwrap()[0].uuid;
+
+    let args = UpdateTaskArgs {
+        description: Some("Draft".to_string()),
+        due_date: Some("2025-04-10".to_string()),
+        ..Default::default()
+    };
+    sync_service.update_task_full(&task_uuid, args).await.unwrap();
+
+    let args = UpdateTaskArgs {
+        description: Some(String::new()),
+        due_date: Some(String::new()),
+        ..Default::default()
+    };
+    sync_service.update_task_full(&task_uuid, args).await.unwrap();
+
+    let task = sync_service.get_task_by_id(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.content, "Write report");
+    assert_eq!(task.description, None);
+    assert_eq!(task.due_date, None);
+    assert_eq!(task.due_datetime, None);
+}
+
+#[tokio::test]
+async fn test_update_task_labels_replaces_them() {
+    let (sync_service, backend, _storage) = setup_service().await;
+    sync_service.create_project("Work", None).await.unwrap();
+    let project_uuid = sync_service.get_projects().await.unwrap()[0].uuid;
+    sync_service
+        .create_task("Write report @urgent @work", Some(project_uuid))
+        .await
+        .unwrap();
+    sync_service.create_label("later").await.unwrap();
+    let task_uuid = sync_service.get_all_tasks().await.unwrap()[0].uuid;
+
+    sync_service
+        .update_task_labels(&task_uuid, vec!["work".to_string(), "later".to_string()])
+        .await
+        .unwrap();
+
+    let mut labels: Vec<String> = sync_service
+        .get_labels_for_task(&task_uuid)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|l| l.name)
+        .collect();
+    labels.sort();
+    assert_eq!(labels, vec!["later".to_string(), "work".to_string()]);
+    let updates = backend.task_updates.lock().unwrap().clone();
+    assert_eq!(
+        updates.last().unwrap().labels,
+        Some(vec!["work".to_string(), "later".to_string()])
+    );
+
+    // An empty list removes every label
+    sync_service.update_task_labels(&task_uuid, Vec::new()).await.unwrap();
+    assert!(sync_service.get_labels_for_task(&task_uuid).await.unwrap().is_empty());
+}
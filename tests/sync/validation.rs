@@ -0,0 +1,48 @@
+use super::mock_backend::setup_service;
+use terminalist::sync::validate_name;
+
+#[test]
+fn test_validate_name() {
+    assert_eq!(validate_name("Project name", "  Work ").unwrap(), "Work");
+    assert_eq!(
+        validate_name("Project name", " \t\n").unwrap_err().to_string(),
+        "Project name cannot be empty"
+    );
+    assert!(validate_name("Label name", "").is_err());
+}
+
+#[tokio::test]
+async fn test_create_methods_reject_empty_names_without_calling_backend() {
+    let (sync_service, backend, _storage) = setup_service().await;
+
+    for input in ["", "   ", "\t\n"] {
+        assert!(sync_service.create_project(input, None).await.is_err());
+        assert!(sync_service.create_label(input).await.is_err());
+        assert!(sync_service.create_task(input, None).await.is_err());
+    }
+
+    assert!(backend.created.lock().unwrap().is_empty());
+    assert!(sync_service.get_projects().await.unwrap().is_empty());
+    assert!(sync_service.get_labels().await.unwrap().is_empty());
+    assert!(sync_service.get_all_tasks().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_create_methods_trim_valid_names() {
+    let (sync_service, backend, _storage) = setup_service().await;
+
+    sync_service.create_project("  Work  ", None).await.unwrap();
+    let project = sync_service.get_projects().await.unwrap().remove(0);
+    assert_eq!(project.name, "Work");
+
+    sync_service.create_label("\turgent ").await.unwrap();
+    assert_eq!(sync_service.get_labels().await.unwrap()[0].name, "urgent");
+
+    sync_service.create_task("  Buy milk  ", Some(project.uuid)).await.unwrap();
+    assert_eq!(sync_service.get_all_tasks().await.unwrap()[0].content, "Buy milk");
+
+    assert_eq!(
+        *backend.created.lock().unwrap(),
+        vec!["Work".to_string(), "urgent".to_string(), "Buy milk".to_string()]
+    );
+}
@@ -1,3 +1,6 @@
+#[path = "common/fixtures.rs"]
+mod fixtures;
+
 #[path = "ui/app_component.rs"]
 mod app_component;
 
@@ -1,4 +1,5 @@
-use terminalist::ui::app_component::AppState;
+use terminalist::ui::app_component::{AppComponent, AppState};
+use terminalist::ui::core::actions::{Action, DialogType};
 
 #[test]
 fn test_app_state_default() {
@@ -10,3 +11,20 @@ fn test_app_state_default() {
         "Default AppState should have no error message"
     );
 }
+
+#[test]
+fn test_quit_asks_only_while_operations_run() {
+    assert!(AppComponent::quit_confirmation(0).is_none());
+
+    match AppComponent::quit_confirmation(1) {
+        Some(DialogType::Confirmation { message, action }) => {
+            assert_eq!(message, "1 operation in progress, quit anyway?");
+            assert!(matches!(*action, Action::ForceQuit));
+        }
+        other => panic!("expected a confirmation, got {:?}", other),
+    }
+    assert!(matches!(
+        AppComponent::quit_confirmation(3),
+        Some(DialogType::Confirmation { message, .. }) if message == "3 operations in progress, quit anyway?"
+    ));
+}
@@ -1,6 +1,9 @@
 #[path = "components/badge.rs"]
 mod badge;
 
+#[path = "components/breadcrumb.rs"]
+mod breadcrumb;
+
 #[path = "components/dialog_component.rs"]
 mod dialog_component;
 
@@ -10,6 +13,9 @@ mod dialogs;
 #[path = "components/sidebar_component.rs"]
 mod sidebar_component;
 
+#[path = "components/task_detail.rs"]
+mod task_detail;
+
 #[path = "components/task_list_component.rs"]
 mod task_list_component;
 
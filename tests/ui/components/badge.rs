@@ -1,4 +1,8 @@
+use crate::fixtures::make_label;
+use ratatui::text::Span;
+use terminalist::config::LabelStyle;
 use terminalist::ui::components::badge::*;
+use terminalist::utils::color;
 
 #[test]
 fn test_create_paren_badge() {
@@ -9,3 +13,27 @@ fn test_create_paren_badge() {
         "Paren badge should contain parentheses"
     );
 }
+
+fn rendered(spans: &[Span<'static>]) -> String {
+    spans.iter().map(|span| span.content.as_ref()).collect()
+}
+
+#[test]
+fn test_label_badges_follow_label_style() {
+    let labels = vec![make_label("waiting", "red"), make_label("errand", "blue")];
+
+    assert_eq!(
+        rendered(&create_label_badges(&labels, LabelStyle::Full)),
+        " @waiting @errand"
+    );
+    assert_eq!(rendered(&create_label_badges(&labels, LabelStyle::Short)), " @wa @er");
+    // Dots are packed together: one column per label plus the leading space
+    assert_eq!(rendered(&create_label_badges(&labels, LabelStyle::Dot)), " ●●");
+    assert!(create_label_badges(&[], LabelStyle::Dot).is_empty());
+}
+
+#[test]
+fn test_dot_label_badge_uses_label_color() {
+    let badge = create_styled_label_badge(&make_label("waiting", "red"), LabelStyle::Dot);
+    assert_eq!(badge.style.fg, Some(color::to_terminal_color("red")));
+}
@@ -0,0 +1,40 @@
+use crate::fixtures::{make_project, make_subproject};
+use terminalist::ui::components::breadcrumb::{format_breadcrumb, project_path};
+use uuid::Uuid;
+
+fn path(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_project_path_walks_up_to_root() {
+    let work = make_project("Work");
+    let backend = make_subproject("Backend", work.uuid);
+    let auth = make_subproject("Auth", backend.uuid);
+    let projects = vec![auth.clone(), work.clone(), backend];
+
+    assert_eq!(project_path(&projects, &auth.uuid), path(&["Work", "Backend", "Auth"]));
+    assert_eq!(project_path(&projects, &work.uuid), path(&["Work"]));
+    assert!(project_path(&projects, &Uuid::new_v4()).is_empty());
+}
+
+#[test]
+fn test_project_path_stops_on_cycle() {
+    let mut first = make_project("First");
+    let second = make_subproject("Second", first.uuid);
+    first.parent_uuid = Some(second.uuid);
+    let projects = vec![first.clone(), second];
+
+    assert_eq!(project_path(&projects, &first.uuid), path(&["Second", "First"]));
+}
+
+#[test]
+fn test_format_breadcrumb_abbreviates_middle_ancestors() {
+    let chain = path(&["Work", "Backend", "Services", "Auth"]);
+
+    assert_eq!(format_breadcrumb(&chain, 100), "Work › Backend › Services › Auth");
+    assert_eq!(format_breadcrumb(&chain, 26), "Work › … › Services › Auth");
+    assert_eq!(format_breadcrumb(&chain, 15), "Work › … › Auth");
+    assert_eq!(format_breadcrumb(&chain, 8), "… › Auth");
+    assert_eq!(format_breadcrumb(&chain, 2), "Auth");
+}
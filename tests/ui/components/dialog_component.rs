@@ -1,7 +1,462 @@
+use crate::fixtures::{make_inbox, make_project, make_task};
+use chrono::{NaiveDate, NaiveTime};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use terminalist::entities::{label, project, section, task};
+use terminalist::sync::duplicates::DuplicateGroup;
 use terminalist::ui::components::DialogComponent;
+use terminalist::ui::core::{Action, Component, DialogType};
+use terminalist::ui_state::{UiState, RECENT_PROJECTS_LIMIT};
+use terminalist::utils::datetime;
+use uuid::Uuid;
 
 #[test]
 fn test_dialog_component_creation() {
     // Test that DialogComponent can be created without panicking
     let _dialog = DialogComponent::new();
 }
+
+#[test]
+fn test_task_creation_preselects_most_recent_existing_project() {
+    let inbox = make_inbox();
+    let work = make_project("Work");
+    let home = make_project("Home");
+    let deleted_uuid = Uuid::new_v4();
+
+    let mut dialog = DialogComponent::new();
+    dialog.update_data(vec![inbox, work.clone(), home.clone()], Vec::new());
+    // The most recent project no longer exists, so the next one is used
+    dialog.set_recent_projects(vec![deleted_uuid, home.uuid, work.uuid]);
+
+    dialog.update(Action::ShowDialog(DialogType::TaskCreation {
+        default_project_uuid: None,
+    }));
+    assert!(matches!(
+        dialog.dialog_type,
+        Some(DialogType::TaskCreation { default_project_uuid: Some(uuid) }) if uuid == home.uuid
+    ));
+    assert_eq!(dialog.selected_task_project_uuid, Some(home.uuid));
+
+    // A view-provided default always wins over the recent list
+    dialog.update(Action::HideDialog);
+    dialog.update(Action::ShowDialog(DialogType::TaskCreation {
+        default_project_uuid: Some(work.uuid),
+    }));
+    assert_eq!(dialog.selected_task_project_uuid, Some(work.uuid));
+}
+
+#[test]
+fn test_task_creation_without_recent_projects_keeps_inbox_default() {
+    let mut dialog = DialogComponent::new();
+    dialog.update_data(vec![make_inbox(), make_project("Work")], Vec::new());
+
+    dialog.update(Action::ShowDialog(DialogType::TaskCreation {
+        default_project_uuid: None,
+    }));
+    assert!(matches!(
+        dialog.dialog_type,
+        Some(DialogType::TaskCreation {
+            default_project_uuid: None
+        })
+    ));
+}
+
+#[test]
+fn test_record_recent_project_moves_to_front_and_caps_length() {
+    let mut state = UiState::default();
+    let uuids: Vec<Uuid> = (0..RECENT_PROJECTS_LIMIT + 2).map(|_| Uuid::new_v4()).collect();
+    for uuid in &uuids {
+        state.record_recent_project(*uuid);
+    }
+    assert_eq!(state.recent_project_uuids.len(), RECENT_PROJECTS_LIMIT);
+    assert_eq!(state.recent_project_uuids[0], *uuids.last().unwrap());
+
+    // Reusing a project moves it to the front without duplicating it
+    let reused = state.recent_project_uuids[2];
+    state.record_recent_project(reused);
+    assert_eq!(state.recent_project_uuids[0], reused);
+    assert_eq!(state.recent_project_uuids.len(), RECENT_PROJECTS_LIMIT);
+    assert_eq!(state.recent_project_uuids.iter().filter(|u| **u == reused).count(), 1);
+}
+
+#[test]
+fn test_confirmation_dialog_runs_action_only_when_accepted() {
+    let task_uuid = Uuid::new_v4();
+    let confirmation = DialogType::Confirmation {
+        message: "Set due to Sat 2025-01-18?".to_string(),
+        action: Box::new(Action::SetTaskDueWeekEnd(task_uuid)),
+    };
+
+    let mut dialog = DialogComponent::new();
+    dialog.update(Action::ShowDialog(confirmation.clone()));
+    assert!(matches!(
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Char('y'))),
+        Action::SetTaskDueWeekEnd(uuid) if uuid == task_uuid
+    ));
+    assert!(dialog.dialog_type.is_none());
+
+    dialog.update(Action::ShowDialog(confirmation));
+    assert!(matches!(
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Esc)),
+        Action::HideDialog
+    ));
+}
+
+#[test]
+fn test_due_date_prompt_parses_input_and_clears_when_empty() {
+    let task_uuid = Uuid::new_v4();
+    let prompt = DialogType::SetDueDate {
+        task_uuid,
+        content: "Renew passport".to_string(),
+        due: Some("2025-05-02".to_string()),
+    };
+
+    let mut dialog = DialogComponent::new();
+    dialog.update(Action::ShowDialog(prompt.clone()));
+    assert_eq!(dialog.input_buffer, "2025-05-02");
+    for _ in 0..dialog.input_buffer.len() {
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Backspace));
+    }
+    for c in "2025-06-10".chars() {
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Char(c)));
+    }
+    assert!(matches!(
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Enter)),
+        Action::SetTaskDueDate { task_uuid: uuid, date: Some(date) } if uuid == task_uuid && date == "2025-06-10"
+    ));
+
+    // A time makes it a datetime
+    dialog.update(Action::ShowDialog(prompt.clone()));
+    dialog.input_buffer = "2025-06-10 14:30".to_string();
+    let expected = datetime::format_due(
+        NaiveDate::from_ymd_opt(2025, 6, 10).unwrap(),
+        NaiveTime::from_hms_opt(14, 30, 0),
+    );
+    assert!(matches!(
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Enter)),
+        Action::SetTaskDueDate { date: Some(date), .. } if date == expected
+    ));
+
+    // Text that isn't a date is reported, and the prompt stays open
+    dialog.update(Action::ShowDialog(prompt.clone()));
+    dialog.input_buffer = "someday maybe".to_string();
+    assert!(matches!(
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Enter)),
+        Action::ShowDialog(DialogType::Error(_))
+    ));
+
+    dialog.update(Action::ShowDialog(prompt));
+    dialog.input_buffer.clear();
+    dialog.cursor_position = 0;
+    assert!(matches!(
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Enter)),
+        Action::SetTaskDueDate { date: None, .. }
+    ));
+}
+
+#[test]
+fn test_deadline_prompt_parses_input_and_clears_when_empty() {
+    let task_uuid = Uuid::new_v4();
+    let prompt = DialogType::DeadlinePrompt {
+        task_uuid,
+        content: "File taxes".to_string(),
+        deadline: Some("2025-04-15".to_string()),
+    };
+
+    let mut dialog = DialogComponent::new();
+    dialog.update(Action::ShowDialog(prompt.clone()));
+    // The current deadline is prefilled so it can be edited or erased
+    assert_eq!(dialog.input_buffer, "2025-04-15");
+    for _ in 0..dialog.input_buffer.len() {
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Backspace));
+    }
+    for c in "by 2025-03-01".chars() {
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Char(c)));
+    }
+    assert!(matches!(
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Enter)),
+        Action::SetTaskDeadline { task_uuid: uuid, deadline: Some(date) } if uuid == task_uuid && date == "2025-03-01"
+    ));
+
+    dialog.update(Action::ShowDialog(prompt));
+    dialog.input_buffer.clear();
+    dialog.cursor_position = 0;
+    assert!(matches!(
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Enter)),
+        Action::SetTaskDeadline { deadline: None, .. }
+    ));
+}
+
+#[test]
+fn test_task_creation_cycles_sections_of_selected_project() {
+    let work = make_project("Work");
+    let home = make_project("Home");
+    let make_section = |name: &str, project: &project::Model, order_index: i32| section::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: Uuid::nil(),
+        remote_id: name.to_string(),
+        name: name.to_string(),
+        project_uuid: project.uuid,
+        order_index,
+    };
+    let later = make_section("Later", &work, 1);
+    let now = make_section("Now", &work, 0);
+    let garden = make_section("Garden", &home, 0);
+
+    let mut dialog = DialogComponent::new();
+    dialog.update_data(vec![work.clone(), home.clone()], Vec::new());
+    dialog.set_sections(vec![later.clone(), now.clone(), garden]);
+    dialog.update(Action::ShowDialog(DialogType::TaskCreation {
+        default_project_uuid: Some(work.uuid),
+    }));
+
+    // Ctrl+S walks the project's sections in order, then back to no section
+    let ctrl_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+    let names: Vec<&str> = dialog.task_dialog_sections().iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["Now", "Later"]);
+    dialog.handle_key_events(ctrl_s);
+    assert_eq!(dialog.selected_task_section_uuid, Some(now.uuid));
+    dialog.handle_key_events(ctrl_s);
+    assert_eq!(dialog.selected_task_section_uuid, Some(later.uuid));
+    dialog.handle_key_events(ctrl_s);
+    assert_eq!(dialog.selected_task_section_uuid, None);
+    dialog.handle_key_events(ctrl_s);
+    assert!(dialog.input_buffer.is_empty());
+
+    dialog.input_buffer = "Plan sprint".to_string();
+    let action = dialog.handle_key_events(KeyEvent::from(KeyCode::Enter));
+    assert!(matches!(
+        action,
+        Action::CreateTask { project_uuid: Some(project), section_uuid: Some(section), .. }
+            if project == work.uuid && section == now.uuid
+    ));
+
+    // Switching projects drops the section picked for the previous one
+    dialog.update(Action::ShowDialog(DialogType::TaskCreation {
+        default_project_uuid: Some(work.uuid),
+    }));
+    dialog.handle_key_events(ctrl_s);
+    dialog.handle_key_events(KeyEvent::from(KeyCode::Tab));
+    assert_eq!(dialog.selected_task_section_uuid, None);
+}
+
+#[test]
+fn test_task_edit_form_sends_only_changed_fields() {
+    let work = make_project("Work");
+    let task = task::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: Uuid::nil(),
+        remote_id: "1".to_string(),
+        content: "Write report".to_string(),
+        description: Some("Draft".to_string()),
+        project_uuid: work.uuid,
+        section_uuid: None,
+        parent_uuid: None,
+        priority: 1,
+        order_index: 0,
+        due_date: Some("2025-04-10".to_string()),
+        due_datetime: None,
+        due_string: None,
+        is_recurring: false,
+        deadline: None,
+        duration: None,
+        is_completed: false,
+        is_deleted: false,
+        postpone_count: 0,
+    };
+
+    let mut dialog = DialogComponent::new();
+    dialog.update_data(vec![work.clone()], Vec::new());
+    dialog.update(Action::ShowDialog(DialogType::TaskEdit {
+        task_uuid: task.uuid,
+        content: task.content.clone(),
+        project_uuid: work.uuid,
+        labels: vec!["home".to_string(), "errands".to_string()],
+        snapshot: Box::new(task.clone()),
+    }));
+    assert_eq!(dialog.input_buffer, "Write report");
+
+    // Tab moves through description and priority, keeping the text of each field
+    let tab = KeyEvent::from(KeyCode::Tab);
+    dialog.handle_key_events(tab);
+    assert_eq!(dialog.input_buffer, "Draft");
+    dialog.handle_key_events(tab);
+    assert_eq!(dialog.input_buffer, "p4");
+    dialog.handle_key_events(KeyEvent::from(KeyCode::Backspace));
+    dialog.handle_key_events(KeyEvent::from(KeyCode::Char('2')));
+
+    // Reformatting the same labels is not a change
+    dialog.handle_key_events(tab);
+    assert_eq!(dialog.input_buffer, "home, errands");
+    dialog.input_buffer = "home errands".to_string();
+    dialog.handle_key_events(KeyEvent::from(KeyCode::BackTab));
+    assert_eq!(dialog.input_buffer, "p2");
+
+    let action = dialog.handle_key_events(KeyEvent::from(KeyCode::Enter));
+    let Action::EditTask { task_uuid, changes, .. } = action else {
+        panic!("expected an edit, got {:?}", action);
+    };
+    assert_eq!(task_uuid, task.uuid);
+    assert_eq!(changes.priority, Some(3));
+    assert!(changes.content.is_none() && changes.description.is_none() && changes.labels.is_none());
+    assert!(changes.due_date.is_none() && changes.due_datetime.is_none());
+    assert!(changes.deadline.is_none());
+    assert!(!dialog.is_visible());
+
+    // The deadline and duration fields come last, after the due date
+    let mut with_deadline = task.clone();
+    with_deadline.deadline = Some("2025-04-30".to_string());
+    with_deadline.duration = Some("90 minute".to_string());
+    dialog.update(Action::ShowDialog(DialogType::TaskEdit {
+        task_uuid: task.uuid,
+        content: task.content.clone(),
+        project_uuid: work.uuid,
+        labels: Vec::new(),
+        snapshot: Box::new(with_deadline),
+    }));
+    dialog.handle_key_events(KeyEvent::from(KeyCode::BackTab));
+    assert_eq!(dialog.input_buffer, "1h30m");
+    dialog.input_buffer = "45 min".to_string();
+    dialog.handle_key_events(KeyEvent::from(KeyCode::BackTab));
+    assert_eq!(dialog.input_buffer, "2025-04-30");
+    dialog.input_buffer = "2025-05-02".to_string();
+    let Action::EditTask { changes, .. } = dialog.handle_key_events(KeyEvent::from(KeyCode::Enter)) else {
+        panic!("expected an edit");
+    };
+    assert_eq!(changes.deadline.as_deref(), Some("2025-05-02"));
+    assert_eq!(changes.duration.as_deref(), Some("45 minute"));
+    assert!(changes.due_date.is_none() && changes.priority.is_none());
+}
+
+#[test]
+fn test_task_labels_dialog_toggles_labels() {
+    let label = |name: &str, order_index: i32| label::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: Uuid::nil(),
+        remote_id: name.to_string(),
+        name: name.to_string(),
+        color: "blue".to_string(),
+        order_index,
+        is_favorite: false,
+    };
+    let task_uuid = Uuid::new_v4();
+
+    let mut dialog = DialogComponent::new();
+    dialog.update_data(Vec::new(), vec![label("home", 0), label("urgent", 1), label("work", 2)]);
+    dialog.update(Action::ShowDialog(DialogType::TaskLabels {
+        task_uuid,
+        content: "Write report".to_string(),
+        labels: vec!["work".to_string()],
+    }));
+
+    // Tick "home", untick "work", then tick "urgent"; moving stops at the last label
+    for c in [' ', 'j', 'j', ' ', 'j', 'k', ' '] {
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Char(c)));
+    }
+
+    match dialog.handle_key_events(KeyEvent::from(KeyCode::Enter)) {
+        Action::SetTaskLabels {
+            task_uuid: uuid,
+            labels,
+        } => {
+            assert_eq!(uuid, task_uuid);
+            assert_eq!(labels, vec!["home".to_string(), "urgent".to_string()]);
+        }
+        other => panic!("expected SetTaskLabels, got {:?}", other),
+    }
+    assert!(!dialog.is_visible());
+}
+
+#[test]
+fn test_mouse_wheel_scrolls_help_but_not_forms() {
+    let wheel = |kind| MouseEvent {
+        kind,
+        column: 10,
+        row: 10,
+        modifiers: KeyModifiers::NONE,
+    };
+    let mut dialog = DialogComponent::new();
+
+    dialog.update(Action::ShowDialog(DialogType::Help));
+    dialog.handle_mouse(wheel(MouseEventKind::ScrollDown));
+    dialog.handle_mouse(wheel(MouseEventKind::ScrollDown));
+    dialog.handle_mouse(wheel(MouseEventKind::ScrollUp));
+    assert_eq!(dialog.scroll_offset, 1);
+
+    dialog.update(Action::HideDialog);
+    dialog.update(Action::ShowDialog(DialogType::TaskCreation {
+        default_project_uuid: None,
+    }));
+    dialog.handle_mouse(wheel(MouseEventKind::ScrollDown));
+    assert_eq!(dialog.scroll_offset, 0);
+}
+
+#[test]
+fn test_deleting_a_duplicate_returns_to_the_duplicates_list() {
+    let project_uuid = Uuid::new_v4();
+    let original = make_task("Buy milk", project_uuid);
+    let duplicate = make_task("buy milk", project_uuid);
+    let group = DuplicateGroup {
+        project_uuid,
+        normalized_content: "buy milk".to_string(),
+        tasks: vec![original, duplicate.clone()],
+    };
+
+    let mut dialog = DialogComponent::new();
+    dialog.update(Action::ShowDialog(DialogType::DuplicateTasks));
+    dialog.update_duplicate_groups(vec![group]);
+    dialog.handle_key_events(KeyEvent::from(KeyCode::Down));
+
+    // Cancelling the confirmation goes back to the unchanged list
+    let confirm = dialog.handle_key_events(KeyEvent::from(KeyCode::Char('d')));
+    dialog.update(confirm);
+    assert!(matches!(
+        dialog.dialog_type,
+        Some(DialogType::DeleteConfirmation { .. })
+    ));
+    assert!(matches!(
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Esc)),
+        Action::None
+    ));
+    assert!(matches!(dialog.dialog_type, Some(DialogType::DuplicateTasks)));
+    assert_eq!(dialog.duplicate_groups.len(), 1);
+
+    // Confirming deletes the task and drops the group it no longer duplicates
+    let confirm = dialog.handle_key_events(KeyEvent::from(KeyCode::Char('d')));
+    dialog.update(confirm);
+    assert!(matches!(
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Enter)),
+        Action::DeleteTask(uuid) if uuid == duplicate.uuid.to_string()
+    ));
+    assert!(matches!(dialog.dialog_type, Some(DialogType::DuplicateTasks)));
+    assert!(dialog.duplicate_groups.is_empty());
+    assert_eq!(dialog.selected_duplicate_index, 0);
+}
+
+#[test]
+fn test_nest_tasks_picker_skips_marked_and_closed_tasks() {
+    let home = make_project("Home");
+    let parent = make_task("Move house", home.uuid);
+    let marked = make_task("Book van", home.uuid);
+    let mut done = make_task("Sell sofa", home.uuid);
+    done.is_completed = true;
+    let other = make_task("Pack boxes", home.uuid);
+
+    let mut dialog = DialogComponent::new();
+    dialog.update_data_with_tasks(
+        vec![home],
+        Vec::new(),
+        vec![parent.clone(), marked.clone(), done, other.clone()],
+    );
+    dialog.update(Action::ShowDialog(DialogType::NestTasks {
+        task_uuids: vec![marked.uuid],
+    }));
+
+    // Only "Move house" and "Pack boxes" are offered, so the cursor stops on the second
+    dialog.handle_key_events(KeyEvent::from(KeyCode::Char('j')));
+    dialog.handle_key_events(KeyEvent::from(KeyCode::Char('j')));
+    assert!(matches!(
+        dialog.handle_key_events(KeyEvent::from(KeyCode::Enter)),
+        Action::BulkNest { tasks, parent_uuid } if tasks == vec![marked.uuid] && parent_uuid == other.uuid
+    ));
+    assert!(dialog.dialog_type.is_none());
+}
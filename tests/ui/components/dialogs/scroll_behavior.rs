@@ -3,3 +3,33 @@ fn test_scroll_behavior_module_exists() {
     // Test that the scroll behavior module compiles and is accessible
     // If this test runs, the module is accessible
 }
+
+#[test]
+fn test_wrapped_line_count_for_known_width() {
+    use terminalist::ui::components::dialogs::scroll_behavior::wrapped_line_count;
+
+    // 25 characters in a 10 column view wrap to 3 rows, plus one short line
+    let text = "aaaa bbbb cccc dddd eeeee\nshort";
+    assert_eq!(wrapped_line_count(text, 10, true), 4);
+
+    // Without wrapping every logical line is a single row
+    assert_eq!(wrapped_line_count(text, 10, false), 2);
+}
+
+#[test]
+fn test_scroll_to_bottom_clamps_to_last_row() {
+    use ratatui::widgets::ScrollbarState;
+    use terminalist::ui::components::dialogs::scroll_behavior::{clamp_to_content, scroll_to_bottom, scroll_up};
+
+    let mut offset = 0;
+    let mut state = ScrollbarState::new(0);
+    scroll_to_bottom(&mut offset, &mut state);
+
+    // 12 wrapped rows in a 5 row viewport: the last page starts at row 7
+    clamp_to_content(&mut offset, &mut state, 12, 5);
+    assert_eq!(offset, 7);
+
+    // Scrolling up from the bottom moves right away
+    scroll_up(&mut offset, &mut state);
+    assert_eq!(offset, 6);
+}
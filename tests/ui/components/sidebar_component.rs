@@ -1,7 +1,249 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use terminalist::entities::{label, project};
+use terminalist::ui::components::sidebar_component::special_view_selection;
 use terminalist::ui::components::SidebarComponent;
+use terminalist::ui::core::{Action, Component, SidebarSection, SidebarSelection};
+use terminalist::ui_state::UiState;
+use uuid::Uuid;
 
 #[test]
 fn test_sidebar_component_creation() {
     // Test that SidebarComponent can be created without panicking
     let _sidebar = SidebarComponent::new();
 }
+
+fn sidebar_with_sections() -> SidebarComponent {
+    let project = project::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: Uuid::nil(),
+        remote_id: "p1".to_string(),
+        name: "Work".to_string(),
+        color: "charcoal".to_string(),
+        is_favorite: false,
+        is_inbox_project: false,
+        order_index: 0,
+        parent_uuid: None,
+    };
+    let label = label::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: Uuid::nil(),
+        remote_id: "l1".to_string(),
+        name: "urgent".to_string(),
+        color: "red".to_string(),
+        order_index: 0,
+        is_favorite: false,
+    };
+
+    let mut sidebar = SidebarComponent::new();
+    sidebar.set_group_sections(true);
+    sidebar.update_data(vec![project], vec![label]);
+    sidebar
+}
+
+fn shift_key(c: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(c), KeyModifiers::SHIFT)
+}
+
+#[test]
+fn test_navigation_skips_collapsed_section() {
+    let mut sidebar = sidebar_with_sections();
+    sidebar.update(Action::NavigateToSidebar(SidebarSelection::Upcoming));
+
+    // Labels follow the views while expanded
+    assert!(matches!(
+        sidebar.handle_key_events(shift_key('J')),
+        Action::NavigateToSidebar(SidebarSelection::Label(0))
+    ));
+
+    sidebar.update(Action::NavigateToSidebar(SidebarSelection::Upcoming));
+    sidebar.set_section_collapsed(SidebarSection::Labels, true);
+    assert!(matches!(
+        sidebar.handle_key_events(shift_key('J')),
+        Action::NavigateToSidebar(SidebarSelection::Project(0))
+    ));
+}
+
+#[test]
+fn test_collapse_keys_target_selection_section() {
+    let mut sidebar = sidebar_with_sections();
+    sidebar.update(Action::NavigateToSidebar(SidebarSelection::Label(0)));
+
+    let action = sidebar.handle_key_events(shift_key('H'));
+    assert!(matches!(
+        action,
+        Action::SetSidebarSectionCollapsed {
+            section: SidebarSection::Labels,
+            collapsed: true
+        }
+    ));
+    sidebar.update(action);
+    assert!(sidebar.is_section_collapsed(SidebarSection::Labels));
+
+    let action = sidebar.handle_key_events(shift_key('L'));
+    assert!(matches!(
+        action,
+        Action::SetSidebarSectionCollapsed {
+            section: SidebarSection::Labels,
+            collapsed: false
+        }
+    ));
+}
+
+#[test]
+fn test_collapsed_sections_round_trip_through_ui_state() {
+    let path = std::env::temp_dir().join("terminalist_test_ui_state").join("ui_state.toml");
+    let _ = std::fs::remove_file(&path);
+
+    // A missing file is not an error
+    assert_eq!(UiState::load_from_file(&path).unwrap(), UiState::default());
+
+    let state = UiState {
+        collapsed_sidebar_sections: vec![SidebarSection::Labels.key().to_string()],
+        ..Default::default()
+    };
+    state.save_to_file(&path).unwrap();
+
+    let loaded = UiState::load_from_file(&path).unwrap();
+    assert_eq!(loaded, state);
+    assert_eq!(
+        SidebarSection::from_key(&loaded.collapsed_sidebar_sections[0]),
+        Some(SidebarSection::Labels)
+    );
+
+    let _ = std::fs::remove_dir_all(path.parent().unwrap());
+}
+
+#[test]
+fn test_layout_state_round_trip_and_corrupt_file() {
+    let path = std::env::temp_dir().join("terminalist_test_layout_state").join("ui_state.toml");
+    let _ = std::fs::remove_file(&path);
+
+    let state = UiState {
+        sidebar_visible: Some(false),
+        sidebar_width: Some(42),
+        last_view: Some("upcoming".to_string()),
+        ..Default::default()
+    };
+    state.save_to_file(&path).unwrap();
+    assert_eq!(UiState::load_from_file(&path).unwrap(), state);
+
+    // A file written before the layout fields existed still loads
+    std::fs::write(&path, "collapsed_sidebar_sections = [\"labels\"]\n").unwrap();
+    let loaded = UiState::load_from_file(&path).unwrap();
+    assert_eq!(loaded.sidebar_visible, None);
+    assert_eq!(loaded.sidebar_width, None);
+    assert_eq!(loaded.last_view, None);
+
+    // A corrupt file is reported so `UiState::load` can fall back to defaults
+    std::fs::write(&path, "sidebar_visible = [not toml").unwrap();
+    assert!(UiState::load_from_file(&path).is_err());
+
+    let _ = std::fs::remove_dir_all(path.parent().unwrap());
+}
+
+#[test]
+fn test_someday_view_is_backed_by_label() {
+    let sidebar = sidebar_with_sections();
+    let mut labels = sidebar.labels.clone();
+
+    // No label named "someday" yet: the view is hidden
+    assert!(special_view_selection("someday", &sidebar.projects, &labels, "someday").is_none());
+
+    labels[0].name = "Someday".to_string();
+    assert!(matches!(
+        special_view_selection("someday", &sidebar.projects, &labels, "someday"),
+        Some(("Someday", SidebarSelection::Label(0)))
+    ));
+
+    // An empty label name disables the view
+    assert!(special_view_selection("someday", &sidebar.projects, &labels, "").is_none());
+}
+
+#[test]
+fn test_jump_to_project_by_typing_its_name() {
+    let project = |name: &str| project::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: Uuid::nil(),
+        remote_id: name.to_lowercase(),
+        name: name.to_string(),
+        color: "charcoal".to_string(),
+        is_favorite: false,
+        is_inbox_project: false,
+        order_index: 0,
+        parent_uuid: None,
+    };
+    let mut sidebar = SidebarComponent::new();
+    sidebar.update_data(
+        vec![project("Groceries"), project("Work"), project("Workshop")],
+        Vec::new(),
+    );
+
+    sidebar.start_jump();
+    assert!(sidebar.is_jumping());
+    for c in "wor".chars() {
+        assert!(matches!(
+            sidebar.handle_jump_key(KeyEvent::from(KeyCode::Char(c))),
+            Action::None
+        ));
+    }
+    assert_eq!(sidebar.jump_matches(), vec![1, 2]);
+
+    // Down picks the next match, wrapping around
+    sidebar.handle_jump_key(KeyEvent::from(KeyCode::Down));
+    assert!(matches!(
+        sidebar.handle_jump_key(KeyEvent::from(KeyCode::Enter)),
+        Action::NavigateToSidebar(SidebarSelection::Project(2))
+    ));
+    assert!(!sidebar.is_jumping());
+
+    // Esc leaves without navigating, and no match means nothing to open
+    sidebar.start_jump();
+    sidebar.handle_jump_key(KeyEvent::from(KeyCode::Char('x')));
+    assert!(sidebar.jump_matches().is_empty());
+    assert!(matches!(
+        sidebar.handle_jump_key(KeyEvent::from(KeyCode::Enter)),
+        Action::None
+    ));
+    sidebar.start_jump();
+    assert!(matches!(
+        sidebar.handle_jump_key(KeyEvent::from(KeyCode::Esc)),
+        Action::None
+    ));
+    assert!(!sidebar.is_jumping());
+}
+
+#[test]
+fn test_favorites_are_listed_at_the_top() {
+    let mut sidebar = sidebar_with_sections();
+    let mut projects = sidebar.projects.clone();
+    projects[0].is_favorite = true;
+    let labels = sidebar.labels.clone();
+    sidebar.update_data(projects, labels);
+
+    // The favorite project is the row just before the views
+    sidebar.update(Action::NavigateToSidebar(SidebarSelection::Today));
+    assert!(matches!(
+        sidebar.handle_key_events(shift_key('K')),
+        Action::NavigateToSidebar(SidebarSelection::Project(0))
+    ));
+
+    // Its row among the projects keeps its place, so navigation goes on from there
+    sidebar.update(Action::NavigateToSidebar(SidebarSelection::Label(0)));
+    let action = sidebar.handle_key_events(shift_key('J'));
+    assert!(matches!(
+        action,
+        Action::NavigateToSidebar(SidebarSelection::Project(0))
+    ));
+    sidebar.update(action);
+    assert!(matches!(
+        sidebar.handle_key_events(shift_key('H')),
+        Action::SetSidebarSectionCollapsed {
+            section: SidebarSection::Projects,
+            collapsed: true
+        }
+    ));
+    assert!(matches!(
+        sidebar.handle_key_events(shift_key('K')),
+        Action::NavigateToSidebar(SidebarSelection::Label(0))
+    ));
+}
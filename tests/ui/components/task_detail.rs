@@ -0,0 +1,115 @@
+use crate::fixtures::{make_project, make_subproject, make_task};
+use chrono::{Local, TimeZone};
+use ratatui::text::Line;
+use terminalist::config::DisplayConfig;
+use terminalist::entities::{label, section};
+use terminalist::ui::components::task_detail::TaskDetail;
+use terminalist::ui::core::theme::Theme;
+use terminalist::utils::color;
+use terminalist::utils::datetime::freeze_time;
+use uuid::Uuid;
+
+fn text(line: &Line) -> String {
+    line.spans.iter().map(|span| span.content.as_ref()).collect()
+}
+
+fn display() -> DisplayConfig {
+    DisplayConfig {
+        date_format: "%Y-%m-%d".to_string(),
+        ..DisplayConfig::default()
+    }
+}
+
+#[test]
+fn test_detail_lists_every_field_that_is_set() {
+    let noon = chrono::NaiveDate::from_ymd_opt(2025, 3, 14)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+    let _frozen = freeze_time(Local.from_local_datetime(&noon).single().unwrap());
+
+    let work = make_project("Work");
+    let reports = make_subproject("Reports", work.uuid);
+    let section = section::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: Uuid::nil(),
+        remote_id: "s1".to_string(),
+        name: "Weekly".to_string(),
+        project_uuid: reports.uuid,
+        order_index: 0,
+    };
+    let urgent = label::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: Uuid::nil(),
+        remote_id: "l1".to_string(),
+        name: "urgent".to_string(),
+        color: "red".to_string(),
+        order_index: 0,
+        is_favorite: false,
+    };
+    let mut task = make_task("Write report", reports.uuid);
+    task.section_uuid = Some(section.uuid);
+    task.description = Some("First line\nSecond line".to_string());
+    task.due_date = Some("2025-03-15".to_string());
+    task.due_string = Some("every saturday".to_string());
+    task.is_recurring = true;
+    task.deadline = Some("2025-03-10".to_string());
+    task.duration = Some("30m".to_string());
+    task.priority = 4;
+
+    let projects = vec![work, reports];
+    let sections = vec![section];
+    let labels = vec![urgent];
+    let label_names = vec!["urgent".to_string(), "unknown".to_string()];
+    let theme = Theme::default();
+    let lines = TaskDetail {
+        task: &task,
+        projects: &projects,
+        sections: &sections,
+        labels: &labels,
+        label_names: &label_names,
+    }
+    .lines(&theme, &display());
+    let texts: Vec<String> = lines.iter().map(text).collect();
+
+    assert_eq!(texts[0], "Write report");
+    assert!(texts.contains(&"Project   Work › Reports".to_string()));
+    assert!(texts.contains(&"Section   Weekly".to_string()));
+    assert!(texts
+        .iter()
+        .any(|line| line.starts_with("Due") && line.ends_with("(2025-03-15)")));
+    assert!(texts.contains(&"Repeats   every saturday".to_string()));
+    assert!(texts.contains(&"Deadline  2025-03-10".to_string()));
+    assert!(texts.contains(&"Duration  30m".to_string()));
+    assert!(texts.contains(&"Priority  p1".to_string()));
+    assert!(texts.contains(&"Labels    @urgent @unknown".to_string()));
+    assert_eq!(texts[texts.len() - 2..], ["First line", "Second line"]);
+
+    // Missed deadline in the overdue color, labels in their own color
+    let deadline = lines.iter().find(|line| text(line).starts_with("Deadline")).unwrap();
+    assert_eq!(deadline.spans[1].style.fg, Some(theme.overdue));
+    let labels_line = lines.iter().find(|line| text(line).starts_with("Labels")).unwrap();
+    assert_eq!(labels_line.spans[1].style.fg, Some(color::to_terminal_color("red")));
+    assert_eq!(labels_line.spans[3].style.fg, Some(theme.text));
+}
+
+#[test]
+fn test_detail_of_bare_task_says_there_is_no_description() {
+    let project = make_project("Inbox");
+    let task = make_task("Write report", project.uuid);
+    let projects = vec![project];
+    let lines = TaskDetail {
+        task: &task,
+        projects: &projects,
+        sections: &[],
+        labels: &[],
+        label_names: &[],
+    }
+    .lines(&Theme::default(), &display());
+    let texts: Vec<String> = lines.iter().map(text).collect();
+
+    assert_eq!(
+        texts,
+        ["Write report", "", "Project   Inbox", "Priority  p4", "", "No description"]
+    );
+}
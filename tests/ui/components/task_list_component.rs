@@ -1,7 +1,646 @@
+use crate::fixtures::{make_label, make_project, make_task};
+use chrono::{Local, TimeZone};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use terminalist::config::{SelectionAfterRemoval, TaskSort, ViewsConfig};
+use terminalist::entities::{project, section, task};
+use terminalist::ui::components::task_list_component::{upcoming_zoom, TaskGrouping, TASK_JUMP_TIMEOUT};
+use terminalist::ui::components::task_list_item_component::TaskListItemType;
 use terminalist::ui::components::TaskListComponent;
+use terminalist::ui::core::{Action, Component, SidebarSelection};
+use terminalist::utils::datetime::freeze_time;
+use uuid::Uuid;
+
+/// A task at `order_index` in its project, for tests that depend on the manual order
+fn ordered_task(content: &str, project_uuid: Uuid, order_index: i32) -> task::Model {
+    task::Model {
+        order_index,
+        ..make_task(content, project_uuid)
+    }
+}
 
 #[test]
 fn test_task_list_component_creation() {
     // Test that TaskListComponent can be created without panicking
     let _task_list = TaskListComponent::new();
 }
+
+fn load(task_list: &mut TaskListComponent, project: &project::Model, tasks: Vec<task::Model>) {
+    task_list.update_data(
+        tasks,
+        Vec::new(),
+        vec![project.clone()],
+        Vec::new(),
+        SidebarSelection::Project(0),
+    );
+}
+
+#[test]
+fn test_selection_follows_task_across_reloads() {
+    let project = make_project("Work");
+    let a = ordered_task("a", project.uuid, 0);
+    let b = ordered_task("b", project.uuid, 1);
+    let c = ordered_task("c", project.uuid, 2);
+
+    let mut task_list = TaskListComponent::new();
+    load(&mut task_list, &project, vec![a.clone(), b.clone(), c.clone()]);
+    task_list.selected_index = 2;
+
+    // Removing a task above the selection keeps the same task selected
+    load(&mut task_list, &project, vec![a.clone(), c.clone()]);
+    assert_eq!(task_list.get_selected_task().map(|t| t.uuid), Some(c.uuid));
+
+    // Removing the selected task selects the nearest remaining one
+    load(&mut task_list, &project, vec![a.clone()]);
+    assert_eq!(task_list.get_selected_task().map(|t| t.uuid), Some(a.uuid));
+}
+
+#[test]
+fn test_mouse_wheel_moves_selection_without_wrapping() {
+    let project = make_project("Work");
+    let a = ordered_task("a", project.uuid, 0);
+    let b = ordered_task("b", project.uuid, 1);
+    let mut task_list = TaskListComponent::new();
+    load(&mut task_list, &project, vec![a.clone(), b.clone()]);
+    let area = Rect::new(30, 0, 70, 20);
+    let wheel = |kind, column| MouseEvent {
+        kind,
+        column,
+        row: 5,
+        modifiers: KeyModifiers::NONE,
+    };
+
+    task_list.handle_mouse(wheel(MouseEventKind::ScrollUp, 40), area);
+    assert_eq!(task_list.get_selected_task().map(|t| t.uuid), Some(a.uuid));
+    task_list.handle_mouse(wheel(MouseEventKind::ScrollDown, 40), area);
+    task_list.handle_mouse(wheel(MouseEventKind::ScrollDown, 40), area);
+    assert_eq!(task_list.get_selected_task().map(|t| t.uuid), Some(b.uuid));
+
+    // Outside the task list (over the sidebar) the wheel does nothing
+    task_list.handle_mouse(wheel(MouseEventKind::ScrollUp, 10), area);
+    assert_eq!(task_list.get_selected_task().map(|t| t.uuid), Some(b.uuid));
+}
+
+#[test]
+fn test_selection_moves_to_top_when_configured() {
+    let project = make_project("Work");
+    let a = ordered_task("a", project.uuid, 0);
+    let b = ordered_task("b", project.uuid, 1);
+    let c = ordered_task("c", project.uuid, 2);
+
+    let mut task_list = TaskListComponent::new();
+    task_list.set_selection_after_removal(SelectionAfterRemoval::Top);
+    load(&mut task_list, &project, vec![a.clone(), b.clone(), c.clone()]);
+    task_list.selected_index = 1;
+
+    load(&mut task_list, &project, vec![a.clone(), c.clone()]);
+    assert_eq!(task_list.get_selected_task().map(|t| t.uuid), Some(a.uuid));
+}
+
+#[test]
+fn test_rescheduled_task_stays_visible_until_highlight_expires() {
+    let project = make_project("Work");
+    // Frozen just before midnight, where "tomorrow" is easiest to get wrong
+    let midnight = chrono::NaiveDate::from_ymd_opt(2025, 3, 14)
+        .unwrap()
+        .and_hms_opt(23, 59, 0)
+        .unwrap();
+    let _frozen = freeze_time(Local.from_local_datetime(&midnight).single().unwrap());
+    let tomorrow = "2025-03-15".to_string();
+    let mut recurring = ordered_task("water plants", project.uuid, 0);
+    recurring.is_recurring = true;
+    recurring.due_date = Some(tomorrow);
+
+    let mut task_list = TaskListComponent::new();
+    task_list.update_data(
+        vec![recurring.clone()],
+        Vec::new(),
+        vec![project.clone()],
+        Vec::new(),
+        SidebarSelection::Today,
+    );
+    assert!(task_list.get_selected_task().is_none());
+
+    // While highlighted, the task moved to tomorrow is still shown in Today
+    let start = Instant::now();
+    task_list.highlight_rescheduled(recurring.uuid, start + Duration::from_millis(500));
+    assert_eq!(task_list.get_selected_task().map(|t| t.uuid), Some(recurring.uuid));
+    assert!(task_list.has_rescheduled_highlights());
+
+    assert!(!task_list.clear_expired_highlights(start));
+    assert!(task_list.clear_expired_highlights(start + Duration::from_secs(1)));
+    assert!(!task_list.has_rescheduled_highlights());
+    assert!(task_list.get_selected_task().is_none());
+}
+
+#[test]
+fn test_typed_numbers_jump_to_tasks() {
+    let project = make_project("Work");
+    let tasks: Vec<task::Model> = (0..12)
+        .map(|i| ordered_task(&format!("task {}", i + 1), project.uuid, i))
+        .collect();
+
+    let mut task_list = TaskListComponent::new();
+    load(&mut task_list, &project, tasks);
+    let start = Instant::now();
+    let step = Duration::from_millis(100);
+
+    // "1" selects task 1 right away, and "2" shortly after extends it to task 12
+    task_list.jump_to_task_digit('1', start);
+    assert_eq!(task_list.selected_index, 0);
+    task_list.jump_to_task_digit('2', start + step);
+    assert_eq!(task_list.selected_index, 11);
+
+    // After a pause a digit starts a new number
+    task_list.jump_to_task_digit('3', start + step + TASK_JUMP_TIMEOUT * 2);
+    assert_eq!(task_list.selected_index, 2);
+
+    // A digit that would point past the last task starts over with that digit
+    let later = start + TASK_JUMP_TIMEOUT * 4;
+    task_list.jump_to_task_digit('1', later);
+    task_list.jump_to_task_digit('5', later + step);
+    assert_eq!(task_list.selected_index, 4);
+}
+
+#[test]
+fn test_dismissed_completed_tasks_reset_on_view_change() {
+    let project = make_project("Work");
+    let open = ordered_task("open", project.uuid, 0);
+    let mut done = ordered_task("done", project.uuid, 1);
+    done.is_completed = true;
+
+    let mut task_list = TaskListComponent::new();
+    load(&mut task_list, &project, vec![open.clone(), done.clone()]);
+    assert_eq!(task_list.dismiss_completed(), 1);
+    assert_eq!(task_list.items.len(), 1);
+
+    // Reloading the same view keeps them hidden
+    load(&mut task_list, &project, vec![open.clone(), done.clone()]);
+    assert_eq!(task_list.items.len(), 1);
+
+    // Switching views brings them back
+    task_list.update_data(
+        vec![open.clone(), done.clone()],
+        Vec::new(),
+        vec![project.clone()],
+        Vec::new(),
+        SidebarSelection::Today,
+    );
+    load(&mut task_list, &project, vec![open, done]);
+    assert_eq!(task_list.items.len(), 2);
+}
+
+#[test]
+fn test_marked_tasks_drive_bulk_actions() {
+    let project = make_project("Work");
+    let first = ordered_task("first", project.uuid, 0);
+    let second = ordered_task("second", project.uuid, 1);
+    let mut done = ordered_task("done", project.uuid, 2);
+    done.is_completed = true;
+
+    let mut task_list = TaskListComponent::new();
+    load(
+        &mut task_list,
+        &project,
+        vec![first.clone(), second.clone(), done.clone()],
+    );
+
+    // Without marks Space still completes the selected task
+    let action = task_list.handle_key_events(KeyEvent::from(KeyCode::Char(' ')));
+    assert!(matches!(action, Action::CompleteTask(uuid) if uuid == first.uuid.to_string()));
+
+    let action = task_list.handle_key_events(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL));
+    task_list.update(action);
+    // Completed tasks are not marked
+    assert_eq!(task_list.marked_task_uuids(), vec![first.uuid, second.uuid]);
+    assert!(task_list.title(80).starts_with("Tasks: 2 marked"));
+
+    task_list.update(Action::ToggleTaskMark);
+    assert_eq!(task_list.marked_task_uuids(), vec![second.uuid]);
+    let marked_rows = task_list
+        .items
+        .iter()
+        .filter(|item| matches!(item, TaskListItemType::Task(task_item) if task_item.marked))
+        .count();
+    assert_eq!(marked_rows, 1);
+
+    let action = task_list.handle_key_events(KeyEvent::from(KeyCode::Char(' ')));
+    assert!(matches!(&action, Action::BulkComplete(uuids) if *uuids == vec![second.uuid]));
+    // Running the bulk action uses up the marks
+    task_list.update(action);
+    assert!(!task_list.has_marks());
+
+    // Esc clears marks instead of falling through to quit
+    task_list.update(Action::ToggleTaskMark);
+    let action = task_list.handle_key_events(KeyEvent::from(KeyCode::Esc));
+    assert!(matches!(action, Action::ClearTaskMarks));
+    task_list.update(action);
+    assert!(!task_list.has_marks());
+    assert!(matches!(
+        task_list.handle_key_events(KeyEvent::from(KeyCode::Esc)),
+        Action::None
+    ));
+
+    // Marks don't survive a view change
+    task_list.update(Action::MarkAllTasks);
+    task_list.update_data(
+        vec![first, second, done],
+        Vec::new(),
+        vec![project],
+        Vec::new(),
+        SidebarSelection::Today,
+    );
+    assert!(!task_list.has_marks());
+}
+
+#[test]
+fn test_title_shows_breadcrumb_for_nested_project() {
+    let parent = make_project("Work");
+    let mut child = make_project("Work");
+    child.name = "Backend".to_string();
+    child.parent_uuid = Some(parent.uuid);
+
+    let mut task_list = TaskListComponent::new();
+    let projects = vec![parent, child];
+    task_list.update_data(
+        Vec::new(),
+        Vec::new(),
+        projects.clone(),
+        Vec::new(),
+        SidebarSelection::Project(0),
+    );
+    assert_eq!(task_list.title(80), "Tasks");
+
+    task_list.update_data(
+        Vec::new(),
+        Vec::new(),
+        projects,
+        Vec::new(),
+        SidebarSelection::Project(1),
+    );
+    assert_eq!(task_list.title(80), "Tasks: Work › Backend");
+}
+
+#[test]
+fn test_today_min_priority_hides_low_priority_until_toggled() {
+    let noon = chrono::NaiveDate::from_ymd_opt(2025, 3, 14)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+    let _frozen = freeze_time(Local.from_local_datetime(&noon).single().unwrap());
+
+    let project = make_project("Work");
+    let mut urgent = ordered_task("urgent", project.uuid, 0);
+    urgent.priority = 4; // P1
+    urgent.due_date = Some("2025-03-14".to_string());
+    let mut overdue = ordered_task("overdue", project.uuid, 1);
+    overdue.priority = 3; // P2
+    overdue.due_date = Some("2025-03-10".to_string());
+    let mut minor = ordered_task("minor", project.uuid, 2);
+    minor.priority = 2; // P3
+    minor.due_date = Some("2025-03-14".to_string());
+
+    let mut task_list = TaskListComponent::new();
+    task_list.update_views_config(ViewsConfig {
+        today_min_priority: 2,
+        ..Default::default()
+    });
+    task_list.update_data(
+        vec![overdue, urgent, minor],
+        Vec::new(),
+        vec![project],
+        Vec::new(),
+        SidebarSelection::Today,
+    );
+    let shown = |task_list: &TaskListComponent| -> Vec<String> {
+        task_list
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                TaskListItemType::Task(task) => Some(task.task.content.clone()),
+                _ => None,
+            })
+            .collect()
+    };
+
+    assert!(task_list.is_priority_filter_active());
+    assert_eq!(shown(&task_list), vec!["overdue", "urgent"]);
+    assert_eq!(task_list.title(80), "Tasks: P1-P2 only (P: show all)");
+
+    assert!(task_list.toggle_show_all_priorities());
+    assert_eq!(shown(&task_list), vec!["overdue", "urgent", "minor"]);
+
+    assert!(!task_list.toggle_show_all_priorities());
+    assert_eq!(shown(&task_list), vec!["overdue", "urgent"]);
+}
+
+#[test]
+fn test_upcoming_zoom_steps_and_title() {
+    assert_eq!(upcoming_zoom(90, true), 365);
+    assert_eq!(upcoming_zoom(90, false), 30);
+    assert_eq!(upcoming_zoom(365, true), 365);
+    assert_eq!(upcoming_zoom(7, false), 7);
+    // A configured horizon between steps moves to the neighbouring step
+    assert_eq!(upcoming_zoom(60, true), 90);
+    assert_eq!(upcoming_zoom(60, false), 30);
+
+    let mut task_list = TaskListComponent::new();
+    task_list.update_data(
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        SidebarSelection::Upcoming,
+    );
+    assert_eq!(task_list.title(80), "Tasks: Upcoming · next 90 days (+/-: zoom)");
+
+    let key = |c| KeyEvent::from(KeyCode::Char(c));
+    assert!(matches!(
+        task_list.handle_key_events(key('-')),
+        Action::SetUpcomingDays(30)
+    ));
+
+    task_list.set_upcoming_days(365);
+    assert_eq!(task_list.title(80), "Tasks: Upcoming · next 365 days (+/-: zoom)");
+    assert!(matches!(task_list.handle_key_events(key('+')), Action::None));
+}
+
+#[test]
+fn test_only_aggregate_views_mark_task_projects() {
+    let project = make_project("Work");
+    let task = ordered_task("a", project.uuid, 0);
+    let aggregate_flags = |task_list: &TaskListComponent| -> Vec<bool> {
+        task_list
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                TaskListItemType::Task(task) => Some(task.in_aggregate_view),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let mut task_list = TaskListComponent::new();
+    load(&mut task_list, &project, vec![task.clone()]);
+    assert_eq!(aggregate_flags(&task_list), vec![false]);
+
+    task_list.update_data(
+        vec![task],
+        Vec::new(),
+        vec![project],
+        Vec::new(),
+        SidebarSelection::Postponed,
+    );
+    assert_eq!(aggregate_flags(&task_list), vec![true]);
+}
+
+#[test]
+fn test_all_view_groups_projects_by_backend() {
+    let personal = Uuid::new_v4();
+    let work = Uuid::new_v4();
+    let home = project::Model {
+        backend_uuid: personal,
+        name: "Home".to_string(),
+        ..make_project("Work")
+    };
+    let office = project::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: work,
+        name: "Office".to_string(),
+        ..make_project("Work")
+    };
+    let tasks = vec![ordered_task("report", office.uuid, 0), ordered_task("dishes", home.uuid, 0)];
+    let rows = |task_list: &TaskListComponent| -> Vec<String> {
+        task_list
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                TaskListItemType::Task(task) => Some(task.task.content.clone()),
+                TaskListItemType::Header(header) => Some(format!("# {}", header.text)),
+                _ => None,
+            })
+            .collect()
+    };
+
+    // Projects of the backend listed first come first, with the backend in their header
+    let mut task_list = TaskListComponent::new();
+    task_list.set_accounts(vec![(work, "Work".to_string()), (personal, "Personal".to_string())]);
+    task_list.update_data(
+        tasks.clone(),
+        Vec::new(),
+        vec![home.clone(), office.clone()],
+        Vec::new(),
+        SidebarSelection::All,
+    );
+    assert_eq!(
+        rows(&task_list),
+        vec!["# Work › Office", "report", "# Personal › Home", "dishes"]
+    );
+
+    // With a single backend, headers only name the project
+    task_list.set_accounts(vec![(personal, "Personal".to_string())]);
+    task_list.update_data(tasks, Vec::new(), vec![home, office], Vec::new(), SidebarSelection::All);
+    assert_eq!(rows(&task_list), vec!["# Home", "dishes", "# Office", "report"]);
+}
+
+#[test]
+fn test_sort_modes_reorder_tasks_within_sections() {
+    let project = make_project("Work");
+    let section = section::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: Uuid::nil(),
+        remote_id: "s1".to_string(),
+        name: "Later".to_string(),
+        project_uuid: project.uuid,
+        order_index: 0,
+    };
+    let mut report = ordered_task("report", project.uuid, 0);
+    report.due_date = Some("2025-03-20".to_string());
+    let mut budget = ordered_task("Budget", project.uuid, 1);
+    budget.priority = 4; // P1
+    budget.due_date = Some("2025-03-12".to_string());
+    let mut archive = ordered_task("archive", project.uuid, 2);
+    archive.priority = 3;
+    let mut zebra = ordered_task("zebra", project.uuid, 3);
+    zebra.section_uuid = Some(section.uuid);
+    zebra.priority = 4;
+    let mut apple = ordered_task("apple", project.uuid, 4);
+    apple.section_uuid = Some(section.uuid);
+
+    let mut task_list = TaskListComponent::new();
+    task_list.update_data(
+        vec![report, budget, archive, zebra, apple],
+        vec![section],
+        vec![project],
+        Vec::new(),
+        SidebarSelection::Project(0),
+    );
+    let rows = |task_list: &TaskListComponent| -> Vec<String> {
+        task_list
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                TaskListItemType::Task(task) => Some(task.task.content.clone()),
+                TaskListItemType::Header(_) => Some("#".to_string()),
+                _ => None,
+            })
+            .collect()
+    };
+    assert_eq!(
+        rows(&task_list),
+        vec!["report", "Budget", "archive", "#", "zebra", "apple"]
+    );
+
+    // Each press of `o` moves to the next order; the section header stays in place
+    let key = KeyEvent::from(KeyCode::Char('o'));
+    task_list.handle_key_events(key);
+    assert_eq!(task_list.sort(), TaskSort::Priority);
+    assert_eq!(
+        rows(&task_list),
+        vec!["Budget", "archive", "report", "#", "zebra", "apple"]
+    );
+    assert_eq!(task_list.title(80), "Tasks · by priority (o: sort)");
+
+    task_list.handle_key_events(key);
+    assert_eq!(task_list.sort(), TaskSort::DueDate);
+    assert_eq!(
+        rows(&task_list),
+        vec!["Budget", "report", "archive", "#", "zebra", "apple"]
+    );
+
+    task_list.handle_key_events(key);
+    assert_eq!(task_list.sort(), TaskSort::Content);
+    assert_eq!(
+        rows(&task_list),
+        vec!["archive", "Budget", "report", "#", "apple", "zebra"]
+    );
+
+    task_list.handle_key_events(key);
+    assert_eq!(task_list.sort(), TaskSort::Default);
+    assert_eq!(task_list.title(80), "Tasks");
+}
+
+#[test]
+fn test_grouping_by_priority_and_label() {
+    let project = make_project("Work");
+    let mut call = ordered_task("call", project.uuid, 0);
+    call.priority = 4;
+    let errand = ordered_task("errand", project.uuid, 1);
+    let mut email = ordered_task("email", project.uuid, 2);
+    email.priority = 4;
+
+    let mut task_list = TaskListComponent::new();
+    task_list.set_task_labels(HashMap::from([
+        (call.uuid, vec!["phone".to_string(), "work".to_string()]),
+        (email.uuid, vec!["work".to_string()]),
+    ]));
+    task_list.update_data(
+        vec![call.clone(), errand, email],
+        Vec::new(),
+        vec![project],
+        vec![make_label("phone", "charcoal"), make_label("work", "charcoal")],
+        SidebarSelection::Upcoming,
+    );
+    let rows = |task_list: &TaskListComponent| -> Vec<String> {
+        task_list
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                TaskListItemType::Task(task) => Some(task.task.content.clone()),
+                TaskListItemType::Header(header) => Some(format!("# {}", header.text)),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let key = KeyEvent::from(KeyCode::Char('g'));
+    task_list.handle_key_events(key);
+    assert_eq!(task_list.grouping(), TaskGrouping::Priority);
+    assert_eq!(
+        rows(&task_list),
+        vec!["# Priority 1", "call", "email", "# Priority 4", "errand"]
+    );
+
+    // A task with several labels is listed under each of them
+    task_list.handle_key_events(key);
+    assert_eq!(task_list.grouping(), TaskGrouping::Label);
+    assert_eq!(
+        rows(&task_list),
+        vec!["# @phone", "call", "# @work", "call", "email", "# No labels", "errand"]
+    );
+    assert!(task_list.title(100).ends_with("(+/-: zoom) · grouped by label (g: group)"));
+
+    // ...but is only acted on once
+    task_list.mark_all();
+    let marked = task_list.marked_task_uuids();
+    assert_eq!(marked.len(), 3);
+    assert_eq!(marked[0], call.uuid);
+
+    task_list.handle_key_events(key);
+    assert_eq!(task_list.grouping(), TaskGrouping::View);
+}
+
+#[test]
+fn test_folding_subtasks_and_sections() {
+    let project = make_project("Work");
+    let section = section::Model {
+        uuid: Uuid::new_v4(),
+        backend_uuid: Uuid::nil(),
+        remote_id: "s1".to_string(),
+        name: "Later".to_string(),
+        project_uuid: project.uuid,
+        order_index: 0,
+    };
+    let parent = ordered_task("parent", project.uuid, 0);
+    let mut child = ordered_task("child", project.uuid, 1);
+    child.parent_uuid = Some(parent.uuid);
+    let mut filed = ordered_task("filed", project.uuid, 2);
+    filed.section_uuid = Some(section.uuid);
+    let mut also_filed = ordered_task("also filed", project.uuid, 3);
+    also_filed.section_uuid = Some(section.uuid);
+
+    let mut task_list = TaskListComponent::new();
+    task_list.update_data(
+        vec![parent.clone(), child, filed, also_filed],
+        vec![section.clone()],
+        vec![project],
+        Vec::new(),
+        SidebarSelection::Project(0),
+    );
+    let rows = |task_list: &TaskListComponent| -> Vec<String> {
+        task_list
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                TaskListItemType::Task(task) => Some(task.task.content.clone()),
+                TaskListItemType::Header(header) => Some(header.text.clone()),
+                _ => None,
+            })
+            .collect()
+    };
+    let fold = KeyEvent::from(KeyCode::Char('z'));
+
+    // A parent task folds its subtasks and stays selected
+    task_list.handle_key_events(fold);
+    assert!(task_list.is_collapsed(&parent.uuid));
+    assert_eq!(rows(&task_list), vec!["parent", "Later", "filed", "also filed"]);
+    assert_eq!(task_list.get_selected_task().map(|t| t.uuid), Some(parent.uuid));
+
+    // A task without subtasks folds its section, which counts the hidden tasks
+    task_list.handle_key_events(KeyEvent::from(KeyCode::Char('j')));
+    task_list.handle_key_events(fold);
+    assert!(task_list.is_collapsed(&section.uuid));
+    assert_eq!(rows(&task_list), vec!["parent", "▸ Later (2 hidden)"]);
+
+    // Hidden tasks are skipped by navigation
+    task_list.handle_key_events(KeyEvent::from(KeyCode::Char('j')));
+    assert_eq!(task_list.get_selected_task().map(|t| t.uuid), Some(parent.uuid));
+
+    task_list.handle_key_events(KeyEvent::from(KeyCode::Char('Z')));
+    assert_eq!(
+        rows(&task_list),
+        vec!["parent", "child", "Later", "filed", "also filed"]
+    );
+}
@@ -10,5 +10,14 @@ mod context;
 #[path = "core/event_handler.rs"]
 mod event_handler;
 
+#[path = "core/keymap.rs"]
+mod keymap;
+
 #[path = "core/task_manager.rs"]
 mod task_manager;
+
+#[path = "core/theme.rs"]
+mod theme;
+
+#[path = "core/undo.rs"]
+mod undo;
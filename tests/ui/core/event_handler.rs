@@ -1,4 +1,5 @@
-use terminalist::ui::core::event_handler::EventType;
+use terminalist::ui::core::event_handler::{EventType, ResizeDebouncer, RESIZE_DEBOUNCE};
+use tokio::time::{Duration, Instant};
 
 #[test]
 fn test_event_type_enum_exists() {
@@ -7,3 +8,40 @@ fn test_event_type_enum_exists() {
     // EventType enum should have a non-zero size
     assert!(event_size > 0, "EventType enum should have a non-zero size");
 }
+
+#[test]
+fn test_resize_burst_applies_first_and_last_size() {
+    let mut debouncer = ResizeDebouncer::new();
+    let start = Instant::now();
+
+    // A single resize after a quiet period is applied right away
+    assert_eq!(debouncer.on_resize(100, 40, start), Some((100, 40)));
+
+    // Rapid follow-ups are held back, keeping only the latest size
+    let step = Duration::from_millis(10);
+    assert_eq!(debouncer.on_resize(101, 40, start + step), None);
+    assert_eq!(debouncer.on_resize(102, 41, start + step * 2), None);
+    assert_eq!(debouncer.poll_settled(start + step * 3), None);
+    assert_eq!(
+        debouncer.time_until_settled(start + step * 3),
+        Some(RESIZE_DEBOUNCE - step)
+    );
+
+    // Once the terminal stops changing, the final size is applied exactly once
+    let settled = start + step * 2 + RESIZE_DEBOUNCE;
+    assert_eq!(debouncer.poll_settled(settled), Some((102, 41)));
+    assert_eq!(debouncer.poll_settled(settled), None);
+    assert_eq!(debouncer.time_until_settled(settled), None);
+}
+
+#[test]
+fn test_pending_resize_can_be_flushed_early() {
+    let mut debouncer = ResizeDebouncer::new();
+    let start = Instant::now();
+
+    debouncer.on_resize(80, 24, start);
+    debouncer.on_resize(90, 30, start + Duration::from_millis(5));
+
+    assert_eq!(debouncer.flush(), Some((90, 30)));
+    assert_eq!(debouncer.poll_settled(start + RESIZE_DEBOUNCE * 2), None);
+}
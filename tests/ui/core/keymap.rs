@@ -0,0 +1,199 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::BTreeMap;
+use terminalist::config::UiConfig;
+use terminalist::ui::components::dialogs::system_dialogs::help_text;
+use terminalist::ui::core::{KeyAction, KeyCategory, KeyMap, KeyPress, StatusContext};
+
+#[test]
+fn test_default_help_lists_bindings_by_category() {
+    let help = help_text(&KeyMap::default());
+
+    assert!(help.contains("NAVIGATION\n----------\nj/k         Navigate tasks (down/up)\n"));
+    assert!(help.contains("I           Quick add task to inbox (any view)\n"));
+    assert!(help.contains("End         Jump to bottom of help\n\nTASK STATUS INDICATORS"));
+
+    // Sections appear in category order
+    let positions: Vec<usize> = KeyCategory::ALL
+        .iter()
+        .map(|category| help.find(category.title()).unwrap())
+        .collect();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_help_reflects_configured_quick_add_key() {
+    let ui = UiConfig {
+        quick_add_key: 'Q',
+        ..Default::default()
+    };
+    let keymap = KeyMap::from_config(&ui);
+
+    let quick_add = keymap
+        .describe()
+        .into_iter()
+        .find(|binding| binding.description.starts_with("Quick add"))
+        .unwrap();
+    assert_eq!(quick_add.keys, "Q");
+    assert_eq!(quick_add.category, KeyCategory::Tasks);
+
+    let help = help_text(&keymap);
+    assert!(help.contains("Q           Quick add task to inbox (any view)\n"));
+    assert!(!help.contains("I           Quick add"));
+}
+
+fn keybindings(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+    entries
+        .iter()
+        .map(|(name, keys)| (name.to_string(), keys.to_string()))
+        .collect()
+}
+
+fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent::new(code, modifiers)
+}
+
+#[test]
+fn test_key_press_parsing() {
+    let ctrl_d = KeyPress::parse("ctrl+D").unwrap();
+    assert_eq!(ctrl_d.code, KeyCode::Char('d'));
+    assert!(ctrl_d.ctrl && !ctrl_d.alt);
+    assert!(ctrl_d.matches(&press(KeyCode::Char('d'), KeyModifiers::CONTROL)));
+    assert!(!ctrl_d.matches(&press(KeyCode::Char('d'), KeyModifiers::NONE)));
+    assert_eq!(ctrl_d.to_string(), "Ctrl+D");
+
+    assert_eq!(KeyPress::parse("Space").unwrap().code, KeyCode::Char(' '));
+    assert_eq!(KeyPress::parse("pagedown").unwrap().code, KeyCode::PageDown);
+    assert_eq!(KeyPress::parse("F5").unwrap().code, KeyCode::F(5));
+    assert_eq!(KeyPress::parse("+").unwrap().code, KeyCode::Char('+'));
+
+    // Shifted characters match whether or not the terminal reports Shift
+    let upper = KeyPress::parse("J").unwrap();
+    assert!(upper.matches(&press(KeyCode::Char('J'), KeyModifiers::SHIFT)));
+    assert!(upper.matches(&press(KeyCode::Char('J'), KeyModifiers::NONE)));
+
+    for invalid in ["", "Ctrl+", "jk", "F13", "Hyper+x"] {
+        assert!(KeyPress::parse(invalid).is_none(), "{:?} should not parse", invalid);
+    }
+}
+
+#[test]
+fn test_default_keymap_matches_builtin_keys() {
+    let keymap = KeyMap::default();
+
+    assert_eq!(
+        keymap.action_for(&press(KeyCode::Char('q'), KeyModifiers::NONE)),
+        Some(KeyAction::Quit)
+    );
+    assert_eq!(
+        keymap.action_for(&press(KeyCode::Char('h'), KeyModifiers::NONE)),
+        Some(KeyAction::Help)
+    );
+    assert_eq!(
+        keymap.action_for(&press(KeyCode::Char('I'), KeyModifiers::SHIFT)),
+        Some(KeyAction::QuickAdd)
+    );
+    assert_eq!(
+        keymap.action_for(&press(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+        None
+    );
+
+    // Without rebinding, components see keys unchanged
+    let j = press(KeyCode::Char('j'), KeyModifiers::NONE);
+    assert_eq!(keymap.component_key(j), Some(j));
+    let plus = press(KeyCode::Char('+'), KeyModifiers::NONE);
+    assert_eq!(keymap.component_key(plus), Some(plus));
+    // Application keys never reach the components
+    assert_eq!(
+        keymap.component_key(press(KeyCode::Char('r'), KeyModifiers::NONE)),
+        None
+    );
+}
+
+#[test]
+fn test_status_hints_follow_rebound_keys() {
+    let keymap = KeyMap::default()
+        .with_keybindings(&keybindings(&[("create_task", "n")]))
+        .unwrap();
+
+    let hints = keymap.status_hints(StatusContext::Tasks);
+    assert_eq!(hints[0], ("n".to_string(), "add"));
+    assert!(hints.contains(&("?/h".to_string(), "help")));
+
+    let marked = keymap.status_hints(StatusContext::Marked);
+    assert_eq!(marked.last(), Some(&("Esc".to_string(), "clear marks")));
+    assert_eq!(
+        keymap.status_hints(StatusContext::Dialog),
+        [("Esc".to_string(), "close")]
+    );
+}
+
+#[test]
+fn test_rebound_keys_translate_for_components() {
+    // Dvorak-style navigation on h/t, with the keys they displace moved elsewhere
+    let keymap = KeyMap::default()
+        .with_keybindings(&keybindings(&[
+            ("task_down", "h"),
+            ("task_up", "t"),
+            ("help", "?"),
+            ("due_today", "Ctrl+t"),
+            ("quit", "Ctrl+q"),
+        ]))
+        .unwrap();
+
+    let h = press(KeyCode::Char('h'), KeyModifiers::NONE);
+    assert_eq!(keymap.action_for(&h), Some(KeyAction::TaskDown));
+    assert_eq!(
+        keymap.component_key(h),
+        Some(press(KeyCode::Char('j'), KeyModifiers::NONE))
+    );
+    assert_eq!(
+        keymap.component_key(press(KeyCode::Char('t'), KeyModifiers::NONE)),
+        Some(press(KeyCode::Char('k'), KeyModifiers::NONE))
+    );
+
+    // The old keys no longer do anything
+    let j = press(KeyCode::Char('j'), KeyModifiers::NONE);
+    assert_eq!(keymap.action_for(&j), None);
+    assert_eq!(keymap.component_key(j), None);
+    assert_eq!(keymap.action_for(&press(KeyCode::Char('q'), KeyModifiers::NONE)), None);
+
+    assert_eq!(
+        keymap.action_for(&press(KeyCode::Char('q'), KeyModifiers::CONTROL)),
+        Some(KeyAction::Quit)
+    );
+
+    let help = help_text(&keymap);
+    assert!(help.contains("h/t         Navigate tasks (down/up)\n"));
+    assert!(help.contains("Ctrl+Q      Quit application\n"));
+}
+
+#[test]
+fn test_invalid_keybindings_are_reported() {
+    let error = KeyMap::default()
+        .with_keybindings(&keybindings(&[("sync", "q"), ("teleport", "x"), ("search", "Ctrl+")]))
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        error.contains("Key 'q' is bound to both 'quit' and 'sync'"),
+        "{}",
+        error
+    );
+    assert!(error.contains("Unknown action 'teleport'"), "{}", error);
+    assert!(error.contains("Invalid key 'Ctrl+' for 'search'"), "{}", error);
+
+    // The quick add key from [ui] takes part in conflict checks
+    let ui = UiConfig {
+        quick_add_key: 'r',
+        ..Default::default()
+    };
+    let error = KeyMap::from_config(&ui)
+        .with_keybindings(&BTreeMap::new())
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("Key 'r' is bound to both 'quick_add' and 'sync'"),
+        "{}",
+        error
+    );
+}
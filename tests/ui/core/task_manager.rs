@@ -1,7 +1,37 @@
 use terminalist::ui::core::task_manager::TaskManager;
+use terminalist::ui::core::{Action, DialogType};
 
 #[test]
 fn test_task_manager_creation() {
     // Test that TaskManager can be created without panicking
     let _task_manager = TaskManager::new();
 }
+
+#[tokio::test]
+async fn test_task_operation_follow_up_only_on_success() {
+    let (mut task_manager, mut actions) = TaskManager::new();
+
+    task_manager.spawn_task_operation(
+        || async { Err(anyhow::anyhow!("backend unavailable")) },
+        "Complete task".to_string(),
+        None,
+        Some(Action::ShowToast("Undid completion".to_string())),
+    );
+    assert!(matches!(
+        actions.recv().await,
+        Some(Action::ShowDialog(DialogType::Error(message))) if message.contains("backend unavailable")
+    ));
+
+    task_manager.spawn_task_operation(
+        || async { Ok("Task completed".to_string()) },
+        "Complete task".to_string(),
+        None,
+        Some(Action::ShowToast("Undid completion".to_string())),
+    );
+    assert!(matches!(
+        actions.recv().await,
+        Some(Action::ShowToast(message)) if message == "Undid completion"
+    ));
+    assert!(matches!(actions.recv().await, Some(Action::RefreshData)));
+    assert!(actions.try_recv().is_err());
+}
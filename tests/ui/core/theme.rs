@@ -0,0 +1,53 @@
+use ratatui::style::Color;
+use std::collections::BTreeMap;
+use terminalist::config::ThemeConfig;
+use terminalist::ui::core::Theme;
+
+fn theme_config(name: &str, colors: &[(&str, &str)]) -> ThemeConfig {
+    ThemeConfig {
+        name: name.to_string(),
+        colors: colors
+            .iter()
+            .map(|(role, color)| (role.to_string(), color.to_string()))
+            .collect::<BTreeMap<_, _>>(),
+    }
+}
+
+#[test]
+fn test_builtin_themes() {
+    assert_eq!(Theme::from_config(&ThemeConfig::default()).unwrap(), Theme::default());
+    for name in Theme::NAMES {
+        assert!(Theme::builtin(name).is_some(), "missing built-in theme {name}");
+    }
+    assert_ne!(Theme::solarized_dark(), Theme::default());
+}
+
+#[test]
+fn test_theme_color_overrides() {
+    let theme = Theme::from_config(&theme_config(
+        "solarized-dark",
+        &[("accent", "light-blue"), ("border", "244"), ("priority_1", "#ff0000")],
+    ))
+    .unwrap();
+    assert_eq!(theme.accent, Color::LightBlue);
+    assert_eq!(theme.border, Color::Indexed(244));
+    assert_eq!(theme.priority_color(4), Color::Rgb(255, 0, 0));
+    // Roles that aren't overridden keep the base theme's colors
+    assert_eq!(theme.error, Theme::solarized_dark().error);
+}
+
+#[test]
+fn test_invalid_theme_config() {
+    assert!(Theme::from_config(&theme_config("nord", &[])).is_err());
+    assert!(Theme::from_config(&theme_config("default", &[("accent", "not-a-color")])).is_err());
+    assert!(Theme::from_config(&theme_config("default", &[("background", "red")])).is_err());
+}
+
+#[test]
+fn test_priority_colors() {
+    let theme = Theme::default();
+    assert_eq!(theme.priority_color(4), Color::Red);
+    assert_eq!(theme.priority_color(1), Color::White);
+    // Unknown priorities are shown like p4
+    assert_eq!(theme.priority_color(0), theme.priority_color(1));
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+use terminalist::sync::tasks::TaskSnapshot;
+use terminalist::ui::core::{UndoEntry, UndoStack};
+use uuid::Uuid;
+
+#[test]
+fn test_undo_stack_pops_newest_first() {
+    let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+    let mut stack = UndoStack::default();
+    stack.push(UndoEntry::Complete { task_uuid: first });
+    stack.push(UndoEntry::Priority {
+        task_uuid: second,
+        previous: 2,
+    });
+
+    assert_eq!(
+        stack.pop(),
+        Some(UndoEntry::Priority {
+            task_uuid: second,
+            previous: 2
+        })
+    );
+    assert_eq!(stack.pop(), Some(UndoEntry::Complete { task_uuid: first }));
+    assert_eq!(stack.pop(), None);
+}
+
+#[test]
+fn test_undo_stack_forgets_oldest_when_full() {
+    let tasks: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+    let mut stack = UndoStack::new(2);
+    for &task_uuid in &tasks {
+        stack.push(UndoEntry::Delete {
+            task_uuid,
+            snapshot: TaskSnapshot::default(),
+        });
+    }
+
+    assert_eq!(stack.len(), 2);
+    assert_eq!(
+        stack.pop(),
+        Some(UndoEntry::Delete {
+            task_uuid: tasks[2],
+            snapshot: TaskSnapshot::default()
+        })
+    );
+    assert_eq!(
+        stack.pop(),
+        Some(UndoEntry::Delete {
+            task_uuid: tasks[1],
+            snapshot: TaskSnapshot::default()
+        })
+    );
+    assert!(stack.is_empty());
+}
+
+#[test]
+fn test_undo_entry_inverse() {
+    let task_uuid = Uuid::new_v4();
+
+    assert_eq!(
+        UndoEntry::Complete { task_uuid }.inverse(),
+        ("Restore task", task_uuid.to_string())
+    );
+    // A deletion carries what the task is recreated with
+    let snapshot = TaskSnapshot {
+        labels: vec!["errands".to_string()],
+        due: Some("2025-03-12T09:00:00".to_string()),
+        deadline: Some("2025-03-14".to_string()),
+    };
+    let (operation, info) = UndoEntry::Delete {
+        task_uuid,
+        snapshot: snapshot.clone(),
+    }
+    .inverse();
+    assert_eq!(operation, "Restore deleted task");
+    let (uuid, encoded) = info.split_once('|').unwrap();
+    assert_eq!(uuid, task_uuid.to_string());
+    assert_eq!(serde_json::from_str::<TaskSnapshot>(encoded).unwrap(), snapshot);
+    assert_eq!(
+        UndoEntry::Priority { task_uuid, previous: 3 }.inverse(),
+        ("Cycle priority", format!("{}|3", task_uuid))
+    );
+}
+
+#[test]
+fn test_undo_stack_forgets_removed_tasks() {
+    let (kept, removed) = (Uuid::new_v4(), Uuid::new_v4());
+    let mut stack = UndoStack::default();
+    stack.push(UndoEntry::Complete { task_uuid: removed });
+    stack.push(UndoEntry::Priority {
+        task_uuid: kept,
+        previous: 1,
+    });
+    stack.push(UndoEntry::Delete {
+        task_uuid: removed,
+        snapshot: TaskSnapshot::default(),
+    });
+    assert_eq!(stack.task_uuids().collect::<Vec<_>>(), vec![removed, kept, removed]);
+
+    stack.forget_tasks(&HashSet::from([removed]));
+    assert_eq!(stack.len(), 1);
+    assert_eq!(stack.pop().map(|entry| entry.task_uuid()), Some(kept));
+}
@@ -1,2 +1,20 @@
+#[path = "common/fixtures.rs"]
+mod fixtures;
+
+#[path = "utils/color.rs"]
+mod color;
+
 #[path = "utils/datetime.rs"]
 mod datetime;
+
+#[path = "utils/fuzzy.rs"]
+mod fuzzy;
+
+#[path = "utils/export.rs"]
+mod export;
+
+#[path = "utils/import.rs"]
+mod import;
+
+#[path = "utils/markdown.rs"]
+mod markdown;
@@ -0,0 +1,27 @@
+use ratatui::style::Color;
+use terminalist::utils::color;
+
+#[test]
+fn test_all_named_colors_are_mapped() {
+    for name in color::COLOR_NAMES {
+        assert_ne!(color::to_terminal_color(name), Color::Gray, "{} should be mapped", name);
+    }
+    assert!(color::COLOR_NAMES.contains(&color::DEFAULT_COLOR));
+}
+
+#[test]
+fn test_unknown_color_falls_back_to_gray() {
+    assert_eq!(color::to_terminal_color("not_a_color"), Color::Gray);
+}
+
+#[test]
+fn test_display_name() {
+    assert_eq!(color::display_name("berry_red"), "Berry Red");
+    assert_eq!(color::display_name("grey"), "Grey");
+}
+
+#[test]
+fn test_index_of_falls_back_to_default_color() {
+    assert_eq!(color::COLOR_NAMES[color::index_of("teal")], "teal");
+    assert_eq!(color::COLOR_NAMES[color::index_of("not_a_color")], color::DEFAULT_COLOR);
+}
@@ -1,4 +1,4 @@
-use chrono::{NaiveDate, Weekday};
+use chrono::{Duration, FixedOffset, Local, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
 use terminalist::utils::datetime::*;
 
 #[test]
@@ -58,3 +58,343 @@ fn test_format_human_datetime_iso_format() {
     assert!(formatted.contains("at"));
     assert!(formatted.contains("09:00"));
 }
+
+#[test]
+fn test_is_postponement() {
+    assert!(is_postponement(Some("2025-03-01"), Some("2025-03-02")));
+    assert!(!is_postponement(Some("2025-03-02"), Some("2025-03-01")));
+    assert!(!is_postponement(Some("2025-03-02"), Some("2025-03-02")));
+    // Scheduling an undated task or clearing the date is not postponing it
+    assert!(!is_postponement(None, Some("2025-03-02")));
+    assert!(!is_postponement(Some("2025-03-02"), None));
+}
+
+#[test]
+fn test_shift_due() {
+    let today = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    assert_eq!(shift_due(None, 1, today), "2025-03-15");
+    assert_eq!(shift_due(Due::parse(Some("2025-02-28"), None), 1, today), "2025-03-01");
+    assert_eq!(shift_due(Due::parse(Some("2025-03-01"), None), -1, today), "2025-02-28");
+
+    // A due time stays at the same local time of day
+    let due = Due::DateTime(local("2025-03-20", "09:30"));
+    let expected = format_due(
+        NaiveDate::from_ymd_opt(2025, 3, 21).unwrap(),
+        NaiveTime::from_hms_opt(9, 30, 0),
+    );
+    assert_eq!(shift_due(Some(due), 1, today), expected);
+}
+
+fn local(date: &str, time: &str) -> chrono::DateTime<Local> {
+    let naive = chrono::NaiveDateTime::parse_from_str(&format!("{date} {time}"), "%Y-%m-%d %H:%M").unwrap();
+    Local.from_local_datetime(&naive).single().unwrap()
+}
+
+#[test]
+fn test_due_parse_prefers_datetime() {
+    let day = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    assert_eq!(Due::parse(Some("2025-03-14"), None), Some(Due::Date(day)));
+    assert_eq!(
+        Due::parse(Some("2025-03-14"), Some("2025-03-14T09:30:00")),
+        Some(Due::DateTime(local("2025-03-14", "09:30")))
+    );
+    // A datetime stored in the date field is still recognized
+    assert_eq!(
+        Due::parse(Some("2025-03-14T09:30:00"), None),
+        Some(Due::DateTime(local("2025-03-14", "09:30")))
+    );
+    assert_eq!(Due::parse(Some("someday"), None), None);
+    assert_eq!(Due::parse(None, None), None);
+}
+
+#[test]
+fn test_due_date_uses_local_day_of_utc_datetime() {
+    let utc = chrono::Utc.with_ymd_and_hms(2025, 3, 14, 23, 30, 0).unwrap();
+    let due = Due::parse(Some("2025-03-14"), Some(&utc.to_rfc3339())).unwrap();
+    assert_eq!(due.date(), utc.with_timezone(&Local).date_naive());
+}
+
+#[test]
+fn test_due_overdue_and_today() {
+    let now = local("2025-03-14", "12:00");
+
+    let morning = Due::DateTime(local("2025-03-14", "09:00"));
+    assert!(morning.is_overdue(now));
+    assert!(morning.is_today(now));
+
+    let today = Due::Date(now.date_naive());
+    assert!(!today.is_overdue(now));
+    assert!(today.is_today(now));
+
+    let yesterday = Due::Date(NaiveDate::from_ymd_opt(2025, 3, 13).unwrap());
+    assert!(yesterday.is_overdue(now));
+    assert!(!yesterday.is_today(now));
+}
+
+#[test]
+fn test_due_humanize_and_display() {
+    let now = local("2025-03-14", "12:00");
+
+    assert_eq!(Due::Date(now.date_naive()).humanize(now), "today");
+    assert_eq!(
+        Due::DateTime(local("2025-03-15", "14:30")).humanize(now),
+        "tomorrow at 14:30"
+    );
+    assert_eq!(
+        Due::DateTime(local("2025-03-15", "14:30")).display("%d/%m/%Y", "%H:%M"),
+        "15/03/2025 14:30"
+    );
+    assert_eq!(Due::Date(now.date_naive()).display("%d/%m/%Y", "%H:%M"), "14/03/2025");
+}
+
+#[test]
+fn test_format_relative() {
+    let today = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap(); // Friday
+    let day = |offset: i64| today + Duration::days(offset);
+
+    assert_eq!(format_relative(day(0), today, "%Y-%m-%d"), "Today");
+    assert_eq!(format_relative(day(1), today, "%Y-%m-%d"), "Tomorrow");
+    assert_eq!(format_relative(day(-1), today, "%Y-%m-%d"), "Yesterday");
+    assert_eq!(format_relative(day(3), today, "%Y-%m-%d"), "Mon");
+    assert_eq!(format_relative(day(-3), today, "%Y-%m-%d"), "3d ago");
+    // Beyond a week, the configured format is used
+    assert_eq!(format_relative(day(7), today, "%d/%m/%Y"), "21/03/2025");
+    assert_eq!(format_relative(day(-7), today, "%b %d"), "Mar 07");
+
+    let now = local("2025-03-14", "12:00");
+    assert_eq!(
+        Due::DateTime(local("2025-03-15", "14:30")).relative(now, "%Y-%m-%d", "%I:%M %p"),
+        "Tomorrow 02:30 PM"
+    );
+}
+
+#[test]
+fn test_format_ago() {
+    let now = local("2025-03-14", "12:00");
+    let _frozen = freeze_time(now);
+    let ago = |elapsed: Duration| format_ago((now - elapsed).with_timezone(&Utc));
+
+    assert_eq!(ago(Duration::seconds(20)), "just now");
+    assert_eq!(ago(Duration::minutes(4)), "4m ago");
+    assert_eq!(ago(Duration::minutes(150)), "2h ago");
+    assert_eq!(ago(Duration::days(3)), "3d ago");
+    // A clock that moved back doesn't give a negative age
+    assert_eq!(ago(Duration::minutes(-5)), "just now");
+}
+
+#[test]
+fn test_is_valid_format() {
+    assert!(is_valid_format("%d/%m/%Y"));
+    assert!(is_valid_format("%b %d"));
+    assert!(!is_valid_format("%Q"));
+}
+
+#[test]
+fn test_frozen_time_drives_relative_dates() {
+    let _frozen = freeze_time(local("2025-12-31", "23:00"));
+
+    assert_eq!(today(), NaiveDate::from_ymd_opt(2025, 12, 31).unwrap());
+    assert_eq!(format_today(), "2025-12-31");
+    // Rolls over into the next year
+    assert_eq!(format_date_with_offset(1), "2026-01-01");
+    assert_eq!(format_human_date("2026-01-01"), "tomorrow");
+    assert_eq!(format_human_datetime("2025-12-31T08:15:00"), "today at 08:15");
+}
+
+#[test]
+fn test_due_dates_agree_near_midnight() {
+    for offset_hours in [13, -11] {
+        let offset = FixedOffset::east_opt(offset_hours * 3600).unwrap();
+        // Just before midnight in that timezone, a different day than in UTC
+        let now = offset.with_ymd_and_hms(2025, 3, 14, 23, 59, 0).unwrap().with_timezone(&Local);
+        let _frozen = freeze_time(now);
+
+        // Due an hour before and an hour after, stored in UTC like the backends do
+        for (due_at, overdue) in [(now - Duration::hours(1), true), (now + Duration::hours(1), false)] {
+            let stored = due_at.with_timezone(&Utc).to_rfc3339();
+            let due = Due::parse(None, Some(&stored)).unwrap();
+            let due_date = local_due_date(Some(&stored[..10]), Some(&stored)).unwrap();
+
+            // The stored day, the parsed due date and the date-based views all agree
+            assert_eq!(due_date, format_ymd(due.date()), "offset {offset_hours}");
+            assert_eq!(due.is_today(now), due_date == format_today(), "offset {offset_hours}");
+            assert_eq!(due.date() < today(), due_date < format_today(), "offset {offset_hours}");
+            assert_eq!(due.is_overdue(now), overdue, "offset {offset_hours}");
+        }
+    }
+
+    // Without a datetime, the stored date is kept
+    assert_eq!(local_due_date(Some("2025-03-14"), None).as_deref(), Some("2025-03-14"));
+}
+
+#[test]
+fn test_frozen_time_is_restored_when_guard_drops() {
+    let outer = local("2025-06-01", "10:00");
+    let _outer = freeze_time(outer);
+    {
+        let _inner = freeze_time(local("2030-01-01", "10:00"));
+        assert_eq!(format_today(), "2030-01-01");
+    }
+    assert_eq!(now(), outer);
+}
+
+#[test]
+fn test_parse_natural_date_deadline_phrases() {
+    let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(); // Wednesday
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+
+    assert_eq!(parse_natural_date("today", today), date(2025, 1, 15));
+    assert_eq!(parse_natural_date("Tomorrow", today), date(2025, 1, 16));
+    assert_eq!(parse_natural_date("by end of month", today), date(2025, 1, 31));
+    assert_eq!(parse_natural_date("eom", today), date(2025, 1, 31));
+    assert_eq!(parse_natural_date("end of week", today), date(2025, 1, 19));
+    assert_eq!(parse_natural_date("end of year", today), date(2025, 12, 31));
+    assert_eq!(parse_natural_date("next week", today), date(2025, 1, 20));
+    assert_eq!(parse_natural_date("weekend", today), date(2025, 1, 18));
+    assert_eq!(parse_natural_date("fri", today), date(2025, 1, 17));
+    assert_eq!(parse_natural_date("by next friday", today), date(2025, 1, 17));
+    assert_eq!(parse_natural_date("wednesday", today), date(2025, 1, 22));
+    assert_eq!(parse_natural_date("in 3 days", today), date(2025, 1, 18));
+    assert_eq!(parse_natural_date("2 weeks", today), date(2025, 1, 29));
+    assert_eq!(parse_natural_date("in 1 month", today), date(2025, 2, 15));
+    assert_eq!(parse_natural_date("on 2025-03-01", today), date(2025, 3, 1));
+}
+
+#[test]
+fn test_parse_natural_date_end_of_month_handles_short_months() {
+    let february = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+    assert_eq!(
+        parse_natural_date("end of month", february),
+        NaiveDate::from_ymd_opt(2024, 2, 29)
+    );
+
+    let december = NaiveDate::from_ymd_opt(2025, 12, 3).unwrap();
+    assert_eq!(
+        parse_natural_date("end of month", december),
+        NaiveDate::from_ymd_opt(2025, 12, 31)
+    );
+}
+
+#[test]
+fn test_parse_natural_date_rejects_unknown_phrases() {
+    let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+    assert_eq!(parse_natural_date("someday", today), None);
+    assert_eq!(parse_natural_date("in 3 fortnights", today), None);
+    assert_eq!(parse_natural_date("", today), None);
+}
+
+#[test]
+fn test_parse_natural_date_month_day() {
+    let today = NaiveDate::from_ymd_opt(2025, 3, 20).unwrap();
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+
+    assert_eq!(parse_natural_date("mar 25", today), date(2025, 3, 25));
+    assert_eq!(parse_natural_date("25 March", today), date(2025, 3, 25));
+    assert_eq!(parse_natural_date("april 1st", today), date(2025, 4, 1));
+    // Days already past this year roll over to the next one
+    assert_eq!(parse_natural_date("mar 14", today), date(2026, 3, 14));
+    assert_eq!(parse_natural_date("feb 30", today), None);
+}
+
+#[test]
+fn test_parse_time_of_day() {
+    let time = |h, m| NaiveTime::from_hms_opt(h, m, 0);
+
+    assert_eq!(parse_time_of_day("3pm"), time(15, 0));
+    assert_eq!(parse_time_of_day("3:30 pm"), time(15, 30));
+    assert_eq!(parse_time_of_day("12am"), time(0, 0));
+    assert_eq!(parse_time_of_day("12pm"), time(12, 0));
+    assert_eq!(parse_time_of_day("noon"), time(12, 0));
+    assert_eq!(parse_time_of_day("09:15"), time(9, 15));
+    assert_eq!(parse_time_of_day("15"), None);
+    assert_eq!(parse_time_of_day("13pm"), None);
+    assert_eq!(parse_time_of_day("3:5pm"), None);
+}
+
+#[test]
+fn test_parse_natural_due_with_time() {
+    let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(); // Wednesday
+    let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+    let time = |h, m| NaiveTime::from_hms_opt(h, m, 0);
+
+    assert_eq!(parse_natural_due("tomorrow", today), Some((date(1, 16), None)));
+    assert_eq!(
+        parse_natural_due("next tuesday 3pm", today),
+        Some((date(1, 21), time(15, 0)))
+    );
+    assert_eq!(
+        parse_natural_due("fri at 9:30 am", today),
+        Some((date(1, 17), time(9, 30)))
+    );
+    assert_eq!(
+        parse_natural_due("5pm tomorrow", today),
+        Some((date(1, 16), time(17, 0)))
+    );
+    assert_eq!(parse_natural_due("at 5pm", today), Some((date(1, 15), time(17, 0))));
+    assert_eq!(parse_natural_due("whenever", today), None);
+}
+
+#[test]
+fn test_split_trailing_due() {
+    let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(); // Wednesday
+    let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+
+    assert_eq!(
+        split_trailing_due("Call mom next tuesday 3pm", today),
+        (
+            "Call mom".to_string(),
+            Some((date(1, 21), NaiveTime::from_hms_opt(15, 0, 0)))
+        )
+    );
+    assert_eq!(
+        split_trailing_due("Renew passport in 3 days", today),
+        ("Renew passport".to_string(), Some((date(1, 18), None)))
+    );
+    assert_eq!(
+        split_trailing_due("File taxes mar 14", today),
+        ("File taxes".to_string(), Some((date(3, 14), None)))
+    );
+
+    // Content without a date phrase, or that would be left empty, is kept as is
+    assert_eq!(split_trailing_due("Buy milk", today), ("Buy milk".to_string(), None));
+    assert_eq!(split_trailing_due("tomorrow", today), ("tomorrow".to_string(), None));
+    assert_eq!(split_trailing_due("Call Tom", today), ("Call Tom".to_string(), None));
+    assert_eq!(
+        split_trailing_due("Rent a car for 2 days", today),
+        ("Rent a car for 2 days".to_string(), None)
+    );
+}
+
+#[test]
+fn test_format_due() {
+    let date = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    assert_eq!(format_due(date, None), "2025-03-14");
+
+    let due = format_due(date, NaiveTime::from_hms_opt(15, 0, 0));
+    let parsed = parse_datetime(&due).unwrap();
+    assert_eq!(parsed.date_naive(), date);
+    assert_eq!(parsed.time(), NaiveTime::from_hms_opt(15, 0, 0).unwrap());
+}
+
+#[test]
+fn test_parse_and_format_duration() {
+    assert_eq!(parse_duration("90"), Some(90));
+    assert_eq!(parse_duration("45m"), Some(45));
+    assert_eq!(parse_duration("45 min"), Some(45));
+    assert_eq!(parse_duration("2h"), Some(120));
+    assert_eq!(parse_duration("1h30m"), Some(90));
+    assert_eq!(parse_duration("1h 30m"), Some(90));
+    assert_eq!(parse_duration("1.5h"), Some(90));
+    // As stored from Todoist's amount and unit
+    assert_eq!(parse_duration("30 minute"), Some(30));
+    assert_eq!(parse_duration("1 day"), Some(1440));
+    assert_eq!(parse_duration("0"), None);
+    assert_eq!(parse_duration("soon"), None);
+    assert_eq!(parse_duration("2 weeks"), None);
+
+    assert_eq!(format_duration(45), "45m");
+    assert_eq!(format_duration(120), "2h");
+    assert_eq!(format_duration(90), "1h30m");
+    assert_eq!(format_duration(2880), "2d");
+    assert_eq!(backend_duration(90), "90 minute");
+}
@@ -0,0 +1,77 @@
+use crate::fixtures::{make_project, make_task};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use terminalist::entities::project;
+use terminalist::utils::export::{self, ExportFormat, ExportGroup};
+use uuid::Uuid;
+
+fn sample() -> (Vec<ExportGroup>, Vec<project::Model>, HashMap<Uuid, Vec<String>>) {
+    let project = make_project("Work");
+    let mut report = make_task("Write report", project.uuid);
+    report.priority = 4;
+    report.due_date = Some("2025-03-12".to_string());
+    let mut outline = make_task("Outline, then draft", project.uuid);
+    outline.parent_uuid = Some(report.uuid);
+    outline.is_completed = true;
+    let call = make_task("Call \"Bob\"", project.uuid);
+
+    let task_labels = HashMap::from([(report.uuid, vec!["office".to_string(), "urgent".to_string()])]);
+    let groups = vec![
+        ExportGroup {
+            header: None,
+            tasks: vec![report, outline],
+        },
+        ExportGroup {
+            header: Some("Later".to_string()),
+            tasks: vec![call],
+        },
+    ];
+    (groups, vec![project], task_labels)
+}
+
+#[test]
+fn test_markdown_checklist() {
+    let (groups, projects, task_labels) = sample();
+    assert_eq!(
+        export::to_markdown("Work", &groups, &projects, &task_labels),
+        "# Work\n\
+         \n\
+         - [ ] Write report · #Work · due 2025-03-12 · p1 · @office @urgent\n  \
+         - [x] Outline, then draft · #Work\n\
+         \n\
+         ## Later\n\
+         \n\
+         - [ ] Call \"Bob\" · #Work\n"
+    );
+}
+
+#[test]
+fn test_csv_rows() {
+    let (groups, projects, task_labels) = sample();
+    assert_eq!(
+        export::to_csv(&groups, &projects, &task_labels),
+        "group,content,project,due,priority,labels,completed\n\
+         ,Write report,Work,2025-03-12,p1,office urgent,false\n\
+         ,\"Outline, then draft\",Work,,p4,,true\n\
+         Later,\"Call \"\"Bob\"\"\",Work,,p4,,false\n"
+    );
+}
+
+#[test]
+fn test_export_paths() {
+    let today = NaiveDate::from_ymd_opt(2025, 3, 12).unwrap();
+    assert_eq!(
+        export::default_path("", "@Errands & Shopping", ExportFormat::Markdown, today),
+        "~/terminalist-errands-shopping-2025-03-12.md"
+    );
+    assert_eq!(
+        export::default_path("/tmp/exports/", "Today", ExportFormat::Csv, today),
+        "/tmp/exports/terminalist-today-2025-03-12.csv"
+    );
+    assert_eq!(ExportFormat::from_path("report.CSV"), ExportFormat::Csv);
+    assert_eq!(ExportFormat::from_path("report.txt"), ExportFormat::Markdown);
+    assert_eq!(
+        export::expand_home("/tmp/report.md"),
+        std::path::PathBuf::from("/tmp/report.md")
+    );
+}
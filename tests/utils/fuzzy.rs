@@ -0,0 +1,30 @@
+use terminalist::utils::fuzzy::fuzzy_score;
+
+#[test]
+fn test_fuzzy_score_matches_subsequences_of_each_word() {
+    assert!(fuzzy_score("by mlk", "Buy milk").is_some());
+    // Words match in any order
+    assert!(fuzzy_score("milk buy", "Buy milk").is_some());
+    // Every word has to match
+    assert!(fuzzy_score("by eggs", "Buy milk").is_none());
+    assert!(fuzzy_score("mom", "Buy milk").is_none());
+    assert_eq!(fuzzy_score("", "Buy milk"), Some(0));
+}
+
+#[test]
+fn test_fuzzy_score_allows_one_transposition() {
+    let exact = fuzzy_score("milk", "Buy milk").unwrap();
+    let swapped = fuzzy_score("mlik", "Buy milk").unwrap();
+    assert!(swapped < exact);
+    assert!(fuzzy_score("lmki", "Buy milk").is_none());
+}
+
+#[test]
+fn test_fuzzy_score_prefers_close_matches() {
+    let compact = fuzzy_score("milk", "Buy milk").unwrap();
+    let scattered = fuzzy_score("milk", "Make invoices for lake").unwrap();
+    assert!(compact > scattered);
+
+    // Matching at a word start beats matching inside a word
+    assert!(fuzzy_score("pay", "Pay rent").unwrap() > fuzzy_score("pay", "Repay loan").unwrap());
+}
@@ -0,0 +1,82 @@
+use terminalist::utils::import::{self, ImportedTask};
+
+#[test]
+fn test_markdown_checklist_with_headers_and_export_details() {
+    let parsed = import::parse_markdown(
+        "# Work\n\
+         \n\
+         - [ ] Write report · #Office · due 2025-03-12 · p1 · @urgent\n  \
+         - [x] Outline\n\
+         Some notes\n\
+         ## Later\n\
+         * Call Bob · the plumber\n\
+         - [ ] Bad date · due someday\n",
+    );
+
+    assert_eq!(
+        parsed.tasks,
+        vec![
+            ImportedTask {
+                line: 3,
+                content: "Write report @urgent".to_string(),
+                project: Some("Office".to_string()),
+                section: None,
+                due: Some("2025-03-12".to_string()),
+                priority: Some(4),
+            },
+            ImportedTask {
+                line: 7,
+                content: "Call Bob · the plumber".to_string(),
+                project: Some("Work".to_string()),
+                section: Some("Later".to_string()),
+                due: None,
+                priority: None,
+            },
+        ]
+    );
+    assert_eq!(parsed.skipped, 1);
+    let failed: Vec<(usize, &str)> = parsed
+        .failures
+        .iter()
+        .map(|failure| (failure.line, failure.reason.as_str()))
+        .collect();
+    assert_eq!(
+        failed,
+        vec![(5, "Not a list item or header"), (8, "Invalid due date 'someday'")]
+    );
+}
+
+#[test]
+fn test_todoist_json_export() {
+    let parsed = import::parse_file(
+        "backup.JSON",
+        r#"{
+            "projects": [{"id": "p1", "name": "Home"}],
+            "sections": [{"id": 7, "name": "Kitchen"}],
+            "items": [
+                {"content": "Fix tap", "project_id": "p1", "section_id": 7, "priority": 3,
+                 "due": {"date": "2025-03-12"}, "labels": ["diy"]},
+                {"content": "Done already", "checked": true},
+                {"content": "  "}
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        parsed.tasks,
+        vec![ImportedTask {
+            line: 1,
+            content: "Fix tap @diy".to_string(),
+            project: Some("Home".to_string()),
+            section: Some("Kitchen".to_string()),
+            due: Some("2025-03-12".to_string()),
+            priority: Some(3),
+        }]
+    );
+    assert_eq!(parsed.skipped, 1);
+    assert_eq!(parsed.failures.len(), 1);
+    assert_eq!(parsed.failures[0].line, 3);
+
+    assert!(import::parse_file("backup.json", "{\"projects\": []}").is_err());
+}
@@ -0,0 +1,102 @@
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use terminalist::utils::markdown::{to_text, MarkdownStyle};
+
+fn style() -> MarkdownStyle {
+    MarkdownStyle {
+        text: Style::default().fg(Color::White),
+        link: Style::default().fg(Color::Blue),
+        marker: Style::default().fg(Color::DarkGray),
+        code: Style::default().fg(Color::Cyan),
+    }
+}
+
+fn text(line: &Line) -> String {
+    line.spans.iter().map(|span| span.content.as_ref()).collect()
+}
+
+fn render(input: &str) -> Vec<Line<'static>> {
+    to_text(input, &style()).lines
+}
+
+#[test]
+fn test_inline_emphasis_code_and_links_are_styled() {
+    let lines = render("Call **Bob** about *the* `api` at [the wiki](https://wiki.example.com)");
+    let text_style = style().text;
+    assert_eq!(
+        lines[0].spans,
+        vec![
+            Span::styled("Call ", text_style),
+            Span::styled("Bob", text_style.add_modifier(Modifier::BOLD)),
+            Span::styled(" about ", text_style),
+            Span::styled("the", text_style.add_modifier(Modifier::ITALIC)),
+            Span::styled(" ", text_style),
+            Span::styled("api", style().code),
+            Span::styled(" at ", text_style),
+            Span::styled("the wiki", text_style.patch(style().link)),
+            Span::styled(" (https://wiki.example.com)", style().marker),
+        ]
+    );
+}
+
+#[test]
+fn test_nested_emphasis() {
+    let lines = render("*all **of** it*");
+    let italic = style().text.add_modifier(Modifier::ITALIC);
+    assert_eq!(
+        lines[0].spans,
+        vec![
+            Span::styled("all ", italic),
+            Span::styled("of", italic.add_modifier(Modifier::BOLD)),
+            Span::styled(" it", italic),
+        ]
+    );
+}
+
+#[test]
+fn test_blocks_get_markers() {
+    let lines = render("# Notes\n- milk\n  * eggs\n- [ ] call\n- [x] email\n2. second\n> quoted\n---");
+    let texts: Vec<String> = lines.iter().map(text).collect();
+    let rule = "─".repeat(20);
+    assert_eq!(
+        texts,
+        [
+            "Notes",
+            "• milk",
+            "  • eggs",
+            "[ ] call",
+            "[x] email",
+            "2. second",
+            "│ quoted",
+            rule.as_str(),
+        ]
+    );
+    assert!(lines[0].spans[0].style.add_modifier.contains(Modifier::BOLD));
+    assert_eq!(lines[1].spans[0].style, style().marker);
+    assert!(lines[4].spans[1].style.add_modifier.contains(Modifier::CROSSED_OUT));
+}
+
+#[test]
+fn test_code_blocks_are_kept_verbatim() {
+    let lines = render("```\nlet **x** = 1;\n```\nafter");
+    let texts: Vec<String> = lines.iter().map(text).collect();
+    assert_eq!(texts, ["  let **x** = 1;", "after"]);
+    assert_eq!(lines[0].spans[0].style, style().code);
+}
+
+#[test]
+fn test_unsupported_or_unclosed_syntax_stays_plain() {
+    for input in [
+        "2 * 3 * 4",
+        "an **unclosed bold",
+        "snake_case_name and file_name",
+        "[not a link] (here)",
+        "a `lonely backtick",
+        "#hashtag",
+    ] {
+        let lines = render(input);
+        assert_eq!(text(&lines[0]), input);
+        assert_eq!(lines[0].spans.len(), 1, "{input}");
+    }
+    assert_eq!(text(&render(r"\*not italic\*")[0]), "*not italic*");
+}